@@ -1,3 +1,25 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+#[path = "udl_features.rs"]
+mod udl_features;
+
+const UDL_FILE: &str = "src/swift_xet_rust.udl";
+
 fn main() {
-    uniffi::generate_scaffolding("src/swift_xet_rust.udl").unwrap();
+    println!("cargo:rerun-if-changed={}", UDL_FILE);
+    println!("cargo:rerun-if-changed=udl_features.rs");
+
+    let udl = fs::read_to_string(UDL_FILE).unwrap();
+    let udl = udl_features::strip_disabled_features(&udl, |feature| {
+        let name = feature.to_uppercase().replace('-', "_");
+        env::var_os(format!("CARGO_FEATURE_{}", name)).is_some()
+    });
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let udl_path = out_dir.join("swift_xet_rust.udl");
+    fs::write(&udl_path, udl).unwrap();
+
+    uniffi::generate_scaffolding_for_crate(udl_path.to_str().unwrap(), "swift_xet_rust").unwrap();
 }
@@ -1,16 +1,165 @@
 use hub_client::RepoInfo as HubRepoInfo;
 use reqwest::Url;
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Once, PoisonError, RwLock};
+use std::time::{Duration, Instant};
 use uniffi::*;
-use urlencoding::encode;
 
+mod xet_cache;
+mod xet_callbacks;
+mod xet_clock;
+mod xet_collision;
+#[cfg(feature = "upload")]
+mod xet_commit;
+mod xet_deadline;
+mod xet_detached;
 mod xet_download;
+mod xet_faults;
+mod xet_fingerprint;
+mod xet_format;
+#[cfg(feature = "upload")]
+mod xet_gitattributes;
+mod xet_glob;
+mod xet_http;
+mod xet_hub;
+mod xet_idle;
+#[cfg(feature = "upload")]
+mod xet_journal;
+mod xet_jwt;
+mod xet_managed;
 mod xet_metadata;
-
+mod xet_parallel;
+mod xet_preset;
+mod xet_ratelimit;
+mod xet_readahead;
+mod xet_reconstruction;
+mod xet_recording;
+mod xet_redirect;
+mod xet_region;
+mod xet_resolution;
+mod xet_resume;
+mod xet_retry;
+mod xet_rollback;
+mod xet_scheduler;
+mod xet_segments;
+#[cfg(feature = "upload")]
+mod xet_staging;
+mod xet_storage;
+mod xet_template;
+mod xet_termination;
+mod xet_throttle;
+mod xet_tls;
+mod xet_transfer;
+mod xet_unicode;
+mod xet_url;
+mod xet_usage;
+mod xet_verify;
+
+use xet_cache::CacheLayout;
+use xet_callbacks::CallbackDispatcher;
+use xet_clock::Clock;
+use xet_collision::{Claim, CollisionDetector};
+use xet_detached::{Caller, LeaveOnDrop};
 use xet_download::{XetDownloadConfig, XetDownloadPlan};
-use xet_metadata::{fetch_file_metadata, get_cached_cas_jwt, FileResolveMetadata, XetFileData};
+use xet_faults::Faults;
+use xet_fingerprint::FingerprintCache;
+use xet_glob::PathFilter;
+use xet_hub::HubCache;
+use xet_idle::{IdleMonitor, IdleSlot};
+#[cfg(feature = "upload")]
+use xet_journal::{CommitIntent, CommitJournal, IntentState, LiveIntent, UploadedFile};
+use xet_managed::{ManagedLibrary, ManagedRecord};
+use xet_metadata::{fetch_file_metadata, get_cached_cas_jwt, FileResolveMetadata};
+use xet_parallel::{map_bounded, map_prioritized};
+use xet_ratelimit::unix_now;
+use xet_readahead::ReadAhead;
+use xet_reconstruction::TermCache;
+use xet_recording::SessionRecorder;
+use xet_redirect::RedirectCache;
+use xet_resolution::ResolutionCache;
+use xet_resume::PartialDownload;
+use xet_retry::with_retries;
+use xet_rollback::OperationJournal;
+use xet_scheduler::Scheduler;
+#[cfg(feature = "upload")]
+use xet_staging::StagedFile;
+use xet_template::{DestinationTemplate, TemplateContext};
+use xet_termination::Termination;
+use xet_throttle::Throttle;
+use xet_tls::TlsOptions;
+use xet_transfer::TransferObserver;
+#[cfg(feature = "upload")]
+use xet_transfer::UploadProgress;
+use xet_usage::UsageLedger;
+use xet_verify::PrefixHasher;
+
+/// The Hub endpoint used unless the client is configured with others.
+const DEFAULT_ENDPOINT: &str = "https://huggingface.co";
+
+/// The revision used when neither the caller nor the client configuration provides one.
+const DEFAULT_REVISION: &str = "main";
+
+/// The header the Hub uses to report a machine-readable error code.
+const HEADER_X_ERROR_CODE: &str = "x-error-code";
+
+/// The allocation unit assumed when estimating on-disk sizes, matching APFS.
+const DISK_BLOCK_SIZE: u64 = 4096;
+
+/// The number of bytes a `FileStream` fetches with each range request.
+const STREAM_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
+/// The number of files a multi-file download fetches at once unless configured.
+const DEFAULT_MAX_CONCURRENT_DOWNLOADS: usize = 4;
+
+/// The number of paths resolved with each request to the Hub's paths-info endpoint.
+const PATHS_INFO_BATCH_SIZE: usize = 50;
+
+/// The number of files checked with each request to the Hub's preupload endpoint.
+#[cfg(feature = "upload")]
+const PREUPLOAD_BATCH_SIZE: usize = 256;
+
+/// The chunk sizes the Xet protocol splits uploaded content with.
+///
+/// Content only deduplicates against chunks cut the same way, so every Xet
+/// uploader uses these and the data crate accepts no others.
+#[cfg(feature = "upload")]
+const XET_CHUNKING: ChunkingParameters = ChunkingParameters {
+    target_chunk_size: 64 * 1024,
+    min_chunk_size: 8 * 1024,
+    max_chunk_size: 128 * 1024,
+};
+
+/// How many bytes of files are uploaded to CAS between journal checkpoints.
+///
+/// A retried upload skips the files sent before the last checkpoint, at the
+/// cost of one more call into the data crate, and its shard upload, per batch.
+/// Checkpoints fall between whole files, so a file larger than this that was
+/// interrupted is sent again from its start.
+///
+/// Finer checkpoints would need the xorbs and shards already in CAS, which
+/// the data crate neither reports while a call runs nor accepts when a new
+/// one starts, so progress within a batch isn't journaled.
+#[cfg(feature = "upload")]
+const UPLOAD_CHECKPOINT_BYTES: u64 = 256 * 1024 * 1024;
+
+/// The number of files `upload_folder` commits in each commit.
+#[cfg(feature = "upload")]
+const UPLOAD_FOLDER_BATCH_SIZE: usize = 100;
+
+/// The paths `upload_folder` never uploads: git's own files and the cache
+/// `huggingface_hub` keeps inside downloaded folders.
+#[cfg(feature = "upload")]
+const UPLOAD_FOLDER_IGNORED: [&str; 5] =
+    [".git", ".git/", "*/.git", "*/.git/", ".cache/huggingface/"];
+
+/// How long before its expiration a CAS token is treated as expired.
+///
+/// This covers clock skew between the device and the Hub, and leaves the
+/// token valid long enough for a transfer to get under way.
+const CAS_TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
 
 pub(crate) const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
@@ -24,9 +173,13 @@ pub enum XetError {
     /// A general operation failure occurred.
     ///
     /// This error indicates that a Xet operation failed for reasons not
-    /// covered by the other specific error types.
+    /// covered by the other specific error types. `cause` describes the
+    /// underlying failure, when there is one.
     #[error("Xet operation failed: {message}")]
-    OperationFailed { message: String },
+    OperationFailed {
+        message: String,
+        cause: Option<ErrorCause>,
+    },
 
     /// Invalid input was provided to a method.
     ///
@@ -39,15 +192,23 @@ pub enum XetError {
     ///
     /// This error indicates a problem reading from or writing to the local
     /// file system, such as permission issues or missing directories.
+    /// `cause` carries the kind of I/O failure, when known.
     #[error("IO error: {message}")]
-    IoError { message: String },
+    IoError {
+        message: String,
+        cause: Option<ErrorCause>,
+    },
 
     /// A network error occurred during a request.
     ///
     /// This error indicates a problem communicating with remote servers,
-    /// such as connection failures or HTTP errors.
+    /// such as connection failures or HTTP errors. `cause` tells them apart,
+    /// along with the HTTP status, when known.
     #[error("Network error: {message}")]
-    NetworkError { message: String },
+    NetworkError {
+        message: String,
+        cause: Option<ErrorCause>,
+    },
 
     /// An authentication error occurred.
     ///
@@ -69,12 +230,293 @@ pub enum XetError {
     /// parsing failures or invalid token formats.
     #[error("Token error: {message}")]
     TokenError { message: String },
+
+    /// The operation was refused by a configured policy or guardrail.
+    ///
+    /// This error occurs when an operation would exceed a limit set in
+    /// `XetClientConfig`, such as a maximum file size or an allowed owner list.
+    #[error("Policy violation: {message}")]
+    PolicyViolation { message: String },
+
+    /// Downloaded content did not match its expected hash.
+    ///
+    /// This error occurs when download verification is enabled and the
+    /// SHA-256 of the written file differs from the one reported by the Hub.
+    /// The partially trusted file is removed before the error is returned.
+    #[error("Integrity error: {message}")]
+    IntegrityError { message: String },
+
+    /// The operation was cancelled.
+    ///
+    /// This error occurs when the `CancellationToken` passed to a transfer is
+    /// cancelled before the transfer completes. Partially written files are removed.
+    /// `reason` is the one given when the token was cancelled.
+    #[error("Cancelled: {message}")]
+    Cancelled {
+        message: String,
+        reason: CancellationReason,
+    },
+
+    /// A blocking method was called from a context where it would deadlock.
+    ///
+    /// This error occurs when a client method is called from a thread that is
+    /// already driving an asynchronous runtime, such as from inside a callback
+    /// that a host runs on a runtime worker thread.
+    #[error("Reentrant call: {message}")]
+    ReentrantCall { message: String },
+
+    /// Two files in a multi-file download would be written to the same destination.
+    ///
+    /// This error occurs when destinations are identical, or differ only in
+    /// case on a case-insensitive filesystem, and the client's
+    /// `DestinationCollisionStrategy` is `Fail`. No files are downloaded.
+    #[error("Destination collision: {message}")]
+    DestinationCollision { message: String },
+
+    /// The repository can't be accessed without suitable credentials.
+    ///
+    /// This error occurs when a repository is private or gated and the client
+    /// has no token, or its token hasn't been granted access. Anonymous requests
+    /// can't tell a private repository from a missing one, so this is also
+    /// reported for repositories that don't exist when the client has no token.
+    #[error("Authentication required to access {repo}")]
+    AuthRequired { repo: String },
+
+    /// The Hub answered with a server error.
+    ///
+    /// `retry_after_secs` is the delay the Hub asked clients to wait, if it
+    /// sent a `Retry-After` header. `service_wide` is true when a request
+    /// unrelated to the repository failed too, meaning the Hub itself appears
+    /// to be down rather than the repository being at fault.
+    #[error("Service unavailable: {message}")]
+    ServiceUnavailable {
+        message: String,
+        retry_after_secs: Option<u64>,
+        service_wide: bool,
+    },
+
+    /// A CAS token expired, or was about to, before a transfer could start.
+    ///
+    /// This error occurs when a `CasJwtInfo` passed to a download has expired
+    /// and the client has no way to refresh it. `expired_at` is the token's
+    /// expiration time as a Unix timestamp. Request a new token with
+    /// `get_cas_jwt` and try again.
+    #[error("Token expired: {message}")]
+    TokenExpired { message: String, expired_at: u64 },
+
+    /// The operation didn't finish within its time limit.
+    ///
+    /// This error occurs when a call outlives the timeout of the
+    /// `CancellationToken` or `DownloadHandle` passed to it, or a network
+    /// operation outlives the client's `operation_timeout_secs`. Requests in
+    /// flight are aborted, and partial files are kept or removed as they are
+    /// when a transfer is cancelled.
+    #[error("Timed out: {message}")]
+    Timeout { message: String },
+
+    /// The client's token doesn't grant an operation on a repository.
+    ///
+    /// This error occurs with fine-grained tokens, which may be granted some
+    /// repositories and not others, or read access to a repository but not
+    /// write access. `repo` is the repository the token was refused for. Check
+    /// access ahead of time with `can_access`.
+    #[error("Permission denied: no {} access to {repo}", .operation.as_str())]
+    PermissionDenied {
+        repo: String,
+        operation: RepoOperation,
+    },
+}
+
+impl XetError {
+    /// Returns a stable, machine-readable code identifying the kind of error.
+    ///
+    /// Codes never change between versions, unlike error messages, so they can
+    /// be used to look up localized descriptions.
+    pub fn code(&self) -> &'static str {
+        match self {
+            XetError::OperationFailed { .. } => "XET_E_OPERATION_FAILED",
+            XetError::InvalidInput { .. } => "XET_E_INVALID_INPUT",
+            XetError::IoError { .. } => "XET_E_IO",
+            XetError::NetworkError { .. } => "XET_E_NETWORK",
+            XetError::AuthError { .. } => "XET_E_AUTH",
+            XetError::CacheError { .. } => "XET_E_CACHE",
+            XetError::TokenError { .. } => "XET_E_TOKEN",
+            XetError::PolicyViolation { .. } => "XET_E_POLICY_VIOLATION",
+            XetError::IntegrityError { .. } => "XET_E_INTEGRITY",
+            XetError::Cancelled { .. } => "XET_E_CANCELLED",
+            XetError::ReentrantCall { .. } => "XET_E_REENTRANT_CALL",
+            XetError::DestinationCollision { .. } => "XET_E_DESTINATION_COLLISION",
+            XetError::AuthRequired { .. } => "XET_E_AUTH_REQUIRED",
+            XetError::ServiceUnavailable { .. } => "XET_E_SERVICE_UNAVAILABLE",
+            XetError::TokenExpired { .. } => "XET_E_TOKEN_EXPIRED",
+            XetError::Timeout { .. } => "XET_E_TIMEOUT",
+            XetError::PermissionDenied { .. } => "XET_E_PERMISSION_DENIED",
+        }
+    }
+
+    /// Returns the underlying failure this error preserves, if any.
+    pub fn cause(&self) -> Option<ErrorCause> {
+        match self {
+            XetError::OperationFailed { cause, .. }
+            | XetError::IoError { cause, .. }
+            | XetError::NetworkError { cause, .. } => cause.clone(),
+            _ => None,
+        }
+    }
+}
+
+/// The underlying failure behind an error, for handling errors programmatically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorCause {
+    /// A file system operation failed.
+    Io { kind: IoErrorKind },
+    /// An HTTP request failed. `status` is the response status, if one was received.
+    Http {
+        kind: HttpErrorKind,
+        status: Option<u16>,
+    },
+    /// Xet data processing failed. `kind` names the data processing error variant.
+    DataProcessing { kind: String },
+}
+
+/// The kind of a failed file system operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IoErrorKind {
+    NotFound,
+    PermissionDenied,
+    AlreadyExists,
+    StorageFull,
+    ReadOnlyFilesystem,
+    Interrupted,
+    UnexpectedEof,
+    InvalidData,
+    Other,
+}
+
+/// The stage at which an HTTP request failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpErrorKind {
+    /// The host name couldn't be resolved.
+    Dns,
+    /// A connection to the server couldn't be established.
+    Connect,
+    /// The request timed out.
+    Timeout,
+    /// The request was redirected too many times.
+    Redirect,
+    /// The server answered with an error status.
+    Status,
+    /// The response body couldn't be read or decoded.
+    Body,
+    /// The request failed for another reason.
+    Other,
+}
+
+impl From<&std::io::Error> for ErrorCause {
+    fn from(err: &std::io::Error) -> Self {
+        use std::io::ErrorKind;
+        let kind = match err.kind() {
+            ErrorKind::NotFound => IoErrorKind::NotFound,
+            ErrorKind::PermissionDenied => IoErrorKind::PermissionDenied,
+            ErrorKind::AlreadyExists => IoErrorKind::AlreadyExists,
+            ErrorKind::StorageFull => IoErrorKind::StorageFull,
+            ErrorKind::ReadOnlyFilesystem => IoErrorKind::ReadOnlyFilesystem,
+            ErrorKind::Interrupted => IoErrorKind::Interrupted,
+            ErrorKind::UnexpectedEof => IoErrorKind::UnexpectedEof,
+            ErrorKind::InvalidData => IoErrorKind::InvalidData,
+            _ => IoErrorKind::Other,
+        };
+        ErrorCause::Io { kind }
+    }
+}
+
+impl From<&reqwest::Error> for ErrorCause {
+    fn from(err: &reqwest::Error) -> Self {
+        let kind = if err.is_timeout() {
+            HttpErrorKind::Timeout
+        } else if err.is_connect() && is_dns_failure(err) {
+            HttpErrorKind::Dns
+        } else if err.is_connect() {
+            HttpErrorKind::Connect
+        } else if err.is_redirect() {
+            HttpErrorKind::Redirect
+        } else if err.is_status() {
+            HttpErrorKind::Status
+        } else if err.is_body() || err.is_decode() {
+            HttpErrorKind::Body
+        } else {
+            HttpErrorKind::Other
+        };
+        ErrorCause::Http {
+            kind,
+            status: err.status().map(|status| status.as_u16()),
+        }
+    }
+}
+
+/// Returns whether a connection failed because its host name couldn't be resolved.
+///
+/// reqwest doesn't classify resolver failures, but the connector reports
+/// them as a `dns error` somewhere in the source chain.
+fn is_dns_failure(err: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(err);
+    while let Some(err) = source {
+        if err.to_string().starts_with("dns error") {
+            return true;
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// Returns the stable, machine-readable code of `error`, such as `"XET_E_AUTH_REQUIRED"`.
+///
+/// Use the code rather than the message to map errors to user-facing text:
+/// messages are in English and may change between versions, but codes don't.
+pub fn error_code(error: &XetError) -> String {
+    error.code().to_string()
+}
+
+/// Formats a byte count with a decimal unit, such as `"1.5 GB"`.
+///
+/// Progress surfaces should use this and its siblings rather than their own
+/// formatting, so the same numbers read the same everywhere. The output is
+/// the same on every platform and in every locale.
+pub fn format_bytes(bytes: u64) -> String {
+    xet_format::bytes(bytes)
+}
+
+/// Formats a throughput in bytes per second, such as `"12.3 MB/s"`.
+pub fn format_rate(bytes_per_second: f64) -> String {
+    xet_format::rate(bytes_per_second)
+}
+
+/// Formats the time left to transfer `remaining_bytes` at `bytes_per_second`,
+/// such as `"3m 07s"`, or returns `None` while the transfer isn't making progress.
+pub fn format_eta(remaining_bytes: u64, bytes_per_second: f64) -> Option<String> {
+    xet_format::eta(remaining_bytes, bytes_per_second)
+}
+
+/// Reads a recording made with `XetClient::record_session` into simulated
+/// faults that reproduce the failures and slow responses it captured.
+///
+/// Configure a client with the faults as its `simulated_faults` and repeat
+/// the calls the recording was made with to see the same errors and retries.
+///
+/// # Errors
+///
+/// Returns `XetError::IoError` if the file can't be read, or
+/// `XetError::InvalidInput` if it isn't a recording.
+#[cfg(feature = "fault-injection")]
+pub fn replay_faults(path: String) -> Result<Vec<SimulatedFault>, XetError> {
+    xet_recording::replay_faults(Path::new(&path))
 }
 
 impl From<std::io::Error> for XetError {
     fn from(err: std::io::Error) -> Self {
         XetError::IoError {
             message: err.to_string(),
+            cause: Some(ErrorCause::from(&err)),
         }
     }
 }
@@ -96,11 +538,13 @@ impl From<reqwest::Error> for XetError {
             } else {
                 XetError::NetworkError {
                     message: format!("HTTP error {}: {}", status, err),
+                    cause: Some(ErrorCause::from(&err)),
                 }
             }
         } else {
             XetError::NetworkError {
                 message: format!("Network error: {}", err),
+                cause: Some(ErrorCause::from(&err)),
             }
         }
     }
@@ -110,6 +554,7 @@ impl From<serde_json::Error> for XetError {
     fn from(err: serde_json::Error) -> Self {
         XetError::OperationFailed {
             message: format!("JSON parsing error: {}", err),
+            cause: None,
         }
     }
 }
@@ -118,6 +563,7 @@ impl From<hub_client::HubClientError> for XetError {
     fn from(err: hub_client::HubClientError) -> Self {
         XetError::OperationFailed {
             message: format!("Hub client error: {}", err),
+            cause: None,
         }
     }
 }
@@ -126,10 +572,23 @@ impl From<data::errors::DataProcessingError> for XetError {
     fn from(err: data::errors::DataProcessingError) -> Self {
         XetError::OperationFailed {
             message: format!("Data processing error: {}", err),
+            cause: Some(ErrorCause::DataProcessing {
+                kind: variant_name(&err),
+            }),
         }
     }
 }
 
+/// Returns the name of the enum variant `value` is, from its `Debug` representation.
+fn variant_name(value: &impl std::fmt::Debug) -> String {
+    let debug = format!("{:?}", value);
+    debug
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
 impl From<utils::errors::AuthError> for XetError {
     fn from(err: utils::errors::AuthError) -> Self {
         XetError::TokenError {
@@ -138,6 +597,550 @@ impl From<utils::errors::AuthError> for XetError {
     }
 }
 
+/// How the client treats files flagged by the Hub's security scanner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnsafeFilePolicy {
+    /// Download flagged files without checking their scan status.
+    #[default]
+    Allow,
+    /// Download flagged files, but report them with an `UnsafeFileDetected` event.
+    /// Files whose scan status can't be looked up are downloaded as well.
+    Warn,
+    /// Refuse to download flagged files.
+    Refuse,
+}
+
+/// The kind of async runtime a client drives its work on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RuntimeFlavor {
+    /// A runtime with a pool of worker threads.
+    #[default]
+    MultiThread,
+    /// A runtime that runs only on the threads calling into the client.
+    ///
+    /// Use this in app extensions and widgets, where spawning a thread pool is
+    /// prohibited or wasteful. Work makes progress only while a client method is
+    /// running, and the client avoids blocking thread pools.
+    CurrentThread,
+}
+
+/// How multi-file downloads handle two files that would be written to the same destination.
+///
+/// Destinations that differ only in case collide on case-insensitive
+/// filesystems, such as the default APFS configuration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DestinationCollisionStrategy {
+    /// Fail with `XetError::DestinationCollision` before downloading anything.
+    #[default]
+    Fail,
+    /// Write later files to a renamed destination, such as `model-2.bin`,
+    /// and report each with a `DestinationRenamed` event.
+    Rename,
+}
+
+/// What a multi-file download leaves on disk when one of its files fails.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FailurePolicy {
+    /// Keep the files that finished before the failure.
+    #[default]
+    KeepCompleted,
+    /// Undo everything the download wrote, restoring any files it replaced.
+    ///
+    /// Use this when a partial set of files is worse than none, such as when
+    /// installing a model whose files must match each other.
+    RollBack,
+}
+
+/// How snapshot and folder downloads treat zero-byte files and Git placeholders
+/// such as `.gitattributes` and `.gitkeep`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlaceholderFilePolicy {
+    /// Download them like any other file.
+    #[default]
+    Fetch,
+    /// Create zero-byte files locally without any requests, and download placeholders
+    /// that have content like any other file.
+    ///
+    /// Resolve requests for empty files can fail in surprising ways, and in
+    /// bulk they add up to many requests for nothing.
+    CreateLocally,
+    /// Leave out zero-byte files and placeholders entirely.
+    Skip,
+}
+
+/// A class of failure that might succeed if tried again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryClass {
+    /// The request timed out.
+    Timeout,
+    /// The host couldn't be resolved or connected to.
+    Connect,
+    /// The server answered with a 5xx status.
+    ServerError,
+    /// The server answered with 429 Too Many Requests.
+    RateLimited,
+}
+
+/// How the client retries metadata fetches, CAS token refreshes, and downloads.
+///
+/// Only failures in one of the `retry_on` classes are retried. Missing files,
+/// authorization failures, and other errors that would fail the same way
+/// again are returned immediately.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The most times an operation is tried, including the first attempt.
+    pub max_attempts: u32,
+    /// The delay before the first retry in milliseconds.
+    pub base_delay_ms: u64,
+    /// The longest delay between attempts in milliseconds.
+    pub max_delay_ms: u64,
+    /// Whether the delay doubles after each retry, up to `max_delay_ms`.
+    pub exponential_backoff: bool,
+    /// Whether each delay is shortened by a random amount of up to half, so
+    /// clients that failed together don't retry together.
+    pub jitter: bool,
+    /// The classes of failure that are retried.
+    pub retry_on: Vec<RetryClass>,
+}
+
+/// A named set of download options tuned for common network conditions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DownloadPreset {
+    /// Fetches several files at once with a deep read-ahead window.
+    WiFi,
+    /// Fetches few files at once with a shallow read-ahead window, and limits
+    /// throughput to 2 MiB per second.
+    Cellular,
+    /// Fetches one file at a time with minimal memory, retries patiently, and
+    /// releases the client's threads when idle.
+    Background,
+}
+
+/// Sends requests for matching repositories to an endpoint other than the Hub.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EndpointOverride {
+    /// A glob pattern matched against the repository's full name, such as `"my-org/*"`.
+    pub repo_pattern: String,
+    /// The base URL of the endpoint, such as `"https://hub.internal.example.com"`.
+    pub endpoint: String,
+}
+
+/// Certificates or public keys that connections to matching hosts must present.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CertificatePin {
+    /// A glob pattern matched against the host name, such as `"huggingface.co"` or `"*.hf.co"`.
+    pub host_pattern: String,
+    /// Hex-encoded SHA-256 digests of certificates or their public keys, any
+    /// one of which must appear in the host's chain.
+    ///
+    /// A certificate's digest covers its DER encoding, and a public key's its
+    /// DER-encoded SubjectPublicKeyInfo. Pin a backup key too, so rotating a
+    /// certificate doesn't lock the app out.
+    pub sha256_hashes: Vec<String>,
+}
+
+/// A directory that holds an area of the cache instead of the default cache directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CacheRoot {
+    /// The area to relocate: `Metadata`, `ManagedModels`, or `DownloadedFiles`.
+    pub area: CacheArea,
+    /// The absolute path of the directory, such as an App Group container.
+    pub path: String,
+}
+
+/// A network fault the client simulates on a schedule, for testing how an app
+/// copes with slow and failing connections.
+#[cfg(feature = "fault-injection")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedFault {
+    /// A substring of the URLs the fault applies to, such as `"/resolve/"`, or
+    /// `None` for every request.
+    pub url_pattern: Option<String>,
+    /// What goes wrong.
+    pub kind: FaultKind,
+    /// The number of matching requests to let through before the fault starts.
+    pub skip: u32,
+    /// The number of matching requests the fault applies to, or `None` for
+    /// every one after those skipped.
+    pub count: Option<u32>,
+}
+
+/// What goes wrong with a request subject to a `SimulatedFault`.
+#[cfg(feature = "fault-injection")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FaultKind {
+    /// The request is delayed before it's sent.
+    Latency { millis: u64 },
+    /// The server appears to answer with `status`, such as 429 or 503, without
+    /// the request being sent.
+    Status {
+        status: u16,
+        retry_after_secs: Option<u64>,
+    },
+    /// The connection drops after `after_bytes` of the response body.
+    DropConnection { after_bytes: u64 },
+    /// The response body ends cleanly after `after_bytes`, short of its declared length.
+    TruncateBody { after_bytes: u64 },
+}
+
+/// The time the client judges token expiry and other server-set deadlines against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClockSource {
+    /// The device's clock, corrected by the skew estimated from the `Date`
+    /// header of Hub responses once it exceeds `clock_skew_tolerance_secs`.
+    #[default]
+    Hub,
+    /// The device's clock as is.
+    Device,
+}
+
+/// Which commit a multi-file download uses when its revision moves partway through.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RevisionPinning {
+    /// Resolve each revision to a commit once and download every file at that commit.
+    ///
+    /// A branch that advances mid-download doesn't affect the result, so the
+    /// files always come from a single commit.
+    #[default]
+    Pinned,
+    /// Resolve the revision again for each file.
+    ///
+    /// Files downloaded after a branch advances come from the new head, so the
+    /// result may mix files from several commits.
+    FollowHead,
+}
+
+/// An operation on a repository that a token may or may not be granted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RepoOperation {
+    /// Resolving and downloading the repository's files.
+    Read,
+    /// Uploading files and committing to the repository.
+    Write,
+}
+
+impl RepoOperation {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RepoOperation::Read => "read",
+            RepoOperation::Write => "write",
+        }
+    }
+}
+
+/// The Unicode normalization applied to local paths the client writes.
+///
+/// Repository paths may arrive in either composed (NFC) or decomposed (NFD)
+/// form. Normalizing consistently keeps a file findable by the same name it
+/// was downloaded under.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PathNormalization {
+    /// Compose characters, as in most Hub paths and Swift string literals.
+    #[default]
+    Nfc,
+    /// Decompose characters, as HFS+ stores names.
+    Nfd,
+    /// Write paths exactly as given.
+    Preserve,
+}
+
+/// A notable occurrence reported to the client's event listener.
+#[derive(Clone, Debug)]
+pub enum XetEvent {
+    /// A file flagged by the Hub's security scanner was encountered.
+    UnsafeFileDetected {
+        repo: String,
+        path: String,
+        status: String,
+    },
+    /// A file in a multi-file download was renamed to avoid a destination collision.
+    DestinationRenamed {
+        repo: String,
+        path: String,
+        destination: String,
+        renamed_to: String,
+    },
+    /// A Hub API response reported the current rate limit.
+    RateLimitUpdated {
+        limit: Option<u64>,
+        remaining: Option<u64>,
+        reset_at: Option<u64>,
+    },
+    /// A file download was cancelled through its `CancellationToken`.
+    TransferCancelled {
+        repo: String,
+        path: String,
+        reason: CancellationReason,
+    },
+    /// A file was resolved from a cached resolution because the Hub was unreachable.
+    StaleMetadataServed {
+        repo: String,
+        path: String,
+        revision: String,
+    },
+    /// A revision moved while a file was being fetched, so the file was
+    /// fetched again at `commit`, the commit the revision was resolved to.
+    RevisionMoved {
+        repo: String,
+        path: String,
+        revision: String,
+        commit: String,
+    },
+    /// A multi-file download skipped the files in a repository the client
+    /// can't read, because the client's `skip_inaccessible_repos` is set.
+    RepoSkipped { repo: String },
+}
+
+/// A host-provided listener for client events.
+///
+/// Events are informational; the listener can't influence the operation
+/// that produced them.
+pub trait XetEventListener: Send + Sync {
+    /// Called when an event occurs.
+    fn on_event(&self, event: XetEvent);
+}
+
+/// Configuration for a `XetClient`.
+///
+/// All fields are optional. Guardrail limits let apps embedding the client
+/// protect users from accidentally downloading a repository that would fill
+/// their device.
+#[derive(Clone, Default)]
+pub struct XetClientConfig {
+    /// A Hugging Face authentication token, if any.
+    pub token: Option<String>,
+    /// The maximum size of a single downloaded file in bytes, if limited.
+    pub max_file_size_bytes: Option<u64>,
+    /// The maximum combined size of a multi-file download in bytes, if limited.
+    pub max_snapshot_size_bytes: Option<u64>,
+    /// The largest response the client reads into memory in bytes, or `None`
+    /// for the default of 512 MiB.
+    ///
+    /// This covers `get_file_content`, tree listings, and other Hub API
+    /// responses, so pointing the client at a huge file or listing fails with
+    /// `XetError::PolicyViolation` rather than exhausting memory. Downloads to
+    /// files are streamed to disk and aren't affected.
+    pub max_response_body_bytes: Option<u64>,
+    /// The repository owners the client may access, or `None` to allow any owner.
+    ///
+    /// Owners are compared case-insensitively.
+    pub allowed_repo_owners: Option<Vec<String>>,
+    /// How to treat files flagged as unsafe by the Hub's security scanner.
+    ///
+    /// Any policy other than `Allow` adds one metadata request per download.
+    pub unsafe_file_policy: UnsafeFilePolicy,
+    /// Whether to check downloaded files against the SHA-256 reported by the Hub.
+    ///
+    /// Content is hashed as it is written, so verification doesn't need a
+    /// second read of the file once the transfer completes.
+    pub verify_downloads: bool,
+    /// The revision used when a method is called without one, or `None` for `"main"`.
+    ///
+    /// Set this when working with repositories whose default branch isn't `main`.
+    pub default_revision: Option<String>,
+    /// The number of chunks fetched ahead of a sequential reader, or `None` for the default of 4.
+    ///
+    /// Larger windows let readers that parse files sequentially overlap network
+    /// and compute, at the cost of holding more chunks in memory.
+    pub read_ahead_chunks: Option<u32>,
+    /// The kind of runtime the client creates.
+    pub runtime_flavor: RuntimeFlavor,
+    /// The number of worker threads for a multi-threaded runtime, or `None` for one per core.
+    ///
+    /// Ignored for the current-thread flavor.
+    pub worker_threads: Option<u32>,
+    /// How multi-file downloads handle files that would share a destination.
+    pub collision_strategy: DestinationCollisionStrategy,
+    /// The Unicode normalization applied to destination paths.
+    ///
+    /// Paths are compared without regard to normalization when detecting
+    /// destination collisions, whatever the policy.
+    pub path_normalization: PathNormalization,
+    /// Which commit multi-file downloads use if their revision moves partway through.
+    pub revision_pinning: RevisionPinning,
+    /// What multi-file downloads leave on disk when one of their files fails.
+    pub failure_policy: FailurePolicy,
+    /// The CAS endpoint to use when the Hub offers several, or `None` to use the fastest.
+    ///
+    /// The preference is ignored if the Hub doesn't offer the endpoint.
+    pub preferred_cas_endpoint: Option<String>,
+    /// The maximum number of redirects followed by a request, or `None` for the default of 10.
+    ///
+    /// Credentials are never forwarded when a redirect leaves the original host.
+    pub max_redirects: Option<u32>,
+    /// The number of seconds without a client call after which the client sheds
+    /// its runtime and pooled connections, or `None` to keep them for its lifetime.
+    ///
+    /// Shed resources are rebuilt on the next call, which pays the cost of new
+    /// worker threads and connections. Background cache maintenance keeps the
+    /// runtime alive while it's running.
+    pub idle_timeout_secs: Option<u64>,
+    /// Whether to silence the diagnostics the client writes to standard error.
+    ///
+    /// This can be changed later with `set_quiet`.
+    pub quiet: bool,
+    /// The number of files a multi-file download fetches at once, or `None` for the default of 4.
+    ///
+    /// Metadata for the files is resolved with the same concurrency.
+    pub max_concurrent_downloads: Option<u32>,
+    /// The number of range requests the client's downloads make at once, or
+    /// `None` for the default of 16.
+    ///
+    /// The budget is shared by every download in progress rather than set per
+    /// file: a download running alone may use all of it, and concurrent
+    /// downloads split it between them. Each Xet term and each HTTP download
+    /// takes one request. Downloads made with `download_files` are scheduled
+    /// by the Xet data crate instead.
+    pub max_concurrent_range_gets: Option<u32>,
+    /// How snapshot and folder downloads treat zero-byte and placeholder files.
+    pub placeholder_file_policy: PlaceholderFilePolicy,
+    /// Whether downloads fall back on the last successful resolution of a file when the Hub is unreachable.
+    ///
+    /// Each successful resolution is recorded in the cache. If resolving a file
+    /// later fails because the Hub is down, timing out, or rate limiting, the
+    /// download proceeds with the recorded etag and hash, and its report is
+    /// flagged as served stale. Missing files and authorization failures are
+    /// never masked.
+    pub serve_stale_metadata: bool,
+    /// The most seconds the CDN URL a file's resolve request redirects to is
+    /// reused for, or `None` to send every content request through the Hub.
+    ///
+    /// Reusing the URL saves a Hub round trip on each request for the file's
+    /// content: re-downloads, resumed downloads, and each range request of a
+    /// segmented download or stream. Signed URLs are reused only until shortly
+    /// before they expire, and a URL is dropped as soon as a request to it fails.
+    pub resolve_redirect_cache_secs: Option<u64>,
+    /// The time CAS token expiry and signed URL expiry are judged against.
+    ///
+    /// A device whose clock is off would otherwise discard valid tokens as
+    /// expired, or start resuming a download with a token the CAS server
+    /// rejects. Check the estimated skew with `get_clock_skew_secs`.
+    pub clock_source: ClockSource,
+    /// The seconds the device's clock may differ from the Hub's before it's
+    /// corrected, or `None` for the default of 30.
+    ///
+    /// The Hub's time is only known to the second and arrives after some
+    /// latency, so a small estimated skew is noise.
+    pub clock_skew_tolerance_secs: Option<u64>,
+    /// The most bytes per second the client downloads across all transfers, if limited.
+    ///
+    /// Use this to cap throughput on metered or cellular connections. The
+    /// limit covers HTTP downloads, file streams, and the range requests that
+    /// reconstruct files stored with Xet. This can be changed later with
+    /// `set_max_download_bytes_per_second`.
+    pub max_download_bytes_per_second: Option<u64>,
+    /// How failed requests are retried, or `None` for the default policy.
+    ///
+    /// The policy covers Xet reconstruction too. The data crate reads its
+    /// retry settings once per process, so a client with any other policy
+    /// reconstructs files with its own range requests instead.
+    pub retry_policy: Option<RetryPolicy>,
+    /// A preset supplying values for the download options above that are left
+    /// unset, or `None` to use each option's own default.
+    ///
+    /// The preset covers `max_concurrent_downloads`, `max_concurrent_range_gets`,
+    /// `read_ahead_chunks`, `max_download_bytes_per_second`, `idle_timeout_secs`,
+    /// and `retry_policy`. Options set explicitly take precedence.
+    pub download_preset: Option<DownloadPreset>,
+    /// Endpoints that serve matching repositories instead of the Hub, such as
+    /// an internal mirror for an organization's repositories.
+    ///
+    /// The first override whose pattern matches a repository applies to its
+    /// metadata, tree listings, file resolution, and CAS token requests. The
+    /// client's token is sent to every configured endpoint.
+    pub endpoint_overrides: Option<Vec<EndpointOverride>>,
+    /// Hub endpoints to try in order, or `None` for `https://huggingface.co` alone.
+    ///
+    /// Use this to reach the Hub through a mirror or an internal proxy, falling
+    /// back to the next endpoint when one can't be reached or fails with a
+    /// server error. Metadata, tree listings, and file resolution fall back;
+    /// CAS token requests and uploads use the first endpoint. Repositories
+    /// matching an `endpoint_overrides` pattern use their override instead.
+    /// The client's token is sent to every configured endpoint.
+    pub hub_endpoints: Option<Vec<String>>,
+    /// PEM-encoded certificates to trust as roots in addition to the system's,
+    /// such as the CA of an enterprise proxy that intercepts TLS.
+    ///
+    /// Each string may hold several certificates.
+    pub additional_root_certificates: Option<Vec<String>>,
+    /// Certificates or public keys that the Hub, CAS, and other hosts must
+    /// present, or `None` to accept any valid chain.
+    ///
+    /// Pins are checked during the handshake, before any request is sent.
+    /// Pinned clients verify chains against the Mozilla root program and
+    /// `additional_root_certificates` rather than the system's roots.
+    ///
+    /// The Xet data crate makes its own connections, so while either option is
+    /// set, files stored with Xet are reconstructed over this client's
+    /// connections and CAS tokens are requested by this client. With pins,
+    /// uploads fail rather than connect unpinned.
+    pub certificate_pins: Option<Vec<CertificatePin>>,
+    /// Directories that hold areas of the cache instead of the default cache
+    /// directory, or `None` to keep every area there.
+    ///
+    /// Point an app and its extensions at the same App Group container to
+    /// share resolutions, managed models, and downloaded files even though
+    /// their sandboxes differ. Areas can share a directory. Chunks, shards,
+    /// and staged uploads are kept by the Xet data crate, which places them
+    /// for the whole process, so they can't be relocated.
+    pub cache_roots: Option<Vec<CacheRoot>>,
+    /// The number of seconds a connection may take to establish, or `None` for no limit.
+    pub connect_timeout_secs: Option<u64>,
+    /// The number of seconds a response may go without receiving any data, or
+    /// `None` for no limit.
+    ///
+    /// This aborts stalled connections without limiting how long a large
+    /// download may take while data keeps arriving.
+    pub read_timeout_secs: Option<u64>,
+    /// The number of seconds any single network operation may take, or `None`
+    /// for no limit.
+    ///
+    /// An operation is a request and its response, or the whole transfer of a
+    /// file's content, including time spent paused, so allow for the largest
+    /// file the client downloads. A call whose operation outlives the limit
+    /// fails with `XetError::Timeout`. Unlike the connect and read timeouts,
+    /// this also bounds transfers the Xet data crate makes over its own
+    /// connections. To limit a whole call instead, pass it a
+    /// `CancellationToken` or `DownloadHandle` created with a timeout.
+    pub operation_timeout_secs: Option<u64>,
+    /// Whether multi-file downloads skip files in repositories the client can't
+    /// read, rather than failing.
+    ///
+    /// Fine-grained tokens may be granted some repositories and not others. With
+    /// this set, a batch spanning several repositories downloads what it can:
+    /// each skipped repository is reported with a `RepoSkipped` event, and
+    /// batch results mark its files `Unauthorized`.
+    pub skip_inaccessible_repos: bool,
+    /// Network faults to simulate, in the order they're checked.
+    ///
+    /// Faults apply to the requests the client makes itself: Hub API calls,
+    /// HTTP downloads, and reconstructions resumed from a partial file. Transfers
+    /// the Xet data crate makes over its own connections aren't affected.
+    #[cfg(feature = "fault-injection")]
+    pub simulated_faults: Option<Vec<SimulatedFault>>,
+}
+
+/// A host-provided policy consulted before each file download begins.
+///
+/// Implement this to veto files you don't expect in repositories you don't
+/// fully trust, such as executables or unexpectedly large files.
+pub trait DownloadPolicy: Send + Sync {
+    /// Returns whether the file may be downloaded.
+    ///
+    /// This is called synchronously on the thread performing the download,
+    /// before any transfer begins, so it should return promptly. Batch
+    /// downloads call it from several threads at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file within the repository.
+    /// * `size` - The size of the file in bytes, or `None` if the server
+    ///   didn't report one before sending it.
+    /// * `content_type` - The content type reported by the server, if any.
+    fn allow_download(&self, path: String, size: Option<u64>, content_type: Option<String>)
+        -> bool;
+}
+
 /// A client for interacting with Xet repositories.
 ///
 /// The `XetClient` provides methods to download files, list repository contents,
@@ -146,11 +1149,146 @@ impl From<utils::errors::AuthError> for XetError {
 /// You can create a client with or without authentication. For public repositories,
 /// authentication is optional. For private repositories or upload operations,
 /// you need to provide a Hugging Face authentication token.
+///
+/// # Thread Safety
+///
+/// A client may be shared and called from any thread or dispatch queue. Methods
+/// block the calling thread until they complete, so avoid calling them from the
+/// main thread. Progress callbacks and events are delivered on a dedicated
+/// callback thread, never on a runtime thread, so they may call back into the
+/// client. Calling a method from within an asynchronous runtime fails with
+/// `XetError::ReentrantCall` rather than deadlocking.
 pub struct XetClient {
-    runtime: tokio::runtime::Runtime,
-    http_client: reqwest::Client,
+    resources: Arc<IdleSlot<Arc<ClientResources>>>,
+    callbacks: Arc<CallbackDispatcher>,
     endpoint: String,
     token: Option<String>,
+    quiet: AtomicBool,
+    config: XetClientConfig,
+    download_policy: RwLock<Option<Arc<dyn DownloadPolicy>>>,
+    event_listener: RwLock<Option<Arc<dyn XetEventListener>>>,
+    rate_limit: RwLock<Option<Arc<RateLimitStatus>>>,
+    /// The Unix time before which the Hub last asked not to be retried.
+    retry_at: RwLock<Option<u64>>,
+    cache: CacheLayout,
+    managed: ManagedLibrary,
+    hub_cache: HubCache,
+    cache_maintenance: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    throttle: Arc<Throttle>,
+    scheduler: Scheduler,
+    faults: Faults,
+    /// Logs the client's requests while `record_session` is in effect.
+    recorder: Arc<SessionRecorder>,
+    /// Stops transfers once `prepare_for_termination` is called.
+    termination: Arc<Termination>,
+    clock: Clock,
+    /// The CDN URLs resolve requests redirected to, reused while they're valid.
+    redirects: Arc<RedirectCache>,
+    usage: Arc<UsageLedger>,
+    /// The label transfers are attributed to when their call doesn't name one.
+    usage_label: RwLock<Option<String>>,
+    retry_policy: RetryPolicy,
+    tls: TlsOptions,
+    /// Sheds `resources` after `idle_timeout_secs`, if configured.
+    _idle_monitor: Option<IdleMonitor>,
+}
+
+/// A file a multi-file download will fetch, with its destination and revision resolved.
+struct DownloadTarget {
+    request: Arc<FileDownloadRequest>,
+    repo_info: HubRepoInfo,
+    destination: String,
+    /// The revision the file is fetched at, which is a commit when pinned.
+    revision: String,
+    /// Whether the file is skipped because the client can't read its repository.
+    unreadable: bool,
+    /// The status the Hub's security scanner flagged the file with, if any.
+    security_status: Option<String>,
+}
+
+/// The metadata a file resolved to, or why it couldn't be resolved.
+type MetadataResult = Result<FileResolveMetadata, XetError>;
+
+/// A file's outcome in a batch, or `None` if it was skipped after another failed.
+type BatchOutcome = Option<Result<Arc<TransferReport>, XetError>>;
+
+/// A file's SHA-256 and the time spent hashing it, or `None` if it wasn't hashed.
+type Digest = Option<(String, Duration)>;
+
+/// The runtime and HTTP client, which are released together when the client is idle.
+///
+/// Pooled connections are driven by tasks on the runtime, so the HTTP client
+/// can't outlive the runtime it was first used on.
+struct ClientResources {
+    runtime: tokio::runtime::Runtime,
+    runtime_flavor: RuntimeFlavor,
+    http_client: reqwest::Client,
+    metadata_client: reqwest::Client,
+}
+
+impl ClientResources {
+    fn new(config: &XetClientConfig, tls: &TlsOptions) -> Result<Self, XetError> {
+        let mut builder = match config.runtime_flavor {
+            RuntimeFlavor::MultiThread => tokio::runtime::Builder::new_multi_thread(),
+            RuntimeFlavor::CurrentThread => tokio::runtime::Builder::new_current_thread(),
+        };
+        if let (RuntimeFlavor::MultiThread, Some(worker_threads)) =
+            (config.runtime_flavor, config.worker_threads)
+        {
+            builder.worker_threads(worker_threads as usize);
+        }
+        let runtime = builder
+            .enable_all()
+            .build()
+            .map_err(|e| XetError::IoError {
+                message: format!("Failed to create tokio runtime: {}", e),
+                cause: Some(ErrorCause::from(&e)),
+            })?;
+
+        let max_redirects = config
+            .max_redirects
+            .unwrap_or(xet_http::DEFAULT_MAX_REDIRECTS);
+        let client_error = |e: reqwest::Error| XetError::NetworkError {
+            message: format!("Failed to create HTTP client: {}", e),
+            cause: Some(ErrorCause::from(&e)),
+        };
+        let timeouts = xet_http::Timeouts::from_config(config);
+        let http_client =
+            xet_http::content_client(max_redirects, tls, timeouts).map_err(client_error)?;
+        let metadata_client = xet_http::metadata_client(tls, timeouts).map_err(client_error)?;
+
+        Ok(Self {
+            runtime,
+            runtime_flavor: config.runtime_flavor,
+            http_client,
+            metadata_client,
+        })
+    }
+
+    /// Runs `future` to completion on the runtime, blocking the calling thread.
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        match self.runtime_flavor {
+            // Blocking through the handle lets any number of threads wait on the
+            // shared runtime at once, with the work itself running on its workers.
+            RuntimeFlavor::MultiThread => self.runtime.handle().block_on(future),
+            // Only the runtime itself can drive I/O and timers on a current-thread
+            // runtime; concurrent callers take turns driving it.
+            RuntimeFlavor::CurrentThread => self.runtime.block_on(future),
+        }
+    }
+}
+
+/// Refuses to block a thread that is already driving an async runtime.
+///
+/// Blocking there would panic or deadlock, so the call fails instead. Detached
+/// calls run on the runtime's blocking pool, where blocking is allowed.
+fn check_not_in_runtime() -> Result<(), XetError> {
+    if tokio::runtime::Handle::try_current().is_ok() && xet_detached::current().is_none() {
+        return Err(XetError::ReentrantCall {
+            message: "Client methods cannot be called from within an async runtime".to_string(),
+        });
+    }
+    Ok(())
 }
 
 // Response types for HF Hub API
@@ -165,6 +1303,33 @@ struct TreeEntry {
     size: Option<u64>,
     #[serde(default)]
     lfs: Option<serde_json::Value>, // LFS pointer info
+    #[serde(default, rename = "xetHash")]
+    xet_hash: Option<String>,
+    #[serde(default, rename = "securityFileStatus")]
+    security_file_status: Option<serde_json::Value>, // Only present for expanded listings
+}
+
+/// The files found by listing a repository directory.
+#[derive(Default)]
+struct RepoListing {
+    files: Vec<TreeEntry>,
+    /// Directories the Hub couldn't list, whose files are missing from `files`.
+    unlisted: Vec<String>,
+}
+
+impl TreeEntry {
+    fn security_status(&self) -> Option<String> {
+        self.security_file_status
+            .as_ref()
+            .and_then(|status| status.get("status"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct RevisionResponse {
+    sha: String,
 }
 
 #[derive(serde::Deserialize)]
@@ -172,45 +1337,251 @@ struct TreeResponse {
     tree: Option<Vec<TreeEntry>>,
 }
 
-/// Information about a file stored in a Xet repository.
+/// Returns whether an error looks like the Hub refusing access.
 ///
-/// This type contains the hash and size of a file, which are used to
-/// identify and download files from Xet's content-addressable storage system.
-#[derive(Clone)]
-pub struct XetFileInfo {
-    inner: data::XetFileInfo,
-}
-
-impl XetFileInfo {
-    /// Creates a new file info instance.
-    ///
-    /// # Arguments
-    ///
-    /// * `hash` - The content hash of the file, typically a SHA-256 hash.
-    /// * `file_size` - The size of the file in bytes.
-    pub fn new(hash: String, file_size: u64) -> Self {
-        Self {
-            inner: data::XetFileInfo::new(hash, file_size),
+/// Several request paths flatten HTTP failures into messages, so those are
+/// recognized by the status they mention.
+fn is_auth_failure(error: &XetError) -> bool {
+    match error {
+        XetError::AuthError { .. } => true,
+        XetError::NetworkError { message, .. } | XetError::OperationFailed { message, .. } => {
+            message.contains("401 Unauthorized") || message.contains("403 Forbidden")
         }
+        _ => false,
     }
+}
 
-    /// Returns the content hash of the file.
-    ///
-    /// This hash uniquely identifies the file's content and is used to
-    /// retrieve the file from Xet's storage system.
-    pub fn hash(&self) -> String {
-        self.inner.hash().to_string()
-    }
+/// Returns the requests whose repository path `filter` includes.
+fn filter_requests(
+    requests: Vec<Arc<FileDownloadRequest>>,
+    filter: &PathFilter,
+) -> Vec<Arc<FileDownloadRequest>> {
+    requests
+        .into_iter()
+        .filter(|request| filter.includes(&request.path()))
+        .collect()
+}
 
-    /// Returns the size of the file in bytes.
-    pub fn file_size(&self) -> u64 {
-        self.inner.file_size()
+/// Takes the failure a batch reports out of `results`, along with its index.
+///
+/// That is the first failure in request order, preferring one that caused
+/// cancellations over the cancellations themselves.
+fn take_first_failure(results: &mut [BatchOutcome]) -> Option<(usize, XetError)> {
+    let failed = |cancelled: bool| {
+        results.iter().position(|result| match result {
+            Some(Err(error)) => matches!(error, XetError::Cancelled { .. }) == cancelled,
+            _ => false,
+        })
+    };
+    let index = failed(false).or_else(|| failed(true))?;
+    match results[index].take() {
+        Some(Err(error)) => Some((index, error)),
+        _ => None,
     }
 }
 
-impl From<data::XetFileInfo> for XetFileInfo {
-    fn from(inner: data::XetFileInfo) -> Self {
-        Self { inner }
+/// Undoes a failed multi-file operation, first discarding the partial downloads of `failed`.
+fn roll_back_batch(
+    journal: OperationJournal,
+    error: XetError,
+    failed: &[&DownloadTarget],
+) -> XetError {
+    for target in failed {
+        let _ = PartialDownload::new(Path::new(&target.destination)).discard();
+    }
+    match journal.roll_back() {
+        Ok(()) => error,
+        Err(e) => XetError::IoError {
+            message: format!("Failed to roll back after \"{}\": {}", error, e),
+            cause: Some(ErrorCause::from(&e)),
+        },
+    }
+}
+
+/// Returns whether `error` reports a 5xx response.
+fn is_server_error(error: &XetError) -> bool {
+    matches!(
+        error.cause(),
+        Some(ErrorCause::Http {
+            status: Some(500..=599),
+            ..
+        })
+    )
+}
+
+/// Returns whether `error` reports a 404 response.
+#[cfg(feature = "upload")]
+fn is_not_found(error: &XetError) -> bool {
+    matches!(
+        error.cause(),
+        Some(ErrorCause::Http {
+            status: Some(404),
+            ..
+        })
+    )
+}
+
+/// Rejects a download throughput limit of zero, which would stall every transfer.
+fn check_download_limit(bytes_per_second: Option<u64>) -> Result<(), XetError> {
+    if bytes_per_second == Some(0) {
+        return Err(XetError::InvalidInput {
+            message: "Download limit must be at least one byte per second".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that an endpoint override has a valid pattern and an HTTP(S) endpoint.
+fn check_endpoint_override(endpoint_override: &EndpointOverride) -> Result<(), XetError> {
+    xet_glob::validate(&endpoint_override.repo_pattern)?;
+    check_endpoint(&endpoint_override.endpoint)
+}
+
+/// Checks that an endpoint is an HTTP(S) URL.
+fn check_endpoint(endpoint: &str) -> Result<(), XetError> {
+    match Url::parse(endpoint) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(()),
+        _ => Err(XetError::InvalidInput {
+            message: format!("Invalid endpoint: {}", endpoint),
+        }),
+    }
+}
+
+/// Rejects staged files that changed after they were staged.
+#[cfg(feature = "upload")]
+fn check_unchanged(files: &[StagedFile]) -> Result<(), XetError> {
+    match files.iter().find(|file| !file.is_unchanged()) {
+        Some(file) => Err(XetError::InvalidInput {
+            message: format!(
+                "{} changed after it was staged; stage it again to publish it",
+                file.local_path.display()
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns whether `error` means the Hub couldn't answer, rather than that it refused.
+fn is_hub_unreachable(error: &XetError) -> bool {
+    if matches!(error, XetError::ServiceUnavailable { .. }) || is_server_error(error) {
+        return true;
+    }
+    matches!(
+        error.cause(),
+        Some(ErrorCause::Http {
+            kind: HttpErrorKind::Dns | HttpErrorKind::Connect | HttpErrorKind::Timeout,
+            ..
+        }) | Some(ErrorCause::Http {
+            status: Some(429),
+            ..
+        })
+    )
+}
+
+/// Returns whether `error` means a tree listing was too large for the Hub to serve.
+///
+/// The Hub rejects some listings with 413, and cuts others off partway
+/// through or takes too long to produce them.
+fn is_oversized_listing(error: &XetError) -> bool {
+    matches!(
+        error.cause(),
+        Some(ErrorCause::Http {
+            kind: HttpErrorKind::Body | HttpErrorKind::Timeout,
+            ..
+        }) | Some(ErrorCause::Http {
+            status: Some(413),
+            ..
+        })
+    )
+}
+
+fn managed_library_error(e: std::io::Error) -> XetError {
+    XetError::CacheError {
+        message: format!("Failed to access managed library: {}", e),
+    }
+}
+
+fn hub_cache_error(e: std::io::Error) -> XetError {
+    XetError::CacheError {
+        message: format!("Failed to access downloaded files: {}", e),
+    }
+}
+
+/// Joins a repository path onto a local directory, rejecting paths that escape it.
+fn repo_file_path(dir: &Path, path: &str) -> Result<String, XetError> {
+    let relative = Path::new(path);
+    if !relative
+        .components()
+        .all(|component| matches!(component, std::path::Component::Normal(_)))
+    {
+        return Err(XetError::InvalidInput {
+            message: format!("Repository path escapes its local directory: {}", path),
+        });
+    }
+    Ok(dir.join(relative).to_string_lossy().to_string())
+}
+
+/// Returns whether a repository file is a zero-byte file or a Git placeholder.
+fn is_placeholder_file(path: &str, size: Option<u64>) -> bool {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    size == Some(0) || matches!(name, ".gitattributes" | ".gitkeep")
+}
+
+/// Creates or truncates a zero-byte file, creating its parent directories.
+fn create_empty_file(path: &Path) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::File::create(path).map(drop)
+}
+
+/// Parses a tree listing, which is either wrapped in a `tree` field or a bare array.
+fn parse_tree_entries(body: &str) -> Result<Vec<TreeEntry>, XetError> {
+    match serde_json::from_str::<TreeResponse>(body) {
+        Ok(tree_resp) => Ok(tree_resp.tree.unwrap_or_default()),
+        Err(_) => Ok(serde_json::from_str::<Vec<TreeEntry>>(body)?),
+    }
+}
+
+/// Information about a file stored in a Xet repository.
+///
+/// This type contains the hash and size of a file, which are used to
+/// identify and download files from Xet's content-addressable storage system.
+#[derive(Clone)]
+pub struct XetFileInfo {
+    inner: data::XetFileInfo,
+}
+
+impl XetFileInfo {
+    /// Creates a new file info instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - The content hash of the file, typically a SHA-256 hash.
+    /// * `file_size` - The size of the file in bytes.
+    pub fn new(hash: String, file_size: u64) -> Self {
+        Self {
+            inner: data::XetFileInfo::new(hash, file_size),
+        }
+    }
+
+    /// Returns the content hash of the file.
+    ///
+    /// This hash uniquely identifies the file's content and is used to
+    /// retrieve the file from Xet's storage system.
+    pub fn hash(&self) -> String {
+        self.inner.hash().to_string()
+    }
+
+    /// Returns the size of the file in bytes.
+    pub fn file_size(&self) -> u64 {
+        self.inner.file_size()
+    }
+}
+
+impl From<data::XetFileInfo> for XetFileInfo {
+    fn from(inner: data::XetFileInfo) -> Self {
+        Self { inner }
     }
 }
 
@@ -220,6 +1591,40 @@ impl From<XetFileInfo> for data::XetFileInfo {
     }
 }
 
+/// Where the content of a Xet-backed file lives, and how to get access to it.
+///
+/// Callers that schedule their own transfers can keep these details and pass
+/// the hash to `download_by_hash`, refreshing CAS tokens from the refresh route
+/// as they expire.
+pub struct XetPointerDetails {
+    xet_hash: String,
+    size: u64,
+    refresh_route: String,
+    cas_endpoint: String,
+}
+
+impl XetPointerDetails {
+    /// Returns the Xet hash of the file's content.
+    pub fn xet_hash(&self) -> String {
+        self.xet_hash.clone()
+    }
+
+    /// Returns the size of the file in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the Hub URL that issues CAS read tokens for the file.
+    pub fn refresh_route(&self) -> String {
+        self.refresh_route.clone()
+    }
+
+    /// Returns the URL of the CAS server that stores the file's content.
+    pub fn cas_endpoint(&self) -> String {
+        self.cas_endpoint.clone()
+    }
+}
+
 /// Metadata about a file or directory entry in a repository.
 ///
 /// This type provides information about entries in a repository's file tree,
@@ -230,6 +1635,8 @@ pub struct FileMetadata {
     size: Option<u64>,
     hash: Option<String>,
     oid: Option<String>,
+    xet_hash: Option<String>,
+    security_status: Option<String>,
 }
 
 impl FileMetadata {
@@ -269,6 +1676,21 @@ impl FileMetadata {
     pub fn oid(&self) -> Option<String> {
         self.oid.clone()
     }
+
+    /// Returns the Xet hash of the file, if it is stored with Xet.
+    pub fn xet_hash(&self) -> Option<String> {
+        self.xet_hash.clone()
+    }
+
+    /// Returns the Hub's security scan status for the file, if available.
+    ///
+    /// Common values are `"safe"`, `"unsafe"`, `"suspicious"`, and `"unscanned"`.
+    /// This value is present in results from `list_files_with_metadata`,
+    /// `list_files_recursive`, `tree_iterator`, and `get_paths_info` when the
+    /// Hub has scan results for the file.
+    pub fn security_status(&self) -> Option<String> {
+        self.security_status.clone()
+    }
 }
 
 impl From<TreeEntry> for FileMetadata {
@@ -280,6 +1702,7 @@ impl From<TreeEntry> for FileMetadata {
             .and_then(|lfs| lfs.get("oid"))
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
+        let security_status = entry.security_status();
 
         Self {
             path: entry.path,
@@ -287,7 +1710,159 @@ impl From<TreeEntry> for FileMetadata {
             size: entry.size,
             hash,
             oid: entry.oid,
+            xet_hash: entry.xet_hash,
+            security_status,
+        }
+    }
+}
+
+/// A lazily paged listing of a repository directory.
+///
+/// Each call to `next_page` fetches one page from the Hub, so very large
+/// listings never have to be held in memory or cross the FFI boundary at once.
+/// Calls from multiple threads are serialized and each receives a distinct page.
+pub struct TreeIterator {
+    client: Arc<XetClient>,
+    next_url: Mutex<Option<String>>,
+}
+
+impl TreeIterator {
+    /// Fetches the next page of entries, or returns `None` once the listing is exhausted.
+    ///
+    /// If fetching a page fails, the same page is requested again on the next call.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::NetworkError` if the page cannot be retrieved.
+    pub fn next_page(&self) -> Result<Option<Vec<Arc<FileMetadata>>>, XetError> {
+        let mut next_url = self.next_url.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(url) = next_url.clone() else {
+            return Ok(None);
+        };
+
+        let (entries, following) = self.client.fetch_tree_page(&url)?;
+        *next_url = following;
+        Ok(Some(
+            entries
+                .into_iter()
+                .map(|entry| Arc::new(FileMetadata::from(entry)))
+                .collect(),
+        ))
+    }
+
+    /// Returns whether every page has been fetched.
+    pub fn is_finished(&self) -> bool {
+        self.next_url
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_none()
+    }
+}
+
+/// The files under a repository directory, and whether any could be missed.
+///
+/// The Hub can fail to list very large directories. Those are listed one
+/// level at a time instead, and any still too large are reported by
+/// `unlisted_paths` rather than silently left out.
+pub struct TreeListing {
+    files: Vec<Arc<FileMetadata>>,
+    unlisted_paths: Vec<String>,
+}
+
+impl TreeListing {
+    /// Returns the files found, with paths relative to the repository root.
+    pub fn files(&self) -> Vec<Arc<FileMetadata>> {
+        self.files.clone()
+    }
+
+    /// Returns whether every directory was listed, so no file is missing.
+    pub fn is_complete(&self) -> bool {
+        self.unlisted_paths.is_empty()
+    }
+
+    /// Returns the directories that couldn't be listed, whose files are missing from `files`.
+    pub fn unlisted_paths(&self) -> Vec<String> {
+        self.unlisted_paths.clone()
+    }
+}
+
+/// A file read sequentially from a repository without being written to disk.
+///
+/// Chunks are fetched with range requests, up to the client's
+/// `read_ahead_chunks` ahead of the reader, so only that many chunks are ever
+/// held in memory. An open stream keeps the client's runtime alive even if the
+/// client sheds its resources while idle. Calls from multiple threads are
+/// serialized and each receives distinct bytes.
+pub struct FileStream {
+    resources: Arc<ClientResources>,
+    size: u64,
+    state: Mutex<FileStreamState>,
+}
+
+struct FileStreamState {
+    reader: ReadAhead,
+    /// The chunk being read from, and how much of it has been returned.
+    chunk: Vec<u8>,
+    chunk_offset: usize,
+    position: u64,
+}
+
+impl FileStream {
+    /// Returns up to `max_bytes` of the file's next bytes, or an empty array at the end of the file.
+    ///
+    /// Fewer than `max_bytes` may be returned before the end, so callers should
+    /// read until they receive an empty array rather than until a short read.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `max_bytes` is zero, `XetError::NetworkError`
+    /// if a chunk cannot be fetched, or `XetError::ReentrantCall` if called from
+    /// within an async runtime. A failed stream can't be read further.
+    pub fn read_next(&self, max_bytes: u32) -> Result<Vec<u8>, XetError> {
+        if max_bytes == 0 {
+            return Err(XetError::InvalidInput {
+                message: "Read size must be at least one byte".to_string(),
+            });
+        }
+        check_not_in_runtime()?;
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.chunk_offset == state.chunk.len() {
+            if state.position == self.size {
+                return Ok(Vec::new());
+            }
+            let chunk = self
+                .resources
+                .block_on(state.reader.next_chunk())
+                .unwrap_or_else(|| {
+                    Err(XetError::NetworkError {
+                        message: "The stream ended before the end of the file".to_string(),
+                        cause: None,
+                    })
+                })?;
+            state.chunk = chunk;
+            state.chunk_offset = 0;
         }
+
+        let start = state.chunk_offset;
+        let end = state.chunk.len().min(start + max_bytes as usize);
+        let bytes = state.chunk[start..end].to_vec();
+        state.chunk_offset = end;
+        state.position += bytes.len() as u64;
+        Ok(bytes)
+    }
+
+    /// Returns the size of the file in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the number of bytes read so far.
+    pub fn position(&self) -> u64 {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .position
     }
 }
 
@@ -297,6 +1872,8 @@ impl From<TreeEntry> for FileMetadata {
 /// interact with Xet's CAS system for downloading or uploading files.
 pub struct CasJwtInfo {
     inner: hub_client::CasJWTInfo,
+    endpoint_latencies: Vec<CasEndpointLatency>,
+    refresh_route: Option<String>,
 }
 
 impl Clone for CasJwtInfo {
@@ -307,6 +1884,8 @@ impl Clone for CasJwtInfo {
                 exp: self.inner.exp,
                 access_token: self.inner.access_token.clone(),
             },
+            endpoint_latencies: self.endpoint_latencies.clone(),
+            refresh_route: self.refresh_route.clone(),
         }
     }
 }
@@ -334,18 +1913,91 @@ impl CasJwtInfo {
     pub fn exp(&self) -> u64 {
         self.inner.exp
     }
+
+    /// Returns the round-trip times measured to each CAS endpoint the Hub offered.
+    ///
+    /// `cas_url` is the endpoint that was chosen. The list is empty if no
+    /// endpoints were measured.
+    pub fn endpoint_latencies(&self) -> Vec<CasEndpointLatency> {
+        self.endpoint_latencies.clone()
+    }
+
+    pub(crate) fn with_endpoint_latencies(mut self, latencies: Vec<CasEndpointLatency>) -> Self {
+        self.endpoint_latencies = latencies;
+        self
+    }
+
+    /// Records the Hub route that issues a replacement for this token.
+    pub(crate) fn with_refresh_route(mut self, refresh_route: String) -> Self {
+        self.refresh_route = Some(refresh_route);
+        self
+    }
+
+    /// Decodes the claims in the access token, without verifying its signature.
+    ///
+    /// Use this to see what a token grants, such as why a token requested for
+    /// downloading is rejected for an upload, or to show how long it remains
+    /// valid. Claims the token doesn't carry are `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if the access token isn't a JWT with a
+    /// JSON object as its payload.
+    pub fn claims(&self) -> Result<CasTokenClaims, XetError> {
+        xet_jwt::decode_claims(&self.inner.access_token)
+    }
+
+    /// Returns whether the token has expired at Unix time `now`, or will
+    /// within `CAS_TOKEN_EXPIRY_MARGIN`.
+    pub(crate) fn is_expiring(&self, now: u64) -> bool {
+        self.inner.exp <= now.saturating_add(CAS_TOKEN_EXPIRY_MARGIN.as_secs())
+    }
 }
 
 impl From<hub_client::CasJWTInfo> for CasJwtInfo {
     fn from(inner: hub_client::CasJWTInfo) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            endpoint_latencies: Vec::new(),
+            refresh_route: None,
+        }
     }
 }
 
+/// The measured round-trip time to a CAS endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CasEndpointLatency {
+    /// The URL of the endpoint.
+    pub endpoint: String,
+    /// The round-trip time in milliseconds, or `None` if the endpoint couldn't be reached.
+    pub rtt_ms: Option<u64>,
+}
+
+/// The claims of a CAS access token, as decoded by `CasJwtInfo::claims`.
+///
+/// The claims are read without verifying the token's signature, so they
+/// describe the token but aren't proof of what the CAS server will accept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CasTokenClaims {
+    /// What the token grants, such as `"read"` or `"write"`, if it says.
+    pub scope: Option<String>,
+    /// The repository the token was issued for, if it says.
+    pub repo: Option<String>,
+    /// The Unix time the token expires at, if it says.
+    pub exp: Option<u64>,
+    /// The Unix time the token was issued at, if it says.
+    pub issued_at: Option<u64>,
+    /// The whole payload as JSON, including claims not listed above.
+    pub payload: String,
+}
+
 /// Progress information for file download or upload operations.
 ///
 /// This type tracks the progress of data transfer operations, including
 /// both the total amount of data to transfer and the amount already completed.
+/// For uploads, the processed bytes count the content chunked and hashed,
+/// and the transfer bytes what's sent after deduplication, which is often
+/// far less.
 pub struct ProgressUpdate {
     total_bytes: u64,
     total_bytes_completed: u64,
@@ -363,7 +2015,7 @@ impl ProgressUpdate {
 
     /// Returns the number of bytes that have been processed.
     ///
-    /// This value increases as files are downloaded or uploaded.
+    /// This value increases as files are downloaded, or as uploads are chunked and hashed.
     pub fn total_bytes_completed(&self) -> u64 {
         self.total_bytes_completed
     }
@@ -381,6 +2033,16 @@ impl ProgressUpdate {
     pub fn total_transfer_bytes_completed(&self) -> u64 {
         self.total_transfer_bytes_completed
     }
+
+    /// Creates an update for a plain HTTP transfer, where every byte crosses the network.
+    pub(crate) fn for_transfer(completed: u64, total: u64) -> Self {
+        Self {
+            total_bytes: total,
+            total_bytes_completed: completed,
+            total_transfer_bytes: total,
+            total_transfer_bytes_completed: completed,
+        }
+    }
 }
 
 impl From<progress_tracking::ProgressUpdate> for ProgressUpdate {
@@ -398,11 +2060,16 @@ impl From<progress_tracking::ProgressUpdate> for ProgressUpdate {
 ///
 /// This type encapsulates the parameters needed to download a single file,
 /// including the repository identifier, file path, destination, and optional revision.
+///
+/// Within a multi-file download, requests with higher priorities start first,
+/// so small files an app needs right away, such as `config.json` or a
+/// tokenizer, needn't wait behind large weight shards.
 pub struct FileDownloadRequest {
     repo: String,
     path: String,
     destination: String,
     revision: Option<String>,
+    priority: AtomicI32,
 }
 
 impl FileDownloadRequest {
@@ -413,13 +2080,34 @@ impl FileDownloadRequest {
     /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
     /// * `path` - The path of the file within the repository.
     /// * `destination` - The local file path where the downloaded file should be saved.
-    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to `"main"`.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
     pub fn new(repo: String, path: String, destination: String, revision: Option<String>) -> Self {
+        Self::with_priority(repo, path, destination, revision, 0)
+    }
+
+    /// Creates a new file download request with a priority.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository.
+    /// * `destination` - The local file path where the downloaded file should be saved.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    /// * `priority` - How soon the file starts within a multi-file download. Higher priorities
+    ///   start first, and requests created with `new` have a priority of 0.
+    pub fn with_priority(
+        repo: String,
+        path: String,
+        destination: String,
+        revision: Option<String>,
+        priority: i32,
+    ) -> Self {
         Self {
             repo,
             path,
             destination,
             revision,
+            priority: AtomicI32::new(priority),
         }
     }
 
@@ -447,924 +2135,7137 @@ impl FileDownloadRequest {
 
     /// Returns the Git revision, branch, or tag name.
     ///
-    /// If `None`, the default branch (typically `"main"`) is used.
+    /// If `None`, the client's default revision (`"main"` unless configured) is used.
     pub fn revision(&self) -> Option<String> {
         self.revision.clone()
     }
-}
-
-/// Information about a Hugging Face repository.
-///
-/// This type contains the repository type and full name, which uniquely
-/// identify a repository on Hugging Face Hub.
-pub struct RepoInfo {
-    repo_type: String,
-    full_name: String,
-}
 
-impl RepoInfo {
-    /// Returns the type of the repository.
-    ///
-    /// Common values are `"model"`, `"dataset"`, and `"space"`.
-    pub fn repo_type(&self) -> String {
-        self.repo_type.clone()
+    /// Returns how soon the file starts within a multi-file download.
+    pub fn priority(&self) -> i32 {
+        self.priority.load(Ordering::Relaxed)
     }
 
-    /// Returns the full name of the repository.
+    /// Changes how soon the file starts within a multi-file download.
     ///
-    /// This is in the format `"owner/repo"` and uniquely identifies
-    /// the repository within its type.
-    pub fn full_name(&self) -> String {
-        self.full_name.clone()
+    /// This can be called while a download of the request is in progress.
+    /// It takes effect if the file hasn't started yet, moving it ahead of or
+    /// behind the other files still waiting.
+    pub fn set_priority(&self, priority: i32) {
+        self.priority.store(priority, Ordering::Relaxed);
     }
 }
 
-impl From<hub_client::RepoInfo> for RepoInfo {
-    fn from(info: hub_client::RepoInfo) -> Self {
-        Self {
-            repo_type: info.repo_type.as_str().to_string(),
-            full_name: info.full_name,
-        }
-    }
+/// What became of one file in a batch download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileDownloadStatus {
+    /// The file is at its destination, whether downloaded or already present.
+    Downloaded,
+    /// The file failed to download.
+    Failed,
+    /// The file wasn't attempted, because the batch's patterns excluded it or
+    /// the batch stopped at an earlier failure.
+    Skipped,
+    /// The file wasn't attempted, because the client can't read its repository.
+    ///
+    /// Only reported when the client's `skip_inaccessible_repos` is set.
+    Unauthorized,
 }
 
-/// Statistics about the local Xet cache.
-///
-/// This type provides information about the cache's size and the number
-/// of cached files.
-pub struct CacheStats {
-    total_size_bytes: u64,
-    file_count: u64,
+/// The outcome of one file in a batch download.
+pub struct FileDownloadResult {
+    request: Arc<FileDownloadRequest>,
+    destination: String,
+    status: FileDownloadStatus,
+    report: Option<Arc<TransferReport>>,
+    error: Option<XetError>,
 }
 
-impl CacheStats {
-    /// Returns the total size of the cache in bytes.
+impl FileDownloadResult {
+    /// Returns the request this is the outcome of.
+    pub fn request(&self) -> Arc<FileDownloadRequest> {
+        self.request.clone()
+    }
+
+    /// Returns what became of the file.
+    pub fn status(&self) -> FileDownloadStatus {
+        self.status
+    }
+
+    /// Returns the local path the file was written to.
     ///
-    /// This includes all cached files and their metadata.
-    pub fn total_size_bytes(&self) -> u64 {
-        self.total_size_bytes
+    /// This differs from the request's destination if the file was renamed to
+    /// avoid a collision.
+    pub fn destination(&self) -> String {
+        self.destination.clone()
     }
 
-    /// Returns the number of files in the cache.
-    pub fn file_count(&self) -> u64 {
-        self.file_count
+    /// Returns the transfer report, if the file was downloaded.
+    pub fn report(&self) -> Option<Arc<TransferReport>> {
+        self.report.clone()
+    }
+
+    /// Returns the stable code of the error the file failed with, if it failed.
+    pub fn error_code(&self) -> Option<String> {
+        self.error.as_ref().map(|error| error.code().to_string())
+    }
+
+    /// Returns a description of the error the file failed with, if it failed.
+    pub fn error_message(&self) -> Option<String> {
+        self.error.as_ref().map(ToString::to_string)
     }
 }
 
-// Progress callback support can be added later if needed
-// For now, progress tracking is handled internally by the data crate
+/// The outcome of every request in a batch download, in request order.
+pub struct BatchDownloadResult {
+    results: Vec<Arc<FileDownloadResult>>,
+}
 
-/// Checks if pointer file detection should be attempted based on file extension.
-///
-/// Returns `false` for known binary file extensions like .safetensors, .bin, .pt, etc.
-/// to avoid unnecessary UTF-8 parsing and improve performance.
-fn should_try_pointer_detection(path: &str) -> bool {
-    let ext = Path::new(path)
-        .extension()
-        .and_then(|s| s.to_str())
-        .unwrap_or("")
-        .to_ascii_lowercase();
-    
-    !matches!(
-        ext.as_str(),
-        "safetensors" | "bin" | "pt" | "onnx" | "tflite"
-        | "tar" | "gz" | "zip" | "xz" | "zst" | "bz2" 
-        | "npy" | "npz" | "h5" | "ckpt" | "pth"
-    )
+impl BatchDownloadResult {
+    /// Returns the outcome of each request, in the order they were given.
+    pub fn results(&self) -> Vec<Arc<FileDownloadResult>> {
+        self.results.clone()
+    }
+
+    /// Returns the local paths of the files that are at their destinations.
+    pub fn downloaded_paths(&self) -> Vec<String> {
+        self.with_status(FileDownloadStatus::Downloaded)
+            .map(|result| result.destination())
+            .collect()
+    }
+
+    /// Returns the number of requests that failed.
+    pub fn failed_count(&self) -> u32 {
+        self.with_status(FileDownloadStatus::Failed).count() as u32
+    }
+
+    /// Returns the number of requests that weren't attempted.
+    pub fn skipped_count(&self) -> u32 {
+        self.with_status(FileDownloadStatus::Skipped).count() as u32
+    }
+
+    /// Returns the number of requests skipped because the client can't read their repositories.
+    pub fn unauthorized_count(&self) -> u32 {
+        self.with_status(FileDownloadStatus::Unauthorized).count() as u32
+    }
+
+    fn with_status(
+        &self,
+        status: FileDownloadStatus,
+    ) -> impl Iterator<Item = &Arc<FileDownloadResult>> {
+        self.results
+            .iter()
+            .filter(move |result| result.status == status)
+    }
+}
+
+/// The transfer path that produced a downloaded file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadSource {
+    /// Reconstructed from Xet content-addressable storage.
+    Xet,
+    /// Fetched from the resolved download URL.
+    Http,
+    /// Fetched from the Hub's resolve endpoints without metadata.
+    Legacy,
+    /// Not fetched, because the destination already held the resolved content.
+    Skipped,
+}
+
+/// The format of a repository archive downloaded with `download_repo_archive`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// A gzip-compressed tarball.
+    TarGz,
+    /// A zip archive.
+    Zip,
+}
+
+impl ArchiveFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+}
+
+/// Whether a downloaded file was checked against its expected hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationStatus {
+    /// Verification was not enabled for the client.
+    NotRequested,
+    /// The Hub didn't report a SHA-256 for the file, so it couldn't be checked.
+    Unavailable,
+    /// The file's SHA-256 matched the one reported by the Hub.
+    Verified,
+}
+
+/// A summary of a completed file download.
+///
+/// Verification time is reported separately from the overall elapsed time.
+/// Because hashing overlaps the transfer, it is usually a small fraction of it.
+pub struct TransferReport {
+    source: DownloadSource,
+    bytes: u64,
+    elapsed: Duration,
+    verification: VerificationStatus,
+    verification_elapsed: Duration,
+    cas_endpoint: Option<String>,
+    cas_endpoint_latencies: Vec<CasEndpointLatency>,
+    served_stale: bool,
+}
+
+impl TransferReport {
+    /// Returns the transfer path that produced the file.
+    pub fn source(&self) -> DownloadSource {
+        self.source
+    }
+
+    /// Returns the size of the downloaded file in bytes.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Returns the wall-clock time of the download in milliseconds, including verification.
+    pub fn elapsed_ms(&self) -> u64 {
+        self.elapsed.as_millis() as u64
+    }
+
+    /// Returns whether the file was verified.
+    pub fn verification(&self) -> VerificationStatus {
+        self.verification
+    }
+
+    /// Returns the time spent hashing the file in milliseconds.
+    pub fn verification_ms(&self) -> u64 {
+        self.verification_elapsed.as_millis() as u64
+    }
+
+    /// Returns the CAS endpoint the file was reconstructed from, if it came from Xet storage.
+    pub fn cas_endpoint(&self) -> Option<String> {
+        self.cas_endpoint.clone()
+    }
+
+    /// Returns the round-trip times measured to each CAS endpoint when choosing one.
+    pub fn cas_endpoint_latencies(&self) -> Vec<CasEndpointLatency> {
+        self.cas_endpoint_latencies.clone()
+    }
+
+    /// Returns whether the file was resolved from a cached resolution because the Hub was unreachable.
+    pub fn served_stale(&self) -> bool {
+        self.served_stale
+    }
+}
+
+/// The Hub API rate limit as of the most recent response that reported one.
+///
+/// Batch tooling can use this to pace requests instead of discovering the
+/// limit through failures. Any value the Hub didn't report is `None`.
+pub struct RateLimitStatus {
+    pub(crate) limit: Option<u64>,
+    pub(crate) remaining: Option<u64>,
+    pub(crate) reset_at: Option<u64>,
+}
+
+impl RateLimitStatus {
+    /// Returns the number of requests allowed in the current window.
+    pub fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    /// Returns the number of requests left in the current window.
+    pub fn remaining(&self) -> Option<u64> {
+        self.remaining
+    }
+
+    /// Returns when the current window resets, as a Unix timestamp in seconds.
+    pub fn reset_at(&self) -> Option<u64> {
+        self.reset_at
+    }
+
+    /// Returns the number of seconds until the current window resets.
+    pub fn seconds_until_reset(&self) -> Option<u64> {
+        self.reset_at.map(|reset_at| reset_at.saturating_sub(unix_now()))
+    }
+}
+
+/// The estimated size of a set of files in a repository.
+pub struct RepoSize {
+    logical_bytes: u64,
+    estimated_disk_bytes: u64,
+    file_count: u64,
+}
+
+impl RepoSize {
+    /// Returns the combined size of the files in bytes.
+    pub fn logical_bytes(&self) -> u64 {
+        self.logical_bytes
+    }
+
+    /// Returns the estimated space the files take once downloaded, in bytes.
+    ///
+    /// Each file is rounded up to a whole number of 4 KiB filesystem blocks,
+    /// which dominates for repositories with many small files.
+    pub fn estimated_disk_bytes(&self) -> u64 {
+        self.estimated_disk_bytes
+    }
+
+    /// Returns the number of files.
+    pub fn file_count(&self) -> u64 {
+        self.file_count
+    }
+}
+
+/// A revision of a file that is available in the local cache.
+pub struct CachedRevision {
+    pub(crate) commit: String,
+    pub(crate) refs: Vec<String>,
+    pub(crate) local_path: String,
+    pub(crate) size: u64,
+}
+
+impl CachedRevision {
+    /// Returns the commit the file was downloaded at.
+    pub fn commit(&self) -> String {
+        self.commit.clone()
+    }
+
+    /// Returns the branches and tags last seen pointing at the commit, such as `"main"`.
+    pub fn refs(&self) -> Vec<String> {
+        self.refs.clone()
+    }
+
+    /// Returns the local path of the file at this revision.
+    pub fn local_path(&self) -> String {
+        self.local_path.clone()
+    }
+
+    /// Returns the size of the file in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// Information about a Hugging Face repository.
+///
+/// This type contains the repository type and full name, which uniquely
+/// identify a repository on Hugging Face Hub.
+pub struct RepoInfo {
+    repo_type: String,
+    full_name: String,
+}
+
+impl RepoInfo {
+    /// Returns the type of the repository.
+    ///
+    /// Common values are `"model"`, `"dataset"`, and `"space"`.
+    pub fn repo_type(&self) -> String {
+        self.repo_type.clone()
+    }
+
+    /// Returns the full name of the repository.
+    ///
+    /// This is in the format `"owner/repo"` and uniquely identifies
+    /// the repository within its type.
+    pub fn full_name(&self) -> String {
+        self.full_name.clone()
+    }
+}
+
+impl From<hub_client::RepoInfo> for RepoInfo {
+    fn from(info: hub_client::RepoInfo) -> Self {
+        Self {
+            repo_type: info.repo_type.as_str().to_string(),
+            full_name: info.full_name,
+        }
+    }
+}
+
+/// A region of the local Xet cache.
+///
+/// The cache holds data with very different refetch costs: content chunks are
+/// expensive to download again, while resolution metadata is cheap. Splitting
+/// statistics and eviction by area lets callers treat them differently.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CacheArea {
+    /// Content chunks and terms fetched from CAS by downloads.
+    Chunks,
+    /// Shard files describing the contents of xorbs.
+    Shards,
+    /// Resolution metadata and other small bookkeeping files.
+    Metadata,
+    /// Data staged for upload that has not been committed yet.
+    StagedUploads,
+    /// Files of models registered with `register_managed_model`, and their manifest.
+    ///
+    /// Cache maintenance never prunes this area.
+    ManagedModels,
+    /// Files downloaded with `download_to_cache`, stored once per etag.
+    ///
+    /// Cache maintenance never prunes this area.
+    DownloadedFiles,
+}
+
+/// Statistics about a single area of the local Xet cache.
+pub struct CacheAreaStats {
+    total_size_bytes: u64,
+    file_count: u64,
+}
+
+impl CacheAreaStats {
+    /// Returns the total size of the area in bytes.
+    pub fn total_size_bytes(&self) -> u64 {
+        self.total_size_bytes
+    }
+
+    /// Returns the number of files in the area.
+    pub fn file_count(&self) -> u64 {
+        self.file_count
+    }
+}
+
+impl From<xet_cache::AreaUsage> for CacheAreaStats {
+    fn from(usage: xet_cache::AreaUsage) -> Self {
+        Self {
+            total_size_bytes: usage.total_size_bytes,
+            file_count: usage.file_count,
+        }
+    }
+}
+
+/// Statistics about the local Xet cache.
+///
+/// This type provides information about the cache's size and the number
+/// of cached files, both in aggregate and broken down by `CacheArea`.
+pub struct CacheStats {
+    total_size_bytes: u64,
+    file_count: u64,
+    deduplicated_bytes: u64,
+    usage: xet_cache::CacheUsage,
+}
+
+impl CacheStats {
+    /// Returns the total size of the cache in bytes.
+    ///
+    /// This includes all cached files and their metadata.
+    pub fn total_size_bytes(&self) -> u64 {
+        self.total_size_bytes
+    }
+
+    /// Returns the number of files in the cache.
+    pub fn file_count(&self) -> u64 {
+        self.file_count
+    }
+
+    /// Returns the bytes saved by storing identical downloaded files once.
+    ///
+    /// Files downloaded with `download_to_cache` that share an etag, within a
+    /// repository or across repositories, are stored once and linked. This is
+    /// the additional space separate copies would take.
+    pub fn deduplicated_bytes(&self) -> u64 {
+        self.deduplicated_bytes
+    }
+
+    /// Returns the statistics for a single cache area.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - The cache area to report on.
+    pub fn area_stats(&self, area: CacheArea) -> Arc<CacheAreaStats> {
+        Arc::new(CacheAreaStats::from(self.usage.area(area)))
+    }
+}
+
+/// The transfers a client completed for one label, as reported by `get_usage_report`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LabelUsage {
+    /// The label the transfers were attributed to, or `None` for unlabeled transfers.
+    pub label: Option<String>,
+    /// The bytes of file content downloaded.
+    pub bytes_downloaded: u64,
+    /// The bytes of file content uploaded, before deduplication.
+    pub bytes_uploaded: u64,
+    /// The number of completed downloads and uploads.
+    pub transfer_count: u64,
+    /// The combined wall-clock time of the transfers in milliseconds.
+    pub elapsed_ms: u64,
+    /// The bytes exchanged with each host the client sent requests to, sorted by host.
+    pub hosts: Vec<HostUsage>,
+}
+
+/// The kind of service a host answering a client's requests provides.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EndpointKind {
+    /// The Hub's website and API, including resolve requests it answers itself.
+    Hub,
+    /// The Xet content-addressable storage API.
+    Cas,
+    /// A CDN or storage bucket serving signed URLs, such as redirect targets and xorb ranges.
+    Cdn,
+}
+
+/// The bytes a client exchanged with one host, as part of a `LabelUsage`.
+///
+/// Counts are approximate: responses count their declared length, even if
+/// their body is abandoned, and uploads count their content before
+/// deduplication, attributed to the CAS endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostUsage {
+    /// The host, with its port if it isn't the default.
+    pub host: String,
+    /// The kind of service the host provides.
+    pub kind: EndpointKind,
+    /// The bytes of request bodies and uploaded content sent to the host.
+    pub bytes_sent: u64,
+    /// The bytes of response bodies received from the host.
+    pub bytes_received: u64,
+    /// The number of requests the host answered.
+    pub request_count: u64,
+}
+
+impl From<xet_cache::CacheUsage> for CacheStats {
+    fn from(usage: xet_cache::CacheUsage) -> Self {
+        let areas = [
+            usage.chunks,
+            usage.shards,
+            usage.metadata,
+            usage.staged_uploads,
+            usage.managed_models,
+            usage.downloaded_files,
+        ];
+        Self {
+            total_size_bytes: areas.iter().map(|area| area.total_size_bytes).sum(),
+            file_count: areas.iter().map(|area| area.file_count).sum(),
+            deduplicated_bytes: 0,
+            usage,
+        }
+    }
+}
+
+/// Which commit of a managed model's repository to keep downloaded.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ManagedRevisionPolicy {
+    /// Follow a branch, offering an update whenever it advances.
+    Track { revision: String },
+    /// Stay at a fixed revision, such as a tag or commit hash.
+    Pin { revision: String },
+}
+
+impl ManagedRevisionPolicy {
+    fn revision(&self) -> &str {
+        match self {
+            Self::Track { revision } | Self::Pin { revision } => revision,
+        }
+    }
+}
+
+/// The state of a managed model's local files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ManagedModelStatus {
+    /// No update has started yet.
+    NotDownloaded,
+    /// An update started but didn't finish, so some files may be missing or from another commit.
+    PartiallyDownloaded,
+    /// The files are complete, but the revision has moved or the patterns have changed since.
+    UpdateAvailable,
+    /// The files are complete and current, as far as the client could determine.
+    UpToDate,
+}
+
+/// A model registered with the client's managed library.
+///
+/// Managed models are downloaded and kept up to date by `update_all_managed`.
+/// Their files live in the `ManagedModels` area of the cache, preserving
+/// paths within the repository.
+pub struct ManagedModel {
+    record: ManagedRecord,
+    latest_commit: Option<String>,
+    local_path: String,
+}
+
+impl ManagedModel {
+    /// Returns the repository identifier, such as `"models/owner/name"`.
+    pub fn repo(&self) -> String {
+        self.record.repo.clone()
+    }
+
+    /// Returns which revision of the repository is kept downloaded.
+    pub fn revision_policy(&self) -> ManagedRevisionPolicy {
+        self.record.revision_policy.clone()
+    }
+
+    /// Returns the glob patterns selecting which files are downloaded, or an empty array for all files.
+    pub fn patterns(&self) -> Vec<String> {
+        self.record.patterns.clone()
+    }
+
+    /// Returns the state of the model's local files.
+    pub fn status(&self) -> ManagedModelStatus {
+        let record = &self.record;
+        if record.target_commit.is_some() {
+            return ManagedModelStatus::PartiallyDownloaded;
+        }
+        let Some(installed) = &record.installed_commit else {
+            return ManagedModelStatus::NotDownloaded;
+        };
+        let moved = self
+            .latest_commit
+            .as_ref()
+            .is_some_and(|latest| latest != installed);
+        if moved || record.installed_patterns != record.patterns {
+            ManagedModelStatus::UpdateAvailable
+        } else {
+            ManagedModelStatus::UpToDate
+        }
+    }
+
+    /// Returns the commit of the last completed update.
+    pub fn installed_commit(&self) -> Option<String> {
+        self.record.installed_commit.clone()
+    }
+
+    /// Returns the commit the revision currently resolves to, or `None` if it couldn't be checked.
+    pub fn latest_commit(&self) -> Option<String> {
+        self.latest_commit.clone()
+    }
+
+    /// Returns the local directory holding the model's files.
+    pub fn local_path(&self) -> String {
+        self.local_path.clone()
+    }
+
+    /// Returns the number of files the model's current or last completed update covers.
+    pub fn file_count(&self) -> u64 {
+        match self.record.target_commit {
+            Some(_) => self.record.planned_files.len() as u64,
+            None => self.record.installed_files.len() as u64,
+        }
+    }
+
+    /// Returns the number of those files that are downloaded.
+    pub fn downloaded_file_count(&self) -> u64 {
+        match self.record.target_commit {
+            Some(_) => self.record.downloaded_files.len() as u64,
+            None => self.record.installed_files.len() as u64,
+        }
+    }
+
+    /// Returns why the most recent update failed, if it did.
+    pub fn last_error(&self) -> Option<String> {
+        self.record.last_error.clone()
+    }
+}
+
+/// A policy for periodic, low-priority maintenance of the local Xet cache.
+///
+/// Each pass removes truncated chunk and shard files, and files downloaded
+/// with `download_to_cache` whose contents no longer match their SHA-256, and
+/// then prunes every area that has a size limit, evicting the least recently
+/// modified files first. Areas without a limit are never pruned.
+#[derive(Clone)]
+pub struct CacheMaintenancePolicy {
+    /// The number of seconds between maintenance passes.
+    pub interval_secs: u64,
+    /// The maximum size of the chunk cache in bytes, if limited.
+    pub max_chunk_cache_bytes: Option<u64>,
+    /// The maximum size of the shard cache in bytes, if limited.
+    pub max_shard_cache_bytes: Option<u64>,
+    /// The maximum size of cached metadata in bytes, if limited.
+    pub max_metadata_bytes: Option<u64>,
+    /// The maximum size of staged upload data in bytes, if limited.
+    pub max_staged_upload_bytes: Option<u64>,
 }
 
-impl XetClient {
-    /// Creates a new Xet client without authentication.
+/// A local file to upload with `upload_files`, and the path it's committed to.
+#[cfg(feature = "upload")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileUploadRequest {
+    /// The path of the file on disk.
+    pub local_path: String,
+    /// The path the file is committed to, relative to the repository root.
+    pub path_in_repo: String,
+}
+
+/// A file to ask the Hub about with `preupload` before uploading it.
+#[cfg(feature = "upload")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreuploadFile {
+    /// The path the file would be committed to, relative to the repository root.
+    pub path_in_repo: String,
+    /// The size of the file in bytes.
+    pub size: u64,
+    /// The file's leading bytes, of which the Hub inspects the first 512.
+    pub sample: Vec<u8>,
+}
+
+/// How the Hub stores a file's content when it's committed.
+#[cfg(feature = "upload")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UploadMode {
+    /// The content is uploaded to Xet storage and committed as a pointer,
+    /// which the Hub reports as LFS.
+    Xet,
+    /// The content is committed inline in git.
+    Regular,
+}
+
+/// The Hub's verdict on a file passed to `preupload`.
+#[cfg(feature = "upload")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreuploadResult {
+    /// The path the file would be committed to.
+    pub path_in_repo: String,
+    /// How the file's content would be stored.
+    pub upload_mode: UploadMode,
+    /// Whether the repository's `.gitignore` excludes the file.
+    pub should_ignore: bool,
+    /// The object ID of the file currently at the path, if there is one.
+    pub remote_oid: Option<String>,
+}
+
+/// What uploading a set of files would transfer, as estimated by `estimate_upload`.
+#[cfg(feature = "upload")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UploadEstimate {
+    /// The bytes in the files.
+    pub total_bytes: u64,
+    /// The bytes of chunks CAS doesn't have, which an upload would send before compression.
+    pub new_bytes: u64,
+    /// The bytes of chunks an upload wouldn't send, because they repeat
+    /// within the files or are already in CAS.
+    pub deduplicated_bytes: u64,
+    /// Of the deduplicated bytes, those found by asking CAS about chunks
+    /// uploaded from elsewhere.
+    pub globally_deduplicated_bytes: u64,
+    /// The number of chunks the files split into.
+    pub total_chunks: u64,
+    /// The number of chunks an upload would send.
+    pub new_chunks: u64,
+}
+
+/// The outcome of one file in a batch upload.
+#[cfg(feature = "upload")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileUploadResult {
+    /// The path the file was committed to.
+    pub path_in_repo: String,
+    /// The SHA-256 digest of the file's content.
+    pub sha256: String,
+    /// The size of the file in bytes.
+    pub size: u64,
+    /// The bytes of the file not uploaded because an earlier file in the
+    /// batch has the same content.
+    ///
+    /// Chunks shared with other files are also uploaded once, but the Xet
+    /// data crate doesn't attribute those savings to individual files.
+    pub bytes_deduplicated: u64,
+}
+
+/// The content-defined chunking an upload split its files with.
+#[cfg(feature = "upload")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChunkingParameters {
+    /// The average chunk size in bytes.
+    pub target_chunk_size: u64,
+    /// The smallest chunk size in bytes, except for the last chunk of a file.
+    pub min_chunk_size: u64,
+    /// The largest chunk size in bytes.
+    pub max_chunk_size: u64,
+}
+
+/// The outcome of a batch upload, committed in a single commit.
+#[cfg(feature = "upload")]
+pub struct BatchUploadResult {
+    commit_oid: String,
+    files: Vec<FileUploadResult>,
+}
+
+#[cfg(feature = "upload")]
+impl BatchUploadResult {
+    /// Returns the ID of the created commit.
+    pub fn commit_oid(&self) -> String {
+        self.commit_oid.clone()
+    }
+
+    /// Returns the outcome of each file, in the order they were requested.
+    pub fn files(&self) -> Vec<FileUploadResult> {
+        self.files.clone()
+    }
+
+    /// Returns the chunking the files were split with, for reproducing their
+    /// chunks elsewhere.
+    ///
+    /// The Xet protocol fixes these sizes, so every upload reports the same ones.
+    pub fn chunking(&self) -> ChunkingParameters {
+        XET_CHUNKING
+    }
+}
+
+/// What happened to an interrupted commit during recovery.
+#[cfg(feature = "upload")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommitRecoveryAction {
+    /// All content had been uploaded and the commit was created.
+    Committed,
+    /// The upload never finished, or the Hub refused the commit, so the intent was discarded.
+    RolledBack,
+    /// The commit could not be completed yet and will be retried on the next recovery.
+    Deferred,
+    /// Some of the files were uploaded; uploading the same files again skips them.
+    Resumable,
+}
+
+/// The outcome of recovering a commit interrupted by a previous process.
+#[cfg(feature = "upload")]
+pub struct RecoveredCommit {
+    repo: String,
+    revision: String,
+    paths: Vec<String>,
+    action: CommitRecoveryAction,
+    commit_oid: Option<String>,
+    error: Option<String>,
+}
+
+#[cfg(feature = "upload")]
+impl RecoveredCommit {
+    /// Returns the full name of the repository the commit targeted.
+    pub fn repo(&self) -> String {
+        self.repo.clone()
+    }
+
+    /// Returns the branch the commit targeted.
+    pub fn revision(&self) -> String {
+        self.revision.clone()
+    }
+
+    /// Returns the repository paths included in the commit.
+    pub fn paths(&self) -> Vec<String> {
+        self.paths.clone()
+    }
+
+    /// Returns the action taken for this commit.
+    pub fn action(&self) -> CommitRecoveryAction {
+        self.action
+    }
+
+    /// Returns the ID of the created commit, if the commit was completed.
+    pub fn commit_oid(&self) -> Option<String> {
+        self.commit_oid.clone()
+    }
+
+    /// Returns a description of why the commit was deferred, if it was.
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+}
+
+/// Local files staged to be published to a repository together.
+///
+/// Stage files with `XetClient::stage_file`, then publish them all in a
+/// single commit with `XetClient::publish`. Nothing is uploaded until then.
+#[cfg(feature = "upload")]
+#[derive(Default)]
+pub struct UploadSession {
+    staged: Mutex<Vec<StagedFile>>,
+}
+
+#[cfg(feature = "upload")]
+impl UploadSession {
+    /// Creates a session with no staged files.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the repository paths staged so far, in the order they were staged.
+    pub fn staged_paths(&self) -> Vec<String> {
+        self.staged_files()
+            .into_iter()
+            .map(|file| file.path_in_repo)
+            .collect()
+    }
+
+    fn staged_files(&self) -> Vec<StagedFile> {
+        self.staged
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// A host-provided receiver for transfer progress.
+///
+/// The callback reports progress for downloads, and for uploads through
+/// `upload_file_with_progress` and `upload_files_with_progress`.
+pub trait ProgressCallback: Send + Sync {
+    /// Called as a transfer makes progress, and once more when it completes.
+    fn on_progress(&self, update: Arc<ProgressUpdate>);
+}
+
+/// Why a transfer was cancelled.
+///
+/// The reason is carried by the resulting `Cancelled` error and
+/// `TransferCancelled` event, so analytics can tell cancellations apart from
+/// failures and resume logic can decide whether to retry on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CancellationReason {
+    /// The user stopped the transfer. It shouldn't be retried automatically.
+    #[default]
+    UserRequested,
+    /// An app or download policy vetoed the transfer. It shouldn't be retried automatically.
+    PolicyVeto,
+    /// Network conditions stopped the transfer, such as a pause on a metered
+    /// network that outlasted its timeout. It can be retried once conditions change.
+    NetworkConditions,
+    /// The host app is about to exit. The transfer resumes when it's retried
+    /// after the next launch.
+    AppTerminating,
+}
+
+/// A handle for cancelling an in-flight transfer.
+///
+/// Pass the same token to a transfer and keep a reference to it; calling
+/// `cancel` from any thread stops the transfer at its next checkpoint.
+#[derive(Default)]
+pub struct CancellationToken {
+    cancelled: AtomicBool,
+    reason: Mutex<Option<CancellationReason>>,
+    /// When transfers using the token time out, if they do.
+    deadline: Option<Instant>,
+    /// The label transfers using the token are attributed to, if not the client's.
+    label: Option<String>,
+}
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a token that fails transfers using it with `XetError::Timeout`
+    /// once `timeout_secs` seconds have passed.
+    ///
+    /// The time limit covers the whole call the token is passed to, counting
+    /// from when the token is created, and aborts requests in flight however
+    /// stalled their connections are.
+    pub fn with_timeout(timeout_secs: u64) -> Self {
+        Self {
+            deadline: Some(Instant::now() + Duration::from_secs(timeout_secs)),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a token whose transfers are attributed to `label` in the
+    /// client's usage report, rather than to the client's usage label.
+    pub fn with_label(label: String) -> Self {
+        Self {
+            label: Some(label),
+            ..Self::default()
+        }
+    }
+
+    /// Requests cancellation of any transfer using this token, on behalf of the user.
+    pub fn cancel(&self) {
+        self.cancel_with_reason(CancellationReason::UserRequested);
+    }
+
+    /// Requests cancellation of any transfer using this token, recording why.
+    ///
+    /// Only the first reason is kept if the token is cancelled more than once.
+    pub fn cancel_with_reason(&self, reason: CancellationReason) {
+        self.reason
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_or_insert(reason);
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Returns whether cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns why the token was cancelled, or `None` if it hasn't been.
+    pub fn reason(&self) -> Option<CancellationReason> {
+        *self.reason.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns when transfers using the token time out, if they do.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Returns the label transfers using the token are attributed to, if any.
+    pub(crate) fn label(&self) -> Option<String> {
+        self.label.clone()
+    }
+}
+
+/// A handle for pausing, resuming, and cancelling an in-flight download.
+///
+/// Pass the same handle to a download and keep a reference to it. Pausing
+/// holds the transfer at its next checkpoint without discarding anything it
+/// has fetched, so an app can suspend work when it moves to the background. If
+/// the connection of a resumable HTTP transfer is dropped while paused, the
+/// transfer reconnects from where it stopped once resumed. A Xet
+/// reconstruction already under way runs to completion before the pause takes
+/// effect.
+pub struct DownloadHandle {
+    cancellation: Arc<CancellationToken>,
+    paused: tokio::sync::watch::Sender<bool>,
+    pauses: AtomicU64,
+}
+
+impl Default for DownloadHandle {
+    fn default() -> Self {
+        Self {
+            cancellation: Arc::new(CancellationToken::new()),
+            paused: tokio::sync::watch::Sender::new(false),
+            pauses: AtomicU64::new(0),
+        }
+    }
+}
+
+impl DownloadHandle {
+    /// Creates a handle that is neither paused nor cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a handle whose download fails with `XetError::Timeout` once
+    /// `timeout_secs` seconds have passed, counting time spent paused.
+    pub fn with_timeout(timeout_secs: u64) -> Self {
+        Self {
+            cancellation: Arc::new(CancellationToken::with_timeout(timeout_secs)),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a handle whose download is attributed to `label` in the client's
+    /// usage report, rather than to the client's usage label.
+    pub fn with_label(label: String) -> Self {
+        Self {
+            cancellation: Arc::new(CancellationToken::with_label(label)),
+            ..Self::default()
+        }
+    }
+
+    /// Pauses any download using this handle at its next checkpoint.
+    pub fn pause(&self) {
+        self.pauses.fetch_add(1, Ordering::AcqRel);
+        self.paused.send_replace(true);
+    }
+
+    /// Lets a paused download continue.
+    pub fn resume(&self) {
+        self.paused.send_replace(false);
+    }
+
+    /// Returns whether the handle is paused.
+    pub fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    /// Cancels any download using this handle, on behalf of the user, even while paused.
+    pub fn cancel(&self) {
+        self.cancel_with_reason(CancellationReason::UserRequested);
+    }
+
+    /// Cancels any download using this handle, recording why.
+    pub fn cancel_with_reason(&self, reason: CancellationReason) {
+        self.cancellation.cancel_with_reason(reason);
+        // Wake a paused transfer so it can observe the cancellation.
+        self.paused.send_modify(|_| {});
+    }
+
+    /// Returns whether cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+
+    /// Returns the number of times the handle has been paused.
+    pub(crate) fn pauses(&self) -> u64 {
+        self.pauses.load(Ordering::Acquire)
+    }
+
+    /// Waits until the handle is resumed or cancelled.
+    pub(crate) async fn wait_until_resumed(&self) {
+        let mut paused = self.paused.subscribe();
+        let _ = paused
+            .wait_for(|paused| !paused || self.cancellation.is_cancelled())
+            .await;
+    }
+}
+
+/// A set of downloads that make up one user-facing task, such as installing a model.
+///
+/// Downloads added to the group share one `DownloadHandle`, so pausing,
+/// resuming, or cancelling the group applies to all of them at once, and a
+/// download added to a cancelled group fails with `XetError::Cancelled`
+/// before it starts. The progress of every download in the group is summed
+/// into a single stream; its total grows as downloads learn their sizes.
+pub struct TransferGroup {
+    handle: Arc<DownloadHandle>,
+    progress: Option<Arc<dyn ProgressCallback>>,
+    /// The bytes completed and the total of each download added to the group.
+    members: Mutex<Vec<(u64, u64)>>,
+}
+
+impl TransferGroup {
+    /// Creates an empty group reporting its aggregate progress to `progress`.
+    pub fn new(progress: Option<Box<dyn ProgressCallback>>) -> Self {
+        Self {
+            handle: Arc::new(DownloadHandle::new()),
+            progress: progress.map(Arc::from),
+            members: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Pauses every download in the group at its next checkpoint.
+    pub fn pause(&self) {
+        self.handle.pause();
+    }
+
+    /// Lets the group's paused downloads continue.
+    pub fn resume(&self) {
+        self.handle.resume();
+    }
+
+    /// Returns whether the group is paused.
+    pub fn is_paused(&self) -> bool {
+        self.handle.is_paused()
+    }
+
+    /// Cancels every download in the group, and any added later, on behalf of the user.
+    pub fn cancel(&self) {
+        self.handle.cancel();
+    }
+
+    /// Cancels every download in the group, and any added later, recording why.
+    pub fn cancel_with_reason(&self, reason: CancellationReason) {
+        self.handle.cancel_with_reason(reason);
+    }
+
+    /// Returns whether cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.handle.is_cancelled()
+    }
+
+    /// Returns the number of downloads added to the group.
+    pub fn download_count(&self) -> u64 {
+        self.lock().len() as u64
+    }
+
+    /// Returns the group's progress so far, summed over its downloads.
+    pub fn progress(&self) -> Arc<ProgressUpdate> {
+        Arc::new(Self::total(&self.lock()))
+    }
+
+    /// Adds a download to the group, returning the callback it reports progress to.
+    fn join(self: &Arc<Self>) -> GroupMember {
+        let mut members = self.lock();
+        members.push((0, 0));
+        GroupMember {
+            group: self.clone(),
+            index: members.len() - 1,
+        }
+    }
+
+    /// Records a download's progress and reports the group's.
+    fn update(&self, index: usize, completed: u64, total: u64) {
+        let update = {
+            let mut members = self.lock();
+            members[index] = (completed, total);
+            Self::total(&members)
+        };
+        if let Some(progress) = &self.progress {
+            progress.on_progress(Arc::new(update));
+        }
+    }
+
+    fn total(members: &[(u64, u64)]) -> ProgressUpdate {
+        let completed = members.iter().map(|(completed, _)| completed).sum();
+        let total = members.iter().map(|(_, total)| total).sum();
+        ProgressUpdate::for_transfer(completed, total)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<(u64, u64)>> {
+        self.members.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Forwards one download's progress into its `TransferGroup`.
+struct GroupMember {
+    group: Arc<TransferGroup>,
+    index: usize,
+}
+
+impl ProgressCallback for GroupMember {
+    fn on_progress(&self, update: Arc<ProgressUpdate>) {
+        self.group.update(
+            self.index,
+            update.total_bytes_completed(),
+            update.total_bytes(),
+        );
+    }
+}
+
+/// Checks if pointer file detection should be attempted based on file extension.
+///
+/// Returns `false` for known binary file extensions like .safetensors, .bin, .pt, etc.
+/// to avoid unnecessary UTF-8 parsing and improve performance.
+fn should_try_pointer_detection(path: &str) -> bool {
+    let ext = Path::new(path)
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    
+    !matches!(
+        ext.as_str(),
+        "safetensors" | "bin" | "pt" | "onnx" | "tflite"
+        | "tar" | "gz" | "zip" | "xz" | "zst" | "bz2" 
+        | "npy" | "npz" | "h5" | "ckpt" | "pth"
+    )
+}
+
+/// Returns whether a security scan status should be treated as unsafe.
+fn is_flagged_security_status(status: &str) -> bool {
+    matches!(status.to_ascii_lowercase().as_str(), "unsafe" | "suspicious")
+}
+
+/// Returns the statuses of the flagged files among `entries`, keyed by path.
+///
+/// Only listings requested with `expand` include scan results.
+fn flagged_entries(entries: &[TreeEntry]) -> HashMap<String, String> {
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let status = entry.security_status()?;
+            is_flagged_security_status(&status).then(|| (entry.path.clone(), status))
+        })
+        .collect()
+}
+
+impl XetClient {
+    /// Creates a new Xet client without authentication.
+    ///
+    /// Use this initializer to create a client for accessing public repositories.
+    /// For private repositories or upload operations, use `with_token` instead.
+    ///
+    /// # Returns
+    ///
+    /// A new `XetClient` instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError` if the client cannot be initialized, such as when
+    /// the runtime cannot be created.
+    pub fn new() -> Result<Self, XetError> {
+        Self::with_config(XetClientConfig::default())
+    }
+
+    /// Creates a new Xet client with an authentication token.
+    ///
+    /// Use this initializer when you need to access private repositories or
+    /// perform upload operations. You can obtain a token from your
+    /// [Hugging Face account settings](https://huggingface.co/settings/tokens).
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - A Hugging Face authentication token. The token must not be empty.
+    ///
+    /// # Returns
+    ///
+    /// A new `XetClient` instance configured with the provided token.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if the token is empty, or `XetError`
+    /// if the client cannot be initialized.
+    pub fn with_token(token: String) -> Result<Self, XetError> {
+        Self::with_config(XetClientConfig {
+            token: Some(token),
+            ..XetClientConfig::default()
+        })
+    }
+
+    /// Creates a new Xet client from a configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The client configuration, including an optional token and guardrail limits.
+    ///
+    /// # Returns
+    ///
+    /// A new `XetClient` instance using the provided configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if the configured token is empty, or `XetError`
+    /// if the client cannot be initialized.
+    pub fn with_config(mut config: XetClientConfig) -> Result<Self, XetError> {
+        if let Some(preset) = config.download_preset {
+            preset.apply(&mut config);
+        }
+        if config.token.as_deref() == Some("") {
+            return Err(XetError::InvalidInput {
+                message: "Token cannot be empty".to_string(),
+            });
+        }
+        if config.worker_threads == Some(0) {
+            return Err(XetError::InvalidInput {
+                message: "Worker thread count must be at least one".to_string(),
+            });
+        }
+        if config.read_ahead_chunks == Some(0) {
+            return Err(XetError::InvalidInput {
+                message: "Read-ahead window must be at least one chunk".to_string(),
+            });
+        }
+        if config.default_revision.as_deref() == Some("") {
+            return Err(XetError::InvalidInput {
+                message: "Default revision cannot be empty".to_string(),
+            });
+        }
+        if config.idle_timeout_secs == Some(0) {
+            return Err(XetError::InvalidInput {
+                message: "Idle timeout must be greater than zero".to_string(),
+            });
+        }
+        if config.max_concurrent_downloads == Some(0) {
+            return Err(XetError::InvalidInput {
+                message: "Concurrent downloads must be at least one".to_string(),
+            });
+        }
+        if config.max_response_body_bytes == Some(0) {
+            return Err(XetError::InvalidInput {
+                message: "Maximum response body size must be greater than zero".to_string(),
+            });
+        }
+        if config.max_concurrent_range_gets == Some(0) {
+            return Err(XetError::InvalidInput {
+                message: "Concurrent range requests must be at least one".to_string(),
+            });
+        }
+        check_download_limit(config.max_download_bytes_per_second)?;
+        let timeouts = [
+            ("Connect", config.connect_timeout_secs),
+            ("Read", config.read_timeout_secs),
+            ("Operation", config.operation_timeout_secs),
+        ];
+        for (name, timeout_secs) in timeouts {
+            if timeout_secs == Some(0) {
+                return Err(XetError::InvalidInput {
+                    message: format!("{} timeout must be greater than zero", name),
+                });
+            }
+        }
+        for endpoint_override in config.endpoint_overrides.iter().flatten() {
+            check_endpoint_override(endpoint_override)?;
+        }
+        if config.hub_endpoints.as_ref().is_some_and(Vec::is_empty) {
+            return Err(XetError::InvalidInput {
+                message: "Hub endpoints cannot be empty".to_string(),
+            });
+        }
+        for endpoint in config.hub_endpoints.iter().flatten() {
+            check_endpoint(endpoint)?;
+        }
+        let retry_policy = config.retry_policy.clone().unwrap_or_default();
+        retry_policy.validate()?;
+        let tls = TlsOptions::from_config(&config)?;
+        let cache = CacheLayout::from_config(&config)?;
+
+        // Apply high-performance defaults BEFORE creating the client
+        Self::apply_performance_defaults();
+
+        let resources = ClientResources::new(&config, &tls)?;
+        let resources = Arc::new(IdleSlot::new(Arc::new(resources)));
+        let cache_maintenance: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>> = Arc::default();
+        let idle_monitor = match config.idle_timeout_secs {
+            Some(idle_timeout_secs) => {
+                let maintenance = cache_maintenance.clone();
+                let monitor = xet_idle::monitor(
+                    &resources,
+                    Duration::from_secs(idle_timeout_secs),
+                    move || {
+                        maintenance
+                            .lock()
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .as_ref()
+                            .is_none_or(|handle| handle.is_finished())
+                    },
+                )
+                .map_err(|e| XetError::IoError {
+                    message: format!("Failed to start idle monitor: {}", e),
+                    cause: Some(ErrorCause::from(&e)),
+                })?;
+                Some(monitor)
+            }
+            None => None,
+        };
+
+        let callbacks = CallbackDispatcher::new().map_err(|e| XetError::IoError {
+            message: format!("Failed to start callback thread: {}", e),
+            cause: Some(ErrorCause::from(&e)),
+        })?;
+        let throttle = Arc::new(Throttle::new(config.max_download_bytes_per_second));
+        let scheduler = Scheduler::new(config.max_concurrent_range_gets);
+        let faults = Faults::new(&config);
+        let clock = Clock::new(config.clock_source, config.clock_skew_tolerance_secs);
+        let redirects = Arc::new(RedirectCache::new(config.resolve_redirect_cache_secs));
+
+        Ok(Self {
+            resources,
+            callbacks: Arc::new(callbacks),
+            endpoint: config
+                .hub_endpoints
+                .as_ref()
+                .and_then(|endpoints| endpoints.first())
+                .map_or(DEFAULT_ENDPOINT, |first| first.trim_end_matches('/'))
+                .to_string(),
+            token: config.token.clone(),
+            quiet: AtomicBool::new(config.quiet),
+            config,
+            download_policy: RwLock::new(None),
+            event_listener: RwLock::new(None),
+            rate_limit: RwLock::new(None),
+            retry_at: RwLock::new(None),
+            managed: ManagedLibrary::open(&cache),
+            hub_cache: HubCache::open(&cache),
+            cache,
+            cache_maintenance,
+            throttle,
+            scheduler,
+            faults,
+            recorder: Arc::default(),
+            termination: Arc::default(),
+            clock,
+            redirects,
+            usage: Arc::default(),
+            usage_label: RwLock::new(None),
+            retry_policy,
+            tls,
+            _idle_monitor: idle_monitor,
+        })
+    }
+
+    /// Get plural form of repo type for API URLs
+    fn repo_type_plural(&self, repo_type: &hub_client::HFRepoType) -> &'static str {
+        match repo_type {
+            hub_client::HFRepoType::Model => "models",
+            hub_client::HFRepoType::Dataset => "datasets",
+            hub_client::HFRepoType::Space => "spaces",
+        }
+    }
+
+    /// Builds the URL of the API route on `endpoint` taking `revision`, such as `paths-info`.
+    fn revision_route(
+        &self,
+        endpoint: &str,
+        repo_info: &HubRepoInfo,
+        route: &str,
+        revision: &str,
+    ) -> String {
+        xet_url::revision_route(
+            endpoint,
+            self.repo_type_plural(&repo_info.repo_type),
+            &repo_info.full_name,
+            route,
+            revision,
+        )
+    }
+
+    /// Builds the tree API URL on `endpoint` for listing `path` at `revision`.
+    ///
+    /// With `expand`, entries also include scan results and last-commit
+    /// details, and the Hub serves them in smaller pages.
+    fn tree_url(
+        &self,
+        endpoint: &str,
+        repo_info: &HubRepoInfo,
+        path: &str,
+        revision: &str,
+        recursive: bool,
+        expand: bool,
+    ) -> String {
+        let mut url = self.revision_route(endpoint, repo_info, "tree", revision);
+        if !path.is_empty() {
+            url.push('/');
+            url.push_str(&xet_url::encode_path(path));
+        }
+        let query: Vec<&str> = [(recursive, "recursive=true"), (expand, "expand=true")]
+            .into_iter()
+            .filter_map(|(enabled, param)| enabled.then_some(param))
+            .collect();
+        if !query.is_empty() {
+            url.push('?');
+            url.push_str(&query.join("&"));
+        }
+        url
+    }
+
+    /// Fetches one page of a tree listing, returning its entries and the next page's URL.
+    fn fetch_tree_page(&self, url: &str) -> Result<(Vec<TreeEntry>, Option<String>), XetError> {
+        self.block_on(async {
+            let mut request = self.http_client()?.get(url);
+            // A `next` link can name any host, which mustn't receive the token.
+            if let Some(token) = self.token.as_ref().filter(|_| self.should_send_auth(url)) {
+                request = request.bearer_auth(token);
+            }
+
+            let response = self.send(request).await?.error_for_status()?;
+            // Relative links point at the host that served the page.
+            let next_url = xet_metadata::link_with_rel(response.headers(), "next").map(|next| {
+                let joined = Url::parse(url).and_then(|base| base.join(&next));
+                joined.map_or(next, |next_url| next_url.to_string())
+            });
+            let body = xet_http::read_text(response, self.max_response_body_bytes()).await?;
+            // A listing cut off partway through reads as malformed JSON.
+            let entries = parse_tree_entries(&body).map_err(|e| XetError::NetworkError {
+                message: format!("Incomplete tree listing from {}: {}", url, e),
+                cause: Some(ErrorCause::Http {
+                    kind: HttpErrorKind::Body,
+                    status: None,
+                }),
+            })?;
+            Ok((entries, next_url))
+        })
+    }
+
+    /// Fetches every page of the tree listing starting at `url`.
+    fn fetch_tree(&self, url: String) -> Result<Vec<TreeEntry>, XetError> {
+        let mut entries = Vec::new();
+        let mut next_url = Some(url);
+        while let Some(url) = next_url {
+            let (page, following) = self.fetch_tree_page(&url)?;
+            entries.extend(page);
+            next_url = following;
+        }
+        Ok(entries)
+    }
+
+    fn read_ahead_chunks(&self) -> u32 {
+        self.config
+            .read_ahead_chunks
+            .unwrap_or(xet_readahead::DEFAULT_READ_AHEAD_CHUNKS)
+    }
+
+    fn resolve_revision(&self, revision: Option<String>) -> String {
+        revision
+            .or_else(|| self.config.default_revision.clone())
+            .unwrap_or_else(|| DEFAULT_REVISION.to_string())
+    }
+
+    /// Parse repository identifier into RepoInfo
+    ///
+    /// Supports formats:
+    /// - "owner/repo" (defaults to model type)
+    /// - "models/owner/repo"
+    /// - "datasets/owner/repo"
+    /// - "spaces/owner/repo"
+    fn parse_repo(&self, repo: &str) -> Result<HubRepoInfo, XetError> {
+        let parts: Vec<&str> = repo.split('/').collect();
+
+        if parts.len() < 2 {
+            return Err(XetError::InvalidInput {
+                message: format!("Repository identifier must be in format 'owner/repo' or 'type/owner/repo', got: {}", repo)
+            });
+        }
+
+        // Check if first part is a repo type
+        let (repo_type_str, repo_id) = if parts.len() >= 3 {
+            let first = parts[0].to_lowercase();
+            if first == "models"
+                || first == "model"
+                || first == "datasets"
+                || first == "dataset"
+                || first == "spaces"
+                || first == "space"
+            {
+                (parts[0], parts[1..].join("/"))
+            } else {
+                ("model", repo.to_string())
+            }
+        } else {
+            ("model", repo.to_string())
+        };
+
+        let repo_info =
+            HubRepoInfo::try_from(repo_type_str, &repo_id).map_err(|e| XetError::InvalidInput {
+                message: format!("Invalid repository: {}", e),
+            })?;
+        self.check_repo_allowed(&repo_info)?;
+        Ok(repo_info)
+    }
+
+    fn check_repo_allowed(&self, repo_info: &HubRepoInfo) -> Result<(), XetError> {
+        let Some(allowed_owners) = &self.config.allowed_repo_owners else {
+            return Ok(());
+        };
+
+        let owner = repo_info
+            .full_name
+            .split('/')
+            .next()
+            .unwrap_or_default();
+        if allowed_owners
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(owner))
+        {
+            Ok(())
+        } else {
+            Err(XetError::PolicyViolation {
+                message: format!(
+                    "Repository owner '{}' is not in the allowed owner list",
+                    owner
+                ),
+            })
+        }
+    }
+
+    fn check_file_size(&self, path: &str, size: u64) -> Result<(), XetError> {
+        match self.config.max_file_size_bytes {
+            Some(max) if size > max => Err(XetError::PolicyViolation {
+                message: format!(
+                    "{} is {} bytes, which exceeds the maximum file size of {} bytes",
+                    path, size, max
+                ),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Applies the size guardrail and the host's download policy before a transfer starts.
+    ///
+    /// A file of unknown size is held to the guardrail as it's written.
+    fn check_download_allowed(
+        &self,
+        path: &str,
+        size: Option<u64>,
+        content_type: Option<&str>,
+    ) -> Result<(), XetError> {
+        if let Some(size) = size {
+            self.check_file_size(path, size)?;
+        }
+
+        let policy = self
+            .download_policy
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        match policy {
+            Some(policy)
+                if !policy.allow_download(
+                    path.to_string(),
+                    size,
+                    content_type.map(str::to_string),
+                ) =>
+            {
+                Err(XetError::PolicyViolation {
+                    message: format!("Download of {} was vetoed by the download policy", path),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_response(&self, path: &str, response: &reqwest::Response) -> Result<(), XetError> {
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok());
+        self.check_download_allowed(path, response.content_length(), content_type)
+    }
+
+    /// Returns whether downloads need the security scan status of their files.
+    fn screens_files(&self) -> bool {
+        self.config.unsafe_file_policy != UnsafeFilePolicy::Allow
+    }
+
+    /// Applies `unsafe_file_policy` using the scan status reported by the paths-info API.
+    fn check_security_status(
+        &self,
+        repo_info: &HubRepoInfo,
+        path: &str,
+        revision: &str,
+    ) -> Result<(), XetError> {
+        let mut flagged = self.flagged_files(repo_info, revision, &[path.to_string()])?;
+        self.apply_unsafe_file_policy(repo_info, path, flagged.remove(path))
+    }
+
+    /// Looks up the scan status of `paths` with the paths-info API, returning
+    /// the statuses of the files the Hub's security scanner flagged.
+    ///
+    /// The paths are looked up together, in as few requests as the API allows.
+    /// With the `Warn` policy, a failed lookup is reported as a diagnostic and
+    /// treated as finding nothing, so it can't stop a download the policy allows.
+    fn flagged_files(
+        &self,
+        repo_info: &HubRepoInfo,
+        revision: &str,
+        paths: &[String],
+    ) -> Result<HashMap<String, String>, XetError> {
+        if !self.screens_files() || paths.is_empty() {
+            return Ok(HashMap::new());
+        }
+        match self.fetch_paths_info(repo_info, revision, paths, true) {
+            Ok(entries) => Ok(flagged_entries(&entries)),
+            Err(e) if self.config.unsafe_file_policy == UnsafeFilePolicy::Warn => {
+                self.diagnostic(format_args!(
+                    "Failed to check the security status of files in {}: {}",
+                    repo_info.full_name, e
+                ));
+                Ok(HashMap::new())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Applies `unsafe_file_policy` to `path`, given the status the Hub's
+    /// security scanner flagged it with, if any.
+    fn apply_unsafe_file_policy(
+        &self,
+        repo_info: &HubRepoInfo,
+        path: &str,
+        status: Option<String>,
+    ) -> Result<(), XetError> {
+        let Some(status) = status else {
+            return Ok(());
+        };
+
+        match self.config.unsafe_file_policy {
+            UnsafeFilePolicy::Allow => Ok(()),
+            UnsafeFilePolicy::Refuse => Err(XetError::PolicyViolation {
+                message: format!(
+                    "{} was flagged as {} by the Hub's security scanner",
+                    path, status
+                ),
+            }),
+            UnsafeFilePolicy::Warn => {
+                self.emit(XetEvent::UnsafeFileDetected {
+                    repo: repo_info.full_name.clone(),
+                    path: path.to_string(),
+                    status,
+                });
+                Ok(())
+            }
+        }
+    }
+
+    fn check_total_size(&self, total_size: u64) -> Result<(), XetError> {
+        match self.config.max_snapshot_size_bytes {
+            Some(max) if total_size > max => Err(XetError::PolicyViolation {
+                message: format!(
+                    "Download totals at least {} bytes, which exceeds the maximum of {} bytes",
+                    total_size, max
+                ),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the version of the Xet client library.
+    ///
+    /// # Returns
+    ///
+    /// A string containing the semantic version number (e.g., `"1.0.0"`).
+    pub fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    /// Retrieves the content of a file from a Xet repository.
+    ///
+    /// This method downloads the file content and returns it as raw bytes.
+    /// For binary files or large files, consider using `download_file` instead
+    /// to save the file directly to disk.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository, relative to the repository root.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// The file content as a byte vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` or `path` is empty, `XetError::PolicyViolation`
+    /// if the file is larger than `max_response_body_bytes`, or `XetError::NetworkError`
+    /// if the file cannot be retrieved from the repository.
+    pub fn get_file_content(
+        &self,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    ) -> Result<Vec<u8>, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+        if path.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Path cannot be empty".to_string(),
+            });
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        let resolved_revision = self.resolve_revision(revision);
+
+        if let Ok(metadata) = self.fetch_metadata(&repo_info, &path, &resolved_revision) {
+            self.check_download_allowed(
+                &path,
+                Some(metadata.size),
+                metadata.content_type.as_deref(),
+            )?;
+            let limit = self.max_response_body_bytes();
+            if metadata.size > limit {
+                return Err(XetError::PolicyViolation {
+                    message: format!(
+                        "{} is {} bytes, which exceeds the maximum response body size of {} bytes; download it to a file instead",
+                        path, metadata.size, limit
+                    ),
+                });
+            }
+            match self.http_get_bytes(&metadata.download_url) {
+                Ok(bytes) => return Ok(bytes),
+                Err(e @ XetError::PolicyViolation { .. }) => return Err(e),
+                Err(_) => {}
+            }
+        }
+
+        self.get_file_content_legacy(repo_info.clone(), path, resolved_revision)
+            .map_err(|e| self.explain_access_error(&repo_info, e))
+    }
+
+    /// Lists all files in a directory within a Xet repository.
+    ///
+    /// This method returns only file paths, not directories. For more detailed
+    /// information including file metadata, use `list_files_with_metadata`.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The directory path within the repository. Use an empty string for the root directory.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// An array of file paths, relative to the repository root.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` is empty, or `XetError::NetworkError`
+    /// if the directory listing cannot be retrieved.
+    pub fn list_files(
+        &self,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    ) -> Result<Vec<String>, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        let rev = self.resolve_revision(revision);
+        let file_paths = self
+            .with_fallback(&repo_info, |endpoint| {
+                let url = self.tree_url(endpoint, &repo_info, &path, &rev, false, false);
+                self.block_on(async {
+                    let mut request = self.http_client()?.get(&url);
+
+                    if let Some(token) = &self.token {
+                        request = request.bearer_auth(token);
+                    }
+
+                    let response = self.send(request).await.map_err(|e| XetError::from(e))?;
+                    let response = response.error_for_status().map_err(|e| XetError::from(e))?;
+                    let body =
+                        xet_http::read_text(response, self.max_response_body_bytes()).await?;
+
+                    let entries = parse_tree_entries(&body)?;
+
+                    Ok::<Vec<String>, XetError>(
+                        entries
+                            .into_iter()
+                            .filter(|entry| entry.entry_type == "file")
+                            .map(|entry| entry.path)
+                            .collect(),
+                    )
+                })
+            })
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+
+        Ok(file_paths)
+    }
+
+    /// Lists all entries in a directory within a Xet repository with metadata.
+    ///
+    /// This method returns both files and directories with their associated metadata,
+    /// including sizes, hashes, and Git object IDs when available.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The directory path within the repository. Use an empty string for the root directory.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// An array of `FileMetadata` objects containing information about each entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` is empty, or `XetError::NetworkError`
+    /// if the directory listing cannot be retrieved.
+    pub fn list_files_with_metadata(
+        &self,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    ) -> Result<Vec<Arc<FileMetadata>>, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        let rev = self.resolve_revision(revision);
+        let metadata = self
+            .with_fallback(&repo_info, |endpoint| {
+                let url = self.tree_url(endpoint, &repo_info, &path, &rev, false, true);
+                self.block_on(async {
+                    let mut request = self.http_client()?.get(&url);
+
+                    if let Some(token) = &self.token {
+                        request = request.bearer_auth(token);
+                    }
+
+                    let response = self.send(request).await.map_err(|e| XetError::from(e))?;
+                    let response = response.error_for_status().map_err(|e| XetError::from(e))?;
+                    let body =
+                        xet_http::read_text(response, self.max_response_body_bytes()).await?;
+
+                    let entries = parse_tree_entries(&body)?;
+
+                    Ok::<Vec<Arc<FileMetadata>>, XetError>(
+                        entries
+                            .into_iter()
+                            .map(|entry| Arc::new(FileMetadata::from(entry)))
+                            .collect(),
+                    )
+                })
+            })
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+
+        Ok(metadata)
+    }
+
+    /// Returns an iterator that lists a directory one page at a time.
+    ///
+    /// Unlike `list_files_with_metadata`, nothing is fetched until the first call
+    /// to `TreeIterator::next_page`, and each call transfers a single page of
+    /// entries across the FFI boundary. Use this for very large repositories so
+    /// results can be displayed as they arrive.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The directory path within the repository. Use an empty string for the root directory.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    /// * `recursive` - Whether to include entries in subdirectories.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` is empty or malformed.
+    pub fn tree_iterator(
+        self: Arc<Self>,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+        recursive: bool,
+    ) -> Result<Arc<TreeIterator>, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        let rev = self.resolve_revision(revision);
+        let endpoint = self.endpoint_for(&repo_info);
+        let url = self.tree_url(endpoint, &repo_info, &path, &rev, recursive, true);
+
+        Ok(Arc::new(TreeIterator {
+            client: self,
+            next_url: Mutex::new(Some(url)),
+        }))
+    }
+
+    /// Lists every file under a directory of a repository, including subdirectories.
+    ///
+    /// The Hub can fail to list very large directories in one go, answering
+    /// with an error or a truncated response. Those are listed one directory at
+    /// a time instead. A directory too large even for that is reported in the
+    /// result rather than failing the whole listing, so check
+    /// `TreeListing::is_complete` before relying on the files being all there.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The directory path within the repository. Use an empty string for the root directory.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// A `TreeListing` of the files found, marked as complete or partial.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` is empty, `XetError::AuthRequired`
+    /// if the repository needs credentials the client lacks, or `XetError::NetworkError`
+    /// if the directory listing cannot be retrieved.
+    pub fn list_files_recursive(
+        &self,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    ) -> Result<Arc<TreeListing>, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        let rev = self.resolve_revision(revision);
+        let listing = self
+            .list_repo_tree(&repo_info, path.trim_matches('/'), &rev, true)
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+
+        Ok(Arc::new(TreeListing {
+            files: listing
+                .files
+                .into_iter()
+                .map(|entry| Arc::new(FileMetadata::from(entry)))
+                .collect(),
+            unlisted_paths: listing.unlisted,
+        }))
+    }
+
+    /// Checks whether the client's token grants an operation on a repository.
+    ///
+    /// Fine-grained tokens may be granted some repositories and not others, or
+    /// read access without write access. Use this to find out up front rather
+    /// than partway through a transfer. Read access is checked by requesting
+    /// the repository's info, and write access by requesting a write token, so
+    /// nothing is downloaded or uploaded either way.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `operation` - The operation to check, `Read` or `Write`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the client may perform the operation, or `false` if the Hub
+    /// refuses it or the repository doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` is empty, `XetError::ServiceUnavailable`
+    /// if the Hub answers with a server error, or `XetError::NetworkError` if it
+    /// can't be reached.
+    pub fn can_access(&self, repo: String, operation: RepoOperation) -> Result<bool, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        self.probe_access(&repo_info, operation)
+    }
+
+    /// Opens a file for reading in chunks, without writing it to disk.
+    ///
+    /// Use this to process large files incrementally, such as streaming a GGUF
+    /// file into a loader. The revision is resolved to a commit when the stream
+    /// is opened, so every chunk comes from the same version of the file even if
+    /// the branch moves while it's being read. The download policy is consulted
+    /// before the stream is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository, relative to the repository root.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// A `FileStream` to read the file's bytes from with `FileStream::read_next`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` or `path` is empty, `XetError::PolicyViolation`
+    /// if the download is vetoed or the file is too large, or `XetError::NetworkError` if the
+    /// file's metadata cannot be resolved.
+    pub fn open_file_stream(
+        &self,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    ) -> Result<Arc<FileStream>, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+        if path.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Path cannot be empty".to_string(),
+            });
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        let revision = self.resolve_revision(revision);
+        let metadata = self
+            .fetch_metadata(&repo_info, &path, &revision)
+            .and_then(|metadata| match metadata.commit_hash.as_str() {
+                "" => Ok(metadata),
+                commit if commit == revision => Ok(metadata),
+                commit => self.fetch_metadata(&repo_info, &path, commit),
+            })
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+        self.check_security_status(&repo_info, &path, &metadata.commit_hash)?;
+        self.check_download_allowed(&path, Some(metadata.size), metadata.content_type.as_deref())?;
+
+        let resources = self.shared_resources()?;
+        let client = resources.http_client.clone();
+        let token = self
+            .token
+            .clone()
+            .filter(|_| self.should_send_auth(&metadata.download_url));
+        let url = metadata.download_url;
+        let size = metadata.size;
+        let throttle = self.throttle.clone();
+        let faults = self.faults.clone();
+        let recorder = self.recorder.clone();
+        let (usage, label) = (self.usage.clone(), self.usage_label());
+        let redirects = self.redirects.clone();
+        let reader = ReadAhead::spawn(
+            resources.runtime.handle(),
+            self.read_ahead_chunks(),
+            move |index| {
+                let (client, url, token) = (client.clone(), url.clone(), token.clone());
+                let (throttle, redirects) = (throttle.clone(), redirects.clone());
+                let future = faults.scope(async move {
+                    let start = index.saturating_mul(STREAM_CHUNK_SIZE);
+                    if start >= size {
+                        return Ok(None);
+                    }
+                    let end = start.saturating_add(STREAM_CHUNK_SIZE).min(size);
+                    // A cached redirect target is fetched without the Hub's
+                    // token, and the Hub is asked again if it fails.
+                    let cached = match redirects.target(&url) {
+                        Some(target) => {
+                            xet_http::fetch_range(&client, &target, None, start..end, size)
+                                .await
+                                .inspect_err(|_| redirects.forget(&url))
+                                .ok()
+                        }
+                        None => None,
+                    };
+                    let chunk = match cached {
+                        Some(chunk) => chunk,
+                        None => {
+                            xet_http::fetch_range(&client, &url, token.as_deref(), start..end, size)
+                                .await?
+                        }
+                    };
+                    throttle.acquire(chunk.len() as u64).await;
+                    Ok(Some(chunk))
+                });
+                usage.scope(label.clone(), recorder.scope(future))
+            },
+        );
+
+        Ok(Arc::new(FileStream {
+            resources,
+            size,
+            state: Mutex::new(FileStreamState {
+                reader,
+                chunk: Vec::new(),
+                chunk_offset: 0,
+                position: 0,
+            }),
+        }))
+    }
+
+    /// Downloads a file from a Xet repository to a local path.
+    ///
+    /// This method downloads the file content and saves it to the specified destination.
+    /// The parent directory of the destination path will be created if it doesn't exist.
+    /// The destination is normalized according to the client's `path_normalization`.
+    ///
+    /// The file is written to `<destination>.incomplete` and only moved into place
+    /// once it's complete and, if requested, verified. A download over HTTP that is
+    /// interrupted or cancelled keeps its partial file, and the next call for the
+    /// same content resumes with a `Range` request instead of starting over.
+    /// Xet reconstruction always starts from the beginning.
+    ///
+    /// If the destination already holds the resolved content, nothing is fetched
+    /// and the report's source is `Skipped`. Files this client downloaded are
+    /// recognized by a fingerprint of their etag, size, and modification time;
+    /// other files are hashed when the Hub reports their SHA-256.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository, relative to the repository root.
+    /// * `destination` - The local file path where the downloaded file should be saved.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// A `TransferReport` describing how the file was fetched and whether it was verified.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if any parameter is empty, `XetError::IoError`
+    /// if the file cannot be written to disk, `XetError::NetworkError` if the file
+    /// cannot be downloaded, or `XetError::IntegrityError` if verification is enabled
+    /// and the downloaded content doesn't match its expected hash.
+    pub fn download_file(
+        &self,
+        repo: String,
+        path: String,
+        destination: String,
+        revision: Option<String>,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        self.download_file_with_progress(repo, path, destination, revision, None, None)
+    }
+
+    /// Downloads a file, reporting progress and honoring cancellation.
+    ///
+    /// Progress is reported for downloads served over HTTP, which stream to disk
+    /// chunk by chunk. Cancellation is checked before each transfer path is
+    /// tried and between HTTP chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository, relative to the repository root.
+    /// * `destination` - The local file path where the downloaded file should be saved.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    /// * `progress` - An optional callback that receives progress updates.
+    /// * `cancellation` - An optional token that cancels the download when triggered.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `download_file`, or `XetError::Cancelled` if
+    /// the download was cancelled.
+    pub fn download_file_with_progress(
+        &self,
+        repo: String,
+        path: String,
+        destination: String,
+        revision: Option<String>,
+        progress: Option<Box<dyn ProgressCallback>>,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        let observer = TransferObserver::new(
+            progress.map(Arc::from),
+            cancellation,
+            self.callbacks.clone(),
+        )
+        .with_throttle(self.throttle.clone());
+        xet_usage::scoped(observer.label(), || {
+            xet_deadline::scoped(observer.deadline(), || {
+                self.download_file_observed(repo, path, destination, revision, observer)
+            })
+        })
+    }
+
+    /// Downloads a file that can be paused, resumed, and cancelled through `handle`.
+    ///
+    /// Pausing holds the download between HTTP chunks, or before its next
+    /// transfer path is tried, keeping everything fetched so far. A resumable
+    /// HTTP transfer whose connection drops while paused reconnects with a
+    /// range request once resumed, rather than starting over.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository, relative to the repository root.
+    /// * `destination` - The local file path where the downloaded file should be saved.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    /// * `progress` - An optional callback that receives progress updates.
+    /// * `handle` - The handle that pauses, resumes, and cancels the download.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `download_file_with_progress`.
+    pub fn download_file_with_handle(
+        &self,
+        repo: String,
+        path: String,
+        destination: String,
+        revision: Option<String>,
+        progress: Option<Box<dyn ProgressCallback>>,
+        handle: Arc<DownloadHandle>,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        let observer = TransferObserver::new(progress.map(Arc::from), None, self.callbacks.clone())
+            .with_handle(handle)
+            .with_throttle(self.throttle.clone());
+        xet_usage::scoped(observer.label(), || {
+            xet_deadline::scoped(observer.deadline(), || {
+                self.download_file_observed(repo, path, destination, revision, observer)
+            })
+        })
+    }
+
+    /// Downloads a file as part of `group`, which pauses, resumes, and
+    /// cancels it along with the group's other downloads.
+    ///
+    /// The file's progress is added to the group's, and counts as complete
+    /// once the download succeeds, including when it's served from the cache
+    /// without reporting any progress of its own. Downloads in one group can
+    /// run concurrently, each on its own thread or through the async variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository, relative to the repository root.
+    /// * `destination` - The local file path where the downloaded file should be saved.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    /// * `group` - The group the download belongs to.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `download_file_with_progress`, including
+    /// `XetError::Cancelled` if the group is cancelled.
+    pub fn download_file_in_group(
+        &self,
+        repo: String,
+        path: String,
+        destination: String,
+        revision: Option<String>,
+        group: Arc<TransferGroup>,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        let member = group.join();
+        let index = member.index;
+        let report = self.download_file_with_handle(
+            repo,
+            path,
+            destination,
+            revision,
+            Some(Box::new(member)),
+            group.handle.clone(),
+        )?;
+        let bytes = report.bytes();
+        self.callbacks
+            .dispatch(move || group.update(index, bytes, bytes));
+        self.callbacks.flush();
+        Ok(report)
+    }
+
+    /// Downloads an archive of a repository's files at a revision.
+    ///
+    /// The Hub packs the whole repository into one tarball or zip, which is
+    /// streamed to disk as it arrives. Nothing is deduplicated or fetched
+    /// from Xet storage, so this suits small repositories where a snapshot's
+    /// per-file requests cost more than they save. As with other downloads,
+    /// the destination only receives the archive once it's complete.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    /// * `destination` - The local file path where the archive should be saved.
+    /// * `format` - The format of the archive.
+    ///
+    /// # Returns
+    ///
+    /// The size of the archive in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` or `destination` is empty,
+    /// `XetError::IoError` if the archive can't be written, or
+    /// `XetError::NetworkError` if it can't be downloaded.
+    pub fn download_repo_archive(
+        &self,
+        repo: String,
+        revision: Option<String>,
+        destination: String,
+        format: ArchiveFormat,
+    ) -> Result<u64, XetError> {
+        self.download_repo_archive_with_progress(repo, revision, destination, format, None, None)
+    }
+
+    /// Downloads an archive of a repository, reporting progress and honoring cancellation.
+    ///
+    /// Progress counts the archive's bytes against its length, when the Hub
+    /// reports one. Cancellation is checked between chunks, and a cancelled or
+    /// failed download leaves nothing behind.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    /// * `destination` - The local file path where the archive should be saved.
+    /// * `format` - The format of the archive.
+    /// * `progress` - An optional callback that receives progress updates.
+    /// * `cancellation` - An optional token that cancels the download when triggered.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `download_repo_archive`, or
+    /// `XetError::Cancelled` if the download was cancelled.
+    pub fn download_repo_archive_with_progress(
+        &self,
+        repo: String,
+        revision: Option<String>,
+        destination: String,
+        format: ArchiveFormat,
+        progress: Option<Box<dyn ProgressCallback>>,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<u64, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+        if destination.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Destination cannot be empty".to_string(),
+            });
+        }
+
+        let destination = self.local_path(&destination);
+        let repo_info = self.parse_repo(&repo)?;
+        let revision = self.resolve_revision(revision);
+        let url = xet_url::archive_url(
+            self.endpoint_for(&repo_info),
+            self.repo_type_plural(&repo_info.repo_type),
+            &repo_info.full_name,
+            &revision,
+            format.extension(),
+        );
+        let observer = TransferObserver::new(
+            progress.map(Arc::from),
+            cancellation,
+            self.callbacks.clone(),
+        )
+        .with_throttle(self.throttle.clone())
+        .with_termination(self.termination.clone());
+        let _active = self.termination.track(None);
+        observer.check_cancelled()?;
+        self.prepare_destination(&destination)?;
+
+        let partial = PartialDownload::new(Path::new(&destination));
+        let result = xet_usage::scoped(observer.label(), || {
+            xet_deadline::scoped(observer.deadline(), || {
+                self.block_on(async {
+                    let response = self.http_get_direct(&url, 0).await?;
+                    xet_transfer::write_response(
+                        response,
+                        partial.path(),
+                        false,
+                        None,
+                        None,
+                        &observer,
+                    )
+                    .await
+                })
+            })
+        });
+        // Deliver outstanding progress so the final update precedes the return.
+        self.callbacks.flush();
+        let result = result
+            .map_err(|e| self.explain_access_error(&repo_info, e))
+            .and_then(|(bytes, _)| {
+                partial.finish().map_err(|e| XetError::IoError {
+                    message: format!("Failed to move archive to {}: {}", destination, e),
+                    cause: Some(ErrorCause::from(&e)),
+                })?;
+                Ok(bytes)
+            });
+        if result.is_err() {
+            // Archives are generated on request, so there's nothing to resume.
+            let _ = partial.discard();
+        }
+        result
+    }
+
+    fn download_file_observed(
+        &self,
+        repo: String,
+        path: String,
+        destination: String,
+        revision: Option<String>,
+        observer: TransferObserver,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+        if path.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Path cannot be empty".to_string(),
+            });
+        }
+        if destination.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Destination cannot be empty".to_string(),
+            });
+        }
+
+        let destination = self.local_path(&destination);
+        let repo_info = self.parse_repo(&repo)?;
+        let resolved_revision = self.resolve_revision(revision);
+        let metadata_result = self.fetch_metadata(&repo_info, &path, &resolved_revision);
+
+        let result = self
+            .check_security_status(&repo_info, &path, &resolved_revision)
+            .and_then(|_| {
+                self.download_file_with_metadata(
+                    repo_info.clone(),
+                    path.clone(),
+                    destination,
+                    resolved_revision,
+                    metadata_result,
+                    &observer,
+                )
+            })
+            .map_err(|e| self.explain_access_error(&repo_info, e));
+        if let Err(XetError::Cancelled { reason, .. }) = &result {
+            self.emit(XetEvent::TransferCancelled {
+                repo: repo_info.full_name.clone(),
+                path: path.clone(),
+                reason: *reason,
+            });
+        }
+
+        // Deliver outstanding progress so the final update precedes the return.
+        self.callbacks.flush();
+        result
+    }
+
+    fn download_file_with_metadata(
+        &self,
+        repo_info: HubRepoInfo,
+        path: String,
+        destination: String,
+        resolved_revision: String,
+        metadata_result: Result<FileResolveMetadata, XetError>,
+        observer: &TransferObserver,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        let started = Instant::now();
+        let observer = &observer.clone().with_termination(self.termination.clone());
+        let _active = self.termination.track(observer.handle());
+        observer.check_cancelled()?;
+
+        // Every source writes beside the destination, which only ever receives
+        // a complete, verified file.
+        let partial = PartialDownload::new(Path::new(&destination));
+        let staged = partial.path().to_string_lossy().to_string();
+
+        let metadata = match metadata_result {
+            Ok(metadata) => metadata,
+            Err(_) => {
+                partial.discard()?;
+                let (bytes, digest) = self.download_file_legacy(
+                    repo_info,
+                    path.clone(),
+                    staged.clone(),
+                    Some(resolved_revision),
+                    observer,
+                )?;
+                let report = self.finish_transfer(
+                    &path,
+                    &staged,
+                    DownloadSource::Legacy,
+                    bytes,
+                    started,
+                    None,
+                    digest,
+                    None,
+                    false,
+                )?;
+                partial.finish()?;
+                return Ok(report);
+            }
+        };
+
+        self.check_download_allowed(&path, Some(metadata.size), metadata.content_type.as_deref())?;
+        let expected_sha256 = xet_verify::expected_sha256(&metadata.etag);
+        if let Some(report) =
+            self.skip_if_current(&destination, &metadata, expected_sha256.as_deref(), started)
+        {
+            return Ok(report);
+        }
+
+        if let Some(xet_data) = metadata.xet_file_data.clone() {
+            self.block_on(observer.checkpoint())?;
+            let result = self
+                .block_on(self.cas_jwt(&xet_data.refresh_route))
+                .and_then(|jwt| {
+                    self.download_xet_resuming(
+                        &xet_data.file_hash,
+                        metadata.size,
+                        jwt,
+                        &partial,
+                        observer,
+                        self.config.verify_downloads,
+                    )
+                });
+            match result {
+                Ok((jwt, digest)) => {
+                    // A mismatch here is reported rather than retried over HTTP,
+                    // which would serve the same content.
+                    let report = self.finish_transfer(
+                        &path,
+                        &staged,
+                        DownloadSource::Xet,
+                        metadata.size,
+                        started,
+                        expected_sha256.as_deref(),
+                        digest,
+                        Some(&jwt),
+                        metadata.served_stale,
+                    )?;
+                    partial.finish()?;
+                    self.record_fingerprint(&destination, &metadata);
+                    return Ok(report);
+                }
+                Err(e @ (XetError::Cancelled { .. } | XetError::Timeout { .. })) => return Err(e),
+                // Keep the terms fetched for the next attempt to resume from.
+                Err(e) if partial.has_progress() => return Err(e),
+                Err(_) => {}
+            }
+        }
+
+        self.block_on(observer.checkpoint())?;
+        let (source, bytes, digest) =
+            match self.download_http_with_metadata(&metadata, &partial, observer) {
+                Ok((bytes, digest)) => (DownloadSource::Http, bytes, digest),
+                Err(e @ (XetError::Cancelled { .. } | XetError::Timeout { .. })) => return Err(e),
+                Err(e @ XetError::IntegrityError { .. }) => {
+                    return self.download_at_resolved_commit(
+                        e,
+                        repo_info,
+                        path,
+                        destination,
+                        &resolved_revision,
+                        &metadata,
+                        observer,
+                    );
+                }
+                // Keep what was fetched for the next attempt to resume from.
+                Err(e) if partial.has_progress() => return Err(e),
+                Err(_) => {
+                    partial.discard()?;
+                    let (bytes, digest) = self.download_file_legacy(
+                        repo_info.clone(),
+                        path.clone(),
+                        staged.clone(),
+                        Some(resolved_revision.clone()),
+                        observer,
+                    )?;
+                    (DownloadSource::Legacy, bytes, digest)
+                }
+            };
+
+        let report = self.finish_transfer(
+            &path,
+            &staged,
+            source,
+            bytes,
+            started,
+            expected_sha256.as_deref(),
+            digest,
+            None,
+            metadata.served_stale,
+        );
+        if report.is_err() {
+            partial.discard()?;
+        }
+        let report = match report {
+            Err(e @ XetError::IntegrityError { .. }) if source == DownloadSource::Http => {
+                return self.download_at_resolved_commit(
+                    e,
+                    repo_info,
+                    path,
+                    destination,
+                    &resolved_revision,
+                    &metadata,
+                    observer,
+                );
+            }
+            report => report?,
+        };
+        partial.finish()?;
+        self.record_fingerprint(&destination, &metadata);
+        Ok(report)
+    }
+
+    /// Downloads a file again at the commit `metadata` resolved its revision
+    /// to, after the content fetched over HTTP contradicted the resolution.
+    ///
+    /// Content is fetched by revision, so a branch that moves after resolving
+    /// can serve another commit's file; a commit can't move, so the file is
+    /// fetched consistently with the resolution, and with any other files
+    /// downloaded at that commit. Content that contradicts a resolution made
+    /// at a commit is corrupt rather than moved, and is reported as `error`.
+    #[allow(clippy::too_many_arguments)]
+    fn download_at_resolved_commit(
+        &self,
+        error: XetError,
+        repo_info: HubRepoInfo,
+        path: String,
+        destination: String,
+        revision: &str,
+        metadata: &FileResolveMetadata,
+        observer: &TransferObserver,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        let commit = metadata.commit_hash.clone();
+        if commit.is_empty() || commit == revision {
+            return Err(error);
+        }
+        self.emit(XetEvent::RevisionMoved {
+            repo: repo_info.full_name.clone(),
+            path: path.clone(),
+            revision: revision.to_string(),
+            commit: commit.clone(),
+        });
+        let metadata_result = self.fetch_metadata(&repo_info, &path, &commit);
+        self.check_security_status(&repo_info, &path, &commit)?;
+        self.download_file_with_metadata(
+            repo_info,
+            path,
+            destination,
+            commit,
+            metadata_result,
+            observer,
+        )
+    }
+
+    /// Returns a report for `destination` if it already holds the resolved content.
+    ///
+    /// A fingerprint recorded by an earlier download answers without reading
+    /// the file. Failing that, a file of the right size is hashed when the etag
+    /// names its SHA-256, and fingerprinted if it matches.
+    fn skip_if_current(
+        &self,
+        destination: &str,
+        metadata: &FileResolveMetadata,
+        expected_sha256: Option<&str>,
+        started: Instant,
+    ) -> Option<Arc<TransferReport>> {
+        let path = Path::new(destination);
+        let (verification, verification_elapsed) =
+            if FingerprintCache::open(&self.cache).matches(path, metadata) {
+                (VerificationStatus::NotRequested, Duration::ZERO)
+            } else {
+                let expected = expected_sha256?;
+                fs::metadata(path)
+                    .ok()
+                    .filter(|file| file.is_file() && file.len() == metadata.size)?;
+                let (actual, elapsed) = xet_verify::hash_file(path).ok()?;
+                if actual != expected {
+                    return None;
+                }
+                self.record_fingerprint(destination, metadata);
+                (VerificationStatus::Verified, elapsed)
+            };
+
+        Some(Arc::new(TransferReport {
+            source: DownloadSource::Skipped,
+            bytes: metadata.size,
+            elapsed: started.elapsed(),
+            verification,
+            verification_elapsed,
+            cas_endpoint: None,
+            cas_endpoint_latencies: Vec::new(),
+            served_stale: metadata.served_stale,
+        }))
+    }
+
+    /// Records that `destination` holds the content in `metadata`, so later
+    /// downloads of the same content can skip it.
+    fn record_fingerprint(&self, destination: &str, metadata: &FileResolveMetadata) {
+        let fingerprints = FingerprintCache::open(&self.cache);
+        if let Err(e) = fingerprints.store(Path::new(destination), metadata) {
+            self.diagnostic(format_args!(
+                "Failed to record fingerprint of {}: {}",
+                destination, e
+            ));
+        }
+    }
+
+    /// Checks a completed download against its expected hash and builds its report.
+    #[allow(clippy::too_many_arguments)]
+    fn finish_transfer(
+        &self,
+        path: &str,
+        destination: &str,
+        source: DownloadSource,
+        bytes: u64,
+        started: Instant,
+        expected_sha256: Option<&str>,
+        digest: Option<(String, Duration)>,
+        cas: Option<&CasJwtInfo>,
+        served_stale: bool,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        let (verification, verification_elapsed) = match (expected_sha256, digest) {
+            (_, None) if !self.config.verify_downloads => {
+                (VerificationStatus::NotRequested, Duration::ZERO)
+            }
+            (Some(expected), Some((actual, elapsed))) if actual == expected => {
+                (VerificationStatus::Verified, elapsed)
+            }
+            (Some(expected), Some((_, elapsed))) => {
+                // Confirm with a full read before treating the file as corrupt.
+                let (actual, rehash_elapsed) = xet_verify::hash_file(Path::new(destination))?;
+                if actual != expected {
+                    let _ = fs::remove_file(destination);
+                    return Err(xet_verify::mismatch_error(path, expected, &actual));
+                }
+                (VerificationStatus::Verified, elapsed + rehash_elapsed)
+            }
+            _ => (VerificationStatus::Unavailable, Duration::ZERO),
+        };
+
+        self.usage
+            .record_download(self.usage_label(), bytes, started.elapsed());
+        Ok(Arc::new(TransferReport {
+            source,
+            bytes,
+            elapsed: started.elapsed(),
+            verification,
+            verification_elapsed,
+            cas_endpoint: cas.map(CasJwtInfo::cas_url),
+            cas_endpoint_latencies: cas
+                .map(CasJwtInfo::endpoint_latencies)
+                .unwrap_or_default(),
+            served_stale,
+        }))
+    }
+
+    /// Downloads multiple files in a single batch operation.
+    ///
+    /// Up to `max_concurrent_downloads` files are fetched at once. If any download
+    /// fails, transfers in flight are cancelled, no further files are started, and
+    /// an error is returned. Files downloaded before the failure are kept, unless
+    /// the client's `failure_policy` is `RollBack`. Use
+    /// `download_files_batch_with_results` to download every file regardless,
+    /// or `download_files_batch_detailed` to learn what became of each file.
+    ///
+    /// Destinations that would collide, including ones that differ only in case
+    /// on a case-insensitive filesystem, are handled according to the client's
+    /// `collision_strategy` before any file is downloaded.
+    ///
+    /// By default each revision is resolved to a commit once, and every file is
+    /// downloaded at that commit even if the branch advances partway through.
+    /// Set the client's `revision_pinning` to `FollowHead` to resolve it per file.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - An array of `FileDownloadRequest` objects, each specifying a file to download.
+    /// * `allow_patterns` - Glob patterns selecting the requests to download, such as `"*.safetensors"`.
+    ///   Pass an empty array to download every request.
+    /// * `ignore_patterns` - Glob patterns for requests to skip, applied after `allow_patterns`.
+    ///
+    /// # Returns
+    ///
+    /// An array of destination paths for successfully downloaded files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if a pattern is invalid, `XetError::OperationFailed`
+    /// if any download fails, with details about which file failed and why, or
+    /// `XetError::DestinationCollision` if two requests share a destination and the
+    /// client's collision strategy is `Fail`.
+    pub fn download_files_batch(
+        &self,
+        requests: Vec<Arc<FileDownloadRequest>>,
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+    ) -> Result<Vec<String>, XetError> {
+        let filter = PathFilter::new(allow_patterns, ignore_patterns)?;
+        self.download_requests(
+            filter_requests(requests, &filter),
+            None,
+            |request, _, _, _| Ok(request.destination()),
+        )
+    }
+
+    /// Returns the requests whose destinations don't already hold their file.
+    ///
+    /// Only local files and records are consulted, so checking a folder that's
+    /// already downloaded makes no requests. A destination counts as present if
+    /// it's a file that, when a download recorded it, hasn't changed since.
+    /// With `serve_stale_metadata`, whose resolutions are recorded, a file is
+    /// also missing if its last resolution has a different etag from the one
+    /// it was downloaded with. Files placed without a download are judged by
+    /// existence alone. A revision that moved since isn't noticed without a
+    /// request to the Hub; `download_file` skips current files after one.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - The requests to check.
+    ///
+    /// # Returns
+    ///
+    /// The requests that need downloading, in their original order.
+    pub fn filter_missing(
+        &self,
+        requests: Vec<Arc<FileDownloadRequest>>,
+    ) -> Vec<Arc<FileDownloadRequest>> {
+        let fingerprints = FingerprintCache::open(&self.cache);
+        let resolutions = ResolutionCache::open(&self.cache);
+        requests
+            .into_iter()
+            .filter(|request| {
+                let destination = self.local_path(&request.destination());
+                let destination = Path::new(&destination);
+                if !destination.is_file() {
+                    return true;
+                }
+                if !fingerprints.has_record(destination) {
+                    return false;
+                }
+                let Some(etag) = fingerprints.unchanged_etag(destination) else {
+                    return true;
+                };
+                let Ok(repo_info) = self.parse_repo(&request.repo()) else {
+                    return true;
+                };
+                let (path, revision) = (request.path(), self.resolve_revision(request.revision()));
+                let key = self.resolution_key(&repo_info, &path, &revision);
+                resolutions
+                    .load(&key)
+                    .is_some_and(|resolved| resolved.etag != etag)
+            })
+            .collect()
+    }
+
+    /// Downloads multiple files, reporting the outcome of each one.
+    ///
+    /// Unlike `download_files_batch`, a failed file doesn't stop the others:
+    /// every file is attempted, with up to `max_concurrent_downloads` at once,
+    /// and its report or error is returned in request order. Destinations are
+    /// resolved and checked for collisions before any file is downloaded. With
+    /// the `RollBack` failure policy the batch is still all or nothing, so any
+    /// failure undoes the operation and is returned as an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - An array of `FileDownloadRequest` objects, each specifying a file to download.
+    /// * `allow_patterns` - Glob patterns selecting the requests to download, such as `"*.safetensors"`.
+    ///   Pass an empty array to download every request.
+    /// * `ignore_patterns` - Glob patterns for requests to skip, applied after `allow_patterns`.
+    ///
+    /// # Returns
+    ///
+    /// The outcome of each selected request, in the order they were given.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if a pattern is invalid, `XetError::PolicyViolation`
+    /// if the files exceed `max_snapshot_size_bytes`, `XetError::DestinationCollision` if two
+    /// requests share a destination and the client's collision strategy is `Fail`, or
+    /// `XetError::OperationFailed` if a download fails and the failure policy is `RollBack`.
+    pub fn download_files_batch_with_results(
+        &self,
+        requests: Vec<Arc<FileDownloadRequest>>,
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+    ) -> Result<Vec<Arc<FileDownloadResult>>, XetError> {
+        let filter = PathFilter::new(allow_patterns, ignore_patterns)?;
+        let (targets, metadata) = self.plan_downloads(
+            filter_requests(requests, &filter),
+            None,
+            |request, _, _, _| Ok(request.destination()),
+        )?;
+        let results = self.download_planned(&targets, metadata, false)?;
+        Ok(self.file_results(targets, results).collect())
+    }
+
+    /// Downloads multiple files, accounting for every request in the result.
+    ///
+    /// Each request is reported as downloaded, failed, or skipped, including
+    /// requests excluded by the patterns. With `continue_on_error`, every
+    /// selected file is attempted, as in `download_files_batch_with_results`.
+    /// Otherwise the batch stops at the first failure, as in
+    /// `download_files_batch`, and files that hadn't started are skipped; files
+    /// in flight when it stopped fail with `XetError::Cancelled`. Either way, a
+    /// failed file is reported rather than returned as an error, unless the
+    /// client's `failure_policy` is `RollBack`.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - An array of `FileDownloadRequest` objects, each specifying a file to download.
+    /// * `allow_patterns` - Glob patterns selecting the requests to download, such as `"*.safetensors"`.
+    ///   Pass an empty array to download every request.
+    /// * `ignore_patterns` - Glob patterns for requests to skip, applied after `allow_patterns`.
+    /// * `continue_on_error` - Whether to keep downloading the remaining files after one fails.
+    ///
+    /// # Returns
+    ///
+    /// The outcome of every request, in the order they were given.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if a pattern is invalid, `XetError::PolicyViolation`
+    /// if the files exceed `max_snapshot_size_bytes`, `XetError::DestinationCollision` if two
+    /// requests share a destination and the client's collision strategy is `Fail`, or
+    /// `XetError::OperationFailed` if a download fails and the failure policy is `RollBack`.
+    pub fn download_files_batch_detailed(
+        &self,
+        requests: Vec<Arc<FileDownloadRequest>>,
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+        continue_on_error: bool,
+    ) -> Result<Arc<BatchDownloadResult>, XetError> {
+        let filter = PathFilter::new(allow_patterns, ignore_patterns)?;
+        let included: Vec<bool> = requests
+            .iter()
+            .map(|request| filter.includes(&request.path()))
+            .collect();
+        let selected = filter_requests(requests.clone(), &filter);
+        let (targets, metadata) =
+            self.plan_downloads(selected, None, |request, _, _, _| Ok(request.destination()))?;
+        let outcomes = self.download_planned(&targets, metadata, !continue_on_error)?;
+
+        let mut planned = self.file_results(targets, outcomes);
+        let results = requests
+            .into_iter()
+            .zip(included)
+            .filter_map(|(request, included)| match included {
+                true => planned.next(),
+                false => Some(Arc::new(FileDownloadResult {
+                    destination: request.destination(),
+                    request,
+                    status: FileDownloadStatus::Skipped,
+                    report: None,
+                    error: None,
+                })),
+            })
+            .collect();
+        Ok(Arc::new(BatchDownloadResult { results }))
+    }
+
+    /// Pairs each planned file with its outcome, explaining any failure.
+    fn file_results<'a>(
+        &'a self,
+        targets: Vec<DownloadTarget>,
+        outcomes: Vec<BatchOutcome>,
+    ) -> impl Iterator<Item = Arc<FileDownloadResult>> + 'a {
+        targets.into_iter().zip(outcomes).map(|(target, outcome)| {
+            let outcome = outcome
+                .map(|result| result.map_err(|e| self.explain_access_error(&target.repo_info, e)));
+            let status = match &outcome {
+                Some(Ok(_)) => FileDownloadStatus::Downloaded,
+                Some(Err(_)) => FileDownloadStatus::Failed,
+                None if target.unreadable => FileDownloadStatus::Unauthorized,
+                None => FileDownloadStatus::Skipped,
+            };
+            Arc::new(FileDownloadResult {
+                request: target.request,
+                destination: target.destination,
+                status,
+                report: outcome
+                    .as_ref()
+                    .and_then(|result| result.as_ref().ok())
+                    .cloned(),
+                error: match outcome {
+                    Some(result) => result.err(),
+                    None if target.unreadable => Some(self.read_denied(&target.repo_info)),
+                    None => None,
+                },
+            })
+        })
+    }
+
+    /// Downloads multiple files, naming each destination from a template.
+    ///
+    /// Instead of each request's own destination, files are written to
+    /// `destination_root` joined with `template` rendered for that file. This lets
+    /// apps that organize storage by model and version download straight into
+    /// place rather than moving gigabytes afterwards.
+    ///
+    /// The following placeholders are supported:
+    ///
+    /// * `{repo_type}` - `model`, `dataset`, or `space`.
+    /// * `{repo_owner}` and `{repo_name}` - The two halves of the repository's full name.
+    /// * `{revision}` - The requested revision, with `/` replaced by `--`.
+    /// * `{commit}` - The resolved commit hash, or the revision if it can't be resolved.
+    /// * `{revision_short}` - The first seven characters of the resolved commit hash,
+    ///   or the revision if it can't be resolved.
+    /// * `{path}` - The file's path within the repository.
+    /// * `{filename}` - The last component of the file's path.
+    ///
+    /// Requests are downloaded concurrently and the operation stops at the first failure,
+    /// handled according to the client's `failure_policy` like `download_files_batch`.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - An array of `FileDownloadRequest` objects, each specifying a file to download.
+    /// * `destination_root` - The local directory that rendered destinations are relative to.
+    /// * `template` - The destination naming template, such as `"{repo_name}/{revision_short}/{path}"`.
+    /// * `allow_patterns` - Glob patterns selecting the requests to download, such as `"*.safetensors"`.
+    ///   Pass an empty array to download every request.
+    /// * `ignore_patterns` - Glob patterns for requests to skip, applied after `allow_patterns`.
+    ///
+    /// # Returns
+    ///
+    /// An array of destination paths for successfully downloaded files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if the template or a pattern is invalid or the
+    /// template renders a path outside `destination_root`, `XetError::DestinationCollision` if two files render
+    /// to the same destination and the client's collision strategy is `Fail`, or
+    /// `XetError::OperationFailed` if any download fails.
+    pub fn download_files_batch_with_template(
+        &self,
+        requests: Vec<Arc<FileDownloadRequest>>,
+        destination_root: String,
+        template: String,
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+    ) -> Result<Vec<String>, XetError> {
+        if destination_root.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Destination root cannot be empty".to_string(),
+            });
+        }
+        let template = DestinationTemplate::parse(&template)?;
+        let filter = PathFilter::new(allow_patterns, ignore_patterns)?;
+        let root = Path::new(&destination_root);
+        let requests = filter_requests(requests, &filter);
+
+        self.download_requests(requests, None, |request, repo_info, revision, metadata| {
+            Ok(template
+                .render(
+                    root,
+                    &TemplateContext {
+                        repo_type: repo_info.repo_type.as_str(),
+                        repo_full_name: &repo_info.full_name,
+                        revision,
+                        commit: metadata.map(|metadata| metadata.commit_hash.as_str()),
+                        path: &request.path(),
+                    },
+                )?
+                .to_string_lossy()
+                .to_string())
+        })
+    }
+
+    /// Downloads every file in a repository into a local directory.
+    ///
+    /// The revision is resolved to a commit once, and the tree is listed and
+    /// downloaded at that commit, so a push during the download can't mix files
+    /// from two revisions. When every allow pattern names an exact path, those
+    /// paths are resolved directly rather than listing the whole tree. Each file
+    /// is written to `destination_dir` at its path within the repository,
+    /// creating subdirectories as needed. Xet-backed, LFS, and regular files are
+    /// all supported. If a file fails, the files already written are handled
+    /// according to the client's `failure_policy`. Zero-byte and placeholder
+    /// files are handled according to its `placeholder_file_policy`.
+    ///
+    /// With a `destination_template`, each file is instead written to
+    /// `destination_dir` joined with the template rendered for that file, using
+    /// the placeholders of `download_files_batch_with_template`.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    /// * `destination_dir` - The local directory the repository's files are written to.
+    /// * `allow_patterns` - Glob patterns selecting the files to download, such as `"*.safetensors"`.
+    ///   Pass an empty array to download every file.
+    /// * `ignore_patterns` - Glob patterns for files to skip, applied after `allow_patterns`.
+    /// * `destination_template` - An optional destination naming template, such as
+    ///   `"{repo_name}/{revision_short}/{path}"`. If `None`, files keep their paths within the repository.
+    ///
+    /// # Returns
+    ///
+    /// The local paths of the downloaded files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if any parameter is invalid or the template renders
+    /// a path outside `destination_dir`, `XetError::PolicyViolation` if the selected files
+    /// exceed `max_snapshot_size_bytes`, or `XetError::OperationFailed` if any download fails.
+    pub fn snapshot_download(
+        &self,
+        repo: String,
+        revision: Option<String>,
+        destination_dir: String,
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+        destination_template: Option<String>,
+    ) -> Result<Vec<String>, XetError> {
+        if destination_dir.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Destination directory cannot be empty".to_string(),
+            });
+        }
+        let template = destination_template
+            .as_deref()
+            .map(DestinationTemplate::parse)
+            .transpose()?;
+        let filter = PathFilter::new(allow_patterns, ignore_patterns)?;
+        let repo_info = self.parse_repo(&repo)?;
+        let revision = self.resolve_revision(revision);
+
+        let commit = self
+            .resolve_commit(&repo_info, &revision)
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+        // Patterns naming exact files are resolved directly, so a few files can be
+        // taken from a large repository without listing its whole tree.
+        let expand = self.screens_files();
+        let entries = match filter.literal_paths() {
+            Some(paths) => self.fetch_paths_info(&repo_info, &commit, paths, expand),
+            None => self.list_repo_files(&repo_info, "", &commit, expand),
+        };
+        let files: Vec<TreeEntry> = entries
+            .map_err(|e| self.explain_access_error(&repo_info, e))?
+            .into_iter()
+            .filter(|entry| entry.entry_type == "file" && filter.includes(&entry.path))
+            .collect();
+        let root = Path::new(&destination_dir);
+        self.download_listed_files(&repo, &commit, files, |entry| match &template {
+            Some(template) => {
+                let context = TemplateContext {
+                    repo_type: repo_info.repo_type.as_str(),
+                    repo_full_name: &repo_info.full_name,
+                    revision: &revision,
+                    commit: Some(&commit),
+                    path: &entry.path,
+                };
+                Ok(template
+                    .render(root, &context)?
+                    .to_string_lossy()
+                    .to_string())
+            }
+            None => repo_file_path(root, &entry.path),
+        })
+    }
+
+    /// Downloads the files under a folder of a repository into a local directory.
+    ///
+    /// A narrower sibling of `snapshot_download` for repositories where only
+    /// part of the tree is wanted, such as `onnx/`. The revision is resolved to
+    /// a commit once, and each file is written to `destination_dir` at its path
+    /// relative to `folder_path`, so `onnx/model.onnx` becomes
+    /// `<destination_dir>/model.onnx`. Failures and zero-byte or placeholder files
+    /// are handled according to the client's `failure_policy` and
+    /// `placeholder_file_policy`, as in `snapshot_download`.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `folder_path` - The path of the folder within the repository, relative to the repository root.
+    /// * `destination_dir` - The local directory the folder's contents are written to.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// The local paths of the downloaded files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if any parameter is invalid, `XetError::PolicyViolation`
+    /// if the folder's files exceed `max_snapshot_size_bytes`, or `XetError::OperationFailed`
+    /// if any download fails.
+    pub fn download_folder(
+        &self,
+        repo: String,
+        folder_path: String,
+        destination_dir: String,
+        revision: Option<String>,
+    ) -> Result<Vec<String>, XetError> {
+        let folder = folder_path.trim_matches('/');
+        if folder.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Folder path cannot be empty".to_string(),
+            });
+        }
+        if destination_dir.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Destination directory cannot be empty".to_string(),
+            });
+        }
+        let repo_info = self.parse_repo(&repo)?;
+        let revision = self.resolve_revision(revision);
+
+        let commit = self
+            .resolve_commit(&repo_info, &revision)
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+        let files = self
+            .list_repo_files(&repo_info, folder, &commit, self.screens_files())
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+        let prefix = format!("{}/", folder);
+        self.download_listed_files(&repo, &commit, files, |entry| {
+            let relative = entry.path.strip_prefix(&prefix).unwrap_or(&entry.path);
+            repo_file_path(Path::new(&destination_dir), relative)
+        })
+    }
+
+    /// Downloads listed files at `commit`, writing each to the path `local_path` gives it.
+    ///
+    /// The combined size is checked against `max_snapshot_size_bytes` before
+    /// anything is downloaded. Zero-byte and placeholder files are handled
+    /// according to the client's `placeholder_file_policy`; files created
+    /// locally are only written once every download has succeeded, so a failed
+    /// operation leaves none of them behind.
+    fn download_listed_files<F>(
+        &self,
+        repo: &str,
+        commit: &str,
+        files: Vec<TreeEntry>,
+        local_path: F,
+    ) -> Result<Vec<String>, XetError>
+    where
+        F: Fn(&TreeEntry) -> Result<String, XetError>,
+    {
+        // Listings made while screening files include their scan results.
+        let flagged = flagged_entries(&files);
+        let policy = self.config.placeholder_file_policy;
+        let (empty, files): (Vec<TreeEntry>, Vec<TreeEntry>) = files
+            .into_iter()
+            .filter(|entry| {
+                policy != PlaceholderFilePolicy::Skip
+                    || !is_placeholder_file(&entry.path, entry.size)
+            })
+            .partition(|entry| {
+                policy == PlaceholderFilePolicy::CreateLocally && entry.size == Some(0)
+            });
+
+        let total_size = files
+            .iter()
+            .filter_map(|entry| entry.size)
+            .fold(0u64, u64::saturating_add);
+        self.check_total_size(total_size)?;
+
+        let empty = empty
+            .iter()
+            .map(|entry| {
+                self.check_download_allowed(&entry.path, Some(0), None)?;
+                Ok(self.local_path(&local_path(entry)?))
+            })
+            .collect::<Result<Vec<_>, XetError>>()?;
+        let requests = files
+            .into_iter()
+            .map(|entry| {
+                let destination = local_path(&entry)?;
+                Ok(Arc::new(FileDownloadRequest::new(
+                    repo.to_string(),
+                    entry.path,
+                    destination,
+                    Some(commit.to_string()),
+                )))
+            })
+            .collect::<Result<Vec<_>, XetError>>()?;
+
+        let mut downloaded =
+            self.download_requests(requests, Some(&flagged), |request, _, _, _| {
+                Ok(request.destination())
+            })?;
+        for destination in empty {
+            create_empty_file(Path::new(&destination)).map_err(|e| XetError::IoError {
+                message: format!("Failed to create {}: {}", destination, e),
+                cause: Some(ErrorCause::from(&e)),
+            })?;
+            downloaded.push(destination);
+        }
+        Ok(downloaded)
+    }
+
+    /// Downloads requests concurrently, stopping at the first failure.
+    ///
+    /// Files that haven't started when one fails are skipped, and transfers
+    /// already in flight are cancelled. The first failure in request order is
+    /// returned.
+    fn download_requests<F>(
+        &self,
+        requests: Vec<Arc<FileDownloadRequest>>,
+        flagged: Option<&HashMap<String, String>>,
+        destination_for: F,
+    ) -> Result<Vec<String>, XetError>
+    where
+        F: FnMut(
+            &FileDownloadRequest,
+            &HubRepoInfo,
+            &str,
+            Option<&FileResolveMetadata>,
+        ) -> Result<String, XetError>,
+    {
+        let (targets, metadata) = self.plan_downloads(requests, flagged, destination_for)?;
+        let mut results = self.download_planned(&targets, metadata, true)?;
+        if let Some((index, error)) = take_first_failure(&mut results) {
+            return Err(self.batch_error(&targets[index], error));
+        }
+        Ok(targets
+            .into_iter()
+            .filter(|target| !target.unreadable)
+            .map(|target| target.destination)
+            .collect())
+    }
+
+    /// Resolves metadata and destinations for every request before any download starts.
+    ///
+    /// Metadata is fetched concurrently, so the combined size can be checked
+    /// against `max_snapshot_size_bytes` and destination collisions are handled
+    /// up front. With `Pinned` revisions, the first file of each repository and
+    /// revision pins it to a commit, and every other file is resolved directly
+    /// at that commit. Files are screened according to `unsafe_file_policy`
+    /// using `flagged`, the scan results of a listing, if given.
+    fn plan_downloads<F>(
+        &self,
+        requests: Vec<Arc<FileDownloadRequest>>,
+        flagged: Option<&HashMap<String, String>>,
+        mut destination_for: F,
+    ) -> Result<(Vec<DownloadTarget>, Vec<MetadataResult>), XetError>
+    where
+        F: FnMut(
+            &FileDownloadRequest,
+            &HubRepoInfo,
+            &str,
+            Option<&FileResolveMetadata>,
+        ) -> Result<String, XetError>,
+    {
+        let mut sources = Vec::with_capacity(requests.len());
+        for request in requests {
+            let repo_info = self.parse_repo(&request.repo())?;
+            let revision = self.resolve_revision(request.revision());
+            sources.push((request, repo_info, revision));
+        }
+
+        // The commit each repository and revision resolved to first, keyed by
+        // `(repo_type, full_name, revision)`.
+        let mut pinned_commits: HashMap<(String, String, String), String> = HashMap::new();
+        let pin_key = |repo_info: &HubRepoInfo, revision: &str| {
+            (
+                repo_info.repo_type.as_str().to_string(),
+                repo_info.full_name.clone(),
+                revision.to_string(),
+            )
+        };
+        let mut resolved: Vec<_> = sources.iter().map(|_| None).collect();
+        if self.config.revision_pinning == RevisionPinning::Pinned {
+            for ((request, repo_info, revision), resolved) in sources.iter().zip(&mut resolved) {
+                let key = pin_key(repo_info, revision);
+                if !pinned_commits.contains_key(&key) {
+                    *resolved = Some(self.fetch_pinned_metadata(
+                        repo_info,
+                        &request.path(),
+                        key,
+                        &mut pinned_commits,
+                    ));
+                }
+            }
+        }
+        let resolved = map_bounded(
+            sources.iter().zip(resolved).collect(),
+            self.max_concurrent_downloads(),
+            |((request, repo_info, revision), resolved)| {
+                resolved.unwrap_or_else(|| {
+                    let revision = pinned_commits
+                        .get(&pin_key(repo_info, revision))
+                        .unwrap_or(revision)
+                        .clone();
+                    let metadata_result =
+                        self.fetch_metadata(repo_info, &request.path(), &revision);
+                    (revision, metadata_result)
+                })
+            },
+        );
+
+        let mut targets = Vec::with_capacity(sources.len());
+        let mut metadata = Vec::with_capacity(sources.len());
+        let mut total_size: u64 = 0;
+        let mut collisions = CollisionDetector::new(self.config.collision_strategy);
+        let mut unreadable_repos: HashMap<(String, String), bool> = HashMap::new();
+        let mut events = Vec::new();
+        for ((request, repo_info, resolved_revision), (download_revision, metadata_result)) in
+            sources.into_iter().zip(resolved)
+        {
+            if let Ok(metadata) = &metadata_result {
+                total_size = total_size.saturating_add(metadata.size);
+                self.check_total_size(total_size)?;
+            }
+
+            let path = request.path();
+            let mut destination = self.local_path(&destination_for(
+                &request,
+                &repo_info,
+                &resolved_revision,
+                metadata_result.as_ref().ok(),
+            )?);
+
+            let source = format!("{}@{}:{}", repo_info.full_name, resolved_revision, path);
+            if let Claim::Renamed(renamed_to) = collisions.claim(Path::new(&destination), &source)? {
+                let renamed_to = renamed_to.to_string_lossy().to_string();
+                events.push(XetEvent::DestinationRenamed {
+                    repo: repo_info.full_name.clone(),
+                    path,
+                    destination,
+                    renamed_to: renamed_to.clone(),
+                });
+                destination = renamed_to;
+            }
+
+            // Only files that failed to resolve are checked, once per repository.
+            let unreadable = metadata_result.is_err()
+                && self.config.skip_inaccessible_repos
+                && *unreadable_repos
+                    .entry((
+                        repo_info.repo_type.as_str().to_string(),
+                        repo_info.full_name.clone(),
+                    ))
+                    .or_insert_with(|| {
+                        let unreadable = matches!(
+                            self.probe_access(&repo_info, RepoOperation::Read),
+                            Ok(false)
+                        );
+                        if unreadable {
+                            events.push(XetEvent::RepoSkipped {
+                                repo: repo_info.full_name.clone(),
+                            });
+                        }
+                        unreadable
+                    });
+
+            targets.push(DownloadTarget {
+                request,
+                repo_info,
+                destination,
+                revision: download_revision,
+                unreadable,
+                security_status: None,
+            });
+            metadata.push(metadata_result);
+        }
+        self.screen_targets(&mut targets, flagged)?;
+
+        for event in events {
+            self.emit(event);
+        }
+        Ok((targets, metadata))
+    }
+
+    /// Records the status the Hub's security scanner flagged each target with.
+    ///
+    /// Statuses come from `flagged` when the targets were listed with their
+    /// scan results. Otherwise the files of each repository and revision are
+    /// looked up together, rather than with a request per file.
+    fn screen_targets(
+        &self,
+        targets: &mut [DownloadTarget],
+        flagged: Option<&HashMap<String, String>>,
+    ) -> Result<(), XetError> {
+        if !self.screens_files() {
+            return Ok(());
+        }
+        if let Some(flagged) = flagged {
+            for target in targets.iter_mut() {
+                target.security_status = flagged.get(&target.request.path()).cloned();
+            }
+            return Ok(());
+        }
+
+        let mut groups: HashMap<(String, String, String), Vec<usize>> = HashMap::new();
+        for (index, target) in targets.iter().enumerate() {
+            if !target.unreadable {
+                let key = (
+                    target.repo_info.repo_type.as_str().to_string(),
+                    target.repo_info.full_name.clone(),
+                    target.revision.clone(),
+                );
+                groups.entry(key).or_default().push(index);
+            }
+        }
+        for indices in groups.into_values() {
+            let paths: Vec<String> = indices
+                .iter()
+                .map(|&index| targets[index].request.path())
+                .collect();
+            let first = &targets[indices[0]];
+            let flagged = self
+                .flagged_files(&first.repo_info, &first.revision, &paths)
+                .map_err(|e| self.explain_access_error(&first.repo_info, e))?;
+            for (index, path) in indices.into_iter().zip(paths) {
+                targets[index].security_status = flagged.get(&path).cloned();
+            }
+        }
+        Ok(())
+    }
+
+    /// Downloads planned files with up to `max_concurrent_downloads` at once.
+    ///
+    /// Files start in order of their requests' priorities, and results are
+    /// returned in the order of `targets`. With `fail_fast`, a
+    /// failure cancels the transfers in flight and leaves the rest unstarted,
+    /// marked `None`. With the `RollBack` failure policy, every destination is
+    /// journaled before the first transfer starts, and any failure undoes the
+    /// whole operation and is returned as an error.
+    fn download_planned(
+        &self,
+        targets: &[DownloadTarget],
+        metadata: Vec<MetadataResult>,
+        fail_fast: bool,
+    ) -> Result<Vec<BatchOutcome>, XetError> {
+        let journal = match self.config.failure_policy {
+            FailurePolicy::KeepCompleted => None,
+            FailurePolicy::RollBack => {
+                let mut journal = OperationJournal::new();
+                for target in targets {
+                    if let Err(e) = journal.prepare(Path::new(&target.destination)) {
+                        let error = XetError::IoError {
+                            message: format!("Failed to prepare {}: {}", target.destination, e),
+                            cause: Some(ErrorCause::from(&e)),
+                        };
+                        return Err(roll_back_batch(
+                            journal,
+                            self.batch_error(target, error),
+                            &[],
+                        ));
+                    }
+                }
+                Some(journal)
+            }
+        };
+
+        let stop = Arc::new(CancellationToken::new());
+        let mut results = map_prioritized(
+            targets.iter().zip(metadata).collect(),
+            self.max_concurrent_downloads(),
+            |(target, _)| target.request.priority(),
+            |(target, metadata_result)| {
+                if target.unreadable || stop.is_cancelled() {
+                    return None;
+                }
+                let observer =
+                    TransferObserver::new(None, Some(stop.clone()), self.callbacks.clone())
+                        .with_throttle(self.throttle.clone());
+                let path = target.request.path();
+                let result = self
+                    .apply_unsafe_file_policy(
+                        &target.repo_info,
+                        &path,
+                        target.security_status.clone(),
+                    )
+                    .and_then(|_| {
+                        self.download_file_with_metadata(
+                            target.repo_info.clone(),
+                            path,
+                            target.destination.clone(),
+                            target.revision.clone(),
+                            metadata_result,
+                            &observer,
+                        )
+                    });
+                if fail_fast && result.is_err() {
+                    stop.cancel();
+                }
+                Some(result)
+            },
+        );
+
+        let Some(journal) = journal else {
+            return Ok(results);
+        };
+        let failed: Vec<&DownloadTarget> = targets
+            .iter()
+            .zip(&results)
+            .filter(|(_, result)| matches!(result, Some(Err(_))))
+            .map(|(target, _)| target)
+            .collect();
+        match take_first_failure(&mut results) {
+            Some((index, error)) => Err(roll_back_batch(
+                journal,
+                self.batch_error(&targets[index], error),
+                &failed,
+            )),
+            None => {
+                journal.commit();
+                Ok(results)
+            }
+        }
+    }
+
+    /// Explains a file's failure and attributes it to the file, unless it concerns the whole batch.
+    fn batch_error(&self, target: &DownloadTarget, error: XetError) -> XetError {
+        match self.explain_access_error(&target.repo_info, error) {
+            e @ (XetError::PolicyViolation { .. }
+            | XetError::AuthRequired { .. }
+            | XetError::ServiceUnavailable { .. }) => e,
+            e => XetError::OperationFailed {
+                message: format!("Failed to download {}: {}", target.request.path(), e),
+                cause: e.cause(),
+            },
+        }
+    }
+
+    /// Returns the number of files a batch transfers at once.
+    fn max_concurrent_downloads(&self) -> usize {
+        self.config
+            .max_concurrent_downloads
+            .map_or(DEFAULT_MAX_CONCURRENT_DOWNLOADS, |limit| limit as usize)
+    }
+
+    /// Resolves metadata for a file at the commit its revision is pinned to.
+    ///
+    /// The first file for a revision pins it to the commit the Hub reports. That
+    /// file's metadata is then resolved again at the commit, so that no transfer
+    /// in the batch refers to the revision by a name that can move.
+    fn fetch_pinned_metadata(
+        &self,
+        repo_info: &HubRepoInfo,
+        path: &str,
+        key: (String, String, String),
+        pinned_commits: &mut HashMap<(String, String, String), String>,
+    ) -> (String, Result<FileResolveMetadata, XetError>) {
+        if let Some(commit) = pinned_commits.get(&key) {
+            return (commit.clone(), self.fetch_metadata(repo_info, path, commit));
+        }
+
+        let revision = key.2.clone();
+        let metadata_result = self.fetch_metadata(repo_info, path, &revision);
+        let commit = match &metadata_result {
+            Ok(metadata) if !metadata.commit_hash.is_empty() => metadata.commit_hash.clone(),
+            _ => return (revision, metadata_result),
+        };
+        pinned_commits.insert(key, commit.clone());
+        if commit == revision {
+            return (revision, metadata_result);
+        }
+        (commit.clone(), self.fetch_metadata(repo_info, path, &commit))
+    }
+
+    /// Retrieves a JWT token for accessing the Content-Addressable Storage (CAS) system.
+    ///
+    /// This method obtains an authentication token that can be used to download or upload
+    /// files directly through Xet's CAS system. The token is valid until its expiration time.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    /// * `is_upload` - `true` for upload operations, `false` for download operations.
+    ///
+    /// # Returns
+    ///
+    /// A `CasJwtInfo` object containing the token and CAS server URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` is empty, `XetError::AuthError` if
+    /// authentication fails, or `XetError::NetworkError` if the token cannot be retrieved.
+    pub fn get_cas_jwt(
+        &self,
+        repo: String,
+        revision: Option<String>,
+        is_upload: bool,
+    ) -> Result<Arc<CasJwtInfo>, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        let endpoint = self.endpoint_for(&repo_info).to_string();
+        let revision = self.resolve_revision(revision);
+        // Refs such as `refs/convert/parquet` contain slashes, which must be
+        // escaped to stay one path segment. The Hub client puts the revision
+        // into its URL as given, so it's handed the escaped form too.
+        let encoded_revision = xet_url::encode_revision(&revision);
+        // Only read tokens are used by downloads, which can refresh them.
+        let refresh_route = (!is_upload)
+            .then(|| self.revision_route(&endpoint, &repo_info, "xet-read-token", &revision));
+        if is_upload {
+            self.check_unpinned()?;
+        }
+        // The Hub client connects without the client's TLS options, so read
+        // tokens are requested directly when they're set.
+        if let Some(refresh_route) = refresh_route.as_deref().filter(|_| !self.tls.is_default()) {
+            return self.block_on(self.cas_jwt(refresh_route));
+        }
+        let operation = if is_upload {
+            hub_client::Operation::Upload
+        } else {
+            hub_client::Operation::Download
+        };
+
+        let cred_helper: Arc<dyn hub_client::CredentialHelper> = if let Some(token) = &self.token {
+            hub_client::BearerCredentialHelper::new(token.clone(), "swift-xet")
+        } else {
+            hub_client::NoopCredentialHelper::new()
+        };
+
+        let user_agent = self.user_agent();
+
+        let hub_client = hub_client::HubClient::new(
+            &endpoint,
+            repo_info.clone(),
+            Some(encoded_revision),
+            user_agent,
+            "",
+            cred_helper,
+        )?;
+
+        let jwt_info = self
+            .block_on(async {
+                hub_client
+                    .get_cas_jwt(operation)
+                    .await
+                    .map_err(XetError::from)
+            })
+            .map_err(|e| match is_upload {
+                true => self.explain_write_error(&repo_info, e),
+                false => e,
+            })?;
+
+        let jwt_info = CasJwtInfo::from(jwt_info);
+        Ok(Arc::new(match refresh_route {
+            Some(refresh_route) => jwt_info.with_refresh_route(refresh_route),
+            None => jwt_info,
+        }))
+    }
+
+    /// Downloads files using the Xet Content-Addressable Storage (CAS) system.
+    ///
+    /// This method downloads files directly from Xet's CAS system using their content hashes.
+    /// Files are written into `destination_dir`, which will be created if missing.
+    /// They're staged beside their destinations and only moved into place once
+    /// all of them are complete, so a failure or crash never leaves a truncated
+    /// file at a destination.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_infos` - An array of `XetFileInfo` objects, each containing a file's hash and size.
+    /// * `destination_dir` - The local directory where downloaded files should be saved.
+    /// * `jwt_info` - A `CasJwtInfo` object describing the CAS endpoint, access token, and expiration.
+    ///
+    /// # Returns
+    ///
+    /// An array of file paths for the successfully downloaded files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `file_infos` is empty or `destination_dir` is empty,
+    /// `XetError::TokenExpired` if `jwt_info` has expired and can't be refreshed,
+    /// `XetError::IoError` if files cannot be written, or `XetError::NetworkError` if downloads fail.
+    pub fn download_files(
+        &self,
+        file_infos: Vec<Arc<XetFileInfo>>,
+        destination_dir: String,
+        jwt_info: Arc<CasJwtInfo>,
+    ) -> Result<Vec<String>, XetError> {
+        if file_infos.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "File infos cannot be empty".to_string(),
+            });
+        }
+        if destination_dir.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Destination directory cannot be empty".to_string(),
+            });
+        }
+
+        let mut total_size: u64 = 0;
+        for info in &file_infos {
+            self.check_file_size(&info.hash(), info.file_size())?;
+            total_size = total_size.saturating_add(info.file_size());
+        }
+        self.check_total_size(total_size)?;
+
+        std::fs::create_dir_all(&destination_dir).map_err(|e| XetError::IoError {
+            message: format!("Failed to create destination directory: {}", e),
+            cause: Some(ErrorCause::from(&e)),
+        })?;
+
+        // Each file is reconstructed beside its destination and only moved
+        // into place once every file in the plan is complete.
+        let destinations: Vec<PathBuf> = (0..file_infos.len())
+            .map(|i| Path::new(&destination_dir).join(format!("file_{}", i)))
+            .collect();
+        let partials: Vec<PartialDownload> = destinations
+            .iter()
+            .map(|destination| PartialDownload::new(destination))
+            .collect();
+        let result = if self.throttle.is_limited()
+            || self.retry_policy != RetryPolicy::default()
+            || !self.tls.is_default()
+        {
+            // The data crate can't be paced, retries with the settings it read
+            // first, and connects with the system's trust, so under a
+            // throughput limit, a custom retry policy, or custom trust each
+            // file is reconstructed with the client's own range requests.
+            let observer = TransferObserver::default().with_throttle(self.throttle.clone());
+            file_infos
+                .iter()
+                .zip(&partials)
+                .try_for_each(|(info, partial)| {
+                    self.download_xet_resuming(
+                        &info.hash(),
+                        info.file_size(),
+                        jwt_info.clone(),
+                        partial,
+                        &observer,
+                        false,
+                    )
+                    .map(|_| ())
+                })
+        } else {
+            let plan: Vec<XetDownloadPlan> = file_infos
+                .into_iter()
+                .zip(&partials)
+                .map(|(info, partial)| {
+                    let data_info = data::XetFileInfo::from((*info).clone());
+                    XetDownloadPlan::new(data_info, partial.path().to_string_lossy().to_string())
+                })
+                .collect();
+            self.block_on(self.execute_xet_plan(plan, jwt_info.clone()))
+                .map(|_| ())
+        };
+        if let Err(e) = result {
+            for partial in &partials {
+                partial.discard()?;
+            }
+            return Err(e);
+        }
+        for partial in &partials {
+            partial.finish()?;
+        }
+
+        Ok(destinations
+            .iter()
+            .map(|destination| destination.to_string_lossy().to_string())
+            .collect())
+    }
+
+    /// Reconstructs a file from CAS by its Xet hash, without resolving a repository path.
+    ///
+    /// This is the minimal download primitive for systems that store Xet hashes
+    /// in their own databases: no Hub metadata is requested, so the caller is
+    /// responsible for obtaining a CAS token that can read the content. The
+    /// file is staged beside `destination` and only moved into place once it's
+    /// complete and `size` bytes long. If the download fails partway, calling
+    /// this again with the same hash fetches only the parts still missing.
+    ///
+    /// # Arguments
+    ///
+    /// * `xet_hash` - The Xet hash of the file's content.
+    /// * `size` - The size of the file in bytes.
+    /// * `destination` - The local path where the file should be saved.
+    /// * `jwt_info` - A `CasJwtInfo` object describing the CAS endpoint, access token, and expiration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `xet_hash` isn't 64 hexadecimal
+    /// digits or `destination` is empty, `XetError::PolicyViolation` if `size`
+    /// exceeds the client's file size limit, `XetError::TokenExpired` if
+    /// `jwt_info` has expired and can't be refreshed, `XetError::IoError` if
+    /// the file cannot be written or has the wrong size, or
+    /// `XetError::NetworkError` if the download fails.
+    pub fn download_by_hash(
+        &self,
+        xet_hash: String,
+        size: u64,
+        destination: String,
+        jwt_info: Arc<CasJwtInfo>,
+    ) -> Result<(), XetError> {
+        if xet_hash.len() != 64 || !xet_hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(XetError::InvalidInput {
+                message: format!("Invalid Xet hash: {}", xet_hash),
+            });
+        }
+        if destination.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Destination cannot be empty".to_string(),
+            });
+        }
+        self.check_file_size(&xet_hash, size)?;
+        self.prepare_destination(&destination)?;
+
+        let partial = PartialDownload::new(Path::new(&destination));
+        let observer = TransferObserver::default()
+            .with_throttle(self.throttle.clone())
+            .with_termination(self.termination.clone());
+        let _active = self.termination.track(None);
+        observer.check_cancelled()?;
+        let result = self
+            .download_xet_resuming(&xet_hash, size, jwt_info, &partial, &observer, false)
+            .and_then(|_| {
+                let written = fs::metadata(partial.path())?.len();
+                if written != size {
+                    return Err(XetError::IoError {
+                        message: format!(
+                            "Reconstructed {} bytes, but expected {} bytes",
+                            written, size
+                        ),
+                        cause: None,
+                    });
+                }
+                Ok(())
+            });
+        if let Err(e) = result {
+            // Keep the terms fetched for the next attempt to resume from.
+            if !partial.has_progress() {
+                partial.discard()?;
+            }
+            return Err(e);
+        }
+        partial.finish()?;
+        Ok(())
+    }
+
+    /// Returns the Xet hash, token refresh route, and CAS endpoint of a file.
+    ///
+    /// The file is resolved without downloading it, and a CAS read token is
+    /// requested from the refresh route to learn the endpoint. The token is
+    /// cached, so a download that follows reuses it.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository, relative to the repository root.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// The file's pointer details, or `None` if the file isn't stored with Xet.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` or `path` is empty,
+    /// `XetError::AuthRequired` if the repository needs credentials the client
+    /// lacks, or `XetError::NetworkError` if the file can't be resolved or no
+    /// CAS token can be obtained.
+    pub fn get_xet_pointer_details(
+        &self,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    ) -> Result<Option<Arc<XetPointerDetails>>, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+        if path.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Path cannot be empty".to_string(),
+            });
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        let resolved_revision = self.resolve_revision(revision);
+        let metadata = self
+            .fetch_metadata(&repo_info, &path, &resolved_revision)
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+        let Some(xet_data) = metadata.xet_file_data else {
+            return Ok(None);
+        };
+        let jwt = self.block_on(self.cas_jwt(&xet_data.refresh_route))?;
+
+        Ok(Some(Arc::new(XetPointerDetails {
+            xet_hash: xet_data.file_hash,
+            size: metadata.size,
+            refresh_route: xet_data.refresh_route,
+            cas_endpoint: jwt.cas_url(),
+        })))
+    }
+
+    /// Returns metadata for a known set of paths in a repository.
+    ///
+    /// The paths are resolved with the Hub's paths-info endpoint, which answers
+    /// for many paths in one request, rather than with a request per file. Sizes,
+    /// LFS hashes, Xet hashes, and security scan statuses are included. Paths
+    /// that don't exist in the repository are left out of the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `paths` - The paths of files or directories within the repository, relative to the repository root.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// A `FileMetadata` object for each path that exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` or any path is empty,
+    /// `XetError::AuthRequired` if the repository needs credentials the client
+    /// lacks, or `XetError::NetworkError` if the metadata cannot be retrieved.
+    pub fn get_paths_info(
+        &self,
+        repo: String,
+        paths: Vec<String>,
+        revision: Option<String>,
+    ) -> Result<Vec<Arc<FileMetadata>>, XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+        if paths.iter().any(|path| path.is_empty()) {
+            return Err(XetError::InvalidInput {
+                message: "Path cannot be empty".to_string(),
+            });
+        }
+        if paths.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        let resolved_revision = self.resolve_revision(revision);
+        let entries = self
+            .fetch_paths_info(&repo_info, &resolved_revision, &paths, true)
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+
+        Ok(entries
+            .into_iter()
+            .map(|entry| Arc::new(FileMetadata::from(entry)))
+            .collect())
+    }
+
+    /// Retrieves file information from a pointer file in the repository.
+    ///
+    /// This method reads a pointer file (either in Xet JSON format or Git LFS format)
+    /// and extracts the file's hash and size information.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path to the pointer file within the repository.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// A `XetFileInfo` object if the pointer file can be parsed, or `None` if
+    /// the file doesn't exist or isn't in a recognized format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` or `path` is empty, or `XetError::NetworkError`
+    /// if the pointer file cannot be retrieved.
+    pub fn get_file_info(
+        &self,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    ) -> Result<Option<Arc<XetFileInfo>>, XetError> {
+        let repo_info = self.parse_repo(&repo)?;
+        let resolved_revision = self.resolve_revision(revision);
+
+        // First, try to get Xet metadata from HTTP headers (preferred method for HuggingFace)
+        // This avoids trying to parse binary files as UTF-8 pointer files
+        match self.fetch_metadata(&repo_info, &path, &resolved_revision) {
+            Ok(metadata) => {
+                self.diagnostic(format_args!(
+                    "✓ Got metadata for {}, size={}, xet_data={}",
+                    path,
+                    metadata.size,
+                    metadata.xet_file_data.is_some()
+                ));
+                // If we have Xet metadata in headers (x-xet-hash), use it directly
+                if let Some(xet_data) = metadata.xet_file_data {
+                    self.diagnostic(format_args!(
+                        "✓ Using Xet CAS for {} with hash {}",
+                        path, xet_data.file_hash
+                    ));
+                    let file_info = data::XetFileInfo::new(xet_data.file_hash, metadata.size);
+                    return Ok(Some(Arc::new(XetFileInfo::from(file_info))));
+                }
+                // Headers present but no Xet data - file is not in Xet CAS
+                self.diagnostic(format_args!(
+                    "⚠️  No Xet headers for {}: falling back to pointer file parsing",
+                    path
+                ));
+            }
+            Err(e) => {
+                self.diagnostic(format_args!("Failed to fetch metadata for {}: {}", path, e));
+            }
+        }
+
+        // Fallback: try to parse as a pointer file (for legacy repos without Xet headers)
+        // Skip this for known binary file extensions to avoid UTF-8 errors
+        if !should_try_pointer_detection(&path) {
+            return Ok(None);
+        }
+
+        // Try to get the pointer file content
+        let content = self.get_file_content(repo.clone(), path.clone(), Some(resolved_revision.clone()))?;
+        
+        // Try to convert to UTF-8, but don't fail on binary data
+        let content_str = match String::from_utf8(content) {
+            Ok(s) => s,
+            Err(_) => {
+                // Not valid UTF-8, likely a binary file or not a pointer file  
+                self.diagnostic(format_args!(
+                    "⚠️  File {} is not valid UTF-8, skipping pointer parsing",
+                    path
+                ));
+                return Ok(None);
+            }
+        };
+
+        // Try to parse as XetFileInfo JSON
+        match serde_json::from_str::<data::XetFileInfo>(&content_str) {
+            Ok(file_info) => Ok(Some(Arc::new(XetFileInfo::from(file_info)))),
+            Err(_) => {
+                // Try to parse as LFS pointer format
+                // LFS pointers have format: version <url>\noid <hash>\nsize <size>
+                let lines: Vec<&str> = content_str.lines().collect();
+                let mut oid = None;
+                let mut size = None;
+
+                for line in lines {
+                    if line.starts_with("oid ") {
+                        oid = Some(line[4..].trim().to_string());
+                    } else if line.starts_with("size ") {
+                        size = line[5..].trim().parse().ok();
+                    }
+                }
+
+                if let (Some(hash), Some(file_size)) = (oid, size) {
+                    Ok(Some(Arc::new(XetFileInfo::new(hash, file_size))))
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    }
+
+    /// Returns the size of the files in a repository, or of those matching `patterns`.
+    ///
+    /// Sizes come from a recursive tree listing. Files the listing doesn't report
+    /// a size for are resolved with an individual metadata request. Use this to
+    /// show the size of a download before starting it.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    /// * `patterns` - Glob patterns selecting the files to count, such as `"*.safetensors"`.
+    ///   Pass an empty array to count every file.
+    ///
+    /// # Returns
+    ///
+    /// A `RepoSize` with the logical and estimated on-disk size.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo` or a pattern is invalid, or
+    /// `XetError::NetworkError` if the listing or a file's metadata can't be retrieved.
+    pub fn get_repo_size(
+        &self,
+        repo: String,
+        revision: Option<String>,
+        patterns: Vec<String>,
+    ) -> Result<Arc<RepoSize>, XetError> {
+        for pattern in &patterns {
+            xet_glob::validate(pattern)?;
+        }
+        let repo_info = self.parse_repo(&repo)?;
+        let revision = self.resolve_revision(revision);
+
+        let mut size = RepoSize {
+            logical_bytes: 0,
+            estimated_disk_bytes: 0,
+            file_count: 0,
+        };
+        let files = self
+            .list_repo_files(&repo_info, "", &revision, false)
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+        for entry in files {
+            if !patterns.is_empty() && !xet_glob::matches_any(&patterns, &entry.path) {
+                continue;
+            }
+            let file_size = match entry.size {
+                Some(file_size) => file_size,
+                None => self.fetch_metadata(&repo_info, &entry.path, &revision)?.size,
+            };
+            size.logical_bytes = size.logical_bytes.saturating_add(file_size);
+            size.estimated_disk_bytes = size
+                .estimated_disk_bytes
+                .saturating_add(file_size.div_ceil(DISK_BLOCK_SIZE) * DISK_BLOCK_SIZE);
+            size.file_count += 1;
+        }
+
+        Ok(Arc::new(size))
+    }
+
+    /// Downloads a file into the client's cache and returns its local path.
+    ///
+    /// Files are stored once per etag and linked into a directory per commit, so
+    /// several revisions of the same file can be on disk at once. A file whose
+    /// contents are already cached isn't downloaded again.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository, relative to the repository root.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// The local path of the file at the commit `revision` resolved to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if any parameter is invalid, `XetError::NetworkError`
+    /// if the file cannot be downloaded, or `XetError::CacheError` if it cannot be stored.
+    pub fn download_to_cache(
+        &self,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    ) -> Result<String, XetError> {
+        if path.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Path cannot be empty".to_string(),
+            });
+        }
+        let repo_info = self.parse_repo(&repo)?;
+        let revision = self.resolve_revision(revision);
+        let key = self.repo_key(&repo_info);
+
+        let metadata = self
+            .fetch_metadata(&repo_info, &path, &revision)
+            .map_err(|e| self.explain_access_error(&repo_info, e))?;
+        let commit = metadata.commit_hash.clone();
+        let snapshot = self.local_path(&repo_file_path(
+            &self.hub_cache.snapshot_dir(&key, &commit),
+            &path,
+        )?);
+        let blob = self.hub_cache.blob_path(&metadata.etag);
+
+        let cached = fs::metadata(&blob).is_ok_and(|existing| existing.len() == metadata.size);
+        if !cached {
+            if let Some(parent) = blob.parent() {
+                fs::create_dir_all(parent).map_err(hub_cache_error)?;
+            }
+            let expected_sha256 = xet_verify::expected_sha256(&metadata.etag);
+            self.check_security_status(&repo_info, &path, &commit)
+                .map_err(|e| self.explain_access_error(&repo_info, e))?;
+            let report = self
+                .download_file_with_metadata(
+                    repo_info.clone(),
+                    path.clone(),
+                    blob.to_string_lossy().to_string(),
+                    commit.clone(),
+                    Ok(metadata),
+                    &TransferObserver::default(),
+                )
+                .map_err(|e| self.explain_access_error(&repo_info, e))?;
+            // Cached contents are served offline by `get_cached_path`, so they
+            // are verified whether or not the client verifies downloads.
+            if let (VerificationStatus::NotRequested, Some(expected)) =
+                (report.verification(), expected_sha256)
+            {
+                let (actual, _) = xet_verify::hash_file(&blob)?;
+                if actual != expected {
+                    let _ = fs::remove_file(&blob);
+                    return Err(xet_verify::mismatch_error(&path, &expected, &actual));
+                }
+            }
+        }
+
+        self.hub_cache
+            .write_ref(&key, &revision, &commit)
+            .map_err(hub_cache_error)?;
+        self.hub_cache
+            .link(&blob, Path::new(&snapshot))
+            .map_err(hub_cache_error)?;
+        Ok(snapshot)
+    }
+
+    /// Downloads a file without blocking the caller, as [`XetClient::download_file`] does.
+    ///
+    /// The transfer runs on the client's runtime, so awaiting it from Swift
+    /// doesn't hold a thread from the cooperative pool. Cancelling the task
+    /// awaiting it cancels the transfer, keeping what was fetched for a retry
+    /// to resume from.
+    pub async fn download_file_async(
+        self: Arc<Self>,
+        repo: String,
+        path: String,
+        destination: String,
+        revision: Option<String>,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        self.run_detached(move |client| client.download_file(repo, path, destination, revision))
+            .await
+    }
+
+    /// Downloads a file without blocking the caller, as
+    /// [`XetClient::download_file_with_progress`] does.
+    pub async fn download_file_with_progress_async(
+        self: Arc<Self>,
+        repo: String,
+        path: String,
+        destination: String,
+        revision: Option<String>,
+        progress: Option<Box<dyn ProgressCallback>>,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        self.run_detached(move |client| {
+            client.download_file_with_progress(
+                repo,
+                path,
+                destination,
+                revision,
+                progress,
+                cancellation,
+            )
+        })
+        .await
+    }
+
+    /// Downloads a file without blocking the caller, as
+    /// [`XetClient::download_file_with_handle`] does.
+    pub async fn download_file_with_handle_async(
+        self: Arc<Self>,
+        repo: String,
+        path: String,
+        destination: String,
+        revision: Option<String>,
+        progress: Option<Box<dyn ProgressCallback>>,
+        handle: Arc<DownloadHandle>,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        self.run_detached(move |client| {
+            client.download_file_with_handle(repo, path, destination, revision, progress, handle)
+        })
+        .await
+    }
+
+    /// Downloads a file without blocking the caller, as
+    /// [`XetClient::download_file_in_group`] does.
+    pub async fn download_file_in_group_async(
+        self: Arc<Self>,
+        repo: String,
+        path: String,
+        destination: String,
+        revision: Option<String>,
+        group: Arc<TransferGroup>,
+    ) -> Result<Arc<TransferReport>, XetError> {
+        self.run_detached(move |client| {
+            client.download_file_in_group(repo, path, destination, revision, group)
+        })
+        .await
+    }
+
+    /// Downloads a batch without blocking the caller, as [`XetClient::download_files_batch`] does.
+    pub async fn download_files_batch_async(
+        self: Arc<Self>,
+        requests: Vec<Arc<FileDownloadRequest>>,
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+    ) -> Result<Vec<String>, XetError> {
+        self.run_detached(move |client| {
+            client.download_files_batch(requests, allow_patterns, ignore_patterns)
+        })
+        .await
+    }
+
+    /// Downloads a batch without blocking the caller, as
+    /// [`XetClient::download_files_batch_with_results`] does.
+    pub async fn download_files_batch_with_results_async(
+        self: Arc<Self>,
+        requests: Vec<Arc<FileDownloadRequest>>,
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+    ) -> Result<Vec<Arc<FileDownloadResult>>, XetError> {
+        self.run_detached(move |client| {
+            client.download_files_batch_with_results(requests, allow_patterns, ignore_patterns)
+        })
+        .await
+    }
+
+    /// Downloads multiple files without blocking the caller, as [`XetClient::download_files_batch_detailed`] does.
+    pub async fn download_files_batch_detailed_async(
+        self: Arc<Self>,
+        requests: Vec<Arc<FileDownloadRequest>>,
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+        continue_on_error: bool,
+    ) -> Result<Arc<BatchDownloadResult>, XetError> {
+        self.run_detached(move |client| {
+            client.download_files_batch_detailed(
+                requests,
+                allow_patterns,
+                ignore_patterns,
+                continue_on_error,
+            )
+        })
+        .await
+    }
+
+    /// Downloads a repository without blocking the caller, as [`XetClient::snapshot_download`] does.
+    pub async fn snapshot_download_async(
+        self: Arc<Self>,
+        repo: String,
+        revision: Option<String>,
+        destination_dir: String,
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+        destination_template: Option<String>,
+    ) -> Result<Vec<String>, XetError> {
+        self.run_detached(move |client| {
+            client.snapshot_download(
+                repo,
+                revision,
+                destination_dir,
+                allow_patterns,
+                ignore_patterns,
+                destination_template,
+            )
+        })
+        .await
+    }
+
+    /// Downloads a folder without blocking the caller, as [`XetClient::download_folder`] does.
+    pub async fn download_folder_async(
+        self: Arc<Self>,
+        repo: String,
+        folder_path: String,
+        destination_dir: String,
+        revision: Option<String>,
+    ) -> Result<Vec<String>, XetError> {
+        self.run_detached(move |client| {
+            client.download_folder(repo, folder_path, destination_dir, revision)
+        })
+        .await
+    }
+
+    /// Caches a file without blocking the caller, as [`XetClient::download_to_cache`] does.
+    pub async fn download_to_cache_async(
+        self: Arc<Self>,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    ) -> Result<String, XetError> {
+        self.run_detached(move |client| client.download_to_cache(repo, path, revision))
+            .await
+    }
+
+    /// Returns the local path of a cached file without touching the network.
+    ///
+    /// The revision is resolved through the branches and tags recorded by
+    /// earlier calls to `download_to_cache`, and the path is returned only if
+    /// the file's contents are fully present. Contents with a SHA-256 etag were
+    /// verified when they were cached. Use this on hot paths, such as model
+    /// loading, that must never wait on a request.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository, relative to the repository root.
+    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to the client's default revision.
+    ///
+    /// # Returns
+    ///
+    /// The local path of the file, or `None` if it isn't cached at that revision.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if any parameter is invalid.
+    pub fn get_cached_path(
+        &self,
+        repo: String,
+        path: String,
+        revision: Option<String>,
+    ) -> Result<Option<String>, XetError> {
+        if path.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Path cannot be empty".to_string(),
+            });
+        }
+        let repo_info = self.parse_repo(&repo)?;
+        let revision = self.resolve_revision(revision);
+        let key = self.repo_key(&repo_info);
+
+        let Some(commit) = self.hub_cache.resolve_cached(&key, &revision) else {
+            return Ok(None);
+        };
+        let snapshot = self.local_path(&repo_file_path(
+            &self.hub_cache.snapshot_dir(&key, &commit),
+            &path,
+        )?);
+        Ok(self
+            .hub_cache
+            .cached_file(Path::new(&snapshot))
+            .map(|file| file.to_string_lossy().to_string()))
+    }
+
+    /// Lists the revisions of a file available in the client's cache.
+    ///
+    /// Only files downloaded with `download_to_cache` are included. No request
+    /// is made, so this works offline and can back a picker for switching
+    /// between model versions already on disk.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file within the repository, relative to the repository root.
+    ///
+    /// # Returns
+    ///
+    /// The cached revisions of the file, most recently cached first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if any parameter is invalid, or
+    /// `XetError::CacheError` if the cache can't be read.
+    pub fn list_cached_revisions(
+        &self,
+        repo: String,
+        path: String,
+    ) -> Result<Vec<Arc<CachedRevision>>, XetError> {
+        if path.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Path cannot be empty".to_string(),
+            });
+        }
+        let repo_info = self.parse_repo(&repo)?;
+        repo_file_path(Path::new(""), &path)?;
+        let revisions = self
+            .hub_cache
+            .cached_revisions(&self.repo_key(&repo_info), &self.local_path(&path))
+            .map_err(hub_cache_error)?;
+        Ok(revisions.into_iter().map(Arc::new).collect())
+    }
+
+    /// Returns how far the Hub's clock is ahead of the device's, in seconds,
+    /// as estimated from the most recent Hub response.
+    ///
+    /// The estimate is updated as a side effect of other calls, and is zero
+    /// until a response arrives. It's negative if the device's clock is ahead.
+    /// With `ClockSource::Hub`, a skew beyond `clock_skew_tolerance_secs` is
+    /// corrected when judging token expiry.
+    pub fn get_clock_skew_secs(&self) -> i64 {
+        self.clock.skew()
+    }
+
+    /// Returns the Hub API rate limit reported by the most recent response that included one.
+    ///
+    /// The status is updated as a side effect of other calls; this method makes
+    /// no request of its own. Each update is also reported to the event listener
+    /// with a `RateLimitUpdated` event.
+    ///
+    /// # Returns
+    ///
+    /// The latest `RateLimitStatus`, or `None` if no response has reported a rate limit yet.
+    pub fn get_rate_limit_status(&self) -> Option<Arc<RateLimitStatus>> {
+        self.rate_limit
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Starts logging the client's requests to a file, for reporting network problems.
+    ///
+    /// Each request's method and URL, response status or error, response
+    /// headers relevant to downloads, and timing are written as a JSON line,
+    /// after a first line describing the client and device. URL signatures and
+    /// credentials are redacted and the token is never written, so recordings
+    /// can be attached to bug reports. With the `fault-injection` feature,
+    /// `replay_faults` turns a recording into simulated faults that reproduce
+    /// its failures and latency. Starting a recording ends any in progress.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The file to record to, which is replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::IoError` if the file can't be created.
+    pub fn record_session(&self, path: String) -> Result<(), XetError> {
+        let environment = serde_json::json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "os": std::env::consts::OS,
+            "arch": std::env::consts::ARCH,
+            "endpoint": self.endpoint,
+            "started_at": unix_now(),
+            "clock_skew_secs": self.clock.skew(),
+            "authenticated": self.token.is_some(),
+        });
+        self.recorder
+            .start(Path::new(&self.local_path(&path)), environment)
+            .map_err(|e| XetError::IoError {
+                message: format!("Failed to start recording to {}: {}", path, e),
+                cause: Some(ErrorCause::from(&e)),
+            })
+    }
+
+    /// Stops the recording started with `record_session`, if any.
+    ///
+    /// Requests in flight when the recording stops aren't recorded.
+    pub fn stop_recording(&self) {
+        self.recorder.stop();
+    }
+
+    /// Parses a repository identifier and returns structured repository information.
+    ///
+    /// This method validates and parses repository identifiers in various formats,
+    /// returning the repository type and full name.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier, which can be in the format
+    ///   `"owner/repo"` (defaults to model type) or `"type/owner/repo"` where type
+    ///   is `models`, `datasets`, or `spaces`.
+    ///
+    /// # Returns
+    ///
+    /// A `RepoInfo` object containing the repository type and full name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if the repository identifier format is invalid.
+    pub fn get_repo_info(&self, repo: String) -> Result<Arc<RepoInfo>, XetError> {
+        let hub_repo_info = self.parse_repo(&repo)?;
+        Ok(Arc::new(RepoInfo::from(hub_repo_info)))
+    }
+
+    /// Clears all files from the local Xet cache.
+    ///
+    /// This method removes all cached files, leaving an empty cache directory.
+    /// Use this to free up disk space or to force fresh downloads of cached files.
+    /// Areas relocated with `cache_roots` are cleared in place, leaving any
+    /// other files in their directories untouched. Commits journaled for
+    /// `recover_pending_commits` aren't cached data and are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::CacheError` if the cache directory cannot be cleared.
+    pub fn clear_cache(&self) -> Result<(), XetError> {
+        for area in self.cache.relocated_areas() {
+            self.clear_cache_area(area)?;
+        }
+        xet_cache::clear_all(self.cache.base()).map_err(|e| XetError::CacheError {
+            message: format!("Failed to clear cache: {}", e),
+        })
+    }
+
+    /// Clears a single area of the local Xet cache.
+    ///
+    /// Other areas are left untouched, so you can, for example, drop cheap
+    /// metadata without discarding expensive content chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - The cache area to clear.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::CacheError` if files in the area cannot be removed.
+    pub fn clear_cache_area(&self, area: CacheArea) -> Result<(), XetError> {
+        xet_cache::clear_area(self.cache.root(area), area).map_err(|e| XetError::CacheError {
+            message: format!("Failed to clear cache area {:?}: {}", area, e),
+        })
+    }
+
+    /// Evicts the least recently modified files in a cache area until it fits a size limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - The cache area to prune.
+    /// * `max_size_bytes` - The maximum size the area may occupy after pruning.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes freed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::CacheError` if the area cannot be scanned or files cannot be removed.
+    pub fn prune_cache_area(&self, area: CacheArea, max_size_bytes: u64) -> Result<u64, XetError> {
+        xet_cache::prune_area(self.cache.root(area), area, max_size_bytes).map_err(|e| {
+            XetError::CacheError {
+                message: format!("Failed to prune cache area {:?}: {}", area, e),
+            }
+        })
+    }
+
+    /// Returns statistics about the local Xet cache.
+    ///
+    /// This method calculates the total size and file count of all cached files,
+    /// along with a breakdown by cache area.
+    /// If the cache directory doesn't exist, returns statistics with zero values.
+    ///
+    /// # Returns
+    ///
+    /// A `CacheStats` object containing the total cache size in bytes,
+    /// the number of cached files, and per-area statistics.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::CacheError` if the cache directory cannot be accessed
+    /// or statistics cannot be calculated.
+    pub fn get_cache_stats(&self) -> Result<Arc<CacheStats>, XetError> {
+        let usage = self.cache.usage().map_err(|e| XetError::CacheError {
+            message: format!("Failed to calculate cache stats: {}", e),
+        })?;
+
+        let mut stats = CacheStats::from(usage);
+        stats.deduplicated_bytes =
+            self.hub_cache
+                .deduplicated_bytes()
+                .map_err(|e| XetError::CacheError {
+                    message: format!("Failed to calculate cache stats: {}", e),
+                })?;
+        Ok(Arc::new(stats))
+    }
+
+    /// Sets the label transfers are attributed to in the usage report.
+    ///
+    /// Transfers that finish after the call are attributed to the new label,
+    /// unless their call names a label of its own through a `CancellationToken`
+    /// or `DownloadHandle` created with one. Give each product feature its own
+    /// label, such as `"onboarding"` or `"model-update"`, to learn what each
+    /// costs in bandwidth.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label, or `None` to leave transfers unlabeled.
+    pub fn set_usage_label(&self, label: Option<String>) {
+        *self
+            .usage_label
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = label;
+    }
+
+    /// Returns the bytes and time of the transfers the client has completed, by label.
+    ///
+    /// Completed downloads count the size of the file, including any part an
+    /// earlier attempt fetched; downloads skipped because the destination was
+    /// current don't count. Totals cover the life of the client, or the time
+    /// since `reset_usage_report`.
+    ///
+    /// Each label also breaks down the bytes its requests exchanged by host,
+    /// counting metadata requests, retries, and failed transfers, so the
+    /// bandwidth spent on the Hub API, CAS, and CDNs can be told apart.
+    ///
+    /// # Returns
+    ///
+    /// The totals of each label with any completed transfers or requests,
+    /// unlabeled transfers first and the rest sorted by label.
+    pub fn get_usage_report(&self) -> Vec<LabelUsage> {
+        self.usage.snapshot()
+    }
+
+    /// Clears the usage report, such as at the start of a new billing period.
+    pub fn reset_usage_report(&self) {
+        self.usage.reset();
+    }
+
+    /// Adds an upload of `bytes` through the Xet data crate to the usage report.
+    ///
+    /// The data crate makes its own requests, so the content is attributed to
+    /// the CAS endpoint of `jwt` here rather than as each request is answered.
+    #[cfg(feature = "upload")]
+    fn record_upload(&self, jwt: &CasJwtInfo, bytes: u64, elapsed: Duration) {
+        let label = self.usage_label();
+        if let Ok(cas_url) = reqwest::Url::parse(&jwt.cas_url()) {
+            self.usage
+                .record_traffic(label.clone(), &cas_url, EndpointKind::Cas, bytes, 0);
+        }
+        self.usage.record_upload(label, bytes, elapsed);
+    }
+
+    /// Returns the label the calling thread's transfers are attributed to.
+    fn usage_label(&self) -> Option<String> {
+        xet_usage::current().or_else(|| {
+            self.usage_label
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        })
+    }
+
+    /// Sets the policy consulted before each file download begins.
+    ///
+    /// The policy is called with each file's repository path, size, and content type
+    /// once they are known, before any content is transferred. Downloads the policy
+    /// rejects fail with `XetError::PolicyViolation`. Raw CAS downloads through
+    /// `download_files` have no repository path and aren't subject to the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy to consult, or `None` to allow all downloads.
+    pub fn set_download_policy(&self, policy: Option<Box<dyn DownloadPolicy>>) {
+        *self
+            .download_policy
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = policy.map(Arc::from);
+    }
+
+    /// Sets whether the client writes diagnostics to standard error.
+    ///
+    /// Diagnostics are meant for development; silence them in release builds
+    /// where output on standard error is treated as a defect.
+    ///
+    /// # Arguments
+    ///
+    /// * `quiet` - `true` to silence diagnostics, or `false` to write them.
+    pub fn set_quiet(&self, quiet: bool) {
+        self.quiet.store(quiet, Ordering::Relaxed);
+    }
+
+    /// Sets the most bytes per second the client downloads across all transfers.
+    ///
+    /// Transfers already under way adopt the new limit as they receive data,
+    /// so an app can tighten the limit when the device moves to a cellular
+    /// connection and lift it again on Wi-Fi.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes_per_second` - The limit, or `None` to download without one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `bytes_per_second` is zero.
+    pub fn set_max_download_bytes_per_second(
+        &self,
+        bytes_per_second: Option<u64>,
+    ) -> Result<(), XetError> {
+        check_download_limit(bytes_per_second)?;
+        self.throttle.set_limit(bytes_per_second);
+        Ok(())
+    }
+
+    /// Sets the listener that receives client events.
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - The listener to notify, or `None` to stop receiving events.
+    pub fn set_event_listener(&self, listener: Option<Box<dyn XetEventListener>>) {
+        *self
+            .event_listener
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = listener.map(Arc::from);
+    }
+
+    /// Starts periodic cache maintenance in the background.
+    ///
+    /// Maintenance runs on the client's runtime as a blocking task, so it never
+    /// competes with transfers for async worker threads. With the current-thread
+    /// runtime flavor, passes run inline and only while a client method is
+    /// running. The first pass runs immediately. Calling this method while maintenance is already running
+    /// replaces the previous policy.
+    ///
+    /// Maintenance stops when `stop_cache_maintenance` is called or the client
+    /// is dropped, so hosts can tie it to their foreground lifecycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The interval and per-area size limits to enforce.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if the policy's interval is zero.
+    pub fn start_cache_maintenance(&self, policy: CacheMaintenancePolicy) -> Result<(), XetError> {
+        if policy.interval_secs == 0 {
+            return Err(XetError::InvalidInput {
+                message: "Maintenance interval must be greater than zero".to_string(),
+            });
+        }
+
+        let current_thread = self.config.runtime_flavor == RuntimeFlavor::CurrentThread;
+        let layout = self.cache.clone();
+        // Downloaded files found intact aren't hashed again until they change.
+        let verified = Arc::new(Mutex::new(HashMap::new()));
+        let maintenance = async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(policy.interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                let policy = policy.clone();
+                let layout = layout.clone();
+                let verified = verified.clone();
+                let run = move || {
+                    let mut verified = verified.lock().unwrap_or_else(PoisonError::into_inner);
+                    xet_cache::run_maintenance(&layout, &policy, &mut verified)
+                };
+                // Failures are retried on the next tick; a busy cache file is not fatal.
+                if current_thread {
+                    let _ = run();
+                } else {
+                    let _ = tokio::task::spawn_blocking(run).await;
+                }
+            }
+        };
+        let handle = self.with_resources(|resources| resources.runtime.spawn(maintenance))?;
+
+        let previous = self
+            .cache_maintenance
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace(handle);
+        if let Some(previous) = previous {
+            previous.abort();
+        }
+        Ok(())
+    }
+
+    /// Stops background cache maintenance started with `start_cache_maintenance`.
+    ///
+    /// A pass that is already removing files finishes before the task exits.
+    /// Calling this method when maintenance isn't running has no effect.
+    pub fn stop_cache_maintenance(&self) {
+        let handle = self
+            .cache_maintenance
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        if let Some(handle) = handle {
+            handle.abort();
+        }
+    }
+
+    /// Prepares the client for the host app to exit, returning when it's safe to.
+    ///
+    /// Call this from `applicationWillTerminate` or its equivalent. Downloads
+    /// under way stop at their next checkpoint, keeping what they've written
+    /// and the resume state beside it, so retrying them after the next launch
+    /// continues where they stopped; a paused download is already held at a
+    /// checkpoint. Downloads started afterwards fail with `XetError::Cancelled`
+    /// and reason `AppTerminating`. Cache maintenance and any session
+    /// recording are stopped, and pending progress and event callbacks are
+    /// delivered before this method returns.
+    ///
+    /// An upload can't stop partway through a batch the Xet data crate is
+    /// sending, but the files it has sent are journaled, so uploading the same
+    /// files again skips them.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout_ms` - How long to wait for downloads to stop, in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every download stopped or paused in time, or `false` if some
+    /// were still running when the timeout passed.
+    pub fn prepare_for_termination(&self, timeout_ms: u64) -> bool {
+        let stopped = self.termination.request(Duration::from_millis(timeout_ms));
+        self.stop_cache_maintenance();
+        self.recorder.stop();
+        self.callbacks.flush();
+        stopped
+    }
+
+    /// Registers a model to be downloaded and kept up to date by `update_all_managed`.
+    ///
+    /// Registering a model that is already registered replaces its revision
+    /// policy and patterns. Nothing is downloaded until `update_all_managed` is called.
+    /// The library is persisted in the cache, so registrations survive relaunches.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `revision_policy` - Which revision of the repository to keep downloaded.
+    /// * `patterns` - Glob patterns selecting the files to download, such as `"*.safetensors"`.
+    ///   Pass an empty array to download every file.
+    ///
+    /// # Returns
+    ///
+    /// The registered `ManagedModel`. Its status doesn't reflect the remote revision;
+    /// call `list_managed` for that.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo`, the revision, or a pattern is invalid,
+    /// `XetError::PolicyViolation` if the repository owner isn't allowed, or
+    /// `XetError::CacheError` if the library can't be saved.
+    pub fn register_managed_model(
+        &self,
+        repo: String,
+        revision_policy: ManagedRevisionPolicy,
+        patterns: Vec<String>,
+    ) -> Result<Arc<ManagedModel>, XetError> {
+        let repo_info = self.parse_repo(&repo)?;
+        if revision_policy.revision().is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Revision cannot be empty".to_string(),
+            });
+        }
+        for pattern in &patterns {
+            xet_glob::validate(pattern)?;
+        }
+
+        let key = self.repo_key(&repo_info);
+        let record = self
+            .managed
+            .update(|records| {
+                if let Some(record) = records.iter_mut().find(|record| record.repo == key) {
+                    record.revision_policy = revision_policy;
+                    record.patterns = patterns;
+                    return record.clone();
+                }
+                let record = ManagedRecord::new(key.clone(), revision_policy, patterns);
+                records.push(record.clone());
+                record
+            })
+            .map_err(managed_library_error)?;
+
+        Ok(Arc::new(self.managed_model(record, None)))
+    }
+
+    /// Lists the models in the managed library with the state of their local files.
+    ///
+    /// The revision of each model is resolved against the Hub to detect updates.
+    /// Models whose revision can't be resolved, for example while offline, report
+    /// their local state and have no `latest_commit`.
+    ///
+    /// # Returns
+    ///
+    /// An array of `ManagedModel` objects in registration order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::CacheError` if the library can't be read.
+    pub fn list_managed(&self) -> Result<Vec<Arc<ManagedModel>>, XetError> {
+        let records = self.managed.load().map_err(managed_library_error)?;
+        Ok(records
+            .into_iter()
+            .map(|record| {
+                let latest = self.latest_managed_commit(&record).ok();
+                Arc::new(self.managed_model(record, latest))
+            })
+            .collect())
+    }
+
+    /// Downloads every managed model that is missing, incomplete, or out of date.
+    ///
+    /// Each model is brought to the commit its revision currently resolves to,
+    /// downloading only the files matching its patterns. Progress is saved after
+    /// every file, so an interrupted update resumes against the same commit rather
+    /// than mixing files from two commits. Files that are no longer part of a
+    /// model once its update completes are removed.
+    ///
+    /// A failure updating one model doesn't stop the others; it is reported by
+    /// that model's `last_error`.
+    ///
+    /// # Returns
+    ///
+    /// An array of `ManagedModel` objects reflecting the state after updating.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::CacheError` if the library can't be read or saved.
+    pub fn update_all_managed(&self) -> Result<Vec<Arc<ManagedModel>>, XetError> {
+        let records = self.managed.load().map_err(managed_library_error)?;
+
+        let mut models = Vec::with_capacity(records.len());
+        for record in records {
+            let repo = record.repo.clone();
+            let (record, latest) = match self.update_managed(record) {
+                Ok((record, latest)) => (record, Some(latest)),
+                Err(e) => {
+                    let message = e.to_string();
+                    let record = self
+                        .managed
+                        .update_record(&repo, |record| record.last_error = Some(message))
+                        .map_err(managed_library_error)?;
+                    match record {
+                        Some(record) => (record, None),
+                        // Unregistered while updating.
+                        None => continue,
+                    }
+                }
+            };
+            models.push(Arc::new(self.managed_model(record, latest)));
+        }
+
+        Ok(models)
+    }
+
+    /// Uploads content from memory and commits it to the repository as one file.
+    ///
+    /// The content is chunked and uploaded to CAS straight from `data`, without
+    /// being written to disk, which suits small artifacts generated on-device
+    /// such as configs, tokenizer patches, or adapters. The commit targets the
+    /// client's default revision, and is journaled so `recover_pending_commits`
+    /// can finish it if the process exits after the upload.
+    ///
+    /// If the repository's `.gitattributes` doesn't already store the file with
+    /// LFS, a rule for it is added in the same commit, as `huggingface_hub`
+    /// does, so the Hub serves the file's content rather than a pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `data` - The content of the file.
+    /// * `path_in_repo` - The path the file is committed to, relative to the repository root.
+    /// * `commit_message` - The summary of the commit.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the created commit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo`, `path_in_repo`, or
+    /// `commit_message` is empty, `XetError::AuthError` if the client has no
+    /// token, `XetError::CacheError` if the commit can't be journaled, or
+    /// `XetError::NetworkError` if the upload or commit fails.
+    #[cfg(feature = "upload")]
+    pub fn upload_bytes(
+        &self,
+        repo: String,
+        data: Vec<u8>,
+        path_in_repo: String,
+        commit_message: String,
+    ) -> Result<String, XetError> {
+        let path_in_repo = path_in_repo.trim_matches('/').to_string();
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+        if path_in_repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Path in repository cannot be empty".to_string(),
+            });
+        }
+        if commit_message.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Commit message cannot be empty".to_string(),
+            });
+        }
+        if self.token.is_none() {
+            return Err(XetError::AuthError {
+                message: "Uploading requires an authentication token".to_string(),
+            });
+        }
+
+        let repo_info = self.parse_repo(&repo)?;
+        let revision = self.resolve_revision(None);
+        let jwt = self.get_cas_jwt(repo, Some(revision.clone()), true)?;
+        let gitattributes = if path_in_repo == xet_gitattributes::GITATTRIBUTES_PATH {
+            None
+        } else {
+            self.lfs_gitattributes(&repo_info, &revision, &[&path_in_repo])?
+        };
+
+        // Journal the commit before any content reaches CAS.
+        let journal = CommitJournal::open(&self.cache);
+        let journal_error = |e: std::io::Error| XetError::CacheError {
+            message: format!("Failed to access commit journal: {}", e),
+        };
+        let mut intent = CommitIntent::new(
+            self.endpoint_for(&repo_info),
+            self.repo_type_plural(&repo_info.repo_type),
+            &repo_info.full_name,
+            &revision,
+            &commit_message,
+        );
+        let _live = LiveIntent::hold(&intent);
+        journal.record(&intent).map_err(journal_error)?;
+
+        let size = data.len() as u64;
+        let mut hasher = xet_verify::StreamingHasher::new();
+        hasher.update(&data);
+        let (sha256, _) = hasher.finish();
+        let started = Instant::now();
+        let uploaded = self.block_on(async {
+            data::data_client::upload_bytes_async(
+                vec![data],
+                Some(jwt.cas_url()),
+                Some((jwt.access_token(), jwt.exp())),
+                None,
+                None,
+                self.user_agent().to_string(),
+            )
+            .await
+            .map_err(XetError::from)
+        });
+        if let Err(e) = uploaded {
+            // Nothing reached the Hub that a later recovery could commit.
+            journal.complete(&intent.id).map_err(journal_error)?;
+            return Err(e);
+        }
+        self.record_upload(&jwt, size, started.elapsed());
+
+        let mut operations = vec![xet_commit::CommitOperation::AddXetFile {
+            path_in_repo,
+            sha256,
+            size,
+        }];
+        if let Some(gitattributes) = gitattributes {
+            operations.push(xet_commit::CommitOperation::AddFile {
+                path_in_repo: xet_gitattributes::GITATTRIBUTES_PATH.to_string(),
+                content: gitattributes.into_bytes(),
+            });
+        }
+        intent.operations = operations;
+        intent.state = IntentState::Uploaded;
+        journal.record(&intent).map_err(journal_error)?;
+
+        let response = self.create_journaled_commit(&intent)?;
+        journal.complete(&intent.id).map_err(journal_error)?;
+        Ok(response.commit_oid)
+    }
+
+    /// Uploads a file from disk and commits it to the repository.
     ///
-    /// Use this initializer to create a client for accessing public repositories.
-    /// For private repositories or upload operations, use `with_token` instead.
+    /// The file is chunked and deduplicated as it's read, so content already
+    /// in CAS isn't uploaded again and the file is never held in memory. Like
+    /// `publish`, the commit is journaled so `recover_pending_commits` can
+    /// finish it if the process exits after the upload, and a `.gitattributes`
+    /// rule storing the file with LFS is added in the same commit when needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `local_path` - The path of the file on disk.
+    /// * `path_in_repo` - The path the file is committed to, relative to the repository root.
+    /// * `revision` - The branch the commit is created on, or `None` for the default revision.
+    /// * `commit_message` - The summary of the commit.
     ///
     /// # Returns
     ///
-    /// A new `XetClient` instance.
+    /// The ID of the created commit.
     ///
     /// # Errors
     ///
-    /// Returns `XetError` if the client cannot be initialized, such as when
-    /// the runtime cannot be created.
-    pub fn new() -> Result<Self, XetError> {
-        // Apply high-performance defaults BEFORE creating the client
-        Self::apply_performance_defaults();
-        
-        let runtime = tokio::runtime::Runtime::new().map_err(|e| XetError::IoError {
-            message: format!("Failed to create tokio runtime: {}", e),
-        })?;
-
-        let http_client = reqwest::Client::builder()
-            .user_agent(concat!(
-                env!("CARGO_PKG_NAME"),
-                "/",
-                env!("CARGO_PKG_VERSION")
-            ))
-            .build()
-            .map_err(|e| XetError::NetworkError {
-                message: format!("Failed to create HTTP client: {}", e),
-            })?;
-
-        Ok(Self {
-            runtime,
-            http_client,
-            endpoint: "https://huggingface.co".to_string(),
-            token: None,
-        })
+    /// Returns `XetError::InvalidInput` if `repo`, `path_in_repo`, or
+    /// `commit_message` is empty, or the file changes during the upload;
+    /// `XetError::IoError` if `local_path` isn't a readable regular file; and
+    /// otherwise the same errors as `publish`.
+    #[cfg(feature = "upload")]
+    pub fn upload_file(
+        &self,
+        repo: String,
+        local_path: String,
+        path_in_repo: String,
+        revision: Option<String>,
+        commit_message: String,
+    ) -> Result<String, XetError> {
+        self.upload_file_with_progress(
+            repo,
+            local_path,
+            path_in_repo,
+            revision,
+            commit_message,
+            None,
+        )
     }
 
-    /// Creates a new Xet client with an authentication token.
+    /// Uploads a file from disk and commits it, reporting progress as it's uploaded.
     ///
-    /// Use this initializer when you need to access private repositories or
-    /// perform upload operations. You can obtain a token from your
-    /// [Hugging Face account settings](https://huggingface.co/settings/tokens).
+    /// Progress is reported as the file is chunked and hashed, in the
+    /// processed bytes, and as its content is sent, in the transfer bytes.
+    /// Content already in storage is deduplicated rather than sent, so the
+    /// transfer total can be far below the file's size.
     ///
     /// # Arguments
     ///
-    /// * `token` - A Hugging Face authentication token. The token must not be empty.
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `local_path` - The path of the file on disk.
+    /// * `path_in_repo` - The path the file is committed to, relative to the repository root.
+    /// * `revision` - The branch the commit is created on, or `None` for the default revision.
+    /// * `commit_message` - The summary of the commit.
+    /// * `progress` - An optional callback that receives progress updates.
     ///
     /// # Returns
     ///
-    /// A new `XetClient` instance configured with the provided token.
+    /// The ID of the created commit.
     ///
     /// # Errors
     ///
-    /// Returns `XetError::InvalidInput` if the token is empty, or `XetError`
-    /// if the client cannot be initialized.
-    pub fn with_token(token: String) -> Result<Self, XetError> {
-        if token.is_empty() {
-            return Err(XetError::InvalidInput {
-                message: "Token cannot be empty".to_string(),
-            });
-        }
-
-        // Apply high-performance defaults BEFORE creating the client
-        Self::apply_performance_defaults();
-
-        let runtime = tokio::runtime::Runtime::new().map_err(|e| XetError::IoError {
-            message: format!("Failed to create tokio runtime: {}", e),
-        })?;
-
-        let http_client = reqwest::Client::builder()
-            .user_agent(concat!(
-                env!("CARGO_PKG_NAME"),
-                "/",
-                env!("CARGO_PKG_VERSION")
-            ))
-            .build()
-            .map_err(|e| XetError::NetworkError {
-                message: format!("Failed to create HTTP client: {}", e),
-            })?;
-
-        Ok(Self {
-            runtime,
-            http_client,
-            endpoint: "https://huggingface.co".to_string(),
-            token: Some(token),
-        })
-    }
-
-    /// Get plural form of repo type for API URLs
-    fn repo_type_plural(&self, repo_type: &hub_client::HFRepoType) -> &'static str {
-        match repo_type {
-            hub_client::HFRepoType::Model => "models",
-            hub_client::HFRepoType::Dataset => "datasets",
-            hub_client::HFRepoType::Space => "spaces",
-        }
+    /// Returns the same errors as `upload_file`.
+    #[cfg(feature = "upload")]
+    pub fn upload_file_with_progress(
+        &self,
+        repo: String,
+        local_path: String,
+        path_in_repo: String,
+        revision: Option<String>,
+        commit_message: String,
+        progress: Option<Box<dyn ProgressCallback>>,
+    ) -> Result<String, XetError> {
+        let session = Arc::new(UploadSession::new());
+        self.stage_file(session.clone(), local_path, path_in_repo)?;
+        let branch = self.resolve_revision(revision);
+        let files = session.staged_files();
+        let progress = progress.map(Arc::from);
+        let (commit_oid, _) = self.commit_files(&files, repo, branch, commit_message, progress)?;
+        Ok(commit_oid)
     }
 
-    /// Parse repository identifier into RepoInfo
+    /// Stages a local file to be published to a repository with `publish`.
     ///
-    /// Supports formats:
-    /// - "owner/repo" (defaults to model type)
-    /// - "models/owner/repo"
-    /// - "datasets/owner/repo"
-    /// - "spaces/owner/repo"
-    fn parse_repo(&self, repo: &str) -> Result<HubRepoInfo, XetError> {
-        let parts: Vec<&str> = repo.split('/').collect();
-
-        if parts.len() < 2 {
+    /// The file isn't read or uploaded until the session is published, so it
+    /// must stay in place and unchanged until then. Staging a file at a path
+    /// already staged in the session replaces the earlier file.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The session to stage the file in.
+    /// * `local_path` - The path of the file on disk.
+    /// * `repo_path` - The path the file is committed to, relative to the repository root.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo_path` is empty, or
+    /// `XetError::IoError` if `local_path` isn't a regular file.
+    #[cfg(feature = "upload")]
+    pub fn stage_file(
+        &self,
+        session: Arc<UploadSession>,
+        local_path: String,
+        repo_path: String,
+    ) -> Result<(), XetError> {
+        let path_in_repo = repo_path.trim_matches('/').to_string();
+        if path_in_repo.is_empty() {
             return Err(XetError::InvalidInput {
-                message: format!("Repository identifier must be in format 'owner/repo' or 'type/owner/repo', got: {}", repo)
+                message: "Path in repository cannot be empty".to_string(),
             });
         }
-
-        // Check if first part is a repo type
-        let (repo_type_str, repo_id) = if parts.len() >= 3 {
-            let first = parts[0].to_lowercase();
-            if first == "models"
-                || first == "model"
-                || first == "datasets"
-                || first == "dataset"
-                || first == "spaces"
-                || first == "space"
-            {
-                (parts[0], parts[1..].join("/"))
-            } else {
-                ("model", repo.to_string())
-            }
-        } else {
-            ("model", repo.to_string())
-        };
-
-        HubRepoInfo::try_from(repo_type_str, &repo_id).map_err(|e| XetError::InvalidInput {
-            message: format!("Invalid repository: {}", e),
-        })
+        let file = StagedFile::new(PathBuf::from(self.local_path(&local_path)), path_in_repo)
+            .map_err(|e| XetError::IoError {
+                message: format!("Failed to stage {}: {}", local_path, e),
+                cause: Some(ErrorCause::from(&e)),
+            })?;
+        let mut staged = session
+            .staged
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        xet_staging::stage(&mut staged, file);
+        Ok(())
     }
 
-    /// Returns the version of the Xet client library.
+    /// Uploads the files staged in a session and commits them to a branch in a single commit.
+    ///
+    /// Every file reaches CAS before the commit is created, and the Hub applies
+    /// a commit in full or not at all, so if any upload or the commit fails,
+    /// none of the files become visible in the repository. The commit is
+    /// journaled, so `recover_pending_commits` can finish it if the process
+    /// exits after the uploads.
+    ///
+    /// The journal also records the files that reach CAS as the upload goes.
+    /// If it fails or the process exits partway through, publishing the same
+    /// files with the same message to the same branch skips the files already
+    /// uploaded and unchanged since. Files are recorded whole, so a file that
+    /// was cut off partway is uploaded again from its start.
+    ///
+    /// A file that changed since it was staged fails the publish before
+    /// anything is committed. A published session is emptied; a session that
+    /// fails to publish keeps its files staged, so it can be published again.
+    ///
+    /// As with `upload_bytes`, rules storing the files with LFS are added to
+    /// the repository's `.gitattributes` in the same commit when needed, unless
+    /// the session stages a `.gitattributes` of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The session whose staged files are published.
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `branch` - The branch the commit is created on.
+    /// * `message` - The summary of the commit.
     ///
     /// # Returns
     ///
-    /// A string containing the semantic version number (e.g., `"1.0.0"`).
-    pub fn version(&self) -> String {
-        env!("CARGO_PKG_VERSION").to_string()
+    /// The ID of the created commit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XetError::InvalidInput` if `repo`, `branch`, or `message` is
+    /// empty, the session has no staged files, or a file changed since it was
+    /// staged; `XetError::AuthError` if the client has no token;
+    /// `XetError::IoError` if a staged file can't be read;
+    /// `XetError::CacheError` if the commit can't be journaled; or
+    /// `XetError::NetworkError` if an upload or the commit fails.
+    #[cfg(feature = "upload")]
+    pub fn publish(
+        &self,
+        session: Arc<UploadSession>,
+        repo: String,
+        branch: String,
+        message: String,
+    ) -> Result<String, XetError> {
+        let files = session.staged_files();
+        let (commit_oid, _) = self.commit_files(&files, repo, branch, message, None)?;
+        session
+            .staged
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|staged| !files.contains(staged));
+        Ok(commit_oid)
     }
 
-    /// Retrieves the content of a file from a Xet repository.
+    /// Uploads many local files in one session and commits them all in a single commit.
     ///
-    /// This method downloads the file content and returns it as raw bytes.
-    /// For binary files or large files, consider using `download_file` instead
-    /// to save the file directly to disk.
+    /// Files are chunked and deduplicated together, so content shared between
+    /// them, down to individual chunks, is uploaded once. As with `publish`,
+    /// either every file lands in the repository or none does, the commit is
+    /// journaled so `recover_pending_commits` can finish it, and uploading the
+    /// same files again after an interruption skips those already sent. A request for
+    /// a path requested earlier in the batch replaces the earlier request.
     ///
     /// # Arguments
     ///
     /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
-    /// * `path` - The path of the file within the repository, relative to the repository root.
-    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to `"main"`.
+    /// * `requests` - The files to upload and the paths they're committed to.
+    /// * `revision` - The branch the commit is created on, or `None` for the default revision.
+    /// * `commit_message` - The summary of the commit.
     ///
     /// # Returns
     ///
-    /// The file content as a byte vector.
+    /// The ID of the created commit and the outcome of each file, in the
+    /// order they were requested.
     ///
     /// # Errors
     ///
-    /// Returns `XetError::InvalidInput` if `repo` or `path` is empty, or `XetError::NetworkError`
-    /// if the file cannot be retrieved from the repository.
-    pub fn get_file_content(
+    /// Returns `XetError::InvalidInput` if `requests` is empty or names an
+    /// empty path, `XetError::IoError` if a local path isn't a regular file,
+    /// and otherwise the same errors as `publish`.
+    #[cfg(feature = "upload")]
+    pub fn upload_files(
         &self,
         repo: String,
-        path: String,
+        requests: Vec<FileUploadRequest>,
         revision: Option<String>,
-    ) -> Result<Vec<u8>, XetError> {
-        if repo.is_empty() {
-            return Err(XetError::InvalidInput {
-                message: "Repository cannot be empty".to_string(),
-            });
-        }
-        if path.is_empty() {
-            return Err(XetError::InvalidInput {
-                message: "Path cannot be empty".to_string(),
-            });
-        }
-
-        let repo_info = self.parse_repo(&repo)?;
-        let resolved_revision = revision.unwrap_or_else(|| "main".to_string());
+        commit_message: String,
+    ) -> Result<Arc<BatchUploadResult>, XetError> {
+        self.upload_files_with_progress(repo, requests, revision, commit_message, None)
+    }
 
-        if let Ok(metadata) = self.runtime.block_on(fetch_file_metadata(
-            &self.endpoint,
-            self.repo_type_plural(&repo_info.repo_type),
-            &repo_info.full_name,
-            &path,
-            &resolved_revision,
-            self.token.as_ref(),
-        )) {
-            if let Ok(bytes) = self.http_get_bytes(&metadata.download_url) {
-                return Ok(bytes);
-            }
+    /// Uploads many local files in one session and commits them all, reporting progress.
+    ///
+    /// Progress covers the whole batch, as in `upload_file_with_progress`: the
+    /// processed bytes advance as the files are chunked and hashed, and the
+    /// transfer bytes as content not deduplicated is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `requests` - The files to upload and the paths they're committed to.
+    /// * `revision` - The branch the commit is created on, or `None` for the default revision.
+    /// * `commit_message` - The summary of the commit.
+    /// * `progress` - An optional callback that receives progress updates.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `upload_files`.
+    #[cfg(feature = "upload")]
+    pub fn upload_files_with_progress(
+        &self,
+        repo: String,
+        requests: Vec<FileUploadRequest>,
+        revision: Option<String>,
+        commit_message: String,
+        progress: Option<Box<dyn ProgressCallback>>,
+    ) -> Result<Arc<BatchUploadResult>, XetError> {
+        let session = Arc::new(UploadSession::new());
+        for request in requests {
+            self.stage_file(session.clone(), request.local_path, request.path_in_repo)?;
         }
-
-        self.get_file_content_legacy(repo_info, path, resolved_revision)
+        let branch = self.resolve_revision(revision);
+        let progress = progress.map(Arc::from);
+        self.commit_batch(
+            session.staged_files(),
+            repo,
+            branch,
+            commit_message,
+            progress,
+        )
     }
 
-    /// Lists all files in a directory within a Xet repository.
+    /// Uploads the files in a local directory and commits them to the repository.
     ///
-    /// This method returns only file paths, not directories. For more detailed
-    /// information including file metadata, use `list_files_with_metadata`.
+    /// The directory is walked recursively and its files are uploaded to the
+    /// same relative paths under `path_in_repo`. Files are chunked and
+    /// deduplicated together within each commit. Folders with many files are
+    /// committed in several commits of at most 100 files each, numbered in
+    /// their messages, so each commit stays within the Hub's limits; if one
+    /// fails, the commits before it remain in the repository.
+    ///
+    /// Patterns match paths relative to `local_dir`, as in
+    /// `download_files_batch`, and a pattern ending in `/` matches everything
+    /// below that directory. `.git` directories and `huggingface_hub`'s
+    /// `.cache/huggingface/` are always ignored. Empty directories aren't
+    /// uploaded, since a repository only records files.
     ///
     /// # Arguments
     ///
     /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
-    /// * `path` - The directory path within the repository. Use an empty string for the root directory.
-    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to `"main"`.
+    /// * `local_dir` - The directory to upload.
+    /// * `path_in_repo` - The directory the files are committed to, or `""` for the repository root.
+    /// * `revision` - The branch the commits are created on, or `None` for the default revision.
+    /// * `allow_patterns` - Only files matching at least one of these patterns are uploaded; empty to upload all.
+    /// * `ignore_patterns` - Files matching any of these patterns are skipped.
+    /// * `commit_message` - The summary of the commits.
     ///
     /// # Returns
     ///
-    /// An array of file paths, relative to the repository root.
+    /// The outcome of each commit, in the order they were created.
     ///
     /// # Errors
     ///
-    /// Returns `XetError::InvalidInput` if `repo` is empty, or `XetError::NetworkError`
-    /// if the directory listing cannot be retrieved.
-    pub fn list_files(
+    /// Returns `XetError::InvalidInput` if a pattern is invalid or no file in
+    /// the directory is selected, `XetError::IoError` if `local_dir` isn't a
+    /// directory or can't be read, and otherwise the same errors as `publish`.
+    #[cfg(feature = "upload")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn upload_folder(
         &self,
         repo: String,
-        path: String,
+        local_dir: String,
+        path_in_repo: String,
         revision: Option<String>,
-    ) -> Result<Vec<String>, XetError> {
-        if repo.is_empty() {
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+        commit_message: String,
+    ) -> Result<Vec<Arc<BatchUploadResult>>, XetError> {
+        let mut ignore_patterns = ignore_patterns;
+        ignore_patterns.extend(UPLOAD_FOLDER_IGNORED.map(str::to_string));
+        let filter = PathFilter::new(allow_patterns, ignore_patterns)?;
+        let prefix = path_in_repo.trim_matches('/');
+        let dir = PathBuf::from(self.local_path(&local_dir));
+        let listed = xet_staging::folder_files(&dir).map_err(|e| XetError::IoError {
+            message: format!("Failed to read {}: {}", local_dir, e),
+            cause: Some(ErrorCause::from(&e)),
+        })?;
+        let mut files = Vec::new();
+        for (local_path, relative) in listed {
+            if !filter.includes(&relative) {
+                continue;
+            }
+            let path_in_repo = match prefix {
+                "" => relative,
+                prefix => format!("{}/{}", prefix, relative),
+            };
+            let file = StagedFile::new(local_path.clone(), path_in_repo).map_err(|e| {
+                XetError::IoError {
+                    message: format!("Failed to stage {}: {}", local_path.display(), e),
+                    cause: Some(ErrorCause::from(&e)),
+                }
+            })?;
+            files.push(file);
+        }
+        if files.is_empty() {
             return Err(XetError::InvalidInput {
-                message: "Repository cannot be empty".to_string(),
+                message: format!("No files in {} match the patterns", local_dir),
             });
         }
 
-        let repo_info = self.parse_repo(&repo)?;
-        let rev = revision.as_deref().unwrap_or("main");
-        let encoded_rev = encode(rev);
-
-        // Build URL for tree API
-        let url = if path.is_empty() {
-            format!(
-                "{}/api/{}/{}/tree/{}",
-                self.endpoint,
-                self.repo_type_plural(&repo_info.repo_type),
-                repo_info.full_name,
-                encoded_rev
-            )
-        } else {
-            let encoded_path = encode(&path);
-            format!(
-                "{}/api/{}/{}/tree/{}/{}",
-                self.endpoint,
-                self.repo_type_plural(&repo_info.repo_type),
-                repo_info.full_name,
-                encoded_rev,
-                encoded_path
-            )
-        };
-
-        let file_paths = self.runtime.block_on(async {
-            let mut request = self.http_client.get(&url);
-
-            if let Some(token) = &self.token {
-                request = request.bearer_auth(token);
-            }
-
-            let response = request.send().await.map_err(|e| XetError::from(e))?;
-            let response = response.error_for_status().map_err(|e| XetError::from(e))?;
-            let body = response.text().await.map_err(|e| XetError::from(e))?;
-
-            // Try to parse as TreeResponse first, then as direct array
-            let entries = match serde_json::from_str::<TreeResponse>(&body) {
-                Ok(tree_resp) => tree_resp.tree.unwrap_or_default(),
-                Err(_) => {
-                    // Try parsing as direct array
-                    serde_json::from_str::<Vec<TreeEntry>>(&body).map_err(|e| XetError::from(e))?
-                }
+        let branch = self.resolve_revision(revision);
+        let batches = files.len().div_ceil(UPLOAD_FOLDER_BATCH_SIZE);
+        let mut results = Vec::with_capacity(batches);
+        for (index, batch) in files.chunks(UPLOAD_FOLDER_BATCH_SIZE).enumerate() {
+            let message = match batches {
+                1 => commit_message.clone(),
+                _ => format!("{} (part {} of {})", commit_message, index + 1, batches),
             };
-
-            Ok::<Vec<String>, XetError>(
-                entries
-                    .into_iter()
-                    .filter(|entry| entry.entry_type == "file")
-                    .map(|entry| entry.path)
-                    .collect(),
-            )
-        })?;
-
-        Ok(file_paths)
+            let result =
+                self.commit_batch(batch.to_vec(), repo.clone(), branch.clone(), message, None)?;
+            results.push(result);
+        }
+        Ok(results)
     }
 
-    /// Lists all entries in a directory within a Xet repository with metadata.
+    /// Asks the Hub how each file would be stored if committed, before any is uploaded.
     ///
-    /// This method returns both files and directories with their associated metadata,
-    /// including sizes, hashes, and Git object IDs when available.
+    /// The Hub decides from each file's path, size, and leading bytes, and from
+    /// the repository's `.gitattributes`, whether its content goes to Xet
+    /// storage or inline in git, and from its `.gitignore` whether it should be
+    /// skipped. Checking first lets a large ingest plan its uploads, or skip
+    /// files whose `remote_oid` shows they're already committed.
     ///
     /// # Arguments
     ///
     /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
-    /// * `path` - The directory path within the repository. Use an empty string for the root directory.
-    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to `"main"`.
+    /// * `files` - The files to check.
+    /// * `revision` - The branch the files would be committed to, or `None` for the default revision.
     ///
     /// # Returns
     ///
-    /// An array of `FileMetadata` objects containing information about each entry.
+    /// The verdict on each file, in the order they were given.
     ///
     /// # Errors
     ///
-    /// Returns `XetError::InvalidInput` if `repo` is empty, or `XetError::NetworkError`
-    /// if the directory listing cannot be retrieved.
-    pub fn list_files_with_metadata(
+    /// Returns `XetError::InvalidInput` if `repo` or a path is empty,
+    /// `XetError::AuthError` if the client has no token, or
+    /// `XetError::NetworkError` if a request fails or the Hub leaves a file out.
+    #[cfg(feature = "upload")]
+    pub fn preupload(
         &self,
         repo: String,
-        path: String,
+        files: Vec<PreuploadFile>,
         revision: Option<String>,
-    ) -> Result<Vec<Arc<FileMetadata>>, XetError> {
+    ) -> Result<Vec<PreuploadResult>, XetError> {
         if repo.is_empty() {
             return Err(XetError::InvalidInput {
                 message: "Repository cannot be empty".to_string(),
             });
         }
-
-        let repo_info = self.parse_repo(&repo)?;
-        let rev = revision.as_deref().unwrap_or("main");
-        let encoded_rev = encode(rev);
-
-        // Build URL for tree API
-        let url = if path.is_empty() {
-            format!(
-                "{}/api/{}/{}/tree/{}",
-                self.endpoint,
-                self.repo_type_plural(&repo_info.repo_type),
-                repo_info.full_name,
-                encoded_rev
-            )
-        } else {
-            let encoded_path = encode(&path);
-            format!(
-                "{}/api/{}/{}/tree/{}/{}",
-                self.endpoint,
-                self.repo_type_plural(&repo_info.repo_type),
-                repo_info.full_name,
-                encoded_rev,
-                encoded_path
-            )
+        let paths: Vec<String> = files
+            .iter()
+            .map(|file| file.path_in_repo.trim_matches('/').to_string())
+            .collect();
+        if paths.iter().any(String::is_empty) {
+            return Err(XetError::InvalidInput {
+                message: "Path in repository cannot be empty".to_string(),
+            });
+        }
+        let Some(token) = &self.token else {
+            return Err(XetError::AuthError {
+                message: "Checking uploads requires an authentication token".to_string(),
+            });
         };
 
-        let metadata = self.runtime.block_on(async {
-            let mut request = self.http_client.get(&url);
-
-            if let Some(token) = &self.token {
-                request = request.bearer_auth(token);
+        let repo_info = self.parse_repo(&repo)?;
+        let revision = self.resolve_revision(revision);
+        let url = self.revision_route(
+            self.endpoint_for(&repo_info),
+            &repo_info,
+            "preupload",
+            &revision,
+        );
+        let entries = self.block_on(async {
+            let mut entries = HashMap::with_capacity(files.len());
+            for (files, paths) in files
+                .chunks(PREUPLOAD_BATCH_SIZE)
+                .zip(paths.chunks(PREUPLOAD_BATCH_SIZE))
+            {
+                let batch: Vec<_> = files
+                    .iter()
+                    .zip(paths)
+                    .map(|(file, path)| (path.as_str(), file.size, file.sample.as_slice()))
+                    .collect();
+                let request = self
+                    .http_client()?
+                    .post(&url)
+                    .bearer_auth(token)
+                    .json(&xet_commit::preupload_payload(&batch));
+                let response = self.send(request).await?.error_for_status()?;
+                let page: xet_commit::PreuploadResponse =
+                    xet_http::read_json(response, self.max_response_body_bytes()).await?;
+                entries.extend(
+                    page.files
+                        .into_iter()
+                        .map(|entry| (entry.path.clone(), entry)),
+                );
             }
-
-            let response = request.send().await.map_err(|e| XetError::from(e))?;
-            let response = response.error_for_status().map_err(|e| XetError::from(e))?;
-            let body = response.text().await.map_err(|e| XetError::from(e))?;
-
-            // Try to parse as TreeResponse first, then as direct array
-            let entries = match serde_json::from_str::<TreeResponse>(&body) {
-                Ok(tree_resp) => tree_resp.tree.unwrap_or_default(),
-                Err(_) => {
-                    // Try parsing as direct array
-                    serde_json::from_str::<Vec<TreeEntry>>(&body).map_err(|e| XetError::from(e))?
-                }
-            };
-
-            Ok::<Vec<Arc<FileMetadata>>, XetError>(
-                entries
-                    .into_iter()
-                    .map(|entry| Arc::new(FileMetadata::from(entry)))
-                    .collect(),
-            )
+            Ok(entries)
         })?;
 
-        Ok(metadata)
+        paths
+            .into_iter()
+            .map(|path| {
+                let entry = entries.get(&path).ok_or_else(|| XetError::NetworkError {
+                    message: format!("The Hub didn't report how to upload {}", path),
+                    cause: None,
+                })?;
+                Ok(PreuploadResult {
+                    upload_mode: match entry.upload_mode.as_str() {
+                        "regular" => UploadMode::Regular,
+                        _ => UploadMode::Xet,
+                    },
+                    should_ignore: entry.should_ignore,
+                    remote_oid: entry.oid.clone(),
+                    path_in_repo: path,
+                })
+            })
+            .collect()
     }
 
-    /// Downloads a file from a Xet repository to a local path.
+    /// Estimates what uploading local files would transfer, without uploading them.
     ///
-    /// This method downloads the file content and saves it to the specified destination.
-    /// The parent directory of the destination path will be created if it doesn't exist.
+    /// The files are chunked and hashed as an upload would, and CAS is asked
+    /// which of their chunks it already has, but no xorb or shard is sent, so
+    /// users can preview the cost of a sync before starting it. Chunks
+    /// repeated within the files, uploaded earlier from this device, or found
+    /// in CAS from other uploads are counted as deduplicated.
     ///
     /// # Arguments
     ///
     /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
-    /// * `path` - The path of the file within the repository, relative to the repository root.
-    /// * `destination` - The local file path where the downloaded file should be saved.
-    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to `"main"`.
+    /// * `local_paths` - The paths of the files on disk.
+    /// * `revision` - The branch the files would be committed to, or `None` for the default revision.
+    ///
+    /// # Returns
+    ///
+    /// The bytes and chunks that would and wouldn't be transferred.
     ///
     /// # Errors
     ///
-    /// Returns `XetError::InvalidInput` if any parameter is empty, `XetError::IoError`
-    /// if the file cannot be written to disk, or `XetError::NetworkError` if the file
-    /// cannot be downloaded.
-    pub fn download_file(
+    /// Returns `XetError::InvalidInput` if `local_paths` is empty,
+    /// `XetError::AuthError` if the client has no token, `XetError::IoError`
+    /// if a local path isn't a regular file, or `XetError::NetworkError` if
+    /// CAS can't be reached.
+    #[cfg(feature = "upload")]
+    pub fn estimate_upload(
         &self,
         repo: String,
-        path: String,
-        destination: String,
+        local_paths: Vec<String>,
         revision: Option<String>,
-    ) -> Result<(), XetError> {
-        if repo.is_empty() {
+    ) -> Result<UploadEstimate, XetError> {
+        if local_paths.is_empty() {
             return Err(XetError::InvalidInput {
-                message: "Repository cannot be empty".to_string(),
+                message: "No files to estimate".to_string(),
             });
         }
-        if path.is_empty() {
-            return Err(XetError::InvalidInput {
-                message: "Path cannot be empty".to_string(),
+        if self.token.is_none() {
+            return Err(XetError::AuthError {
+                message: "Estimating an upload requires an authentication token".to_string(),
             });
         }
-        if destination.is_empty() {
-            return Err(XetError::InvalidInput {
-                message: "Destination cannot be empty".to_string(),
-            });
+        let paths: Vec<String> = local_paths
+            .iter()
+            .map(|local_path| self.local_path(local_path))
+            .collect();
+        for path in &paths {
+            let metadata = std::fs::metadata(path).map_err(|e| XetError::IoError {
+                message: format!("Failed to read {}: {}", path, e),
+                cause: Some(ErrorCause::from(&e)),
+            })?;
+            if !metadata.is_file() {
+                return Err(XetError::IoError {
+                    message: format!("{} is not a regular file", path),
+                    cause: None,
+                });
+            }
         }
 
-        let repo_info = self.parse_repo(&repo)?;
-        let resolved_revision = revision.unwrap_or_else(|| "main".to_string());
-
-        let metadata_result = self.runtime.block_on(fetch_file_metadata(
-            &self.endpoint,
-            self.repo_type_plural(&repo_info.repo_type),
-            &repo_info.full_name,
-            &path,
-            &resolved_revision,
-            self.token.as_ref(),
-        ));
-
-        match metadata_result {
-            Ok(metadata) => {
-                if let Some(xet_data) = metadata.xet_file_data.clone() {
-                    if self
-                        .runtime
-                        .block_on(self.download_with_xet_async(
-                            &xet_data,
-                            metadata.size,
-                            &destination,
-                        ))
-                        .is_ok()
-                    {
-                        return Ok(());
-                    }
-                }
-
-                if self
-                    .download_http_with_metadata(&metadata, &destination)
-                    .is_ok()
-                {
-                    return Ok(());
-                }
-
-                self.download_file_legacy(repo_info, path, destination, Some(resolved_revision))
-            }
-            Err(_) => {
-                self.download_file_legacy(repo_info, path, destination, Some(resolved_revision))
+        let revision = self.resolve_revision(revision);
+        let jwt = self.get_cas_jwt(repo, Some(revision), true)?;
+        let (metrics, _) = self.block_on(async {
+            let config = data::data_client::default_config(
+                jwt.cas_url(),
+                None,
+                Some((jwt.access_token(), jwt.exp())),
+                None,
+                self.user_agent().to_string(),
+            )?;
+            // A dry run cleans files as an upload does but never sends what it produces.
+            let session = data::FileUploadSession::dry_run(Arc::new(config), None).await?;
+            for path in &paths {
+                data::data_client::clean_file(session.clone(), path).await?;
             }
-        }
+            Ok(session.finalize_with_report().await?)
+        })?;
+        Ok(UploadEstimate {
+            total_bytes: metrics.total_bytes,
+            new_bytes: metrics.new_bytes,
+            deduplicated_bytes: metrics.deduped_bytes,
+            globally_deduplicated_bytes: metrics.deduped_bytes_by_global_dedup,
+            total_chunks: metrics.total_chunks,
+            new_chunks: metrics.new_chunks,
+        })
     }
 
-    /// Downloads multiple files in a single batch operation.
+    /// Deletes a file from the repository in a new commit.
     ///
-    /// This method processes download requests sequentially. If any download fails,
-    /// the operation stops and returns an error. All successfully downloaded files
-    /// are saved before the error is reported.
+    /// Like uploads, the commit is journaled, so `recover_pending_commits` can
+    /// finish it if the process exits before the Hub responds, and it carries
+    /// an idempotency key, so a retried deletion isn't committed twice.
     ///
     /// # Arguments
     ///
-    /// * `requests` - An array of `FileDownloadRequest` objects, each specifying a file to download.
+    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
+    /// * `path` - The path of the file to delete, relative to the repository root.
+    /// * `revision` - The branch the commit is created on, or `None` for the default revision.
+    /// * `commit_message` - The summary of the commit.
     ///
     /// # Returns
     ///
-    /// An array of destination paths for successfully downloaded files.
+    /// The ID of the created commit.
     ///
     /// # Errors
     ///
-    /// Returns `XetError::OperationFailed` if any download fails, with details
-    /// about which file failed and why.
-    pub fn download_files_batch(
+    /// Returns `XetError::InvalidInput` if `repo`, `path`, or `commit_message`
+    /// is empty, `XetError::AuthError` if the client has no token,
+    /// `XetError::CacheError` if the commit can't be journaled, or
+    /// `XetError::NetworkError` if the commit fails, such as when the file
+    /// doesn't exist.
+    #[cfg(feature = "upload")]
+    pub fn delete_file(
         &self,
-        requests: Vec<Arc<FileDownloadRequest>>,
-    ) -> Result<Vec<String>, XetError> {
-        let mut results = Vec::new();
-
-        for request in requests {
-            match self.download_file(
-                request.repo(),
-                request.path(),
-                request.destination(),
-                request.revision(),
-            ) {
-                Ok(_) => results.push(request.destination()),
-                Err(e) => {
-                    return Err(XetError::OperationFailed {
-                        message: format!("Failed to download {}: {}", request.path(), e),
-                    });
-                }
-            }
+        repo: String,
+        path: String,
+        revision: Option<String>,
+        commit_message: String,
+    ) -> Result<String, XetError> {
+        let path_in_repo = path.trim_matches('/').to_string();
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+        if path_in_repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Path in repository cannot be empty".to_string(),
+            });
+        }
+        if commit_message.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Commit message cannot be empty".to_string(),
+            });
+        }
+        if self.token.is_none() {
+            return Err(XetError::AuthError {
+                message: "Deleting files requires an authentication token".to_string(),
+            });
         }
 
-        Ok(results)
+        let repo_info = self.parse_repo(&repo)?;
+        let revision = self.resolve_revision(revision);
+        self.commit_operations(
+            &repo_info,
+            &revision,
+            &commit_message,
+            vec![xet_commit::CommitOperation::Delete { path_in_repo }],
+        )
     }
 
-    /// Retrieves a JWT token for accessing the Content-Addressable Storage (CAS) system.
+    /// Moves a file to a new path in the repository in a new commit.
     ///
-    /// This method obtains an authentication token that can be used to download or upload
-    /// files directly through Xet's CAS system. The token is valid until its expiration time.
+    /// The Hub's commit API has no move operation, so the commit adds the file
+    /// at `to_path` and deletes it from `from_path`, as `huggingface_hub`
+    /// copies files. Files stored with Xet or LFS are added by their SHA-256
+    /// digest, so their content isn't transferred; files stored in git are
+    /// downloaded and committed again. Any file at `to_path` is replaced. Like
+    /// deletions, the commit is journaled and carries an idempotency key.
     ///
     /// # Arguments
     ///
     /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
-    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to `"main"`.
-    /// * `is_upload` - `true` for upload operations, `false` for download operations.
+    /// * `from_path` - The path of the file to move, relative to the repository root.
+    /// * `to_path` - The path to move the file to, relative to the repository root.
+    /// * `revision` - The branch the commit is created on, or `None` for the default revision.
+    /// * `commit_message` - The summary of the commit.
     ///
     /// # Returns
     ///
-    /// A `CasJwtInfo` object containing the token and CAS server URL.
+    /// The ID of the created commit.
     ///
     /// # Errors
     ///
-    /// Returns `XetError::InvalidInput` if `repo` is empty, `XetError::AuthError` if
-    /// authentication fails, or `XetError::NetworkError` if the token cannot be retrieved.
-    pub fn get_cas_jwt(
+    /// Returns `XetError::InvalidInput` if any argument is empty, the paths
+    /// are the same, or no file exists at `from_path`, `XetError::AuthError`
+    /// if the client has no token, `XetError::CacheError` if the commit can't
+    /// be journaled, or `XetError::NetworkError` if the file can't be read or
+    /// the commit fails.
+    #[cfg(feature = "upload")]
+    pub fn move_file(
         &self,
         repo: String,
+        from_path: String,
+        to_path: String,
         revision: Option<String>,
-        is_upload: bool,
-    ) -> Result<Arc<CasJwtInfo>, XetError> {
+        commit_message: String,
+    ) -> Result<String, XetError> {
+        let from_path = from_path.trim_matches('/').to_string();
+        let to_path = to_path.trim_matches('/').to_string();
         if repo.is_empty() {
             return Err(XetError::InvalidInput {
                 message: "Repository cannot be empty".to_string(),
             });
         }
+        if from_path.is_empty() || to_path.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Path in repository cannot be empty".to_string(),
+            });
+        }
+        if from_path == to_path {
+            return Err(XetError::InvalidInput {
+                message: format!("{} can't be moved to itself", from_path),
+            });
+        }
+        if commit_message.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Commit message cannot be empty".to_string(),
+            });
+        }
+        if self.token.is_none() {
+            return Err(XetError::AuthError {
+                message: "Moving files requires an authentication token".to_string(),
+            });
+        }
 
         let repo_info = self.parse_repo(&repo)?;
-        let operation = if is_upload {
-            hub_client::Operation::Upload
-        } else {
-            hub_client::Operation::Download
+        let revision = self.resolve_revision(revision);
+        let entry = self
+            .fetch_paths_info(
+                &repo_info,
+                &revision,
+                std::slice::from_ref(&from_path),
+                false,
+            )
+            .map_err(|e| self.explain_access_error(&repo_info, e))?
+            .into_iter()
+            .find(|entry| entry.path == from_path && entry.entry_type == "file")
+            .ok_or_else(|| XetError::InvalidInput {
+                message: format!("{} isn't a file at {}", from_path, revision),
+            })?;
+        let lfs = entry.lfs.as_ref();
+        let oid = lfs.and_then(|lfs| lfs.get("oid")).and_then(|v| v.as_str());
+        let size = lfs.and_then(|lfs| lfs.get("size")).and_then(|v| v.as_u64());
+        let added = match (oid, size) {
+            (Some(sha256), Some(size)) => xet_commit::CommitOperation::AddXetFile {
+                path_in_repo: to_path,
+                sha256: sha256.to_string(),
+                size,
+            },
+            _ => xet_commit::CommitOperation::AddFile {
+                content: self.get_file_content(repo, from_path.clone(), Some(revision.clone()))?,
+                path_in_repo: to_path,
+            },
         };
+        self.commit_operations(
+            &repo_info,
+            &revision,
+            &commit_message,
+            vec![
+                added,
+                xet_commit::CommitOperation::Delete {
+                    path_in_repo: from_path,
+                },
+            ],
+        )
+    }
 
-        let cred_helper: Arc<dyn hub_client::CredentialHelper> = if let Some(token) = &self.token {
-            hub_client::BearerCredentialHelper::new(token.clone(), "swift-xet")
+    /// Commits `operations`, which need no uploads, to `branch`, returning the commit ID.
+    ///
+    /// The commit is journaled first, so `recover_pending_commits` can finish it.
+    #[cfg(feature = "upload")]
+    fn commit_operations(
+        &self,
+        repo_info: &HubRepoInfo,
+        branch: &str,
+        message: &str,
+        operations: Vec<xet_commit::CommitOperation>,
+    ) -> Result<String, XetError> {
+        let journal = CommitJournal::open(&self.cache);
+        let journal_error = |e: std::io::Error| XetError::CacheError {
+            message: format!("Failed to access commit journal: {}", e),
+        };
+        let mut intent = CommitIntent::new(
+            self.endpoint_for(repo_info),
+            self.repo_type_plural(&repo_info.repo_type),
+            &repo_info.full_name,
+            branch,
+            message,
+        );
+        intent.operations = operations;
+        intent.state = IntentState::Uploaded;
+        let _live = LiveIntent::hold(&intent);
+        journal.record(&intent).map_err(journal_error)?;
+
+        let response = self.create_journaled_commit(&intent)?;
+        journal.complete(&intent.id).map_err(journal_error)?;
+        Ok(response.commit_oid)
+    }
+
+    /// Commits `files` with `commit_files` and reports the outcome of each.
+    #[cfg(feature = "upload")]
+    fn commit_batch(
+        &self,
+        files: Vec<StagedFile>,
+        repo: String,
+        branch: String,
+        message: String,
+        progress: Option<Arc<dyn ProgressCallback>>,
+    ) -> Result<Arc<BatchUploadResult>, XetError> {
+        let (commit_oid, uploaded) = self.commit_files(&files, repo, branch, message, progress)?;
+        let deduplicated = xet_staging::duplicate_bytes(&uploaded);
+        let files = files
+            .into_iter()
+            .zip(uploaded)
+            .zip(deduplicated)
+            .map(
+                |((file, (sha256, size)), bytes_deduplicated)| FileUploadResult {
+                    path_in_repo: file.path_in_repo,
+                    sha256,
+                    size,
+                    bytes_deduplicated,
+                },
+            )
+            .collect();
+        Ok(Arc::new(BatchUploadResult { commit_oid, files }))
+    }
+
+    /// Uploads `files` to CAS and commits them to `branch`, returning the
+    /// commit ID and the SHA-256 digest and size of each file.
+    #[cfg(feature = "upload")]
+    fn commit_files(
+        &self,
+        files: &[StagedFile],
+        repo: String,
+        branch: String,
+        message: String,
+        progress: Option<Arc<dyn ProgressCallback>>,
+    ) -> Result<(String, Vec<(String, u64)>), XetError> {
+        if repo.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Repository cannot be empty".to_string(),
+            });
+        }
+        if branch.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Branch cannot be empty".to_string(),
+            });
+        }
+        if message.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Commit message cannot be empty".to_string(),
+            });
+        }
+        if self.token.is_none() {
+            return Err(XetError::AuthError {
+                message: "Uploading requires an authentication token".to_string(),
+            });
+        }
+        if files.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Upload session has no staged files".to_string(),
+            });
+        }
+        check_unchanged(files)?;
+
+        let repo_info = self.parse_repo(&repo)?;
+        let jwt = self.get_cas_jwt(repo, Some(branch.clone()), true)?;
+        let stages_gitattributes = files
+            .iter()
+            .any(|file| file.path_in_repo == xet_gitattributes::GITATTRIBUTES_PATH);
+        let gitattributes = if stages_gitattributes {
+            None
         } else {
-            hub_client::NoopCredentialHelper::new()
+            let paths: Vec<&str> = files
+                .iter()
+                .map(|file| file.path_in_repo.as_str())
+                .collect();
+            self.lfs_gitattributes(&repo_info, &branch, &paths)?
         };
 
-        let user_agent = self.user_agent();
+        // Journal the commit before any content reaches CAS, or pick up the
+        // journaled commit of an interrupted upload of the same files.
+        let journal = CommitJournal::open(&self.cache);
+        let journal_error = |e: std::io::Error| XetError::CacheError {
+            message: format!("Failed to access commit journal: {}", e),
+        };
+        let mut intent = CommitIntent::new(
+            self.endpoint_for(&repo_info),
+            self.repo_type_plural(&repo_info.repo_type),
+            &repo_info.full_name,
+            &branch,
+            &message,
+        );
+        intent.staged = files.iter().map(|file| file.path_in_repo.clone()).collect();
+        intent.staged.sort();
+        if let Some(interrupted) = journal.resumable(&intent).map_err(journal_error)? {
+            intent = interrupted;
+        }
+        let _live = LiveIntent::hold(&intent);
+        journal.record(&intent).map_err(journal_error)?;
 
-        let hub_client = hub_client::HubClient::new(
-            &self.endpoint,
-            repo_info,
-            revision,
-            user_agent,
-            "",
-            cred_helper,
-        )?;
+        let uploaded = match self.upload_staged(files, &jwt, progress, &mut intent, &journal) {
+            Ok(uploaded) => uploaded,
+            // Files already in CAS are kept for the next attempt to resume after.
+            Err(e) if !intent.uploaded.is_empty() => return Err(e),
+            Err(e) => {
+                // Nothing reached the Hub that a later recovery could commit.
+                journal.complete(&intent.id).map_err(journal_error)?;
+                return Err(e);
+            }
+        };
 
-        let jwt_info = self
-            .runtime
-            .block_on(async { hub_client.get_cas_jwt(operation).await })?;
+        let mut operations: Vec<xet_commit::CommitOperation> = files
+            .iter()
+            .zip(&uploaded)
+            .map(
+                |(file, (sha256, size))| xet_commit::CommitOperation::AddXetFile {
+                    path_in_repo: file.path_in_repo.clone(),
+                    sha256: sha256.clone(),
+                    size: *size,
+                },
+            )
+            .collect();
+        if let Some(gitattributes) = gitattributes {
+            operations.push(xet_commit::CommitOperation::AddFile {
+                path_in_repo: xet_gitattributes::GITATTRIBUTES_PATH.to_string(),
+                content: gitattributes.into_bytes(),
+            });
+        }
+        intent.operations = operations;
+        intent.state = IntentState::Uploaded;
+        journal.record(&intent).map_err(journal_error)?;
 
-        Ok(Arc::new(CasJwtInfo::from(jwt_info)))
+        let response = self.create_journaled_commit(&intent)?;
+        journal.complete(&intent.id).map_err(journal_error)?;
+        Ok((response.commit_oid, uploaded))
     }
 
-    /// Downloads files using the Xet Content-Addressable Storage (CAS) system.
+    /// Uploads staged files to CAS, returning the SHA-256 digest and size of each.
     ///
-    /// This method downloads files directly from Xet's CAS system using their content hashes.
-    /// Files are written into `destination_dir`, which will be created if missing.
+    /// Files are uploaded in batches of about `UPLOAD_CHECKPOINT_BYTES`, and
+    /// `intent` is journaled after each batch with the files now in CAS, so a
+    /// retry after a failure or after the process exits skips them. Files
+    /// `intent` already records as uploaded, unchanged since, are skipped. A
+    /// batch cut off partway through is uploaded again in full.
     ///
-    /// # Arguments
+    /// Fails if a file changes while it is hashed or uploaded, since its
+    /// uploaded content may no longer match its digest.
+    #[cfg(feature = "upload")]
+    fn upload_staged(
+        &self,
+        files: &[StagedFile],
+        jwt: &CasJwtInfo,
+        progress: Option<Arc<dyn ProgressCallback>>,
+        intent: &mut CommitIntent,
+        journal: &CommitJournal,
+    ) -> Result<Vec<(String, u64)>, XetError> {
+        let resumed = |file: &StagedFile| {
+            intent
+                .uploaded
+                .iter()
+                .find(|uploaded| uploaded.is_of(file))
+                .map(|uploaded| uploaded.sha256.clone())
+        };
+        let mut digests: Vec<Option<String>> = files.iter().map(resumed).collect();
+        let total_bytes = files.iter().map(StagedFile::size).sum();
+        let resumed_bytes = files
+            .iter()
+            .zip(&digests)
+            .filter(|(_, digest)| digest.is_some())
+            .map(|(file, _)| file.size())
+            .sum();
+        // Entries for files no longer staged, or changed since, can't be resumed.
+        intent
+            .uploaded
+            .retain(|uploaded| files.iter().any(|file| uploaded.is_of(file)));
+
+        let mut batches: Vec<Vec<usize>> = Vec::new();
+        let mut batch_bytes = 0;
+        for (index, file) in files.iter().enumerate() {
+            if digests[index].is_some() {
+                continue;
+            }
+            match batches.last_mut() {
+                Some(batch) if batch_bytes < UPLOAD_CHECKPOINT_BYTES => batch.push(index),
+                _ => {
+                    batches.push(vec![index]);
+                    batch_bytes = 0;
+                }
+            }
+            batch_bytes += file.size();
+        }
+
+        let progress = progress.map(|progress| {
+            Arc::new(UploadProgress::new(
+                progress,
+                self.callbacks.clone(),
+                total_bytes,
+                resumed_bytes,
+            ))
+        });
+        for batch in batches {
+            let mut batch_digests = Vec::with_capacity(batch.len());
+            for &index in &batch {
+                let local_path = &files[index].local_path;
+                let (sha256, _) =
+                    xet_verify::hash_file(local_path).map_err(|e| XetError::IoError {
+                        message: format!("Failed to read {}: {}", local_path.display(), e),
+                        cause: Some(ErrorCause::from(&e)),
+                    })?;
+                batch_digests.push(sha256);
+            }
+
+            let paths = batch
+                .iter()
+                .map(|&index| files[index].local_path.to_string_lossy().into_owned())
+                .collect();
+            if let Some(progress) = &progress {
+                progress.next_batch();
+            }
+            let updater = progress
+                .clone()
+                .map(|progress| progress as Arc<dyn progress_tracking::TrackingProgressUpdater>);
+            let started = Instant::now();
+            let infos = self.block_on(async {
+                data::data_client::upload_async(
+                    paths,
+                    Some(jwt.cas_url()),
+                    Some((jwt.access_token(), jwt.exp())),
+                    None,
+                    updater,
+                    self.user_agent().to_string(),
+                )
+                .await
+                .map_err(XetError::from)
+            })?;
+            let bytes = infos.iter().map(|info| info.file_size()).sum();
+            self.record_upload(jwt, bytes, started.elapsed());
+
+            for (&index, sha256) in batch.iter().zip(batch_digests) {
+                check_unchanged(std::slice::from_ref(&files[index]))?;
+                intent
+                    .uploaded
+                    .push(UploadedFile::new(&files[index], sha256.clone()));
+                digests[index] = Some(sha256);
+            }
+            journal.record(intent).map_err(|e| XetError::CacheError {
+                message: format!("Failed to access commit journal: {}", e),
+            })?;
+        }
+
+        Ok(files
+            .iter()
+            .zip(digests)
+            .map(|(file, digest)| (digest.unwrap_or_default(), file.size()))
+            .collect())
+    }
+
+    /// Creates the commit described by a journaled intent.
+    #[cfg(feature = "upload")]
+    fn create_journaled_commit(
+        &self,
+        intent: &CommitIntent,
+    ) -> Result<xet_commit::CommitResponse, xet_commit::CommitError> {
+        let client = self.http_client()?;
+        self.block_on(async {
+            Ok(xet_commit::create_commit(
+                &client,
+                &intent.endpoint,
+                &intent.repo_type_plural,
+                &intent.repo_full_name,
+                &intent.revision,
+                &intent.summary,
+                &intent.operations,
+                intent.idempotency_key.as_deref().unwrap_or_default(),
+                self.token.as_ref(),
+                &|headers| self.observe_headers(headers),
+            )
+            .await)
+        })?
+    }
+
+    /// Returns the repository's `.gitattributes` with rules storing `paths`
+    /// with LFS, or `None` if it already does.
+    #[cfg(feature = "upload")]
+    fn lfs_gitattributes(
+        &self,
+        repo_info: &HubRepoInfo,
+        revision: &str,
+        paths: &[&str],
+    ) -> Result<Option<String>, XetError> {
+        let path = xet_gitattributes::GITATTRIBUTES_PATH;
+        let current = match self.fetch_metadata(repo_info, path, revision) {
+            Ok(metadata) => self.http_get_bytes(&metadata.download_url)?,
+            // A repository without the file has no rules yet.
+            Err(e) if is_not_found(&e) => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(xet_gitattributes::track_with_lfs(
+            &String::from_utf8_lossy(&current),
+            paths,
+        ))
+    }
+
+    /// Finishes or rolls back commits interrupted by a previous process.
     ///
-    /// * `file_infos` - An array of `XetFileInfo` objects, each containing a file's hash and size.
-    /// * `destination_dir` - The local directory where downloaded files should be saved.
-    /// * `jwt_info` - A `CasJwtInfo` object describing the CAS endpoint, access token, and expiration.
+    /// Upload operations journal their intent (target repository and branch,
+    /// file list, and content hashes) before talking to the Hub. If the process
+    /// exits after content reached CAS but before the commit was created, that
+    /// content would otherwise be stranded. Call this method once at launch.
+    ///
+    /// Commits whose content was fully uploaded are created now. Commits whose
+    /// upload stopped partway through in the last week are kept, so uploading
+    /// the same files with the same message to the same branch skips the files
+    /// already in CAS; other commits whose upload never finished are
+    /// rolled back. Commits the Hub refuses (403, 404 or 409) are rolled back
+    /// too, since no later attempt could create them; commits that can't be
+    /// created because of any other failure stay journaled for the next call.
+    /// Commits of uploads still running in this process are left to them and
+    /// aren't reported. Each
+    /// commit carries an idempotency key journaled with its intent, so a commit
+    /// whose earlier attempt landed without a response isn't created twice.
     ///
     /// # Returns
     ///
-    /// An array of file paths for the successfully downloaded files.
+    /// An array of `RecoveredCommit` objects, one per journaled commit.
     ///
     /// # Errors
     ///
-    /// Returns `XetError::InvalidInput` if `file_infos` is empty or `destination_dir` is empty,
-    /// `XetError::IoError` if files cannot be written, or `XetError::NetworkError` if downloads fail.
-    pub fn download_files(
-        &self,
-        file_infos: Vec<Arc<XetFileInfo>>,
-        destination_dir: String,
-        jwt_info: Arc<CasJwtInfo>,
-    ) -> Result<Vec<String>, XetError> {
-        if file_infos.is_empty() {
-            return Err(XetError::InvalidInput {
-                message: "File infos cannot be empty".to_string(),
-            });
+    /// Returns `XetError::CacheError` if the journal cannot be read or updated.
+    #[cfg(feature = "upload")]
+    pub fn recover_pending_commits(&self) -> Result<Vec<Arc<RecoveredCommit>>, XetError> {
+        let journal = CommitJournal::open(&self.cache);
+        let journal_error = |e: std::io::Error| XetError::CacheError {
+            message: format!("Failed to access commit journal: {}", e),
+        };
+
+        let mut recovered = Vec::new();
+        for mut intent in journal.pending().map_err(journal_error)? {
+            // An upload still running in this process finishes its own commit.
+            if xet_journal::is_live(&intent.id) {
+                continue;
+            }
+            let paths = intent
+                .operations
+                .iter()
+                .map(|operation| operation.path_in_repo().to_string())
+                .collect();
+
+            // The key is journaled before the first attempt, so every later
+            // attempt at this commit carries the same one.
+            if intent.state == IntentState::Uploaded && intent.idempotency_key.is_none() {
+                intent.idempotency_key = Some(xet_commit::new_idempotency_key());
+                journal.record(&intent).map_err(journal_error)?;
+            }
+
+            let (action, commit_oid, error) = match intent.state {
+                IntentState::Uploading if intent.is_resumable() => {
+                    (CommitRecoveryAction::Resumable, None, None)
+                }
+                IntentState::Uploading => (CommitRecoveryAction::RolledBack, None, None),
+                IntentState::Uploaded => match self.create_journaled_commit(&intent) {
+                    Ok(response) => (CommitRecoveryAction::Committed, Some(response.commit_oid), None),
+                    Err(xet_commit::CommitError::Refused(e)) => {
+                        (CommitRecoveryAction::RolledBack, None, Some(e.to_string()))
+                    }
+                    Err(xet_commit::CommitError::Failed(e)) => {
+                        (CommitRecoveryAction::Deferred, None, Some(e.to_string()))
+                    }
+                },
+            };
+
+            if !matches!(
+                action,
+                CommitRecoveryAction::Deferred | CommitRecoveryAction::Resumable
+            ) {
+                journal.complete(&intent.id).map_err(journal_error)?;
+            }
+
+            recovered.push(Arc::new(RecoveredCommit {
+                repo: intent.repo_full_name,
+                revision: intent.revision,
+                paths,
+                action,
+                commit_oid,
+                error,
+            }));
         }
-        if destination_dir.is_empty() {
-            return Err(XetError::InvalidInput {
-                message: "Destination directory cannot be empty".to_string(),
-            });
+
+        Ok(recovered)
+    }
+
+    /// Brings one managed model to the latest commit of its revision.
+    ///
+    /// Returns the saved record and the commit it was updated to.
+    fn update_managed(&self, record: ManagedRecord) -> Result<(ManagedRecord, String), XetError> {
+        let repo_info = self.parse_repo(&record.repo)?;
+        // An unfinished update resumes against the commit it started with.
+        let target = match &record.target_commit {
+            Some(target) => target.clone(),
+            None => self.latest_managed_commit(&record)?,
+        };
+        if record.target_commit.is_none()
+            && record.installed_commit.as_deref() == Some(target.as_str())
+            && record.installed_patterns == record.patterns
+        {
+            return Ok((record, target));
+        }
+
+        let mut record = record;
+        if record.target_commit.as_deref() != Some(target.as_str())
+            || record.planned_files.is_empty()
+        {
+            let files: Vec<TreeEntry> = self
+                .list_repo_files(&repo_info, "", &target, false)?
+                .into_iter()
+                .filter(|entry| {
+                    record.patterns.is_empty()
+                        || xet_glob::matches_any(&record.patterns, &entry.path)
+                })
+                .collect();
+            let total_size = files
+                .iter()
+                .filter_map(|entry| entry.size)
+                .fold(0u64, u64::saturating_add);
+            self.check_total_size(total_size)?;
+
+            let planned: Vec<String> = files.into_iter().map(|entry| entry.path).collect();
+            let target = target.clone();
+            record = self
+                .managed
+                .update_record(&record.repo, move |record| {
+                    record.target_commit = Some(target);
+                    record.planned_files = planned;
+                    record.downloaded_files.clear();
+                    record.last_error = None;
+                })
+                .map_err(managed_library_error)?
+                .ok_or_else(|| XetError::OperationFailed {
+                    message: format!("{} is no longer a managed model", record.repo),
+                    cause: None,
+                })?;
+        }
+
+        let model_dir = self.managed.model_dir(&record.repo);
+        let pending: Vec<String> = record
+            .planned_files
+            .iter()
+            .filter(|path| !record.downloaded_files.contains(path))
+            .cloned()
+            .collect();
+        let mut flagged = self.flagged_files(&repo_info, &target, &pending)?;
+        for path in pending {
+            let destination = self.local_path(&repo_file_path(&model_dir, &path)?);
+            let metadata_result = self.fetch_metadata(&repo_info, &path, &target);
+            self.apply_unsafe_file_policy(&repo_info, &path, flagged.remove(&path))
+                .and_then(|_| {
+                    self.download_file_with_metadata(
+                        repo_info.clone(),
+                        path.clone(),
+                        destination,
+                        target.clone(),
+                        metadata_result,
+                        &TransferObserver::default(),
+                    )
+                })
+                .map_err(|e| XetError::OperationFailed {
+                    message: format!("Failed to download {}: {}", path, e),
+                    cause: e.cause(),
+                })?;
+            self.managed
+                .update_record(&record.repo, |record| record.downloaded_files.push(path))
+                .map_err(managed_library_error)?;
         }
 
-        std::fs::create_dir_all(&destination_dir).map_err(|e| XetError::IoError {
-            message: format!("Failed to create destination directory: {}", e),
-        })?;
+        let stale: Vec<String> = record
+            .installed_files
+            .iter()
+            .filter(|path| !record.planned_files.contains(path))
+            .cloned()
+            .collect();
+        for path in stale {
+            if let Ok(file) = repo_file_path(&model_dir, &path) {
+                let _ = fs::remove_file(self.local_path(&file));
+            }
+        }
 
-        let plan: Vec<XetDownloadPlan> = file_infos
-            .into_iter()
-            .enumerate()
-            .map(|(i, info)| {
-                let data_info = data::XetFileInfo::from((*info).clone());
-                let destination = Path::new(&destination_dir)
-                    .join(format!("file_{}", i))
-                    .to_string_lossy()
-                    .to_string();
-                XetDownloadPlan::new(data_info, destination)
+        let record = self
+            .managed
+            .update_record(&record.repo, |record| {
+                record.installed_commit = record.target_commit.take();
+                record.installed_patterns = record.patterns.clone();
+                record.installed_files = std::mem::take(&mut record.planned_files);
+                record.downloaded_files.clear();
+                record.last_error = None;
             })
-            .collect();
+            .map_err(managed_library_error)?
+            .ok_or_else(|| XetError::OperationFailed {
+                message: format!("{} is no longer a managed model", record.repo),
+                cause: None,
+            })?;
+        Ok((record, target))
+    }
 
-        let downloaded_paths = self
-            .runtime
-            .block_on(self.execute_xet_plan(plan, jwt_info.clone()))?;
+    /// Returns the commit a managed model's revision currently resolves to.
+    fn latest_managed_commit(&self, record: &ManagedRecord) -> Result<String, XetError> {
+        let repo_info = self.parse_repo(&record.repo)?;
+        self.resolve_commit(&repo_info, record.revision_policy.revision())
+    }
 
-        Ok(downloaded_paths)
+    fn managed_model(&self, record: ManagedRecord, latest_commit: Option<String>) -> ManagedModel {
+        let local_path = self
+            .managed
+            .model_dir(&record.repo)
+            .to_string_lossy()
+            .to_string();
+        ManagedModel {
+            record,
+            latest_commit,
+            local_path,
+        }
     }
 
-    /// Retrieves file information from a pointer file in the repository.
-    ///
-    /// This method reads a pointer file (either in Xet JSON format or Git LFS format)
-    /// and extracts the file's hash and size information.
-    ///
-    /// # Arguments
-    ///
-    /// * `repo` - The repository identifier (e.g., `"owner/repo"` or `"datasets/owner/repo"`).
-    /// * `path` - The path to the pointer file within the repository.
-    /// * `revision` - An optional Git revision, branch, or tag name. If `None`, defaults to `"main"`.
-    ///
-    /// # Returns
-    ///
-    /// A `XetFileInfo` object if the pointer file can be parsed, or `None` if
-    /// the file doesn't exist or isn't in a recognized format.
+    /// Returns the canonical identifier the local libraries key repositories by, such as `models/owner/name`.
+    fn repo_key(&self, repo_info: &HubRepoInfo) -> String {
+        format!(
+            "{}/{}",
+            self.repo_type_plural(&repo_info.repo_type),
+            repo_info.full_name
+        )
+    }
+
+    /// Resolves a revision to the commit it currently points to.
+    fn resolve_commit(&self, repo_info: &HubRepoInfo, revision: &str) -> Result<String, XetError> {
+        let url = self.revision_route(
+            self.endpoint_for(repo_info),
+            repo_info,
+            "revision",
+            revision,
+        );
+
+        self.block_on(async {
+            let mut request = self.http_client()?.get(&url);
+            if let Some(token) = &self.token {
+                request = request.bearer_auth(token);
+            }
+
+            let response = self.send(request).await?.error_for_status()?;
+            let revision: RevisionResponse =
+                xet_http::read_json(response, self.max_response_body_bytes()).await?;
+            Ok(revision.sha)
+        })
+    }
+
+    /// Lists every file under `path` in the repository at `revision`, following pagination.
     ///
-    /// # Errors
+    /// An empty `path` lists the whole repository. Fails if any directory
+    /// couldn't be listed, so no file is silently left out. With `expand`,
+    /// entries include scan results.
+    fn list_repo_files(
+        &self,
+        repo_info: &HubRepoInfo,
+        path: &str,
+        revision: &str,
+        expand: bool,
+    ) -> Result<Vec<TreeEntry>, XetError> {
+        let listing = self.list_repo_tree(repo_info, path, revision, expand)?;
+        if !listing.unlisted.is_empty() {
+            return Err(XetError::OperationFailed {
+                message: format!(
+                    "Failed to list every directory of {}: {}",
+                    repo_info.full_name,
+                    listing.unlisted.join(", ")
+                ),
+                cause: None,
+            });
+        }
+        Ok(listing.files)
+    }
+
+    /// Lists every file under `path` in the repository at `revision`, along
+    /// with any directories that couldn't be listed.
+    fn list_repo_tree(
+        &self,
+        repo_info: &HubRepoInfo,
+        path: &str,
+        revision: &str,
+        expand: bool,
+    ) -> Result<RepoListing, XetError> {
+        self.with_fallback(repo_info, |endpoint| {
+            let mut listing = RepoListing::default();
+            self.list_tree_into(endpoint, repo_info, path, revision, expand, &mut listing)?;
+            Ok(listing)
+        })
+    }
+
+    /// Adds the files under `path` to `listing`.
     ///
-    /// Returns `XetError::InvalidInput` if `repo` or `path` is empty, or `XetError::NetworkError`
-    /// if the pointer file cannot be retrieved.
-    pub fn get_file_info(
+    /// The Hub can fail to serve recursive listings of very large directories,
+    /// answering with 413 or a truncated response. Such a directory is listed
+    /// one level at a time instead, descending into each subdirectory, and one
+    /// too large to list even that way is recorded in `listing.unlisted`.
+    fn list_tree_into(
         &self,
-        repo: String,
-        path: String,
-        revision: Option<String>,
-    ) -> Result<Option<Arc<XetFileInfo>>, XetError> {
-        let repo_info = self.parse_repo(&repo)?;
-        let resolved_revision = revision.unwrap_or_else(|| "main".to_string());
+        endpoint: &str,
+        repo_info: &HubRepoInfo,
+        path: &str,
+        revision: &str,
+        expand: bool,
+        listing: &mut RepoListing,
+    ) -> Result<(), XetError> {
+        let recursive_url = self.tree_url(endpoint, repo_info, path, revision, true, expand);
+        match self.fetch_tree(recursive_url) {
+            Ok(entries) => {
+                let files = entries
+                    .into_iter()
+                    .filter(|entry| entry.entry_type == "file");
+                listing.files.extend(files);
+                return Ok(());
+            }
+            Err(e) if is_oversized_listing(&e) => {
+                self.diagnostic(format_args!(
+                    "Listing /{} one directory at a time: {}",
+                    path, e
+                ));
+            }
+            Err(e) => return Err(e),
+        }
 
-        // First, try to get Xet metadata from HTTP headers (preferred method for HuggingFace)
-        // This avoids trying to parse binary files as UTF-8 pointer files
-        match self.runtime.block_on(fetch_file_metadata(
-            &self.endpoint,
-            self.repo_type_plural(&repo_info.repo_type),
-            &repo_info.full_name,
-            &path,
-            &resolved_revision,
-            self.token.as_ref(),
-        )) {
-            Ok(metadata) => {
-                eprintln!("✓ Got metadata for {}, size={}, xet_data={}", path, metadata.size, metadata.xet_file_data.is_some());
-                // If we have Xet metadata in headers (x-xet-hash), use it directly
-                if let Some(xet_data) = metadata.xet_file_data {
-                    eprintln!("✓ Using Xet CAS for {} with hash {}", path, xet_data.file_hash);
-                    let file_info = data::XetFileInfo::new(xet_data.file_hash, metadata.size);
-                    return Ok(Some(Arc::new(XetFileInfo::from(file_info))));
-                }
-                // Headers present but no Xet data - file is not in Xet CAS
-                eprintln!("⚠️  No Xet headers for {}: falling back to pointer file parsing", path);
+        let url = self.tree_url(endpoint, repo_info, path, revision, false, expand);
+        let entries = match self.fetch_tree(url) {
+            Ok(entries) => entries,
+            Err(e) if is_oversized_listing(&e) => {
+                listing.unlisted.push(path.to_string());
+                return Ok(());
             }
-            Err(e) => {
-                eprintln!("Failed to fetch metadata for {}: {}", path, e);
+            Err(e) => return Err(e),
+        };
+        for entry in entries {
+            match entry.entry_type.as_str() {
+                "file" => listing.files.push(entry),
+                "directory" => self.list_tree_into(
+                    endpoint,
+                    repo_info,
+                    &entry.path,
+                    revision,
+                    expand,
+                    listing,
+                )?,
+                _ => {}
             }
         }
+        Ok(())
+    }
 
-        // Fallback: try to parse as a pointer file (for legacy repos without Xet headers)
-        // Skip this for known binary file extensions to avoid UTF-8 errors
-        if !should_try_pointer_detection(&path) {
-            return Ok(None);
+    /// Replaces an authentication failure with `AuthRequired` if the repository needs credentials,
+    /// and a server error with `ServiceUnavailable`.
+    ///
+    /// The Hub answers anonymous requests for private and gated repositories
+    /// with 401, which otherwise reads as a bad token or a missing repository.
+    /// Other errors are returned unchanged.
+    fn explain_access_error(&self, repo_info: &HubRepoInfo, error: XetError) -> XetError {
+        if is_server_error(&error) {
+            return self.explain_server_error(self.endpoint_for(repo_info), error);
+        }
+        if !is_auth_failure(&error) {
+            return error;
+        }
+        match self.probe_repo_access(repo_info) {
+            Some(explained) => explained,
+            None => error,
         }
+    }
 
-        // Try to get the pointer file content
-        let content = self.get_file_content(repo.clone(), path.clone(), Some(resolved_revision.clone()))?;
-        
-        // Try to convert to UTF-8, but don't fail on binary data
-        let content_str = match String::from_utf8(content) {
-            Ok(s) => s,
-            Err(_) => {
-                // Not valid UTF-8, likely a binary file or not a pointer file  
-                eprintln!("⚠️  File {} is not valid UTF-8, skipping pointer parsing", path);
-                return Ok(None);
-            }
-        };
+    /// Checks whether the Hub as a whole is failing after a server error.
+    ///
+    /// A lightweight listing unrelated to any repository is requested; if it
+    /// fails too, the outage is reported as service-wide.
+    fn explain_server_error(&self, endpoint: &str, error: XetError) -> XetError {
+        let url = format!("{}/api/models?limit=1", endpoint);
+        let service_wide = self
+            .block_on(async {
+                let response = self.send(self.http_client()?.get(&url)).await?;
+                Ok(response.status().is_server_error())
+            })
+            .unwrap_or(true);
+        let retry_after_secs = self
+            .retry_at
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .map(|retry_at| retry_at.saturating_sub(unix_now()))
+            .filter(|secs| *secs > 0);
+        XetError::ServiceUnavailable {
+            message: error.to_string(),
+            retry_after_secs,
+            service_wide,
+        }
+    }
 
-        // Try to parse as XetFileInfo JSON
-        match serde_json::from_str::<data::XetFileInfo>(&content_str) {
-            Ok(file_info) => Ok(Some(Arc::new(XetFileInfo::from(file_info)))),
-            Err(_) => {
-                // Try to parse as LFS pointer format
-                // LFS pointers have format: version <url>\noid <hash>\nsize <size>
-                let lines: Vec<&str> = content_str.lines().collect();
-                let mut oid = None;
-                let mut size = None;
+    /// Requests the repository's info, with and without the client's token, to see why access failed.
+    fn probe_repo_access(&self, repo_info: &HubRepoInfo) -> Option<XetError> {
+        let url = format!(
+            "{}/api/{}/{}",
+            self.endpoint_for(repo_info),
+            self.repo_type_plural(&repo_info.repo_type),
+            repo_info.full_name
+        );
+        let auth_required = || XetError::AuthRequired {
+            repo: repo_info.full_name.clone(),
+        };
 
-                for line in lines {
-                    if line.starts_with("oid ") {
-                        oid = Some(line[4..].trim().to_string());
-                    } else if line.starts_with("size ") {
-                        size = line[5..].trim().parse().ok();
-                    }
+        let result: Result<Option<XetError>, XetError> = self.block_on(async {
+            let (status, gated) = self.probe_status(&url, self.token.as_ref()).await?;
+            if status.is_success() {
+                return Ok(None);
+            }
+            if gated {
+                return Ok(Some(auth_required()));
+            }
+            match (self.token.is_some(), status) {
+                (false, reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN) => {
+                    Ok(Some(auth_required()))
                 }
-
-                if let (Some(hash), Some(file_size)) = (oid, size) {
-                    Ok(Some(Arc::new(XetFileInfo::new(hash, file_size))))
-                } else {
-                    Ok(None)
+                (true, reqwest::StatusCode::UNAUTHORIZED) => {
+                    let (anonymous, _) = self.probe_status(&url, None).await?;
+                    Ok(Some(if anonymous.is_success() {
+                        XetError::AuthError {
+                            message: format!(
+                                "The Hub rejected the client's token for {}, which is public",
+                                repo_info.full_name
+                            ),
+                        }
+                    } else {
+                        XetError::AuthError {
+                            message: format!(
+                                "The Hub rejected the client's token for {}",
+                                repo_info.full_name
+                            ),
+                        }
+                    }))
                 }
+                (true, reqwest::StatusCode::FORBIDDEN) => Ok(Some(auth_required())),
+                _ => Ok(None),
             }
+        });
+        result.ok().flatten()
+    }
+
+    /// Requests the repository's info, or a write token for it, to see whether the client may access it.
+    fn probe_access(
+        &self,
+        repo_info: &HubRepoInfo,
+        operation: RepoOperation,
+    ) -> Result<bool, XetError> {
+        let endpoint = self.endpoint_for(repo_info);
+        let repo_url = format!(
+            "{}/api/{}/{}",
+            endpoint,
+            self.repo_type_plural(&repo_info.repo_type),
+            repo_info.full_name
+        );
+        let url = match operation {
+            RepoOperation::Read => repo_url,
+            // Anonymous clients can't write to any repository.
+            RepoOperation::Write if self.token.is_none() => return Ok(false),
+            RepoOperation::Write => self.revision_route(
+                endpoint,
+                repo_info,
+                "xet-write-token",
+                &self.resolve_revision(None),
+            ),
+        };
+        let (status, _) = self.block_on(self.probe_status(&url, self.token.as_ref()))?;
+        if status.is_server_error() {
+            let error = XetError::NetworkError {
+                message: format!("HTTP {} checking access to {}", status, repo_info.full_name),
+                cause: Some(ErrorCause::Http {
+                    kind: HttpErrorKind::Status,
+                    status: Some(status.as_u16()),
+                }),
+            };
+            return Err(self.explain_server_error(endpoint, error));
         }
+        Ok(status.is_success())
     }
 
-    /// Parses a repository identifier and returns structured repository information.
-    ///
-    /// This method validates and parses repository identifiers in various formats,
-    /// returning the repository type and full name.
-    ///
-    /// # Arguments
-    ///
-    /// * `repo` - The repository identifier, which can be in the format
-    ///   `"owner/repo"` (defaults to model type) or `"type/owner/repo"` where type
-    ///   is `models`, `datasets`, or `spaces`.
-    ///
-    /// # Returns
-    ///
-    /// A `RepoInfo` object containing the repository type and full name.
-    ///
-    /// # Errors
-    ///
-    /// Returns `XetError::InvalidInput` if the repository identifier format is invalid.
-    pub fn get_repo_info(&self, repo: String) -> Result<Arc<RepoInfo>, XetError> {
-        let hub_repo_info = self.parse_repo(&repo)?;
-        Ok(Arc::new(RepoInfo::from(hub_repo_info)))
+    /// Returns the error for a repository the client has been found unable to read.
+    fn read_denied(&self, repo_info: &HubRepoInfo) -> XetError {
+        match self.token {
+            Some(_) => XetError::PermissionDenied {
+                repo: repo_info.full_name.clone(),
+                operation: RepoOperation::Read,
+            },
+            None => XetError::AuthRequired {
+                repo: repo_info.full_name.clone(),
+            },
+        }
     }
 
-    /// Clears all files from the local Xet cache.
+    /// Replaces an authentication failure with `PermissionDenied` if the client
+    /// can read the repository but not write to it.
     ///
-    /// This method removes all cached files and recreates an empty cache directory.
-    /// Use this to free up disk space or to force fresh downloads of cached files.
-    ///
-    /// # Errors
-    ///
-    /// Returns `XetError::CacheError` if the cache directory cannot be cleared or recreated.
-    pub fn clear_cache(&self) -> Result<(), XetError> {
-        let cache_dir = xet_runtime::xet_cache_root();
+    /// Other failures are explained as by `explain_access_error`.
+    fn explain_write_error(&self, repo_info: &HubRepoInfo, error: XetError) -> XetError {
+        if is_auth_failure(&error)
+            && self.token.is_some()
+            && matches!(self.probe_access(repo_info, RepoOperation::Read), Ok(true))
+        {
+            return XetError::PermissionDenied {
+                repo: repo_info.full_name.clone(),
+                operation: RepoOperation::Write,
+            };
+        }
+        self.explain_access_error(repo_info, error)
+    }
 
-        // Remove all files in cache directory
-        if cache_dir.exists() {
-            std::fs::remove_dir_all(&cache_dir).map_err(|e| XetError::CacheError {
-                message: format!("Failed to clear cache: {}", e),
-            })?;
+    /// Returns the status of a probe request and whether the Hub reported the repository as gated.
+    async fn probe_status(
+        &self,
+        url: &str,
+        token: Option<&String>,
+    ) -> Result<(reqwest::StatusCode, bool), XetError> {
+        let mut request = self.http_client()?.get(url);
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        let response = self.send(request).await?;
+        let status = response.status();
+        let error_code = response
+            .headers()
+            .get(HEADER_X_ERROR_CODE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+        let body = response.text().await.unwrap_or_default();
+        let gated = error_code.as_deref() == Some("GatedRepo")
+            || body.contains("gated repo")
+            || body.contains("is restricted");
+        Ok((status, gated))
+    }
 
-            // Recreate empty directory
-            std::fs::create_dir_all(&cache_dir).map_err(|e| XetError::CacheError {
-                message: format!("Failed to recreate cache directory: {}", e),
-            })?;
+    /// Sends a request, recording any rate limit or retry delay the response reports.
+    async fn send(&self, request: reqwest::RequestBuilder) -> reqwest::Result<reqwest::Response> {
+        let response = xet_faults::send(request).await?;
+        self.observe_headers(response.headers());
+        Ok(response)
+    }
+
+    fn observe_headers(&self, headers: &reqwest::header::HeaderMap) {
+        self.clock.observe(headers);
+        let now = unix_now();
+        if let Some(retry_after) = xet_ratelimit::parse_retry_after(headers) {
+            *self
+                .retry_at
+                .write()
+                .unwrap_or_else(PoisonError::into_inner) = Some(now.saturating_add(retry_after));
         }
+        let Some(status) = xet_ratelimit::parse_rate_limit(headers, now) else {
+            return;
+        };
+        self.emit(XetEvent::RateLimitUpdated {
+            limit: status.limit,
+            remaining: status.remaining,
+            reset_at: status.reset_at,
+        });
+        *self
+            .rate_limit
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(status));
+    }
 
-        Ok(())
+    /// Applies the configured Unicode normalization to a local path.
+    fn local_path(&self, path: &str) -> String {
+        xet_unicode::normalize_path(path, self.config.path_normalization)
     }
 
-    /// Returns statistics about the local Xet cache.
-    ///
-    /// This method calculates the total size and file count of all cached files.
-    /// If the cache directory doesn't exist, returns statistics with zero values.
-    ///
-    /// # Returns
+    fn emit(&self, event: XetEvent) {
+        let listener = self
+            .event_listener
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        if let Some(listener) = listener {
+            self.callbacks.dispatch(move || listener.on_event(event));
+        }
+    }
+
+    /// Runs `future` to completion on the client's runtime.
     ///
-    /// A `CacheStats` object containing the total cache size in bytes
-    /// and the number of cached files.
+    /// Blocking on the runtime from a thread that is already driving one would
+    /// panic or deadlock, so such calls are refused instead. The future is
+    /// abandoned with `XetError::Timeout` once the call's deadline or the
+    /// client's operation timeout passes, whichever comes first, and with
+    /// `XetError::Cancelled` once the caller of a detached call stops waiting.
+    fn block_on<T, F>(&self, future: F) -> Result<T, XetError>
+    where
+        F: std::future::Future<Output = Result<T, XetError>>,
+    {
+        check_not_in_runtime()?;
+        let operation_deadline = self
+            .config
+            .operation_timeout_secs
+            .map(|secs| Instant::now() + Duration::from_secs(secs));
+        let deadline = xet_deadline::current()
+            .into_iter()
+            .chain(operation_deadline)
+            .min();
+        let future = self.recorder.scope(self.faults.scope(future));
+        let future = self.usage.scope(self.usage_label(), future);
+        let future = xet_deadline::enforce(deadline, future);
+        let future = xet_detached::enforce(xet_detached::current(), future);
+        self.with_resources(|resources| resources.block_on(future))?
+    }
+
+    /// Runs `call` on the runtime's blocking pool and resolves with its result.
     ///
-    /// # Errors
+    /// The client's methods block while they drive its runtime, which would
+    /// panic or stall on a foreign executor's thread. The pool reuses its
+    /// threads across calls and keeps the awaiting task suspended rather than
+    /// parked until the call returns. Dropping the returned future abandons
+    /// the request the call is waiting on, failing it with `XetError::Cancelled`.
+    async fn run_detached<T: Send + 'static>(
+        self: Arc<Self>,
+        call: impl FnOnce(&XetClient) -> Result<T, XetError> + Send + 'static,
+    ) -> Result<T, XetError> {
+        let runtime = self.shared_resources()?.runtime.handle().clone();
+        let caller = Arc::new(Caller::default());
+        let _leave = LeaveOnDrop(caller.clone());
+        let task = runtime.spawn_blocking(move || {
+            let result = xet_detached::scoped(caller, || call(&self));
+            // A runtime can't be shut down from its own pool, so a client its
+            // host released during the call is dropped on a thread of its own.
+            if let Some(client) = Arc::into_inner(self) {
+                std::thread::spawn(move || drop(client));
+            }
+            result
+        });
+        task.await.unwrap_or_else(|e| {
+            Err(XetError::OperationFailed {
+                message: format!("The operation ended without returning a result: {}", e),
+                cause: None,
+            })
+        })
+    }
+
+    /// Calls `f` with the runtime and HTTP client, rebuilding them if they were shed while idle.
+    fn with_resources<R>(&self, f: impl FnOnce(&ClientResources) -> R) -> Result<R, XetError> {
+        self.resources.with(
+            || ClientResources::new(&self.config, &self.tls).map(Arc::new),
+            |resources| f(resources),
+        )
+    }
+
+    /// Returns the runtime and HTTP client, for work that must keep them alive after the call returns.
     ///
-    /// Returns `XetError::CacheError` if the cache directory cannot be accessed
-    /// or statistics cannot be calculated.
-    pub fn get_cache_stats(&self) -> Result<Arc<CacheStats>, XetError> {
-        let cache_dir = xet_runtime::xet_cache_root();
+    /// Shedding idle resources only releases the client's reference, so the
+    /// returned resources stay usable for as long as they're held.
+    fn shared_resources(&self) -> Result<Arc<ClientResources>, XetError> {
+        self.resources.with(
+            || ClientResources::new(&self.config, &self.tls).map(Arc::new),
+            Arc::clone,
+        )
+    }
 
-        if !cache_dir.exists() {
-            return Ok(Arc::new(CacheStats {
-                total_size_bytes: 0,
-                file_count: 0,
-            }));
-        }
+    /// Returns the HTTP client for the runtime currently in use.
+    fn http_client(&self) -> Result<reqwest::Client, XetError> {
+        self.with_resources(|resources| resources.http_client.clone())
+    }
 
-        let mut total_size: u64 = 0;
-        let mut file_count: u64 = 0;
-
-        fn calculate_size(
-            path: &Path,
-            total_size: &mut u64,
-            file_count: &mut u64,
-        ) -> std::io::Result<()> {
-            if path.is_file() {
-                *total_size += path.metadata()?.len();
-                *file_count += 1;
-            } else if path.is_dir() {
-                for entry in std::fs::read_dir(path)? {
-                    let entry = entry?;
-                    calculate_size(&entry.path(), total_size, file_count)?;
+    /// Resolves entries for specific paths with one request to the paths-info API.
+    ///
+    /// With `expand`, entries also include scan results and last-commit details.
+    fn fetch_paths_info(
+        &self,
+        repo_info: &HubRepoInfo,
+        revision: &str,
+        paths: &[String],
+        expand: bool,
+    ) -> Result<Vec<TreeEntry>, XetError> {
+        let url = self.revision_route(
+            self.endpoint_for(repo_info),
+            repo_info,
+            "paths-info",
+            revision,
+        );
+
+        self.block_on(async {
+            let mut entries = Vec::with_capacity(paths.len());
+            // Large selections are split so no request outgrows what the Hub accepts.
+            for batch in paths.chunks(PATHS_INFO_BATCH_SIZE) {
+                let mut form: Vec<(&str, &str)> =
+                    batch.iter().map(|path| ("paths", path.as_str())).collect();
+                if expand {
+                    form.push(("expand", "true"));
+                }
+
+                let mut request = self.http_client()?.post(&url).form(&form);
+                if let Some(token) = &self.token {
+                    request = request.bearer_auth(token);
                 }
+
+                let response = self.send(request).await?.error_for_status()?;
+                let page: Vec<TreeEntry> =
+                    xet_http::read_json(response, self.max_response_body_bytes()).await?;
+                entries.extend(page);
             }
-            Ok(())
+            Ok(entries)
+        })
+    }
+
+    fn fetch_metadata(
+        &self,
+        repo_info: &HubRepoInfo,
+        path: &str,
+        revision: &str,
+    ) -> Result<FileResolveMetadata, XetError> {
+        let repo_type = self.repo_type_plural(&repo_info.repo_type);
+        let observe = |headers: &reqwest::header::HeaderMap| self.observe_headers(headers);
+        let client = self.with_resources(|resources| resources.metadata_client.clone())?;
+        let result = self.with_fallback(repo_info, |endpoint| {
+            self.block_on(with_retries(&self.retry_policy, || {
+                fetch_file_metadata(
+                    &client,
+                    endpoint,
+                    repo_type,
+                    &repo_info.full_name,
+                    path,
+                    revision,
+                    self.token.as_ref(),
+                    &observe,
+                )
+            }))
+        });
+        if let Ok(FileResolveMetadata {
+            download_url,
+            redirect_target: Some(target),
+            ..
+        }) = &result
+        {
+            self.redirects.store(download_url, target, self.clock.now());
+        }
+        if !self.config.serve_stale_metadata {
+            return result;
         }
 
-        calculate_size(&cache_dir, &mut total_size, &mut file_count).map_err(|e| {
-            XetError::CacheError {
-                message: format!("Failed to calculate cache stats: {}", e),
+        let key = self.resolution_key(repo_info, path, revision);
+        let resolutions = ResolutionCache::open(&self.cache);
+        match result {
+            Ok(metadata) => {
+                if let Err(e) = resolutions.store(&key, &metadata) {
+                    self.diagnostic(format_args!(
+                        "Failed to record resolution of {}: {}",
+                        path, e
+                    ));
+                }
+                Ok(metadata)
             }
-        })?;
+            Err(e) if is_hub_unreachable(&e) => match resolutions.load(&key) {
+                Some(metadata) => {
+                    self.emit(XetEvent::StaleMetadataServed {
+                        repo: repo_info.full_name.clone(),
+                        path: path.to_string(),
+                        revision: revision.to_string(),
+                    });
+                    Ok(metadata)
+                }
+                None => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }
 
-        Ok(Arc::new(CacheStats {
-            total_size_bytes: total_size,
-            file_count,
-        }))
+    /// Returns the key the resolution of `path` at `revision` is recorded under.
+    fn resolution_key<'a>(
+        &'a self,
+        repo_info: &'a HubRepoInfo,
+        path: &'a str,
+        revision: &'a str,
+    ) -> [&'a str; 5] {
+        [
+            self.endpoint_for(repo_info),
+            self.repo_type_plural(&repo_info.repo_type),
+            repo_info.full_name.as_str(),
+            path,
+            revision,
+        ]
     }
 
     fn download_file_legacy(
@@ -1373,66 +9274,69 @@ impl XetClient {
         path: String,
         destination: String,
         revision: Option<String>,
-    ) -> Result<(), XetError> {
-        let revision = revision.unwrap_or_else(|| "main".to_string());
+        observer: &TransferObserver,
+    ) -> Result<(u64, Option<(String, Duration)>), XetError> {
+        let revision = self.resolve_revision(revision);
         let urls_to_try = self.build_resolve_urls(&repo_info, &path, &revision);
 
-        self.runtime.block_on(async {
+        self.block_on(async {
             let mut last_error = None;
 
             for url in urls_to_try {
-                let mut request = self.http_client.get(&url);
+                let mut request = self.http_client()?.get(&url);
 
                 if let Some(token) = &self.token {
                     request = request.bearer_auth(token);
                 }
 
-                match request.send().await {
+                match self.send(request).await {
                     Ok(response) => match response.error_for_status() {
-                        Ok(resp) => match resp.bytes().await {
-                            Ok(bytes) => {
-                                let dest_path = Path::new(&destination);
-                                if let Some(parent) = dest_path.parent() {
-                                    fs::create_dir_all(parent).map_err(|e| XetError::IoError {
-                                        message: format!("Failed to create directory: {}", e),
-                                    })?;
+                        Ok(resp) => {
+                            self.check_response(&path, &resp)?;
+                            self.prepare_destination(&destination)?;
+                            match xet_transfer::write_response(
+                                resp,
+                                Path::new(&destination),
+                                self.config.verify_downloads,
+                                None,
+                                self.config.max_file_size_bytes,
+                                observer,
+                            )
+                            .await
+                            {
+                                Ok(written) => return Ok(written),
+                                Err(XetError::NetworkError { message, cause }) => {
+                                    last_error = Some((message, cause));
+                                    continue;
                                 }
-
-                                fs::write(dest_path, bytes.as_ref()).map_err(|e| {
-                                    XetError::IoError {
-                                        message: format!("Failed to write file: {}", e),
-                                    }
-                                })?;
-
-                                return Ok::<(), XetError>(());
+                                Err(e) => return Err(e),
                             }
-                            Err(e) => {
-                                last_error = Some(format!("Failed to read response body: {}", e));
-                                continue;
-                            }
-                        },
+                        }
                         Err(e) => {
-                            last_error = Some(format!("HTTP error: {}", e));
+                            let message = format!("HTTP error: {}", e);
+                            last_error = Some((message, Some(ErrorCause::from(&e))));
                             continue;
                         }
                     },
                     Err(e) => {
-                        last_error = Some(format!("Request error: {}", e));
+                        let message = format!("Request error: {}", e);
+                        last_error = Some((message, Some(ErrorCause::from(&e))));
                         continue;
                     }
                 }
             }
 
-            let error_msg = last_error.unwrap_or_else(|| "Unknown error".to_string());
-            Err::<(), XetError>(XetError::NetworkError {
+            // The last failure's cause tells server errors from the rest.
+            let (error_msg, cause) =
+                last_error.unwrap_or_else(|| ("Unknown error".to_string(), None));
+            Err(XetError::NetworkError {
                 message: format!(
                     "Could not download file. Tried multiple endpoints. Last error: {}",
                     error_msg
                 ),
+                cause,
             })
-        })?;
-
-        Ok(())
+        })
     }
 
     fn get_file_content_legacy(
@@ -1443,151 +9347,332 @@ impl XetClient {
     ) -> Result<Vec<u8>, XetError> {
         let urls_to_try = self.build_resolve_urls(&repo_info, &path, &revision);
 
-        let content = self.runtime.block_on(async {
+        let content = self.block_on(async {
             let mut last_error = None;
 
             for url in urls_to_try {
-                let mut request = self.http_client.get(&url);
+                let mut request = self.http_client()?.get(&url);
 
                 if let Some(token) = &self.token {
                     request = request.bearer_auth(token);
                 }
 
-                match request.send().await {
+                match self.send(request).await {
                     Ok(response) => match response.error_for_status() {
-                        Ok(resp) => match resp.bytes().await {
-                            Ok(bytes) => return Ok::<Vec<u8>, XetError>(bytes.to_vec()),
-                            Err(e) => {
-                                last_error = Some(format!("Failed to read response body: {}", e));
-                                continue;
+                        Ok(resp) => {
+                            self.check_response(&path, &resp)?;
+                            match xet_http::read_body(resp, self.max_response_body_bytes()).await {
+                                Ok(bytes) => return Ok::<Vec<u8>, XetError>(bytes),
+                                Err(e @ XetError::PolicyViolation { .. }) => return Err(e),
+                                Err(e) => {
+                                    let message = format!("Failed to read response body: {}", e);
+                                    last_error = Some((message, e.cause()));
+                                    continue;
+                                }
                             }
-                        },
+                        }
                         Err(e) => {
-                            last_error = Some(format!("HTTP error for {}: {}", url, e));
+                            let message = format!("HTTP error for {}: {}", url, e);
+                            last_error = Some((message, Some(ErrorCause::from(&e))));
                             continue;
                         }
                     },
                     Err(e) => {
-                        last_error = Some(format!("Request error for {}: {}", url, e));
+                        let message = format!("Request error for {}: {}", url, e);
+                        last_error = Some((message, Some(ErrorCause::from(&e))));
                         continue;
                     }
                 }
             }
 
-            let error_msg = last_error.unwrap_or_else(|| "Unknown error".to_string());
+            let (error_msg, cause) =
+                last_error.unwrap_or_else(|| ("Unknown error".to_string(), None));
             Err::<Vec<u8>, XetError>(XetError::NetworkError {
                 message: format!(
                     "Could not retrieve file. Tried multiple endpoints. Last error: {}",
                     error_msg
                 ),
+                cause,
             })
         })?;
 
         Ok(content)
     }
 
+    /// Builds the URLs a file can be resolved from, in the order they're
+    /// tried, on each endpoint serving the repository in turn.
     fn build_resolve_urls(
         &self,
         repo_info: &HubRepoInfo,
         path: &str,
         revision: &str,
     ) -> Vec<String> {
-        let encoded_path = encode(path);
-        let encoded_rev = encode(revision);
-        let repo_type = &repo_info.repo_type;
-        let canonical_prefix = match repo_type {
-            hub_client::HFRepoType::Model => "",
-            hub_client::HFRepoType::Dataset => "datasets/",
-            hub_client::HFRepoType::Space => "spaces/",
-        };
-        vec![
-            format!(
-                "{}/{canonical_prefix}{}/resolve/{}/{}",
-                self.endpoint, repo_info.full_name, encoded_rev, encoded_path
-            ),
-            format!(
-                "{}/api/{}/{}/resolve/{}/{}",
-                self.endpoint,
-                self.repo_type_plural(repo_type),
-                repo_info.full_name,
-                encoded_rev,
-                encoded_path
-            ),
-            format!(
-                "{}/api/{}/{}/resolve/{}?revision={}",
-                self.endpoint,
-                self.repo_type_plural(repo_type),
-                repo_info.full_name,
-                encoded_path,
-                encoded_rev
-            ),
-        ]
+        let repo_type_plural = self.repo_type_plural(&repo_info.repo_type);
+        self.endpoints_for(repo_info)
+            .into_iter()
+            .flat_map(|endpoint| {
+                xet_metadata::resolve_urls(
+                    endpoint,
+                    repo_type_plural,
+                    &repo_info.full_name,
+                    path,
+                    revision,
+                )
+            })
+            .collect()
     }
 
-    async fn download_with_xet_async(
+    /// Reconstructs the file with `file_hash` into `partial`, fetching only
+    /// the terms earlier attempts didn't write.
+    ///
+    /// Retries resume from whatever the failed attempt wrote, with a fresh
+    /// reconstruction, since the URLs terms are fetched from expire. With
+    /// `verify`, the file is hashed as it's written and its digest returned.
+    ///
+    /// The client runs the reconstruction itself because the Xet data crate
+    /// downloads a whole file per call: it can't skip terms already on disk,
+    /// pace its requests, share the client's range request budget, or use the
+    /// client's TLS options. Its chunk cache is private to its downloader, so
+    /// terms are cached in the chunk area by `TermCache` instead.
+    fn download_xet_resuming(
         &self,
-        xet_data: &XetFileData,
-        expected_size: u64,
-        destination: &str,
-    ) -> Result<(), XetError> {
-        self.prepare_destination(destination)?;
-
-        let jwt = get_cached_cas_jwt(
-            &self.http_client,
-            &xet_data.refresh_route,
-            self.token.as_ref(),
-        )
-        .await?;
-        let file_info = data::XetFileInfo::new(xet_data.file_hash.clone(), expected_size);
-        let plan = vec![XetDownloadPlan::new(file_info, destination.to_string())];
-        self.execute_xet_plan(plan, jwt).await?;
-        Ok(())
+        file_hash: &str,
+        size: u64,
+        jwt: Arc<CasJwtInfo>,
+        partial: &PartialDownload,
+        observer: &TransferObserver,
+        verify: bool,
+    ) -> Result<(Arc<CasJwtInfo>, Digest), XetError> {
+        self.prepare_destination(&partial.path().to_string_lossy())?;
+        let client = self.http_client()?;
+        let policy = &self.retry_policy;
+        let cache = TermCache::open(&self.cache);
+        let mut hasher = verify.then(|| PrefixHasher::new(partial.path()));
+        let mut jwt = jwt;
+        let mut attempts = 1;
+        loop {
+            jwt = self.block_on(self.fresh_cas_jwt(jwt))?;
+            let result = self.block_on(async {
+                let reconstruction = xet_reconstruction::query(
+                    &client,
+                    &jwt.cas_url(),
+                    &jwt.access_token(),
+                    file_hash,
+                )
+                .await?;
+                xet_reconstruction::download(
+                    &client,
+                    &self.scheduler,
+                    &cache,
+                    reconstruction,
+                    file_hash,
+                    size,
+                    partial,
+                    observer,
+                    hasher.as_mut(),
+                )
+                .await
+            });
+            match result {
+                Ok(()) => {
+                    let digest = hasher.map(|hasher| hasher.finish(size)).transpose()?;
+                    return Ok((jwt, digest));
+                }
+                Err(e) if attempts < policy.max_attempts && policy.retries(&e) => {
+                    self.block_on(async {
+                        tokio::time::sleep(policy.delay(attempts)).await;
+                        Ok(())
+                    })?;
+                    attempts += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
     }
 
+    /// Downloads a file from its resolved URL, resuming an earlier partial download.
+    ///
+    /// Large files are fetched in segments with concurrent range requests, and
+    /// in a single request if the server doesn't support ranges.
     fn download_http_with_metadata(
         &self,
         metadata: &FileResolveMetadata,
-        destination: &str,
-    ) -> Result<(), XetError> {
-        let bytes = self.http_get_bytes(&metadata.download_url)?;
-        self.write_bytes(destination, &bytes)
+        partial: &PartialDownload,
+        observer: &TransferObserver,
+    ) -> Result<(u64, Option<(String, Duration)>), XetError> {
+        self.prepare_destination(&partial.path().to_string_lossy())?;
+        if xet_segments::is_segmented(metadata.size) {
+            match self.download_http_segmented(metadata, partial, observer) {
+                Ok(digest) => return Ok((metadata.size, digest)),
+                Err(e) if xet_segments::is_ranges_ignored(&e) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let policy = &self.retry_policy;
+        let mut attempts = 1;
+        loop {
+            let pauses = observer.pauses();
+            let offset = partial.resume_offset(&metadata.etag, metadata.size)?;
+            let result = self.block_on(async {
+                let _slot = self.scheduler.acquire().await;
+                let response = self.http_get_from(&metadata.download_url, offset).await?;
+                if let Some(drift) =
+                    xet_metadata::served_drift(metadata, response.status(), response.headers())
+                {
+                    return Err(XetError::IntegrityError {
+                        message: format!("The revision moved during the download: {}", drift),
+                    });
+                }
+                xet_transfer::write_response(
+                    response,
+                    partial.path(),
+                    self.config.verify_downloads,
+                    Some(offset),
+                    self.config.max_file_size_bytes,
+                    observer,
+                )
+                .await
+            });
+            match result {
+                // A connection dropped while the transfer was paused picks
+                // up where it stopped.
+                Err(XetError::NetworkError { .. }) if observer.pauses() != pauses => continue,
+                // Retries resume from whatever the failed attempt wrote.
+                Err(e) if attempts < policy.max_attempts && policy.retries(&e) => {
+                    self.block_on(async {
+                        tokio::time::sleep(policy.delay(attempts)).await;
+                        Ok(())
+                    })?;
+                    attempts += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Fetches a file in segments into `partial`, retrying with whatever
+    /// segments earlier attempts wrote.
+    ///
+    /// When downloads are verified, the file is hashed as it's written and
+    /// its digest returned.
+    fn download_http_segmented(
+        &self,
+        metadata: &FileResolveMetadata,
+        partial: &PartialDownload,
+        observer: &TransferObserver,
+    ) -> Result<Digest, XetError> {
+        let client = self.http_client()?;
+        let policy = &self.retry_policy;
+        let mut hasher = self
+            .config
+            .verify_downloads
+            .then(|| PrefixHasher::new(partial.path()));
+        let mut attempts = 1;
+        loop {
+            let pauses = observer.pauses();
+            // Segments are fetched from the URL the file last redirected to,
+            // if it's cached, rather than each being redirected by the Hub.
+            let target = self.redirects.target(&metadata.download_url);
+            let source = FileResolveMetadata {
+                download_url: target
+                    .clone()
+                    .unwrap_or_else(|| metadata.download_url.clone()),
+                ..metadata.clone()
+            };
+            let token = self
+                .token
+                .as_deref()
+                .filter(|_| self.should_send_auth(&source.download_url));
+            let result = self.block_on(xet_segments::download(
+                &client,
+                &self.scheduler,
+                &source,
+                token,
+                partial,
+                observer,
+                hasher.as_mut(),
+            ));
+            match result {
+                Ok(()) => {
+                    let size = metadata.size;
+                    return Ok(hasher.map(|hasher| hasher.finish(size)).transpose()?);
+                }
+                Err(e @ (XetError::Cancelled { .. } | XetError::Timeout { .. })) => return Err(e),
+                // The target may have expired early, so the next attempt asks
+                // the Hub again, keeping the segments this one wrote.
+                Err(_) if target.is_some() => {
+                    self.redirects.forget(&metadata.download_url);
+                }
+                // A connection dropped while the transfer was paused picks
+                // up where it stopped.
+                Err(XetError::NetworkError { .. }) if observer.pauses() != pauses => continue,
+                Err(e) if attempts < policy.max_attempts && policy.retries(&e) => {
+                    self.block_on(async {
+                        tokio::time::sleep(policy.delay(attempts)).await;
+                        Ok(())
+                    })?;
+                    attempts += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
     }
 
     fn http_get_bytes(&self, url: &str) -> Result<Vec<u8>, XetError> {
-        let mut request = self.http_client.get(url);
+        self.block_on(async {
+            let response = self.http_get(url).await?;
+            xet_http::read_body(response, self.max_response_body_bytes()).await
+        })
+    }
+
+    /// Returns the largest response body the client reads into memory.
+    fn max_response_body_bytes(&self) -> u64 {
+        self.config
+            .max_response_body_bytes
+            .unwrap_or(xet_http::DEFAULT_MAX_RESPONSE_BODY_BYTES)
+    }
+
+    async fn http_get(&self, url: &str) -> Result<reqwest::Response, XetError> {
+        self.http_get_from(url, 0).await
+    }
+
+    /// Requests `url` starting at byte `offset`, which the server may ignore.
+    ///
+    /// The request goes straight to the URL `url` last redirected to, if it's
+    /// cached, and falls back on `url` if that fails.
+    async fn http_get_from(&self, url: &str, offset: u64) -> Result<reqwest::Response, XetError> {
+        if let Some(target) = self.redirects.target(url) {
+            match self.http_get_direct(&target, offset).await {
+                Ok(response) => return Ok(response),
+                Err(_) => self.redirects.forget(url),
+            }
+        }
+        self.http_get_direct(url, offset).await
+    }
+
+    /// Requests `url` starting at byte `offset`, bypassing cached redirects.
+    async fn http_get_direct(&self, url: &str, offset: u64) -> Result<reqwest::Response, XetError> {
+        let mut request = self.http_client()?.get(url);
+        if offset > 0 {
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-", offset));
+        }
         if self.should_send_auth(url) {
             if let Some(token) = &self.token {
                 request = request.bearer_auth(token);
             }
         }
 
-        self.runtime.block_on(async {
-            let response = request
-                .send()
-                .await
-                .map_err(|e| XetError::NetworkError {
-                    message: format!("Request error: {}", e),
-                })?
-                .error_for_status()
-                .map_err(|e| XetError::NetworkError {
-                    message: format!("HTTP error: {}", e),
-                })?;
-
-            response
-                .bytes()
-                .await
-                .map(|bytes| bytes.to_vec())
-                .map_err(|e| XetError::NetworkError {
-                    message: format!("Failed to read response body: {}", e),
-                })
-        })
-    }
-
-    fn write_bytes(&self, destination: &str, bytes: &[u8]) -> Result<(), XetError> {
-        self.prepare_destination(destination)?;
-        fs::write(destination, bytes).map_err(|e| XetError::IoError {
-            message: format!("Failed to write file: {}", e),
-        })
+        self.send(request)
+            .await
+            .map_err(|e| XetError::NetworkError {
+                message: format!("Request error: {}", e),
+                cause: Some(ErrorCause::from(&e)),
+            })?
+            .error_for_status()
+            .map_err(|e| XetError::NetworkError {
+                message: format!("HTTP error: {}", e),
+                cause: Some(ErrorCause::from(&e)),
+            })
     }
 
     fn prepare_destination(&self, destination: &str) -> Result<(), XetError> {
@@ -1595,20 +9680,86 @@ impl XetClient {
         if let Some(parent) = dest_path.parent() {
             fs::create_dir_all(parent).map_err(|e| XetError::IoError {
                 message: format!("Failed to create directory: {}", e),
+                cause: Some(ErrorCause::from(&e)),
             })?;
         }
         Ok(())
     }
 
+    /// Writes a diagnostic to standard error unless the client is quiet.
+    fn diagnostic(&self, message: std::fmt::Arguments) {
+        if !self.quiet.load(Ordering::Relaxed) {
+            eprintln!("{}", message);
+        }
+    }
+
     fn should_send_auth(&self, download_url: &str) -> bool {
         if self.token.is_none() {
             return false;
         }
 
-        match (Url::parse(download_url), Url::parse(&self.endpoint)) {
-            (Ok(target), Ok(base)) => target.domain() == base.domain(),
-            _ => true,
+        let Ok(target) = Url::parse(download_url) else {
+            return true;
+        };
+        // The token goes to the Hub and any mirror the client was pointed at.
+        let overrides = self.config.endpoint_overrides.iter().flatten();
+        let hub_endpoints = self.config.hub_endpoints.iter().flatten();
+        std::iter::once(self.endpoint.as_str())
+            .chain(overrides.map(|endpoint_override| endpoint_override.endpoint.as_str()))
+            .chain(hub_endpoints.map(String::as_str))
+            .any(|endpoint| match Url::parse(endpoint) {
+                Ok(base) => target.origin() == base.origin(),
+                Err(_) => true,
+            })
+    }
+
+    /// Returns the endpoint serving `repo_info`: the first override whose
+    /// pattern matches the repository, or the first Hub endpoint.
+    fn endpoint_for(&self, repo_info: &HubRepoInfo) -> &str {
+        self.endpoints_for(repo_info)[0]
+    }
+
+    /// Returns the endpoints serving `repo_info` in the order they're tried.
+    ///
+    /// A repository matching an override is served by that override alone;
+    /// any other is served by the configured Hub endpoints.
+    fn endpoints_for(&self, repo_info: &HubRepoInfo) -> Vec<&str> {
+        let matched = self
+            .config
+            .endpoint_overrides
+            .iter()
+            .flatten()
+            .find(|candidate| xet_glob::matches(&candidate.repo_pattern, &repo_info.full_name));
+        if let Some(matched) = matched {
+            return vec![matched.endpoint.trim_end_matches('/')];
+        }
+        let fallbacks = self.config.hub_endpoints.iter().flatten().skip(1);
+        std::iter::once(self.endpoint.as_str())
+            .chain(fallbacks.map(|endpoint| endpoint.trim_end_matches('/')))
+            .collect()
+    }
+
+    /// Runs `attempt` with each endpoint serving `repo_info` in turn, until one answers.
+    ///
+    /// Only failures meaning an endpoint couldn't answer move on to the next
+    /// one; a refusal, such as a missing file, is returned as is.
+    fn with_fallback<T>(
+        &self,
+        repo_info: &HubRepoInfo,
+        mut attempt: impl FnMut(&str) -> Result<T, XetError>,
+    ) -> Result<T, XetError> {
+        let endpoints = self.endpoints_for(repo_info);
+        let mut result = attempt(endpoints[0]);
+        for endpoint in &endpoints[1..] {
+            match &result {
+                Err(e) if is_hub_unreachable(e) => {
+                    self.diagnostic(format_args!("Falling back to {}: {}", endpoint, e));
+                    result = attempt(endpoint);
+                }
+                _ => break,
+            }
         }
+        result
     }
 
     fn user_agent(&self) -> &'static str {
@@ -1620,8 +9771,66 @@ impl XetClient {
         plan: Vec<XetDownloadPlan>,
         jwt: Arc<CasJwtInfo>,
     ) -> Result<Vec<String>, XetError> {
-        xet_download::download_with_plan(plan, jwt, self.user_agent(), XetDownloadConfig::default())
-            .await
+        self.check_unpinned()?;
+        let jwt = self.fresh_cas_jwt(jwt).await?;
+        let config = XetDownloadConfig {
+            retry_policy: self.retry_policy.clone(),
+            ..XetDownloadConfig::default()
+        };
+        xet_download::download_with_plan(plan, jwt, self.user_agent(), config).await
+    }
+
+    /// Refuses work the Xet data crate would do over its own connections,
+    /// which can't enforce the client's certificate pins.
+    fn check_unpinned(&self) -> Result<(), XetError> {
+        if self.tls.is_pinned() {
+            return Err(XetError::PolicyViolation {
+                message: "Xet storage can't be reached with certificate pins configured"
+                    .to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a CAS token from `refresh_route`, reusing a cached one while it's valid.
+    async fn cas_jwt(&self, refresh_route: &str) -> Result<Arc<CasJwtInfo>, XetError> {
+        let client = self.http_client()?;
+        let observe = |headers: &reqwest::header::HeaderMap| self.observe_headers(headers);
+        with_retries(&self.retry_policy, || {
+            get_cached_cas_jwt(
+                &client,
+                refresh_route,
+                self.token.as_ref(),
+                self.config.preferred_cas_endpoint.as_deref(),
+                &self.clock,
+                &observe,
+            )
+        })
+        .await
+    }
+
+    /// Returns `jwt`, or a replacement if it's expiring and can be refreshed.
+    ///
+    /// An expired token would otherwise only fail once the transfer is well
+    /// under way, with an error that doesn't say why.
+    async fn fresh_cas_jwt(&self, jwt: Arc<CasJwtInfo>) -> Result<Arc<CasJwtInfo>, XetError> {
+        if !jwt.is_expiring(self.clock.now()) {
+            return Ok(jwt);
+        }
+        let jwt = match &jwt.refresh_route {
+            Some(refresh_route) => self.cas_jwt(refresh_route).await?,
+            None => jwt,
+        };
+        if jwt.is_expiring(self.clock.now()) {
+            return Err(XetError::TokenExpired {
+                message: format!(
+                    "The CAS token expires at {}; request a new one with get_cas_jwt",
+                    jwt.exp()
+                ),
+                expired_at: jwt.exp(),
+            });
+        }
+        Ok(jwt)
     }
     
     /// Apply high-performance defaults for downloads.
@@ -1629,6 +9838,13 @@ impl XetClient {
     /// This sets environment variables that the underlying Xet library reads
     /// during initialization to configure optimal download performance.
     fn apply_performance_defaults() {
+        // Environment variables are process-wide and mutating them while other
+        // threads read them is unsound, so they are only ever set once.
+        static APPLY: Once = Once::new();
+        APPLY.call_once(Self::set_performance_env);
+    }
+
+    fn set_performance_env() {
         // Per-file concurrency for range GETs (CRITICAL for single large file throughput)
         // Default to 256 for excellent throughput on modern networks
         if std::env::var("HF_XET_NUM_CONCURRENT_RANGE_GETS").is_err() {
@@ -1654,3 +9870,770 @@ impl XetClient {
 
 // Include the generated UniFFI bindings
 uniffi::include_scaffolding!("swift_xet_rust");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MaxSizePolicy(u64);
+
+    impl DownloadPolicy for MaxSizePolicy {
+        fn allow_download(
+            &self,
+            _path: String,
+            size: Option<u64>,
+            _content_type: Option<String>,
+        ) -> bool {
+            size.is_some_and(|size| size <= self.0)
+        }
+    }
+
+    /// Serves `connections` connections on localhost, answering each request
+    /// with `respond`, and returns the server's URL and the requests it
+    /// received, lowercased.
+    fn serve(
+        connections: usize,
+        respond: impl Fn(&str) -> Vec<u8> + Send + 'static,
+    ) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for stream in listener.incoming().take(connections) {
+                let mut stream = stream.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                // Requests with a body may arrive in the same read as their headers.
+                while !request.windows(4).any(|line| line == b"\r\n\r\n") {
+                    match stream.read(&mut buffer) {
+                        Ok(0) | Err(_) => break,
+                        Ok(read) => request.extend_from_slice(&buffer[..read]),
+                    }
+                }
+                let request = String::from_utf8_lossy(&request).to_lowercase();
+                let _ = stream.write_all(&respond(&request));
+                requests.push(request);
+            }
+            requests
+        });
+        (url, server)
+    }
+
+    #[cfg(feature = "upload")]
+    #[test]
+    fn recovery_rolls_back_refused_commits_and_skips_live_uploads() {
+        let (url, server) = serve(1, |_| {
+            b"HTTP/1.1 409 Conflict\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_vec()
+        });
+        let mut client = XetClient::with_config(XetClientConfig {
+            token: Some("hf_token".to_string()),
+            ..XetClientConfig::default()
+        })
+        .unwrap();
+        let dir = std::env::temp_dir().join(format!("swift-xet-recovery-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        client.cache = CacheLayout::new(dir.clone());
+        let journal = CommitJournal::open(&client.cache);
+        let uploaded = |summary: &str| {
+            let mut intent = CommitIntent::new(&url, "models", "owner/repo", "main", summary);
+            intent.operations = vec![xet_commit::CommitOperation::Delete {
+                path_in_repo: "model.safetensors".to_string(),
+            }];
+            intent.state = IntentState::Uploaded;
+            journal.record(&intent).unwrap();
+            intent
+        };
+        let running = uploaded("Running");
+        uploaded("Refused");
+        let _live = LiveIntent::hold(&running);
+
+        let recovered = client.recover_pending_commits().unwrap();
+        assert_eq!(recovered.len(), 1);
+        assert_eq!(recovered[0].action(), CommitRecoveryAction::RolledBack);
+        let pending: Vec<String> = journal
+            .pending()
+            .unwrap()
+            .into_iter()
+            .map(|intent| intent.id)
+            .collect();
+        assert_eq!(pending, vec![running.id]);
+
+        server.join().unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn shared_types_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<XetClient>();
+        assert_send_sync::<CancellationToken>();
+        assert_send_sync::<DownloadHandle>();
+        assert_send_sync::<TransferGroup>();
+        assert_send_sync::<TransferReport>();
+    }
+
+    #[test]
+    fn transfer_groups_sum_their_downloads() {
+        struct Recorder(Arc<Mutex<Vec<(u64, u64)>>>);
+        impl ProgressCallback for Recorder {
+            fn on_progress(&self, update: Arc<ProgressUpdate>) {
+                let bytes = (update.total_bytes_completed(), update.total_bytes());
+                self.0.lock().unwrap().push(bytes);
+            }
+        }
+
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Box::new(Recorder(updates.clone()));
+        let group = Arc::new(TransferGroup::new(Some(recorder)));
+        let config = group.join();
+        let weights = group.join();
+        config.on_progress(Arc::new(ProgressUpdate::for_transfer(10, 10)));
+        weights.on_progress(Arc::new(ProgressUpdate::for_transfer(0, 90)));
+        weights.on_progress(Arc::new(ProgressUpdate::for_transfer(45, 90)));
+        assert_eq!(group.download_count(), 2);
+        assert_eq!(*updates.lock().unwrap(), [(10, 10), (10, 100), (55, 100)]);
+        assert_eq!(group.progress().total_bytes_completed(), 55);
+
+        // Every download in the group shares the handle the group cancels.
+        group.cancel();
+        assert!(group.handle.is_cancelled());
+    }
+
+    #[test]
+    fn placeholder_files_are_recognized_by_size_or_name() {
+        assert!(is_placeholder_file("empty.txt", Some(0)));
+        assert!(is_placeholder_file(".gitattributes", Some(1519)));
+        assert!(is_placeholder_file("onnx/.gitkeep", None));
+        assert!(!is_placeholder_file("model.safetensors", Some(42)));
+        assert!(!is_placeholder_file("config.json", None));
+    }
+
+    #[test]
+    fn flagged_files_are_read_from_expanded_listings() {
+        let entries = parse_tree_entries(
+            r#"[
+                {"type": "file", "path": "model.pkl", "securityFileStatus": {"status": "unsafe"}},
+                {"type": "file", "path": "weights.bin", "securityFileStatus": {"status": "Suspicious"}},
+                {"type": "file", "path": "config.json", "securityFileStatus": {"status": "safe"}},
+                {"type": "file", "path": "README.md"}
+            ]"#,
+        )
+        .unwrap();
+        let flagged = flagged_entries(&entries);
+        assert_eq!(flagged.len(), 2);
+        assert_eq!(flagged["model.pkl"], "unsafe");
+        assert_eq!(flagged["weights.bin"], "Suspicious");
+    }
+
+    #[test]
+    fn auth_failures_are_recognized_in_flattened_errors() {
+        assert!(is_auth_failure(&XetError::AuthError {
+            message: "Authentication failed".to_string(),
+        }));
+        assert!(is_auth_failure(&XetError::NetworkError {
+            message: "HTTP error: HTTP status client error (401 Unauthorized) for url".to_string(),
+            cause: None,
+        }));
+        assert!(!is_auth_failure(&XetError::NetworkError {
+            message: "HTTP error: HTTP status client error (404 Not Found) for url".to_string(),
+            cause: None,
+        }));
+        assert!(!is_auth_failure(&XetError::PolicyViolation {
+            message: "401 Unauthorized".to_string(),
+        }));
+    }
+
+    #[test]
+    fn cas_tokens_expire_within_the_margin() {
+        let token = |exp| {
+            CasJwtInfo::from(hub_client::CasJWTInfo {
+                cas_url: "https://cas.example.com".to_string(),
+                exp,
+                access_token: "token".to_string(),
+            })
+        };
+        let now = unix_now();
+        assert!(token(0).is_expiring(now));
+        assert!(token(now + 30).is_expiring(now));
+        assert!(!token(now + 3600).is_expiring(now));
+    }
+
+    #[test]
+    fn endpoint_overrides_apply_to_matching_repositories() {
+        let client = XetClient::with_config(XetClientConfig {
+            token: Some("hf_token".to_string()),
+            endpoint_overrides: Some(vec![EndpointOverride {
+                repo_pattern: "my-org/*".to_string(),
+                endpoint: "https://mirror.example.com/".to_string(),
+            }]),
+            ..XetClientConfig::default()
+        })
+        .unwrap();
+        let repo = |full_name: &str| HubRepoInfo {
+            repo_type: hub_client::HFRepoType::Model,
+            full_name: full_name.to_string(),
+        };
+        let (mirrored, public) = (repo("my-org/model"), repo("other-org/model"));
+        assert_eq!(client.endpoint_for(&mirrored), "https://mirror.example.com");
+        assert_eq!(client.endpoint_for(&public), "https://huggingface.co");
+        assert!(client.should_send_auth("https://mirror.example.com/my-org/model"));
+        assert!(!client.should_send_auth("https://cdn.example.org/my-org/model"));
+        assert!(!client.should_send_auth("https://mirror.example.com:8443/my-org/model"));
+
+        let invalid = XetClient::with_config(XetClientConfig {
+            endpoint_overrides: Some(vec![EndpointOverride {
+                repo_pattern: "my-org/*".to_string(),
+                endpoint: "mirror.example.com".to_string(),
+            }]),
+            ..XetClientConfig::default()
+        });
+        assert!(matches!(invalid, Err(XetError::InvalidInput { .. })));
+    }
+
+    #[test]
+    fn responses_without_a_length_are_held_to_the_size_limit() {
+        struct Recorder(Arc<Mutex<Vec<Option<u64>>>>);
+        impl DownloadPolicy for Recorder {
+            fn allow_download(
+                &self,
+                _path: String,
+                size: Option<u64>,
+                _content_type: Option<String>,
+            ) -> bool {
+                self.0.lock().unwrap().push(size);
+                true
+            }
+        }
+
+        let (endpoint, server) = serve(1, |_| {
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n\
+             5\r\n01234\r\n5\r\n56789\r\n0\r\n\r\n"
+                .as_bytes()
+                .to_vec()
+        });
+        let client = XetClient::with_config(XetClientConfig {
+            hub_endpoints: Some(vec![endpoint]),
+            max_file_size_bytes: Some(8),
+            ..XetClientConfig::default()
+        })
+        .unwrap();
+        let sizes = Arc::new(Mutex::new(Vec::new()));
+        client.set_download_policy(Some(Box::new(Recorder(sizes.clone()))));
+        let destination = std::env::temp_dir()
+            .join(format!("swift-xet-unsized-{}.bin", std::process::id()))
+            .to_string_lossy()
+            .to_string();
+        let result = client.download_file_legacy(
+            client.parse_repo("owner/repo").unwrap(),
+            "model.bin".to_string(),
+            destination.clone(),
+            None,
+            &TransferObserver::default(),
+        );
+        server.join().unwrap();
+        assert!(matches!(result, Err(XetError::PolicyViolation { .. })));
+        assert_eq!(*sizes.lock().unwrap(), [None]);
+        assert!(!Path::new(&destination).exists());
+    }
+
+    #[test]
+    fn tree_pages_on_other_origins_are_fetched_without_the_token() {
+        let page = |link: Option<String>| {
+            let link = link.map_or(String::new(), |url| {
+                format!("Link: <{}>; rel=\"next\"\r\n", url)
+            });
+            format!(
+                "HTTP/1.1 200 OK\r\n{}Content-Length: 2\r\nConnection: close\r\n\r\n[]",
+                link
+            )
+            .into_bytes()
+        };
+        let (elsewhere, foreign) = serve(1, move |_| page(None));
+        let next = format!("{}/api/models/owner/repo/tree/main?cursor=1", elsewhere);
+        let (endpoint, hub) = serve(1, move |_| page(Some(next.clone())));
+        let client = XetClient::with_config(XetClientConfig {
+            token: Some("hf_token".to_string()),
+            hub_endpoints: Some(vec![endpoint.clone()]),
+            ..XetClientConfig::default()
+        })
+        .unwrap();
+
+        let entries = client
+            .fetch_tree(format!("{}/api/models/owner/repo/tree/main", endpoint))
+            .unwrap();
+        assert!(entries.is_empty());
+        assert!(hub.join().unwrap()[0].contains("authorization: bearer hf_token"));
+        assert!(!foreign.join().unwrap()[0].contains("authorization"));
+    }
+
+    #[test]
+    fn hub_endpoints_are_tried_in_order() {
+        let client = XetClient::with_config(XetClientConfig {
+            quiet: true,
+            hub_endpoints: Some(vec![
+                "https://hf-mirror.com/".to_string(),
+                "https://huggingface.co".to_string(),
+            ]),
+            endpoint_overrides: Some(vec![EndpointOverride {
+                repo_pattern: "my-org/*".to_string(),
+                endpoint: "https://internal.example.com".to_string(),
+            }]),
+            ..XetClientConfig::default()
+        })
+        .unwrap();
+        let repo = |full_name: &str| HubRepoInfo {
+            repo_type: hub_client::HFRepoType::Model,
+            full_name: full_name.to_string(),
+        };
+        let (internal, public) = (repo("my-org/model"), repo("other-org/model"));
+        assert_eq!(
+            client.endpoints_for(&public),
+            ["https://hf-mirror.com", "https://huggingface.co"]
+        );
+        assert_eq!(
+            client.endpoints_for(&internal),
+            ["https://internal.example.com"]
+        );
+        let urls = client.build_resolve_urls(&public, "config.json", "main");
+        assert!(urls[0].starts_with("https://hf-mirror.com/"));
+        assert!(urls[5].starts_with("https://huggingface.co/"));
+
+        // Parquet exports live at a ref whose slashes must stay escaped.
+        let dataset = HubRepoInfo {
+            repo_type: hub_client::HFRepoType::Dataset,
+            full_name: "owner/data".to_string(),
+        };
+        let revision = "refs/convert/parquet";
+        let urls = client.build_resolve_urls(&dataset, "default/train/0000.parquet", revision);
+        assert!(urls[0].contains("/datasets/owner/data/resolve/refs%2Fconvert%2Fparquet/"));
+        let tree = client.tree_url(
+            "https://huggingface.co",
+            &dataset,
+            "",
+            revision,
+            false,
+            false,
+        );
+        assert!(tree.ends_with("/api/datasets/owner/data/tree/refs%2Fconvert%2Fparquet"));
+        let tree = client.tree_url("https://huggingface.co", &dataset, "", "main", true, true);
+        assert!(tree.ends_with("/tree/main?recursive=true&expand=true"));
+
+        // So do branches named with slashes.
+        for url in client.build_resolve_urls(&dataset, "config.json", "release/v1.0") {
+            assert!(url.contains("release%2Fv1.0"), "{}", url);
+            assert!(!url.contains("release/v1.0"), "{}", url);
+        }
+        let route = client.revision_route(
+            "https://huggingface.co",
+            &dataset,
+            "paths-info",
+            "release/v1.0",
+        );
+        assert!(route.ends_with("/api/datasets/owner/data/paths-info/release%2Fv1.0"));
+
+        // Resolving and listing escape special characters in a path alike.
+        let path = "my dir/c++ #1?.txt";
+        let encoded = "my%20dir/c%2B%2B%20%231%3F.txt";
+        let tree = client.tree_url(
+            "https://huggingface.co",
+            &dataset,
+            path,
+            "main",
+            false,
+            false,
+        );
+        assert!(tree.ends_with(&format!("/tree/main/{}", encoded)));
+        for url in client.build_resolve_urls(&dataset, path, "main") {
+            assert!(url.contains(encoded), "{}", url);
+        }
+
+        let failure = |status| XetError::NetworkError {
+            message: "Request failed".to_string(),
+            cause: Some(ErrorCause::Http {
+                kind: HttpErrorKind::Status,
+                status: Some(status),
+            }),
+        };
+        let mut attempted = Vec::new();
+        let result: Result<(), XetError> = client.with_fallback(&public, |endpoint| {
+            attempted.push(endpoint.to_string());
+            Err(failure(503))
+        });
+        assert!(result.is_err());
+        assert_eq!(attempted.len(), 2);
+
+        let mut attempted = Vec::new();
+        let result: Result<(), XetError> = client.with_fallback(&public, |endpoint| {
+            attempted.push(endpoint.to_string());
+            Err(failure(404))
+        });
+        assert!(result.is_err());
+        assert_eq!(attempted, ["https://hf-mirror.com"]);
+
+        let empty = XetClient::with_config(XetClientConfig {
+            hub_endpoints: Some(Vec::new()),
+            ..XetClientConfig::default()
+        });
+        assert!(matches!(empty, Err(XetError::InvalidInput { .. })));
+    }
+
+    #[test]
+    fn batch_results_are_counted_by_status() {
+        let result = |path: &str, status| {
+            Arc::new(FileDownloadResult {
+                request: Arc::new(FileDownloadRequest::new(
+                    "owner/repo".to_string(),
+                    path.to_string(),
+                    format!("/tmp/{}", path),
+                    None,
+                )),
+                destination: format!("/tmp/{}", path),
+                status,
+                report: None,
+                error: None,
+            })
+        };
+        let batch = BatchDownloadResult {
+            results: vec![
+                result("a.bin", FileDownloadStatus::Downloaded),
+                result("b.bin", FileDownloadStatus::Failed),
+                result("c.txt", FileDownloadStatus::Skipped),
+                result("d.bin", FileDownloadStatus::Downloaded),
+                result("e.bin", FileDownloadStatus::Unauthorized),
+            ],
+        };
+        assert_eq!(batch.downloaded_paths(), ["/tmp/a.bin", "/tmp/d.bin"]);
+        assert_eq!(batch.failed_count(), 1);
+        assert_eq!(batch.skipped_count(), 1);
+        assert_eq!(batch.unauthorized_count(), 1);
+    }
+
+    #[test]
+    fn download_by_hash_rejects_malformed_hashes() {
+        let client = XetClient::new().unwrap();
+        let jwt = Arc::new(CasJwtInfo::from(hub_client::CasJWTInfo {
+            cas_url: "https://cas.example.com".to_string(),
+            exp: unix_now() + 3600,
+            access_token: "token".to_string(),
+        }));
+        for hash in ["", "abc123", &"g".repeat(64)] {
+            let result =
+                client.download_by_hash(hash.to_string(), 42, "/tmp/file".to_string(), jwt.clone());
+            assert!(matches!(result, Err(XetError::InvalidInput { .. })));
+        }
+    }
+
+    #[test]
+    fn cancelled_or_failed_archive_downloads_leave_nothing_behind() {
+        use std::io::{Read, Write};
+
+        // Serves the start of an archive, then trickles the rest until the
+        // client hangs up.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 4096];
+            let _ = stream.read(&mut request);
+            let headers = "HTTP/1.1 200 OK\r\nContent-Length: 1048576\r\n\r\n";
+            let _ = stream.write_all(headers.as_bytes());
+            for _ in 0..500 {
+                if stream.write_all(&[0u8; 1024]).is_err() {
+                    break;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        });
+
+        struct Canceller(Arc<CancellationToken>);
+        impl ProgressCallback for Canceller {
+            fn on_progress(&self, _update: Arc<ProgressUpdate>) {
+                self.0.cancel();
+            }
+        }
+
+        let client = XetClient::with_config(XetClientConfig {
+            hub_endpoints: Some(vec![endpoint]),
+            ..XetClientConfig::default()
+        })
+        .unwrap();
+        let dir = std::env::temp_dir().join(format!("swift-xet-archive-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let destination = dir.join("repo.zip");
+        let token = Arc::new(CancellationToken::new());
+        let result = client.download_repo_archive_with_progress(
+            "owner/repo".to_string(),
+            None,
+            destination.to_string_lossy().to_string(),
+            ArchiveFormat::Zip,
+            Some(Box::new(Canceller(token.clone()))),
+            Some(token),
+        );
+        assert!(matches!(result, Err(XetError::Cancelled { .. })));
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+        drop(client);
+        server.join().unwrap();
+
+        // A failed download also drops what an earlier one left behind.
+        let unreachable = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", unreachable.local_addr().unwrap());
+        drop(unreachable);
+        let client = XetClient::with_config(XetClientConfig {
+            hub_endpoints: Some(vec![endpoint]),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: 1,
+                ..RetryPolicy::default()
+            }),
+            ..XetClientConfig::default()
+        })
+        .unwrap();
+        std::fs::write(dir.join("repo.zip.incomplete"), b"stale").unwrap();
+        let result = client.download_repo_archive(
+            "owner/repo".to_string(),
+            None,
+            destination.to_string_lossy().to_string(),
+            ArchiveFormat::Zip,
+        );
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "upload")]
+    #[test]
+    fn estimate_upload_requires_files() {
+        let client = XetClient::new().unwrap();
+        let result = client.estimate_upload("owner/repo".to_string(), Vec::new(), None);
+        assert!(matches!(result, Err(XetError::InvalidInput { .. })));
+    }
+
+    #[test]
+    fn oversized_listings_are_recognized() {
+        let oversized = |kind, status| {
+            is_oversized_listing(&XetError::NetworkError {
+                message: "Request failed".to_string(),
+                cause: Some(ErrorCause::Http { kind, status }),
+            })
+        };
+        assert!(oversized(HttpErrorKind::Status, Some(413)));
+        assert!(oversized(HttpErrorKind::Body, None));
+        assert!(!oversized(HttpErrorKind::Status, Some(404)));
+        assert!(!oversized(HttpErrorKind::Connect, None));
+    }
+
+    #[test]
+    fn causes_are_preserved_through_conversion_and_wrapping() {
+        let error = XetError::from(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+        let wrapped = XetError::OperationFailed {
+            message: format!("Failed to download model.bin: {}", error),
+            cause: error.cause(),
+        };
+        assert_eq!(
+            wrapped.cause(),
+            Some(ErrorCause::Io {
+                kind: IoErrorKind::PermissionDenied,
+            })
+        );
+
+        assert!(is_server_error(&XetError::NetworkError {
+            message: "Request failed".to_string(),
+            cause: Some(ErrorCause::Http {
+                kind: HttpErrorKind::Status,
+                status: Some(502),
+            }),
+        }));
+        assert!(!is_server_error(&XetError::NetworkError {
+            message: "HTTP error 500 in the path".to_string(),
+            cause: Some(ErrorCause::Http {
+                kind: HttpErrorKind::Status,
+                status: Some(404),
+            }),
+        }));
+    }
+
+    #[test]
+    fn variant_names_are_read_from_debug_output() {
+        #[allow(dead_code)]
+        #[derive(Debug)]
+        enum Example {
+            Unit,
+            Tuple(u8),
+            Struct { field: u8 },
+        }
+        assert_eq!(variant_name(&Example::Unit), "Unit");
+        assert_eq!(variant_name(&Example::Tuple(1)), "Tuple");
+        assert_eq!(variant_name(&Example::Struct { field: 1 }), "Struct");
+    }
+
+    #[test]
+    fn error_codes_are_stable() {
+        assert_eq!(
+            error_code(&XetError::AuthRequired {
+                repo: "owner/private".to_string(),
+            }),
+            "XET_E_AUTH_REQUIRED"
+        );
+        assert_eq!(
+            XetError::InvalidInput {
+                message: "Path cannot be empty".to_string(),
+            }
+            .code(),
+            "XET_E_INVALID_INPUT"
+        );
+        assert_eq!(
+            XetError::TokenExpired {
+                message: "The CAS token expires at 0".to_string(),
+                expired_at: 0,
+            }
+            .code(),
+            "XET_E_TOKEN_EXPIRED"
+        );
+        assert_eq!(xet_deadline::timeout_error().code(), "XET_E_TIMEOUT");
+        assert_eq!(
+            XetError::PermissionDenied {
+                repo: "owner/repo".to_string(),
+                operation: RepoOperation::Write,
+            }
+            .code(),
+            "XET_E_PERMISSION_DENIED"
+        );
+    }
+
+    #[test]
+    fn concurrent_calls_share_one_client() {
+        let client = Arc::new(XetClient::new().unwrap());
+        let threads: Vec<_> = (0..16u64)
+            .map(|i| {
+                let client = client.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..100 {
+                        client.set_download_policy(Some(Box::new(MaxSizePolicy(i))));
+                        let _ = client.check_download_allowed("model.bin", Some(8), None);
+                        client
+                            .block_on(async {
+                                tokio::task::yield_now().await;
+                                Ok(())
+                            })
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn concurrent_downloads_share_one_client() {
+        const DOWNLOADS: usize = 8;
+        let (endpoint, server) = serve(DOWNLOADS * 2, |request| {
+            let line = request.lines().next().unwrap_or_default();
+            let name = line
+                .split("/resolve/main/")
+                .nth(1)
+                .and_then(|rest| rest.split(' ').next())
+                .unwrap_or_default();
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nETag: \"{}\"\r\n\
+                 X-Repo-Commit: {}\r\nConnection: close\r\n\r\n",
+                name.len(),
+                name,
+                "c".repeat(40)
+            );
+            if line.starts_with("head ") {
+                head.into_bytes()
+            } else {
+                format!("{}{}", head, name).into_bytes()
+            }
+        });
+        let client = Arc::new(
+            XetClient::with_config(XetClientConfig {
+                hub_endpoints: Some(vec![endpoint]),
+                ..XetClientConfig::default()
+            })
+            .unwrap(),
+        );
+        let dir = std::env::temp_dir().join(format!("swift-xet-concurrent-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let threads: Vec<_> = (0..DOWNLOADS)
+            .map(|i| {
+                let client = client.clone();
+                let destination = dir.join(format!("file-{}.bin", i));
+                std::thread::spawn(move || {
+                    client.download_file(
+                        "owner/repo".to_string(),
+                        format!("file-{}.bin", i),
+                        destination.to_string_lossy().to_string(),
+                        None,
+                    )
+                })
+            })
+            .collect();
+        for (i, thread) in threads.into_iter().enumerate() {
+            let report = thread.join().unwrap().unwrap();
+            let name = format!("file-{}.bin", i);
+            assert_eq!(report.bytes(), name.len() as u64);
+            assert_eq!(fs::read_to_string(dir.join(&name)).unwrap(), name);
+        }
+        server.join().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn current_thread_client_runs_work() {
+        let client = XetClient::with_config(XetClientConfig {
+            runtime_flavor: RuntimeFlavor::CurrentThread,
+            ..Default::default()
+        })
+        .unwrap();
+        let value = client
+            .block_on(async {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                Ok(42)
+            })
+            .unwrap();
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    fn detached_calls_block_on_the_client_runtime() {
+        let client = Arc::new(XetClient::new().unwrap());
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let call = client.clone().run_detached(|client| {
+            client.block_on(async {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                Ok(42)
+            })
+        });
+        assert_eq!(runtime.block_on(call).unwrap(), 42);
+
+        // Dropping the awaiting future cancels the call it's waiting on.
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let call = client.run_detached(move |client| {
+            let result = client.block_on(std::future::pending::<Result<(), XetError>>());
+            sender.send(result).unwrap();
+            Ok(())
+        });
+        runtime.block_on(async {
+            let _ = tokio::time::timeout(Duration::from_millis(50), call).await;
+        });
+        let result = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(matches!(result, Err(XetError::Cancelled { .. })));
+    }
+
+    #[test]
+    fn block_on_refuses_reentrant_calls() {
+        let client = XetClient::new().unwrap();
+        let result = client.block_on(async { client.block_on(async { Ok(()) }) });
+        assert!(matches!(result, Err(XetError::ReentrantCall { .. })));
+    }
+}
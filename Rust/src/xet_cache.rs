@@ -0,0 +1,564 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crate::xet_hub::HubCache;
+use crate::xet_storage::{CacheStorage, FileSystemStorage};
+use crate::{CacheArea, CacheMaintenancePolicy, XetClientConfig, XetError};
+
+const CHUNK_CACHE_DIR: &str = "chunk-cache";
+/// Holds the terms of Xet reconstructions run by this client, which count
+/// toward the chunk area alongside the data crate's own chunk cache.
+pub const TERM_CACHE_DIR: &str = "term-cache";
+const SHARD_CACHE_DIR: &str = "shard-cache";
+const STAGING_DIR: &str = "staging";
+pub const MANAGED_DIR: &str = "managed";
+pub const HUB_DIR: &str = "hub";
+/// Holds the commit journal, which records work still owed to the Hub rather
+/// than cached data, so clearing and pruning never touch it.
+pub const JOURNAL_DIR: &str = "journal";
+
+const AREAS: [CacheArea; 6] = [
+    CacheArea::Chunks,
+    CacheArea::Shards,
+    CacheArea::Metadata,
+    CacheArea::StagedUploads,
+    CacheArea::ManagedModels,
+    CacheArea::DownloadedFiles,
+];
+
+#[derive(Clone, Copy, Default)]
+pub struct AreaUsage {
+    pub total_size_bytes: u64,
+    pub file_count: u64,
+}
+
+impl AreaUsage {
+    fn add(&mut self, size: u64) {
+        self.total_size_bytes += size;
+        self.file_count += 1;
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct CacheUsage {
+    pub chunks: AreaUsage,
+    pub shards: AreaUsage,
+    pub metadata: AreaUsage,
+    pub staged_uploads: AreaUsage,
+    pub managed_models: AreaUsage,
+    pub downloaded_files: AreaUsage,
+}
+
+impl CacheUsage {
+    pub fn area(&self, area: CacheArea) -> AreaUsage {
+        match area {
+            CacheArea::Chunks => self.chunks,
+            CacheArea::Shards => self.shards,
+            CacheArea::Metadata => self.metadata,
+            CacheArea::StagedUploads => self.staged_uploads,
+            CacheArea::ManagedModels => self.managed_models,
+            CacheArea::DownloadedFiles => self.downloaded_files,
+        }
+    }
+
+    fn area_mut(&mut self, area: CacheArea) -> &mut AreaUsage {
+        match area {
+            CacheArea::Chunks => &mut self.chunks,
+            CacheArea::Shards => &mut self.shards,
+            CacheArea::Metadata => &mut self.metadata,
+            CacheArea::StagedUploads => &mut self.staged_uploads,
+            CacheArea::ManagedModels => &mut self.managed_models,
+            CacheArea::DownloadedFiles => &mut self.downloaded_files,
+        }
+    }
+}
+
+struct CachedFile {
+    path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+}
+
+pub fn cache_root() -> PathBuf {
+    xet_runtime::xet_cache_root()
+}
+
+/// Where each area of the cache lives, and the storage its records are kept in.
+///
+/// Areas live under the Xet data crate's cache directory unless relocated.
+/// A relocated area keeps the directory it would have had under the default
+/// root, so several areas can share a root and still be told apart by
+/// `area_for_path`.
+#[derive(Clone)]
+pub struct CacheLayout {
+    base: PathBuf,
+    overrides: HashMap<CacheArea, PathBuf>,
+    storage: Arc<dyn CacheStorage>,
+}
+
+impl CacheLayout {
+    /// Places every area under `base`, storing records as files.
+    pub fn new(base: PathBuf) -> Self {
+        Self {
+            base,
+            overrides: HashMap::new(),
+            storage: Arc::new(FileSystemStorage),
+        }
+    }
+
+    /// Places the areas relocated by `config` under their roots, and the rest under the default root.
+    pub fn from_config(config: &XetClientConfig) -> Result<Self, XetError> {
+        let mut layout = Self::new(cache_root());
+        for cache_root in config.cache_roots.iter().flatten() {
+            if matches!(
+                cache_root.area,
+                CacheArea::Chunks | CacheArea::Shards | CacheArea::StagedUploads
+            ) {
+                return Err(XetError::InvalidInput {
+                    message: format!(
+                        "The {:?} cache area is kept by the Xet data crate and can't be relocated",
+                        cache_root.area
+                    ),
+                });
+            }
+            let path = PathBuf::from(&cache_root.path);
+            if !path.is_absolute() {
+                return Err(XetError::InvalidInput {
+                    message: format!("Cache root must be an absolute path: {}", cache_root.path),
+                });
+            }
+            if layout.overrides.contains_key(&cache_root.area) {
+                return Err(XetError::InvalidInput {
+                    message: format!("Cache area {:?} has more than one root", cache_root.area),
+                });
+            }
+            layout = layout.with_root(cache_root.area, path);
+        }
+        Ok(layout)
+    }
+
+    /// Places `area` under `root` instead of the default root.
+    pub fn with_root(mut self, area: CacheArea, root: PathBuf) -> Self {
+        self.overrides.insert(area, root);
+        self
+    }
+
+    /// Returns the root the directory of `area` is placed under.
+    pub fn root(&self, area: CacheArea) -> &Path {
+        self.overrides.get(&area).unwrap_or(&self.base)
+    }
+
+    /// Returns the default root, which holds every area that wasn't relocated.
+    pub fn base(&self) -> &Path {
+        &self.base
+    }
+
+    pub fn storage(&self) -> Arc<dyn CacheStorage> {
+        self.storage.clone()
+    }
+
+    /// Returns the areas placed somewhere other than the default root.
+    pub fn relocated_areas(&self) -> impl Iterator<Item = CacheArea> + '_ {
+        AREAS
+            .into_iter()
+            .filter(|area| self.overrides.contains_key(area))
+    }
+
+    /// Measures each area at its own root.
+    ///
+    /// Files an area left under the default root before it was relocated
+    /// aren't counted; clear that area before relocating it to reclaim them.
+    pub fn usage(&self) -> io::Result<CacheUsage> {
+        let mut measured: HashMap<&Path, CacheUsage> = HashMap::new();
+        let mut usage = CacheUsage::default();
+        for area in AREAS {
+            let root = self.root(area);
+            if let Entry::Vacant(entry) = measured.entry(root) {
+                entry.insert(collect_usage(root)?);
+            }
+            *usage.area_mut(area) = measured[root].area(area);
+        }
+        Ok(usage)
+    }
+}
+
+/// Classifies a cached file by the well-known xet-core directory it lives under.
+///
+/// The data client nests its caches below a per-endpoint directory, so the
+/// area is determined by the first matching component rather than a fixed depth.
+/// Anything outside the chunk, term, shard, staging, managed model, and downloaded file directories is
+/// cheap-to-refetch bookkeeping and counts as metadata.
+pub fn area_for_path(root: &Path, path: &Path) -> CacheArea {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    for component in relative.components() {
+        match component.as_os_str().to_str() {
+            Some(CHUNK_CACHE_DIR | TERM_CACHE_DIR) => return CacheArea::Chunks,
+            Some(SHARD_CACHE_DIR) => return CacheArea::Shards,
+            Some(STAGING_DIR) => return CacheArea::StagedUploads,
+            Some(MANAGED_DIR) => return CacheArea::ManagedModels,
+            Some(HUB_DIR) => return CacheArea::DownloadedFiles,
+            _ => {}
+        }
+    }
+    CacheArea::Metadata
+}
+
+pub fn collect_usage(root: &Path) -> io::Result<CacheUsage> {
+    let mut usage = CacheUsage::default();
+    for file in list_files(root)? {
+        usage.area_mut(area_for_path(root, &file.path)).add(file.size);
+    }
+    Ok(usage)
+}
+
+/// Removes everything under `root` except the commit journal.
+pub fn clear_all(root: &Path) -> io::Result<()> {
+    if !root.is_dir() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        if entry.file_name() == JOURNAL_DIR {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            fs::remove_dir_all(entry.path())?;
+        } else {
+            remove_file_if_exists(&entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+pub fn clear_area(root: &Path, area: CacheArea) -> io::Result<()> {
+    for file in list_files(root)? {
+        if area_for_path(root, &file.path) == area {
+            remove_file_if_exists(&file.path)?;
+        }
+    }
+    remove_empty_dirs(root)?;
+    Ok(())
+}
+
+/// Evicts the least recently modified files in `area` until it fits in `max_size_bytes`.
+///
+/// Returns the number of bytes freed.
+pub fn prune_area(root: &Path, area: CacheArea, max_size_bytes: u64) -> io::Result<u64> {
+    let mut files: Vec<CachedFile> = list_files(root)?
+        .into_iter()
+        .filter(|file| area_for_path(root, &file.path) == area)
+        .collect();
+
+    let mut current_size: u64 = files.iter().map(|file| file.size).sum();
+    if current_size <= max_size_bytes {
+        return Ok(0);
+    }
+
+    files.sort_by_key(|file| file.modified);
+
+    let mut freed: u64 = 0;
+    for file in files {
+        if current_size <= max_size_bytes {
+            break;
+        }
+        remove_file_if_exists(&file.path)?;
+        current_size = current_size.saturating_sub(file.size);
+        freed += file.size;
+    }
+
+    remove_empty_dirs(root)?;
+    Ok(freed)
+}
+
+/// Runs a single maintenance pass: drops truncated entries and downloaded
+/// files that fail verification, then enforces the policy's size limits.
+///
+/// `verified` carries the downloaded files found intact from one pass to the next.
+pub fn run_maintenance(
+    layout: &CacheLayout,
+    policy: &CacheMaintenancePolicy,
+    verified: &mut HashMap<PathBuf, (u64, SystemTime)>,
+) -> io::Result<()> {
+    remove_truncated_files(layout.base())?;
+    HubCache::open(layout).remove_corrupt_blobs(verified)?;
+
+    let limits = [
+        (CacheArea::Chunks, policy.max_chunk_cache_bytes),
+        (CacheArea::Shards, policy.max_shard_cache_bytes),
+        (CacheArea::Metadata, policy.max_metadata_bytes),
+        (CacheArea::StagedUploads, policy.max_staged_upload_bytes),
+    ];
+    for (area, limit) in limits {
+        if let Some(max_size_bytes) = limit {
+            prune_area(layout.root(area), area, max_size_bytes)?;
+        }
+    }
+    Ok(())
+}
+
+/// Removes zero-byte chunk and shard files, which can only result from interrupted writes.
+fn remove_truncated_files(root: &Path) -> io::Result<()> {
+    for file in list_files(root)? {
+        let area = area_for_path(root, &file.path);
+        if file.size == 0 && matches!(area, CacheArea::Chunks | CacheArea::Shards) {
+            remove_file_if_exists(&file.path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Lists the cached files under `root`, leaving out the commit journal.
+fn list_files(root: &Path) -> io::Result<Vec<CachedFile>> {
+    let mut files = Vec::new();
+    if root.exists() {
+        walk(root, &mut files)?;
+    }
+    let journal = root.join(JOURNAL_DIR);
+    files.retain(|file| !file.path.starts_with(&journal));
+    Ok(files)
+}
+
+fn walk(path: &Path, files: &mut Vec<CachedFile>) -> io::Result<()> {
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            walk(&entry.path(), files)?;
+        } else if file_type.is_file() {
+            let metadata = entry.metadata()?;
+            files.push(CachedFile {
+                path: entry.path(),
+                size: metadata.len(),
+                modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn remove_file_if_exists(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Removes directories left empty by eviction, keeping `root` itself.
+fn remove_empty_dirs(root: &Path) -> io::Result<()> {
+    if !root.is_dir() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            let path = entry.path();
+            remove_empty_dirs(&path)?;
+            if fs::read_dir(&path)?.next().is_none() {
+                fs::remove_dir(&path)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "swift-xet-cache-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_file(path: &Path, len: usize) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![0u8; len]).unwrap();
+    }
+
+    #[test]
+    fn area_for_path_matches_nested_directories() {
+        let root = Path::new("/cache");
+        assert_eq!(
+            area_for_path(root, Path::new("/cache/endpoint/chunk-cache/ab/cd")),
+            CacheArea::Chunks
+        );
+        assert_eq!(
+            area_for_path(root, Path::new("/cache/term-cache/ab/0-4")),
+            CacheArea::Chunks
+        );
+        assert_eq!(
+            area_for_path(root, Path::new("/cache/endpoint/shard-cache/x.mdb")),
+            CacheArea::Shards
+        );
+        assert_eq!(
+            area_for_path(root, Path::new("/cache/endpoint/staging/xorbs/1")),
+            CacheArea::StagedUploads
+        );
+        assert_eq!(
+            area_for_path(root, Path::new("/cache/managed/models/models--a--b/config.json")),
+            CacheArea::ManagedModels
+        );
+        assert_eq!(
+            area_for_path(root, Path::new("/cache/hub/models--a--b/blobs/e1")),
+            CacheArea::DownloadedFiles
+        );
+        assert_eq!(
+            area_for_path(root, Path::new("/cache/endpoint/resolve.json")),
+            CacheArea::Metadata
+        );
+    }
+
+    #[test]
+    fn collect_usage_splits_by_area() {
+        let root = scratch_dir("usage");
+        write_file(&root.join("ep/chunk-cache/a"), 10);
+        write_file(&root.join("ep/chunk-cache/b"), 5);
+        write_file(&root.join("ep/shard-cache/s"), 3);
+        write_file(&root.join("ep/other"), 1);
+
+        let usage = collect_usage(&root).unwrap();
+        assert_eq!(usage.chunks.total_size_bytes, 15);
+        assert_eq!(usage.chunks.file_count, 2);
+        assert_eq!(usage.shards.total_size_bytes, 3);
+        assert_eq!(usage.metadata.file_count, 1);
+        assert_eq!(usage.staged_uploads.file_count, 0);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn relocated_areas_are_measured_at_their_roots() {
+        let root = scratch_dir("layout");
+        let shared = root.join("group");
+        write_file(&root.join("base/ep/chunk-cache/a"), 10);
+        write_file(&root.join("base/resolutions/stale.json"), 7);
+        write_file(&shared.join("resolutions/r.json"), 3);
+        write_file(&shared.join("hub/blobs/e1"), 5);
+
+        let layout = CacheLayout::new(root.join("base"))
+            .with_root(CacheArea::Metadata, shared.clone())
+            .with_root(CacheArea::DownloadedFiles, shared.clone());
+        assert_eq!(layout.root(CacheArea::Chunks), root.join("base"));
+        let usage = layout.usage().unwrap();
+        assert_eq!(usage.chunks.total_size_bytes, 10);
+        assert_eq!(usage.metadata.total_size_bytes, 3);
+        assert_eq!(usage.downloaded_files.total_size_bytes, 5);
+
+        let config = |area, path: &str| XetClientConfig {
+            cache_roots: Some(vec![crate::CacheRoot {
+                area,
+                path: path.to_string(),
+            }]),
+            ..XetClientConfig::default()
+        };
+        let shared_path = shared.to_str().unwrap();
+        assert!(CacheLayout::from_config(&config(CacheArea::Metadata, shared_path)).is_ok());
+        assert!(CacheLayout::from_config(&config(CacheArea::Chunks, shared_path)).is_err());
+        assert!(CacheLayout::from_config(&config(CacheArea::Metadata, "relative")).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn prune_area_evicts_oldest_first() {
+        let root = scratch_dir("prune");
+        let old = root.join("ep/chunk-cache/old");
+        let new = root.join("ep/chunk-cache/new");
+        write_file(&old, 10);
+        write_file(&new, 10);
+        write_file(&root.join("ep/shard-cache/s"), 50);
+        fs::File::options()
+            .write(true)
+            .open(&old)
+            .unwrap()
+            .set_modified(UNIX_EPOCH + Duration::from_secs(1))
+            .unwrap();
+
+        let freed = prune_area(&root, CacheArea::Chunks, 15).unwrap();
+        assert_eq!(freed, 10);
+        assert!(!old.exists());
+        assert!(new.exists());
+        assert!(root.join("ep/shard-cache/s").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn run_maintenance_applies_limits_and_drops_truncated_files() {
+        let root = scratch_dir("maintenance");
+        write_file(&root.join("ep/chunk-cache/empty"), 0);
+        write_file(&root.join("ep/chunk-cache/full"), 10);
+        write_file(&root.join("ep/metadata.json"), 10);
+
+        let policy = CacheMaintenancePolicy {
+            interval_secs: 60,
+            max_chunk_cache_bytes: None,
+            max_shard_cache_bytes: None,
+            max_metadata_bytes: Some(0),
+            max_staged_upload_bytes: None,
+        };
+        run_maintenance(
+            &CacheLayout::new(root.clone()),
+            &policy,
+            &mut HashMap::new(),
+        )
+        .unwrap();
+
+        assert!(!root.join("ep/chunk-cache/empty").exists());
+        assert!(root.join("ep/chunk-cache/full").exists());
+        assert!(!root.join("ep/metadata.json").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn the_commit_journal_survives_clearing_and_pruning() {
+        let root = scratch_dir("journal");
+        let intent = root.join(JOURNAL_DIR).join("1.json");
+        write_file(&intent, 10);
+        write_file(&root.join("ep/staging/xorbs/1"), 10);
+        write_file(&root.join("resolutions/r.json"), 10);
+
+        let policy = CacheMaintenancePolicy {
+            interval_secs: 60,
+            max_chunk_cache_bytes: None,
+            max_shard_cache_bytes: None,
+            max_metadata_bytes: Some(0),
+            max_staged_upload_bytes: Some(0),
+        };
+        run_maintenance(
+            &CacheLayout::new(root.clone()),
+            &policy,
+            &mut HashMap::new(),
+        )
+        .unwrap();
+        assert!(!root.join("ep/staging/xorbs/1").exists());
+        assert!(!root.join("resolutions/r.json").exists());
+        assert!(intent.exists());
+
+        for area in AREAS {
+            clear_area(&root, area).unwrap();
+        }
+        assert_eq!(collect_usage(&root).unwrap().metadata.file_count, 0);
+        assert!(intent.exists());
+
+        write_file(&root.join("ep/chunk-cache/a"), 10);
+        clear_all(&root).unwrap();
+        assert!(!root.join("ep").exists());
+        assert!(intent.exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
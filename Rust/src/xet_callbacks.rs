@@ -0,0 +1,79 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::sync::{Mutex, PoisonError};
+use std::thread::{self, ThreadId};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Runs host callbacks on a dedicated thread, away from the runtime.
+///
+/// Callbacks are delivered in the order they are dispatched. Because they never
+/// run on a thread that is driving the client's runtime, a callback may call
+/// back into the client without deadlocking it.
+pub struct CallbackDispatcher {
+    sender: Mutex<mpsc::Sender<Job>>,
+    thread_id: ThreadId,
+}
+
+impl CallbackDispatcher {
+    pub fn new() -> std::io::Result<Self> {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let handle = thread::Builder::new()
+            .name("xet-callbacks".to_string())
+            .spawn(move || {
+                // Exits once the dispatcher, and with it the sender, is dropped.
+                for job in receiver {
+                    // A panicking host callback must not stop delivery of later ones.
+                    let _ = panic::catch_unwind(AssertUnwindSafe(job));
+                }
+            })?;
+
+        Ok(Self {
+            sender: Mutex::new(sender),
+            thread_id: handle.thread().id(),
+        })
+    }
+
+    pub fn dispatch(&self, job: impl FnOnce() + Send + 'static) {
+        let sender = self.sender.lock().unwrap_or_else(PoisonError::into_inner);
+        let _ = sender.send(Box::new(job));
+    }
+
+    /// Waits until every callback dispatched so far has run.
+    ///
+    /// Returns immediately when called from a callback, which would otherwise
+    /// wait on itself.
+    pub fn flush(&self) {
+        if self.is_current_thread() {
+            return;
+        }
+        let (done, wait) = mpsc::channel();
+        self.dispatch(move || {
+            let _ = done.send(());
+        });
+        let _ = wait.recv();
+    }
+
+    pub fn is_current_thread(&self) -> bool {
+        thread::current().id() == self.thread_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn flush_waits_for_dispatched_callbacks_in_order() {
+        let dispatcher = CallbackDispatcher::new().unwrap();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        for value in 0..3 {
+            let seen = seen.clone();
+            dispatcher.dispatch(move || seen.lock().unwrap().push(value));
+        }
+        dispatcher.flush();
+        assert_eq!(*seen.lock().unwrap(), vec![0, 1, 2]);
+        assert!(!dispatcher.is_current_thread());
+    }
+}
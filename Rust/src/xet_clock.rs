@@ -0,0 +1,181 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+use reqwest::header::{HeaderMap, DATE};
+
+use crate::xet_ratelimit::unix_now;
+use crate::ClockSource;
+
+/// The skew ignored when the client configuration doesn't set a tolerance.
+pub const DEFAULT_SKEW_TOLERANCE_SECS: u64 = 30;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A source of the current Unix time, replaceable in tests.
+pub trait TimeSource: Send + Sync {
+    /// Returns the current Unix time in seconds.
+    fn unix_now(&self) -> u64;
+}
+
+/// The device's wall clock.
+struct DeviceTime;
+
+impl TimeSource for DeviceTime {
+    fn unix_now(&self) -> u64 {
+        unix_now()
+    }
+}
+
+/// The time that token expiry and other deadlines set by servers are judged against.
+///
+/// A device whose clock is off would otherwise treat valid tokens as expired,
+/// or keep using expired ones. The clock estimates the skew from the `Date`
+/// header of each Hub response and, with `ClockSource::Hub`, corrects the
+/// device's time by it once it exceeds the tolerance. Dates only have
+/// one-second precision and arrive after some latency, so small skews are noise.
+pub struct Clock {
+    time: Arc<dyn TimeSource>,
+    corrected: bool,
+    tolerance: u64,
+    /// The Hub's time minus the device's, in seconds, as of the latest response.
+    skew: AtomicI64,
+}
+
+impl Clock {
+    pub fn new(source: ClockSource, tolerance_secs: Option<u64>) -> Self {
+        Self {
+            time: Arc::new(DeviceTime),
+            corrected: source == ClockSource::Hub,
+            tolerance: tolerance_secs.unwrap_or(DEFAULT_SKEW_TOLERANCE_SECS),
+            skew: AtomicI64::new(0),
+        }
+    }
+
+    /// Reads the device's time from `time` instead of the wall clock.
+    #[cfg(test)]
+    pub fn with_time_source(mut self, time: Arc<dyn TimeSource>) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Updates the estimated skew from the `Date` header of a Hub response, if it has one.
+    pub fn observe(&self, headers: &HeaderMap) {
+        let Some(date) = headers
+            .get(DATE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_http_date)
+        else {
+            return;
+        };
+        let skew = date as i64 - self.time.unix_now() as i64;
+        self.skew.store(skew, Ordering::Relaxed);
+    }
+
+    /// Returns the Hub's time minus the device's in seconds, as last estimated.
+    pub fn skew(&self) -> i64 {
+        self.skew.load(Ordering::Relaxed)
+    }
+
+    /// Returns the current Unix time in seconds, corrected for skew beyond the tolerance.
+    pub fn now(&self) -> u64 {
+        let device = self.time.unix_now();
+        let skew = self.skew();
+        if !self.corrected || skew.unsigned_abs() <= self.tolerance {
+            return device;
+        }
+        device.saturating_add_signed(skew)
+    }
+}
+
+/// Parses an HTTP date in the IMF-fixdate form servers send, such as
+/// `Sun, 06 Nov 1994 08:49:37 GMT`, into a Unix time.
+fn parse_http_date(value: &str) -> Option<u64> {
+    let fields: Vec<&str> = value.split_whitespace().collect();
+    let [_, day, month, year, time, "GMT"] = fields.as_slice() else {
+        return None;
+    };
+    let day: u64 = day.parse().ok()?;
+    let month = MONTHS.iter().position(|name| name == month)? as u64 + 1;
+    let year: u64 = year.parse().ok()?;
+    let mut time = time.split(':').map(|field| field.parse::<u64>().ok());
+    let (Some(Some(hours)), Some(Some(minutes)), Some(Some(seconds)), None) =
+        (time.next(), time.next(), time.next(), time.next())
+    else {
+        return None;
+    };
+    if year < 1970 || !(1..=31).contains(&day) || hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+    Some(days_since_epoch(year, month, day) * 86_400 + hours * 3_600 + minutes * 60 + seconds)
+}
+
+/// Returns the number of days from 1970-01-01 to a date in the proleptic Gregorian calendar.
+fn days_since_epoch(year: u64, month: u64, day: u64) -> u64 {
+    // Counting years from March puts the leap day at the end of the year.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    struct FixedTime(u64);
+
+    impl TimeSource for FixedTime {
+        fn unix_now(&self) -> u64 {
+            self.0
+        }
+    }
+
+    fn dated(date: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(DATE, HeaderValue::from_static(date));
+        headers
+    }
+
+    #[test]
+    fn http_dates_are_parsed() {
+        assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(784_111_777)
+        );
+        assert_eq!(
+            parse_http_date("Thu, 29 Feb 2024 12:00:00 GMT"),
+            Some(1_709_208_000)
+        );
+        assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49 GMT"), None);
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 PST"), None);
+    }
+
+    #[test]
+    fn skew_beyond_the_tolerance_is_corrected() {
+        // The device runs ten minutes behind the Hub.
+        let device = Arc::new(FixedTime(784_111_777 - 600));
+        let clock = Clock::new(ClockSource::Hub, Some(30)).with_time_source(device.clone());
+        assert_eq!(clock.now(), device.0);
+        clock.observe(&dated("Sun, 06 Nov 1994 08:49:37 GMT"));
+        assert_eq!(clock.skew(), 600);
+        assert_eq!(clock.now(), 784_111_777);
+
+        let device_clock =
+            Clock::new(ClockSource::Device, Some(30)).with_time_source(device.clone());
+        device_clock.observe(&dated("Sun, 06 Nov 1994 08:49:37 GMT"));
+        assert_eq!(device_clock.skew(), 600);
+        assert_eq!(device_clock.now(), device.0);
+
+        // Skew within the tolerance is noise.
+        let tolerant = Clock::new(ClockSource::Hub, Some(900)).with_time_source(device.clone());
+        tolerant.observe(&dated("Sun, 06 Nov 1994 08:49:37 GMT"));
+        assert_eq!(tolerant.now(), device.0);
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::xet_unicode;
+use crate::{DestinationCollisionStrategy, XetError};
+
+static PROBE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Detects multi-file downloads writing two files to the same place.
+///
+/// Destinations are compared the way the filesystem that will hold them
+/// compares names, so on a case-insensitive volume `Model.bin` and `model.bin`
+/// collide even though the strings differ. Names that differ only in Unicode
+/// normalization always collide.
+pub struct CollisionDetector {
+    strategy: DestinationCollisionStrategy,
+    /// Case sensitivity of each probed directory.
+    case_insensitive: HashMap<PathBuf, bool>,
+    /// The source of each claimed destination, keyed by its folded form.
+    claimed: HashMap<String, String>,
+}
+
+/// The result of claiming a destination.
+pub enum Claim {
+    /// The destination is free.
+    Free,
+    /// The destination collided and was renamed to the given path.
+    Renamed(PathBuf),
+}
+
+impl CollisionDetector {
+    pub fn new(strategy: DestinationCollisionStrategy) -> Self {
+        Self {
+            strategy,
+            case_insensitive: HashMap::new(),
+            claimed: HashMap::new(),
+        }
+    }
+
+    /// Claims `destination` for the file identified by `source`.
+    ///
+    /// Requesting the same source at the same destination twice isn't a
+    /// collision. Returns `XetError::DestinationCollision` if the destination is
+    /// taken and the strategy is `Fail`.
+    pub fn claim(&mut self, destination: &Path, source: &str) -> Result<Claim, XetError> {
+        let key = self.key_for(destination);
+        let Some(existing) = self.claimed.get(&key) else {
+            self.claimed.insert(key, source.to_string());
+            return Ok(Claim::Free);
+        };
+        if existing == source {
+            return Ok(Claim::Free);
+        }
+
+        match self.strategy {
+            DestinationCollisionStrategy::Fail => Err(XetError::DestinationCollision {
+                message: format!(
+                    "{} and {} would both be written to {}",
+                    existing,
+                    source,
+                    destination.display()
+                ),
+            }),
+            DestinationCollisionStrategy::Rename => {
+                let mut suffix = 2;
+                loop {
+                    let renamed = with_suffix(destination, suffix);
+                    let key = self.key_for(&renamed);
+                    if let Entry::Vacant(entry) = self.claimed.entry(key) {
+                        entry.insert(source.to_string());
+                        return Ok(Claim::Renamed(renamed));
+                    }
+                    suffix += 1;
+                }
+            }
+        }
+    }
+
+    fn key_for(&mut self, destination: &Path) -> String {
+        let dir = match destination.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let probe_dir = dir
+            .ancestors()
+            .find(|ancestor| ancestor.is_dir())
+            .unwrap_or(dir)
+            .to_path_buf();
+        let case_insensitive = *self
+            .case_insensitive
+            .entry(probe_dir)
+            .or_insert_with_key(|dir| is_case_insensitive(dir));
+
+        let path = xet_unicode::comparison_key(&destination.to_string_lossy());
+        if case_insensitive {
+            path.to_lowercase()
+        } else {
+            path
+        }
+    }
+}
+
+/// Returns whether `dir` is on a volume that ignores case in file names.
+///
+/// This is checked by creating a short-lived file in `dir`. When that isn't
+/// possible, Apple platforms are assumed to use case-insensitive APFS.
+pub fn is_case_insensitive(dir: &Path) -> bool {
+    let name = format!(
+        ".XetCaseProbe-{}-{}",
+        std::process::id(),
+        PROBE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
+    let probe = dir.join(&name);
+    if fs::File::create(&probe).is_err() {
+        return cfg!(any(target_os = "macos", target_os = "ios"));
+    }
+    let insensitive = dir.join(name.to_lowercase()).exists();
+    let _ = fs::remove_file(&probe);
+    insensitive
+}
+
+/// Inserts `-{suffix}` before the extension, as in `model-2.bin`.
+fn with_suffix(path: &Path, suffix: u32) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = match path.extension() {
+        Some(extension) => format!("{}-{}.{}", stem, suffix, extension.to_string_lossy()),
+        None => format!("{}-{}", stem, suffix),
+    };
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detector(
+        strategy: DestinationCollisionStrategy,
+        case_insensitive: bool,
+    ) -> CollisionDetector {
+        let mut detector = CollisionDetector::new(strategy);
+        detector
+            .case_insensitive
+            .insert(std::env::temp_dir(), case_insensitive);
+        detector
+    }
+
+    #[test]
+    fn case_collisions_follow_the_filesystem() {
+        let dir = std::env::temp_dir();
+
+        let mut sensitive = detector(DestinationCollisionStrategy::Fail, false);
+        assert!(sensitive
+            .claim(&dir.join("Model.bin"), "a/Model.bin")
+            .is_ok());
+        assert!(sensitive
+            .claim(&dir.join("model.bin"), "a/model.bin")
+            .is_ok());
+
+        let mut insensitive = detector(DestinationCollisionStrategy::Fail, true);
+        assert!(insensitive
+            .claim(&dir.join("Model.bin"), "a/Model.bin")
+            .is_ok());
+        assert!(matches!(
+            insensitive.claim(&dir.join("model.bin"), "a/model.bin"),
+            Err(XetError::DestinationCollision { .. })
+        ));
+        // The same file requested twice isn't a collision.
+        assert!(insensitive
+            .claim(&dir.join("Model.bin"), "a/Model.bin")
+            .is_ok());
+    }
+
+    #[test]
+    fn rename_strategy_picks_the_next_free_suffix() {
+        let dir = std::env::temp_dir();
+        let mut detector = detector(DestinationCollisionStrategy::Rename, true);
+
+        detector.claim(&dir.join("model.bin"), "a").unwrap();
+        detector.claim(&dir.join("Model-2.bin"), "b").unwrap();
+        match detector.claim(&dir.join("MODEL.bin"), "c").unwrap() {
+            Claim::Renamed(path) => assert_eq!(path, dir.join("MODEL-3.bin")),
+            Claim::Free => panic!("expected a collision"),
+        }
+        match detector.claim(&dir.join("README"), "d").unwrap() {
+            Claim::Free => {}
+            Claim::Renamed(_) => panic!("expected no collision"),
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{xet_faults, xet_url, XetError};
+
+/// The header carrying a commit's idempotency key.
+const HEADER_IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+/// The number of leading bytes of a file the preupload endpoint inspects.
+pub const PREUPLOAD_SAMPLE_SIZE: usize = 512;
+/// How many times a commit is sent before an ambiguous failure is returned.
+const COMMIT_ATTEMPTS: u32 = 3;
+/// The delay before the first retry, doubled for each one after.
+const COMMIT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Why a commit wasn't created.
+#[derive(Debug)]
+pub enum CommitError {
+    /// The Hub refused the commit (403, 404 or 409), so sending it again
+    /// can't create it.
+    Refused(XetError),
+    /// The commit may still be created by sending it again later.
+    Failed(XetError),
+}
+
+impl From<XetError> for CommitError {
+    fn from(err: XetError) -> Self {
+        CommitError::Failed(err)
+    }
+}
+
+impl From<reqwest::Error> for CommitError {
+    fn from(err: reqwest::Error) -> Self {
+        let refused = err
+            .status()
+            .is_some_and(|status| matches!(status.as_u16(), 403 | 404 | 409));
+        if refused {
+            CommitError::Refused(err.into())
+        } else {
+            CommitError::Failed(err.into())
+        }
+    }
+}
+
+impl From<CommitError> for XetError {
+    fn from(err: CommitError) -> Self {
+        match err {
+            CommitError::Refused(err) | CommitError::Failed(err) => err,
+        }
+    }
+}
+
+/// A single change applied by a Hub commit.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CommitOperation {
+    /// Adds or replaces a file whose content was already uploaded to CAS.
+    AddXetFile {
+        path_in_repo: String,
+        sha256: String,
+        size: u64,
+    },
+    /// Adds or replaces a small file stored in git, such as `.gitattributes`.
+    AddFile {
+        path_in_repo: String,
+        content: Vec<u8>,
+    },
+    /// Removes a file from the repository.
+    Delete { path_in_repo: String },
+}
+
+impl CommitOperation {
+    pub fn path_in_repo(&self) -> &str {
+        match self {
+            CommitOperation::AddXetFile { path_in_repo, .. } => path_in_repo,
+            CommitOperation::AddFile { path_in_repo, .. } => path_in_repo,
+            CommitOperation::Delete { path_in_repo } => path_in_repo,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CommitResponse {
+    #[serde(rename = "commitOid")]
+    pub commit_oid: String,
+}
+
+/// The Hub's verdict on one file in a preupload request.
+#[derive(Clone, Debug, Deserialize)]
+pub struct PreuploadEntry {
+    pub path: String,
+    /// `"lfs"` for content uploaded to storage and committed as a pointer, or
+    /// `"regular"` for content committed inline.
+    #[serde(rename = "uploadMode")]
+    pub upload_mode: String,
+    /// Whether the repository's `.gitignore` excludes the file.
+    #[serde(rename = "shouldIgnore", default)]
+    pub should_ignore: bool,
+    /// The object ID of the file already at the path, if any.
+    #[serde(default)]
+    pub oid: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PreuploadResponse {
+    pub files: Vec<PreuploadEntry>,
+}
+
+/// Builds the JSON payload of the Hub preupload endpoint from each file's
+/// path, size, and leading bytes, of which only the first
+/// `PREUPLOAD_SAMPLE_SIZE` are sent.
+pub fn preupload_payload(files: &[(&str, u64, &[u8])]) -> serde_json::Value {
+    let files: Vec<_> = files
+        .iter()
+        .map(|(path, size, sample)| {
+            let sample = &sample[..sample.len().min(PREUPLOAD_SAMPLE_SIZE)];
+            serde_json::json!({ "path": path, "size": size, "sample": base64(sample) })
+        })
+        .collect();
+    serde_json::json!({ "files": files })
+}
+
+/// Returns a new key identifying one commit across every attempt to create it.
+///
+/// The key is random rather than derived from the commit's contents, so two
+/// deliberate commits of the same changes are never mistaken for a retry.
+pub fn new_idempotency_key() -> String {
+    let random = || RandomState::new().build_hasher().finish();
+    format!("{:016x}{:016x}", random(), random())
+}
+
+/// Builds the NDJSON payload expected by the Hub commit endpoint.
+pub fn commit_payload(summary: &str, operations: &[CommitOperation]) -> String {
+    let mut lines = vec![serde_json::json!({
+        "key": "header",
+        "value": { "summary": summary, "description": "" },
+    })];
+
+    for operation in operations {
+        lines.push(match operation {
+            CommitOperation::AddXetFile {
+                path_in_repo,
+                sha256,
+                size,
+            } => serde_json::json!({
+                "key": "lfsFile",
+                "value": { "path": path_in_repo, "algo": "sha256", "oid": sha256, "size": size },
+            }),
+            CommitOperation::AddFile {
+                path_in_repo,
+                content,
+            } => serde_json::json!({
+                "key": "file",
+                "value": { "path": path_in_repo, "content": base64(content), "encoding": "base64" },
+            }),
+            CommitOperation::Delete { path_in_repo } => serde_json::json!({
+                "key": "deletedFile",
+                "value": { "path": path_in_repo },
+            }),
+        });
+    }
+
+    lines
+        .iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Encodes `bytes` as standard, padded base64, as the commit endpoint expects.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for group in bytes.chunks(3) {
+        let value = group.iter().enumerate().fold(0u32, |value, (i, byte)| {
+            value | (*byte as u32) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= group.len() {
+                encoded.push(ALPHABET[(value >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Creates a commit, retrying failures that leave its outcome unknown.
+///
+/// Every attempt carries `idempotency_key`, so a retry after a timeout or a
+/// server error can't create the commit twice if the first attempt landed.
+/// Callers that retry later, such as commit recovery, must pass the same key.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_commit(
+    client: &reqwest::Client,
+    endpoint: &str,
+    repo_type_plural: &str,
+    repo_full_name: &str,
+    revision: &str,
+    summary: &str,
+    operations: &[CommitOperation],
+    idempotency_key: &str,
+    token: Option<&String>,
+    observe: &(dyn Fn(&reqwest::header::HeaderMap) + Sync),
+) -> Result<CommitResponse, CommitError> {
+    let token = token.ok_or_else(|| XetError::AuthError {
+        message: "Creating a commit requires an authentication token".to_string(),
+    })?;
+
+    let url = xet_url::revision_route(
+        endpoint,
+        repo_type_plural,
+        repo_full_name,
+        "commit",
+        revision,
+    );
+
+    let payload = commit_payload(summary, operations);
+    let mut attempt = 1;
+    loop {
+        let result = async {
+            let request = client
+                .post(&url)
+                .bearer_auth(token)
+                .header(reqwest::header::CONTENT_TYPE, "application/x-ndjson")
+                .header(HEADER_IDEMPOTENCY_KEY, idempotency_key)
+                .body(payload.clone());
+            let response = xet_faults::send(request).await?;
+            observe(response.headers());
+            response.error_for_status()
+        }
+        .await;
+
+        match result {
+            Err(e) if attempt < COMMIT_ATTEMPTS && is_retryable(&e) => {
+                tokio::time::sleep(COMMIT_RETRY_DELAY * 2u32.pow(attempt - 1)).await;
+                attempt += 1;
+            }
+            result => return Ok(result?.json::<CommitResponse>().await?),
+        }
+    }
+}
+
+/// Returns whether a failed commit attempt may be sent again.
+///
+/// Timeouts and server errors don't say whether the commit was created, which
+/// the idempotency key makes safe to retry; connection failures never reached
+/// the Hub at all. Anything else would fail the same way again.
+fn is_retryable(error: &reqwest::Error) -> bool {
+    error.is_timeout()
+        || error.is_connect()
+        || error
+            .status()
+            .is_some_and(|status| status.is_server_error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idempotency_keys_are_unique() {
+        let key = new_idempotency_key();
+        assert_eq!(key.len(), 32);
+        assert_ne!(key, new_idempotency_key());
+    }
+
+    #[test]
+    fn commit_payload_writes_header_then_operations() {
+        let payload = commit_payload(
+            "Add weights",
+            &[
+                CommitOperation::AddXetFile {
+                    path_in_repo: "model.safetensors".to_string(),
+                    sha256: "abc".to_string(),
+                    size: 42,
+                },
+                CommitOperation::AddFile {
+                    path_in_repo: ".gitattributes".to_string(),
+                    content: b"*.bin filter=lfs\n".to_vec(),
+                },
+                CommitOperation::Delete {
+                    path_in_repo: "old.bin".to_string(),
+                },
+            ],
+        );
+
+        let lines: Vec<serde_json::Value> = payload
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0]["key"], "header");
+        assert_eq!(lines[0]["value"]["summary"], "Add weights");
+        assert_eq!(lines[1]["key"], "lfsFile");
+        assert_eq!(lines[1]["value"]["oid"], "abc");
+        assert_eq!(lines[1]["value"]["size"], 42);
+        assert_eq!(lines[2]["key"], "file");
+        assert_eq!(lines[2]["value"]["content"], "Ki5iaW4gZmlsdGVyPWxmcwo=");
+        assert_eq!(lines[3]["key"], "deletedFile");
+        assert_eq!(lines[3]["value"]["path"], "old.bin");
+    }
+
+    #[test]
+    fn preupload_payload_samples_the_start_of_each_file() {
+        let large = vec![b'a'; 1024];
+        let payload = preupload_payload(&[("config.json", 2, b"{}"), ("model.bin", 1024, &large)]);
+        assert_eq!(payload["files"][0]["path"], "config.json");
+        assert_eq!(payload["files"][0]["size"], 2);
+        assert_eq!(payload["files"][0]["sample"], "e30=");
+        assert_eq!(
+            payload["files"][1]["sample"].as_str().unwrap(),
+            base64(&large[..PREUPLOAD_SAMPLE_SIZE])
+        );
+
+        let response: PreuploadResponse = serde_json::from_str(
+            r#"{"files":[{"path":"model.bin","uploadMode":"lfs","shouldIgnore":false,"oid":"abc"},{"path":"config.json","uploadMode":"regular"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(response.files[0].upload_mode, "lfs");
+        assert_eq!(response.files[0].oid.as_deref(), Some("abc"));
+        assert!(!response.files[1].should_ignore);
+        assert_eq!(response.files[1].oid, None);
+    }
+
+    #[test]
+    fn base64_pads_partial_groups() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+}
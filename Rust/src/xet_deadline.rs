@@ -0,0 +1,95 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::time::Instant;
+
+use crate::XetError;
+
+thread_local! {
+    /// The time by which the call running on this thread must finish, if limited.
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Restores the deadline a scope replaced, even if the call in it panics.
+struct Restore(Option<Instant>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        DEADLINE.set(self.0);
+    }
+}
+
+/// Runs `call` with its network operations required to finish by `deadline`.
+///
+/// A deadline already in effect on this thread is never extended, so work
+/// done on behalf of an outer call stays within that call's limit.
+pub fn scoped<T>(deadline: Option<Instant>, call: impl FnOnce() -> T) -> T {
+    let Some(deadline) = deadline else {
+        return call();
+    };
+    let previous = DEADLINE.get();
+    let _restore = Restore(previous);
+    DEADLINE.set(Some(
+        previous.map_or(deadline, |previous| previous.min(deadline)),
+    ));
+    call()
+}
+
+/// Returns the deadline in effect on this thread, if any.
+pub fn current() -> Option<Instant> {
+    DEADLINE.get()
+}
+
+/// Runs `future`, failing with `XetError::Timeout` if it hasn't finished by `deadline`.
+///
+/// The future is dropped when the deadline passes, which aborts any request
+/// it's waiting on, however stalled the connection.
+pub async fn enforce<T, F>(deadline: Option<Instant>, future: F) -> Result<T, XetError>
+where
+    F: Future<Output = Result<T, XetError>>,
+{
+    let Some(deadline) = deadline else {
+        return future.await;
+    };
+    tokio::time::timeout_at(deadline.into(), future)
+        .await
+        .unwrap_or_else(|_| Err(timeout_error()))
+}
+
+pub fn timeout_error() -> XetError {
+    XetError::Timeout {
+        message: "The operation did not finish before its deadline".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn scopes_never_extend_an_outer_deadline() {
+        let soon = Instant::now() + Duration::from_secs(1);
+        let later = soon + Duration::from_secs(60);
+        assert_eq!(current(), None);
+        scoped(Some(soon), || {
+            scoped(Some(later), || assert_eq!(current(), Some(soon)));
+            scoped(None, || assert_eq!(current(), Some(soon)));
+        });
+        assert_eq!(current(), None);
+    }
+
+    #[test]
+    fn stalled_futures_time_out() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let deadline = Instant::now() + Duration::from_millis(10);
+        let result: Result<(), XetError> =
+            runtime.block_on(enforce(Some(deadline), std::future::pending()));
+        assert!(matches!(result, Err(XetError::Timeout { .. })));
+        assert!(runtime
+            .block_on(enforce(None, async { Ok::<_, XetError>(1) }))
+            .is_ok());
+    }
+}
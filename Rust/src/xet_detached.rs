@@ -0,0 +1,122 @@
+use std::cell::RefCell;
+use std::future::{poll_fn, Future};
+use std::pin::pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::Poll;
+
+use tokio::sync::Notify;
+
+use crate::{CancellationReason, XetError};
+
+thread_local! {
+    /// The caller awaiting the detached call running on this thread, if any.
+    static CALLER: RefCell<Option<Arc<Caller>>> = const { RefCell::new(None) };
+}
+
+/// Whether the future awaiting a detached call is still waiting for it.
+#[derive(Default)]
+pub struct Caller {
+    gone: AtomicBool,
+    notify: Notify,
+}
+
+impl Caller {
+    /// Records that the caller stopped waiting, abandoning the call's work.
+    pub fn leave(&self) {
+        self.gone.store(true, Ordering::Release);
+        self.notify.notify_waiters();
+    }
+
+    /// Resolves once the caller has stopped waiting.
+    async fn left(&self) {
+        loop {
+            // Created before checking, so leaving in between still wakes it.
+            let notified = self.notify.notified();
+            if self.gone.load(Ordering::Acquire) {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// Leaves the caller when dropped, as the future awaiting the call is when
+/// the task running it is cancelled.
+pub struct LeaveOnDrop(pub Arc<Caller>);
+
+impl Drop for LeaveOnDrop {
+    fn drop(&mut self) {
+        self.0.leave();
+    }
+}
+
+/// Restores the caller a scope replaced, even if the call in it panics.
+struct Restore(Option<Arc<Caller>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CALLER.set(self.0.take());
+    }
+}
+
+/// Runs `call` on behalf of `caller`, so its network operations are abandoned
+/// once the caller stops waiting.
+pub fn scoped<T>(caller: Arc<Caller>, call: impl FnOnce() -> T) -> T {
+    let _restore = Restore(CALLER.replace(Some(caller)));
+    call()
+}
+
+/// Returns the caller of the detached call running on this thread, if any.
+pub fn current() -> Option<Arc<Caller>> {
+    CALLER.with_borrow(Option::clone)
+}
+
+/// Runs `future`, failing with `XetError::Cancelled` once `caller` stops waiting.
+///
+/// The future is dropped when the caller leaves, which aborts any request
+/// it's waiting on.
+pub async fn enforce<T, F>(caller: Option<Arc<Caller>>, future: F) -> Result<T, XetError>
+where
+    F: Future<Output = Result<T, XetError>>,
+{
+    let Some(caller) = caller else {
+        return future.await;
+    };
+    let mut future = pin!(future);
+    let mut left = pin!(caller.left());
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(output);
+        }
+        left.as_mut().poll(cx).map(|()| {
+            Err(XetError::Cancelled {
+                message: "The operation was cancelled because its caller stopped waiting"
+                    .to_string(),
+                reason: CancellationReason::UserRequested,
+            })
+        })
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaving_abandons_the_work() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let caller = Arc::new(Caller::default());
+        let finished = runtime.block_on(enforce(Some(caller.clone()), async { Ok(1) }));
+        assert_eq!(finished.unwrap(), 1);
+
+        caller.leave();
+        let pending = std::future::pending::<Result<(), XetError>>();
+        let abandoned = runtime.block_on(enforce(Some(caller), pending));
+        assert!(matches!(abandoned, Err(XetError::Cancelled { .. })));
+    }
+}
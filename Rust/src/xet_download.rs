@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::{CasJwtInfo, XetError};
+use crate::{CasJwtInfo, RetryPolicy, XetError};
 
 pub struct XetDownloadPlan {
     pub file_info: data::XetFileInfo,
@@ -16,13 +16,11 @@ impl XetDownloadPlan {
     }
 }
 
-#[allow(dead_code)]
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct XetDownloadConfig {
     pub chunk_size_bytes: usize,
     pub max_parallel_files: usize,
-    pub parallel_failures: usize,
-    pub max_retries: usize,
+    pub retry_policy: RetryPolicy,
 }
 
 impl Default for XetDownloadConfig {
@@ -42,8 +40,7 @@ impl Default for XetDownloadConfig {
         Self {
             chunk_size_bytes,
             max_parallel_files,
-            parallel_failures: 4,
-            max_retries: 3,
+            retry_policy: RetryPolicy::default(),
         }
     }
 }
@@ -79,6 +76,12 @@ pub async fn download_with_plan(
 }
 
 fn apply_download_config(config: XetDownloadConfig) {
+    // Concurrent downloads would otherwise race on the process environment.
+    static APPLY: std::sync::Once = std::sync::Once::new();
+    APPLY.call_once(|| set_download_env(config));
+}
+
+fn set_download_env(config: XetDownloadConfig) {
     // Set high-performance defaults that work well for typical use cases.
     // Users can override with environment variables if needed.
     
@@ -118,4 +121,18 @@ fn apply_download_config(config: XetDownloadConfig) {
             config.chunk_size_bytes.to_string(),
         );
     }
+
+    // Retries within reconstruction follow the client's retry policy
+    if std::env::var("HF_XET_CLIENT_RETRY_MAX_ATTEMPTS").is_err() {
+        std::env::set_var(
+            "HF_XET_CLIENT_RETRY_MAX_ATTEMPTS",
+            config.retry_policy.max_attempts.to_string(),
+        );
+    }
+    if std::env::var("HF_XET_CLIENT_RETRY_BASE_DELAY_MS").is_err() {
+        std::env::set_var(
+            "HF_XET_CLIENT_RETRY_BASE_DELAY_MS",
+            config.retry_policy.base_delay_ms.to_string(),
+        );
+    }
 }
@@ -0,0 +1,312 @@
+use std::future::Future;
+#[cfg(feature = "fault-injection")]
+use std::sync::Arc;
+
+use reqwest::{RequestBuilder, Response};
+
+use crate::{xet_recording, xet_usage, XetClientConfig};
+
+/// The network faults a client simulates, for testing retries and resumption.
+///
+/// Faults are only simulated with the `fault-injection` feature, and only for
+/// requests the client makes itself. Transfers the Xet data crate makes over
+/// its own connections aren't affected.
+#[derive(Clone, Default)]
+pub struct Faults {
+    #[cfg(feature = "fault-injection")]
+    injector: Option<Arc<injector::FaultInjector>>,
+}
+
+impl Faults {
+    pub fn new(config: &XetClientConfig) -> Self {
+        #[cfg(feature = "fault-injection")]
+        return Self {
+            injector: config
+                .simulated_faults
+                .clone()
+                .filter(|faults| !faults.is_empty())
+                .map(|faults| Arc::new(injector::FaultInjector::new(faults))),
+        };
+        #[cfg(not(feature = "fault-injection"))]
+        {
+            let _ = config;
+            Self {}
+        }
+    }
+
+    /// Runs `future` with the requests it sends subject to these faults.
+    #[cfg(feature = "fault-injection")]
+    pub fn scope<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        injector::scope(self.injector.clone(), future)
+    }
+
+    #[cfg(not(feature = "fault-injection"))]
+    pub fn scope<F: Future>(&self, future: F) -> F {
+        future
+    }
+}
+
+/// Sends `request`, applying any faults simulated for the calling task,
+/// recording it if the task's client is recording, and counting its bytes in
+/// the usage report.
+///
+/// Without the `fault-injection` feature or a recording, this just sends the request.
+pub async fn send(request: RequestBuilder) -> reqwest::Result<Response> {
+    let (client, request) = request.build_split();
+    let request = request?;
+    let method = request.method().clone();
+    let sent = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map_or(0, |body| body.len() as u64);
+    let recording = xet_recording::begin(&request);
+    let result = send_unrecorded(RequestBuilder::from_parts(client, request)).await;
+    if let Some(recording) = recording {
+        xet_recording::finish(recording, &result);
+    }
+    if let Ok(response) = &result {
+        xet_usage::count_response(&method, sent, response);
+    }
+    result
+}
+
+async fn send_unrecorded(request: RequestBuilder) -> reqwest::Result<Response> {
+    #[cfg(feature = "fault-injection")]
+    if let Ok(injector) = injector::CURRENT.try_with(Arc::clone) {
+        return injector.send(request).await;
+    }
+    request.send().await
+}
+
+/// Wraps `future`, which will run on a task of its own, in the calling task's
+/// simulated faults, recorder, and usage ledger.
+///
+/// Spawned tasks don't otherwise see the faults of the task that spawned them.
+#[cfg(feature = "fault-injection")]
+pub fn inherit<F: Future>(future: F) -> impl Future<Output = F::Output> {
+    let future = injector::scope(injector::CURRENT.try_with(Arc::clone).ok(), future);
+    xet_usage::inherit(xet_recording::inherit(future))
+}
+
+#[cfg(not(feature = "fault-injection"))]
+pub fn inherit<F: Future>(future: F) -> impl Future<Output = F::Output> {
+    xet_usage::inherit(xet_recording::inherit(future))
+}
+
+#[cfg(feature = "fault-injection")]
+mod injector {
+    use std::error::Error;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use http_body::{Body as HttpBody, Frame, SizeHint};
+    use reqwest::{Body, RequestBuilder, Response, ResponseBuilderExt};
+
+    use crate::{FaultKind, SimulatedFault};
+
+    tokio::task_local! {
+        /// The faults simulated for the client call running on this task.
+        pub static CURRENT: Arc<FaultInjector>;
+    }
+
+    /// Runs `future` with the requests it sends subject to `injector`'s faults, if any.
+    pub async fn scope<F: Future>(injector: Option<Arc<FaultInjector>>, future: F) -> F::Output {
+        match injector {
+            Some(injector) => CURRENT.scope(injector, future).await,
+            None => future.await,
+        }
+    }
+
+    /// Applies a client's simulated faults to the requests it sends.
+    pub struct FaultInjector {
+        faults: Vec<SimulatedFault>,
+        /// The number of requests each fault's pattern has matched so far.
+        matched: Vec<AtomicU32>,
+    }
+
+    impl FaultInjector {
+        pub fn new(faults: Vec<SimulatedFault>) -> Self {
+            let matched = faults.iter().map(|_| AtomicU32::new(0)).collect();
+            Self { faults, matched }
+        }
+
+        pub async fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+            let (client, request) = request.build_split();
+            let request = request?;
+            let url = request.url().clone();
+
+            let mut body_fault = None;
+            for kind in self.due(url.as_str()) {
+                match kind {
+                    FaultKind::Latency { millis } => {
+                        tokio::time::sleep(Duration::from_millis(millis)).await;
+                    }
+                    FaultKind::Status {
+                        status,
+                        retry_after_secs,
+                    } => {
+                        let mut response = http::Response::builder().status(status).url(url);
+                        if let Some(secs) = retry_after_secs {
+                            response = response.header(reqwest::header::RETRY_AFTER, secs);
+                        }
+                        let response = response.body(Body::from(Vec::new()));
+                        return Ok(Response::from(response.expect("status faults are valid")));
+                    }
+                    FaultKind::DropConnection { after_bytes } => {
+                        body_fault = Some(BodyEnd::Error(after_bytes));
+                    }
+                    FaultKind::TruncateBody { after_bytes } => {
+                        body_fault = Some(BodyEnd::Truncate(after_bytes));
+                    }
+                }
+            }
+
+            let response = client.execute(request).await?;
+            let Some(end) = body_fault else {
+                return Ok(response);
+            };
+            let url = response.url().clone();
+            let response = http::Response::<Body>::from(response);
+            let (parts, body) = response.into_parts();
+            let body = Body::wrap(FaultyBody { inner: body, end });
+            let mut builder = http::Response::builder().status(parts.status).url(url);
+            if let Some(headers) = builder.headers_mut() {
+                *headers = parts.headers;
+            }
+            Ok(Response::from(
+                builder.body(body).expect("responses rebuild unchanged"),
+            ))
+        }
+
+        /// Returns the faults due for a request to `url`, advancing each matching fault's schedule.
+        pub(super) fn due(&self, url: &str) -> Vec<FaultKind> {
+            self.faults
+                .iter()
+                .zip(&self.matched)
+                .filter(|(fault, _)| {
+                    fault
+                        .url_pattern
+                        .as_deref()
+                        .is_none_or(|pattern| url.contains(pattern))
+                })
+                .filter(|(fault, matched)| {
+                    let seen = matched.fetch_add(1, Ordering::Relaxed);
+                    let after_skip = seen.checked_sub(fault.skip);
+                    after_skip.is_some_and(|n| fault.count.is_none_or(|count| n < count))
+                })
+                .map(|(fault, _)| fault.kind.clone())
+                .collect()
+        }
+    }
+
+    /// How a faulty response body ends once its budget of bytes is spent.
+    enum BodyEnd {
+        /// The connection drops, failing the read.
+        Error(u64),
+        /// The body ends early, as if the server stopped sending.
+        Truncate(u64),
+    }
+
+    impl BodyEnd {
+        fn remaining(&mut self) -> &mut u64 {
+            match self {
+                BodyEnd::Error(remaining) | BodyEnd::Truncate(remaining) => remaining,
+            }
+        }
+    }
+
+    /// A response body cut off after a number of bytes.
+    struct FaultyBody {
+        inner: Body,
+        end: BodyEnd,
+    }
+
+    impl HttpBody for FaultyBody {
+        type Data = Bytes;
+        type Error = Box<dyn Error + Send + Sync>;
+
+        fn poll_frame(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<Result<Frame<Bytes>, Self::Error>>> {
+            let this = self.get_mut();
+            if *this.end.remaining() == 0 {
+                return Poll::Ready(match this.end {
+                    BodyEnd::Error(_) => Some(Err("simulated connection drop".into())),
+                    BodyEnd::Truncate(_) => None,
+                });
+            }
+            let frame = match Pin::new(&mut this.inner).poll_frame(cx) {
+                Poll::Ready(Some(Ok(frame))) => frame,
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e.into()))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+            let Ok(mut data) = frame.into_data() else {
+                return Poll::Ready(None);
+            };
+            let remaining = this.end.remaining();
+            data.truncate((*remaining).min(data.len() as u64) as usize);
+            *remaining -= data.len() as u64;
+            Poll::Ready(Some(Ok(Frame::data(data))))
+        }
+
+        /// Reports the length the server declared, which a truncated body falls short of.
+        fn size_hint(&self) -> SizeHint {
+            self.inner.size_hint()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fault-injection"))]
+mod tests {
+    use super::*;
+    use crate::{FaultKind, SimulatedFault};
+    use injector::FaultInjector;
+
+    #[test]
+    fn faults_follow_their_schedule() {
+        let injector = FaultInjector::new(vec![SimulatedFault {
+            url_pattern: Some("/resolve/".to_string()),
+            kind: FaultKind::Status {
+                status: 503,
+                retry_after_secs: Some(1),
+            },
+            skip: 1,
+            count: Some(2),
+        }]);
+        let due = |url: &str| injector.due(url).len();
+        assert_eq!(due("https://hf.co/api/models/a/b"), 0);
+        assert_eq!(due("https://hf.co/a/b/resolve/main/x"), 0);
+        assert_eq!(due("https://hf.co/a/b/resolve/main/x"), 1);
+        assert_eq!(due("https://hf.co/a/b/resolve/main/x"), 1);
+        assert_eq!(due("https://hf.co/a/b/resolve/main/x"), 0);
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let faults = Faults::new(&XetClientConfig {
+            simulated_faults: Some(vec![SimulatedFault {
+                url_pattern: None,
+                kind: FaultKind::Status {
+                    status: 429,
+                    retry_after_secs: None,
+                },
+                skip: 0,
+                count: None,
+            }]),
+            ..XetClientConfig::default()
+        });
+        let status = runtime.block_on(faults.scope(async {
+            let request = reqwest::Client::new().get("http://127.0.0.1:9/");
+            send(request).await.map(|response| response.status())
+        }));
+        assert_eq!(status.unwrap(), reqwest::StatusCode::TOO_MANY_REQUESTS);
+    }
+}
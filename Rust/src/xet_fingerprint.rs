@@ -0,0 +1,177 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::xet_cache::CacheLayout;
+use crate::xet_metadata::FileResolveMetadata;
+use crate::xet_storage::CacheStorage;
+use crate::CacheArea;
+
+const FINGERPRINTS_DIR: &str = "fingerprints";
+
+/// The content a download wrote to a destination, and the file it left there.
+#[derive(PartialEq, Eq, Serialize, Deserialize)]
+struct Fingerprint {
+    etag: String,
+    xet_hash: Option<String>,
+    size: u64,
+    modified_secs: u64,
+    modified_nanos: u32,
+}
+
+/// Records of what each download destination holds, so unchanged files aren't fetched again.
+///
+/// A record only vouches for a destination while the file there still has
+/// the size and modification time the download left it with, so a file
+/// edited or replaced since is never mistaken for the content it once held.
+/// Records are small JSON files named by a hash of the destination path, and
+/// live in the metadata area of the cache.
+pub struct FingerprintCache {
+    dir: PathBuf,
+    storage: Arc<dyn CacheStorage>,
+}
+
+impl FingerprintCache {
+    pub fn new(dir: PathBuf, storage: Arc<dyn CacheStorage>) -> Self {
+        Self { dir, storage }
+    }
+
+    /// Opens the fingerprints stored in the metadata area of `layout`.
+    pub fn open(layout: &CacheLayout) -> Self {
+        Self::new(
+            layout.root(CacheArea::Metadata).join(FINGERPRINTS_DIR),
+            layout.storage(),
+        )
+    }
+
+    /// Records that `destination` now holds the content described by `metadata`.
+    pub fn store(&self, destination: &Path, metadata: &FileResolveMetadata) -> io::Result<()> {
+        let fingerprint = fingerprint(destination, metadata)?;
+        let contents = serde_json::to_vec(&fingerprint).map_err(io::Error::other)?;
+        self.storage.write(&self.entry_path(destination), &contents)
+    }
+
+    /// Returns whether `destination` is recorded as holding the content
+    /// described by `metadata` and hasn't changed since.
+    ///
+    /// Content matches by etag, or by Xet hash for files whose etag changed
+    /// format without their content changing.
+    pub fn matches(&self, destination: &Path, metadata: &FileResolveMetadata) -> bool {
+        let Some(recorded) = self.recorded(destination) else {
+            return false;
+        };
+        let Ok(current) = fingerprint(destination, metadata) else {
+            return false;
+        };
+        let same_content = recorded.etag == current.etag
+            || (recorded.xet_hash.is_some() && recorded.xet_hash == current.xet_hash);
+        same_content
+            && current.size == metadata.size
+            && recorded.size == current.size
+            && recorded.modified_secs == current.modified_secs
+            && recorded.modified_nanos == current.modified_nanos
+    }
+
+    /// Returns whether a download recorded what `destination` holds.
+    pub fn has_record(&self, destination: &Path) -> bool {
+        self.recorded(destination).is_some()
+    }
+
+    /// Returns the etag of the content recorded at `destination`, if the file
+    /// there hasn't changed since it was recorded.
+    pub fn unchanged_etag(&self, destination: &Path) -> Option<String> {
+        let recorded = self.recorded(destination)?;
+        let file = fs::metadata(destination).ok()?;
+        let modified = file.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        (recorded.size == file.len()
+            && recorded.modified_secs == modified.as_secs()
+            && recorded.modified_nanos == modified.subsec_nanos())
+        .then_some(recorded.etag)
+    }
+
+    fn recorded(&self, destination: &Path) -> Option<Fingerprint> {
+        let contents = self.storage.read(&self.entry_path(destination)).ok()?;
+        serde_json::from_slice(&contents).ok()
+    }
+
+    fn entry_path(&self, destination: &Path) -> PathBuf {
+        let digest = Sha256::digest(destination.to_string_lossy().as_bytes());
+        let name: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+        self.dir.join(format!("{}.json", name))
+    }
+}
+
+/// Describes the file at `destination` as holding the content in `metadata`.
+///
+/// The size is the file's own, so a truncated file never matches a record.
+fn fingerprint(destination: &Path, metadata: &FileResolveMetadata) -> io::Result<Fingerprint> {
+    let file = fs::metadata(destination)?;
+    let modified = file
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map_err(io::Error::other)?;
+    Ok(Fingerprint {
+        etag: metadata.etag.clone(),
+        xet_hash: metadata
+            .xet_file_data
+            .as_ref()
+            .map(|data| data.file_hash.clone()),
+        size: file.len(),
+        modified_secs: modified.as_secs(),
+        modified_nanos: modified.subsec_nanos(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xet_storage::FileSystemStorage;
+
+    #[test]
+    fn records_lapse_when_the_file_changes() {
+        let dir =
+            std::env::temp_dir().join(format!("swift-xet-fingerprints-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let cache = FingerprintCache::new(dir.join("records"), Arc::new(FileSystemStorage));
+        let destination = dir.join("config.json");
+        fs::write(&destination, "{}").unwrap();
+
+        let metadata = FileResolveMetadata {
+            download_url: "https://huggingface.co/owner/repo/resolve/main/config.json".to_string(),
+            etag: "\"abc\"".to_string(),
+            commit_hash: "0123456789".to_string(),
+            size: 2,
+            content_type: None,
+            xet_file_data: None,
+            redirect_target: None,
+            served_stale: false,
+        };
+        assert!(!cache.matches(&destination, &metadata));
+        assert!(!cache.has_record(&destination));
+        cache.store(&destination, &metadata).unwrap();
+        assert!(cache.matches(&destination, &metadata));
+        assert_eq!(
+            cache.unchanged_etag(&destination).as_deref(),
+            Some("\"abc\"")
+        );
+
+        let updated = FileResolveMetadata {
+            etag: "\"def\"".to_string(),
+            ..metadata.clone()
+        };
+        assert!(!cache.matches(&destination, &updated));
+
+        fs::write(&destination, "{\"a\":1}").unwrap();
+        assert!(!cache.matches(&destination, &metadata));
+        assert!(cache.has_record(&destination));
+        assert_eq!(cache.unchanged_etag(&destination), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+/// Decimal byte units, as file sizes are shown on Apple platforms.
+const UNITS: [&str; 7] = ["B", "KB", "MB", "GB", "TB", "PB", "EB"];
+
+/// Formats a byte count with a decimal unit, such as `"1.5 GB"`.
+///
+/// Counts under 1000 are whole bytes. Larger ones keep one decimal place
+/// below 100 and none from there, so the text stays at most five characters
+/// before the unit. The decimal separator is always `.`, whatever the locale.
+pub fn bytes(bytes: u64) -> String {
+    if bytes < 1000 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    // Rounding can carry into the next unit, as 999.96 KB rounds to 1000 KB.
+    if value.round() >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    let decimals = if value < 99.95 { 1 } else { 0 };
+    format!("{:.*} {}", decimals, value, UNITS[unit])
+}
+
+/// Formats a throughput, such as `"12.3 MB/s"`.
+///
+/// A negative or non-finite rate is shown as no throughput.
+pub fn rate(bytes_per_second: f64) -> String {
+    let bytes_per_second = if bytes_per_second.is_finite() {
+        bytes_per_second.max(0.0)
+    } else {
+        0.0
+    };
+    format!("{}/s", bytes(bytes_per_second.round() as u64))
+}
+
+/// Formats the time left to transfer `remaining_bytes` at `bytes_per_second`,
+/// such as `"3m 07s"`, or `None` if the transfer isn't making progress.
+///
+/// The time is rounded up to whole seconds and shown in its two largest
+/// units, so it never reads as finished before it is.
+pub fn eta(remaining_bytes: u64, bytes_per_second: f64) -> Option<String> {
+    if remaining_bytes == 0 {
+        return Some("0s".to_string());
+    }
+    if !(bytes_per_second.is_finite() && bytes_per_second > 0.0) {
+        return None;
+    }
+    let seconds = (remaining_bytes as f64 / bytes_per_second).ceil();
+    if seconds >= u64::MAX as f64 {
+        return None;
+    }
+    Some(duration(seconds as u64))
+}
+
+fn duration(seconds: u64) -> String {
+    let (minutes, seconds) = (seconds / 60, seconds % 60);
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+    let (days, hours) = (hours / 24, hours % 24);
+    if days > 0 {
+        format!("{}d {:02}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {:02}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_counts_use_decimal_units() {
+        assert_eq!(bytes(0), "0 B");
+        assert_eq!(bytes(999), "999 B");
+        assert_eq!(bytes(1000), "1.0 KB");
+        assert_eq!(bytes(1_500_000), "1.5 MB");
+        assert_eq!(bytes(99_960), "100 KB");
+        assert_eq!(bytes(999_960), "1.0 MB");
+        assert_eq!(bytes(4_200_000_000), "4.2 GB");
+        assert_eq!(bytes(u64::MAX), "18.4 EB");
+    }
+
+    #[test]
+    fn rates_are_bytes_per_second() {
+        assert_eq!(rate(12_345_678.0), "12.3 MB/s");
+        assert_eq!(rate(-1.0), "0 B/s");
+        assert_eq!(rate(f64::NAN), "0 B/s");
+    }
+
+    #[test]
+    fn etas_round_up_to_their_two_largest_units() {
+        assert_eq!(eta(0, 0.0).as_deref(), Some("0s"));
+        assert_eq!(eta(100, 0.0), None);
+        assert_eq!(eta(1, 1000.0).as_deref(), Some("1s"));
+        assert_eq!(eta(187, 1.0).as_deref(), Some("3m 07s"));
+        assert_eq!(eta(7_500, 1.0).as_deref(), Some("2h 05m"));
+        assert_eq!(eta(100_800, 1.0).as_deref(), Some("1d 04h"));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::xet_glob;
+
+/// The path of the attributes file at the root of a repository.
+pub const GITATTRIBUTES_PATH: &str = ".gitattributes";
+
+/// The attributes that store a file with LFS, as `git lfs track` writes them.
+const LFS_ATTRIBUTES: &str = "filter=lfs diff=lfs merge=lfs -text";
+
+/// Returns whether the rules in `gitattributes` store `path` with LFS.
+///
+/// As in git, later lines override earlier ones, and a pattern without a `/`
+/// matches the file name at any depth.
+pub fn tracks_with_lfs(gitattributes: &str, path: &str) -> bool {
+    let mut tracked = false;
+    for line in gitattributes.lines() {
+        let mut fields = line.split_whitespace();
+        let Some(pattern) = fields.next().filter(|pattern| !pattern.starts_with('#')) else {
+            continue;
+        };
+        if !pattern_matches(pattern, path) {
+            continue;
+        }
+        for attribute in fields {
+            match attribute {
+                "filter=lfs" => tracked = true,
+                "-filter" | "!filter" => tracked = false,
+                _ if attribute.starts_with("filter=") => tracked = false,
+                _ => {}
+            }
+        }
+    }
+    tracked
+}
+
+/// Returns `gitattributes` with rules storing each of `paths` with LFS, or
+/// `None` if every path already is.
+pub fn track_with_lfs(gitattributes: &str, paths: &[&str]) -> Option<String> {
+    let untracked: Vec<&str> = paths
+        .iter()
+        .copied()
+        .filter(|path| !tracks_with_lfs(gitattributes, path))
+        .collect();
+    if untracked.is_empty() {
+        return None;
+    }
+
+    let mut updated = gitattributes.to_string();
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    for path in untracked {
+        updated.push_str(&format!("{} {}\n", literal_pattern(path), LFS_ATTRIBUTES));
+    }
+    Some(updated)
+}
+
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    match pattern.strip_prefix('/') {
+        Some(anchored) => xet_glob::matches(anchored, path),
+        None if pattern.contains('/') => xet_glob::matches(pattern, path),
+        None => xet_glob::matches(pattern, path.rsplit('/').next().unwrap_or(path)),
+    }
+}
+
+/// Returns a pattern anchored at the root that matches `path`.
+///
+/// Characters that would end the pattern or be read as wildcards are matched
+/// with `?` instead, which accepts them without escaping.
+fn literal_pattern(path: &str) -> String {
+    let path: String = path
+        .chars()
+        .map(|c| match c {
+            '*' | '?' | '[' | ']' | '\\' | '"' | '#' | '!' => '?',
+            c if c.is_whitespace() => '?',
+            c => c,
+        })
+        .collect();
+    format!("/{}", path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HUB_DEFAULTS: &str = "*.bin filter=lfs diff=lfs merge=lfs -text\n\
+                                *.safetensors filter=lfs diff=lfs merge=lfs -text\n\
+                                # Adapters\n\
+                                adapters/*.pt filter=lfs diff=lfs merge=lfs -text\n";
+
+    #[test]
+    fn rules_follow_git_precedence() {
+        assert!(tracks_with_lfs(HUB_DEFAULTS, "model.bin"));
+        assert!(tracks_with_lfs(HUB_DEFAULTS, "weights/model.safetensors"));
+        assert!(tracks_with_lfs(HUB_DEFAULTS, "adapters/lora.pt"));
+        assert!(!tracks_with_lfs(HUB_DEFAULTS, "lora.pt"));
+        assert!(!tracks_with_lfs(HUB_DEFAULTS, "config.json"));
+
+        let overridden = format!("{}small.bin -filter\n", HUB_DEFAULTS);
+        assert!(!tracks_with_lfs(&overridden, "small.bin"));
+        assert!(tracks_with_lfs(&overridden, "large.bin"));
+    }
+
+    #[test]
+    fn untracked_paths_are_appended() {
+        assert_eq!(track_with_lfs(HUB_DEFAULTS, &["model.bin"]), None);
+
+        let updated =
+            track_with_lfs("*.bin filter=lfs", &["model.bin", "tokenizer patch.json"]).unwrap();
+        assert_eq!(
+            updated,
+            "*.bin filter=lfs\n/tokenizer?patch.json filter=lfs diff=lfs merge=lfs -text\n"
+        );
+        assert!(tracks_with_lfs(&updated, "tokenizer patch.json"));
+        assert!(!tracks_with_lfs(&updated, "nested/tokenizer patch.json"));
+
+        let created = track_with_lfs("", &["adapter.gguf"]).unwrap();
+        assert_eq!(
+            created,
+            "/adapter.gguf filter=lfs diff=lfs merge=lfs -text\n"
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::XetError;
+
+/// Checks that `pattern` is a well-formed glob pattern.
+///
+/// Patterns follow `fnmatch` rules, like the pattern arguments of the
+/// `huggingface_hub` Python library: `*` matches any run of characters
+/// including `/`, `?` matches one character, and `[...]` matches one character
+/// from a set, negated with `[!...]`. A pattern ending in `/` matches
+/// everything below that directory.
+pub fn validate(pattern: &str) -> Result<(), XetError> {
+    if pattern.is_empty() {
+        return Err(XetError::InvalidInput {
+            message: "Pattern cannot be empty".to_string(),
+        });
+    }
+
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '[' {
+            match class_end(&chars, i) {
+                Some(end) => i = end,
+                None => {
+                    return Err(XetError::InvalidInput {
+                        message: format!("Unclosed character class in pattern: {}", pattern),
+                    })
+                }
+            }
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Returns whether `path` matches `pattern`.
+pub fn matches(pattern: &str, path: &str) -> bool {
+    let mut pattern: Vec<char> = pattern.chars().collect();
+    if pattern.last() == Some(&'/') {
+        pattern.push('*');
+    }
+    let path: Vec<char> = path.chars().collect();
+    matches_from(&pattern, &path)
+}
+
+/// Returns whether `path` matches any of `patterns`.
+pub fn matches_any(patterns: &[String], path: &str) -> bool {
+    patterns.iter().any(|pattern| matches(pattern, path))
+}
+
+/// Selects files by `allow_patterns` and `ignore_patterns`, as `huggingface_hub` does.
+///
+/// A path is included if it matches any allow pattern, or there are none, and
+/// matches no ignore pattern.
+pub struct PathFilter {
+    allow_patterns: Vec<String>,
+    ignore_patterns: Vec<String>,
+}
+
+impl PathFilter {
+    pub fn new(
+        allow_patterns: Vec<String>,
+        ignore_patterns: Vec<String>,
+    ) -> Result<Self, XetError> {
+        for pattern in allow_patterns.iter().chain(&ignore_patterns) {
+            validate(pattern)?;
+        }
+        Ok(Self {
+            allow_patterns,
+            ignore_patterns,
+        })
+    }
+
+    /// Returns whether `path` is selected.
+    pub fn includes(&self, path: &str) -> bool {
+        (self.allow_patterns.is_empty() || matches_any(&self.allow_patterns, path))
+            && !matches_any(&self.ignore_patterns, path)
+    }
+
+    /// Returns the allow patterns if every one names an exact path, so the
+    /// selection can be resolved without listing the repository.
+    pub fn literal_paths(&self) -> Option<&[String]> {
+        let literal =
+            |pattern: &String| !pattern.ends_with('/') && !pattern.contains(['*', '?', '[']);
+        (!self.allow_patterns.is_empty() && self.allow_patterns.iter().all(literal))
+            .then_some(self.allow_patterns.as_slice())
+    }
+}
+
+fn matches_from(pattern: &[char], path: &[char]) -> bool {
+    let (mut p, mut s) = (0, 0);
+    // The most recent `*` and the path position it is currently matched up to.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while s < path.len() {
+        let step = match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, s));
+                p += 1;
+                continue;
+            }
+            Some('?') => Some(p + 1),
+            Some('[') => match class_end(pattern, p) {
+                Some(end) if class_matches(&pattern[p + 1..end], path[s]) => Some(end + 1),
+                Some(_) => None,
+                None => (path[s] == '[').then_some(p + 1),
+            },
+            Some(&c) => (c == path[s]).then_some(p + 1),
+            None => None,
+        };
+
+        match (step, backtrack) {
+            (Some(next), _) => {
+                p = next;
+                s += 1;
+            }
+            (None, Some((star, matched))) => {
+                p = star + 1;
+                s = matched + 1;
+                backtrack = Some((star, matched + 1));
+            }
+            (None, None) => return false,
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Returns the index of the `]` closing the class that opens at `start`.
+fn class_end(pattern: &[char], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    if pattern.get(i) == Some(&'!') {
+        i += 1;
+    }
+    // A `]` first in the class is a literal.
+    if pattern.get(i) == Some(&']') {
+        i += 1;
+    }
+    (i..pattern.len()).find(|&j| pattern[j] == ']')
+}
+
+fn class_matches(class: &[char], c: char) -> bool {
+    let (negated, class) = match class.first() {
+        Some('!') => (true, &class[1..]),
+        _ => (false, class),
+    };
+
+    let mut matched = false;
+    let mut i = 0;
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == '-' {
+            matched |= class[i] <= c && c <= class[i + 2];
+            i += 3;
+        } else {
+            matched |= class[i] == c;
+            i += 1;
+        }
+    }
+    matched != negated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_fnmatch_patterns() {
+        assert!(matches("*.safetensors", "model.safetensors"));
+        assert!(matches("*.safetensors", "onnx/model.safetensors"));
+        assert!(!matches("*.safetensors", "model.bin"));
+        assert!(matches("tokenizer*", "tokenizer_config.json"));
+        assert!(matches("model-?????-of-*.bin", "model-00001-of-00002.bin"));
+        assert!(matches("onnx/", "onnx/model.onnx"));
+        assert!(!matches("onnx/", "model.onnx"));
+        assert!(matches("[!.]*", "config.json"));
+        assert!(!matches("[!.]*", ".gitattributes"));
+        assert!(matches("model.[a-c]in", "model.bin"));
+        assert!(matches("*", ""));
+    }
+
+    #[test]
+    fn path_filter_applies_allow_then_ignore_patterns() {
+        let everything = PathFilter::new(Vec::new(), Vec::new()).unwrap();
+        assert!(everything.includes("model.bin"));
+
+        let filter = PathFilter::new(
+            vec!["*.safetensors".to_string(), "tokenizer*".to_string()],
+            vec!["onnx/".to_string()],
+        )
+        .unwrap();
+        assert!(filter.includes("model.safetensors"));
+        assert!(filter.includes("tokenizer.json"));
+        assert!(!filter.includes("pytorch_model.bin"));
+        assert!(!filter.includes("onnx/model.safetensors"));
+
+        assert!(PathFilter::new(Vec::new(), vec!["[".to_string()]).is_err());
+    }
+
+    #[test]
+    fn literal_paths_require_every_allow_pattern_to_be_exact() {
+        let exact = vec!["config.json".to_string(), "onnx/model.onnx".to_string()];
+        let filter = PathFilter::new(exact.clone(), Vec::new()).unwrap();
+        assert_eq!(filter.literal_paths(), Some(exact.as_slice()));
+
+        for patterns in [vec![], vec!["config.json", "*.bin"], vec!["onnx/"]] {
+            let patterns = patterns.into_iter().map(String::from).collect();
+            let filter = PathFilter::new(patterns, Vec::new()).unwrap();
+            assert_eq!(filter.literal_paths(), None);
+        }
+    }
+
+    #[test]
+    fn validate_rejects_malformed_patterns() {
+        assert!(validate("*.bin").is_ok());
+        assert!(validate("[]]").is_ok());
+        assert!(validate("").is_err());
+        assert!(validate("model.[bin").is_err());
+    }
+}
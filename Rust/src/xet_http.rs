@@ -0,0 +1,339 @@
+use std::ops::Range;
+use std::time::Duration;
+
+use reqwest::{redirect::Policy, Client, ClientBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::xet_faults;
+use crate::xet_tls::TlsOptions;
+use crate::{ErrorCause, XetClientConfig, XetError, USER_AGENT};
+
+/// The number of redirects followed when the client configuration doesn't set a limit.
+pub const DEFAULT_MAX_REDIRECTS: u32 = 10;
+
+/// How long a connection may take to establish, and may stall between reads.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timeouts {
+    pub connect: Option<Duration>,
+    pub read: Option<Duration>,
+}
+
+impl Timeouts {
+    pub fn from_config(config: &XetClientConfig) -> Self {
+        Self {
+            connect: config.connect_timeout_secs.map(Duration::from_secs),
+            read: config.read_timeout_secs.map(Duration::from_secs),
+        }
+    }
+
+    fn apply(self, mut builder: ClientBuilder) -> ClientBuilder {
+        if let Some(connect) = self.connect {
+            builder = builder.connect_timeout(connect);
+        }
+        if let Some(read) = self.read {
+            builder = builder.read_timeout(read);
+        }
+        builder
+    }
+}
+
+/// Builds the client for API and content requests, which follows up to `max_redirects` redirects.
+///
+/// `Authorization` and cookie headers are dropped whenever a redirect leaves
+/// the original host or port, so a token sent to the Hub never reaches the CDN
+/// or storage hosts that resolve requests redirect to.
+pub fn content_client(
+    max_redirects: u32,
+    tls: &TlsOptions,
+    timeouts: Timeouts,
+) -> reqwest::Result<Client> {
+    let builder = Client::builder()
+        .user_agent(USER_AGENT)
+        .redirect(Policy::limited(max_redirects as usize));
+    tls.apply(timeouts.apply(builder))?.build()
+}
+
+/// Builds the client for resolve requests, which never follows redirects.
+///
+/// The Hub reports file metadata in the headers of the redirect itself, so the
+/// redirect is read rather than followed.
+pub fn metadata_client(tls: &TlsOptions, timeouts: Timeouts) -> reqwest::Result<Client> {
+    let builder = Client::builder()
+        .user_agent(USER_AGENT)
+        .redirect(Policy::none());
+    tls.apply(timeouts.apply(builder))?.build()
+}
+
+/// Fetches the bytes of `range` from `url`, which serves a file of `size` bytes.
+///
+/// Servers that ignore the range are only accepted when the range covers the
+/// whole file, so a partial read can never silently return the wrong bytes.
+pub async fn fetch_range(
+    client: &Client,
+    url: &str,
+    token: Option<&str>,
+    range: Range<u64>,
+    size: u64,
+) -> Result<Vec<u8>, XetError> {
+    let mut request = client.get(url).header(
+        reqwest::header::RANGE,
+        format!("bytes={}-{}", range.start, range.end.saturating_sub(1)),
+    );
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    let network_error = |e: reqwest::Error| XetError::NetworkError {
+        message: format!("HTTP error: {}", e),
+        cause: Some(ErrorCause::from(&e)),
+    };
+    let response = xet_faults::send(request)
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(network_error)?;
+
+    let whole_file = range.start == 0 && range.end == size;
+    if response.status() != StatusCode::PARTIAL_CONTENT && !whole_file {
+        return Err(XetError::NetworkError {
+            message: format!("Server ignored the requested range of {}", url),
+            cause: None,
+        });
+    }
+    let bytes = response.bytes().await.map_err(network_error)?;
+    if bytes.len() as u64 != range.end - range.start {
+        return Err(XetError::NetworkError {
+            message: format!(
+                "Expected {} bytes from {}, received {}",
+                range.end - range.start,
+                url,
+                bytes.len()
+            ),
+            cause: None,
+        });
+    }
+    Ok(bytes.to_vec())
+}
+
+/// The largest response body the client reads into memory, unless configured.
+pub const DEFAULT_MAX_RESPONSE_BODY_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Reads the body of `response` into memory, failing if it's over `limit` bytes.
+///
+/// A body that declares its length fails before any of it is read, and one
+/// that doesn't is read only until it passes the limit.
+pub async fn read_body(mut response: Response, limit: u64) -> Result<Vec<u8>, XetError> {
+    if response.content_length().unwrap_or(0) > limit {
+        return Err(body_too_large(limit));
+    }
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if (body.len() + chunk.len()) as u64 > limit {
+            return Err(body_too_large(limit));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// Reads the body of `response` as UTF-8 text, failing if it's over `limit` bytes.
+pub async fn read_text(response: Response, limit: u64) -> Result<String, XetError> {
+    let body = read_body(response, limit).await?;
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Reads the body of `response` as JSON, failing if it's over `limit` bytes.
+pub async fn read_json<T: DeserializeOwned>(response: Response, limit: u64) -> Result<T, XetError> {
+    let body = read_body(response, limit).await?;
+    Ok(serde_json::from_slice(&body)?)
+}
+
+pub fn body_too_large(limit: u64) -> XetError {
+    XetError::PolicyViolation {
+        message: format!(
+            "The response is larger than the maximum response body size of {} bytes",
+            limit
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread::JoinHandle;
+
+    /// Serves `response` to each connection and records the requests received.
+    struct TestServer {
+        url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+        handle: JoinHandle<()>,
+    }
+
+    impl TestServer {
+        fn start(connections: usize, response: impl Fn(&str) -> String + Send + 'static) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let recorded = requests.clone();
+            let handle = std::thread::spawn(move || {
+                for stream in listener.incoming().take(connections) {
+                    let mut stream = stream.unwrap();
+                    let mut request = Vec::new();
+                    let mut buffer = [0u8; 1024];
+                    while !request.ends_with(b"\r\n\r\n") {
+                        let read = stream.read(&mut buffer).unwrap();
+                        if read == 0 {
+                            break;
+                        }
+                        request.extend_from_slice(&buffer[..read]);
+                    }
+                    let request = String::from_utf8_lossy(&request).to_lowercase();
+                    stream.write_all(response(&request).as_bytes()).unwrap();
+                    recorded.lock().unwrap().push(request);
+                }
+            });
+            Self {
+                url,
+                requests,
+                handle,
+            }
+        }
+
+        fn requests(self) -> Vec<String> {
+            self.handle.join().unwrap();
+            Arc::try_unwrap(self.requests)
+                .unwrap()
+                .into_inner()
+                .unwrap()
+        }
+    }
+
+    fn redirect_to(location: String) -> impl Fn(&str) -> String {
+        move |_| {
+            format!(
+                "HTTP/1.1 302 Found\r\nLocation: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                location
+            )
+        }
+    }
+
+    fn ok(_: &str) -> String {
+        "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok".to_string()
+    }
+
+    fn get(client: &Client, url: &str) -> reqwest::Result<reqwest::Response> {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(client.get(url).bearer_auth("hf_secret").send())
+    }
+
+    #[test]
+    fn content_client_strips_auth_on_cross_origin_redirects() {
+        let cdn = TestServer::start(1, ok);
+        let hub = TestServer::start(1, redirect_to(format!("{}/blob", cdn.url)));
+
+        let client = content_client(
+            DEFAULT_MAX_REDIRECTS,
+            &TlsOptions::default(),
+            Timeouts::default(),
+        )
+        .unwrap();
+        let response = get(&client, &format!("{}/resolve/main/model.bin", hub.url)).unwrap();
+        assert!(response.status().is_success());
+
+        let hub_requests = hub.requests();
+        assert!(hub_requests[0].contains("authorization: bearer hf_secret"));
+        let cdn_requests = cdn.requests();
+        assert_eq!(cdn_requests.len(), 1);
+        assert!(!cdn_requests[0].contains("authorization"));
+    }
+
+    #[test]
+    fn content_client_limits_redirect_hops() {
+        let hub = TestServer::start(1, redirect_to("http://127.0.0.1:9/blob".to_string()));
+
+        let client = content_client(0, &TlsOptions::default(), Timeouts::default()).unwrap();
+        let error = get(&client, &format!("{}/resolve/main/model.bin", hub.url)).unwrap_err();
+        assert!(error.is_redirect());
+        hub.requests();
+    }
+
+    #[test]
+    fn fetch_range_requests_and_checks_the_range() {
+        let server = TestServer::start(2, |request| {
+            if request.contains("range: bytes=2-5") {
+                "HTTP/1.1 206 Partial Content\r\nContent-Length: 4\r\nConnection: close\r\n\r\n2345"
+                    .to_string()
+            } else {
+                "HTTP/1.1 200 OK\r\nContent-Length: 10\r\nConnection: close\r\n\r\n0123456789"
+                    .to_string()
+            }
+        });
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let client = content_client(
+            DEFAULT_MAX_REDIRECTS,
+            &TlsOptions::default(),
+            Timeouts::default(),
+        )
+        .unwrap();
+        let url = format!("{}/model.bin", server.url);
+
+        let bytes = runtime.block_on(fetch_range(&client, &url, None, 2..6, 10));
+        assert_eq!(bytes.unwrap(), b"2345");
+        let ignored = runtime.block_on(fetch_range(&client, &url, None, 6..10, 10));
+        assert!(ignored.is_err());
+        server.requests();
+    }
+
+    #[test]
+    fn read_body_stops_at_the_limit() {
+        let server = TestServer::start(3, |request| {
+            if request.contains("/chunked") {
+                "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n\
+                 5\r\n01234\r\n5\r\n56789\r\n0\r\n\r\n"
+                    .to_string()
+            } else {
+                "HTTP/1.1 200 OK\r\nContent-Length: 10\r\nConnection: close\r\n\r\n0123456789"
+                    .to_string()
+            }
+        });
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let client = metadata_client(&TlsOptions::default(), Timeouts::default()).unwrap();
+        let read = |path: &str, limit| {
+            runtime.block_on(async {
+                let response = client.get(format!("{}{}", server.url, path)).send().await?;
+                read_body(response, limit).await
+            })
+        };
+
+        assert_eq!(read("/sized", 10).unwrap(), b"0123456789");
+        assert!(matches!(
+            read("/sized", 9),
+            Err(XetError::PolicyViolation { .. })
+        ));
+        assert!(matches!(
+            read("/chunked", 7),
+            Err(XetError::PolicyViolation { .. })
+        ));
+        server.requests();
+    }
+
+    #[test]
+    fn metadata_client_does_not_follow_redirects() {
+        let hub = TestServer::start(1, redirect_to("http://127.0.0.1:9/blob".to_string()));
+
+        let client = metadata_client(&TlsOptions::default(), Timeouts::default()).unwrap();
+        let response = get(&client, &format!("{}/resolve/main/model.bin", hub.url)).unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::FOUND);
+        assert_eq!(hub.requests().len(), 1);
+    }
+}
@@ -0,0 +1,455 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crate::xet_cache::{self, CacheLayout};
+use crate::xet_storage::CacheStorage;
+use crate::xet_verify;
+use crate::{CacheArea, CachedRevision};
+
+const BLOBS_DIR: &str = "blobs";
+const REFS_DIR: &str = "refs";
+const SNAPSHOTS_DIR: &str = "snapshots";
+
+/// Files downloaded into the cache, laid out like the `huggingface_hub` cache.
+///
+/// File contents are stored once per etag in a shared `blobs` directory. Each
+/// repository has its own directory holding:
+///
+/// * `snapshots/<commit>/<path>` — links into `blobs` for each commit;
+/// * `refs/<revision>` — the commit a branch or tag last resolved to.
+///
+/// Because contents are keyed by etag rather than path, any number of
+/// revisions of the same file can be on disk at once, and files with the same
+/// contents share a blob even across repositories, such as fine-tunes that
+/// ship their base model's tokenizer.
+pub struct HubCache {
+    root: PathBuf,
+    storage: Arc<dyn CacheStorage>,
+}
+
+impl HubCache {
+    pub fn new(root: PathBuf, storage: Arc<dyn CacheStorage>) -> Self {
+        Self { root, storage }
+    }
+
+    /// Opens the layout stored in the downloaded files area of `layout`.
+    ///
+    /// Refs are kept in the layout's storage; blobs and snapshot links are
+    /// always files, since callers are handed their paths.
+    pub fn open(layout: &CacheLayout) -> Self {
+        Self::new(
+            layout
+                .root(CacheArea::DownloadedFiles)
+                .join(xet_cache::HUB_DIR),
+            layout.storage(),
+        )
+    }
+
+    /// Returns the directory of the repository identified by `repo`, such as `models/owner/name`.
+    pub fn repo_dir(&self, repo: &str) -> PathBuf {
+        self.root.join(repo.replace('/', "--"))
+    }
+
+    /// Returns where the contents with `etag` are stored.
+    pub fn blob_path(&self, etag: &str) -> PathBuf {
+        let etag = etag.trim_start_matches("W/").trim_matches('"');
+        self.root
+            .join(BLOBS_DIR)
+            .join(etag.replace(['/', '\\'], "_"))
+    }
+
+    /// Returns the directory the files of `commit` are linked into.
+    pub fn snapshot_dir(&self, repo: &str, commit: &str) -> PathBuf {
+        self.repo_dir(repo).join(SNAPSHOTS_DIR).join(commit)
+    }
+
+    /// Records that `revision` resolved to `commit`.
+    ///
+    /// Nothing is recorded when the revision is the commit itself.
+    pub fn write_ref(&self, repo: &str, revision: &str, commit: &str) -> io::Result<()> {
+        if revision == commit || !is_relative_name(revision) {
+            return Ok(());
+        }
+        let path = self.repo_dir(repo).join(REFS_DIR).join(revision);
+        self.storage.write(&path, commit.as_bytes())
+    }
+
+    /// Points the snapshot file at `snapshot` to `blob`, replacing any previous link.
+    pub fn link(&self, blob: &Path, snapshot: &Path) -> io::Result<()> {
+        if let Some(parent) = snapshot.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        match fs::remove_file(snapshot) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+        link_file(blob, snapshot)
+    }
+
+    /// Returns the commit `revision` resolves to locally, without contacting the Hub.
+    ///
+    /// A branch or tag resolves through its recorded ref; a commit resolves to
+    /// itself if anything was cached at it.
+    pub fn resolve_cached(&self, repo: &str, revision: &str) -> Option<String> {
+        if !is_relative_name(revision) {
+            return None;
+        }
+        let repo_dir = self.repo_dir(repo);
+        if let Ok(commit) = self.storage.read(&repo_dir.join(REFS_DIR).join(revision)) {
+            return Some(String::from_utf8_lossy(&commit).trim().to_string());
+        }
+        repo_dir
+            .join(SNAPSHOTS_DIR)
+            .join(revision)
+            .is_dir()
+            .then(|| revision.to_string())
+    }
+
+    /// Returns the snapshot file at `snapshot` if the blob it links to is present.
+    ///
+    /// Blobs are only moved into place once downloaded in full, so a present
+    /// blob always holds complete contents.
+    pub fn cached_file(&self, snapshot: &Path) -> Option<PathBuf> {
+        let metadata = fs::metadata(snapshot).ok()?;
+        metadata.is_file().then(|| snapshot.to_path_buf())
+    }
+
+    /// Returns the bytes saved by sharing blobs between snapshot files.
+    ///
+    /// This is how much more space the cached files would take if every
+    /// snapshot file held its own copy of its contents.
+    pub fn deduplicated_bytes(&self) -> io::Result<u64> {
+        let mut links: HashMap<PathBuf, u64> = HashMap::new();
+        let repos = match fs::read_dir(&self.root) {
+            Ok(repos) => repos,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(err),
+        };
+        for repo in repos {
+            let repo = repo?;
+            if repo.file_name() != BLOBS_DIR && repo.file_type()?.is_dir() {
+                count_links(&repo.path().join(SNAPSHOTS_DIR), &mut links)?;
+            }
+        }
+
+        let mut saved = 0u64;
+        for (blob, count) in links {
+            if let Ok(metadata) = fs::metadata(&blob) {
+                saved = saved.saturating_add(metadata.len().saturating_mul(count - 1));
+            }
+        }
+        Ok(saved)
+    }
+
+    /// Removes blobs whose contents don't hash to the SHA-256 their etag names,
+    /// such as ones cut short by a full disk, returning how many were removed.
+    ///
+    /// Blobs named by a git object ID can't be checked this way and are kept.
+    /// `verified` remembers the size and modification time of blobs found
+    /// intact, so later passes only hash blobs that are new or changed.
+    pub fn remove_corrupt_blobs(
+        &self,
+        verified: &mut HashMap<PathBuf, (u64, SystemTime)>,
+    ) -> io::Result<usize> {
+        let blobs = match fs::read_dir(self.root.join(BLOBS_DIR)) {
+            Ok(blobs) => blobs,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(err),
+        };
+        let mut removed = 0;
+        for blob in blobs {
+            let blob = blob?;
+            let name = blob.file_name().to_string_lossy().to_string();
+            let Some(expected) = xet_verify::expected_sha256(&name) else {
+                continue;
+            };
+            let metadata = blob.metadata()?;
+            let stamp = (metadata.len(), metadata.modified()?);
+            let path = blob.path();
+            if verified.get(&path) == Some(&stamp) {
+                continue;
+            }
+            let (actual, _) = xet_verify::hash_file(&path)?;
+            if actual == expected {
+                verified.insert(path, stamp);
+            } else {
+                verified.remove(&path);
+                fs::remove_file(&path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Lists the cached revisions of the file at `path`, most recently cached first.
+    pub fn cached_revisions(&self, repo: &str, path: &str) -> io::Result<Vec<CachedRevision>> {
+        let repo_dir = self.repo_dir(repo);
+        let snapshots = match fs::read_dir(repo_dir.join(SNAPSHOTS_DIR)) {
+            Ok(snapshots) => snapshots,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let refs = read_refs(&repo_dir.join(REFS_DIR))?;
+
+        let mut revisions = Vec::new();
+        for snapshot in snapshots {
+            let snapshot = snapshot?;
+            let file = snapshot.path().join(path);
+            // Following the link skips snapshots whose blob is gone.
+            let Ok(metadata) = fs::metadata(&file) else {
+                continue;
+            };
+            if !metadata.is_file() {
+                continue;
+            }
+            let commit = snapshot.file_name().to_string_lossy().to_string();
+            let modified = fs::symlink_metadata(&file)
+                .and_then(|link| link.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            revisions.push((
+                modified,
+                CachedRevision {
+                    refs: refs.get(&commit).cloned().unwrap_or_default(),
+                    commit,
+                    local_path: file.to_string_lossy().to_string(),
+                    size: metadata.len(),
+                },
+            ));
+        }
+
+        revisions.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+        Ok(revisions
+            .into_iter()
+            .map(|(_, revision)| revision)
+            .collect())
+    }
+}
+
+/// Returns whether `name` stays inside the directory it is joined onto.
+fn is_relative_name(name: &str) -> bool {
+    !name.is_empty()
+        && Path::new(name)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// Counts the snapshot links below `dir` pointing at each blob.
+///
+/// Snapshot files that aren't symlinks hold their own copy and aren't counted.
+fn count_links(dir: &Path, links: &mut HashMap<PathBuf, u64>) -> io::Result<()> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            count_links(&path, links)?;
+        } else if file_type.is_symlink() {
+            let target = fs::read_link(&path)?;
+            let blob = match path.parent() {
+                Some(parent) => parent.join(target),
+                None => target,
+            };
+            if let Ok(blob) = fs::canonicalize(blob) {
+                *links.entry(blob).or_default() += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads every ref below `dir`, grouped by the commit it points to.
+fn read_refs(dir: &Path) -> io::Result<HashMap<String, Vec<String>>> {
+    let mut refs: HashMap<String, Vec<String>> = HashMap::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        let entries = match fs::read_dir(&current) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            if path.extension().is_some_and(|extension| extension == "tmp") {
+                continue;
+            }
+            let name = path
+                .strip_prefix(dir)
+                .unwrap_or(&path)
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let commit = fs::read_to_string(&path)?.trim().to_string();
+            refs.entry(commit).or_default().push(name);
+        }
+    }
+    for names in refs.values_mut() {
+        names.sort();
+    }
+    Ok(refs)
+}
+
+/// Links `snapshot` to `blob` with a relative symlink, so the cache can be moved.
+#[cfg(unix)]
+fn link_file(blob: &Path, snapshot: &Path) -> io::Result<()> {
+    let target = match snapshot.parent() {
+        Some(parent) => relative_path(parent, blob),
+        None => blob.to_path_buf(),
+    };
+    std::os::unix::fs::symlink(target, snapshot)
+}
+
+/// Links `snapshot` to `blob`, falling back to a copy where hard links aren't supported.
+#[cfg(not(unix))]
+fn link_file(blob: &Path, snapshot: &Path) -> io::Result<()> {
+    fs::hard_link(blob, snapshot).or_else(|_| fs::copy(blob, snapshot).map(|_| ()))
+}
+
+/// Returns the path of `target` relative to the directory `from`.
+#[cfg(unix)]
+fn relative_path(from: &Path, target: &Path) -> PathBuf {
+    let from: Vec<_> = from.components().collect();
+    let target: Vec<_> = target.components().collect();
+    let common = from.iter().zip(&target).take_while(|(a, b)| a == b).count();
+
+    let mut relative = PathBuf::new();
+    for _ in common..from.len() {
+        relative.push("..");
+    }
+    for component in &target[common..] {
+        relative.push(component);
+    }
+    relative
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xet_storage::FileSystemStorage;
+
+    const REPO: &str = "models/owner/name";
+
+    #[test]
+    fn revisions_of_the_same_file_coexist() {
+        let root = std::env::temp_dir().join(format!("swift-xet-hub-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let cache = HubCache::new(root.clone(), Arc::new(FileSystemStorage));
+
+        for (commit, etag, contents) in [("aaa111", "\"e1\"", "v1"), ("bbb222", "\"e2\"", "v2!")] {
+            let blob = cache.blob_path(etag);
+            fs::create_dir_all(blob.parent().unwrap()).unwrap();
+            fs::write(&blob, contents).unwrap();
+            let snapshot = cache.snapshot_dir(REPO, commit).join("config.json");
+            cache.link(&blob, &snapshot).unwrap();
+        }
+        cache.write_ref(REPO, "main", "bbb222").unwrap();
+        cache.write_ref(REPO, "refs/pr/1", "bbb222").unwrap();
+        cache.write_ref(REPO, "aaa111", "aaa111").unwrap();
+
+        let mut revisions = cache.cached_revisions(REPO, "config.json").unwrap();
+        revisions.sort_by(|a, b| a.commit.cmp(&b.commit));
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[0].commit, "aaa111");
+        assert!(revisions[0].refs.is_empty());
+        assert_eq!(fs::read_to_string(&revisions[0].local_path).unwrap(), "v1");
+        assert_eq!(revisions[1].refs, vec!["main", "refs/pr/1"]);
+        assert_eq!(revisions[1].size, 3);
+
+        assert_eq!(
+            cache.resolve_cached(REPO, "main").as_deref(),
+            Some("bbb222")
+        );
+        assert_eq!(
+            cache.resolve_cached(REPO, "aaa111").as_deref(),
+            Some("aaa111")
+        );
+        assert_eq!(cache.resolve_cached(REPO, "dev"), None);
+        let snapshot = cache.snapshot_dir(REPO, "aaa111").join("config.json");
+        assert_eq!(cache.cached_file(&snapshot), Some(snapshot));
+        fs::remove_file(cache.blob_path("\"e1\"")).unwrap();
+        assert_eq!(
+            cache.cached_file(&cache.snapshot_dir(REPO, "aaa111").join("config.json")),
+            None
+        );
+
+        assert!(cache
+            .cached_revisions(REPO, "missing.json")
+            .unwrap()
+            .is_empty());
+        assert!(cache
+            .cached_revisions("models/other/name", "config.json")
+            .unwrap()
+            .is_empty());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn blobs_that_dont_match_their_sha256_are_removed() {
+        let root =
+            std::env::temp_dir().join(format!("swift-xet-hub-verify-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let cache = HubCache::new(root.clone(), Arc::new(FileSystemStorage));
+        // The SHA-256 of "hello".
+        let etag = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        let intact = cache.blob_path(etag);
+        fs::create_dir_all(intact.parent().unwrap()).unwrap();
+        fs::write(&intact, "hello").unwrap();
+        let truncated = cache.blob_path(&etag.replace("2cf2", "0000"));
+        fs::write(&truncated, "hel").unwrap();
+        let git_object = cache.blob_path("\"e1\"");
+        fs::write(&git_object, "anything").unwrap();
+
+        let mut verified = HashMap::new();
+        assert_eq!(cache.remove_corrupt_blobs(&mut verified).unwrap(), 1);
+        assert!(intact.exists() && git_object.exists());
+        assert!(!truncated.exists());
+        assert_eq!(verified.len(), 1);
+
+        // A blob changed since it was verified is hashed again.
+        fs::write(&intact, "hello, world").unwrap();
+        assert_eq!(cache.remove_corrupt_blobs(&mut verified).unwrap(), 1);
+        assert!(!intact.exists());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn repositories_share_blobs_with_the_same_etag() {
+        let root = std::env::temp_dir().join(format!("swift-xet-hub-dedup-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let cache = HubCache::new(root.clone(), Arc::new(FileSystemStorage));
+        assert_eq!(cache.deduplicated_bytes().unwrap(), 0);
+
+        let blob = cache.blob_path("\"shared\"");
+        fs::create_dir_all(blob.parent().unwrap()).unwrap();
+        fs::write(&blob, vec![0u8; 100]).unwrap();
+        for repo in ["models/base/llm", "models/a/llm-ft", "models/b/llm-ft"] {
+            let snapshot = cache.snapshot_dir(repo, "c0ffee").join("tokenizer.json");
+            cache.link(&blob, &snapshot).unwrap();
+        }
+
+        assert_eq!(cache.deduplicated_bytes().unwrap(), 200);
+        let revisions = cache
+            .cached_revisions("models/b/llm-ft", "tokenizer.json")
+            .unwrap();
+        assert_eq!(revisions[0].size, 100);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
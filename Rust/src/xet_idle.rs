@@ -0,0 +1,166 @@
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The shortest interval between idle checks, so short timeouts don't spin.
+const MIN_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A resource that is built on first use and can be released while unused.
+///
+/// Uses hold a read lock, so the resource is never released out from under a
+/// caller; releasing only succeeds when nothing is using it.
+pub struct IdleSlot<T> {
+    value: RwLock<Option<T>>,
+    last_used: Mutex<Instant>,
+}
+
+impl<T> IdleSlot<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value: RwLock::new(Some(value)),
+            last_used: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Calls `f` with the resource, building it with `build` if it was released.
+    pub fn with<R, E>(
+        &self,
+        build: impl FnOnce() -> Result<T, E>,
+        f: impl FnOnce(&T) -> R,
+    ) -> Result<R, E> {
+        self.touch();
+        let mut build = Some(build);
+        loop {
+            let value = self.value.read().unwrap_or_else(PoisonError::into_inner);
+            if let Some(value) = value.as_ref() {
+                let result = f(value);
+                self.touch();
+                return Ok(result);
+            }
+            drop(value);
+
+            let mut value = self.value.write().unwrap_or_else(PoisonError::into_inner);
+            if value.is_none() {
+                if let Some(build) = build.take() {
+                    *value = Some(build()?);
+                }
+            }
+        }
+    }
+
+    /// Releases the resource if it has been unused for at least `timeout`.
+    ///
+    /// Returns whether the resource was released.
+    pub fn release_if_idle(&self, timeout: Duration) -> bool {
+        if self.idle_for() < timeout {
+            return false;
+        }
+        let released = match self.value.try_write() {
+            Ok(mut value) => value.take(),
+            Err(_) => None,
+        };
+        // Dropped outside the lock, since tearing down a runtime can take a moment.
+        released.is_some()
+    }
+
+    /// Returns whether the resource is currently built.
+    #[cfg(test)]
+    pub fn is_loaded(&self) -> bool {
+        self.value
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_some()
+    }
+
+    fn idle_for(&self) -> Duration {
+        self.last_used
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .elapsed()
+    }
+
+    fn touch(&self) {
+        *self
+            .last_used
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Instant::now();
+    }
+}
+
+/// Stops its monitor thread when dropped.
+pub struct IdleMonitor {
+    _stop: mpsc::Sender<()>,
+}
+
+/// Starts a thread that releases `slot`'s resource once it has been idle for `timeout`.
+///
+/// `can_release` is consulted before each release, so work the slot doesn't
+/// see, such as background tasks on a runtime, can keep the resource alive.
+/// The thread exits when the returned monitor or the slot is dropped.
+pub fn monitor<T: Send + Sync + 'static>(
+    slot: &Arc<IdleSlot<T>>,
+    timeout: Duration,
+    can_release: impl Fn() -> bool + Send + 'static,
+) -> std::io::Result<IdleMonitor> {
+    let (stop, stopped) = mpsc::channel::<()>();
+    let slot = Arc::downgrade(slot);
+    let interval = (timeout / 4).max(MIN_CHECK_INTERVAL);
+    thread::Builder::new()
+        .name("xet-idle".to_string())
+        .spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                let Some(slot) = slot.upgrade() else {
+                    return;
+                };
+                if can_release() {
+                    slot.release_if_idle(timeout);
+                }
+            }
+        })?;
+    Ok(IdleMonitor { _stop: stop })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn released_resources_are_rebuilt_on_next_use() {
+        let slot = IdleSlot::new(1);
+        assert!(!slot.release_if_idle(Duration::from_secs(60)));
+        assert!(slot.release_if_idle(Duration::ZERO));
+        assert!(!slot.is_loaded());
+
+        let builds = AtomicUsize::new(0);
+        let build = || {
+            builds.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, ()>(2)
+        };
+        assert_eq!(slot.with(build, |value| *value), Ok(2));
+        assert_eq!(slot.with(build, |value| *value), Ok(2));
+        assert_eq!(builds.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn resources_in_use_are_not_released() {
+        let slot = IdleSlot::new(1);
+        let released = slot
+            .with(|| Ok::<_, ()>(1), |_| slot.release_if_idle(Duration::ZERO))
+            .unwrap();
+        assert!(!released);
+        assert!(slot.is_loaded());
+    }
+
+    #[test]
+    fn monitor_releases_idle_resources() {
+        let slot = Arc::new(IdleSlot::new(1));
+        let _monitor = monitor(&slot, Duration::from_millis(10), || true).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while slot.is_loaded() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(!slot.is_loaded());
+    }
+}
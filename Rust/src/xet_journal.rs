@@ -0,0 +1,330 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::xet_cache::{self, CacheLayout};
+use crate::xet_commit::{self, CommitOperation};
+use crate::xet_staging::StagedFile;
+use crate::xet_storage::CacheStorage;
+
+/// How long an interrupted upload can be resumed before recovery discards it.
+pub const RESUMABLE_UPLOAD_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+static NEXT_INTENT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The ids of the intents whose uploads are running in this process.
+static LIVE_INTENTS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// Marks an intent as belonging to an upload running in this process until
+/// dropped, so recovery leaves it to that upload.
+pub struct LiveIntent(String);
+
+impl LiveIntent {
+    pub fn hold(intent: &CommitIntent) -> Self {
+        live_intents().insert(intent.id.clone());
+        Self(intent.id.clone())
+    }
+}
+
+impl Drop for LiveIntent {
+    fn drop(&mut self) {
+        live_intents().remove(&self.0);
+    }
+}
+
+/// Returns whether an upload running in this process owns the intent `id`.
+pub fn is_live(id: &str) -> bool {
+    live_intents().contains(id)
+}
+
+fn live_intents() -> std::sync::MutexGuard<'static, BTreeSet<String>> {
+    // The set stays consistent even if a holder panicked.
+    LIVE_INTENTS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// How far a journaled commit got before the process stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntentState {
+    /// Content is still being uploaded; hashes may be incomplete.
+    Uploading,
+    /// All content is in CAS and only the Hub commit remains.
+    Uploaded,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CommitIntent {
+    pub id: String,
+    pub endpoint: String,
+    pub repo_type_plural: String,
+    pub repo_full_name: String,
+    pub revision: String,
+    pub summary: String,
+    pub operations: Vec<CommitOperation>,
+    pub state: IntentState,
+    pub created_at: u64,
+    /// Sent with every attempt to create the commit, so retries can't duplicate it.
+    ///
+    /// Intents journaled before keys were introduced have none until recovery assigns one.
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+    /// The repository paths of the files being uploaded, sorted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub staged: Vec<String>,
+    /// The files whose content reached CAS before the process stopped, so an
+    /// upload of the same files skips them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uploaded: Vec<UploadedFile>,
+}
+
+/// A file whose content is in CAS, recorded with the size and modification
+/// time it was uploaded with so a file changed since is uploaded again.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UploadedFile {
+    pub path_in_repo: String,
+    pub local_path: PathBuf,
+    pub size: u64,
+    pub modified: SystemTime,
+    pub sha256: String,
+}
+
+impl UploadedFile {
+    pub fn new(file: &StagedFile, sha256: String) -> Self {
+        Self {
+            path_in_repo: file.path_in_repo.clone(),
+            local_path: file.local_path.clone(),
+            size: file.size(),
+            modified: file.modified(),
+            sha256,
+        }
+    }
+
+    /// Returns whether this is `file`'s content as it was staged.
+    pub fn is_of(&self, file: &StagedFile) -> bool {
+        self.path_in_repo == file.path_in_repo
+            && self.local_path == file.local_path
+            && self.size == file.size()
+            && self.modified == file.modified()
+    }
+}
+
+impl CommitIntent {
+    pub fn new(
+        endpoint: &str,
+        repo_type_plural: &str,
+        repo_full_name: &str,
+        revision: &str,
+        summary: &str,
+    ) -> Self {
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        let sequence = NEXT_INTENT_ID.fetch_add(1, Ordering::Relaxed);
+
+        Self {
+            id: format!("{}-{}-{}", created_at, std::process::id(), sequence),
+            endpoint: endpoint.to_string(),
+            repo_type_plural: repo_type_plural.to_string(),
+            repo_full_name: repo_full_name.to_string(),
+            revision: revision.to_string(),
+            summary: summary.to_string(),
+            operations: Vec::new(),
+            state: IntentState::Uploading,
+            created_at,
+            idempotency_key: Some(xet_commit::new_idempotency_key()),
+            staged: Vec::new(),
+            uploaded: Vec::new(),
+        }
+    }
+
+    /// Returns whether this intent's upload stopped partway through, recently
+    /// enough to be resumed.
+    pub fn is_resumable(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        self.state == IntentState::Uploading
+            && !self.uploaded.is_empty()
+            && now.saturating_sub(self.created_at) < RESUMABLE_UPLOAD_MAX_AGE.as_secs()
+    }
+
+    /// Returns whether `other` uploads the same files to the same place with
+    /// the same message, so it can resume this intent's upload.
+    fn is_resumed_by(&self, other: &CommitIntent) -> bool {
+        self.endpoint == other.endpoint
+            && self.repo_type_plural == other.repo_type_plural
+            && self.repo_full_name == other.repo_full_name
+            && self.revision == other.revision
+            && self.summary == other.summary
+            && self.staged == other.staged
+    }
+}
+
+/// Durable record of commits that have started but not finished.
+///
+/// Each intent is a small JSON file written atomically, so a crash at any
+/// point leaves either the previous or the next state on disk, never a torn one.
+pub struct CommitJournal {
+    dir: PathBuf,
+    storage: Arc<dyn CacheStorage>,
+}
+
+impl CommitJournal {
+    pub fn new(dir: PathBuf, storage: Arc<dyn CacheStorage>) -> Self {
+        Self { dir, storage }
+    }
+
+    /// Opens the journal kept under the default root of `layout`, apart from
+    /// any area that clearing or pruning the cache would remove.
+    pub fn open(layout: &CacheLayout) -> Self {
+        Self::new(layout.base().join(xet_cache::JOURNAL_DIR), layout.storage())
+    }
+
+    pub fn record(&self, intent: &CommitIntent) -> io::Result<()> {
+        let contents = serde_json::to_vec_pretty(intent).map_err(io::Error::other)?;
+        self.storage.write(&self.intent_path(&intent.id), &contents)
+    }
+
+    pub fn complete(&self, id: &str) -> io::Result<()> {
+        self.storage.remove(&self.intent_path(id))
+    }
+
+    pub fn pending(&self) -> io::Result<Vec<CommitIntent>> {
+        let mut intents = Vec::new();
+        for path in self.storage.list(&self.dir)? {
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            // An unreadable intent can never be completed; drop it rather than
+            // failing recovery of every other pending commit.
+            match self.read_intent(&path) {
+                Some(intent) => intents.push(intent),
+                None => self.storage.remove(&path)?,
+            }
+        }
+        intents.sort_by_key(|intent| intent.created_at);
+        Ok(intents)
+    }
+
+    /// Returns the interrupted upload that `intent` would resume, if any.
+    ///
+    /// An upload still running in this process isn't interrupted, so it
+    /// isn't resumed by another.
+    pub fn resumable(&self, intent: &CommitIntent) -> io::Result<Option<CommitIntent>> {
+        Ok(self.pending()?.into_iter().find(|pending| {
+            pending.is_resumable() && pending.is_resumed_by(intent) && !is_live(&pending.id)
+        }))
+    }
+
+    fn intent_path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", id))
+    }
+
+    fn read_intent(&self, path: &Path) -> Option<CommitIntent> {
+        let contents = self.storage.read(path).ok()?;
+        serde_json::from_slice(&contents).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xet_storage::FileSystemStorage;
+    use std::fs;
+
+    #[test]
+    fn journal_round_trip() {
+        let dir = std::env::temp_dir().join(format!("swift-xet-journal-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let journal = CommitJournal::new(dir.clone(), Arc::new(FileSystemStorage));
+
+        let mut intent = CommitIntent::new(
+            "https://huggingface.co",
+            "models",
+            "owner/repo",
+            "main",
+            "Upload",
+        );
+        intent.operations.push(CommitOperation::AddXetFile {
+            path_in_repo: "a.bin".to_string(),
+            sha256: "abc".to_string(),
+            size: 1,
+        });
+        journal.record(&intent).unwrap();
+
+        intent.state = IntentState::Uploaded;
+        journal.record(&intent).unwrap();
+
+        let pending = journal.pending().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].state, IntentState::Uploaded);
+        assert_eq!(pending[0].operations.len(), 1);
+        assert_eq!(pending[0].idempotency_key, intent.idempotency_key);
+
+        journal.complete(&intent.id).unwrap();
+        assert!(journal.pending().unwrap().is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn retried_uploads_skip_files_already_sent() {
+        let dir =
+            std::env::temp_dir().join(format!("swift-xet-journal-resume-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let journal = CommitJournal::new(dir.join("journal"), Arc::new(FileSystemStorage));
+        let local_path = dir.join("model.safetensors");
+        fs::write(&local_path, b"weights").unwrap();
+        let file = StagedFile::new(local_path.clone(), "model.safetensors".to_string()).unwrap();
+
+        let new_intent = || {
+            let mut intent = CommitIntent::new(
+                "https://huggingface.co",
+                "models",
+                "owner/repo",
+                "main",
+                "Upload",
+            );
+            intent.staged = vec!["model.safetensors".to_string()];
+            intent
+        };
+        let mut interrupted = new_intent();
+        journal.record(&interrupted).unwrap();
+        // Nothing reached CAS, so there's nothing to resume.
+        assert!(journal.resumable(&new_intent()).unwrap().is_none());
+
+        interrupted
+            .uploaded
+            .push(UploadedFile::new(&file, "abc".to_string()));
+        journal.record(&interrupted).unwrap();
+        let resumed = journal.resumable(&new_intent()).unwrap().unwrap();
+        assert_eq!(resumed.id, interrupted.id);
+        assert!(resumed.uploaded[0].is_of(&file));
+
+        let mut elsewhere = new_intent();
+        elsewhere.revision = "dev".to_string();
+        assert!(journal.resumable(&elsewhere).unwrap().is_none());
+
+        // An upload still running here is left to finish on its own.
+        let live = LiveIntent::hold(&interrupted);
+        assert!(is_live(&interrupted.id));
+        assert!(journal.resumable(&new_intent()).unwrap().is_none());
+        drop(live);
+        assert!(!is_live(&interrupted.id));
+
+        // A file changed since it was uploaded is uploaded again.
+        fs::write(&local_path, b"retrained weights").unwrap();
+        let changed = StagedFile::new(local_path, "model.safetensors".to_string()).unwrap();
+        assert!(!resumed.uploaded[0].is_of(&changed));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
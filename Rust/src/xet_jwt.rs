@@ -0,0 +1,144 @@
+use serde_json::Value;
+
+use crate::{CasTokenClaims, XetError};
+
+/// Decodes the claims in the payload of the JWT `token`, without verifying its signature.
+///
+/// The Hub has named the repository claim differently over time, so `repo`
+/// is read from `repoId`, `repo_id`, or `repo`, which may be a string or an
+/// object with an `id` or `name`. A `scope` given as a list is joined with
+/// spaces, as in OAuth.
+pub fn decode_claims(token: &str) -> Result<CasTokenClaims, XetError> {
+    let invalid = |reason: &str| XetError::InvalidInput {
+        message: format!("The CAS access token isn't a valid JWT: {}", reason),
+    };
+    let mut parts = token.split('.');
+    let payload = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(_), Some(payload), Some(_), None) => payload,
+        _ => return Err(invalid("it doesn't have three parts")),
+    };
+    let bytes = base64url_decode(payload.trim_end_matches('='))
+        .ok_or_else(|| invalid("its payload isn't base64url"))?;
+    let claims: Value =
+        serde_json::from_slice(&bytes).map_err(|_| invalid("its payload isn't JSON"))?;
+    if !claims.is_object() {
+        return Err(invalid("its payload isn't a JSON object"));
+    }
+
+    let scope = match &claims["scope"] {
+        Value::String(scope) => Some(scope.clone()),
+        Value::Array(scopes) => Some(
+            scopes
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        _ => None,
+    };
+    let repo = ["repoId", "repo_id", "repo"]
+        .iter()
+        .find_map(|name| match &claims[*name] {
+            Value::String(repo) => Some(repo.clone()),
+            Value::Object(repo) => ["id", "name"]
+                .iter()
+                .find_map(|key| repo.get(*key)?.as_str().map(str::to_string)),
+            _ => None,
+        });
+    Ok(CasTokenClaims {
+        scope,
+        repo,
+        exp: claims["exp"].as_u64(),
+        issued_at: claims["iat"].as_u64(),
+        payload: claims.to_string(),
+    })
+}
+
+/// Decodes unpadded base64url, the encoding of each part of a JWT.
+fn base64url_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(encoded.len() * 3 / 4);
+    let (mut buffer, mut bits) = (0u32, 0);
+    for byte in encoded.bytes() {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'-' => 62,
+            b'_' => 63,
+            _ => return None,
+        };
+        buffer = buffer << 6 | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    // A lone trailing character can't hold a byte, and the bits left over
+    // after the last byte must be zero in a canonical encoding.
+    if bits >= 6 || buffer & ((1 << bits) - 1) != 0 {
+        return None;
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(payload: &str) -> String {
+        format!("eyJhbGciOiJFUzI1NiJ9.{}.c2lnbmF0dXJl", payload)
+    }
+
+    #[test]
+    fn base64url_decodes_partial_groups() {
+        assert_eq!(base64url_decode("").unwrap(), b"");
+        assert_eq!(base64url_decode("Zg").unwrap(), b"f");
+        assert_eq!(base64url_decode("Zm8").unwrap(), b"fo");
+        assert_eq!(base64url_decode("Zm9vYmFy").unwrap(), b"foobar");
+        assert_eq!(base64url_decode("-_8").unwrap(), [0xfb, 0xff]);
+        assert_eq!(base64url_decode("Zm9v+g"), None);
+        assert_eq!(base64url_decode("Z"), None);
+        assert_eq!(base64url_decode("Zm9vY"), None);
+        assert_eq!(base64url_decode("Zh"), None);
+        assert_eq!(base64url_decode("Zm9"), None);
+    }
+
+    #[test]
+    fn claims_are_read_from_the_payload() {
+        // {"scope":"read","repoId":"owner/model","exp":1700000000,"iat":1699996400}
+        let claims = decode_claims(&token(
+            "eyJzY29wZSI6InJlYWQiLCJyZXBvSWQiOiJvd25lci9tb2RlbCIsImV4cCI6MTcwMDAwMDAwMCwiaWF0IjoxNjk5OTk2NDAwfQ",
+        ))
+        .unwrap();
+        assert_eq!(claims.scope.as_deref(), Some("read"));
+        assert_eq!(claims.repo.as_deref(), Some("owner/model"));
+        assert_eq!(claims.exp, Some(1_700_000_000));
+        assert_eq!(claims.issued_at, Some(1_699_996_400));
+
+        // {"scope":["read","write"],"repo":{"type":"dataset","id":"owner/data"}}
+        let claims = decode_claims(&token(
+            "eyJzY29wZSI6WyJyZWFkIiwid3JpdGUiXSwicmVwbyI6eyJ0eXBlIjoiZGF0YXNldCIsImlkIjoib3duZXIvZGF0YSJ9fQ",
+        ))
+        .unwrap();
+        assert_eq!(claims.scope.as_deref(), Some("read write"));
+        assert_eq!(claims.repo.as_deref(), Some("owner/data"));
+        assert_eq!(claims.exp, None);
+
+        for malformed in [
+            "opaque",
+            "a.b",
+            "a.!!!.c",
+            // [1]
+            &token("WzFd"),
+            // not json
+            &token("bm90IGpzb24"),
+        ] {
+            assert!(
+                matches!(decode_claims(malformed), Err(XetError::InvalidInput { .. })),
+                "{}",
+                malformed
+            );
+        }
+    }
+}
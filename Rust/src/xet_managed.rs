@@ -0,0 +1,186 @@
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use serde::{Deserialize, Serialize};
+
+use crate::xet_cache::{self, CacheLayout};
+use crate::xet_storage::CacheStorage;
+use crate::{CacheArea, ManagedRevisionPolicy};
+
+const LIBRARY_FILE: &str = "library.json";
+const MODELS_DIR: &str = "models";
+
+/// A model registered with the managed library, as persisted on disk.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ManagedRecord {
+    /// The canonical repository identifier, such as `models/owner/name`.
+    pub repo: String,
+    pub revision_policy: ManagedRevisionPolicy,
+    pub patterns: Vec<String>,
+    /// The commit of the last completed update, if any.
+    #[serde(default)]
+    pub installed_commit: Option<String>,
+    /// The patterns the last completed update was planned with.
+    #[serde(default)]
+    pub installed_patterns: Vec<String>,
+    /// The files written by the last completed update.
+    #[serde(default)]
+    pub installed_files: Vec<String>,
+    /// The commit an unfinished update is moving to.
+    #[serde(default)]
+    pub target_commit: Option<String>,
+    /// The files an unfinished update will write.
+    #[serde(default)]
+    pub planned_files: Vec<String>,
+    /// The planned files already written by an unfinished update.
+    #[serde(default)]
+    pub downloaded_files: Vec<String>,
+    /// Why the most recent update failed, if it did.
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+impl ManagedRecord {
+    pub fn new(
+        repo: String,
+        revision_policy: ManagedRevisionPolicy,
+        patterns: Vec<String>,
+    ) -> Self {
+        Self {
+            repo,
+            revision_policy,
+            patterns,
+            installed_commit: None,
+            installed_patterns: Vec::new(),
+            installed_files: Vec::new(),
+            target_commit: None,
+            planned_files: Vec::new(),
+            downloaded_files: Vec::new(),
+            last_error: None,
+        }
+    }
+}
+
+/// The manifest of managed models and the directory their files live in.
+///
+/// The manifest is a single JSON file rewritten atomically, so a crash leaves
+/// either the previous or the next state on disk. Updates within a process are
+/// serialized so concurrent changes to different models aren't lost.
+pub struct ManagedLibrary {
+    root: PathBuf,
+    storage: Arc<dyn CacheStorage>,
+    lock: Mutex<()>,
+}
+
+impl ManagedLibrary {
+    pub fn new(root: PathBuf, storage: Arc<dyn CacheStorage>) -> Self {
+        Self {
+            root,
+            storage,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Opens the library stored in the managed models area of `layout`.
+    pub fn open(layout: &CacheLayout) -> Self {
+        Self::new(
+            layout
+                .root(CacheArea::ManagedModels)
+                .join(xet_cache::MANAGED_DIR),
+            layout.storage(),
+        )
+    }
+
+    /// Returns the directory holding the files of the model identified by `repo`.
+    pub fn model_dir(&self, repo: &str) -> PathBuf {
+        self.root.join(MODELS_DIR).join(repo.replace('/', "--"))
+    }
+
+    pub fn load(&self) -> io::Result<Vec<ManagedRecord>> {
+        let _guard = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+        self.read()
+    }
+
+    /// Applies `change` to the records and saves the result.
+    pub fn update<T>(&self, change: impl FnOnce(&mut Vec<ManagedRecord>) -> T) -> io::Result<T> {
+        let _guard = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut records = self.read()?;
+        let result = change(&mut records);
+        self.write(&records)?;
+        Ok(result)
+    }
+
+    /// Applies `change` to the record for `repo`, if it is still registered.
+    pub fn update_record(
+        &self,
+        repo: &str,
+        change: impl FnOnce(&mut ManagedRecord),
+    ) -> io::Result<Option<ManagedRecord>> {
+        self.update(|records| {
+            let record = records.iter_mut().find(|record| record.repo == repo)?;
+            change(record);
+            Some(record.clone())
+        })
+    }
+
+    fn read(&self) -> io::Result<Vec<ManagedRecord>> {
+        match self.storage.read(&self.root.join(LIBRARY_FILE)) {
+            Ok(contents) => serde_json::from_slice(&contents).map_err(io::Error::other),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn write(&self, records: &[ManagedRecord]) -> io::Result<()> {
+        let contents = serde_json::to_vec_pretty(records).map_err(io::Error::other)?;
+        self.storage.write(&self.root.join(LIBRARY_FILE), &contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xet_storage::FileSystemStorage;
+    use std::fs;
+
+    #[test]
+    fn records_round_trip_through_the_manifest() {
+        let root = std::env::temp_dir().join(format!("swift-xet-managed-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let library = ManagedLibrary::new(root.clone(), Arc::new(FileSystemStorage));
+        assert!(library.load().unwrap().is_empty());
+
+        library
+            .update(|records| {
+                records.push(ManagedRecord::new(
+                    "models/owner/name".to_string(),
+                    ManagedRevisionPolicy::Track {
+                        revision: "main".to_string(),
+                    },
+                    vec!["*.json".to_string()],
+                ))
+            })
+            .unwrap();
+        let updated = library
+            .update_record("models/owner/name", |record| {
+                record.installed_commit = Some("abc123".to_string());
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated.installed_commit.as_deref(), Some("abc123"));
+
+        let records = ManagedLibrary::new(root.clone(), Arc::new(FileSystemStorage))
+            .load()
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].patterns, vec!["*.json".to_string()]);
+        assert_eq!(records[0].installed_commit.as_deref(), Some("abc123"));
+        assert_eq!(
+            library.model_dir("models/owner/name"),
+            root.join("models").join("models--owner--name")
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -1,13 +1,19 @@
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant};
 
 use hub_client::CasJWTInfo as HubCasJwtInfo;
 use once_cell::sync::Lazy;
-use reqwest::header::{HeaderMap, CONTENT_LENGTH, CONTENT_RANGE, ETAG, LINK, RANGE};
-use reqwest::{redirect::Policy, Client};
+use reqwest::header::{
+    HeaderMap, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, ETAG, LINK, LOCATION,
+    RANGE,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
 
-use crate::{CasJwtInfo, XetError, USER_AGENT};
+use crate::xet_clock::Clock;
+use crate::{xet_faults, xet_region, xet_url};
+use crate::{CasJwtInfo, ErrorCause, HttpErrorKind, XetError};
 
 const HEADER_X_REPO_COMMIT: &str = "x-repo-commit";
 const HEADER_X_XET_HASH: &str = "x-xet-hash";
@@ -23,20 +29,27 @@ const TOKEN_CACHE_SAFETY_WINDOW: Duration = Duration::from_secs(60);
 static TOKEN_CACHE: Lazy<std::sync::Mutex<HashMap<String, CachedToken>>> =
     Lazy::new(|| std::sync::Mutex::new(HashMap::new()));
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct XetFileData {
     pub file_hash: String,
     pub refresh_route: String,
 }
 
 #[allow(dead_code)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FileResolveMetadata {
     pub download_url: String,
     pub etag: String,
     pub commit_hash: String,
     pub size: u64,
+    pub content_type: Option<String>,
     pub xet_file_data: Option<XetFileData>,
+    /// The URL the resolve request redirected to, if it did.
+    #[serde(skip)]
+    pub redirect_target: Option<String>,
+    /// Whether this is an earlier resolution served because the Hub couldn't be reached.
+    #[serde(skip)]
+    pub served_stale: bool,
 }
 
 #[derive(Clone)]
@@ -60,29 +73,22 @@ fn canonical_repo_prefix(repo_type_plural: &str) -> &'static str {
     }
 }
 
-pub async fn fetch_file_metadata(
+/// Builds the URLs a file can be resolved from on `endpoint`, in the order they're tried.
+///
+/// These match the forms `hf_hub_url` and `hf_transfer` use, with the path and
+/// revision escaped the same way in each.
+pub fn resolve_urls(
     endpoint: &str,
     repo_type_plural: &str,
     repo_full_name: &str,
     path: &str,
     revision: &str,
-    token: Option<&String>,
-) -> Result<FileResolveMetadata, XetError> {
-    let metadata_client = Client::builder()
-        .user_agent(USER_AGENT)
-        .redirect(Policy::none())
-        .build()
-        .map_err(|e| XetError::NetworkError {
-            message: format!("Failed to create metadata client: {}", e),
-        })?;
+) -> Vec<String> {
     let endpoint = endpoint.trim_end_matches('/');
-    let encoded_path = urlencoding::encode(path);
-    let encoded_rev = urlencoding::encode(revision);
-
+    let encoded_path = xet_url::encode_path(path);
+    let encoded_rev = xet_url::encode_revision(revision);
     let canonical_prefix = canonical_repo_prefix(repo_type_plural);
-
-    // Try multiple URL formats to match the behavior of hf_transfer / hf_hub_url
-    let candidate_urls = vec![
+    vec![
         // Canonical URL (no /api prefix, repo type only for datasets/spaces)
         format!(
             "{endpoint}/{canonical_prefix}{repo_full_name}/resolve/{encoded_rev}/{encoded_path}"
@@ -95,13 +101,36 @@ pub async fn fetch_file_metadata(
         format!(
             "{endpoint}/api/{repo_type_plural}/{repo_full_name}/resolve/{encoded_path}?revision={encoded_rev}"
         ),
-        // Format 3: direct resolve endpoint (without /api prefix) - legacy fallback
-        format!(
-            "{endpoint}/{repo_full_name}/resolve/{encoded_rev}/{encoded_path}"
-        ),
-    ];
+    ]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn fetch_file_metadata(
+    metadata_client: &Client,
+    endpoint: &str,
+    repo_type_plural: &str,
+    repo_full_name: &str,
+    path: &str,
+    revision: &str,
+    token: Option<&String>,
+    observe: &(dyn Fn(&HeaderMap) + Sync),
+) -> Result<FileResolveMetadata, XetError> {
+    let endpoint = endpoint.trim_end_matches('/');
+
+    // Try multiple URL formats to match the behavior of hf_transfer / hf_hub_url
+    let mut candidate_urls =
+        resolve_urls(endpoint, repo_type_plural, repo_full_name, path, revision);
+    // Format 3: direct resolve endpoint (without /api prefix) - legacy fallback
+    candidate_urls.push(format!(
+        "{}/{}/resolve/{}/{}",
+        endpoint,
+        repo_full_name,
+        xet_url::encode_revision(revision),
+        xet_url::encode_path(path)
+    ));
 
     let mut last_error: Option<String> = None;
+    let mut last_cause: Option<ErrorCause> = None;
 
     for url in candidate_urls {
         // Try HEAD first (more efficient)
@@ -110,18 +139,25 @@ pub async fn fetch_file_metadata(
             head_request = head_request.bearer_auth(token);
         }
 
-        match head_request.send().await {
+        match xet_faults::send(head_request).await {
             Ok(resp) => {
+                observe(resp.headers());
                 let status = resp.status();
                 if status.is_success() || status.is_redirection() {
-                    match parse_metadata_from_response(resp, endpoint) {
+                    match parse_metadata_from_response(&resp, endpoint) {
                         Ok(metadata) => return Ok(metadata),
                         Err(err) => {
                             last_error = Some(err.to_string());
+                            last_cause = None;
                             continue;
                         }
                     }
-                } else if status.is_client_error() {
+                }
+                last_cause = Some(ErrorCause::Http {
+                    kind: HttpErrorKind::Status,
+                    status: Some(status.as_u16()),
+                });
+                if status.is_client_error() {
                     last_error = Some(format!("HEAD request failed with status: {}", status));
                     continue;
                 } else {
@@ -142,18 +178,31 @@ pub async fn fetch_file_metadata(
             get_request = get_request.bearer_auth(token);
         }
 
-        match get_request.send().await {
+        match xet_faults::send(get_request).await {
             Ok(resp) => {
+                observe(resp.headers());
                 let status = resp.status();
                 if status.is_success() || status.is_redirection() {
-                    match parse_metadata_from_response(resp, endpoint) {
+                    let parsed = parse_metadata_from_response(&resp, endpoint);
+                    // Some proxies ignore the range and answer 200 with the
+                    // whole file. Only the headers are wanted, so the response
+                    // is dropped before its body is read, which closes the
+                    // connection rather than downloading the file.
+                    drop(resp);
+                    match parsed {
                         Ok(metadata) => return Ok(metadata),
                         Err(err) => {
                             last_error = Some(err.to_string());
+                            last_cause = None;
                             continue;
                         }
                     }
-                } else if status.is_client_error() {
+                }
+                last_cause = Some(ErrorCause::Http {
+                    kind: HttpErrorKind::Status,
+                    status: Some(status.as_u16()),
+                });
+                if status.is_client_error() {
                     last_error = Some(format!("GET request failed with status: {}", status));
                 } else {
                     last_error =
@@ -162,6 +211,7 @@ pub async fn fetch_file_metadata(
             }
             Err(err) => {
                 last_error = Some(format!("GET request failed: {}", err));
+                last_cause = Some(ErrorCause::from(&err));
             }
         }
     }
@@ -171,6 +221,7 @@ pub async fn fetch_file_metadata(
             "Failed to retrieve HEAD metadata: {}",
             last_error.unwrap_or_else(|| "unknown error".to_string())
         ),
+        cause: last_cause,
     })
 }
 
@@ -178,6 +229,9 @@ pub async fn get_cached_cas_jwt(
     client: &Client,
     refresh_route: &str,
     token: Option<&String>,
+    preferred_endpoint: Option<&str>,
+    clock: &Clock,
+    observe: &(dyn Fn(&HeaderMap) + Sync),
 ) -> Result<Arc<CasJwtInfo>, XetError> {
     if let Some(cached) = get_cached_token(refresh_route) {
         if cached.is_valid() {
@@ -190,45 +244,61 @@ pub async fn get_cached_cas_jwt(
         request = request.bearer_auth(token);
     }
 
-    let response = request
-        .send()
+    let response = xet_faults::send(request)
         .await
         .map_err(|e| XetError::NetworkError {
             message: format!("Failed to fetch CAS JWT: {}", e),
-        })?
+            cause: Some(ErrorCause::from(&e)),
+        })?;
+    observe(response.headers());
+    let response = response
         .error_for_status()
         .map_err(|e| XetError::NetworkError {
             message: format!("Failed to fetch CAS JWT: {}", e),
+            cause: Some(ErrorCause::from(&e)),
         })?;
 
     let headers = response.headers();
     let endpoint =
         header_to_string(headers, HEADER_X_XET_ENDPOINT).ok_or_else(|| XetError::NetworkError {
             message: "CAS endpoint header missing".to_string(),
+            cause: None,
         })?;
     let access_token = header_to_string(headers, HEADER_X_XET_ACCESS_TOKEN).ok_or_else(|| {
         XetError::NetworkError {
             message: "CAS access token header missing".to_string(),
+            cause: None,
         }
     })?;
     let expiration = header_to_string(headers, HEADER_X_XET_EXPIRATION)
         .and_then(|v| v.parse::<u64>().ok())
         .ok_or_else(|| XetError::NetworkError {
             message: "CAS expiration header missing".to_string(),
+            cause: None,
         })?;
 
-    let cas_jwt = Arc::new(CasJwtInfo::from(HubCasJwtInfo {
-        cas_url: endpoint.clone(),
-        exp: expiration,
-        access_token: access_token.clone(),
-    }));
-
-    cache_token(refresh_route.to_string(), cas_jwt.clone());
+    // Measuring happens once per token, so its cost is shared by every
+    // download made before the token expires.
+    let endpoints = xet_region::parse_endpoints(&endpoint);
+    let latencies = xet_region::measure(client, &endpoints).await;
+    let cas_url = xet_region::select(&latencies, preferred_endpoint).unwrap_or(endpoint);
+
+    let cas_jwt = Arc::new(
+        CasJwtInfo::from(HubCasJwtInfo {
+            cas_url,
+            exp: expiration,
+            access_token: access_token.clone(),
+        })
+        .with_endpoint_latencies(latencies)
+        .with_refresh_route(refresh_route.to_string()),
+    );
+
+    cache_token(refresh_route.to_string(), cas_jwt.clone(), clock.now());
     Ok(cas_jwt)
 }
 
 fn parse_metadata_from_response(
-    response: reqwest::Response,
+    response: &reqwest::Response,
     endpoint: &str,
 ) -> Result<FileResolveMetadata, XetError> {
     let headers = response.headers().clone();
@@ -236,24 +306,44 @@ fn parse_metadata_from_response(
     let commit_hash =
         header_to_string(&headers, HEADER_X_REPO_COMMIT).ok_or_else(|| XetError::NetworkError {
             message: "Missing X-Repo-Commit header".to_string(),
+            cause: None,
         })?;
 
     let etag = header_to_string(&headers, HEADER_X_LINKED_ETAG)
         .or_else(|| header_to_string(&headers, ETAG.as_str()))
         .ok_or_else(|| XetError::NetworkError {
             message: "Missing ETag header".to_string(),
+            cause: None,
         })?;
 
     let size = parse_file_size(&headers)?;
 
+    // A redirect's own Content-Type describes the redirect body, not the file.
+    let content_type = if response.status().is_success() {
+        header_to_string(&headers, CONTENT_TYPE.as_str())
+    } else {
+        None
+    };
+
     let xet_file_data = parse_xet_file_data(&headers, endpoint);
 
+    let redirect_target = if response.status().is_redirection() {
+        header_to_string(&headers, LOCATION.as_str())
+            .and_then(|location| response.url().join(&location).ok())
+            .map(String::from)
+    } else {
+        None
+    };
+
     Ok(FileResolveMetadata {
         download_url: response.url().to_string(),
         etag,
         commit_hash,
         size,
+        content_type,
         xet_file_data,
+        redirect_target,
+        served_stale: false,
     })
 }
 
@@ -271,13 +361,19 @@ fn parse_xet_file_data(headers: &HeaderMap, endpoint: &str) -> Option<XetFileDat
 }
 
 fn extract_refresh_route(headers: &HeaderMap, endpoint: &str) -> Option<String> {
-    let link_value = headers.get(LINK)?.to_str().ok()?.to_string();
+    link_with_rel(headers, "xet-auth").map(|route| rewrite_refresh_route(&route, endpoint))
+}
+
+/// Returns the target of the `Link` header entry with relation `rel`, if any.
+pub fn link_with_rel(headers: &HeaderMap, rel: &str) -> Option<String> {
+    let link_value = headers.get(LINK)?.to_str().ok()?;
+    let needle = format!("rel=\"{}\"", rel);
     for fragment in link_value.split(',') {
-        if fragment.to_ascii_lowercase().contains("rel=\"xet-auth\"") {
+        if fragment.to_ascii_lowercase().contains(&needle) {
             if let Some(url_start) = fragment.find('<') {
                 if let Some(url_end) = fragment[url_start + 1..].find('>') {
                     let raw = &fragment[url_start + 1..url_start + 1 + url_end];
-                    return Some(rewrite_refresh_route(raw.trim(), endpoint));
+                    return Some(raw.trim().to_string());
                 }
             }
         }
@@ -315,17 +411,60 @@ fn parse_file_size(headers: &HeaderMap) -> Result<u64, XetError> {
         }
     }
 
-    if let Some(content_length) = header_to_string(headers, CONTENT_LENGTH.as_str()) {
-        if let Ok(length) = content_length.parse::<u64>() {
-            return Ok(length);
+    // An encoded body's length says nothing about the file's.
+    if !headers.contains_key(CONTENT_ENCODING) {
+        if let Some(content_length) = header_to_string(headers, CONTENT_LENGTH.as_str()) {
+            if let Ok(length) = content_length.parse::<u64>() {
+                return Ok(length);
+            }
         }
     }
 
     Err(XetError::NetworkError {
         message: "Missing file size headers in response".to_string(),
+        cause: None,
     })
 }
 
+/// Describes how a content response contradicts the resolution it was
+/// requested for, if it does.
+///
+/// A branch that moves between resolving a file and fetching its content can
+/// serve another commit's file. The Hub names the commit it served on the
+/// responses it answers itself, and every full or partial response reveals the
+/// file's total size, so either can show the move before anything is written.
+pub fn served_drift(
+    metadata: &FileResolveMetadata,
+    status: StatusCode,
+    headers: &HeaderMap,
+) -> Option<String> {
+    if let Some(commit) = header_to_string(headers, HEADER_X_REPO_COMMIT) {
+        if commit != metadata.commit_hash {
+            return Some(format!(
+                "commit {} was served, but {} was resolved",
+                commit, metadata.commit_hash
+            ));
+        }
+    }
+    // An encoded body's length says nothing about the file's.
+    if headers.contains_key(CONTENT_ENCODING) {
+        return None;
+    }
+    let size = if status == StatusCode::PARTIAL_CONTENT {
+        header_to_string(headers, CONTENT_RANGE.as_str())
+            .and_then(|range| parse_total_from_content_range(&range))
+    } else {
+        header_to_string(headers, CONTENT_LENGTH.as_str()).and_then(|len| len.parse().ok())
+    };
+    match size {
+        Some(size) if size != metadata.size => Some(format!(
+            "{} bytes were served, but {} were resolved",
+            size, metadata.size
+        )),
+        _ => None,
+    }
+}
+
 fn parse_total_from_content_range(value: &str) -> Option<u64> {
     let parts: Vec<&str> = value.split('/').collect();
     parts.last()?.parse::<u64>().ok()
@@ -338,9 +477,10 @@ fn get_cached_token(key: &str) -> Option<CachedToken> {
         .and_then(|cache| cache.get(key).cloned())
 }
 
-fn cache_token(key: String, token: Arc<CasJwtInfo>) {
+/// Caches `token` until shortly before it expires, judged from Unix time `now`.
+fn cache_token(key: String, token: Arc<CasJwtInfo>, now: u64) {
     if let Ok(mut cache) = TOKEN_CACHE.lock() {
-        let expiry = compute_cache_expiry(token.exp());
+        let expiry = compute_cache_expiry(token.exp(), now);
         cache.insert(
             key,
             CachedToken {
@@ -351,13 +491,8 @@ fn cache_token(key: String, token: Arc<CasJwtInfo>) {
     }
 }
 
-fn compute_cache_expiry(exp: u64) -> Instant {
-    let now_unix = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|duration| duration.as_secs())
-        .unwrap_or(0);
-
-    let ttl_secs = exp.saturating_sub(now_unix);
+fn compute_cache_expiry(exp: u64, now: u64) -> Instant {
+    let ttl_secs = exp.saturating_sub(now);
     let ttl = Duration::from_secs(ttl_secs);
     Instant::now()
         .checked_add(ttl)
@@ -396,6 +531,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn link_with_rel_selects_matching_entry() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LINK,
+            HeaderValue::from_static(
+                r#"<https://huggingface.co/api/models/foo/tree/main?cursor=abc>; rel="next", <https://huggingface.co/x>; rel="xet-auth""#,
+            ),
+        );
+
+        assert_eq!(
+            link_with_rel(&headers, "next").as_deref(),
+            Some("https://huggingface.co/api/models/foo/tree/main?cursor=abc")
+        );
+        assert!(link_with_rel(&HeaderMap::new(), "next").is_none());
+    }
+
     #[test]
     fn extract_refresh_route_reads_link_header() {
         let mut headers = HeaderMap::new();
@@ -438,7 +590,7 @@ mod tests {
             access_token: "secret".to_string(),
         }));
 
-        cache_token("test".to_string(), token.clone());
+        cache_token("test".to_string(), token.clone(), 1_700_000_000);
         let cached = get_cached_token("test").expect("token should be cached");
         assert!(cached.is_valid());
         assert_eq!(cached.value.access_token(), token.access_token());
@@ -447,4 +599,64 @@ mod tests {
             cache.remove("test");
         }
     }
+
+    #[test]
+    fn served_drift_compares_commit_and_size() {
+        let metadata = FileResolveMetadata {
+            download_url: "https://huggingface.co/foo/resolve/main/a.bin".to_string(),
+            etag: "\"abc\"".to_string(),
+            commit_hash: "1111".to_string(),
+            size: 10,
+            content_type: None,
+            xet_file_data: None,
+            redirect_target: None,
+            served_stale: false,
+        };
+        let headers = |pairs: &[(&'static str, &'static str)]| {
+            let mut headers = HeaderMap::new();
+            for (name, value) in pairs {
+                headers.insert(*name, HeaderValue::from_static(value));
+            }
+            headers
+        };
+
+        let same = headers(&[("x-repo-commit", "1111"), ("content-length", "10")]);
+        assert_eq!(served_drift(&metadata, StatusCode::OK, &same), None);
+        let resumed = headers(&[("content-range", "bytes 4-9/10"), ("content-length", "6")]);
+        assert_eq!(
+            served_drift(&metadata, StatusCode::PARTIAL_CONTENT, &resumed),
+            None
+        );
+
+        let moved = headers(&[("x-repo-commit", "2222"), ("content-length", "10")]);
+        assert!(served_drift(&metadata, StatusCode::OK, &moved).is_some());
+        let resized = headers(&[("content-range", "bytes 4-11/12")]);
+        assert!(served_drift(&metadata, StatusCode::PARTIAL_CONTENT, &resized).is_some());
+        let encoded = headers(&[("content-encoding", "gzip"), ("content-length", "4")]);
+        assert_eq!(served_drift(&metadata, StatusCode::OK, &encoded), None);
+    }
+
+    #[test]
+    fn file_size_survives_an_ignored_range() {
+        let headers = |pairs: &[(&'static str, &'static str)]| {
+            let mut headers = HeaderMap::new();
+            for (name, value) in pairs {
+                headers.insert(*name, HeaderValue::from_static(value));
+            }
+            headers
+        };
+
+        let ranged = headers(&[("content-range", "bytes 0-0/10"), ("content-length", "1")]);
+        assert_eq!(parse_file_size(&ranged).unwrap(), 10);
+        let ignored = headers(&[("content-length", "10")]);
+        assert_eq!(parse_file_size(&ignored).unwrap(), 10);
+        let encoded = headers(&[("content-encoding", "gzip"), ("content-length", "4")]);
+        assert!(parse_file_size(&encoded).is_err());
+        let linked = headers(&[
+            ("x-linked-size", "10"),
+            ("content-encoding", "gzip"),
+            ("content-length", "4"),
+        ]);
+        assert_eq!(parse_file_size(&linked).unwrap(), 10);
+    }
 }
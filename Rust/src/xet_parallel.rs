@@ -0,0 +1,130 @@
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::sync::{Mutex, PoisonError};
+use std::thread;
+
+/// Calls `f` on every item with at most `limit` calls running at once.
+///
+/// The calling thread works through items alongside up to `limit - 1` helper
+/// threads, so a limit of one runs everything in order on the caller. Results
+/// are returned in the order of `items`, whichever call finished first. If a
+/// helper thread can't be started, the remaining threads pick up its share.
+pub fn map_bounded<T, R, F>(items: Vec<T>, limit: usize, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    map_queued(items, limit, VecDeque::pop_front, f)
+}
+
+/// Calls `f` on every item like `map_bounded`, starting items with higher priorities first.
+///
+/// An item's priority is read each time a call finishes and the next item is
+/// chosen, so raising the priority of an item that hasn't started moves it up
+/// the queue. Items with equal priorities start in the order of `items`.
+pub fn map_prioritized<T, R, P, F>(items: Vec<T>, limit: usize, priority: P, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    P: Fn(&T) -> i32 + Sync,
+    F: Fn(T) -> R + Sync,
+{
+    let next = |queue: &mut VecDeque<(usize, T)>| {
+        let position = queue
+            .iter()
+            .enumerate()
+            .max_by_key(|(position, (_, item))| (priority(item), Reverse(*position)))?
+            .0;
+        queue.remove(position)
+    };
+    map_queued(items, limit, next, f)
+}
+
+/// Calls `f` on every item, taking each next item from the queue with `next`.
+fn map_queued<T, R, N, F>(items: Vec<T>, limit: usize, next: N, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    N: Fn(&mut VecDeque<(usize, T)>) -> Option<(usize, T)> + Sync,
+    F: Fn(T) -> R + Sync,
+{
+    let count = items.len();
+    let helpers = limit.max(1).min(count).saturating_sub(1);
+    let queue: Mutex<VecDeque<_>> = Mutex::new(items.into_iter().enumerate().collect());
+    let results: Mutex<Vec<Option<R>>> = Mutex::new((0..count).map(|_| None).collect());
+
+    let work = || loop {
+        let next = next(&mut queue.lock().unwrap_or_else(PoisonError::into_inner));
+        let Some((index, item)) = next else {
+            return;
+        };
+        let result = f(item);
+        results.lock().unwrap_or_else(PoisonError::into_inner)[index] = Some(result);
+    };
+
+    thread::scope(|scope| {
+        for _ in 0..helpers {
+            let _ = thread::Builder::new()
+                .name("xet-batch".to_string())
+                .spawn_scoped(scope, work);
+        }
+        work();
+    });
+
+    results
+        .into_inner()
+        .unwrap_or_else(PoisonError::into_inner)
+        .into_iter()
+        .map(|result| result.expect("every item is processed before the scope ends"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn results_keep_input_order_within_the_limit() {
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let results = map_bounded((0..16).collect(), 3, |n: u64| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(16 - n));
+            running.fetch_sub(1, Ordering::SeqCst);
+            n * 2
+        });
+
+        assert_eq!(results, (0..16).map(|n| n * 2).collect::<Vec<_>>());
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[test]
+    fn a_limit_of_one_runs_on_the_caller() {
+        let caller = thread::current().id();
+        let threads = map_bounded(vec![(); 4], 1, |_| thread::current().id());
+        assert!(threads.iter().all(|id| *id == caller));
+        assert!(map_bounded(Vec::<u8>::new(), 4, |n| n).is_empty());
+    }
+
+    #[test]
+    fn higher_priorities_start_first() {
+        let started = Mutex::new(Vec::new());
+        let priorities = [0, 5, 0, 9, 5];
+        let results = map_prioritized(
+            (0..5).collect(),
+            1,
+            |n: &usize| priorities[*n],
+            |n| {
+                started.lock().unwrap().push(n);
+                n
+            },
+        );
+
+        assert_eq!(results, [0, 1, 2, 3, 4]);
+        assert_eq!(started.into_inner().unwrap(), [3, 1, 4, 0, 2]);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{DownloadPreset, RetryPolicy, XetClientConfig};
+
+/// The throughput limit of the cellular preset, in bytes per second.
+const CELLULAR_BYTES_PER_SECOND: u64 = 2 * 1024 * 1024;
+
+impl DownloadPreset {
+    /// Fills in the options of `config` that were left unset with this preset's values.
+    pub(crate) fn apply(self, config: &mut XetClientConfig) {
+        let (concurrent_downloads, range_gets, read_ahead_chunks) = match self {
+            DownloadPreset::WiFi => (8, 32, 8),
+            DownloadPreset::Cellular => (2, 4, 2),
+            DownloadPreset::Background => (1, 2, 1),
+        };
+        config
+            .max_concurrent_downloads
+            .get_or_insert(concurrent_downloads);
+        config.max_concurrent_range_gets.get_or_insert(range_gets);
+        config.read_ahead_chunks.get_or_insert(read_ahead_chunks);
+
+        match self {
+            DownloadPreset::WiFi => {}
+            DownloadPreset::Cellular => {
+                config
+                    .max_download_bytes_per_second
+                    .get_or_insert(CELLULAR_BYTES_PER_SECOND);
+            }
+            DownloadPreset::Background => {
+                // Background work outlives flaky connections by waiting longer
+                // between more attempts, and gives up its threads between them.
+                config.idle_timeout_secs.get_or_insert(30);
+                config.retry_policy.get_or_insert(RetryPolicy {
+                    max_attempts: 10,
+                    base_delay_ms: 2_000,
+                    max_delay_ms: 60_000,
+                    ..RetryPolicy::default()
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_only_fill_unset_options() {
+        let mut config = XetClientConfig {
+            max_concurrent_downloads: Some(3),
+            ..XetClientConfig::default()
+        };
+        DownloadPreset::Cellular.apply(&mut config);
+        assert_eq!(config.max_concurrent_downloads, Some(3));
+        assert_eq!(config.read_ahead_chunks, Some(2));
+        assert_eq!(config.max_concurrent_range_gets, Some(4));
+        assert_eq!(
+            config.max_download_bytes_per_second,
+            Some(CELLULAR_BYTES_PER_SECOND)
+        );
+
+        let mut config = XetClientConfig::default();
+        DownloadPreset::WiFi.apply(&mut config);
+        assert_eq!(config.max_download_bytes_per_second, None);
+        assert_eq!(config.retry_policy, None);
+
+        let mut config = XetClientConfig::default();
+        DownloadPreset::Background.apply(&mut config);
+        assert_eq!(config.max_concurrent_downloads, Some(1));
+        assert!(config.retry_policy.unwrap().max_attempts > 3);
+    }
+}
@@ -0,0 +1,156 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+
+use crate::RateLimitStatus;
+
+const HEADER_X_RATELIMIT_LIMIT: &str = "x-ratelimit-limit";
+const HEADER_X_RATELIMIT_REMAINING: &str = "x-ratelimit-remaining";
+const HEADER_X_RATELIMIT_RESET: &str = "x-ratelimit-reset";
+const HEADER_RATELIMIT: &str = "ratelimit";
+const HEADER_RATELIMIT_POLICY: &str = "ratelimit-policy";
+const HEADER_RETRY_AFTER: &str = "retry-after";
+
+/// Reset values at or above this are Unix timestamps rather than a number of seconds.
+const RESET_EPOCH_THRESHOLD: u64 = 1_000_000_000;
+
+/// Reads rate-limit headers from a Hub response, if it has any.
+///
+/// Both the `x-ratelimit-*` headers and the structured `RateLimit` and
+/// `RateLimit-Policy` headers are understood. `now` is the current Unix time
+/// in seconds, used to turn relative reset values into timestamps.
+pub fn parse_rate_limit(headers: &HeaderMap, now: u64) -> Option<RateLimitStatus> {
+    let mut limit = header_u64(headers, HEADER_X_RATELIMIT_LIMIT);
+    let mut remaining = header_u64(headers, HEADER_X_RATELIMIT_REMAINING);
+    let mut reset = header_u64(headers, HEADER_X_RATELIMIT_RESET);
+
+    if let Some(value) = header_str(headers, HEADER_RATELIMIT) {
+        remaining = remaining.or_else(|| structured_param(value, "r"));
+        reset = reset.or_else(|| structured_param(value, "t"));
+    }
+    if let Some(value) = header_str(headers, HEADER_RATELIMIT_POLICY) {
+        limit = limit.or_else(|| structured_param(value, "q"));
+    }
+
+    if limit.is_none() && remaining.is_none() && reset.is_none() {
+        return None;
+    }
+
+    let reset_at = reset.map(|reset| {
+        if reset >= RESET_EPOCH_THRESHOLD {
+            reset
+        } else {
+            now.saturating_add(reset)
+        }
+    });
+
+    Some(RateLimitStatus {
+        limit,
+        remaining,
+        reset_at,
+    })
+}
+
+/// Reads the number of seconds a `Retry-After` header asks clients to wait.
+///
+/// The Hub sends the delay in seconds; the HTTP-date form isn't understood.
+pub fn parse_retry_after(headers: &HeaderMap) -> Option<u64> {
+    header_u64(headers, HEADER_RETRY_AFTER)
+}
+
+/// Returns the current Unix time in seconds.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    header_str(headers, name).and_then(|value| value.trim().parse().ok())
+}
+
+/// Returns a numeric parameter such as `r=42` from a structured header like `"api";r=42;t=30`.
+fn structured_param(value: &str, name: &str) -> Option<u64> {
+    value
+        .split([';', ','])
+        .filter_map(|part| part.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .and_then(|(_, value)| value.trim().parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn parses_x_ratelimit_headers() {
+        let status = parse_rate_limit(
+            &headers(&[
+                ("x-ratelimit-limit", "1000"),
+                ("x-ratelimit-remaining", "998"),
+                ("x-ratelimit-reset", "60"),
+            ]),
+            NOW,
+        )
+        .unwrap();
+        assert_eq!(status.limit, Some(1000));
+        assert_eq!(status.remaining, Some(998));
+        assert_eq!(status.reset_at, Some(NOW + 60));
+
+        let absolute =
+            parse_rate_limit(&headers(&[("x-ratelimit-reset", "1700000300")]), NOW).unwrap();
+        assert_eq!(absolute.reset_at, Some(1_700_000_300));
+    }
+
+    #[test]
+    fn parses_structured_ratelimit_headers() {
+        let status = parse_rate_limit(
+            &headers(&[
+                ("ratelimit", "\"api\";r=489;t=89"),
+                ("ratelimit-policy", "\"fixed window\";\"api\";q=500;w=300"),
+            ]),
+            NOW,
+        )
+        .unwrap();
+        assert_eq!(status.limit, Some(500));
+        assert_eq!(status.remaining, Some(489));
+        assert_eq!(status.reset_at, Some(NOW + 89));
+    }
+
+    #[test]
+    fn ignores_responses_without_rate_limit_headers() {
+        assert!(parse_rate_limit(&headers(&[("content-type", "application/json")]), NOW).is_none());
+    }
+
+    #[test]
+    fn parses_retry_after_seconds() {
+        assert_eq!(
+            parse_retry_after(&headers(&[("retry-after", "120")])),
+            Some(120)
+        );
+        assert_eq!(
+            parse_retry_after(&headers(&[(
+                "retry-after",
+                "Wed, 21 Oct 2015 07:28:00 GMT"
+            )])),
+            None
+        );
+        assert_eq!(parse_retry_after(&headers(&[])), None);
+    }
+}
@@ -0,0 +1,104 @@
+use std::future::Future;
+
+use tokio::runtime::Handle;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::XetError;
+
+/// The number of chunks prefetched when the client configuration doesn't set one.
+pub const DEFAULT_READ_AHEAD_CHUNKS: u32 = 4;
+
+/// Fetches the chunks of a sequential read ahead of its consumer.
+///
+/// At most `window` fetched chunks wait in memory at any time; once the window is
+/// full the producer pauses until the consumer takes one. This lets network
+/// transfer overlap the consumer's own work without buffering the whole file.
+pub struct ReadAhead {
+    receiver: mpsc::Receiver<Result<Vec<u8>, XetError>>,
+    task: JoinHandle<()>,
+}
+
+impl ReadAhead {
+    /// Starts prefetching on `handle`.
+    ///
+    /// `fetch` is called with increasing chunk indices and returns `None` after
+    /// the last chunk. A window of zero is treated as one.
+    pub fn spawn<F, Fut>(handle: &Handle, window: u32, mut fetch: F) -> Self
+    where
+        F: FnMut(u64) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Option<Vec<u8>>, XetError>> + Send,
+    {
+        let (sender, receiver) = mpsc::channel(window.max(1) as usize);
+        let task = handle.spawn(async move {
+            let mut index = 0;
+            loop {
+                let result = fetch(index).await;
+                let finished = !matches!(result, Ok(Some(_)));
+                let item = match result {
+                    Ok(Some(chunk)) => Ok(chunk),
+                    Ok(None) => return,
+                    Err(e) => Err(e),
+                };
+                // The consumer dropping the reader ends prefetching.
+                if sender.send(item).await.is_err() || finished {
+                    return;
+                }
+                index += 1;
+            }
+        });
+
+        Self { receiver, task }
+    }
+
+    /// Returns the next chunk, or `None` once every chunk has been consumed.
+    pub async fn next_chunk(&mut self) -> Option<Result<Vec<u8>, XetError>> {
+        self.receiver.recv().await
+    }
+}
+
+impl Drop for ReadAhead {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn read_ahead_stays_within_window() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let fetched = Arc::new(AtomicU64::new(0));
+
+        let mut reader = {
+            let fetched = fetched.clone();
+            ReadAhead::spawn(runtime.handle(), 2, move |index| {
+                let fetched = fetched.clone();
+                async move {
+                    if index == 10 {
+                        return Ok(None);
+                    }
+                    fetched.fetch_add(1, Ordering::SeqCst);
+                    Ok(Some(vec![index as u8]))
+                }
+            })
+        };
+
+        runtime.block_on(async {
+            let mut consumed = 0u64;
+            while let Some(chunk) = reader.next_chunk().await {
+                assert_eq!(chunk.unwrap(), vec![consumed as u8]);
+                consumed += 1;
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                // Two chunks may wait in the window, plus one blocked on sending.
+                assert!(fetched.load(Ordering::SeqCst) <= consumed + 3);
+            }
+            assert_eq!(consumed, 10);
+        });
+    }
+}
@@ -0,0 +1,622 @@
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use reqwest::Client;
+use serde::Deserialize;
+use tokio::task::JoinSet;
+
+use crate::xet_cache::{self, CacheLayout};
+use crate::xet_faults;
+use crate::xet_resume::{covers, insert_range, PartialDownload};
+use crate::xet_scheduler::Scheduler;
+use crate::xet_transfer::TransferObserver;
+use crate::xet_verify::PrefixHasher;
+use crate::{CacheArea, XetError};
+
+/// The size of the header preceding each chunk in a xorb.
+const CHUNK_HEADER_SIZE: usize = 8;
+
+/// How a file is rebuilt from chunks stored in xorbs, as returned by CAS.
+#[derive(Deserialize)]
+pub struct Reconstruction {
+    /// The bytes of the first term that precede the requested range, which
+    /// is zero when the whole file is requested.
+    #[serde(default)]
+    offset_into_first_range: u64,
+    terms: Vec<Term>,
+    fetch_info: HashMap<String, Vec<FetchInfo>>,
+}
+
+/// A run of chunks in one xorb, in the order they appear in the file.
+#[derive(Clone, Deserialize)]
+struct Term {
+    hash: String,
+    unpacked_length: u64,
+    range: ChunkRange,
+}
+
+/// Where a run of chunks in a xorb can be fetched from.
+#[derive(Clone, Deserialize)]
+struct FetchInfo {
+    range: ChunkRange,
+    url: String,
+    url_range: ByteRange,
+}
+
+/// Chunk indices within a xorb, excluding `end`.
+#[derive(Clone, Copy, Deserialize)]
+struct ChunkRange {
+    start: u64,
+    end: u64,
+}
+
+/// Byte offsets within a xorb, including `end`.
+#[derive(Clone, Copy, Deserialize)]
+struct ByteRange {
+    start: u64,
+    end: u64,
+}
+
+/// A term, where it's written in the file, and where it's fetched from.
+struct Span {
+    offset: u64,
+    /// The leading bytes of the term that aren't part of the file.
+    skip: u64,
+    term: Term,
+    fetch: FetchInfo,
+}
+
+impl Span {
+    fn len(&self) -> u64 {
+        self.term.unpacked_length - self.skip
+    }
+}
+
+/// Terms kept in the chunk area of the cache, so content shared by several
+/// files, or by revisions of one, is fetched once.
+///
+/// The Xet data crate's chunk cache is only read and filled by its own
+/// downloader, so reconstructions run by this client keep their terms here.
+/// A term is stored decompressed under its xorb hash and chunk range, which
+/// identify its contents, so an entry never goes stale; cache maintenance
+/// evicts the least recently used ones with the rest of the chunk area.
+#[derive(Clone)]
+pub struct TermCache {
+    root: PathBuf,
+}
+
+impl TermCache {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Opens the term cache in the chunk area of `layout`.
+    pub fn open(layout: &CacheLayout) -> Self {
+        Self::new(
+            layout
+                .root(CacheArea::Chunks)
+                .join(xet_cache::TERM_CACHE_DIR),
+        )
+    }
+
+    /// Returns the contents of `term`, if they're cached and intact.
+    fn get(&self, term: &Term) -> Option<Vec<u8>> {
+        let path = self.path(term)?;
+        let data = fs::read(&path).ok()?;
+        if data.len() as u64 != term.unpacked_length {
+            return None;
+        }
+        // Eviction goes by modification time, so a hit counts as a use.
+        if let Ok(file) = File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(data)
+    }
+
+    /// Stores the contents of `term`, replacing any earlier copy at once.
+    fn put(&self, term: &Term, data: &[u8]) -> io::Result<()> {
+        let Some(path) = self.path(term) else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_extension("tmp");
+        fs::write(&temp_path, data)?;
+        fs::rename(&temp_path, &path)
+    }
+
+    /// Returns where `term` is stored, or `None` if its xorb hash can't name a directory.
+    fn path(&self, term: &Term) -> Option<PathBuf> {
+        if term.hash.is_empty() || !term.hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let name = format!("{}-{}", term.range.start, term.range.end);
+        Some(self.root.join(&term.hash).join(name))
+    }
+}
+
+/// Asks CAS how to reconstruct the file with `file_hash`.
+pub async fn query(
+    client: &Client,
+    cas_url: &str,
+    token: &str,
+    file_hash: &str,
+) -> Result<Reconstruction, XetError> {
+    let url = format!(
+        "{}/v1/reconstructions/{}",
+        cas_url.trim_end_matches('/'),
+        file_hash
+    );
+    let request = client.get(url).bearer_auth(token);
+    let response = xet_faults::send(request).await?.error_for_status()?;
+    Ok(response.json().await?)
+}
+
+/// Writes the terms of `reconstruction` that earlier attempts didn't into `partial`.
+///
+/// Each term is flushed to disk and recorded beside the partial file as soon
+/// as it's written, so a failed attempt, or a process that exits partway,
+/// loses no more than the terms in flight. Pauses and cancellation are checked
+/// between terms.
+///
+/// Terms found in `cache` are written first, without fetching them. Each of
+/// the rest is fetched with a slot from `scheduler`, so the number of terms
+/// in flight adapts to the client's other downloads, and stored in `cache`
+/// once fetched. Fetched terms wait in memory until they're written, and a
+/// term can span a whole xorb, so the scheduler's budget also bounds memory
+/// use. Fetched bytes are accounted to `observer` as they arrive, holding the
+/// download to the client's throughput limit.
+///
+/// With a `hasher`, the file is hashed as the terms written so far join onto
+/// its start, so verifying it doesn't wait for the whole file.
+#[allow(clippy::too_many_arguments)]
+pub async fn download(
+    client: &Client,
+    scheduler: &Scheduler,
+    cache: &TermCache,
+    reconstruction: Reconstruction,
+    file_hash: &str,
+    size: u64,
+    partial: &PartialDownload,
+    observer: &TransferObserver,
+    hasher: Option<&mut PrefixHasher>,
+) -> Result<(), XetError> {
+    let spans = reconstruction.spans(size)?;
+    let written = partial.written_ranges(file_hash, size)?;
+    let completed: u64 = written.iter().map(|(start, end)| end - start).sum();
+    observer.report(completed, size);
+    let mut writer = TermWriter {
+        file: OpenOptions::new().write(true).open(partial.path())?,
+        partial,
+        file_hash,
+        size,
+        written,
+        completed,
+        observer,
+        hasher,
+    };
+
+    let mut missing = Vec::new();
+    for span in spans {
+        if covers(&writer.written, span.offset, span.len()) {
+            continue;
+        }
+        match cache.get(&span.term) {
+            Some(data) => {
+                observer.checkpoint().await?;
+                writer.write(span.offset, &data[span.skip as usize..])?;
+            }
+            None => missing.push(span),
+        }
+    }
+
+    let mut missing = missing.into_iter();
+    let mut tasks = JoinSet::new();
+    loop {
+        while !missing.as_slice().is_empty() {
+            // Only wait for a slot with nothing in flight, so the download
+            // always makes progress without holding up other downloads.
+            let slot = if tasks.is_empty() {
+                scheduler.acquire().await
+            } else if let Some(slot) = scheduler.try_acquire() {
+                slot
+            } else {
+                break;
+            };
+            let Some(span) = missing.next() else { break };
+            let (client, cache, observer) = (client.clone(), cache.clone(), observer.clone());
+            tasks.spawn(xet_faults::inherit(async move {
+                let data = fetch_term(&client, &span.term, &span.fetch, &observer).await;
+                drop(slot);
+                let data = data?;
+                // The cache only saves refetching, so failing to fill it is harmless.
+                let _ = cache.put(&span.term, &data);
+                Ok::<_, XetError>((span.offset, span.skip, data))
+            }));
+        }
+        let Some(joined) = tasks.join_next().await else {
+            break;
+        };
+        let (offset, skip, data) = joined.map_err(|e| XetError::OperationFailed {
+            message: format!("Reconstruction task failed: {}", e),
+            cause: None,
+        })??;
+        observer.checkpoint().await?;
+        writer.write(offset, &data[skip as usize..])?;
+    }
+    Ok(())
+}
+
+/// Writes terms into their place in a partial file, recording each as it lands.
+struct TermWriter<'a> {
+    file: File,
+    partial: &'a PartialDownload,
+    file_hash: &'a str,
+    size: u64,
+    written: Vec<(u64, u64)>,
+    completed: u64,
+    observer: &'a TransferObserver,
+    hasher: Option<&'a mut PrefixHasher>,
+}
+
+impl TermWriter<'_> {
+    fn write(&mut self, offset: u64, data: &[u8]) -> Result<(), XetError> {
+        self.file.seek(SeekFrom::Start(offset))?;
+        self.file.write_all(data)?;
+        self.file.sync_data()?;
+        let len = data.len() as u64;
+        insert_range(&mut self.written, offset, offset + len);
+        self.partial
+            .record_written(self.file_hash, self.size, &self.written)?;
+        if let Some(hasher) = self.hasher.as_deref_mut() {
+            hasher.advance(&self.written)?;
+        }
+        self.completed += len;
+        self.observer.report(self.completed, self.size);
+        Ok(())
+    }
+}
+
+impl Reconstruction {
+    /// Places each term in the file and finds where to fetch it from.
+    fn spans(self, size: u64) -> Result<Vec<Span>, XetError> {
+        let mut offset = 0;
+        let mut skip = self.offset_into_first_range;
+        let mut spans = Vec::with_capacity(self.terms.len());
+        for term in self.terms {
+            let fetch = self
+                .fetch_info
+                .get(&term.hash)
+                .and_then(|fetches| {
+                    fetches.iter().find(|fetch| {
+                        fetch.range.start <= term.range.start && term.range.end <= fetch.range.end
+                    })
+                })
+                .cloned()
+                .ok_or_else(|| malformed(format!("no fetch location for xorb {}", term.hash)))?;
+            if skip > term.unpacked_length {
+                return Err(malformed(format!(
+                    "offset {} is past the end of the first term",
+                    skip
+                )));
+            }
+            let span = Span {
+                offset,
+                skip,
+                term,
+                fetch,
+            };
+            offset += span.len();
+            spans.push(span);
+            skip = 0;
+        }
+        if offset != size {
+            return Err(malformed(format!(
+                "terms total {} bytes, but the file is {} bytes",
+                offset, size
+            )));
+        }
+        Ok(spans)
+    }
+}
+
+/// Fetches the chunks of `term` and returns their decompressed contents.
+async fn fetch_term(
+    client: &Client,
+    term: &Term,
+    fetch: &FetchInfo,
+    observer: &TransferObserver,
+) -> Result<Vec<u8>, XetError> {
+    let range = format!("bytes={}-{}", fetch.url_range.start, fetch.url_range.end);
+    let request = client.get(&fetch.url).header(reqwest::header::RANGE, range);
+    let mut response = xet_faults::send(request).await?.error_for_status()?;
+    let mut xorb = Vec::new();
+    while let Some(bytes) = response.chunk().await? {
+        observer.account(bytes.len() as u64).await;
+        xorb.extend_from_slice(&bytes);
+    }
+
+    // The fetched range can hold chunks before and after the term's.
+    let skip = (term.range.start - fetch.range.start) as usize;
+    let take = (term.range.end - term.range.start) as usize;
+    let mut data = Vec::with_capacity(term.unpacked_length as usize);
+    let mut remaining = &xorb[..];
+    for index in 0..skip + take {
+        let (chunk, rest) = split_chunk(remaining)?;
+        if index >= skip {
+            decompress(chunk, &mut data)?;
+        }
+        remaining = rest;
+    }
+    if data.len() as u64 != term.unpacked_length {
+        return Err(malformed(format!(
+            "term of xorb {} is {} bytes, expected {}",
+            term.hash,
+            data.len(),
+            term.unpacked_length
+        )));
+    }
+    Ok(data)
+}
+
+/// A chunk's header fields and its still-compressed contents.
+struct Chunk<'a> {
+    scheme: u8,
+    uncompressed_len: usize,
+    contents: &'a [u8],
+}
+
+/// Splits the first chunk off `xorb`.
+///
+/// Each chunk starts with a version byte, a 3-byte compressed length, a
+/// compression scheme byte, and a 3-byte uncompressed length, all little-endian.
+fn split_chunk(xorb: &[u8]) -> Result<(Chunk<'_>, &[u8]), XetError> {
+    let header = xorb
+        .get(..CHUNK_HEADER_SIZE)
+        .ok_or_else(|| malformed("truncated chunk header".to_string()))?;
+    if header[0] != 0 {
+        return Err(malformed(format!(
+            "unsupported chunk version {}",
+            header[0]
+        )));
+    }
+    let u24 = |bytes: &[u8]| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]) as usize;
+    let compressed_len = u24(&header[1..4]);
+    let end = CHUNK_HEADER_SIZE + compressed_len;
+    let contents = xorb
+        .get(CHUNK_HEADER_SIZE..end)
+        .ok_or_else(|| malformed("truncated chunk".to_string()))?;
+    let chunk = Chunk {
+        scheme: header[4],
+        uncompressed_len: u24(&header[5..8]),
+        contents,
+    };
+    Ok((chunk, &xorb[end..]))
+}
+
+/// Appends the decompressed contents of `chunk` to `out`.
+fn decompress(chunk: Chunk, out: &mut Vec<u8>) -> Result<(), XetError> {
+    let start = out.len();
+    match chunk.scheme {
+        0 => out.extend_from_slice(chunk.contents),
+        1 => {
+            lz4_flex::frame::FrameDecoder::new(chunk.contents).read_to_end(out)?;
+        }
+        2 => {
+            let mut grouped = Vec::with_capacity(chunk.uncompressed_len);
+            lz4_flex::frame::FrameDecoder::new(chunk.contents).read_to_end(&mut grouped)?;
+            out.extend(regroup(&grouped));
+        }
+        scheme => {
+            return Err(malformed(format!(
+                "unsupported compression scheme {}",
+                scheme
+            )));
+        }
+    }
+    if out.len() - start != chunk.uncompressed_len {
+        return Err(malformed("chunk has the wrong length".to_string()));
+    }
+    Ok(())
+}
+
+/// Undoes byte grouping, which stores every fourth byte together so that
+/// floating-point data compresses better.
+///
+/// The first `len % 4` groups are a byte longer than the rest.
+fn regroup(grouped: &[u8]) -> Vec<u8> {
+    let len = grouped.len();
+    let mut starts = [0; 4];
+    for group in 1..4 {
+        let previous_len = len / 4 + usize::from(group - 1 < len % 4);
+        starts[group] = starts[group - 1] + previous_len;
+    }
+    (0..len).map(|i| grouped[starts[i % 4] + i / 4]).collect()
+}
+
+fn malformed(detail: String) -> XetError {
+    XetError::OperationFailed {
+        message: format!("Malformed reconstruction: {}", detail),
+        cause: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufRead;
+    use std::net::TcpListener;
+
+    fn chunk(scheme: u8, contents: &[u8], uncompressed_len: usize) -> Vec<u8> {
+        let mut chunk = vec![0];
+        chunk.extend_from_slice(&(contents.len() as u32).to_le_bytes()[..3]);
+        chunk.push(scheme);
+        chunk.extend_from_slice(&(uncompressed_len as u32).to_le_bytes()[..3]);
+        chunk.extend_from_slice(contents);
+        chunk
+    }
+
+    fn lz4(data: &[u8]) -> Vec<u8> {
+        let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn chunks_decompress_in_every_scheme() {
+        let data: Vec<u8> = (0..23).collect();
+        // Grouped: bytes 0, 4, 8, ... then 1, 5, 9, ... and so on.
+        let grouped: Vec<u8> = (0..4)
+            .flat_map(|group| data.iter().copied().skip(group).step_by(4))
+            .collect();
+        assert_eq!(regroup(&grouped), data);
+
+        let mut xorb = chunk(0, b"raw", 3);
+        xorb.extend(chunk(1, &lz4(&data), data.len()));
+        xorb.extend(chunk(2, &lz4(&grouped), data.len()));
+
+        let mut out = Vec::new();
+        let mut remaining = &xorb[..];
+        while !remaining.is_empty() {
+            let (chunk, rest) = split_chunk(remaining).unwrap();
+            decompress(chunk, &mut out).unwrap();
+            remaining = rest;
+        }
+        assert_eq!(&out[..3], b"raw");
+        assert_eq!(&out[3..26], &data[..]);
+        assert_eq!(&out[26..], &data[..]);
+
+        assert!(split_chunk(&xorb[..5]).is_err());
+        assert!(decompress(split_chunk(&chunk(9, b"x", 1)).unwrap().0, &mut out).is_err());
+    }
+
+    #[test]
+    fn files_are_reconstructed_from_cas_and_share_cached_terms() {
+        let data: Vec<u8> = (0..23).collect();
+        let grouped: Vec<u8> = (0..4)
+            .flat_map(|group| data.iter().copied().skip(group).step_by(4))
+            .collect();
+        let mut xorb = chunk(0, b"hello, ", 7);
+        xorb.extend(chunk(1, &lz4(b"world"), 5));
+        xorb.extend(chunk(2, &lz4(&grouped), data.len()));
+        let xorb_hash = "ab".repeat(32);
+        let range = format!("range: bytes=0-{}", xorb.len() - 1);
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        // Responses follow the CAS wire format: terms in file order, each a
+        // chunk range of a xorb, and the byte ranges holding those chunks.
+        let reconstruction = {
+            let (url, xorb_hash, last) = (url.clone(), xorb_hash.clone(), xorb.len() - 1);
+            move |offset: u64, terms: &[(u64, u64, usize)]| {
+                let terms: Vec<String> = terms
+                    .iter()
+                    .map(|(start, end, len)| {
+                        format!(
+                            r#"{{"hash":"{}","unpacked_length":{},"range":{{"start":{},"end":{}}}}}"#,
+                            xorb_hash, len, start, end
+                        )
+                    })
+                    .collect();
+                format!(
+                    r#"{{"offset_into_first_range":{},"terms":[{}],"fetch_info":{{"{}":[{{"range":{{"start":0,"end":3}},"url":"{}/xorbs/{}","url_range":{{"start":0,"end":{}}}}}]}}}}"#,
+                    offset,
+                    terms.join(","),
+                    xorb_hash,
+                    url,
+                    xorb_hash,
+                    last
+                )
+                .into_bytes()
+            }
+        };
+        let first = reconstruction(0, &[(1, 3, 28), (0, 2, 12)]);
+        let second = reconstruction(5, &[(1, 3, 28)]);
+        let server = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for stream in listener.incoming().take(4) {
+                let mut stream = stream.unwrap();
+                let mut reader = io::BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                while !request.ends_with("\r\n\r\n") {
+                    if reader.read_line(&mut request).unwrap() == 0 {
+                        break;
+                    }
+                }
+                let body = if request.starts_with(&format!("GET /xorbs/{}", xorb_hash)) {
+                    xorb.clone()
+                } else if request.contains(&"1".repeat(64)) {
+                    first.clone()
+                } else {
+                    second.clone()
+                };
+                let head = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                stream.write_all(head.as_bytes()).unwrap();
+                stream.write_all(&body).unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+
+        let dir = std::env::temp_dir().join(format!("swift-xet-terms-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let cache = TermCache::new(dir.join("terms"));
+        let client = Client::new();
+        let scheduler = Scheduler::new(None);
+        let observer = TransferObserver::default();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let reconstruct = |file_hash: &str, size: u64, name: &str| {
+            let partial = PartialDownload::new(&dir.join(name));
+            runtime
+                .block_on(async {
+                    let reconstruction = query(&client, &url, "token", file_hash).await?;
+                    download(
+                        &client,
+                        &scheduler,
+                        &cache,
+                        reconstruction,
+                        file_hash,
+                        size,
+                        &partial,
+                        &observer,
+                        None,
+                    )
+                    .await
+                })
+                .unwrap();
+            partial.finish().unwrap();
+            fs::read(dir.join(name)).unwrap()
+        };
+
+        let mut expected = b"world".to_vec();
+        expected.extend(&data);
+        expected.extend(b"hello, world");
+        assert_eq!(reconstruct(&"1".repeat(64), 40, "first"), expected);
+        // The second file starts partway into a term the first one cached.
+        assert_eq!(reconstruct(&"2".repeat(64), 23, "second"), data);
+
+        let requests = server.join().unwrap();
+        let fetches: Vec<&String> = requests
+            .iter()
+            .filter(|request| request.starts_with("GET /xorbs/"))
+            .collect();
+        assert_eq!(fetches.len(), 2);
+        assert!(fetches
+            .iter()
+            .all(|request| request.to_lowercase().contains(&range)));
+        assert!(requests[0].starts_with(&format!("GET /v1/reconstructions/{}", "1".repeat(64))));
+        assert!(requests[3].starts_with(&format!("GET /v1/reconstructions/{}", "2".repeat(64))));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,366 @@
+use std::fs::File;
+use std::future::Future;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Instant;
+
+use reqwest::header::{HeaderMap, RANGE};
+use reqwest::{Request, Response, Url};
+use serde_json::{json, Map, Value};
+
+#[cfg(feature = "fault-injection")]
+use crate::{ErrorCause, FaultKind, SimulatedFault, XetError};
+
+/// The response headers a recording keeps; credentials and cookies never appear in it.
+const RECORDED_HEADERS: [&str; 19] = [
+    "content-length",
+    "content-range",
+    "content-type",
+    "date",
+    "etag",
+    "link",
+    "location",
+    "ratelimit",
+    "ratelimit-policy",
+    "retry-after",
+    "x-amz-cf-pop",
+    "x-cache",
+    "x-error-code",
+    "x-linked-etag",
+    "x-linked-size",
+    "x-repo-commit",
+    "x-request-id",
+    "x-xet-hash",
+    "x-xet-refresh-route",
+];
+
+/// Query parameters that sign a URL or carry a credential, whose values are redacted.
+const SIGNING_PARAMETERS: [&str; 6] = [
+    "credential",
+    "key-pair-id",
+    "policy",
+    "signature",
+    "token",
+    "x-amz-security-token",
+];
+
+/// How long a recorded request must have taken for its latency to be replayed.
+///
+/// Faster requests are within what the maintainer's own network adds.
+#[cfg(feature = "fault-injection")]
+const SLOW_REQUEST_MILLIS: u64 = 500;
+
+tokio::task_local! {
+    /// The recorder of the client call running on this task.
+    static CURRENT: Arc<SessionRecorder>;
+}
+
+/// Logs the requests a client sends to a file, so network problems seen in
+/// the field can be reported and replayed.
+///
+/// A recording is a file of JSON lines. The first describes the environment
+/// it was made in, and each after it a request: its method and URL, the
+/// response status or the kind of error it failed with, a subset of the
+/// response headers, and how long the response took to arrive. Signatures and
+/// credentials in URLs are redacted, and request headers, which carry the
+/// token, aren't recorded. As with simulated faults, transfers the Xet data
+/// crate makes over its own connections aren't recorded.
+#[derive(Default)]
+pub struct SessionRecorder {
+    recording: Mutex<Option<Recording>>,
+}
+
+struct Recording {
+    file: File,
+    started: Instant,
+}
+
+/// A request being recorded, until its response arrives.
+pub struct PendingRequest {
+    method: String,
+    url: String,
+    range: Option<String>,
+    sent: Instant,
+}
+
+impl SessionRecorder {
+    /// Starts recording to `path`, replacing the file and any recording in progress.
+    pub fn start(&self, path: &Path, environment: Value) -> io::Result<()> {
+        let mut file = File::create(path)?;
+        let mut header = json!({ "type": "session" });
+        if let (Some(header), Value::Object(environment)) = (header.as_object_mut(), environment) {
+            header.extend(environment);
+        }
+        writeln!(file, "{}", header)?;
+        *self.lock() = Some(Recording {
+            file,
+            started: Instant::now(),
+        });
+        Ok(())
+    }
+
+    /// Stops recording, returning whether a recording was in progress.
+    pub fn stop(&self) -> bool {
+        self.lock().take().is_some()
+    }
+
+    /// Runs `future` with the requests it sends recorded while a recording is in progress.
+    pub fn scope<F: Future>(self: &Arc<Self>, future: F) -> impl Future<Output = F::Output> {
+        CURRENT.scope(self.clone(), future)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<Recording>> {
+        self.recording
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn finish(&self, request: PendingRequest, result: &reqwest::Result<Response>) {
+        let elapsed_ms = request.sent.elapsed().as_millis() as u64;
+        let mut guard = self.lock();
+        let Some(recording) = guard.as_mut() else {
+            return;
+        };
+        let (status, error, headers) = match result {
+            Ok(response) => (
+                Some(response.status().as_u16()),
+                None,
+                recorded_headers(response.headers()),
+            ),
+            Err(error) => (None, Some(error_kind(error)), Map::new()),
+        };
+        let entry = json!({
+            "type": "request",
+            "at_ms": request.sent.duration_since(recording.started).as_millis() as u64,
+            "method": request.method,
+            "url": request.url,
+            "range": request.range,
+            "status": status,
+            "error": error,
+            "elapsed_ms": elapsed_ms,
+            "headers": headers,
+        });
+        // A recording that can't be written is abandoned rather than failing requests.
+        if writeln!(recording.file, "{}", entry).is_err() {
+            *guard = None;
+        }
+    }
+}
+
+/// Begins recording `request` if the calling task's client is recording.
+pub fn begin(request: &Request) -> Option<(Arc<SessionRecorder>, PendingRequest)> {
+    let recorder = CURRENT.try_with(Arc::clone).ok()?;
+    if recorder.lock().is_none() {
+        return None;
+    }
+    let pending = PendingRequest {
+        method: request.method().to_string(),
+        url: redact_url(request.url()),
+        range: request
+            .headers()
+            .get(RANGE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string),
+        sent: Instant::now(),
+    };
+    Some((recorder, pending))
+}
+
+/// Records the outcome of a request begun with `begin`.
+pub fn finish(
+    (recorder, request): (Arc<SessionRecorder>, PendingRequest),
+    result: &reqwest::Result<Response>,
+) {
+    recorder.finish(request, result);
+}
+
+/// Wraps `future`, which will run on a task of its own, in the calling task's recorder.
+pub fn inherit<F: Future>(future: F) -> impl Future<Output = F::Output> {
+    let recorder = CURRENT.try_with(Arc::clone).ok();
+    async move {
+        match recorder {
+            Some(recorder) => CURRENT.scope(recorder, future).await,
+            None => future.await,
+        }
+    }
+}
+
+/// Returns `url` with the values of signing and credential parameters replaced.
+fn redact_url(url: &Url) -> String {
+    if url.query().is_none() {
+        return url.to_string();
+    }
+    let mut redacted = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| {
+            let lowered = name.to_ascii_lowercase();
+            let signing = SIGNING_PARAMETERS
+                .iter()
+                .any(|parameter| lowered.ends_with(parameter));
+            let value = if signing { "REDACTED".into() } else { value };
+            (name.into_owned(), value.into_owned())
+        })
+        .collect();
+    redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    redacted.to_string()
+}
+
+fn recorded_headers(headers: &HeaderMap) -> Map<String, Value> {
+    RECORDED_HEADERS
+        .iter()
+        .filter_map(|name| {
+            let value = headers.get(*name)?.to_str().ok()?;
+            let value = match Url::parse(value) {
+                Ok(url) if *name == "location" => redact_url(&url),
+                _ => value.to_string(),
+            };
+            Some((name.to_string(), Value::String(value)))
+        })
+        .collect()
+}
+
+/// Classifies a failed request without its message, which can include a signed URL.
+fn error_kind(error: &reqwest::Error) -> &'static str {
+    if error.is_timeout() {
+        "timeout"
+    } else if error.is_connect() {
+        "connect"
+    } else if error.is_body() || error.is_decode() {
+        "body"
+    } else if error.is_redirect() {
+        "redirect"
+    } else {
+        "request"
+    }
+}
+
+/// Reads a recording made with `SessionRecorder` into faults that reproduce it.
+///
+/// Each request that failed is matched by its URL's path, skipping as many
+/// earlier requests as matched the same path when it was recorded, so the
+/// failure recurs at the same point of the same sequence of calls. Error
+/// statuses are replayed as such, failed connections as connections that
+/// drop before the body, and slow responses as latency.
+#[cfg(feature = "fault-injection")]
+pub fn replay_faults(path: &Path) -> Result<Vec<SimulatedFault>, XetError> {
+    let contents = std::fs::read_to_string(path).map_err(|e| XetError::IoError {
+        message: format!("Failed to read recording {}: {}", path.display(), e),
+        cause: Some(ErrorCause::from(&e)),
+    })?;
+    let mut paths = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: Value = serde_json::from_str(line).map_err(|e| XetError::InvalidInput {
+            message: format!("Line {} of the recording isn't JSON: {}", index + 1, e),
+        })?;
+        if entry["type"] != "request" {
+            continue;
+        }
+        let Some(url) = entry["url"].as_str().and_then(|url| Url::parse(url).ok()) else {
+            continue;
+        };
+        paths.push((url.path().to_string(), entry));
+    }
+
+    let mut faults = Vec::new();
+    for (index, (pattern, entry)) in paths.iter().enumerate() {
+        let skip = paths[..index]
+            .iter()
+            .filter(|(path, _)| path.contains(pattern.as_str()))
+            .count() as u32;
+        let fault = |kind| SimulatedFault {
+            url_pattern: Some(pattern.clone()),
+            kind,
+            skip,
+            count: Some(1),
+        };
+        let elapsed_ms = entry["elapsed_ms"].as_u64().unwrap_or(0);
+        if elapsed_ms >= SLOW_REQUEST_MILLIS {
+            faults.push(fault(FaultKind::Latency { millis: elapsed_ms }));
+        }
+        match entry["status"].as_u64() {
+            Some(status) if status >= 400 => faults.push(fault(FaultKind::Status {
+                status: status as u16,
+                retry_after_secs: entry["headers"]["retry-after"]
+                    .as_str()
+                    .and_then(|value| value.trim().parse().ok()),
+            })),
+            Some(_) => {}
+            None => faults.push(fault(FaultKind::DropConnection { after_bytes: 0 })),
+        }
+    }
+    Ok(faults)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signatures_are_redacted() {
+        let url = Url::parse(
+            "https://cdn.example.com/a.bin?X-Amz-Expires=3600&X-Amz-Signature=abc&X-Amz-Credential=key",
+        )
+        .unwrap();
+        assert_eq!(
+            redact_url(&url),
+            "https://cdn.example.com/a.bin?X-Amz-Expires=3600&X-Amz-Signature=REDACTED&X-Amz-Credential=REDACTED"
+        );
+        let url = Url::parse("https://cdn.example.com/a.bin?Expires=1&Signature=abc&Key-Pair-Id=k")
+            .unwrap();
+        assert_eq!(
+            redact_url(&url),
+            "https://cdn.example.com/a.bin?Expires=1&Signature=REDACTED&Key-Pair-Id=REDACTED"
+        );
+        let url = Url::parse("https://huggingface.co/api/models/owner/repo").unwrap();
+        assert_eq!(redact_url(&url), url.as_str());
+    }
+
+    #[cfg(feature = "fault-injection")]
+    #[test]
+    fn recordings_replay_as_faults() {
+        let path = std::env::temp_dir().join(format!("swift-xet-recording-{}", std::process::id()));
+        let lines = [
+            r#"{"type":"session","version":"0.1.0"}"#,
+            r#"{"type":"request","url":"https://hf.co/a/b/resolve/main/x","status":200,"elapsed_ms":20}"#,
+            r#"{"type":"request","url":"https://hf.co/a/b/resolve/main/x","status":503,"elapsed_ms":40,"headers":{"retry-after":"2"}}"#,
+            r#"{"type":"request","url":"https://cdn.example.com/x?Signature=REDACTED","status":null,"error":"timeout","elapsed_ms":900}"#,
+        ];
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let faults = replay_faults(&path).unwrap();
+        let resolve = Some("/a/b/resolve/main/x".to_string());
+        assert_eq!(
+            faults,
+            [
+                SimulatedFault {
+                    url_pattern: resolve,
+                    kind: FaultKind::Status {
+                        status: 503,
+                        retry_after_secs: Some(2),
+                    },
+                    skip: 1,
+                    count: Some(1),
+                },
+                SimulatedFault {
+                    url_pattern: Some("/x".to_string()),
+                    kind: FaultKind::Latency { millis: 900 },
+                    skip: 2,
+                    count: Some(1),
+                },
+                SimulatedFault {
+                    url_pattern: Some("/x".to_string()),
+                    kind: FaultKind::DropConnection { after_bytes: 0 },
+                    skip: 2,
+                    count: Some(1),
+                },
+            ]
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
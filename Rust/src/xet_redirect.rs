@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use reqwest::Url;
+
+/// How long before a signed target's expiry it stops being reused.
+const EXPIRY_SAFETY_WINDOW: Duration = Duration::from_secs(60);
+
+/// The URLs resolve requests last redirected to, reused until they expire.
+///
+/// Each request for a file's content goes to its resolve URL, which the Hub
+/// redirects to a signed CDN URL. Reusing the CDN URL lets re-downloads and
+/// the range requests of a segmented download or stream skip that round trip.
+pub struct RedirectCache {
+    /// How long a target is reused at most, or `None` if caching is off.
+    ttl: Option<Duration>,
+    targets: Mutex<HashMap<String, CachedTarget>>,
+}
+
+struct CachedTarget {
+    url: String,
+    expires_at: Instant,
+}
+
+impl RedirectCache {
+    /// Creates a cache that reuses targets for at most `ttl_secs`, or none if `None`.
+    pub fn new(ttl_secs: Option<u64>) -> Self {
+        Self {
+            ttl: ttl_secs.map(Duration::from_secs),
+            targets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records that `resolve_url` redirected to `target` at Unix time `now`.
+    ///
+    /// Targets signed to expire are kept until shortly before they do, if
+    /// that's sooner than the cache's lifetime.
+    pub fn store(&self, resolve_url: &str, target: &str, now: u64) {
+        let Some(ttl) = self.ttl else {
+            return;
+        };
+        let lifetime = Url::parse(target)
+            .ok()
+            .and_then(|url| signed_lifetime(&url, now));
+        let ttl = match lifetime {
+            Some(lifetime) => ttl.min(lifetime.saturating_sub(EXPIRY_SAFETY_WINDOW)),
+            None => ttl,
+        };
+        if ttl.is_zero() {
+            return;
+        }
+        self.targets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(
+                resolve_url.to_string(),
+                CachedTarget {
+                    url: target.to_string(),
+                    expires_at: Instant::now() + ttl,
+                },
+            );
+    }
+
+    /// Returns the URL `resolve_url` last redirected to, if it hasn't expired.
+    pub fn target(&self, resolve_url: &str) -> Option<String> {
+        let mut targets = self.targets.lock().unwrap_or_else(PoisonError::into_inner);
+        match targets.get(resolve_url) {
+            Some(target) if Instant::now() < target.expires_at => Some(target.url.clone()),
+            Some(_) => {
+                targets.remove(resolve_url);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Forgets the target of `resolve_url`, so its next request goes through the Hub.
+    pub fn forget(&self, resolve_url: &str) {
+        self.targets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(resolve_url);
+    }
+}
+
+/// Returns how long a signed URL remains valid after Unix time `now`, if its query says.
+///
+/// CloudFront URLs carry the Unix time they expire at in `Expires`, and S3
+/// URLs the seconds they're valid for in `X-Amz-Expires`, counted from when
+/// they were signed, which for a fresh redirect is now.
+fn signed_lifetime(url: &Url, now: u64) -> Option<Duration> {
+    url.query_pairs().find_map(|(name, value)| {
+        let value = value.parse::<u64>().ok()?;
+        match name.as_ref() {
+            "Expires" => Some(Duration::from_secs(value.saturating_sub(now))),
+            "X-Amz-Expires" => Some(Duration::from_secs(value)),
+            _ => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESOLVE_URL: &str = "https://huggingface.co/owner/repo/resolve/main/model.bin";
+    const NOW: u64 = 1_700_000_000;
+
+    #[test]
+    fn targets_are_reused_until_they_expire() {
+        let disabled = RedirectCache::new(None);
+        disabled.store(RESOLVE_URL, "https://cdn.example.com/model.bin", NOW);
+        assert_eq!(disabled.target(RESOLVE_URL), None);
+
+        let cache = RedirectCache::new(Some(300));
+        cache.store(RESOLVE_URL, "https://cdn.example.com/model.bin", NOW);
+        assert_eq!(
+            cache.target(RESOLVE_URL).as_deref(),
+            Some("https://cdn.example.com/model.bin")
+        );
+        cache.forget(RESOLVE_URL);
+        assert_eq!(cache.target(RESOLVE_URL), None);
+
+        // Targets about to expire aren't worth keeping.
+        cache.store(
+            RESOLVE_URL,
+            "https://cdn.example.com/model.bin?X-Amz-Expires=30",
+            NOW,
+        );
+        assert_eq!(cache.target(RESOLVE_URL), None);
+        let expiring = format!("https://cdn.example.com/model.bin?Expires={}", NOW + 30);
+        cache.store(RESOLVE_URL, &expiring, NOW);
+        assert_eq!(cache.target(RESOLVE_URL), None);
+    }
+
+    #[test]
+    fn signed_urls_report_their_lifetime() {
+        let s3 =
+            Url::parse("https://cdn.example.com/a?X-Amz-Date=20250101T000000Z&X-Amz-Expires=3600")
+                .unwrap();
+        assert_eq!(signed_lifetime(&s3, NOW), Some(Duration::from_secs(3600)));
+
+        let cloudfront =
+            Url::parse(&format!("https://cdn.example.com/a?Expires={}", NOW + 600)).unwrap();
+        assert_eq!(
+            signed_lifetime(&cloudfront, NOW),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(
+            signed_lifetime(&cloudfront, NOW + 900),
+            Some(Duration::ZERO)
+        );
+
+        let unsigned = Url::parse("https://cdn.example.com/a").unwrap();
+        assert_eq!(signed_lifetime(&unsigned, NOW), None);
+    }
+}
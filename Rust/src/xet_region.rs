@@ -0,0 +1,109 @@
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+
+use crate::{xet_faults, CasEndpointLatency};
+
+/// How long a latency probe may take before the endpoint is treated as unreachable.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Splits a CAS endpoint header into the endpoints it offers.
+///
+/// The Hub currently sends a single endpoint. A comma-separated list is
+/// treated as regional alternatives serving the same content.
+pub fn parse_endpoints(header: &str) -> Vec<String> {
+    header
+        .split(',')
+        .map(|endpoint| endpoint.trim().trim_end_matches('/').to_string())
+        .filter(|endpoint| !endpoint.is_empty())
+        .collect()
+}
+
+/// Measures the round-trip time to each endpoint with a `HEAD` request.
+///
+/// Any response counts, since only the time to reach the server matters.
+/// Endpoints that can't be reached in time are reported without a time.
+pub async fn measure(client: &Client, endpoints: &[String]) -> Vec<CasEndpointLatency> {
+    let mut latencies = Vec::with_capacity(endpoints.len());
+    for endpoint in endpoints {
+        let started = Instant::now();
+        let probe = xet_faults::send(client.head(endpoint));
+        let probe = tokio::time::timeout(PROBE_TIMEOUT, probe).await;
+        latencies.push(CasEndpointLatency {
+            endpoint: endpoint.clone(),
+            rtt_ms: matches!(probe, Ok(Ok(_))).then(|| started.elapsed().as_millis() as u64),
+        });
+    }
+    latencies
+}
+
+/// Picks the endpoint to use: `preferred` if it was offered, otherwise the fastest.
+///
+/// If no endpoint could be measured, the first one offered is used.
+pub fn select(latencies: &[CasEndpointLatency], preferred: Option<&str>) -> Option<String> {
+    if let Some(preferred) = preferred.map(|preferred| preferred.trim_end_matches('/')) {
+        if let Some(offered) = latencies
+            .iter()
+            .find(|latency| latency.endpoint == preferred)
+        {
+            return Some(offered.endpoint.clone());
+        }
+    }
+    latencies
+        .iter()
+        .filter_map(|latency| latency.rtt_ms.map(|rtt_ms| (rtt_ms, latency)))
+        .min_by_key(|(rtt_ms, _)| *rtt_ms)
+        .map(|(_, latency)| latency)
+        .or_else(|| latencies.first())
+        .map(|latency| latency.endpoint.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latency(endpoint: &str, rtt_ms: Option<u64>) -> CasEndpointLatency {
+        CasEndpointLatency {
+            endpoint: endpoint.to_string(),
+            rtt_ms,
+        }
+    }
+
+    #[test]
+    fn parses_single_and_multiple_endpoints() {
+        assert_eq!(
+            parse_endpoints("https://cas-server.xethub.hf.co"),
+            vec!["https://cas-server.xethub.hf.co"]
+        );
+        assert_eq!(
+            parse_endpoints("https://us.cas.example/, https://eu.cas.example,"),
+            vec!["https://us.cas.example", "https://eu.cas.example"]
+        );
+    }
+
+    #[test]
+    fn select_prefers_the_callers_choice_then_the_fastest() {
+        let latencies = [
+            latency("https://us.cas.example", Some(180)),
+            latency("https://eu.cas.example", Some(25)),
+            latency("https://ap.cas.example", None),
+        ];
+        assert_eq!(
+            select(&latencies, None).as_deref(),
+            Some("https://eu.cas.example")
+        );
+        assert_eq!(
+            select(&latencies, Some("https://us.cas.example/")).as_deref(),
+            Some("https://us.cas.example")
+        );
+        assert_eq!(
+            select(&latencies, Some("https://elsewhere.example")).as_deref(),
+            Some("https://eu.cas.example")
+        );
+        assert_eq!(
+            select(&[latency("https://ap.cas.example", None)], None).as_deref(),
+            Some("https://ap.cas.example")
+        );
+        assert_eq!(select(&[], None), None);
+    }
+}
@@ -0,0 +1,116 @@
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+
+use crate::xet_cache::CacheLayout;
+use crate::xet_metadata::FileResolveMetadata;
+use crate::xet_storage::CacheStorage;
+use crate::CacheArea;
+
+const RESOLUTIONS_DIR: &str = "resolutions";
+
+/// The last successful resolution of each file, kept to fall back on when the Hub is unreachable.
+///
+/// Each resolution is a small JSON file named by a hash of the endpoint,
+/// repository, path, and revision it answers, written atomically so a reader
+/// never sees a torn entry. Entries live in the metadata area of the cache.
+pub struct ResolutionCache {
+    dir: PathBuf,
+    storage: Arc<dyn CacheStorage>,
+}
+
+impl ResolutionCache {
+    pub fn new(dir: PathBuf, storage: Arc<dyn CacheStorage>) -> Self {
+        Self { dir, storage }
+    }
+
+    /// Opens the resolutions stored in the metadata area of `layout`.
+    pub fn open(layout: &CacheLayout) -> Self {
+        Self::new(
+            layout.root(CacheArea::Metadata).join(RESOLUTIONS_DIR),
+            layout.storage(),
+        )
+    }
+
+    /// Records `metadata` as the latest resolution of `key`.
+    pub fn store(&self, key: &[&str], metadata: &FileResolveMetadata) -> io::Result<()> {
+        let contents = serde_json::to_vec(metadata).map_err(io::Error::other)?;
+        self.storage.write(&self.entry_path(key), &contents)
+    }
+
+    /// Returns the latest recorded resolution of `key`, marked as served stale.
+    pub fn load(&self, key: &[&str]) -> Option<FileResolveMetadata> {
+        let contents = self.storage.read(&self.entry_path(key)).ok()?;
+        let metadata: FileResolveMetadata = serde_json::from_slice(&contents).ok()?;
+        Some(FileResolveMetadata {
+            served_stale: true,
+            ..metadata
+        })
+    }
+
+    fn entry_path(&self, key: &[&str]) -> PathBuf {
+        let mut hasher = Sha256::new();
+        for part in key {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        let name: String = hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        self.dir.join(format!("{}.json", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xet_storage::FileSystemStorage;
+    use std::fs;
+
+    #[test]
+    fn stored_resolutions_load_as_stale() {
+        let dir =
+            std::env::temp_dir().join(format!("swift-xet-resolutions-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let cache = ResolutionCache::new(dir.clone(), Arc::new(FileSystemStorage));
+        let key = [
+            "https://huggingface.co",
+            "models",
+            "owner/repo",
+            "config.json",
+            "main",
+        ];
+        assert!(cache.load(&key).is_none());
+
+        let metadata = FileResolveMetadata {
+            download_url: "https://huggingface.co/owner/repo/resolve/main/config.json".to_string(),
+            etag: "\"abc\"".to_string(),
+            commit_hash: "0123456789".to_string(),
+            size: 42,
+            content_type: None,
+            xet_file_data: None,
+            redirect_target: None,
+            served_stale: false,
+        };
+        cache.store(&key, &metadata).unwrap();
+
+        let loaded = cache.load(&key).unwrap();
+        assert!(loaded.served_stale);
+        assert_eq!(loaded.etag, metadata.etag);
+        assert_eq!(loaded.size, 42);
+        let other = [
+            "https://huggingface.co",
+            "models",
+            "owner/repo",
+            "config.json",
+            "dev",
+        ];
+        assert!(cache.load(&other).is_none());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
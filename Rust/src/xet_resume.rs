@@ -0,0 +1,238 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// The suffix of the file a download is written to until it's complete and verified.
+const INCOMPLETE_SUFFIX: &str = ".incomplete";
+/// The suffix of the file recording what a partial download is fetching.
+const STATE_SUFFIX: &str = ".incomplete.json";
+
+/// What a partial download is fetching, so it's only resumed for the same content.
+#[derive(PartialEq, Eq, Serialize, Deserialize)]
+struct ResumeState {
+    etag: String,
+    size: u64,
+    /// The byte ranges written so far, for transfers that don't write in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    written: Vec<(u64, u64)>,
+}
+
+/// A download written next to its destination until it's complete.
+///
+/// The destination only ever holds a complete file. Transfers that can resume
+/// record the etag and size they're fetching alongside the partial file, so a
+/// later attempt for the same content continues where the last one stopped.
+/// Transfers that write pieces of the file in any order also record which
+/// byte ranges they've written.
+pub struct PartialDownload {
+    destination: PathBuf,
+    path: PathBuf,
+    state_path: PathBuf,
+}
+
+impl PartialDownload {
+    pub fn new(destination: &Path) -> Self {
+        Self {
+            destination: destination.to_path_buf(),
+            path: with_suffix(destination, INCOMPLETE_SUFFIX),
+            state_path: with_suffix(destination, STATE_SUFFIX),
+        }
+    }
+
+    /// The path the download is written to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the offset to resume fetching `etag` from, starting over if needed.
+    ///
+    /// Partial content for anything other than `etag` and `size` is discarded,
+    /// and the content being fetched is recorded for the next attempt.
+    pub fn resume_offset(&self, etag: &str, size: u64) -> io::Result<u64> {
+        let state = ResumeState {
+            etag: etag.to_string(),
+            size,
+            written: Vec::new(),
+        };
+        let written = fs::metadata(&self.path).map(|metadata| metadata.len());
+        match (self.read_state(), written) {
+            (Some(recorded), Ok(written)) if recorded == state && written < size => Ok(written),
+            _ => {
+                self.discard()?;
+                self.write_state(&state)?;
+                Ok(0)
+            }
+        }
+    }
+
+    /// Returns the byte ranges of `content` already written, starting over if needed.
+    ///
+    /// Partial content for anything other than `content` and `size` is
+    /// discarded, and an empty partial file is created to write into.
+    pub fn written_ranges(&self, content: &str, size: u64) -> io::Result<Vec<(u64, u64)>> {
+        match self.read_state() {
+            Some(recorded)
+                if recorded.etag == content && recorded.size == size && self.path.exists() =>
+            {
+                Ok(recorded.written)
+            }
+            _ => {
+                self.discard()?;
+                fs::File::create(&self.path)?;
+                self.record_written(content, size, &[])?;
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    /// Records that `written` byte ranges of the partial file hold `content`.
+    pub fn record_written(
+        &self,
+        content: &str,
+        size: u64,
+        written: &[(u64, u64)],
+    ) -> io::Result<()> {
+        self.write_state(&ResumeState {
+            etag: content.to_string(),
+            size,
+            written: written.to_vec(),
+        })
+    }
+
+    /// Returns whether there is partial content a later attempt could resume.
+    pub fn has_progress(&self) -> bool {
+        self.read_state().is_some()
+            && fs::metadata(&self.path).is_ok_and(|metadata| metadata.len() > 0)
+    }
+
+    /// Removes any partial content and its recorded state.
+    pub fn discard(&self) -> io::Result<()> {
+        remove_if_present(&self.path)?;
+        remove_if_present(&self.state_path)
+    }
+
+    /// Moves the completed download to its destination.
+    ///
+    /// The content is flushed to disk first, so a crash can't leave the
+    /// destination pointing at a file whose data was never written.
+    pub fn finish(&self) -> io::Result<()> {
+        fs::File::open(&self.path)?.sync_all()?;
+        fs::rename(&self.path, &self.destination)?;
+        remove_if_present(&self.state_path)
+    }
+
+    fn read_state(&self) -> Option<ResumeState> {
+        let contents = fs::read(&self.state_path).ok()?;
+        serde_json::from_slice(&contents).ok()
+    }
+
+    /// Replaces the recorded state, so a crash leaves either the old or the new one.
+    fn write_state(&self, state: &ResumeState) -> io::Result<()> {
+        let contents = serde_json::to_vec(state).map_err(io::Error::other)?;
+        let temp_path = with_suffix(&self.state_path, ".tmp");
+        fs::write(&temp_path, contents)?;
+        fs::rename(&temp_path, &self.state_path)
+    }
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+fn remove_if_present(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Returns whether `ranges` cover the `len` bytes at `offset`.
+pub fn covers(ranges: &[(u64, u64)], offset: u64, len: u64) -> bool {
+    ranges
+        .iter()
+        .any(|&(start, end)| start <= offset && offset + len <= end)
+}
+
+/// Adds `start..end` to the sorted, disjoint `ranges`, merging any it touches.
+pub fn insert_range(ranges: &mut Vec<(u64, u64)>, start: u64, end: u64) {
+    let (mut start, mut end) = (start, end);
+    ranges.retain(|&(other_start, other_end)| {
+        let touches = other_start <= end && start <= other_end;
+        if touches {
+            start = start.min(other_start);
+            end = end.max(other_end);
+        }
+        !touches
+    });
+    let index = ranges.partition_point(|&(other_start, _)| other_start < start);
+    ranges.insert(index, (start, end));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resumes_only_the_same_content() {
+        let dir = std::env::temp_dir().join(format!("swift-xet-resume-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let partial = PartialDownload::new(&dir.join("model.bin"));
+        assert_eq!(partial.path(), dir.join("model.bin.incomplete"));
+
+        assert_eq!(partial.resume_offset("\"abc\"", 10).unwrap(), 0);
+        assert!(!partial.has_progress());
+        fs::write(partial.path(), b"0123").unwrap();
+        assert!(partial.has_progress());
+        assert_eq!(partial.resume_offset("\"abc\"", 10).unwrap(), 4);
+
+        assert_eq!(partial.resume_offset("\"def\"", 10).unwrap(), 0);
+        assert!(!partial.path().exists());
+
+        fs::write(partial.path(), b"0123456789").unwrap();
+        partial.finish().unwrap();
+        assert_eq!(fs::read(dir.join("model.bin")).unwrap(), b"0123456789");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn written_ranges_survive_only_for_the_same_content() {
+        let dir = std::env::temp_dir().join(format!("swift-xet-ranges-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let partial = PartialDownload::new(&dir.join("model.bin"));
+
+        assert!(partial.written_ranges("abc", 10).unwrap().is_empty());
+        assert!(partial.path().exists());
+        partial.record_written("abc", 10, &[(4, 8)]).unwrap();
+        assert_eq!(partial.written_ranges("abc", 10).unwrap(), vec![(4, 8)]);
+        // Sequential transfers don't resume from content written out of order.
+        assert_eq!(partial.resume_offset("abc", 10).unwrap(), 0);
+        assert!(partial.written_ranges("abc", 10).unwrap().is_empty());
+
+        partial.record_written("abc", 10, &[(0, 2)]).unwrap();
+        assert!(partial.written_ranges("def", 10).unwrap().is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn written_ranges_merge() {
+        let mut ranges = Vec::new();
+        insert_range(&mut ranges, 10, 20);
+        insert_range(&mut ranges, 0, 5);
+        insert_range(&mut ranges, 30, 40);
+        assert_eq!(ranges, vec![(0, 5), (10, 20), (30, 40)]);
+        insert_range(&mut ranges, 5, 10);
+        insert_range(&mut ranges, 20, 30);
+        assert_eq!(ranges, vec![(0, 40)]);
+        assert!(covers(&ranges, 12, 28));
+        assert!(!covers(&ranges, 35, 10));
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::{ErrorCause, HttpErrorKind, RetryClass, RetryPolicy, XetError};
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 500,
+            max_delay_ms: 10_000,
+            exponential_backoff: true,
+            jitter: true,
+            retry_on: vec![
+                RetryClass::Timeout,
+                RetryClass::Connect,
+                RetryClass::ServerError,
+                RetryClass::RateLimited,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Checks that the policy can be followed.
+    pub(crate) fn validate(&self) -> Result<(), XetError> {
+        if self.max_attempts == 0 {
+            return Err(XetError::InvalidInput {
+                message: "Retry policy must allow at least one attempt".to_string(),
+            });
+        }
+        if self.base_delay_ms > self.max_delay_ms {
+            return Err(XetError::InvalidInput {
+                message: "Retry base delay cannot exceed the maximum delay".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns whether `error` is in a class the policy retries.
+    pub(crate) fn retries(&self, error: &XetError) -> bool {
+        classify(error).is_some_and(|class| self.retry_on.contains(&class))
+    }
+
+    /// Returns how long to wait before retry number `retry`, counting from one.
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        let factor = if self.exponential_backoff {
+            2u64.saturating_pow(retry.saturating_sub(1))
+        } else {
+            1
+        };
+        let delay_ms = self
+            .base_delay_ms
+            .saturating_mul(factor)
+            .min(self.max_delay_ms);
+        if !self.jitter {
+            return Duration::from_millis(delay_ms);
+        }
+        // Spread retries over the upper half of the delay, so clients that
+        // failed together don't retry together.
+        let random = RandomState::new().build_hasher().finish();
+        Duration::from_millis(delay_ms / 2 + random % (delay_ms / 2 + 1))
+    }
+}
+
+/// Returns the class of a failure that might succeed if tried again, if it is one.
+pub fn classify(error: &XetError) -> Option<RetryClass> {
+    if matches!(error, XetError::ServiceUnavailable { .. }) {
+        return Some(RetryClass::ServerError);
+    }
+    match error.cause()? {
+        ErrorCause::Http {
+            kind: HttpErrorKind::Timeout,
+            ..
+        } => Some(RetryClass::Timeout),
+        ErrorCause::Http {
+            kind: HttpErrorKind::Dns | HttpErrorKind::Connect,
+            ..
+        } => Some(RetryClass::Connect),
+        ErrorCause::Http {
+            status: Some(429), ..
+        } => Some(RetryClass::RateLimited),
+        ErrorCause::Http {
+            status: Some(status),
+            ..
+        } if (500..600).contains(&status) => Some(RetryClass::ServerError),
+        _ => None,
+    }
+}
+
+/// Runs `attempt` until it succeeds, fails in a way `policy` doesn't retry,
+/// or runs out of attempts, waiting between attempts as the policy says.
+pub async fn with_retries<T, F, Fut>(policy: &RetryPolicy, mut attempt: F) -> Result<T, XetError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, XetError>>,
+{
+    let mut attempts = 1;
+    loop {
+        match attempt().await {
+            Err(e) if attempts < policy.max_attempts && policy.retries(&e) => {
+                tokio::time::sleep(policy.delay(attempts)).await;
+                attempts += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn http_error(kind: HttpErrorKind, status: Option<u16>) -> XetError {
+        XetError::NetworkError {
+            message: "Request failed".to_string(),
+            cause: Some(ErrorCause::Http { kind, status }),
+        }
+    }
+
+    #[test]
+    fn failures_are_classified_by_cause() {
+        let classes = [
+            (
+                http_error(HttpErrorKind::Timeout, None),
+                Some(RetryClass::Timeout),
+            ),
+            (
+                http_error(HttpErrorKind::Dns, None),
+                Some(RetryClass::Connect),
+            ),
+            (
+                http_error(HttpErrorKind::Status, Some(429)),
+                Some(RetryClass::RateLimited),
+            ),
+            (
+                http_error(HttpErrorKind::Status, Some(503)),
+                Some(RetryClass::ServerError),
+            ),
+            (http_error(HttpErrorKind::Status, Some(404)), None),
+        ];
+        for (error, class) in classes {
+            assert_eq!(classify(&error), class);
+        }
+
+        let policy = RetryPolicy {
+            retry_on: vec![RetryClass::Timeout],
+            ..RetryPolicy::default()
+        };
+        assert!(policy.retries(&http_error(HttpErrorKind::Timeout, None)));
+        assert!(!policy.retries(&http_error(HttpErrorKind::Status, Some(503))));
+    }
+
+    #[test]
+    fn delays_back_off_up_to_the_maximum() {
+        let policy = RetryPolicy {
+            base_delay_ms: 100,
+            max_delay_ms: 1000,
+            jitter: false,
+            ..RetryPolicy::default()
+        };
+        let delays: Vec<u64> = (1..=5)
+            .map(|retry| policy.delay(retry).as_millis() as u64)
+            .collect();
+        assert_eq!(delays, [100, 200, 400, 800, 1000]);
+
+        let jittered = RetryPolicy {
+            jitter: true,
+            ..policy.clone()
+        };
+        let delay = jittered.delay(3);
+        assert!(delay >= Duration::from_millis(200) && delay <= Duration::from_millis(400));
+
+        let constant = RetryPolicy {
+            exponential_backoff: false,
+            ..policy
+        };
+        assert_eq!(constant.delay(4), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn with_retries_stops_after_max_attempts() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let policy = RetryPolicy {
+            base_delay_ms: 1,
+            ..RetryPolicy::default()
+        };
+
+        let mut calls = 0;
+        let result: Result<(), XetError> = runtime.block_on(with_retries(&policy, || {
+            calls += 1;
+            async { Err(http_error(HttpErrorKind::Connect, None)) }
+        }));
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        let result: Result<(), XetError> = runtime.block_on(with_retries(&policy, || {
+            calls += 1;
+            async { Err(http_error(HttpErrorKind::Status, Some(403))) }
+        }));
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,165 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static BACKUP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Something a multi-file operation changed on disk.
+enum JournalEntry {
+    /// A directory that didn't exist before the operation.
+    CreatedDir(PathBuf),
+    /// A file that didn't exist before the operation.
+    CreatedFile(PathBuf),
+    /// A file that existed before the operation, moved aside to `backup`.
+    ReplacedFile { path: PathBuf, backup: PathBuf },
+}
+
+/// A record of everything a multi-file operation writes, so it can be undone.
+///
+/// Each destination is recorded before it's written. Files it would replace
+/// are moved aside rather than overwritten, so rolling back restores the tree
+/// to exactly how it was before the operation started.
+#[derive(Default)]
+pub struct OperationJournal {
+    entries: Vec<JournalEntry>,
+}
+
+impl OperationJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that `destination` is about to be written.
+    ///
+    /// Missing parent directories are created and recorded, and an existing
+    /// file at `destination` is moved to a hidden backup next to it.
+    pub fn prepare(&mut self, destination: &Path) -> io::Result<()> {
+        if let Some(parent) = destination.parent() {
+            let missing: Vec<&Path> = parent
+                .ancestors()
+                .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+                .collect();
+            for dir in missing.into_iter().rev() {
+                fs::create_dir(dir)?;
+                self.entries
+                    .push(JournalEntry::CreatedDir(dir.to_path_buf()));
+            }
+        }
+
+        if destination.is_file() {
+            let backup = backup_path(destination);
+            fs::rename(destination, &backup)?;
+            self.entries.push(JournalEntry::ReplacedFile {
+                path: destination.to_path_buf(),
+                backup,
+            });
+        } else {
+            self.entries
+                .push(JournalEntry::CreatedFile(destination.to_path_buf()));
+        }
+        Ok(())
+    }
+
+    /// Keeps everything the operation wrote and discards the backups of replaced files.
+    pub fn commit(self) {
+        for entry in self.entries {
+            if let JournalEntry::ReplacedFile { backup, .. } = entry {
+                let _ = fs::remove_file(backup);
+            }
+        }
+    }
+
+    /// Undoes everything the operation wrote, most recent first.
+    ///
+    /// Every entry is attempted even if some fail; the first failure is returned.
+    /// Created directories are only removed if they're empty, so files that
+    /// something else wrote into them are left alone.
+    pub fn roll_back(self) -> io::Result<()> {
+        let mut first_error = None;
+        for entry in self.entries.into_iter().rev() {
+            let result = match entry {
+                JournalEntry::CreatedFile(path) => match fs::remove_file(path) {
+                    Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+                    _ => Ok(()),
+                },
+                JournalEntry::ReplacedFile { path, backup } => fs::rename(backup, path),
+                JournalEntry::CreatedDir(dir) => {
+                    let _ = fs::remove_dir(dir);
+                    Ok(())
+                }
+            };
+            if let Err(e) = result {
+                first_error.get_or_insert(e);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    path.with_file_name(format!(
+        ".{}.rollback-{}-{}",
+        name,
+        std::process::id(),
+        BACKUP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "swift-xet-rollback-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn roll_back_restores_the_previous_tree() {
+        let dir = temp_dir("roll-back");
+        let existing = dir.join("config.json");
+        fs::write(&existing, "old").unwrap();
+        let created = dir.join("weights/model.bin");
+
+        let mut journal = OperationJournal::new();
+        journal.prepare(&existing).unwrap();
+        fs::write(&existing, "new").unwrap();
+        journal.prepare(&created).unwrap();
+        fs::write(&created, "weights").unwrap();
+
+        journal.roll_back().unwrap();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "old");
+        assert!(!dir.join("weights").exists());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn commit_keeps_new_files_and_drops_backups() {
+        let dir = temp_dir("commit");
+        let existing = dir.join("config.json");
+        fs::write(&existing, "old").unwrap();
+
+        let mut journal = OperationJournal::new();
+        journal.prepare(&existing).unwrap();
+        fs::write(&existing, "new").unwrap();
+        journal.commit();
+
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "new");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// The number of range requests a client makes at once, unless configured.
+pub const DEFAULT_MAX_CONCURRENT_RANGE_GETS: u32 = 16;
+
+/// A budget of concurrent range requests shared by every download of a client.
+///
+/// Each request holds a slot until its response has been read. A download
+/// running alone can fill the whole budget, while several downloads split it,
+/// so a batch of small files keeps the connection as busy as one large file
+/// without a large file crowding out the rest. Slots are handed out in the
+/// order they were waited for.
+pub struct Scheduler {
+    slots: Arc<Semaphore>,
+}
+
+impl Scheduler {
+    pub fn new(max_concurrent_range_gets: Option<u32>) -> Self {
+        let budget = max_concurrent_range_gets.unwrap_or(DEFAULT_MAX_CONCURRENT_RANGE_GETS);
+        Self {
+            slots: Arc::new(Semaphore::new(budget as usize)),
+        }
+    }
+
+    /// Waits for a free slot, which is returned to the budget when dropped.
+    pub async fn acquire(&self) -> OwnedSemaphorePermit {
+        self.slots
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("scheduler slots are never closed")
+    }
+
+    /// Takes a free slot if there is one and no other download is waiting for it.
+    pub fn try_acquire(&self) -> Option<OwnedSemaphorePermit> {
+        self.slots.clone().try_acquire_owned().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_are_shared_and_returned() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let scheduler = Scheduler::new(Some(2));
+        let first = scheduler.try_acquire().unwrap();
+        let second = runtime.block_on(scheduler.acquire());
+        assert!(scheduler.try_acquire().is_none());
+
+        drop(first);
+        assert!(scheduler.try_acquire().is_some());
+        drop(second);
+    }
+}
@@ -0,0 +1,243 @@
+use std::fs::OpenOptions;
+use std::io::{Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Instant;
+
+use reqwest::{Client, StatusCode};
+use tokio::task::JoinSet;
+
+use crate::xet_faults;
+use crate::xet_metadata::{self, FileResolveMetadata};
+use crate::xet_resume::{covers, insert_range, PartialDownload};
+use crate::xet_scheduler::Scheduler;
+use crate::xet_transfer::{TransferObserver, PROGRESS_INTERVAL};
+use crate::xet_verify::PrefixHasher;
+use crate::{ErrorCause, HttpErrorKind, XetError};
+
+/// The size of the pieces a large file is fetched in, each with its own range request.
+const SEGMENT_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Returns whether a file of `size` bytes is large enough to fetch in segments.
+pub fn is_segmented(size: u64) -> bool {
+    size >= 2 * SEGMENT_SIZE
+}
+
+/// Fetches the file `metadata` describes into `partial` with concurrent range
+/// requests, writing each segment straight to its place in the file.
+///
+/// Segments are written as they arrive, so memory use doesn't grow with the
+/// file, and each is flushed to disk and recorded beside the partial file once
+/// complete, so a failed attempt loses no more than the segments in flight.
+/// Each segment takes a slot from `scheduler` while it's fetched. Fails with
+/// `XetError::NetworkError` without writing anything if the server ignores
+/// range requests, and with `XetError::IntegrityError` if a response shows the
+/// file changed since it was resolved.
+///
+/// With a `hasher`, the file is hashed as the segments written so far join
+/// onto its start, so verifying it doesn't wait for the whole file.
+pub async fn download(
+    client: &Client,
+    scheduler: &Scheduler,
+    metadata: &FileResolveMetadata,
+    token: Option<&str>,
+    partial: &PartialDownload,
+    observer: &TransferObserver,
+    mut hasher: Option<&mut PrefixHasher>,
+) -> Result<(), XetError> {
+    let size = metadata.size;
+    let mut written = partial.written_ranges(&metadata.etag, size)?;
+    let completed: u64 = written.iter().map(|(start, end)| end - start).sum();
+    let progress = Arc::new(Progress::new(completed, size));
+    observer.report(completed, size);
+
+    let missing: Vec<(u64, u64)> = (0..size)
+        .step_by(SEGMENT_SIZE as usize)
+        .map(|start| (start, (start + SEGMENT_SIZE).min(size)))
+        .filter(|&(start, end)| !covers(&written, start, end - start))
+        .collect();
+    let metadata = Arc::new(metadata.clone());
+    let token = token.map(str::to_string);
+    let mut missing = missing.into_iter();
+    let mut tasks = JoinSet::new();
+    loop {
+        while !missing.as_slice().is_empty() {
+            // Only wait for a slot with nothing in flight, so the download
+            // always makes progress without holding up other downloads.
+            let slot = if tasks.is_empty() {
+                scheduler.acquire().await
+            } else if let Some(slot) = scheduler.try_acquire() {
+                slot
+            } else {
+                break;
+            };
+            let Some((start, end)) = missing.next() else {
+                break;
+            };
+            let segment = Segment {
+                client: client.clone(),
+                metadata: metadata.clone(),
+                token: token.clone(),
+                path: partial.path().to_path_buf(),
+                start,
+                end,
+            };
+            let observer = observer.clone();
+            let progress = progress.clone();
+            tasks.spawn(xet_faults::inherit(async move {
+                let result = segment.fetch(&observer, &progress).await;
+                drop(slot);
+                result.map(|()| (start, end))
+            }));
+        }
+        let Some(joined) = tasks.join_next().await else {
+            break;
+        };
+        let (start, end) = joined.map_err(|e| XetError::OperationFailed {
+            message: format!("Segment task failed: {}", e),
+            cause: None,
+        })??;
+        insert_range(&mut written, start, end);
+        partial.record_written(&metadata.etag, size, &written)?;
+        if let Some(hasher) = hasher.as_deref_mut() {
+            hasher.advance(&written)?;
+        }
+    }
+    observer.report(size, size);
+    Ok(())
+}
+
+/// A byte range of a file, fetched into its place in the partial file.
+struct Segment {
+    client: Client,
+    metadata: Arc<FileResolveMetadata>,
+    token: Option<String>,
+    path: PathBuf,
+    start: u64,
+    end: u64,
+}
+
+impl Segment {
+    async fn fetch(self, observer: &TransferObserver, progress: &Progress) -> Result<(), XetError> {
+        let mut request = self.client.get(&self.metadata.download_url).header(
+            reqwest::header::RANGE,
+            format!("bytes={}-{}", self.start, self.end - 1),
+        );
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        let mut response = xet_faults::send(request).await?.error_for_status()?;
+        if response.status() != StatusCode::PARTIAL_CONTENT {
+            return Err(ranges_ignored());
+        }
+        if let Some(drift) =
+            xet_metadata::served_drift(&self.metadata, response.status(), response.headers())
+        {
+            return Err(XetError::IntegrityError {
+                message: format!("The revision moved during the download: {}", drift),
+            });
+        }
+
+        let mut file = OpenOptions::new().write(true).open(&self.path)?;
+        file.seek(SeekFrom::Start(self.start))?;
+        let mut received = 0;
+        while let Some(chunk) = response.chunk().await? {
+            observer.checkpoint().await?;
+            received += chunk.len() as u64;
+            if received > self.end - self.start {
+                return Err(short_segment(self.start, self.end, received));
+            }
+            file.write_all(&chunk)?;
+            observer.account(chunk.len() as u64).await;
+            progress.add(chunk.len() as u64, observer);
+        }
+        if received != self.end - self.start {
+            return Err(short_segment(self.start, self.end, received));
+        }
+        file.sync_data()?;
+        Ok(())
+    }
+}
+
+/// Progress across the segments of a file, reported at most every `PROGRESS_INTERVAL`.
+struct Progress {
+    completed: AtomicU64,
+    total: u64,
+    last_report: Mutex<Instant>,
+}
+
+impl Progress {
+    fn new(completed: u64, total: u64) -> Self {
+        Self {
+            completed: AtomicU64::new(completed),
+            total,
+            last_report: Mutex::new(Instant::now()),
+        }
+    }
+
+    fn add(&self, bytes: u64, observer: &TransferObserver) {
+        let completed = self.completed.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        let mut last_report = self
+            .last_report
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if last_report.elapsed() >= PROGRESS_INTERVAL {
+            observer.report(completed.min(self.total), self.total);
+            *last_report = Instant::now();
+        }
+    }
+}
+
+/// Returns whether `error` means the server answered a range request with the whole file.
+pub fn is_ranges_ignored(error: &XetError) -> bool {
+    matches!(
+        error,
+        XetError::NetworkError {
+            cause: Some(ErrorCause::Http {
+                kind: HttpErrorKind::Status,
+                status: Some(200),
+            }),
+            ..
+        }
+    )
+}
+
+fn ranges_ignored() -> XetError {
+    XetError::NetworkError {
+        message: "The server ignored a range request".to_string(),
+        cause: Some(ErrorCause::Http {
+            kind: HttpErrorKind::Status,
+            status: Some(200),
+        }),
+    }
+}
+
+fn short_segment(start: u64, end: u64, received: u64) -> XetError {
+    XetError::NetworkError {
+        message: format!(
+            "Expected {} bytes for the segment at {}, received {}",
+            end - start,
+            start,
+            received
+        ),
+        cause: Some(ErrorCause::Http {
+            kind: HttpErrorKind::Body,
+            status: None,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_large_files_are_segmented() {
+        assert!(!is_segmented(0));
+        assert!(!is_segmented(2 * SEGMENT_SIZE - 1));
+        assert!(is_segmented(2 * SEGMENT_SIZE));
+        assert!(is_ranges_ignored(&ranges_ignored()));
+        assert!(!is_ranges_ignored(&short_segment(0, 10, 5)));
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A local file staged to be published at a path in a repository.
+///
+/// Staging records the file's size and modification time, so a file edited
+/// after it was staged is caught before its content is committed rather than
+/// publishing something other than what was staged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StagedFile {
+    pub local_path: PathBuf,
+    pub path_in_repo: String,
+    size: u64,
+    modified: SystemTime,
+}
+
+impl StagedFile {
+    /// Stages the regular file at `local_path` to be published at `path_in_repo`.
+    pub fn new(local_path: PathBuf, path_in_repo: String) -> io::Result<Self> {
+        let metadata = fs::metadata(&local_path)?;
+        if !metadata.is_file() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "not a regular file",
+            ));
+        }
+        Ok(Self {
+            local_path,
+            path_in_repo,
+            size: metadata.len(),
+            modified: metadata.modified()?,
+        })
+    }
+
+    /// Returns the size the file was staged with.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the modification time the file was staged with.
+    pub fn modified(&self) -> SystemTime {
+        self.modified
+    }
+
+    /// Returns whether the file still has the size and modification time it was staged with.
+    pub fn is_unchanged(&self) -> bool {
+        fs::metadata(&self.local_path).is_ok_and(|metadata| {
+            metadata.is_file()
+                && metadata.len() == self.size
+                && metadata.modified().ok() == Some(self.modified)
+        })
+    }
+}
+
+/// Adds `file` to `staged`, replacing any file staged at the same repository path.
+pub fn stage(staged: &mut Vec<StagedFile>, file: StagedFile) {
+    staged.retain(|existing| existing.path_in_repo != file.path_in_repo);
+    staged.push(file);
+}
+
+/// Returns, for each uploaded file's `(sha256, size)`, the bytes not uploaded
+/// because an earlier file has the same content.
+pub fn duplicate_bytes(uploaded: &[(String, u64)]) -> Vec<u64> {
+    let mut seen = HashSet::new();
+    uploaded
+        .iter()
+        .map(|(sha256, size)| if seen.insert(sha256) { 0 } else { *size })
+        .collect()
+}
+
+/// Returns the regular files below `dir`, each with its path relative to
+/// `dir` joined with `/`, sorted by that path.
+///
+/// Symbolic links to files are followed, but links to directories aren't, so
+/// a link cycle can't make the walk endless. Directories are only visited for
+/// their files, so empty directories contribute nothing, as in git.
+pub fn folder_files(dir: &Path) -> io::Result<Vec<(PathBuf, String)>> {
+    let mut files = Vec::new();
+    let mut pending = vec![(dir.to_path_buf(), String::new())];
+    while let Some((dir, prefix)) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let relative = format!("{}{}", prefix, name);
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                pending.push((entry.path(), format!("{}/", relative)));
+            } else if fs::metadata(entry.path()).is_ok_and(|metadata| metadata.is_file()) {
+                files.push((entry.path(), relative));
+            }
+        }
+    }
+    files.sort_by(|(_, a), (_, b)| a.cmp(b));
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restaging_a_path_replaces_it() {
+        let dir = std::env::temp_dir().join(format!("swift-xet-staging-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let first = dir.join("a.bin");
+        let second = dir.join("b.bin");
+        fs::write(&first, "a").unwrap();
+        fs::write(&second, "bb").unwrap();
+
+        let mut staged = Vec::new();
+        stage(
+            &mut staged,
+            StagedFile::new(first.clone(), "model.bin".to_string()).unwrap(),
+        );
+        stage(
+            &mut staged,
+            StagedFile::new(second.clone(), "model.bin".to_string()).unwrap(),
+        );
+        assert_eq!(staged.len(), 1);
+        assert_eq!(staged[0].local_path, second);
+        assert!(staged[0].is_unchanged());
+
+        fs::write(&second, "changed").unwrap();
+        assert!(!staged[0].is_unchanged());
+        assert!(StagedFile::new(dir.clone(), "dir".to_string()).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn folders_list_their_files_recursively() {
+        let dir = std::env::temp_dir().join(format!("swift-xet-folder-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("onnx/fp16")).unwrap();
+        fs::create_dir_all(dir.join("empty")).unwrap();
+        fs::write(dir.join("config.json"), "{}").unwrap();
+        fs::write(dir.join("onnx/model.onnx"), "a").unwrap();
+        fs::write(dir.join("onnx/fp16/model.onnx"), "b").unwrap();
+
+        let files = folder_files(&dir).unwrap();
+        let paths: Vec<&str> = files.iter().map(|(_, path)| path.as_str()).collect();
+        assert_eq!(
+            paths,
+            ["config.json", "onnx/fp16/model.onnx", "onnx/model.onnx"]
+        );
+        assert_eq!(files[1].0, dir.join("onnx/fp16/model.onnx"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn repeated_content_counts_as_duplicate() {
+        let uploaded = [
+            ("a".to_string(), 3),
+            ("b".to_string(), 5),
+            ("a".to_string(), 3),
+        ];
+        assert_eq!(duplicate_bytes(&uploaded), [0, 0, 3]);
+    }
+}
@@ -0,0 +1,101 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Reads and writes the small records the cache keeps, such as resolutions,
+/// fingerprints, refs, and manifests.
+///
+/// Records are addressed by path, so a store works the same whichever root its
+/// area was placed under. Content the Xet data crate writes, and the blobs of
+/// downloaded files, are written directly rather than through this trait.
+pub trait CacheStorage: Send + Sync {
+    /// Returns the contents of the record at `path`, failing with
+    /// `io::ErrorKind::NotFound` if there is none.
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    /// Replaces the record at `path` with `contents`, so that readers see
+    /// either the previous or the next contents, never a torn record.
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()>;
+
+    /// Removes the record at `path`, if there is one.
+    #[cfg_attr(not(feature = "upload"), allow(dead_code))]
+    fn remove(&self, path: &Path) -> io::Result<()>;
+
+    /// Returns the paths of the records directly inside `dir`, or none if it doesn't exist.
+    #[cfg_attr(not(feature = "upload"), allow(dead_code))]
+    fn list(&self, dir: &Path) -> io::Result<Vec<PathBuf>>;
+}
+
+/// Stores records as files, writing each to a temporary file and renaming it into place.
+pub struct FileSystemStorage;
+
+impl CacheStorage for FileSystemStorage {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        fs::read(path)
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut temp_path = path.to_path_buf().into_os_string();
+        temp_path.push(".tmp");
+        fs::write(&temp_path, contents)?;
+        fs::rename(&temp_path, path)
+    }
+
+    fn remove(&self, path: &Path) -> io::Result<()> {
+        match fs::remove_file(path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    fn list(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut paths = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                paths.push(entry.path());
+            }
+        }
+        Ok(paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_are_replaced_and_listed() {
+        let dir = std::env::temp_dir().join(format!("swift-xet-storage-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let storage = FileSystemStorage;
+        let path = dir.join("records").join("a.json");
+
+        assert!(storage.list(&dir.join("records")).unwrap().is_empty());
+        assert_eq!(
+            storage.read(&path).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+        storage.write(&path, b"1").unwrap();
+        storage.write(&path, b"2").unwrap();
+        assert_eq!(storage.read(&path).unwrap(), b"2");
+        assert_eq!(
+            storage.list(&dir.join("records")).unwrap(),
+            vec![path.clone()]
+        );
+
+        storage.remove(&path).unwrap();
+        storage.remove(&path).unwrap();
+        assert!(storage.list(&dir.join("records")).unwrap().is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,198 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::XetError;
+
+const PLACEHOLDERS: &[&str] = &[
+    "repo_type",
+    "repo_owner",
+    "repo_name",
+    "revision",
+    "revision_short",
+    "commit",
+    "path",
+    "filename",
+];
+
+const SHORT_COMMIT_LEN: usize = 7;
+
+/// Values substituted into a destination template for one file.
+pub struct TemplateContext<'a> {
+    pub repo_type: &'a str,
+    pub repo_full_name: &'a str,
+    pub revision: &'a str,
+    pub commit: Option<&'a str>,
+    pub path: &'a str,
+}
+
+/// A validated destination naming template such as `{repo_name}/{revision_short}/{path}`.
+///
+/// Placeholders are resolved per file once its metadata is known, so
+/// `{commit}` and `{revision_short}` reflect the commit actually served.
+pub struct DestinationTemplate {
+    template: String,
+}
+
+impl DestinationTemplate {
+    pub fn parse(template: &str) -> Result<Self, XetError> {
+        if template.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: "Destination template cannot be empty".to_string(),
+            });
+        }
+
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..]
+                .find('}')
+                .ok_or_else(|| XetError::InvalidInput {
+                    message: format!("Unclosed placeholder in destination template: {}", template),
+                })?;
+            let name = &rest[start + 1..start + end];
+            if !PLACEHOLDERS.contains(&name) {
+                return Err(XetError::InvalidInput {
+                    message: format!(
+                        "Unknown placeholder {{{}}} in destination template; expected one of {}",
+                        name,
+                        PLACEHOLDERS.join(", ")
+                    ),
+                });
+            }
+            rest = &rest[start + end + 1..];
+        }
+
+        Ok(Self {
+            template: template.to_string(),
+        })
+    }
+
+    /// Renders the template relative to `root`, rejecting results that escape it.
+    pub fn render(&self, root: &Path, context: &TemplateContext) -> Result<PathBuf, XetError> {
+        let (owner, name) = context
+            .repo_full_name
+            .split_once('/')
+            .unwrap_or(("", context.repo_full_name));
+        let revision = flatten_ref(context.revision);
+        let revision_short = match context.commit {
+            Some(commit) => commit.chars().take(SHORT_COMMIT_LEN).collect(),
+            None => revision.clone(),
+        };
+        let filename = Path::new(context.path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(context.path);
+
+        // Placeholders are expanded in one pass, so a value containing
+        // placeholder text, such as a path with `{revision}` in it, is kept as is.
+        let mut rendered = String::with_capacity(self.template.len());
+        let mut rest = self.template.as_str();
+        while let Some(start) = rest.find('{') {
+            // `parse` checked that every placeholder is closed and known.
+            let end = start + rest[start..].find('}').unwrap_or(rest.len() - start);
+            rendered.push_str(&rest[..start]);
+            rendered.push_str(match &rest[start + 1..end] {
+                "repo_type" => context.repo_type,
+                "repo_owner" => owner,
+                "repo_name" => name,
+                "revision" => &revision,
+                "revision_short" => &revision_short,
+                "commit" => context.commit.unwrap_or(&revision),
+                "path" => context.path,
+                "filename" => filename,
+                _ => "",
+            });
+            rest = &rest[end + 1..];
+        }
+        rendered.push_str(rest);
+
+        let relative = Path::new(&rendered);
+        let escapes = relative
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir));
+        if escapes {
+            return Err(XetError::InvalidInput {
+                message: format!(
+                    "Destination template rendered a path outside the destination root: {}",
+                    rendered
+                ),
+            });
+        }
+
+        Ok(root.join(relative))
+    }
+}
+
+/// Flattens refs such as `refs/pr/1` into a single path component.
+fn flatten_ref(revision: &str) -> String {
+    revision.replace('/', "--")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context<'a>(commit: Option<&'a str>, path: &'a str) -> TemplateContext<'a> {
+        TemplateContext {
+            repo_type: "model",
+            repo_full_name: "Qwen/Qwen3-0.6B",
+            revision: "refs/pr/1",
+            commit,
+            path,
+        }
+    }
+
+    #[test]
+    fn render_substitutes_all_placeholders() {
+        let template = DestinationTemplate::parse(
+            "{repo_type}/{repo_owner}/{repo_name}/{revision}/{revision_short}/{path}",
+        )
+        .unwrap();
+        let rendered = template
+            .render(
+                Path::new("/models"),
+                &context(Some("0123456789abcdef"), "onnx/model.onnx"),
+            )
+            .unwrap();
+        assert_eq!(
+            rendered,
+            Path::new("/models/model/Qwen/Qwen3-0.6B/refs--pr--1/0123456/onnx/model.onnx")
+        );
+    }
+
+    #[test]
+    fn revision_short_falls_back_to_revision() {
+        let template = DestinationTemplate::parse("{revision_short}/{filename}").unwrap();
+        let rendered = template
+            .render(Path::new("/root"), &context(None, "onnx/model.onnx"))
+            .unwrap();
+        assert_eq!(rendered, Path::new("/root/refs--pr--1/model.onnx"));
+    }
+
+    #[test]
+    fn values_are_not_expanded_again() {
+        let template = DestinationTemplate::parse("{revision}/{path}").unwrap();
+        let rendered = template
+            .render(
+                Path::new("/root"),
+                &context(None, "{revision}/{commit}.bin"),
+            )
+            .unwrap();
+        assert_eq!(
+            rendered,
+            Path::new("/root/refs--pr--1/{revision}/{commit}.bin")
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unknown_placeholders() {
+        assert!(DestinationTemplate::parse("{repo}/{path}").is_err());
+        assert!(DestinationTemplate::parse("{path").is_err());
+    }
+
+    #[test]
+    fn render_rejects_escaping_paths() {
+        let template = DestinationTemplate::parse("{path}").unwrap();
+        assert!(template
+            .render(Path::new("/root"), &context(None, "../etc/passwd"))
+            .is_err());
+    }
+}
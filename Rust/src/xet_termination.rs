@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::DownloadHandle;
+
+/// How often a wait for transfers to stop rechecks the ones that are paused.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Winds down a client's transfers when the host app is about to exit.
+///
+/// Once termination is requested, transfers stop at their next checkpoint,
+/// keeping what they've written for the next launch to resume, and new ones
+/// fail before they start. A paused transfer is already held at a checkpoint,
+/// so it counts as stopped.
+#[derive(Default)]
+pub struct Termination {
+    requested: AtomicBool,
+    next_id: AtomicU64,
+    /// The transfers under way, with the handle that pauses each, if any.
+    active: Mutex<HashMap<u64, Option<Arc<DownloadHandle>>>>,
+    stopped: Condvar,
+}
+
+/// A transfer counted as under way until it's dropped.
+pub struct ActiveTransfer {
+    termination: Arc<Termination>,
+    id: u64,
+}
+
+impl Termination {
+    /// Returns whether termination has been requested.
+    pub fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::Acquire)
+    }
+
+    /// Counts a transfer, paused through `handle` if it has one, as under way.
+    pub fn track(self: &Arc<Self>, handle: Option<Arc<DownloadHandle>>) -> ActiveTransfer {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.lock().insert(id, handle);
+        ActiveTransfer {
+            termination: self.clone(),
+            id,
+        }
+    }
+
+    /// Asks transfers to stop and waits up to `timeout` for them to.
+    ///
+    /// Returns whether every transfer stopped or paused in time.
+    pub fn request(&self, timeout: Duration) -> bool {
+        self.requested.store(true, Ordering::Release);
+        let deadline = Instant::now() + timeout;
+        let mut active = self.lock();
+        loop {
+            let stopped = active
+                .values()
+                .all(|handle| handle.as_ref().is_some_and(|handle| handle.is_paused()));
+            let now = Instant::now();
+            if stopped || now >= deadline {
+                return stopped;
+            }
+            let wait = (deadline - now).min(POLL_INTERVAL);
+            active = self
+                .stopped
+                .wait_timeout(active, wait)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<u64, Option<Arc<DownloadHandle>>>> {
+        self.active.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for ActiveTransfer {
+    fn drop(&mut self) {
+        self.termination.lock().remove(&self.id);
+        self.termination.stopped.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waits_for_transfers_to_stop_or_pause() {
+        let termination = Arc::new(Termination::default());
+        assert!(termination.request(Duration::ZERO));
+
+        let termination = Arc::new(Termination::default());
+        let handle = Arc::new(DownloadHandle::new());
+        let _paused = termination.track(Some(handle.clone()));
+        let running = termination.track(None);
+        handle.pause();
+        assert!(!termination.request(Duration::from_millis(10)));
+        assert!(termination.is_requested());
+
+        let stopper = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            drop(running);
+        });
+        assert!(termination.request(Duration::from_secs(5)));
+        stopper.join().unwrap();
+    }
+}
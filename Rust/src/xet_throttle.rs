@@ -0,0 +1,81 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// A limit on download throughput shared by every transfer of a client.
+///
+/// Each transfer accounts for the bytes it receives, and is held back until
+/// they fit within the limit. Time spent idle isn't banked, so a transfer
+/// starting after a quiet period can't burst past the limit.
+pub struct Throttle {
+    /// The limit in bytes per second, or zero for no limit.
+    bytes_per_second: AtomicU64,
+    /// When the bytes accounted for so far will have been transferred at the limit.
+    next_free: Mutex<Instant>,
+}
+
+impl Throttle {
+    pub fn new(bytes_per_second: Option<u64>) -> Self {
+        Self {
+            bytes_per_second: AtomicU64::new(bytes_per_second.unwrap_or(0)),
+            next_free: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Changes the limit, taking effect for the next bytes accounted for.
+    pub fn set_limit(&self, bytes_per_second: Option<u64>) {
+        self.bytes_per_second
+            .store(bytes_per_second.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    /// Returns whether throughput is limited.
+    pub fn is_limited(&self) -> bool {
+        self.bytes_per_second.load(Ordering::Relaxed) > 0
+    }
+
+    /// Accounts for `bytes` received, waiting until earlier bytes fit within the limit.
+    pub async fn acquire(&self, bytes: u64) {
+        let bytes_per_second = self.bytes_per_second.load(Ordering::Relaxed);
+        if bytes_per_second == 0 {
+            return;
+        }
+        let cost = Duration::from_secs_f64(bytes as f64 / bytes_per_second as f64);
+        let start = {
+            let mut next_free = self
+                .next_free
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            let start = (*next_free).max(Instant::now());
+            *next_free = start + cost;
+            start
+        };
+        tokio::time::sleep_until(start.into()).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acquire_paces_to_the_limit() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let throttle = Throttle::new(Some(1000));
+        let started = Instant::now();
+        runtime.block_on(async {
+            for _ in 0..5 {
+                throttle.acquire(100).await;
+            }
+        });
+        assert!(started.elapsed() >= Duration::from_millis(400));
+
+        throttle.set_limit(None);
+        assert!(!throttle.is_limited());
+        let started = Instant::now();
+        runtime.block_on(throttle.acquire(1_000_000));
+        assert!(started.elapsed() < Duration::from_millis(400));
+    }
+}
@@ -0,0 +1,262 @@
+use std::sync::Arc;
+
+use reqwest::{Certificate, ClientBuilder};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::WebPkiServerVerifier;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, RootCertStore, SignatureScheme};
+use sha2::{Digest, Sha256};
+
+use crate::{xet_glob, CertificatePin, XetClientConfig, XetError};
+
+/// The certificates a client trusts and the pins it enforces, beyond the defaults.
+#[derive(Clone, Default)]
+pub struct TlsOptions {
+    roots: Vec<CertificateDer<'static>>,
+    /// The TLS configuration enforcing pins, if any hosts are pinned.
+    pinned: Option<rustls::ClientConfig>,
+}
+
+/// The SHA-256 digests a host must present one of.
+#[derive(Clone, Debug)]
+struct Pin {
+    host_pattern: String,
+    digests: Vec<[u8; 32]>,
+}
+
+impl TlsOptions {
+    /// Parses the additional root certificates and pins of `config`.
+    pub fn from_config(config: &XetClientConfig) -> Result<Self, XetError> {
+        let mut roots = Vec::new();
+        for pem in config.additional_root_certificates.iter().flatten() {
+            let certificates = CertificateDer::pem_slice_iter(pem.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| XetError::InvalidInput {
+                    message: format!("Invalid root certificate: {}", e),
+                })?;
+            if certificates.is_empty() {
+                return Err(XetError::InvalidInput {
+                    message: "Root certificate contains no PEM certificates".to_string(),
+                });
+            }
+            roots.extend(certificates);
+        }
+
+        let pins = config
+            .certificate_pins
+            .iter()
+            .flatten()
+            .map(Pin::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        let pinned = if pins.is_empty() {
+            None
+        } else {
+            Some(pinned_config(&roots, pins)?)
+        };
+        Ok(Self { roots, pinned })
+    }
+
+    /// Returns whether connections are verified as they would be without options.
+    pub fn is_default(&self) -> bool {
+        self.roots.is_empty() && self.pinned.is_none()
+    }
+
+    /// Returns whether any host's certificates are pinned.
+    pub fn is_pinned(&self) -> bool {
+        self.pinned.is_some()
+    }
+
+    /// Configures `builder` to trust the additional roots and enforce the pins.
+    ///
+    /// Without pins, the additional roots are trusted alongside the system's.
+    /// Pins are checked during the handshake, before a request or its token is
+    /// sent, which the system TLS stack doesn't allow; pinned clients verify
+    /// chains against the Mozilla root program and the additional roots instead.
+    pub fn apply(&self, builder: ClientBuilder) -> reqwest::Result<ClientBuilder> {
+        if let Some(pinned) = &self.pinned {
+            return Ok(builder.use_preconfigured_tls(pinned.clone()));
+        }
+        self.roots.iter().try_fold(builder, |builder, root| {
+            Ok(builder.add_root_certificate(Certificate::from_der(root)?))
+        })
+    }
+}
+
+/// Builds a TLS configuration that trusts the Mozilla roots and `roots`, and enforces `pins`.
+fn pinned_config(
+    roots: &[CertificateDer<'static>],
+    pins: Vec<Pin>,
+) -> Result<rustls::ClientConfig, XetError> {
+    let tls_error = |e: &dyn std::fmt::Display| XetError::InvalidInput {
+        message: format!("Invalid TLS configuration: {}", e),
+    };
+    let mut store = RootCertStore::from_iter(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    for root in roots {
+        store.add(root.clone()).map_err(|e| tls_error(&e))?;
+    }
+
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let inner = WebPkiServerVerifier::builder_with_provider(Arc::new(store), provider.clone())
+        .build()
+        .map_err(|e| tls_error(&e))?;
+    let verifier = PinningVerifier { inner, pins };
+    Ok(rustls::ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .map_err(|e| tls_error(&e))?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier))
+        .with_no_client_auth())
+}
+
+impl Pin {
+    fn parse(pin: &CertificatePin) -> Result<Self, XetError> {
+        xet_glob::validate(&pin.host_pattern)?;
+        if pin.sha256_hashes.is_empty() {
+            return Err(XetError::InvalidInput {
+                message: format!("Certificate pin for {} has no hashes", pin.host_pattern),
+            });
+        }
+        let digests = pin
+            .sha256_hashes
+            .iter()
+            .map(|hash| {
+                parse_digest(hash).ok_or_else(|| XetError::InvalidInput {
+                    message: format!("Invalid SHA-256 hash in certificate pin: {}", hash),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            host_pattern: pin.host_pattern.to_ascii_lowercase(),
+            digests,
+        })
+    }
+}
+
+/// Parses a hex-encoded SHA-256 digest, with or without the colons OpenSSL
+/// separates bytes with.
+fn parse_digest(hash: &str) -> Option<[u8; 32]> {
+    let hex: Vec<u8> = hash.bytes().filter(|&b| b != b':').collect();
+    if hex.len() != 64 {
+        return None;
+    }
+    let mut digest = [0u8; 32];
+    for (byte, pair) in digest.iter_mut().zip(hex.chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(digest)
+}
+
+/// Returns the SHA-256 digests of a certificate and of its public key.
+fn digests(certificate: &CertificateDer) -> Vec<[u8; 32]> {
+    let mut digests = vec![Sha256::digest(certificate).into()];
+    if let Ok(parsed) = webpki::EndEntityCert::try_from(certificate) {
+        digests.push(Sha256::digest(parsed.subject_public_key_info()).into());
+    }
+    digests
+}
+
+/// Verifies chains as usual, then requires hosts with pins to present a
+/// pinned certificate or public key somewhere in the chain.
+#[derive(Debug)]
+struct PinningVerifier {
+    inner: Arc<WebPkiServerVerifier>,
+    pins: Vec<Pin>,
+}
+
+impl ServerCertVerifier for PinningVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let verified = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        )?;
+
+        let host = server_name.to_str().to_ascii_lowercase();
+        let presented: Vec<[u8; 32]> = std::iter::once(end_entity)
+            .chain(intermediates)
+            .flat_map(digests)
+            .collect();
+        let unmatched = self.pins.iter().any(|pin| {
+            xet_glob::matches(&pin.host_pattern, &host)
+                && !pin.digests.iter().any(|digest| presented.contains(digest))
+        });
+        if unmatched {
+            return Err(rustls::Error::General(format!(
+                "{} presented no pinned certificate or public key",
+                host
+            )));
+        }
+        Ok(verified)
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pins_are_parsed_from_hex_digests() {
+        let hash = "AB".repeat(32);
+        let colons = vec!["ab"; 32].join(":");
+        assert_eq!(parse_digest(&hash), Some([0xab; 32]));
+        assert_eq!(parse_digest(&colons), Some([0xab; 32]));
+        assert_eq!(parse_digest("abcd"), None);
+        assert_eq!(parse_digest(&"zz".repeat(32)), None);
+
+        let config = |sha256_hashes: Vec<String>| XetClientConfig {
+            certificate_pins: Some(vec![CertificatePin {
+                host_pattern: "huggingface.co".to_string(),
+                sha256_hashes,
+            }]),
+            ..XetClientConfig::default()
+        };
+        let options = TlsOptions::from_config(&config(vec![hash])).unwrap();
+        assert!(options.is_pinned());
+        assert!(TlsOptions::from_config(&config(Vec::new())).is_err());
+        assert!(TlsOptions::from_config(&config(vec!["abcd".to_string()])).is_err());
+    }
+
+    #[test]
+    fn root_certificates_must_be_pem() {
+        let config = XetClientConfig {
+            additional_root_certificates: Some(vec!["not a certificate".to_string()]),
+            ..XetClientConfig::default()
+        };
+        assert!(TlsOptions::from_config(&config).is_err());
+        assert!(TlsOptions::from_config(&XetClientConfig::default())
+            .unwrap()
+            .is_default());
+    }
+}
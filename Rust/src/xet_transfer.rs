@@ -0,0 +1,453 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::Arc;
+#[cfg(feature = "upload")]
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::xet_callbacks::CallbackDispatcher;
+use crate::xet_deadline;
+use crate::xet_termination::Termination;
+use crate::xet_throttle::Throttle;
+use crate::xet_verify::StreamingHasher;
+use crate::{
+    CancellationReason, CancellationToken, DownloadHandle, ErrorCause, ProgressCallback,
+    ProgressUpdate, XetError,
+};
+
+pub const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Upper bound on bytes held in memory between the network and the file.
+///
+/// Response chunks are often only a few kilobytes, so they are coalesced
+/// into writes of up to this size rather than issuing a syscall per chunk.
+const WRITE_BUFFER_SIZE: usize = 256 * 1024;
+
+/// Progress reporting, pausing, and cancellation for a single transfer.
+#[derive(Clone, Default)]
+pub struct TransferObserver {
+    progress: Option<Arc<dyn ProgressCallback>>,
+    cancellation: Option<Arc<CancellationToken>>,
+    handle: Option<Arc<DownloadHandle>>,
+    throttle: Option<Arc<Throttle>>,
+    callbacks: Option<Arc<CallbackDispatcher>>,
+    termination: Option<Arc<Termination>>,
+}
+
+impl TransferObserver {
+    pub fn new(
+        progress: Option<Arc<dyn ProgressCallback>>,
+        cancellation: Option<Arc<CancellationToken>>,
+        callbacks: Arc<CallbackDispatcher>,
+    ) -> Self {
+        Self {
+            progress,
+            cancellation,
+            handle: None,
+            throttle: None,
+            callbacks: Some(callbacks),
+            termination: None,
+        }
+    }
+
+    /// Pauses and cancels the transfer through `handle`.
+    pub fn with_handle(mut self, handle: Arc<DownloadHandle>) -> Self {
+        self.cancellation = Some(handle.cancellation.clone());
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Limits the transfer's throughput with `throttle`.
+    pub fn with_throttle(mut self, throttle: Arc<Throttle>) -> Self {
+        self.throttle = Some(throttle);
+        self
+    }
+
+    /// Stops the transfer once the host app is about to exit.
+    pub fn with_termination(mut self, termination: Arc<Termination>) -> Self {
+        self.termination = Some(termination);
+        self
+    }
+
+    /// Returns the handle that pauses the transfer, if it has one.
+    pub fn handle(&self) -> Option<Arc<DownloadHandle>> {
+        self.handle.clone()
+    }
+
+    pub fn check_cancelled(&self) -> Result<(), XetError> {
+        if self
+            .termination
+            .as_ref()
+            .is_some_and(|termination| termination.is_requested())
+        {
+            return Err(XetError::Cancelled {
+                message: "The transfer was stopped because the app is terminating".to_string(),
+                reason: CancellationReason::AppTerminating,
+            });
+        }
+        match &self.cancellation {
+            Some(token) if token.is_cancelled() => Err(XetError::Cancelled {
+                message: "The transfer was cancelled".to_string(),
+                reason: token.reason().unwrap_or_default(),
+            }),
+            _ if self.is_past_deadline() => Err(xet_deadline::timeout_error()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns when the transfer times out, if it does.
+    pub fn deadline(&self) -> Option<Instant> {
+        let token = self.cancellation.as_ref()?;
+        token.deadline()
+    }
+
+    /// Returns the label the transfer is attributed to, if its call names one.
+    pub fn label(&self) -> Option<String> {
+        self.cancellation.as_ref()?.label()
+    }
+
+    fn is_past_deadline(&self) -> bool {
+        self.deadline()
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Waits out a pause, then checks for cancellation.
+    pub async fn checkpoint(&self) -> Result<(), XetError> {
+        if let Some(handle) = self.handle.as_ref().filter(|handle| handle.is_paused()) {
+            handle.wait_until_resumed().await;
+        }
+        self.check_cancelled()
+    }
+
+    /// Accounts for `bytes` received, waiting while the transfer is over its throughput limit.
+    pub async fn account(&self, bytes: u64) {
+        if let Some(throttle) = &self.throttle {
+            throttle.acquire(bytes).await;
+        }
+    }
+
+    /// Returns the number of times the transfer has been paused.
+    pub fn pauses(&self) -> u64 {
+        self.handle.as_ref().map_or(0, |handle| handle.pauses())
+    }
+
+    /// Reports progress on the callback thread, keeping host code off the runtime.
+    pub fn report(&self, completed: u64, total: u64) {
+        if let (Some(progress), Some(callbacks)) = (&self.progress, &self.callbacks) {
+            let progress = progress.clone();
+            callbacks.dispatch(move || {
+                progress.on_progress(Arc::new(ProgressUpdate::for_transfer(completed, total)))
+            });
+        }
+    }
+}
+
+/// Forwards the Xet data crate's progress on an upload to a host callback.
+///
+/// The data crate reports the bytes it has chunked and hashed as processed,
+/// and the bytes it has sent after deduplication as transferred, so the two
+/// advance separately and the transfer total can be far below the file size.
+/// An upload made in batches reports each batch's progress on top of the
+/// batches before it, and files a resumed upload already sent count as
+/// processed from the start.
+#[cfg(feature = "upload")]
+pub struct UploadProgress {
+    progress: Arc<dyn ProgressCallback>,
+    callbacks: Arc<CallbackDispatcher>,
+    total_bytes: u64,
+    /// The counts of the batches before the current one, and of the current one so far.
+    counts: Mutex<(UploadCounts, UploadCounts)>,
+}
+
+#[cfg(feature = "upload")]
+#[derive(Clone, Copy, Default)]
+struct UploadCounts {
+    processed: u64,
+    transfer: u64,
+    transferred: u64,
+}
+
+#[cfg(feature = "upload")]
+impl UploadProgress {
+    /// Reports progress on an upload of `total_bytes`, of which
+    /// `resumed_bytes` were sent by an earlier attempt.
+    pub fn new(
+        progress: Arc<dyn ProgressCallback>,
+        callbacks: Arc<CallbackDispatcher>,
+        total_bytes: u64,
+        resumed_bytes: u64,
+    ) -> Self {
+        let resumed = UploadCounts {
+            processed: resumed_bytes,
+            ..UploadCounts::default()
+        };
+        Self {
+            progress,
+            callbacks,
+            total_bytes,
+            counts: Mutex::new((resumed, UploadCounts::default())),
+        }
+    }
+
+    /// Starts counting the next batch on top of the ones before it.
+    pub fn next_batch(&self) {
+        let mut counts = self.counts.lock().unwrap_or_else(PoisonError::into_inner);
+        let (carried, batch) = &mut *counts;
+        carried.processed += batch.processed;
+        carried.transfer += batch.transfer;
+        carried.transferred += batch.transferred;
+        *batch = UploadCounts::default();
+    }
+}
+
+#[cfg(feature = "upload")]
+#[async_trait::async_trait]
+impl progress_tracking::TrackingProgressUpdater for UploadProgress {
+    async fn register_updates(&self, update: progress_tracking::ProgressUpdate) {
+        let update = {
+            let mut counts = self.counts.lock().unwrap_or_else(PoisonError::into_inner);
+            let (carried, batch) = &mut *counts;
+            *batch = UploadCounts {
+                processed: update.total_bytes_completed,
+                transfer: update.total_transfer_bytes,
+                transferred: update.total_transfer_bytes_completed,
+            };
+            ProgressUpdate {
+                total_bytes: self.total_bytes,
+                total_bytes_completed: carried.processed + batch.processed,
+                total_transfer_bytes: carried.transfer + batch.transfer,
+                total_transfer_bytes_completed: carried.transferred + batch.transferred,
+            }
+        };
+        let progress = self.progress.clone();
+        self.callbacks
+            .dispatch(move || progress.on_progress(Arc::new(update)));
+    }
+}
+
+/// Streams a response body to `destination` one chunk at a time.
+///
+/// Memory use is bounded by `WRITE_BUFFER_SIZE` plus a single response chunk,
+/// regardless of the file's size, and throughput is held to the observer's
+/// limit, if any. Progress is reported at most every `PROGRESS_INTERVAL`, plus
+/// once at the start and end, and pauses and cancellation are checked between
+/// chunks. Returns the number of bytes in the file and, when `hash` is set, the
+/// file's SHA-256.
+///
+/// With `resume_from`, a `206 Partial Content` response is appended to the
+/// first `resume_from` bytes already in `destination`, and a failed or
+/// cancelled transfer keeps what it wrote for the next attempt. Otherwise the
+/// file is written from the start and removed if the transfer fails.
+///
+/// The transfer fails with `XetError::PolicyViolation` once the file grows
+/// past `max_size`, which holds responses that don't state their length to
+/// the client's file size limit.
+pub async fn write_response(
+    mut response: reqwest::Response,
+    destination: &Path,
+    hash: bool,
+    resume_from: Option<u64>,
+    max_size: Option<u64>,
+    observer: &TransferObserver,
+) -> Result<(u64, Option<(String, Duration)>), XetError> {
+    let offset = match resume_from {
+        Some(offset) if response.status() == reqwest::StatusCode::PARTIAL_CONTENT => offset,
+        _ => 0,
+    };
+    let result = stream_body(&mut response, destination, hash, offset, max_size, observer).await;
+    if result.is_err() && resume_from.is_none() {
+        let _ = fs::remove_file(destination);
+    }
+    result
+}
+
+async fn stream_body(
+    response: &mut reqwest::Response,
+    destination: &Path,
+    hash: bool,
+    offset: u64,
+    max_size: Option<u64>,
+    observer: &TransferObserver,
+) -> Result<(u64, Option<(String, Duration)>), XetError> {
+    let write_error = |e: std::io::Error| XetError::IoError {
+        message: format!("Failed to write file: {}", e),
+        cause: Some(ErrorCause::from(&e)),
+    };
+
+    let total = response.content_length().unwrap_or(0) + offset;
+    let mut hasher = hash.then(StreamingHasher::new);
+    let file = if offset > 0 {
+        if let Some(hasher) = hasher.as_mut() {
+            hash_prefix(destination, offset, hasher).map_err(write_error)?;
+        }
+        let mut file = OpenOptions::new()
+            .write(true)
+            .open(destination)
+            .map_err(write_error)?;
+        // Drop anything past the offset the server is resuming from.
+        file.set_len(offset).map_err(write_error)?;
+        file.seek(SeekFrom::Start(offset)).map_err(write_error)?;
+        file
+    } else {
+        File::create(destination).map_err(write_error)?
+    };
+    let mut file = BufWriter::with_capacity(WRITE_BUFFER_SIZE, file);
+    let mut written: u64 = offset;
+    let mut last_report = Instant::now();
+    observer.report(0, total);
+
+    while let Some(chunk) = response.chunk().await.map_err(|e| XetError::NetworkError {
+        message: format!("Failed to read response body: {}", e),
+        cause: Some(ErrorCause::from(&e)),
+    })? {
+        observer.checkpoint().await?;
+        if let Some(max) = max_size.filter(|max| written + chunk.len() as u64 > *max) {
+            return Err(XetError::PolicyViolation {
+                message: format!(
+                    "The download is larger than the maximum file size of {} bytes",
+                    max
+                ),
+            });
+        }
+        file.write_all(&chunk).map_err(write_error)?;
+        if let Some(hasher) = hasher.as_mut() {
+            hasher.update(&chunk);
+        }
+        written += chunk.len() as u64;
+        observer.account(chunk.len() as u64).await;
+
+        if last_report.elapsed() >= PROGRESS_INTERVAL {
+            observer.report(written, total.max(written));
+            last_report = Instant::now();
+        }
+    }
+
+    file.flush().map_err(write_error)?;
+    observer.report(written, written);
+    Ok((written, hasher.map(StreamingHasher::finish)))
+}
+
+/// Feeds the first `len` bytes of `path` to `hasher`, so a resumed file hashes as a whole.
+fn hash_prefix(path: &Path, len: u64, hasher: &mut StreamingHasher) -> std::io::Result<()> {
+    let mut prefix = File::open(path)?.take(len);
+    let mut buffer = vec![0u8; WRITE_BUFFER_SIZE];
+    loop {
+        let read = prefix.read(&mut buffer)?;
+        if read == 0 {
+            return Ok(());
+        }
+        hasher.update(&buffer[..read]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_cancelled_follows_token() {
+        let token = Arc::new(CancellationToken::new());
+        let callbacks = Arc::new(CallbackDispatcher::new().unwrap());
+        let observer = TransferObserver::new(None, Some(token.clone()), callbacks);
+        assert!(observer.check_cancelled().is_ok());
+
+        token.cancel_with_reason(CancellationReason::NetworkConditions);
+        token.cancel();
+        assert!(matches!(
+            observer.check_cancelled(),
+            Err(XetError::Cancelled {
+                reason: CancellationReason::NetworkConditions,
+                ..
+            })
+        ));
+        assert!(TransferObserver::default().check_cancelled().is_ok());
+    }
+
+    #[test]
+    fn checkpoint_waits_out_pauses() {
+        let handle = Arc::new(DownloadHandle::new());
+        let callbacks = Arc::new(CallbackDispatcher::new().unwrap());
+        let observer = TransferObserver::new(None, None, callbacks).with_handle(handle.clone());
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        assert!(runtime.block_on(observer.checkpoint()).is_ok());
+
+        handle.pause();
+        handle.pause();
+        assert_eq!(observer.pauses(), 2);
+        let resumer = {
+            let handle = handle.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(50));
+                handle.resume();
+            })
+        };
+        assert!(runtime.block_on(observer.checkpoint()).is_ok());
+        assert!(!handle.is_paused());
+        resumer.join().unwrap();
+
+        handle.pause();
+        let canceller = {
+            let handle = handle.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(50));
+                handle.cancel();
+            })
+        };
+        assert!(matches!(
+            runtime.block_on(observer.checkpoint()),
+            Err(XetError::Cancelled { .. })
+        ));
+        canceller.join().unwrap();
+    }
+
+    #[cfg(feature = "upload")]
+    #[test]
+    fn upload_progress_keeps_processing_and_transfer_apart() {
+        use progress_tracking::TrackingProgressUpdater;
+        use std::sync::Mutex;
+
+        struct Recorder(Mutex<Vec<(u64, u64)>>);
+        impl ProgressCallback for Recorder {
+            fn on_progress(&self, update: Arc<ProgressUpdate>) {
+                let bytes = (
+                    update.total_bytes_completed(),
+                    update.total_transfer_bytes_completed(),
+                );
+                self.0.lock().unwrap().push(bytes);
+            }
+        }
+
+        let recorder = Arc::new(Recorder(Mutex::new(Vec::new())));
+        let callbacks = Arc::new(CallbackDispatcher::new().unwrap());
+        let progress = UploadProgress::new(recorder.clone(), callbacks.clone(), 300, 100);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        // The first 100 bytes were sent before the upload was interrupted. Of
+        // the rest, the second file deduplicates against the first, so nothing
+        // more is sent for it, and it's uploaded in a batch of its own.
+        for (processed, transferred) in [(100, 0), (100, 60), (0, 0), (100, 0)] {
+            if processed == 0 {
+                progress.next_batch();
+            }
+            runtime.block_on(
+                progress.register_updates(progress_tracking::ProgressUpdate {
+                    total_bytes: 100,
+                    total_bytes_completed: processed,
+                    total_transfer_bytes: 60,
+                    total_transfer_bytes_completed: transferred,
+                    ..Default::default()
+                }),
+            );
+        }
+        callbacks.flush();
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [(200, 0), (200, 60), (200, 60), (300, 60)]
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::PathNormalization;
+
+/// Applies `policy` to a local path.
+pub fn normalize_path(path: &str, policy: PathNormalization) -> String {
+    match policy {
+        PathNormalization::Nfc => path.nfc().collect(),
+        PathNormalization::Nfd => path.nfd().collect(),
+        PathNormalization::Preserve => path.to_string(),
+    }
+}
+
+/// Returns a form of `path` that is equal for canonically equivalent paths.
+///
+/// APFS and HFS+ treat names that differ only in normalization as the same
+/// file, so local paths are compared in this form.
+pub fn comparison_key(path: &str) -> String {
+    path.nfc().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // "é" as a single code point and as "e" followed by a combining acute accent.
+    const COMPOSED: &str = "caf\u{e9}/r\u{e9}sum\u{e9}.txt";
+    const DECOMPOSED: &str = "cafe\u{301}/re\u{301}sume\u{301}.txt";
+
+    #[test]
+    fn normalize_path_follows_policy() {
+        assert_eq!(normalize_path(DECOMPOSED, PathNormalization::Nfc), COMPOSED);
+        assert_eq!(normalize_path(COMPOSED, PathNormalization::Nfd), DECOMPOSED);
+        assert_eq!(
+            normalize_path(DECOMPOSED, PathNormalization::Preserve),
+            DECOMPOSED
+        );
+        assert_eq!(comparison_key(COMPOSED), comparison_key(DECOMPOSED));
+    }
+}
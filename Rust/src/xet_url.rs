@@ -0,0 +1,162 @@
+/// Escapes a path in a repository for use in a URL, keeping the slashes between its components.
+///
+/// Every other character outside the unreserved set is percent-encoded as
+/// UTF-8, so names with spaces, `#`, `?`, `+`, `%`, or non-ASCII characters
+/// reach the Hub as written, the way `huggingface_hub` sends them.
+pub fn encode_path(path: &str) -> String {
+    path.split('/')
+        .map(urlencoding::encode)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Escapes a revision as a single URL path segment or query value.
+///
+/// Refs such as `refs/pr/1` or `refs/convert/parquet` contain slashes, which
+/// are escaped too, so the revision can't be mistaken for part of a path.
+pub fn encode_revision(revision: &str) -> String {
+    urlencoding::encode(revision).into_owned()
+}
+
+/// Returns the URL of a Hub API route taking a revision, such as `tree` or `commit`.
+///
+/// The Hub routes these as `/api/<type>/<repo>/<route>/<revision>`, so a
+/// branch such as `release/v1.0` only reaches the right route escaped.
+pub fn revision_route(
+    endpoint: &str,
+    repo_type_plural: &str,
+    repo_full_name: &str,
+    route: &str,
+    revision: &str,
+) -> String {
+    format!(
+        "{}/api/{}/{}/{}/{}",
+        endpoint.trim_end_matches('/'),
+        repo_type_plural,
+        repo_full_name,
+        route,
+        encode_revision(revision)
+    )
+}
+
+/// Returns the URL of the Hub's archive of a repository at `revision`, such as
+/// `https://huggingface.co/datasets/owner/data/archive/main.zip`.
+///
+/// Archives are served from the repository's page rather than the API, where
+/// models have no type prefix.
+pub fn archive_url(
+    endpoint: &str,
+    repo_type_plural: &str,
+    repo_full_name: &str,
+    revision: &str,
+    extension: &str,
+) -> String {
+    let prefix = match repo_type_plural {
+        "models" => String::new(),
+        other => format!("{}/", other),
+    };
+    format!(
+        "{}/{}{}/archive/{}.{}",
+        endpoint.trim_end_matches('/'),
+        prefix,
+        repo_full_name,
+        encode_revision(revision),
+        extension
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_keep_their_slashes_and_escape_everything_else() {
+        let cases = [
+            ("config.json", "config.json"),
+            ("onnx/model.onnx", "onnx/model.onnx"),
+            ("my file.txt", "my%20file.txt"),
+            ("notes#1.md", "notes%231.md"),
+            ("what?.txt", "what%3F.txt"),
+            ("c++/a+b.h", "c%2B%2B/a%2Bb.h"),
+            ("100%.txt", "100%25.txt"),
+            ("a&b=c;d.txt", "a%26b%3Dc%3Bd.txt"),
+            ("café/naïve.txt", "caf%C3%A9/na%C3%AFve.txt"),
+            ("日本/語.txt", "%E6%97%A5%E6%9C%AC/%E8%AA%9E.txt"),
+            ("-_.~", "-_.~"),
+        ];
+        for (path, encoded) in cases {
+            assert_eq!(encode_path(path), encoded, "{}", path);
+            assert_eq!(
+                urlencoding::decode(&encode_path(path)).unwrap(),
+                path,
+                "{}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn revisions_are_one_segment() {
+        assert_eq!(encode_revision("main"), "main");
+        assert_eq!(encode_revision("v1.0"), "v1.0");
+        assert_eq!(encode_revision("refs/pr/1"), "refs%2Fpr%2F1");
+        assert_eq!(
+            encode_revision("refs/convert/parquet"),
+            "refs%2Fconvert%2Fparquet"
+        );
+        assert_eq!(encode_revision("feature #2"), "feature%20%232");
+    }
+
+    #[test]
+    fn revision_routes_keep_slashed_branches_in_one_segment() {
+        assert_eq!(
+            revision_route(
+                "https://huggingface.co/",
+                "models",
+                "owner/repo",
+                "tree",
+                "main"
+            ),
+            "https://huggingface.co/api/models/owner/repo/tree/main"
+        );
+        for route in ["tree", "paths-info", "revision", "commit", "xet-read-token"] {
+            assert_eq!(
+                revision_route(
+                    "https://huggingface.co",
+                    "datasets",
+                    "owner/data",
+                    route,
+                    "release/v1.0"
+                ),
+                format!(
+                    "https://huggingface.co/api/datasets/owner/data/{}/release%2Fv1.0",
+                    route
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn archives_are_served_from_repository_pages() {
+        assert_eq!(
+            archive_url(
+                "https://huggingface.co/",
+                "models",
+                "owner/model",
+                "main",
+                "tar.gz"
+            ),
+            "https://huggingface.co/owner/model/archive/main.tar.gz"
+        );
+        assert_eq!(
+            archive_url(
+                "https://huggingface.co",
+                "datasets",
+                "owner/data",
+                "release/v1.0",
+                "zip"
+            ),
+            "https://huggingface.co/datasets/owner/data/archive/release%2Fv1.0.zip"
+        );
+    }
+}
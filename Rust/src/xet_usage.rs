@@ -0,0 +1,286 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use reqwest::header::CONTENT_LENGTH;
+use reqwest::{Method, Response, Url};
+
+use crate::{EndpointKind, HostUsage, LabelUsage};
+
+thread_local! {
+    /// The label the call running on this thread attributes its transfers to, if any.
+    static LABEL: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+tokio::task_local! {
+    /// The ledger the client call running on this task counts its requests in,
+    /// and the label it attributes them to.
+    static TRAFFIC: (Arc<UsageLedger>, Option<String>);
+}
+
+/// Restores the label a scope replaced, even if the call in it panics.
+struct Restore(Option<String>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        LABEL.set(self.0.take());
+    }
+}
+
+/// Runs `call` with the transfers it completes attributed to `label`.
+///
+/// Without a label, the label already in effect on this thread is kept.
+pub fn scoped<T>(label: Option<String>, call: impl FnOnce() -> T) -> T {
+    if label.is_none() {
+        return call();
+    }
+    let _restore = Restore(LABEL.replace(label));
+    call()
+}
+
+/// Returns the label in effect on this thread, if any.
+pub fn current() -> Option<String> {
+    LABEL.with_borrow(Clone::clone)
+}
+
+/// Counts a response to a request with a body of `sent` bytes in the calling
+/// task's ledger, if it has one.
+///
+/// The response is attributed to the host it came from after any redirects,
+/// by its declared length, so a body that's abandoned partway still counts
+/// in full. Responses to `HEAD` requests have no body.
+pub fn count_response(method: &Method, sent: u64, response: &Response) {
+    let _ = TRAFFIC.try_with(|(ledger, label)| {
+        let received = match *method {
+            Method::HEAD => 0,
+            _ => response
+                .headers()
+                .get(CONTENT_LENGTH)
+                .and_then(|value| value.to_str().ok()?.parse().ok())
+                .unwrap_or(0),
+        };
+        let url = response.url();
+        ledger.record_traffic(label.clone(), url, endpoint_kind(url), sent, received);
+    });
+}
+
+/// Wraps `future`, which will run on a task of its own, in the calling task's ledger.
+pub fn inherit<F: Future>(future: F) -> impl Future<Output = F::Output> {
+    let traffic = TRAFFIC.try_with(Clone::clone).ok();
+    async move {
+        match traffic {
+            Some(traffic) => TRAFFIC.scope(traffic, future).await,
+            None => future.await,
+        }
+    }
+}
+
+/// Returns the kind of service that answered a request for `url`.
+///
+/// Hub pages and API routes, including resolve URLs the Hub answers without
+/// redirecting, are under `/api/` or contain `/resolve/`; the CAS API is
+/// versioned under `/v1/`; anything else, such as a redirect's signed target
+/// or a xorb range, comes from a CDN or storage bucket.
+pub fn endpoint_kind(url: &Url) -> EndpointKind {
+    let path = url.path();
+    if path.starts_with("/api/") || path.contains("/resolve/") {
+        EndpointKind::Hub
+    } else if path.starts_with("/v1/") {
+        EndpointKind::Cas
+    } else {
+        EndpointKind::Cdn
+    }
+}
+
+/// Totals of the transfers a client has completed, by label.
+#[derive(Default)]
+pub struct UsageLedger {
+    labels: Mutex<BTreeMap<Option<String>, LabelUsage>>,
+}
+
+impl UsageLedger {
+    /// Runs `future` with the requests it sends counted in this ledger under `label`.
+    pub fn scope<F: Future>(
+        self: &Arc<Self>,
+        label: Option<String>,
+        future: F,
+    ) -> impl Future<Output = F::Output> {
+        TRAFFIC.scope((self.clone(), label), future)
+    }
+
+    /// Adds `sent` and `received` bytes exchanged with the host of `url` to `label`'s totals.
+    pub fn record_traffic(
+        &self,
+        label: Option<String>,
+        url: &Url,
+        kind: EndpointKind,
+        sent: u64,
+        received: u64,
+    ) {
+        let Some(host) = url.host_str() else {
+            return;
+        };
+        let host = match url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_string(),
+        };
+        let mut labels = self.labels.lock().unwrap_or_else(PoisonError::into_inner);
+        let usage = label_usage(&mut labels, label);
+        let index = match usage.hosts.binary_search_by(|usage| usage.host.cmp(&host)) {
+            Ok(index) => index,
+            Err(index) => {
+                usage.hosts.insert(
+                    index,
+                    HostUsage {
+                        host,
+                        kind,
+                        bytes_sent: 0,
+                        bytes_received: 0,
+                        request_count: 0,
+                    },
+                );
+                index
+            }
+        };
+        let host = &mut usage.hosts[index];
+        host.bytes_sent = host.bytes_sent.saturating_add(sent);
+        host.bytes_received = host.bytes_received.saturating_add(received);
+        host.request_count += 1;
+    }
+
+    /// Adds a completed download of `bytes` that took `elapsed` to `label`'s totals.
+    pub fn record_download(&self, label: Option<String>, bytes: u64, elapsed: Duration) {
+        self.record(label, elapsed, |usage| {
+            usage.bytes_downloaded = usage.bytes_downloaded.saturating_add(bytes);
+        });
+    }
+
+    /// Adds a completed upload of `bytes` that took `elapsed` to `label`'s totals.
+    #[cfg(feature = "upload")]
+    pub fn record_upload(&self, label: Option<String>, bytes: u64, elapsed: Duration) {
+        self.record(label, elapsed, |usage| {
+            usage.bytes_uploaded = usage.bytes_uploaded.saturating_add(bytes);
+        });
+    }
+
+    fn record(&self, label: Option<String>, elapsed: Duration, add: impl FnOnce(&mut LabelUsage)) {
+        let mut labels = self.labels.lock().unwrap_or_else(PoisonError::into_inner);
+        let usage = label_usage(&mut labels, label);
+        add(usage);
+        usage.transfer_count += 1;
+        usage.elapsed_ms = usage.elapsed_ms.saturating_add(elapsed.as_millis() as u64);
+    }
+
+    /// Returns the totals of each label with any transfers or requests, unlabeled ones first.
+    pub fn snapshot(&self) -> Vec<LabelUsage> {
+        self.labels
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Forgets every total recorded so far.
+    pub fn reset(&self) {
+        self.labels
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+fn label_usage(
+    labels: &mut BTreeMap<Option<String>, LabelUsage>,
+    label: Option<String>,
+) -> &mut LabelUsage {
+    labels.entry(label.clone()).or_insert_with(|| LabelUsage {
+        label,
+        ..LabelUsage::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transfers_are_totaled_by_label() {
+        let ledger = UsageLedger::default();
+        let second = Duration::from_secs(1);
+        scoped(Some("onboarding".to_string()), || {
+            scoped(None, || ledger.record_download(current(), 10, second));
+            #[cfg(feature = "upload")]
+            ledger.record_upload(current(), 5, second);
+        });
+        ledger.record_download(current(), 7, second);
+
+        let usage = ledger.snapshot();
+        assert_eq!(usage.len(), 2);
+        assert_eq!(usage[0].label, None);
+        assert_eq!(usage[0].bytes_downloaded, 7);
+        assert_eq!(usage[1].label.as_deref(), Some("onboarding"));
+        assert_eq!(usage[1].bytes_downloaded, 10);
+        #[cfg(feature = "upload")]
+        {
+            assert_eq!(usage[1].bytes_uploaded, 5);
+            assert_eq!(usage[1].transfer_count, 2);
+            assert_eq!(usage[1].elapsed_ms, 2000);
+        }
+
+        ledger.reset();
+        assert!(ledger.snapshot().is_empty());
+    }
+
+    #[test]
+    fn traffic_is_totaled_by_host() {
+        let ledger = UsageLedger::default();
+        let url = |url: &str| Url::parse(url).unwrap();
+        let resolve = url("https://huggingface.co/owner/repo/resolve/main/model.bin");
+        let reconstruction = url("https://cas-server.xethub.hf.co/v1/reconstructions/abc");
+        let xorb = url("https://transfer.xethub.hf.co/xorbs/default/abc?X-Amz-Signature=x");
+        assert_eq!(endpoint_kind(&resolve), EndpointKind::Hub);
+        assert_eq!(endpoint_kind(&reconstruction), EndpointKind::Cas);
+        assert_eq!(endpoint_kind(&xorb), EndpointKind::Cdn);
+
+        for (url, received) in [
+            (&resolve, 10),
+            (&reconstruction, 20),
+            (&xorb, 300),
+            (&xorb, 400),
+        ] {
+            ledger.record_traffic(None, url, endpoint_kind(url), 0, received);
+        }
+        let local = url("http://localhost:8080/api/models/owner/repo");
+        ledger.record_traffic(None, &local, EndpointKind::Hub, 5, 1);
+
+        let usage = ledger.snapshot();
+        assert_eq!(usage.len(), 1);
+        assert_eq!(usage[0].transfer_count, 0);
+        let hosts: Vec<(&str, u64, u64, u64)> = usage[0]
+            .hosts
+            .iter()
+            .map(|host| {
+                let HostUsage {
+                    host,
+                    bytes_sent,
+                    bytes_received,
+                    request_count,
+                    ..
+                } = host;
+                (host.as_str(), *bytes_sent, *bytes_received, *request_count)
+            })
+            .collect();
+        assert_eq!(
+            hosts,
+            [
+                ("cas-server.xethub.hf.co", 0, 20, 1),
+                ("huggingface.co", 0, 10, 1),
+                ("localhost:8080", 5, 1, 1),
+                ("transfer.xethub.hf.co", 0, 700, 2),
+            ]
+        );
+    }
+}
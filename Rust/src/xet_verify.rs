@@ -0,0 +1,158 @@
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use sha2::{Digest, Sha256};
+
+use crate::XetError;
+
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Returns the SHA-256 an etag commits to, if it is one.
+///
+/// The Hub reports the SHA-256 of LFS and Xet-backed files as their linked
+/// etag; plain git blobs use a git object ID, which can't be checked this way.
+pub fn expected_sha256(etag: &str) -> Option<String> {
+    let etag = etag.trim_start_matches("W/").trim_matches('"');
+    if etag.len() == 64 && etag.chars().all(|c| c.is_ascii_hexdigit()) {
+        Some(etag.to_ascii_lowercase())
+    } else {
+        None
+    }
+}
+
+/// Incremental SHA-256 that tracks only the time spent hashing.
+pub struct StreamingHasher {
+    hasher: Sha256,
+    busy: Duration,
+}
+
+impl StreamingHasher {
+    pub fn new() -> Self {
+        Self {
+            hasher: Sha256::new(),
+            busy: Duration::ZERO,
+        }
+    }
+
+    pub fn update(&mut self, chunk: &[u8]) {
+        let started = Instant::now();
+        self.hasher.update(chunk);
+        self.busy += started.elapsed();
+    }
+
+    pub fn finish(self) -> (String, Duration) {
+        let started = Instant::now();
+        let digest = format!("{:x}", self.hasher.finalize());
+        (digest, self.busy + started.elapsed())
+    }
+}
+
+/// Hashes a file written in pieces, in any order, as its written prefix grows.
+///
+/// Each call to `advance` hashes the bytes the latest pieces joined onto the
+/// start of the file, reading them back while they're likely still cached, so
+/// hashing overlaps the transfer instead of rereading the file once it's done.
+pub struct PrefixHasher {
+    path: PathBuf,
+    hasher: StreamingHasher,
+    hashed: u64,
+}
+
+impl PrefixHasher {
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            hasher: StreamingHasher::new(),
+            hashed: 0,
+        }
+    }
+
+    /// Hashes whatever the sorted, disjoint `written` ranges add to the prefix hashed so far.
+    pub fn advance(&mut self, written: &[(u64, u64)]) -> io::Result<()> {
+        let end = match written.first() {
+            Some(&(0, end)) if end > self.hashed => end,
+            _ => return Ok(()),
+        };
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(self.hashed))?;
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        while self.hashed < end {
+            let len = (end - self.hashed).min(CHUNK_SIZE as u64) as usize;
+            file.read_exact(&mut buffer[..len])?;
+            self.hasher.update(&buffer[..len]);
+            self.hashed += len as u64;
+        }
+        Ok(())
+    }
+
+    /// Returns the digest of the first `size` bytes, hashing any not hashed yet.
+    pub fn finish(mut self, size: u64) -> io::Result<(String, Duration)> {
+        self.advance(&[(0, size)])?;
+        Ok(self.hasher.finish())
+    }
+}
+
+pub fn hash_file(path: &Path) -> io::Result<(String, Duration)> {
+    let mut file = File::open(path)?;
+    let mut hasher = StreamingHasher::new();
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            return Ok(hasher.finish());
+        }
+        hasher.update(&buffer[..read]);
+    }
+}
+
+pub fn mismatch_error(path: &str, expected: &str, actual: &str) -> XetError {
+    XetError::IntegrityError {
+        message: format!(
+            "SHA-256 of {} is {}, expected {}",
+            path, actual, expected
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HELLO_SHA256: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+    #[test]
+    fn expected_sha256_accepts_only_sha256_etags() {
+        assert_eq!(
+            expected_sha256(&format!("\"{}\"", HELLO_SHA256.to_uppercase())).as_deref(),
+            Some(HELLO_SHA256)
+        );
+        assert!(expected_sha256("a94a8fe5ccb19ba61c4c0873d391e987982fbbd3").is_none());
+    }
+
+    #[test]
+    fn streaming_hasher_matches_known_digest() {
+        let mut hasher = StreamingHasher::new();
+        hasher.update(b"hel");
+        hasher.update(b"lo");
+        assert_eq!(hasher.finish().0, HELLO_SHA256);
+    }
+
+    #[test]
+    fn prefix_hasher_hashes_pieces_written_out_of_order() {
+        let path =
+            std::env::temp_dir().join(format!("swift-xet-verify-prefix-{}", std::process::id()));
+        std::fs::write(&path, b"\0\0\0lo").unwrap();
+
+        let mut hasher = PrefixHasher::new(&path);
+        hasher.advance(&[(3, 5)]).unwrap();
+        assert_eq!(hasher.hashed, 0);
+
+        std::fs::write(&path, b"hello").unwrap();
+        hasher.advance(&[(0, 5)]).unwrap();
+        assert_eq!(hasher.hashed, 5);
+        assert_eq!(hasher.finish(5).unwrap().0, HELLO_SHA256);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,32 @@
+// Shared by build.rs and uniffi-gen so the scaffolding and the generated
+// bindings always export the same API for a given feature set.
+
+/// Removes UDL blocks guarded by disabled cargo features.
+///
+/// A block starts with a `// #if feature = "name"` line and ends with a
+/// `// #endif` line. Guards don't nest.
+pub fn strip_disabled_features(udl: &str, enabled: impl Fn(&str) -> bool) -> String {
+    let mut output = String::with_capacity(udl.len());
+    let mut skipping = false;
+
+    for line in udl.lines() {
+        let directive = line.trim();
+        if let Some(feature) = directive
+            .strip_prefix("// #if feature = \"")
+            .and_then(|rest| rest.strip_suffix('"'))
+        {
+            skipping = !enabled(feature);
+            continue;
+        }
+        if directive == "// #endif" {
+            skipping = false;
+            continue;
+        }
+        if !skipping {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+
+    output
+}
@@ -1,7 +1,11 @@
 use std::env;
+use std::fs;
 use camino::Utf8PathBuf;
 use uniffi_bindgen::bindings::SwiftBindingGenerator;
 
+#[path = "../../udl_features.rs"]
+mod udl_features;
+
 fn main() {
     let args: Vec<String> = env::args().collect();
     let udl_file = Utf8PathBuf::from(&args[1]);
@@ -10,18 +14,47 @@ fn main() {
     } else {
         Utf8PathBuf::from(".")
     };
-    
+    // An optional comma-separated list of enabled cargo features. Without it,
+    // every feature-gated declaration in the UDL is kept.
+    let features: Option<Vec<String>> = args
+        .get(3)
+        .map(|list| list.split(',').map(|f| f.trim().to_string()).collect());
+
     println!("Generating Swift bindings from {:?} to {:?}", udl_file, out_dir);
-    
+
+    let crate_root = udl_file
+        .parent()
+        .and_then(|src| src.parent())
+        .expect("UDL file should be in the crate's src directory")
+        .to_owned();
+    let config_file = crate_root.join("uniffi.toml");
+
+    let udl_file = match features {
+        Some(features) => {
+            println!("Enabled features: {:?}", features);
+            let udl = fs::read_to_string(&udl_file).expect("Failed to read UDL file");
+            let udl = udl_features::strip_disabled_features(&udl, |feature| {
+                features.iter().any(|enabled| enabled == feature)
+            });
+            // Bindgen locates the crate from the UDL's grandparent directory.
+            let generated = Utf8PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("generated");
+            fs::create_dir_all(&generated).expect("Failed to create generated directory");
+            let generated_udl = generated.join("swift_xet_rust.udl");
+            fs::write(&generated_udl, udl).expect("Failed to write UDL file");
+            generated_udl
+        }
+        None => udl_file,
+    };
+
     uniffi_bindgen::generate_bindings(
         &udl_file,
-        None,
+        Some(&config_file),
         SwiftBindingGenerator,
         Some(&out_dir),
         None,
-        None,
+        Some("swift_xet_rust"),
         false,
     ).expect("Failed to generate bindings");
-    
+
     println!("Swift bindings generated successfully!");
 }
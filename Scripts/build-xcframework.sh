@@ -24,28 +24,37 @@ rm -rf "$XCFRAMEWORK_DIR"
 mkdir -p "$BUILD_DIR"
 mkdir -p "$XCFRAMEWORK_DIR"
 
+# Set FEATURES to a comma-separated list (e.g. FEATURES="" for a download-only
+# build) to build without the default cargo features. Generate bindings with
+# the same FEATURES value.
+if [ -n "${FEATURES+set}" ]; then
+    CARGO_FEATURE_ARGS=(--no-default-features --features "$FEATURES")
+else
+    CARGO_FEATURE_ARGS=()
+fi
+
 cd "$RUST_DIR"
 
 # Build for macOS (both architectures)
 echo ""
 echo "📦 Building for macOS (arm64)..."
-cargo build --release --target aarch64-apple-darwin
+cargo build --release "${CARGO_FEATURE_ARGS[@]}" --target aarch64-apple-darwin
 
 echo "📦 Building for macOS (x86_64)..."
-cargo build --release --target x86_64-apple-darwin
+cargo build --release "${CARGO_FEATURE_ARGS[@]}" --target x86_64-apple-darwin
 
 # Build for iOS
 echo ""
 echo "📦 Building for iOS (arm64)..."
-cargo build --release --target aarch64-apple-ios
+cargo build --release "${CARGO_FEATURE_ARGS[@]}" --target aarch64-apple-ios
 
 # Build for iOS Simulator
 echo ""
 echo "📦 Building for iOS Simulator (arm64)..."
-cargo build --release --target aarch64-apple-ios-sim
+cargo build --release "${CARGO_FEATURE_ARGS[@]}" --target aarch64-apple-ios-sim
 
 echo "📦 Building for iOS Simulator (x86_64)..."
-cargo build --release --target x86_64-apple-ios
+cargo build --release "${CARGO_FEATURE_ARGS[@]}" --target x86_64-apple-ios
 
 # Create universal binaries
 echo ""
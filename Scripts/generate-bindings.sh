@@ -13,16 +13,26 @@ PROJECT_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
 RUST_DIR="$PROJECT_ROOT/Rust"
 OUTPUT_DIR="$PROJECT_ROOT/Sources/Xet"
 
+# Set FEATURES to a comma-separated list (e.g. FEATURES="" for a download-only
+# build) to build without the default cargo features.
+if [ -n "${FEATURES+set}" ]; then
+    CARGO_FEATURE_ARGS=(--no-default-features --features "$FEATURES")
+    UDL_FEATURE_ARGS=("$FEATURES")
+else
+    CARGO_FEATURE_ARGS=()
+    UDL_FEATURE_ARGS=()
+fi
+
 cd "$RUST_DIR"
 
 echo "Building Rust library..."
-cargo build --release
+cargo build --release "${CARGO_FEATURE_ARGS[@]}"
 
 echo "Generating UniFFI Swift bindings..."
 
 # Generate the bindings using the permanent uniffi-gen project
 cd "$RUST_DIR/uniffi-gen"
-cargo run --release -- "$RUST_DIR/src/swift_xet_rust.udl" "$OUTPUT_DIR"
+cargo run --release -- "$RUST_DIR/src/swift_xet_rust.udl" "$OUTPUT_DIR" "${UDL_FEATURE_ARGS[@]}"
 
 cd "$RUST_DIR"
 
@@ -395,7 +395,61 @@ fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
 
 
 // Public interface members begin here.
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt16: FfiConverterPrimitive {
+    typealias FfiType = UInt16
+    typealias SwiftType = UInt16
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt16 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
+    typealias FfiType = UInt32
+    typealias SwiftType = UInt32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt32: FfiConverterPrimitive {
+    typealias FfiType = Int32
+    typealias SwiftType = Int32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int32, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
@@ -413,6 +467,38 @@ fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt64: FfiConverterPrimitive {
+    typealias FfiType = Int64
+    typealias SwiftType = Int64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int64, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDouble: FfiConverterPrimitive {
+    typealias FfiType = Double
+    typealias SwiftType = Double
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Double {
+        return try lift(readDouble(&buf))
+    }
+
+    public static func write(_ value: Double, into buf: inout [UInt8]) {
+        writeDouble(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -478,35 +564,62 @@ fileprivate struct FfiConverterString: FfiConverter {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterData: FfiConverterRustBuffer {
+    typealias SwiftType = Data
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Data {
+        let len: Int32 = try readInt(&buf)
+        return Data(try readBytes(&buf, count: Int(len)))
+    }
+
+    public static func write(_ value: Data, into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        writeBytes(&buf, value)
+    }
+}
+
 
 
 
 /**
- * Statistics about the local Xet cache.
- *
- * This type provides information about the cache's size and the number
- * of cached files.
+ * The outcome of every request in a batch download, in request order.
  */
-public protocol CacheStatsProtocol: AnyObject, Sendable {
+public protocol BatchDownloadResultProtocol: AnyObject, Sendable {
     
     /**
-     * Returns the number of files in the cache.
+     * Returns the local paths of the files that are at their destinations.
      */
-    func fileCount()  -> UInt64
+    func downloadedPaths()  -> [String]
     
     /**
-     * Returns the total size of the cache in bytes.
+     * Returns the number of requests that failed.
      */
-    func totalSizeBytes()  -> UInt64
+    func failedCount()  -> UInt32
+    
+    /**
+     * Returns the outcome of each request, in the order they were given.
+     */
+    func results()  -> [FileDownloadResult]
+    
+    /**
+     * Returns the number of requests that weren't attempted.
+     */
+    func skippedCount()  -> UInt32
+    
+    /**
+     * Returns the number of requests skipped because the client can't read their repositories.
+     */
+    func unauthorizedCount()  -> UInt32
     
 }
 /**
- * Statistics about the local Xet cache.
- *
- * This type provides information about the cache's size and the number
- * of cached files.
+ * The outcome of every request in a batch download, in request order.
  */
-open class CacheStats: CacheStatsProtocol, @unchecked Sendable {
+open class BatchDownloadResult: BatchDownloadResultProtocol, @unchecked Sendable {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -543,7 +656,7 @@ open class CacheStats: CacheStatsProtocol, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_swift_xet_rust_fn_clone_cachestats(self.pointer, $0) }
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_batchdownloadresult(self.pointer, $0) }
     }
     // No primary constructor declared for this class.
 
@@ -552,28 +665,58 @@ open class CacheStats: CacheStatsProtocol, @unchecked Sendable {
             return
         }
 
-        try! rustCall { uniffi_swift_xet_rust_fn_free_cachestats(pointer, $0) }
+        try! rustCall { uniffi_swift_xet_rust_fn_free_batchdownloadresult(pointer, $0) }
     }
 
     
 
     
     /**
-     * Returns the number of files in the cache.
+     * Returns the local paths of the files that are at their destinations.
      */
-open func fileCount() -> UInt64  {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_cachestats_file_count(self.uniffiClonePointer(),$0
+open func downloadedPaths() -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_batchdownloadresult_downloaded_paths(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Returns the total size of the cache in bytes.
+     * Returns the number of requests that failed.
      */
-open func totalSizeBytes() -> UInt64  {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_cachestats_total_size_bytes(self.uniffiClonePointer(),$0
+open func failedCount() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_batchdownloadresult_failed_count(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the outcome of each request, in the order they were given.
+     */
+open func results() -> [FileDownloadResult]  {
+    return try!  FfiConverterSequenceTypeFileDownloadResult.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_batchdownloadresult_results(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the number of requests that weren't attempted.
+     */
+open func skippedCount() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_batchdownloadresult_skipped_count(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the number of requests skipped because the client can't read their repositories.
+     */
+open func unauthorizedCount() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_batchdownloadresult_unauthorized_count(self.uniffiClonePointer(),$0
     )
 })
 }
@@ -585,20 +728,20 @@ open func totalSizeBytes() -> UInt64  {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCacheStats: FfiConverter {
+public struct FfiConverterTypeBatchDownloadResult: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = CacheStats
+    typealias SwiftType = BatchDownloadResult
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> CacheStats {
-        return CacheStats(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> BatchDownloadResult {
+        return BatchDownloadResult(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: CacheStats) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: BatchDownloadResult) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CacheStats {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BatchDownloadResult {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -609,7 +752,7 @@ public struct FfiConverterTypeCacheStats: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: CacheStats, into buf: inout [UInt8]) {
+    public static func write(_ value: BatchDownloadResult, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -620,15 +763,15 @@ public struct FfiConverterTypeCacheStats: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCacheStats_lift(_ pointer: UnsafeMutableRawPointer) throws -> CacheStats {
-    return try FfiConverterTypeCacheStats.lift(pointer)
+public func FfiConverterTypeBatchDownloadResult_lift(_ pointer: UnsafeMutableRawPointer) throws -> BatchDownloadResult {
+    return try FfiConverterTypeBatchDownloadResult.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCacheStats_lower(_ value: CacheStats) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeCacheStats.lower(value)
+public func FfiConverterTypeBatchDownloadResult_lower(_ value: BatchDownloadResult) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeBatchDownloadResult.lower(value)
 }
 
 
@@ -637,36 +780,30 @@ public func FfiConverterTypeCacheStats_lower(_ value: CacheStats) -> UnsafeMutab
 
 
 /**
- * JWT token information for accessing the Content-Addressable Storage (CAS) system.
- *
- * This type contains the authentication token and endpoint URL needed to
- * interact with Xet's CAS system for downloading or uploading files.
+ * The outcome of a batch upload, committed in a single commit.
  */
-public protocol CasJwtInfoProtocol: AnyObject, Sendable {
+public protocol BatchUploadResultProtocol: AnyObject, Sendable {
     
     /**
-     * Returns the JWT access token for authenticating CAS requests.
+     * Returns the chunking the files were split with, for reproducing their chunks elsewhere.
      */
-    func accessToken()  -> String
+    func chunking()  -> ChunkingParameters
     
     /**
-     * Returns the URL of the CAS server endpoint.
+     * Returns the ID of the created commit.
      */
-    func casUrl()  -> String
+    func commitOid()  -> String
     
     /**
-     * Returns the expiration time of the token as a Unix timestamp.
+     * Returns the outcome of each file, in the order they were requested.
      */
-    func exp()  -> UInt64
+    func files()  -> [FileUploadResult]
     
 }
 /**
- * JWT token information for accessing the Content-Addressable Storage (CAS) system.
- *
- * This type contains the authentication token and endpoint URL needed to
- * interact with Xet's CAS system for downloading or uploading files.
+ * The outcome of a batch upload, committed in a single commit.
  */
-open class CasJwtInfo: CasJwtInfoProtocol, @unchecked Sendable {
+open class BatchUploadResult: BatchUploadResultProtocol, @unchecked Sendable {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -703,7 +840,7 @@ open class CasJwtInfo: CasJwtInfoProtocol, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_swift_xet_rust_fn_clone_casjwtinfo(self.pointer, $0) }
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_batchuploadresult(self.pointer, $0) }
     }
     // No primary constructor declared for this class.
 
@@ -712,38 +849,38 @@ open class CasJwtInfo: CasJwtInfoProtocol, @unchecked Sendable {
             return
         }
 
-        try! rustCall { uniffi_swift_xet_rust_fn_free_casjwtinfo(pointer, $0) }
+        try! rustCall { uniffi_swift_xet_rust_fn_free_batchuploadresult(pointer, $0) }
     }
 
     
 
     
     /**
-     * Returns the JWT access token for authenticating CAS requests.
+     * Returns the chunking the files were split with, for reproducing their chunks elsewhere.
      */
-open func accessToken() -> String  {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_casjwtinfo_access_token(self.uniffiClonePointer(),$0
+open func chunking() -> ChunkingParameters  {
+    return try!  FfiConverterTypeChunkingParameters_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_batchuploadresult_chunking(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Returns the URL of the CAS server endpoint.
+     * Returns the ID of the created commit.
      */
-open func casUrl() -> String  {
+open func commitOid() -> String  {
     return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_casjwtinfo_cas_url(self.uniffiClonePointer(),$0
+    uniffi_swift_xet_rust_fn_method_batchuploadresult_commit_oid(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Returns the expiration time of the token as a Unix timestamp.
+     * Returns the outcome of each file, in the order they were requested.
      */
-open func exp() -> UInt64  {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_casjwtinfo_exp(self.uniffiClonePointer(),$0
+open func files() -> [FileUploadResult]  {
+    return try!  FfiConverterSequenceTypeFileUploadResult.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_batchuploadresult_files(self.uniffiClonePointer(),$0
     )
 })
 }
@@ -755,20 +892,20 @@ open func exp() -> UInt64  {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCasJwtInfo: FfiConverter {
+public struct FfiConverterTypeBatchUploadResult: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = CasJwtInfo
+    typealias SwiftType = BatchUploadResult
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> CasJwtInfo {
-        return CasJwtInfo(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> BatchUploadResult {
+        return BatchUploadResult(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: CasJwtInfo) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: BatchUploadResult) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CasJwtInfo {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BatchUploadResult {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -779,7 +916,7 @@ public struct FfiConverterTypeCasJwtInfo: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: CasJwtInfo, into buf: inout [UInt8]) {
+    public static func write(_ value: BatchUploadResult, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -790,15 +927,15 @@ public struct FfiConverterTypeCasJwtInfo: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCasJwtInfo_lift(_ pointer: UnsafeMutableRawPointer) throws -> CasJwtInfo {
-    return try FfiConverterTypeCasJwtInfo.lift(pointer)
+public func FfiConverterTypeBatchUploadResult_lift(_ pointer: UnsafeMutableRawPointer) throws -> BatchUploadResult {
+    return try FfiConverterTypeBatchUploadResult.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCasJwtInfo_lower(_ value: CasJwtInfo) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeCasJwtInfo.lower(value)
+public func FfiConverterTypeBatchUploadResult_lower(_ value: BatchUploadResult) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeBatchUploadResult.lower(value)
 }
 
 
@@ -807,46 +944,25 @@ public func FfiConverterTypeCasJwtInfo_lower(_ value: CasJwtInfo) -> UnsafeMutab
 
 
 /**
- * Metadata about a file or directory entry in a repository.
- *
- * This type provides information about entries in a repository's file tree,
- * including their paths, types, sizes, and content identifiers.
+ * Statistics about a single area of the local Xet cache.
  */
-public protocol FileMetadataProtocol: AnyObject, Sendable {
-    
-    /**
-     * Returns the type of the entry.
-     */
-    func entryType()  -> String
+public protocol CacheAreaStatsProtocol: AnyObject, Sendable {
     
     /**
-     * Returns the content hash of the file, if available.
-     */
-    func hash()  -> String?
-    
-    /**
-     * Returns the Git object ID of the entry, if available.
-     */
-    func oid()  -> String?
-    
-    /**
-     * Returns the path of the file or directory within the repository.
+     * Returns the number of files in the area.
      */
-    func path()  -> String
+    func fileCount()  -> UInt64
     
     /**
-     * Returns the size of the file in bytes, if available.
+     * Returns the total size of the area in bytes.
      */
-    func size()  -> UInt64?
+    func totalSizeBytes()  -> UInt64
     
 }
 /**
- * Metadata about a file or directory entry in a repository.
- *
- * This type provides information about entries in a repository's file tree,
- * including their paths, types, sizes, and content identifiers.
+ * Statistics about a single area of the local Xet cache.
  */
-open class FileMetadata: FileMetadataProtocol, @unchecked Sendable {
+open class CacheAreaStats: CacheAreaStatsProtocol, @unchecked Sendable {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -883,7 +999,7 @@ open class FileMetadata: FileMetadataProtocol, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_swift_xet_rust_fn_clone_filemetadata(self.pointer, $0) }
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_cacheareastats(self.pointer, $0) }
     }
     // No primary constructor declared for this class.
 
@@ -892,58 +1008,28 @@ open class FileMetadata: FileMetadataProtocol, @unchecked Sendable {
             return
         }
 
-        try! rustCall { uniffi_swift_xet_rust_fn_free_filemetadata(pointer, $0) }
+        try! rustCall { uniffi_swift_xet_rust_fn_free_cacheareastats(pointer, $0) }
     }
 
     
 
     
     /**
-     * Returns the type of the entry.
-     */
-open func entryType() -> String  {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_filemetadata_entry_type(self.uniffiClonePointer(),$0
-    )
-})
-}
-    
-    /**
-     * Returns the content hash of the file, if available.
-     */
-open func hash() -> String?  {
-    return try!  FfiConverterOptionString.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_filemetadata_hash(self.uniffiClonePointer(),$0
-    )
-})
-}
-    
-    /**
-     * Returns the Git object ID of the entry, if available.
-     */
-open func oid() -> String?  {
-    return try!  FfiConverterOptionString.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_filemetadata_oid(self.uniffiClonePointer(),$0
-    )
-})
-}
-    
-    /**
-     * Returns the path of the file or directory within the repository.
+     * Returns the number of files in the area.
      */
-open func path() -> String  {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_filemetadata_path(self.uniffiClonePointer(),$0
+open func fileCount() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cacheareastats_file_count(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Returns the size of the file in bytes, if available.
+     * Returns the total size of the area in bytes.
      */
-open func size() -> UInt64?  {
-    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_filemetadata_size(self.uniffiClonePointer(),$0
+open func totalSizeBytes() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cacheareastats_total_size_bytes(self.uniffiClonePointer(),$0
     )
 })
 }
@@ -955,20 +1041,20 @@ open func size() -> UInt64?  {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFileMetadata: FfiConverter {
+public struct FfiConverterTypeCacheAreaStats: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = FileMetadata
+    typealias SwiftType = CacheAreaStats
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FileMetadata {
-        return FileMetadata(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> CacheAreaStats {
+        return CacheAreaStats(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: FileMetadata) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: CacheAreaStats) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileMetadata {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CacheAreaStats {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -979,7 +1065,7 @@ public struct FfiConverterTypeFileMetadata: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: FileMetadata, into buf: inout [UInt8]) {
+    public static func write(_ value: CacheAreaStats, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -990,15 +1076,15 @@ public struct FfiConverterTypeFileMetadata: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFileMetadata_lift(_ pointer: UnsafeMutableRawPointer) throws -> FileMetadata {
-    return try FfiConverterTypeFileMetadata.lift(pointer)
+public func FfiConverterTypeCacheAreaStats_lift(_ pointer: UnsafeMutableRawPointer) throws -> CacheAreaStats {
+    return try FfiConverterTypeCacheAreaStats.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFileMetadata_lower(_ value: FileMetadata) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeFileMetadata.lower(value)
+public func FfiConverterTypeCacheAreaStats_lower(_ value: CacheAreaStats) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeCacheAreaStats.lower(value)
 }
 
 
@@ -1007,31 +1093,41 @@ public func FfiConverterTypeFileMetadata_lower(_ value: FileMetadata) -> UnsafeM
 
 
 /**
- * Information about a Hugging Face repository.
+ * Statistics about the local Xet cache.
  *
- * This type contains the repository type and full name, which uniquely
- * identify a repository on Hugging Face Hub.
+ * This type provides information about the cache's size and the number
+ * of cached files, both in aggregate and broken down by area.
  */
-public protocol RepoInfoProtocol: AnyObject, Sendable {
+public protocol CacheStatsProtocol: AnyObject, Sendable {
     
     /**
-     * Returns the full name of the repository.
+     * Returns the statistics for a single cache area.
      */
-    func fullName()  -> String
+    func areaStats(area: CacheArea)  -> CacheAreaStats
     
     /**
-     * Returns the type of the repository.
+     * Returns the bytes saved by storing identical downloaded files once.
      */
-    func repoType()  -> String
+    func deduplicatedBytes()  -> UInt64
+    
+    /**
+     * Returns the number of files in the cache.
+     */
+    func fileCount()  -> UInt64
+    
+    /**
+     * Returns the total size of the cache in bytes.
+     */
+    func totalSizeBytes()  -> UInt64
     
 }
 /**
- * Information about a Hugging Face repository.
+ * Statistics about the local Xet cache.
  *
- * This type contains the repository type and full name, which uniquely
- * identify a repository on Hugging Face Hub.
+ * This type provides information about the cache's size and the number
+ * of cached files, both in aggregate and broken down by area.
  */
-open class RepoInfo: RepoInfoProtocol, @unchecked Sendable {
+open class CacheStats: CacheStatsProtocol, @unchecked Sendable {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -1068,7 +1164,7 @@ open class RepoInfo: RepoInfoProtocol, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_swift_xet_rust_fn_clone_repoinfo(self.pointer, $0) }
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_cachestats(self.pointer, $0) }
     }
     // No primary constructor declared for this class.
 
@@ -1077,28 +1173,49 @@ open class RepoInfo: RepoInfoProtocol, @unchecked Sendable {
             return
         }
 
-        try! rustCall { uniffi_swift_xet_rust_fn_free_repoinfo(pointer, $0) }
+        try! rustCall { uniffi_swift_xet_rust_fn_free_cachestats(pointer, $0) }
     }
 
     
 
     
     /**
-     * Returns the full name of the repository.
+     * Returns the statistics for a single cache area.
      */
-open func fullName() -> String  {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_repoinfo_full_name(self.uniffiClonePointer(),$0
+open func areaStats(area: CacheArea) -> CacheAreaStats  {
+    return try!  FfiConverterTypeCacheAreaStats_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cachestats_area_stats(self.uniffiClonePointer(),
+        FfiConverterTypeCacheArea_lower(area),$0
     )
 })
 }
     
     /**
-     * Returns the type of the repository.
+     * Returns the bytes saved by storing identical downloaded files once.
      */
-open func repoType() -> String  {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_repoinfo_repo_type(self.uniffiClonePointer(),$0
+open func deduplicatedBytes() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cachestats_deduplicated_bytes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the number of files in the cache.
+     */
+open func fileCount() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cachestats_file_count(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the total size of the cache in bytes.
+     */
+open func totalSizeBytes() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cachestats_total_size_bytes(self.uniffiClonePointer(),$0
     )
 })
 }
@@ -1110,20 +1227,20 @@ open func repoType() -> String  {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRepoInfo: FfiConverter {
+public struct FfiConverterTypeCacheStats: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = RepoInfo
+    typealias SwiftType = CacheStats
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> RepoInfo {
-        return RepoInfo(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> CacheStats {
+        return CacheStats(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: RepoInfo) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: CacheStats) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepoInfo {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CacheStats {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -1134,7 +1251,7 @@ public struct FfiConverterTypeRepoInfo: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: RepoInfo, into buf: inout [UInt8]) {
+    public static func write(_ value: CacheStats, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -1145,15 +1262,15 @@ public struct FfiConverterTypeRepoInfo: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRepoInfo_lift(_ pointer: UnsafeMutableRawPointer) throws -> RepoInfo {
-    return try FfiConverterTypeRepoInfo.lift(pointer)
+public func FfiConverterTypeCacheStats_lift(_ pointer: UnsafeMutableRawPointer) throws -> CacheStats {
+    return try FfiConverterTypeCacheStats.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRepoInfo_lower(_ value: RepoInfo) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeRepoInfo.lower(value)
+public func FfiConverterTypeCacheStats_lower(_ value: CacheStats) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeCacheStats.lower(value)
 }
 
 
@@ -1162,64 +1279,35 @@ public func FfiConverterTypeRepoInfo_lower(_ value: RepoInfo) -> UnsafeMutableRa
 
 
 /**
- * A client for interacting with Xet repositories.
- *
- * The `XetClient` provides methods to download files, list repository contents,
- * and manage the local cache for Xet-enabled repositories on Hugging Face Hub.
- *
- * You can create a client with or without authentication. For public repositories,
- * authentication is optional. For private repositories or upload operations,
- * you need to provide a Hugging Face authentication token.
+ * A revision of a file that is available in the local cache.
  */
-public protocol XetClientProtocol: AnyObject, Sendable {
-    
-    /**
-     * Clears all files from the local Xet cache.
-     */
-    func clearCache() throws 
-    
-    /**
-     * Downloads files using the Xet Content-Addressable Storage (CAS) system.
-     */
-    func downloadFiles(fileInfos: [XetFileInfo], destinationDir: String, jwtInfo: CasJwtInfo) throws  -> [String]
-    
-    /**
-     * Returns statistics about the local Xet cache.
-     */
-    func getCacheStats() throws  -> CacheStats
+public protocol CachedRevisionProtocol: AnyObject, Sendable {
     
     /**
-     * Retrieves a JWT token for accessing the Content-Addressable Storage (CAS) system.
+     * Returns the commit the file was downloaded at.
      */
-    func getCasJwt(repo: String, revision: String?, isUpload: Bool) throws  -> CasJwtInfo
+    func commit()  -> String
     
     /**
-     * Retrieves file information from a pointer file in the repository.
+     * Returns the local path of the file at this revision.
      */
-    func getFileInfo(repo: String, path: String, revision: String?) throws  -> XetFileInfo?
+    func localPath()  -> String
     
     /**
-     * Parses a repository identifier and returns structured repository information.
+     * Returns the branches and tags last seen pointing at the commit, such as `"main"`.
      */
-    func getRepoInfo(repo: String) throws  -> RepoInfo
+    func refs()  -> [String]
     
     /**
-     * Returns the version of the Xet client library.
+     * Returns the size of the file in bytes.
      */
-    func version()  -> String
+    func size()  -> UInt64
     
 }
 /**
- * A client for interacting with Xet repositories.
- *
- * The `XetClient` provides methods to download files, list repository contents,
- * and manage the local cache for Xet-enabled repositories on Hugging Face Hub.
- *
- * You can create a client with or without authentication. For public repositories,
- * authentication is optional. For private repositories or upload operations,
- * you need to provide a Hugging Face authentication token.
+ * A revision of a file that is available in the local cache.
  */
-open class XetClient: XetClientProtocol, @unchecked Sendable {
+open class CachedRevision: CachedRevisionProtocol, @unchecked Sendable {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -1256,117 +1344,57 @@ open class XetClient: XetClientProtocol, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_swift_xet_rust_fn_clone_xetclient(self.pointer, $0) }
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_cachedrevision(self.pointer, $0) }
     }
-    /**
-     * Creates a new Xet client without authentication.
-     */
-public convenience init()throws  {
-    let pointer =
-        try rustCallWithError(FfiConverterTypeXetError_lift) {
-    uniffi_swift_xet_rust_fn_constructor_xetclient_new($0
-    )
-}
-    self.init(unsafeFromRawPointer: pointer)
-}
+    // No primary constructor declared for this class.
 
     deinit {
         guard let pointer = pointer else {
             return
         }
 
-        try! rustCall { uniffi_swift_xet_rust_fn_free_xetclient(pointer, $0) }
+        try! rustCall { uniffi_swift_xet_rust_fn_free_cachedrevision(pointer, $0) }
     }
 
     
-    /**
-     * Creates a new Xet client with an authentication token.
-     */
-public static func withToken(token: String)throws  -> XetClient  {
-    return try  FfiConverterTypeXetClient_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
-    uniffi_swift_xet_rust_fn_constructor_xetclient_with_token(
-        FfiConverterString.lower(token),$0
-    )
-})
-}
-    
 
     
     /**
-     * Clears all files from the local Xet cache.
-     */
-open func clearCache()throws   {try rustCallWithError(FfiConverterTypeXetError_lift) {
-    uniffi_swift_xet_rust_fn_method_xetclient_clear_cache(self.uniffiClonePointer(),$0
-    )
-}
-}
-    
-    /**
-     * Downloads files using the Xet Content-Addressable Storage (CAS) system.
-     */
-open func downloadFiles(fileInfos: [XetFileInfo], destinationDir: String, jwtInfo: CasJwtInfo)throws  -> [String]  {
-    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
-    uniffi_swift_xet_rust_fn_method_xetclient_download_files(self.uniffiClonePointer(),
-        FfiConverterSequenceTypeXetFileInfo.lower(fileInfos),
-        FfiConverterString.lower(destinationDir),
-        FfiConverterTypeCasJwtInfo_lower(jwtInfo),$0
-    )
-})
-}
-    
-    /**
-     * Returns statistics about the local Xet cache.
-     */
-open func getCacheStats()throws  -> CacheStats  {
-    return try  FfiConverterTypeCacheStats_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
-    uniffi_swift_xet_rust_fn_method_xetclient_get_cache_stats(self.uniffiClonePointer(),$0
-    )
-})
-}
-    
-    /**
-     * Retrieves a JWT token for accessing the Content-Addressable Storage (CAS) system.
+     * Returns the commit the file was downloaded at.
      */
-open func getCasJwt(repo: String, revision: String?, isUpload: Bool)throws  -> CasJwtInfo  {
-    return try  FfiConverterTypeCasJwtInfo_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
-    uniffi_swift_xet_rust_fn_method_xetclient_get_cas_jwt(self.uniffiClonePointer(),
-        FfiConverterString.lower(repo),
-        FfiConverterOptionString.lower(revision),
-        FfiConverterBool.lower(isUpload),$0
+open func commit() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cachedrevision_commit(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Retrieves file information from a pointer file in the repository.
+     * Returns the local path of the file at this revision.
      */
-open func getFileInfo(repo: String, path: String, revision: String?)throws  -> XetFileInfo?  {
-    return try  FfiConverterOptionTypeXetFileInfo.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
-    uniffi_swift_xet_rust_fn_method_xetclient_get_file_info(self.uniffiClonePointer(),
-        FfiConverterString.lower(repo),
-        FfiConverterString.lower(path),
-        FfiConverterOptionString.lower(revision),$0
+open func localPath() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cachedrevision_local_path(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Parses a repository identifier and returns structured repository information.
+     * Returns the branches and tags last seen pointing at the commit, such as `"main"`.
      */
-open func getRepoInfo(repo: String)throws  -> RepoInfo  {
-    return try  FfiConverterTypeRepoInfo_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
-    uniffi_swift_xet_rust_fn_method_xetclient_get_repo_info(self.uniffiClonePointer(),
-        FfiConverterString.lower(repo),$0
+open func refs() -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cachedrevision_refs(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Returns the version of the Xet client library.
+     * Returns the size of the file in bytes.
      */
-open func version() -> String  {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_xetclient_version(self.uniffiClonePointer(),$0
+open func size() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cachedrevision_size(self.uniffiClonePointer(),$0
     )
 })
 }
@@ -1378,20 +1406,20 @@ open func version() -> String  {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeXetClient: FfiConverter {
+public struct FfiConverterTypeCachedRevision: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = XetClient
+    typealias SwiftType = CachedRevision
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> XetClient {
-        return XetClient(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> CachedRevision {
+        return CachedRevision(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: XetClient) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: CachedRevision) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> XetClient {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CachedRevision {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -1402,7 +1430,7 @@ public struct FfiConverterTypeXetClient: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: XetClient, into buf: inout [UInt8]) {
+    public static func write(_ value: CachedRevision, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -1413,15 +1441,15 @@ public struct FfiConverterTypeXetClient: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeXetClient_lift(_ pointer: UnsafeMutableRawPointer) throws -> XetClient {
-    return try FfiConverterTypeXetClient.lift(pointer)
+public func FfiConverterTypeCachedRevision_lift(_ pointer: UnsafeMutableRawPointer) throws -> CachedRevision {
+    return try FfiConverterTypeCachedRevision.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeXetClient_lower(_ value: XetClient) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeXetClient.lower(value)
+public func FfiConverterTypeCachedRevision_lower(_ value: CachedRevision) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeCachedRevision.lower(value)
 }
 
 
@@ -1430,31 +1458,35 @@ public func FfiConverterTypeXetClient_lower(_ value: XetClient) -> UnsafeMutable
 
 
 /**
- * Information about a file stored in a Xet repository.
- *
- * This type contains the hash and size of a file, which are used to
- * identify and download files from Xet's content-addressable storage system.
+ * A handle for cancelling an in-flight transfer.
  */
-public protocol XetFileInfoProtocol: AnyObject, Sendable {
+public protocol CancellationTokenProtocol: AnyObject, Sendable {
     
     /**
-     * Returns the size of the file in bytes.
+     * Requests cancellation of any transfer using this token, on behalf of the user.
      */
-    func fileSize()  -> UInt64
+    func cancel() 
     
     /**
-     * Returns the content hash of the file.
+     * Requests cancellation of any transfer using this token, recording why.
      */
-    func hash()  -> String
+    func cancelWithReason(reason: CancellationReason) 
+    
+    /**
+     * Returns whether cancellation has been requested.
+     */
+    func isCancelled()  -> Bool
+    
+    /**
+     * Returns why the token was cancelled, or `null` if it hasn't been.
+     */
+    func reason()  -> CancellationReason?
     
 }
 /**
- * Information about a file stored in a Xet repository.
- *
- * This type contains the hash and size of a file, which are used to
- * identify and download files from Xet's content-addressable storage system.
+ * A handle for cancelling an in-flight transfer.
  */
-open class XetFileInfo: XetFileInfoProtocol, @unchecked Sendable {
+open class CancellationToken: CancellationTokenProtocol, @unchecked Sendable {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -1491,17 +1523,15 @@ open class XetFileInfo: XetFileInfoProtocol, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_swift_xet_rust_fn_clone_xetfileinfo(self.pointer, $0) }
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_cancellationtoken(self.pointer, $0) }
     }
     /**
-     * Creates a new file info instance.
+     * Creates a token that has not been cancelled.
      */
-public convenience init(hash: String, fileSize: UInt64) {
+public convenience init() {
     let pointer =
         try! rustCall() {
-    uniffi_swift_xet_rust_fn_constructor_xetfileinfo_new(
-        FfiConverterString.lower(hash),
-        FfiConverterUInt64.lower(fileSize),$0
+    uniffi_swift_xet_rust_fn_constructor_cancellationtoken_new($0
     )
 }
     self.init(unsafeFromRawPointer: pointer)
@@ -1512,53 +1542,94 @@ public convenience init(hash: String, fileSize: UInt64) {
             return
         }
 
-        try! rustCall { uniffi_swift_xet_rust_fn_free_xetfileinfo(pointer, $0) }
+        try! rustCall { uniffi_swift_xet_rust_fn_free_cancellationtoken(pointer, $0) }
     }
 
     
-
-    
     /**
-     * Returns the size of the file in bytes.
+     * Creates a token whose transfers are attributed to a label in the client's usage report, rather than to the client's usage label.
      */
-open func fileSize() -> UInt64  {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_xetfileinfo_file_size(self.uniffiClonePointer(),$0
+public static func withLabel(label: String) -> CancellationToken  {
+    return try!  FfiConverterTypeCancellationToken_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_constructor_cancellationtoken_with_label(
+        FfiConverterString.lower(label),$0
     )
 })
 }
     
     /**
-     * Returns the content hash of the file.
+     * Creates a token that fails transfers using it with a timeout error once the given number of seconds have passed.
      */
-open func hash() -> String  {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_swift_xet_rust_fn_method_xetfileinfo_hash(self.uniffiClonePointer(),$0
+public static func withTimeout(timeoutSecs: UInt64) -> CancellationToken  {
+    return try!  FfiConverterTypeCancellationToken_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_constructor_cancellationtoken_with_timeout(
+        FfiConverterUInt64.lower(timeoutSecs),$0
     )
 })
 }
     
 
-}
-
-
+    
+    /**
+     * Requests cancellation of any transfer using this token, on behalf of the user.
+     */
+open func cancel()  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cancellationtoken_cancel(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Requests cancellation of any transfer using this token, recording why.
+     */
+open func cancelWithReason(reason: CancellationReason)  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cancellationtoken_cancel_with_reason(self.uniffiClonePointer(),
+        FfiConverterTypeCancellationReason_lower(reason),$0
+    )
+}
+}
+    
+    /**
+     * Returns whether cancellation has been requested.
+     */
+open func isCancelled() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cancellationtoken_is_cancelled(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns why the token was cancelled, or `null` if it hasn't been.
+     */
+open func reason() -> CancellationReason?  {
+    return try!  FfiConverterOptionTypeCancellationReason.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_cancellationtoken_reason(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeXetFileInfo: FfiConverter {
+public struct FfiConverterTypeCancellationToken: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = XetFileInfo
+    typealias SwiftType = CancellationToken
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> XetFileInfo {
-        return XetFileInfo(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> CancellationToken {
+        return CancellationToken(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: XetFileInfo) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: CancellationToken) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> XetFileInfo {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CancellationToken {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -1569,7 +1640,7 @@ public struct FfiConverterTypeXetFileInfo: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: XetFileInfo, into buf: inout [UInt8]) {
+    public static func write(_ value: CancellationToken, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -1580,150 +1651,437 @@ public struct FfiConverterTypeXetFileInfo: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeXetFileInfo_lift(_ pointer: UnsafeMutableRawPointer) throws -> XetFileInfo {
-    return try FfiConverterTypeXetFileInfo.lift(pointer)
+public func FfiConverterTypeCancellationToken_lift(_ pointer: UnsafeMutableRawPointer) throws -> CancellationToken {
+    return try FfiConverterTypeCancellationToken.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeXetFileInfo_lower(_ value: XetFileInfo) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeXetFileInfo.lower(value)
+public func FfiConverterTypeCancellationToken_lower(_ value: CancellationToken) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeCancellationToken.lower(value)
 }
 
 
 
 
+
+
 /**
- * An error that occurs during Xet operations.
+ * JWT token information for accessing the Content-Addressable Storage (CAS) system.
  *
- * This error type represents various failure conditions that can occur when
- * interacting with Xet repositories, including network issues, authentication
- * problems, and data processing errors.
+ * This type contains the authentication token and endpoint URL needed to
+ * interact with Xet's CAS system for downloading or uploading files.
  */
-public enum XetError: Swift.Error {
-
+public protocol CasJwtInfoProtocol: AnyObject, Sendable {
     
+    /**
+     * Returns the JWT access token for authenticating CAS requests.
+     */
+    func accessToken()  -> String
     
     /**
-     * A general operation failure occurred.
+     * Returns the URL of the CAS server endpoint.
      */
-    case OperationFailed(message: String
-    )
+    func casUrl()  -> String
+    
     /**
-     * Invalid input was provided to a method.
+     * Decodes the claims in the access token, without verifying its signature.
      */
-    case InvalidInput(message: String
-    )
+    func claims() throws  -> CasTokenClaims
+    
     /**
-     * An I/O error occurred during file operations.
+     * Returns the round-trip times measured to each CAS endpoint the Hub offered.
+     */
+    func endpointLatencies()  -> [CasEndpointLatency]
+    
+    /**
+     * Returns the expiration time of the token as a Unix timestamp.
+     */
+    func exp()  -> UInt64
+    
+}
+/**
+ * JWT token information for accessing the Content-Addressable Storage (CAS) system.
+ *
+ * This type contains the authentication token and endpoint URL needed to
+ * interact with Xet's CAS system for downloading or uploading files.
+ */
+open class CasJwtInfo: CasJwtInfoProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_casjwtinfo(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_casjwtinfo(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the JWT access token for authenticating CAS requests.
      */
-    case IoError(message: String
+open func accessToken() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_casjwtinfo_access_token(self.uniffiClonePointer(),$0
     )
+})
+}
+    
     /**
-     * A network error occurred during a request.
+     * Returns the URL of the CAS server endpoint.
      */
-    case NetworkError(message: String
+open func casUrl() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_casjwtinfo_cas_url(self.uniffiClonePointer(),$0
     )
+})
+}
+    
     /**
-     * An authentication error occurred.
+     * Decodes the claims in the access token, without verifying its signature.
      */
-    case AuthError(message: String
+open func claims()throws  -> CasTokenClaims  {
+    return try  FfiConverterTypeCasTokenClaims_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_casjwtinfo_claims(self.uniffiClonePointer(),$0
     )
+})
+}
+    
     /**
-     * A cache operation failed.
+     * Returns the round-trip times measured to each CAS endpoint the Hub offered.
      */
-    case CacheError(message: String
+open func endpointLatencies() -> [CasEndpointLatency]  {
+    return try!  FfiConverterSequenceTypeCasEndpointLatency.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_casjwtinfo_endpoint_latencies(self.uniffiClonePointer(),$0
     )
+})
+}
+    
     /**
-     * A token-related error occurred.
+     * Returns the expiration time of the token as a Unix timestamp.
      */
-    case TokenError(message: String
+open func exp() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_casjwtinfo_exp(self.uniffiClonePointer(),$0
     )
+})
+}
+    
+
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeXetError: FfiConverterRustBuffer {
-    typealias SwiftType = XetError
+public struct FfiConverterTypeCasJwtInfo: FfiConverter {
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> XetError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = CasJwtInfo
 
-        
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> CasJwtInfo {
+        return CasJwtInfo(unsafeFromRawPointer: pointer)
+    }
 
-        
-        case 1: return .OperationFailed(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .InvalidInput(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .IoError(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 4: return .NetworkError(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 5: return .AuthError(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 6: return .CacheError(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 7: return .TokenError(
-            message: try FfiConverterString.read(from: &buf)
-            )
+    public static func lower(_ value: CasJwtInfo) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CasJwtInfo {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
         }
+        return try lift(ptr!)
     }
 
-    public static func write(_ value: XetError, into buf: inout [UInt8]) {
-        switch value {
+    public static func write(_ value: CasJwtInfo, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
 
-        
 
-        
-        
-        case let .OperationFailed(message):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .InvalidInput(message):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .IoError(message):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .NetworkError(message):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .AuthError(message):
-            writeInt(&buf, Int32(5))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .CacheError(message):
-            writeInt(&buf, Int32(6))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .TokenError(message):
-            writeInt(&buf, Int32(7))
-            FfiConverterString.write(message, into: &buf)
-            
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCasJwtInfo_lift(_ pointer: UnsafeMutableRawPointer) throws -> CasJwtInfo {
+    return try FfiConverterTypeCasJwtInfo.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCasJwtInfo_lower(_ value: CasJwtInfo) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeCasJwtInfo.lower(value)
+}
+
+
+
+
+
+
+/**
+ * A handle for pausing, resuming, and cancelling an in-flight download.
+ */
+public protocol DownloadHandleProtocol: AnyObject, Sendable {
+    
+    /**
+     * Cancels any download using this handle, on behalf of the user, even while paused.
+     */
+    func cancel() 
+    
+    /**
+     * Cancels any download using this handle, recording why.
+     */
+    func cancelWithReason(reason: CancellationReason) 
+    
+    /**
+     * Returns whether cancellation has been requested.
+     */
+    func isCancelled()  -> Bool
+    
+    /**
+     * Returns whether the handle is paused.
+     */
+    func isPaused()  -> Bool
+    
+    /**
+     * Pauses any download using this handle at its next checkpoint.
+     */
+    func pause() 
+    
+    /**
+     * Lets a paused download continue.
+     */
+    func resume() 
+    
+}
+/**
+ * A handle for pausing, resuming, and cancelling an in-flight download.
+ */
+open class DownloadHandle: DownloadHandleProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_downloadhandle(self.pointer, $0) }
+    }
+    /**
+     * Creates a handle that is neither paused nor cancelled.
+     */
+public convenience init() {
+    let pointer =
+        try! rustCall() {
+    uniffi_swift_xet_rust_fn_constructor_downloadhandle_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_downloadhandle(pointer, $0) }
+    }
+
+    
+    /**
+     * Creates a handle whose download is attributed to a label in the client's usage report, rather than to the client's usage label.
+     */
+public static func withLabel(label: String) -> DownloadHandle  {
+    return try!  FfiConverterTypeDownloadHandle_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_constructor_downloadhandle_with_label(
+        FfiConverterString.lower(label),$0
+    )
+})
+}
+    
+    /**
+     * Creates a handle whose download fails with a timeout error once the given number of seconds have passed.
+     */
+public static func withTimeout(timeoutSecs: UInt64) -> DownloadHandle  {
+    return try!  FfiConverterTypeDownloadHandle_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_constructor_downloadhandle_with_timeout(
+        FfiConverterUInt64.lower(timeoutSecs),$0
+    )
+})
+}
+    
+
+    
+    /**
+     * Cancels any download using this handle, on behalf of the user, even while paused.
+     */
+open func cancel()  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_downloadhandle_cancel(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Cancels any download using this handle, recording why.
+     */
+open func cancelWithReason(reason: CancellationReason)  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_downloadhandle_cancel_with_reason(self.uniffiClonePointer(),
+        FfiConverterTypeCancellationReason_lower(reason),$0
+    )
+}
+}
+    
+    /**
+     * Returns whether cancellation has been requested.
+     */
+open func isCancelled() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_downloadhandle_is_cancelled(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns whether the handle is paused.
+     */
+open func isPaused() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_downloadhandle_is_paused(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Pauses any download using this handle at its next checkpoint.
+     */
+open func pause()  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_downloadhandle_pause(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Lets a paused download continue.
+     */
+open func resume()  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_downloadhandle_resume(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDownloadHandle: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = DownloadHandle
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> DownloadHandle {
+        return DownloadHandle(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: DownloadHandle) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DownloadHandle {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
         }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: DownloadHandle, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
     }
 }
 
@@ -1731,223 +2089,12194 @@ public struct FfiConverterTypeXetError: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeXetError_lift(_ buf: RustBuffer) throws -> XetError {
-    return try FfiConverterTypeXetError.lift(buf)
+public func FfiConverterTypeDownloadHandle_lift(_ pointer: UnsafeMutableRawPointer) throws -> DownloadHandle {
+    return try FfiConverterTypeDownloadHandle.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeXetError_lower(_ value: XetError) -> RustBuffer {
-    return FfiConverterTypeXetError.lower(value)
+public func FfiConverterTypeDownloadHandle_lower(_ value: DownloadHandle) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeDownloadHandle.lower(value)
 }
 
 
-extension XetError: Equatable, Hashable {}
 
 
 
 
-extension XetError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
+/**
+ * A request to download a file from a repository.
+ *
+ * This type encapsulates the parameters needed to download a single file,
+ * including the repository identifier, file path, destination, and optional revision.
+ */
+public protocol FileDownloadRequestProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the local file path where the file will be saved.
+     */
+    func destination()  -> String
+    
+    /**
+     * Returns the path of the file within the repository.
+     */
+    func path()  -> String
+    
+    /**
+     * Returns how soon the file starts within a multi-file download.
+     */
+    func priority()  -> Int32
+    
+    /**
+     * Returns the repository identifier.
+     */
+    func repo()  -> String
+    
+    /**
+     * Returns the Git revision, branch, or tag name.
+     */
+    func revision()  -> String?
+    
+    /**
+     * Changes how soon the file starts within a multi-file download, taking effect if it hasn't started yet.
+     */
+    func setPriority(priority: Int32) 
+    
 }
+/**
+ * A request to download a file from a repository.
+ *
+ * This type encapsulates the parameters needed to download a single file,
+ * including the repository identifier, file path, destination, and optional revision.
+ */
+open class FileDownloadRequest: FileDownloadRequestProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
 
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
 
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
-    typealias SwiftType = UInt64?
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_filedownloadrequest(self.pointer, $0) }
+    }
+    /**
+     * Creates a new file download request.
+     */
+public convenience init(repo: String, path: String, destination: String, revision: String?) {
+    let pointer =
+        try! rustCall() {
+    uniffi_swift_xet_rust_fn_constructor_filedownloadrequest_new(
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(destination),
+        FfiConverterOptionString.lower(revision),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
+    deinit {
+        guard let pointer = pointer else {
             return
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterUInt64.write(value, into: &buf)
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_filedownloadrequest(pointer, $0) }
+    }
+
+    
+    /**
+     * Creates a new file download request with a priority, where higher priorities start first within a multi-file download.
+     */
+public static func withPriority(repo: String, path: String, destination: String, revision: String?, priority: Int32) -> FileDownloadRequest  {
+    return try!  FfiConverterTypeFileDownloadRequest_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_constructor_filedownloadrequest_with_priority(
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(destination),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterInt32.lower(priority),$0
+    )
+})
+}
+    
+
+    
+    /**
+     * Returns the local file path where the file will be saved.
+     */
+open func destination() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadrequest_destination(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the path of the file within the repository.
+     */
+open func path() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadrequest_path(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns how soon the file starts within a multi-file download.
+     */
+open func priority() -> Int32  {
+    return try!  FfiConverterInt32.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadrequest_priority(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the repository identifier.
+     */
+open func repo() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadrequest_repo(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the Git revision, branch, or tag name.
+     */
+open func revision() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadrequest_revision(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Changes how soon the file starts within a multi-file download, taking effect if it hasn't started yet.
+     */
+open func setPriority(priority: Int32)  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadrequest_set_priority(self.uniffiClonePointer(),
+        FfiConverterInt32.lower(priority),$0
+    )
+}
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileDownloadRequest: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FileDownloadRequest
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FileDownloadRequest {
+        return FileDownloadRequest(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FileDownloadRequest) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileDownloadRequest {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FileDownloadRequest, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileDownloadRequest_lift(_ pointer: UnsafeMutableRawPointer) throws -> FileDownloadRequest {
+    return try FfiConverterTypeFileDownloadRequest.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileDownloadRequest_lower(_ value: FileDownloadRequest) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFileDownloadRequest.lower(value)
+}
+
+
+
+
+
+
+/**
+ * The outcome of one file in a batch download.
+ */
+public protocol FileDownloadResultProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the local path the file was written to.
+     */
+    func destination()  -> String
+    
+    /**
+     * Returns the stable code of the error the file failed with, if it failed.
+     */
+    func errorCode()  -> String?
+    
+    /**
+     * Returns a description of the error the file failed with, if it failed.
+     */
+    func errorMessage()  -> String?
+    
+    /**
+     * Returns the transfer report, if the file was downloaded.
+     */
+    func report()  -> TransferReport?
+    
+    /**
+     * Returns the request this is the outcome of.
+     */
+    func request()  -> FileDownloadRequest
+    
+    /**
+     * Returns what became of the file.
+     */
+    func status()  -> FileDownloadStatus
+    
+}
+/**
+ * The outcome of one file in a batch download.
+ */
+open class FileDownloadResult: FileDownloadResultProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_filedownloadresult(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_filedownloadresult(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the local path the file was written to.
+     */
+open func destination() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadresult_destination(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the stable code of the error the file failed with, if it failed.
+     */
+open func errorCode() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadresult_error_code(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns a description of the error the file failed with, if it failed.
+     */
+open func errorMessage() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadresult_error_message(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the transfer report, if the file was downloaded.
+     */
+open func report() -> TransferReport?  {
+    return try!  FfiConverterOptionTypeTransferReport.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadresult_report(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the request this is the outcome of.
+     */
+open func request() -> FileDownloadRequest  {
+    return try!  FfiConverterTypeFileDownloadRequest_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadresult_request(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns what became of the file.
+     */
+open func status() -> FileDownloadStatus  {
+    return try!  FfiConverterTypeFileDownloadStatus_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filedownloadresult_status(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileDownloadResult: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FileDownloadResult
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FileDownloadResult {
+        return FileDownloadResult(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FileDownloadResult) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileDownloadResult {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FileDownloadResult, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileDownloadResult_lift(_ pointer: UnsafeMutableRawPointer) throws -> FileDownloadResult {
+    return try FfiConverterTypeFileDownloadResult.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileDownloadResult_lower(_ value: FileDownloadResult) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFileDownloadResult.lower(value)
+}
+
+
+
+
+
+
+/**
+ * Metadata about a file or directory entry in a repository.
+ *
+ * This type provides information about entries in a repository's file tree,
+ * including their paths, types, sizes, and content identifiers.
+ */
+public protocol FileMetadataProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the type of the entry.
+     */
+    func entryType()  -> String
+    
+    /**
+     * Returns the content hash of the file, if available.
+     */
+    func hash()  -> String?
+    
+    /**
+     * Returns the Git object ID of the entry, if available.
+     */
+    func oid()  -> String?
+    
+    /**
+     * Returns the path of the file or directory within the repository.
+     */
+    func path()  -> String
+    
+    /**
+     * Returns the Hub's security scan status for the file, if available.
+     */
+    func securityStatus()  -> String?
+    
+    /**
+     * Returns the size of the file in bytes, if available.
+     */
+    func size()  -> UInt64?
+    
+    /**
+     * Returns the Xet hash of the file, if it is stored with Xet.
+     */
+    func xetHash()  -> String?
+    
+}
+/**
+ * Metadata about a file or directory entry in a repository.
+ *
+ * This type provides information about entries in a repository's file tree,
+ * including their paths, types, sizes, and content identifiers.
+ */
+open class FileMetadata: FileMetadataProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_filemetadata(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_filemetadata(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the type of the entry.
+     */
+open func entryType() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filemetadata_entry_type(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the content hash of the file, if available.
+     */
+open func hash() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filemetadata_hash(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the Git object ID of the entry, if available.
+     */
+open func oid() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filemetadata_oid(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the path of the file or directory within the repository.
+     */
+open func path() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filemetadata_path(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the Hub's security scan status for the file, if available.
+     */
+open func securityStatus() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filemetadata_security_status(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the size of the file in bytes, if available.
+     */
+open func size() -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filemetadata_size(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the Xet hash of the file, if it is stored with Xet.
+     */
+open func xetHash() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filemetadata_xet_hash(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileMetadata: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FileMetadata
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FileMetadata {
+        return FileMetadata(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FileMetadata) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileMetadata {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FileMetadata, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileMetadata_lift(_ pointer: UnsafeMutableRawPointer) throws -> FileMetadata {
+    return try FfiConverterTypeFileMetadata.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileMetadata_lower(_ value: FileMetadata) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFileMetadata.lower(value)
+}
+
+
+
+
+
+
+/**
+ * A file read sequentially from a repository without being written to disk.
+ */
+public protocol FileStreamProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the number of bytes read so far.
+     */
+    func position()  -> UInt64
+    
+    /**
+     * Returns up to `max_bytes` of the file's next bytes, or an empty array at the end of the file.
+     */
+    func readNext(maxBytes: UInt32) throws  -> Data
+    
+    /**
+     * Returns the size of the file in bytes.
+     */
+    func size()  -> UInt64
+    
+}
+/**
+ * A file read sequentially from a repository without being written to disk.
+ */
+open class FileStream: FileStreamProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_filestream(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_filestream(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the number of bytes read so far.
+     */
+open func position() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filestream_position(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns up to `max_bytes` of the file's next bytes, or an empty array at the end of the file.
+     */
+open func readNext(maxBytes: UInt32)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_filestream_read_next(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(maxBytes),$0
+    )
+})
+}
+    
+    /**
+     * Returns the size of the file in bytes.
+     */
+open func size() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_filestream_size(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileStream: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = FileStream
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> FileStream {
+        return FileStream(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: FileStream) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileStream {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: FileStream, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileStream_lift(_ pointer: UnsafeMutableRawPointer) throws -> FileStream {
+    return try FfiConverterTypeFileStream.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileStream_lower(_ value: FileStream) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeFileStream.lower(value)
+}
+
+
+
+
+
+
+/**
+ * A model registered with the client's managed library.
+ */
+public protocol ManagedModelProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the number of those files that are downloaded.
+     */
+    func downloadedFileCount()  -> UInt64
+    
+    /**
+     * Returns the number of files the model's current or last completed update covers.
+     */
+    func fileCount()  -> UInt64
+    
+    /**
+     * Returns the commit of the last completed update.
+     */
+    func installedCommit()  -> String?
+    
+    /**
+     * Returns why the most recent update failed, if it did.
+     */
+    func lastError()  -> String?
+    
+    /**
+     * Returns the commit the revision currently resolves to, or `null` if it couldn't be checked.
+     */
+    func latestCommit()  -> String?
+    
+    /**
+     * Returns the local directory holding the model's files.
+     */
+    func localPath()  -> String
+    
+    /**
+     * Returns the glob patterns selecting which files are downloaded, or an empty array for all files.
+     */
+    func patterns()  -> [String]
+    
+    /**
+     * Returns the repository identifier, such as `"models/owner/name"`.
+     */
+    func repo()  -> String
+    
+    /**
+     * Returns which revision of the repository is kept downloaded.
+     */
+    func revisionPolicy()  -> ManagedRevisionPolicy
+    
+    /**
+     * Returns the state of the model's local files.
+     */
+    func status()  -> ManagedModelStatus
+    
+}
+/**
+ * A model registered with the client's managed library.
+ */
+open class ManagedModel: ManagedModelProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_managedmodel(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_managedmodel(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the number of those files that are downloaded.
+     */
+open func downloadedFileCount() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_managedmodel_downloaded_file_count(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the number of files the model's current or last completed update covers.
+     */
+open func fileCount() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_managedmodel_file_count(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the commit of the last completed update.
+     */
+open func installedCommit() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_managedmodel_installed_commit(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns why the most recent update failed, if it did.
+     */
+open func lastError() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_managedmodel_last_error(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the commit the revision currently resolves to, or `null` if it couldn't be checked.
+     */
+open func latestCommit() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_managedmodel_latest_commit(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the local directory holding the model's files.
+     */
+open func localPath() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_managedmodel_local_path(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the glob patterns selecting which files are downloaded, or an empty array for all files.
+     */
+open func patterns() -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_managedmodel_patterns(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the repository identifier, such as `"models/owner/name"`.
+     */
+open func repo() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_managedmodel_repo(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns which revision of the repository is kept downloaded.
+     */
+open func revisionPolicy() -> ManagedRevisionPolicy  {
+    return try!  FfiConverterTypeManagedRevisionPolicy_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_managedmodel_revision_policy(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the state of the model's local files.
+     */
+open func status() -> ManagedModelStatus  {
+    return try!  FfiConverterTypeManagedModelStatus_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_managedmodel_status(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeManagedModel: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = ManagedModel
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> ManagedModel {
+        return ManagedModel(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: ManagedModel) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManagedModel {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: ManagedModel, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManagedModel_lift(_ pointer: UnsafeMutableRawPointer) throws -> ManagedModel {
+    return try FfiConverterTypeManagedModel.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManagedModel_lower(_ value: ManagedModel) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeManagedModel.lower(value)
+}
+
+
+
+
+
+
+/**
+ * Progress information for file download or upload operations.
+ */
+public protocol ProgressUpdateProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the total number of bytes to process.
+     */
+    func totalBytes()  -> UInt64
+    
+    /**
+     * Returns the number of bytes that have been processed.
+     */
+    func totalBytesCompleted()  -> UInt64
+    
+    /**
+     * Returns the total number of bytes to transfer over the network.
+     */
+    func totalTransferBytes()  -> UInt64
+    
+    /**
+     * Returns the number of bytes that have been transferred over the network.
+     */
+    func totalTransferBytesCompleted()  -> UInt64
+    
+}
+/**
+ * Progress information for file download or upload operations.
+ */
+open class ProgressUpdate: ProgressUpdateProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_progressupdate(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_progressupdate(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the total number of bytes to process.
+     */
+open func totalBytes() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_progressupdate_total_bytes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the number of bytes that have been processed.
+     */
+open func totalBytesCompleted() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_progressupdate_total_bytes_completed(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the total number of bytes to transfer over the network.
+     */
+open func totalTransferBytes() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_progressupdate_total_transfer_bytes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the number of bytes that have been transferred over the network.
+     */
+open func totalTransferBytesCompleted() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_progressupdate_total_transfer_bytes_completed(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProgressUpdate: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = ProgressUpdate
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> ProgressUpdate {
+        return ProgressUpdate(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: ProgressUpdate) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProgressUpdate {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: ProgressUpdate, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProgressUpdate_lift(_ pointer: UnsafeMutableRawPointer) throws -> ProgressUpdate {
+    return try FfiConverterTypeProgressUpdate.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProgressUpdate_lower(_ value: ProgressUpdate) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeProgressUpdate.lower(value)
+}
+
+
+
+
+
+
+/**
+ * The Hub API rate limit as of the most recent response that reported one.
+ *
+ * Any value the Hub didn't report is `null`.
+ */
+public protocol RateLimitStatusProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the number of requests allowed in the current window.
+     */
+    func limit()  -> UInt64?
+    
+    /**
+     * Returns the number of requests left in the current window.
+     */
+    func remaining()  -> UInt64?
+    
+    /**
+     * Returns when the current window resets, as a Unix timestamp in seconds.
+     */
+    func resetAt()  -> UInt64?
+    
+    /**
+     * Returns the number of seconds until the current window resets.
+     */
+    func secondsUntilReset()  -> UInt64?
+    
+}
+/**
+ * The Hub API rate limit as of the most recent response that reported one.
+ *
+ * Any value the Hub didn't report is `null`.
+ */
+open class RateLimitStatus: RateLimitStatusProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_ratelimitstatus(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_ratelimitstatus(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the number of requests allowed in the current window.
+     */
+open func limit() -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_ratelimitstatus_limit(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the number of requests left in the current window.
+     */
+open func remaining() -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_ratelimitstatus_remaining(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns when the current window resets, as a Unix timestamp in seconds.
+     */
+open func resetAt() -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_ratelimitstatus_reset_at(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the number of seconds until the current window resets.
+     */
+open func secondsUntilReset() -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_ratelimitstatus_seconds_until_reset(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRateLimitStatus: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = RateLimitStatus
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> RateLimitStatus {
+        return RateLimitStatus(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: RateLimitStatus) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RateLimitStatus {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: RateLimitStatus, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRateLimitStatus_lift(_ pointer: UnsafeMutableRawPointer) throws -> RateLimitStatus {
+    return try FfiConverterTypeRateLimitStatus.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRateLimitStatus_lower(_ value: RateLimitStatus) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeRateLimitStatus.lower(value)
+}
+
+
+
+
+
+
+/**
+ * The outcome of recovering a commit interrupted by a previous process.
+ */
+public protocol RecoveredCommitProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the action taken for this commit.
+     */
+    func action()  -> CommitRecoveryAction
+    
+    /**
+     * Returns the ID of the created commit, if the commit was completed.
+     */
+    func commitOid()  -> String?
+    
+    /**
+     * Returns a description of why the commit was deferred, if it was.
+     */
+    func error()  -> String?
+    
+    /**
+     * Returns the repository paths included in the commit.
+     */
+    func paths()  -> [String]
+    
+    /**
+     * Returns the full name of the repository the commit targeted.
+     */
+    func repo()  -> String
+    
+    /**
+     * Returns the branch the commit targeted.
+     */
+    func revision()  -> String
+    
+}
+/**
+ * The outcome of recovering a commit interrupted by a previous process.
+ */
+open class RecoveredCommit: RecoveredCommitProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_recoveredcommit(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_recoveredcommit(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the action taken for this commit.
+     */
+open func action() -> CommitRecoveryAction  {
+    return try!  FfiConverterTypeCommitRecoveryAction_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_recoveredcommit_action(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the ID of the created commit, if the commit was completed.
+     */
+open func commitOid() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_recoveredcommit_commit_oid(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns a description of why the commit was deferred, if it was.
+     */
+open func error() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_recoveredcommit_error(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the repository paths included in the commit.
+     */
+open func paths() -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_recoveredcommit_paths(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the full name of the repository the commit targeted.
+     */
+open func repo() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_recoveredcommit_repo(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the branch the commit targeted.
+     */
+open func revision() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_recoveredcommit_revision(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRecoveredCommit: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = RecoveredCommit
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> RecoveredCommit {
+        return RecoveredCommit(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: RecoveredCommit) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecoveredCommit {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: RecoveredCommit, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecoveredCommit_lift(_ pointer: UnsafeMutableRawPointer) throws -> RecoveredCommit {
+    return try FfiConverterTypeRecoveredCommit.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecoveredCommit_lower(_ value: RecoveredCommit) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeRecoveredCommit.lower(value)
+}
+
+
+
+
+
+
+/**
+ * Information about a Hugging Face repository.
+ *
+ * This type contains the repository type and full name, which uniquely
+ * identify a repository on Hugging Face Hub.
+ */
+public protocol RepoInfoProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the full name of the repository.
+     */
+    func fullName()  -> String
+    
+    /**
+     * Returns the type of the repository.
+     */
+    func repoType()  -> String
+    
+}
+/**
+ * Information about a Hugging Face repository.
+ *
+ * This type contains the repository type and full name, which uniquely
+ * identify a repository on Hugging Face Hub.
+ */
+open class RepoInfo: RepoInfoProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_repoinfo(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_repoinfo(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the full name of the repository.
+     */
+open func fullName() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_repoinfo_full_name(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the type of the repository.
+     */
+open func repoType() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_repoinfo_repo_type(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRepoInfo: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = RepoInfo
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> RepoInfo {
+        return RepoInfo(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: RepoInfo) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepoInfo {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: RepoInfo, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepoInfo_lift(_ pointer: UnsafeMutableRawPointer) throws -> RepoInfo {
+    return try FfiConverterTypeRepoInfo.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepoInfo_lower(_ value: RepoInfo) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeRepoInfo.lower(value)
+}
+
+
+
+
+
+
+/**
+ * The estimated size of a set of files in a repository.
+ */
+public protocol RepoSizeProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the estimated space the files take once downloaded, in bytes.
+     */
+    func estimatedDiskBytes()  -> UInt64
+    
+    /**
+     * Returns the number of files.
+     */
+    func fileCount()  -> UInt64
+    
+    /**
+     * Returns the combined size of the files in bytes.
+     */
+    func logicalBytes()  -> UInt64
+    
+}
+/**
+ * The estimated size of a set of files in a repository.
+ */
+open class RepoSize: RepoSizeProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_reposize(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_reposize(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the estimated space the files take once downloaded, in bytes.
+     */
+open func estimatedDiskBytes() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_reposize_estimated_disk_bytes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the number of files.
+     */
+open func fileCount() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_reposize_file_count(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the combined size of the files in bytes.
+     */
+open func logicalBytes() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_reposize_logical_bytes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRepoSize: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = RepoSize
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> RepoSize {
+        return RepoSize(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: RepoSize) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepoSize {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: RepoSize, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepoSize_lift(_ pointer: UnsafeMutableRawPointer) throws -> RepoSize {
+    return try FfiConverterTypeRepoSize.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepoSize_lower(_ value: RepoSize) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeRepoSize.lower(value)
+}
+
+
+
+
+
+
+/**
+ * A set of downloads that make up one user-facing task, paused, resumed, cancelled, and reported on together.
+ */
+public protocol TransferGroupProtocol: AnyObject, Sendable {
+    
+    /**
+     * Cancels every download in the group, and any added later, on behalf of the user.
+     */
+    func cancel() 
+    
+    /**
+     * Cancels every download in the group, and any added later, recording why.
+     */
+    func cancelWithReason(reason: CancellationReason) 
+    
+    /**
+     * Returns the number of downloads added to the group.
+     */
+    func downloadCount()  -> UInt64
+    
+    /**
+     * Returns whether cancellation has been requested.
+     */
+    func isCancelled()  -> Bool
+    
+    /**
+     * Returns whether the group is paused.
+     */
+    func isPaused()  -> Bool
+    
+    /**
+     * Pauses every download in the group at its next checkpoint.
+     */
+    func pause() 
+    
+    /**
+     * Returns the group's progress so far, summed over its downloads.
+     */
+    func progress()  -> ProgressUpdate
+    
+    /**
+     * Lets the group's paused downloads continue.
+     */
+    func resume() 
+    
+}
+/**
+ * A set of downloads that make up one user-facing task, paused, resumed, cancelled, and reported on together.
+ */
+open class TransferGroup: TransferGroupProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_transfergroup(self.pointer, $0) }
+    }
+    /**
+     * Creates an empty group reporting its aggregate progress to a callback.
+     */
+public convenience init(progress: ProgressCallback?) {
+    let pointer =
+        try! rustCall() {
+    uniffi_swift_xet_rust_fn_constructor_transfergroup_new(
+        FfiConverterOptionCallbackInterfaceProgressCallback.lower(progress),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_transfergroup(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Cancels every download in the group, and any added later, on behalf of the user.
+     */
+open func cancel()  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transfergroup_cancel(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Cancels every download in the group, and any added later, recording why.
+     */
+open func cancelWithReason(reason: CancellationReason)  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transfergroup_cancel_with_reason(self.uniffiClonePointer(),
+        FfiConverterTypeCancellationReason_lower(reason),$0
+    )
+}
+}
+    
+    /**
+     * Returns the number of downloads added to the group.
+     */
+open func downloadCount() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transfergroup_download_count(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns whether cancellation has been requested.
+     */
+open func isCancelled() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transfergroup_is_cancelled(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns whether the group is paused.
+     */
+open func isPaused() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transfergroup_is_paused(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Pauses every download in the group at its next checkpoint.
+     */
+open func pause()  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transfergroup_pause(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Returns the group's progress so far, summed over its downloads.
+     */
+open func progress() -> ProgressUpdate  {
+    return try!  FfiConverterTypeProgressUpdate_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transfergroup_progress(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Lets the group's paused downloads continue.
+     */
+open func resume()  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transfergroup_resume(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTransferGroup: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = TransferGroup
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> TransferGroup {
+        return TransferGroup(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: TransferGroup) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TransferGroup {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: TransferGroup, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTransferGroup_lift(_ pointer: UnsafeMutableRawPointer) throws -> TransferGroup {
+    return try FfiConverterTypeTransferGroup.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTransferGroup_lower(_ value: TransferGroup) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeTransferGroup.lower(value)
+}
+
+
+
+
+
+
+/**
+ * A summary of a completed file download.
+ *
+ * Verification time is reported separately from the overall elapsed time.
+ */
+public protocol TransferReportProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the size of the downloaded file in bytes.
+     */
+    func bytes()  -> UInt64
+    
+    /**
+     * Returns the CAS endpoint the file was reconstructed from, if it came from Xet storage.
+     */
+    func casEndpoint()  -> String?
+    
+    /**
+     * Returns the round-trip times measured to each CAS endpoint when choosing one.
+     */
+    func casEndpointLatencies()  -> [CasEndpointLatency]
+    
+    /**
+     * Returns the wall-clock time of the download in milliseconds, including verification.
+     */
+    func elapsedMs()  -> UInt64
+    
+    /**
+     * Returns whether the file was resolved from a cached resolution because the Hub was unreachable.
+     */
+    func servedStale()  -> Bool
+    
+    /**
+     * Returns the transfer path that produced the file.
+     */
+    func source()  -> DownloadSource
+    
+    /**
+     * Returns whether the file was verified.
+     */
+    func verification()  -> VerificationStatus
+    
+    /**
+     * Returns the time spent hashing the file in milliseconds.
+     */
+    func verificationMs()  -> UInt64
+    
+}
+/**
+ * A summary of a completed file download.
+ *
+ * Verification time is reported separately from the overall elapsed time.
+ */
+open class TransferReport: TransferReportProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_transferreport(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_transferreport(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the size of the downloaded file in bytes.
+     */
+open func bytes() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transferreport_bytes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the CAS endpoint the file was reconstructed from, if it came from Xet storage.
+     */
+open func casEndpoint() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transferreport_cas_endpoint(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the round-trip times measured to each CAS endpoint when choosing one.
+     */
+open func casEndpointLatencies() -> [CasEndpointLatency]  {
+    return try!  FfiConverterSequenceTypeCasEndpointLatency.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transferreport_cas_endpoint_latencies(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the wall-clock time of the download in milliseconds, including verification.
+     */
+open func elapsedMs() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transferreport_elapsed_ms(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns whether the file was resolved from a cached resolution because the Hub was unreachable.
+     */
+open func servedStale() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transferreport_served_stale(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the transfer path that produced the file.
+     */
+open func source() -> DownloadSource  {
+    return try!  FfiConverterTypeDownloadSource_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transferreport_source(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns whether the file was verified.
+     */
+open func verification() -> VerificationStatus  {
+    return try!  FfiConverterTypeVerificationStatus_lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transferreport_verification(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the time spent hashing the file in milliseconds.
+     */
+open func verificationMs() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_transferreport_verification_ms(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTransferReport: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = TransferReport
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> TransferReport {
+        return TransferReport(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: TransferReport) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TransferReport {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: TransferReport, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTransferReport_lift(_ pointer: UnsafeMutableRawPointer) throws -> TransferReport {
+    return try FfiConverterTypeTransferReport.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTransferReport_lower(_ value: TransferReport) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeTransferReport.lower(value)
+}
+
+
+
+
+
+
+/**
+ * A lazily paged listing of a repository directory.
+ */
+public protocol TreeIteratorProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns whether every page has been fetched.
+     */
+    func isFinished()  -> Bool
+    
+    /**
+     * Fetches the next page of entries, or returns `null` once the listing is exhausted.
+     */
+    func nextPage() throws  -> [FileMetadata]?
+    
+}
+/**
+ * A lazily paged listing of a repository directory.
+ */
+open class TreeIterator: TreeIteratorProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_treeiterator(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_treeiterator(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns whether every page has been fetched.
+     */
+open func isFinished() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_treeiterator_is_finished(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Fetches the next page of entries, or returns `null` once the listing is exhausted.
+     */
+open func nextPage()throws  -> [FileMetadata]?  {
+    return try  FfiConverterOptionSequenceTypeFileMetadata.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_treeiterator_next_page(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTreeIterator: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = TreeIterator
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> TreeIterator {
+        return TreeIterator(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: TreeIterator) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TreeIterator {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: TreeIterator, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTreeIterator_lift(_ pointer: UnsafeMutableRawPointer) throws -> TreeIterator {
+    return try FfiConverterTypeTreeIterator.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTreeIterator_lower(_ value: TreeIterator) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeTreeIterator.lower(value)
+}
+
+
+
+
+
+
+/**
+ * The files under a repository directory, and whether any could be missed.
+ */
+public protocol TreeListingProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the files found, with paths relative to the repository root.
+     */
+    func files()  -> [FileMetadata]
+    
+    /**
+     * Returns whether every directory was listed, so no file is missing.
+     */
+    func isComplete()  -> Bool
+    
+    /**
+     * Returns the directories that couldn't be listed, whose files are missing from `files`.
+     */
+    func unlistedPaths()  -> [String]
+    
+}
+/**
+ * The files under a repository directory, and whether any could be missed.
+ */
+open class TreeListing: TreeListingProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_treelisting(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_treelisting(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the files found, with paths relative to the repository root.
+     */
+open func files() -> [FileMetadata]  {
+    return try!  FfiConverterSequenceTypeFileMetadata.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_treelisting_files(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns whether every directory was listed, so no file is missing.
+     */
+open func isComplete() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_treelisting_is_complete(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the directories that couldn't be listed, whose files are missing from `files`.
+     */
+open func unlistedPaths() -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_treelisting_unlisted_paths(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTreeListing: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = TreeListing
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> TreeListing {
+        return TreeListing(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: TreeListing) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TreeListing {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: TreeListing, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTreeListing_lift(_ pointer: UnsafeMutableRawPointer) throws -> TreeListing {
+    return try FfiConverterTypeTreeListing.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTreeListing_lower(_ value: TreeListing) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeTreeListing.lower(value)
+}
+
+
+
+
+
+
+/**
+ * Local files staged to be published to a repository together in a single commit.
+ */
+public protocol UploadSessionProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the repository paths staged so far, in the order they were staged.
+     */
+    func stagedPaths()  -> [String]
+    
+}
+/**
+ * Local files staged to be published to a repository together in a single commit.
+ */
+open class UploadSession: UploadSessionProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_uploadsession(self.pointer, $0) }
+    }
+    /**
+     * Creates a session with no staged files.
+     */
+public convenience init() {
+    let pointer =
+        try! rustCall() {
+    uniffi_swift_xet_rust_fn_constructor_uploadsession_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_uploadsession(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the repository paths staged so far, in the order they were staged.
+     */
+open func stagedPaths() -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_uploadsession_staged_paths(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUploadSession: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = UploadSession
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> UploadSession {
+        return UploadSession(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: UploadSession) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UploadSession {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: UploadSession, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUploadSession_lift(_ pointer: UnsafeMutableRawPointer) throws -> UploadSession {
+    return try FfiConverterTypeUploadSession.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUploadSession_lower(_ value: UploadSession) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeUploadSession.lower(value)
+}
+
+
+
+
+
+
+/**
+ * A client for interacting with Xet repositories.
+ *
+ * The `XetClient` provides methods to download files, list repository contents,
+ * and manage the local cache for Xet-enabled repositories on Hugging Face Hub.
+ *
+ * You can create a client with or without authentication. For public repositories,
+ * authentication is optional. For private repositories or upload operations,
+ * you need to provide a Hugging Face authentication token.
+ */
+public protocol XetClientProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns whether the client's token grants an operation on a repository.
+     */
+    func canAccess(repo: String, operation: RepoOperation) throws  -> Bool
+    
+    /**
+     * Clears all files from the local Xet cache.
+     */
+    func clearCache() throws 
+    
+    /**
+     * Clears a single area of the local Xet cache.
+     */
+    func clearCacheArea(area: CacheArea) throws 
+    
+    /**
+     * Deletes a file from a branch of the repository, or the default revision if `null`, in a new commit, returning the commit ID.
+     */
+    func deleteFile(repo: String, path: String, revision: String?, commitMessage: String) throws  -> String
+    
+    /**
+     * Reconstructs a file from CAS by its Xet hash, without resolving a repository path.
+     */
+    func downloadByHash(xetHash: String, size: UInt64, destination: String, jwtInfo: CasJwtInfo) throws 
+    
+    /**
+     * Downloads a file from a Xet repository to a local path.
+     */
+    func downloadFile(repo: String, path: String, destination: String, revision: String?) throws  -> TransferReport
+    
+    /**
+     * Downloads a file from a Xet repository without blocking the caller.
+     */
+    func downloadFileAsync(repo: String, path: String, destination: String, revision: String?) async throws  -> TransferReport
+    
+    /**
+     * Downloads a file as part of a group, which pauses, resumes, cancels, and reports progress on it with the group's other downloads.
+     */
+    func downloadFileInGroup(repo: String, path: String, destination: String, revision: String?, group: TransferGroup) throws  -> TransferReport
+    
+    /**
+     * Downloads a file as part of a group without blocking the caller.
+     */
+    func downloadFileInGroupAsync(repo: String, path: String, destination: String, revision: String?, group: TransferGroup) async throws  -> TransferReport
+    
+    /**
+     * Downloads a file that can be paused, resumed, and cancelled through a handle.
+     */
+    func downloadFileWithHandle(repo: String, path: String, destination: String, revision: String?, progress: ProgressCallback?, handle: DownloadHandle) throws  -> TransferReport
+    
+    /**
+     * Downloads a file without blocking the caller, pausing, resuming, and cancelling through a handle.
+     */
+    func downloadFileWithHandleAsync(repo: String, path: String, destination: String, revision: String?, progress: ProgressCallback?, handle: DownloadHandle) async throws  -> TransferReport
+    
+    /**
+     * Downloads a file, reporting progress and honoring cancellation.
+     */
+    func downloadFileWithProgress(repo: String, path: String, destination: String, revision: String?, progress: ProgressCallback?, cancellation: CancellationToken?) throws  -> TransferReport
+    
+    /**
+     * Downloads a file without blocking the caller, reporting progress and honoring cancellation.
+     */
+    func downloadFileWithProgressAsync(repo: String, path: String, destination: String, revision: String?, progress: ProgressCallback?, cancellation: CancellationToken?) async throws  -> TransferReport
+    
+    /**
+     * Downloads files using the Xet Content-Addressable Storage (CAS) system.
+     */
+    func downloadFiles(fileInfos: [XetFileInfo], destinationDir: String, jwtInfo: CasJwtInfo) throws  -> [String]
+    
+    /**
+     * Downloads the requests matching the given patterns in a single batch operation.
+     */
+    func downloadFilesBatch(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String]) throws  -> [String]
+    
+    /**
+     * Downloads the requests matching the given patterns without blocking the caller.
+     */
+    func downloadFilesBatchAsync(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String]) async throws  -> [String]
+    
+    /**
+     * Downloads multiple files, reporting every request as downloaded, failed, or skipped.
+     */
+    func downloadFilesBatchDetailed(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String], continueOnError: Bool) throws  -> BatchDownloadResult
+    
+    /**
+     * Downloads multiple files without blocking the caller, reporting every request as downloaded, failed, or skipped.
+     */
+    func downloadFilesBatchDetailedAsync(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String], continueOnError: Bool) async throws  -> BatchDownloadResult
+    
+    /**
+     * Downloads the requests matching the given patterns, reporting the outcome of each one.
+     */
+    func downloadFilesBatchWithResults(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String]) throws  -> [FileDownloadResult]
+    
+    /**
+     * Downloads the requests matching the given patterns without blocking the caller, reporting the outcome of each one.
+     */
+    func downloadFilesBatchWithResultsAsync(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String]) async throws  -> [FileDownloadResult]
+    
+    /**
+     * Downloads multiple files, naming each destination from a template.
+     */
+    func downloadFilesBatchWithTemplate(requests: [FileDownloadRequest], destinationRoot: String, template: String, allowPatterns: [String], ignorePatterns: [String]) throws  -> [String]
+    
+    /**
+     * Downloads the files under a folder of a repository into a local directory, returning the local paths.
+     */
+    func downloadFolder(repo: String, folderPath: String, destinationDir: String, revision: String?) throws  -> [String]
+    
+    /**
+     * Downloads the files under a folder of a repository without blocking the caller.
+     */
+    func downloadFolderAsync(repo: String, folderPath: String, destinationDir: String, revision: String?) async throws  -> [String]
+    
+    /**
+     * Downloads an archive of a repository's files at a revision, returning its size in bytes.
+     */
+    func downloadRepoArchive(repo: String, revision: String?, destination: String, format: ArchiveFormat) throws  -> UInt64
+    
+    /**
+     * Downloads an archive of a repository, reporting progress and honoring cancellation.
+     */
+    func downloadRepoArchiveWithProgress(repo: String, revision: String?, destination: String, format: ArchiveFormat, progress: ProgressCallback?, cancellation: CancellationToken?) throws  -> UInt64
+    
+    /**
+     * Downloads a file into the client's cache and returns its local path.
+     */
+    func downloadToCache(repo: String, path: String, revision: String?) throws  -> String
+    
+    /**
+     * Downloads a file into the client's cache without blocking the caller.
+     */
+    func downloadToCacheAsync(repo: String, path: String, revision: String?) async throws  -> String
+    
+    /**
+     * Estimates the bytes uploading local files would transfer after deduplication, without uploading them.
+     */
+    func estimateUpload(repo: String, localPaths: [String], revision: String?) throws  -> UploadEstimate
+    
+    /**
+     * Returns the requests whose destinations don't already hold their file, checking only local files and records.
+     */
+    func filterMissing(requests: [FileDownloadRequest])  -> [FileDownloadRequest]
+    
+    /**
+     * Returns statistics about the local Xet cache.
+     */
+    func getCacheStats() throws  -> CacheStats
+    
+    /**
+     * Returns the local path of a cached file without touching the network, or `null` if it isn't cached.
+     */
+    func getCachedPath(repo: String, path: String, revision: String?) throws  -> String?
+    
+    /**
+     * Retrieves a JWT token for accessing the Content-Addressable Storage (CAS) system.
+     */
+    func getCasJwt(repo: String, revision: String?, isUpload: Bool) throws  -> CasJwtInfo
+    
+    /**
+     * Returns how far the Hub's clock is ahead of the device's in seconds, as estimated from the most recent Hub response.
+     */
+    func getClockSkewSecs()  -> Int64
+    
+    /**
+     * Retrieves file information from a pointer file in the repository.
+     */
+    func getFileInfo(repo: String, path: String, revision: String?) throws  -> XetFileInfo?
+    
+    /**
+     * Returns metadata for each of the given paths that exists, resolved in as few requests as possible.
+     */
+    func getPathsInfo(repo: String, paths: [String], revision: String?) throws  -> [FileMetadata]
+    
+    /**
+     * Returns the Hub API rate limit reported by the most recent response that included one.
+     */
+    func getRateLimitStatus()  -> RateLimitStatus?
+    
+    /**
+     * Parses a repository identifier and returns structured repository information.
+     */
+    func getRepoInfo(repo: String) throws  -> RepoInfo
+    
+    /**
+     * Returns the size of the files in a repository, or of those matching `patterns`.
+     */
+    func getRepoSize(repo: String, revision: String?, patterns: [String]) throws  -> RepoSize
+    
+    /**
+     * Returns the bytes and time of the transfers the client has completed, by label.
+     */
+    func getUsageReport()  -> [LabelUsage]
+    
+    /**
+     * Returns the Xet hash, token refresh route, and CAS endpoint of a file, or `null` if it isn't stored with Xet.
+     */
+    func getXetPointerDetails(repo: String, path: String, revision: String?) throws  -> XetPointerDetails?
+    
+    /**
+     * Lists the revisions of a file available in the client's cache, most recently cached first.
+     */
+    func listCachedRevisions(repo: String, path: String) throws  -> [CachedRevision]
+    
+    /**
+     * Lists every file under a directory, reporting whether any directory was too large to list.
+     */
+    func listFilesRecursive(repo: String, path: String, revision: String?) throws  -> TreeListing
+    
+    /**
+     * Lists the models in the managed library with the state of their local files.
+     */
+    func listManaged() throws  -> [ManagedModel]
+    
+    /**
+     * Moves a file to a new path on a branch of the repository, or the default revision if `null`, in a new commit, returning the commit ID.
+     */
+    func moveFile(repo: String, fromPath: String, toPath: String, revision: String?, commitMessage: String) throws  -> String
+    
+    /**
+     * Opens a file for reading in chunks, without writing it to disk.
+     */
+    func openFileStream(repo: String, path: String, revision: String?) throws  -> FileStream
+    
+    /**
+     * Stops downloads at their next checkpoint, keeping their resume state, and returns whether they all stopped within the timeout.
+     */
+    func prepareForTermination(timeoutMs: UInt64)  -> Bool
+    
+    /**
+     * Asks the Hub whether each file would be stored in Xet or inline in git, and whether it's ignored, before uploading any.
+     */
+    func preupload(repo: String, files: [PreuploadFile], revision: String?) throws  -> [PreuploadResult]
+    
+    /**
+     * Evicts the least recently modified files in a cache area until it fits a size limit.
+     */
+    func pruneCacheArea(area: CacheArea, maxSizeBytes: UInt64) throws  -> UInt64
+    
+    /**
+     * Uploads the files staged in a session and commits them to a branch in a single commit, returning the commit ID.
+     */
+    func publish(session: UploadSession, repo: String, branch: String, message: String) throws  -> String
+    
+    /**
+     * Starts logging the client's requests, with signatures and credentials redacted, to a file of JSON lines for reporting network problems.
+     */
+    func recordSession(path: String) throws 
+    
+    /**
+     * Finishes or rolls back commits interrupted by a previous process.
+     */
+    func recoverPendingCommits() throws  -> [RecoveredCommit]
+    
+    /**
+     * Registers a model to be downloaded and kept up to date by `update_all_managed`.
+     */
+    func registerManagedModel(repo: String, revisionPolicy: ManagedRevisionPolicy, patterns: [String]) throws  -> ManagedModel
+    
+    /**
+     * Clears the usage report, such as at the start of a new billing period.
+     */
+    func resetUsageReport() 
+    
+    /**
+     * Sets the policy consulted before each file download begins.
+     */
+    func setDownloadPolicy(policy: DownloadPolicy?) 
+    
+    /**
+     * Sets the listener that receives client events.
+     */
+    func setEventListener(listener: XetEventListener?) 
+    
+    /**
+     * Sets the most bytes per second the client downloads across all transfers.
+     */
+    func setMaxDownloadBytesPerSecond(bytesPerSecond: UInt64?) throws 
+    
+    /**
+     * Sets whether the client writes diagnostics to standard error.
+     */
+    func setQuiet(quiet: Bool) 
+    
+    /**
+     * Sets the label transfers are attributed to in the usage report, or `null` to leave them unlabeled.
+     */
+    func setUsageLabel(label: String?) 
+    
+    /**
+     * Downloads every file in a repository into a local directory, returning the local paths.
+     */
+    func snapshotDownload(repo: String, revision: String?, destinationDir: String, allowPatterns: [String], ignorePatterns: [String], destinationTemplate: String?) throws  -> [String]
+    
+    /**
+     * Downloads every file in a repository into a local directory without blocking the caller.
+     */
+    func snapshotDownloadAsync(repo: String, revision: String?, destinationDir: String, allowPatterns: [String], ignorePatterns: [String], destinationTemplate: String?) async throws  -> [String]
+    
+    /**
+     * Stages a local file to be published at a path in a repository, replacing any file staged at that path.
+     */
+    func stageFile(session: UploadSession, localPath: String, repoPath: String) throws 
+    
+    /**
+     * Starts periodic cache maintenance in the background.
+     */
+    func startCacheMaintenance(policy: CacheMaintenancePolicy) throws 
+    
+    /**
+     * Stops background cache maintenance.
+     */
+    func stopCacheMaintenance() 
+    
+    /**
+     * Stops the recording started with `record_session`, if any.
+     */
+    func stopRecording() 
+    
+    /**
+     * Returns an iterator that lists a directory one page at a time.
+     */
+    func treeIterator(repo: String, path: String, revision: String?, recursive: Bool) throws  -> TreeIterator
+    
+    /**
+     * Downloads every managed model that is missing, incomplete, or out of date.
+     */
+    func updateAllManaged() throws  -> [ManagedModel]
+    
+    /**
+     * Uploads content from memory and commits it to the repository as one file, returning the commit ID.
+     */
+    func uploadBytes(repo: String, data: Data, pathInRepo: String, commitMessage: String) throws  -> String
+    
+    /**
+     * Uploads a file from disk and commits it to a branch of the repository, or the default revision if `null`, returning the commit ID.
+     */
+    func uploadFile(repo: String, localPath: String, pathInRepo: String, revision: String?, commitMessage: String) throws  -> String
+    
+    /**
+     * Uploads a file from disk and commits it, reporting chunking and hashing progress separately from the bytes sent after deduplication.
+     */
+    func uploadFileWithProgress(repo: String, localPath: String, pathInRepo: String, revision: String?, commitMessage: String, progress: ProgressCallback?) throws  -> String
+    
+    /**
+     * Uploads many local files in one session, so content shared between them is uploaded once, and commits them all in a single commit.
+     */
+    func uploadFiles(repo: String, requests: [FileUploadRequest], revision: String?, commitMessage: String) throws  -> BatchUploadResult
+    
+    /**
+     * Uploads many local files in one session and commits them all, reporting progress across the batch.
+     */
+    func uploadFilesWithProgress(repo: String, requests: [FileUploadRequest], revision: String?, commitMessage: String, progress: ProgressCallback?) throws  -> BatchUploadResult
+    
+    /**
+     * Uploads the files in a local directory, selected by glob patterns, under a path in the repository, committing at most 100 files per commit.
+     */
+    func uploadFolder(repo: String, localDir: String, pathInRepo: String, revision: String?, allowPatterns: [String], ignorePatterns: [String], commitMessage: String) throws  -> [BatchUploadResult]
+    
+    /**
+     * Returns the version of the Xet client library.
+     */
+    func version()  -> String
+    
+}
+/**
+ * A client for interacting with Xet repositories.
+ *
+ * The `XetClient` provides methods to download files, list repository contents,
+ * and manage the local cache for Xet-enabled repositories on Hugging Face Hub.
+ *
+ * You can create a client with or without authentication. For public repositories,
+ * authentication is optional. For private repositories or upload operations,
+ * you need to provide a Hugging Face authentication token.
+ */
+open class XetClient: XetClientProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_xetclient(self.pointer, $0) }
+    }
+    /**
+     * Creates a new Xet client without authentication.
+     */
+public convenience init()throws  {
+    let pointer =
+        try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_constructor_xetclient_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_xetclient(pointer, $0) }
+    }
+
+    
+    /**
+     * Creates a new Xet client from a configuration.
+     */
+public static func withConfig(config: XetClientConfig)throws  -> XetClient  {
+    return try  FfiConverterTypeXetClient_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_constructor_xetclient_with_config(
+        FfiConverterTypeXetClientConfig_lower(config),$0
+    )
+})
+}
+    
+    /**
+     * Creates a new Xet client with an authentication token.
+     */
+public static func withToken(token: String)throws  -> XetClient  {
+    return try  FfiConverterTypeXetClient_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_constructor_xetclient_with_token(
+        FfiConverterString.lower(token),$0
+    )
+})
+}
+    
+
+    
+    /**
+     * Returns whether the client's token grants an operation on a repository.
+     */
+open func canAccess(repo: String, operation: RepoOperation)throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_can_access(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterTypeRepoOperation_lower(operation),$0
+    )
+})
+}
+    
+    /**
+     * Clears all files from the local Xet cache.
+     */
+open func clearCache()throws   {try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_clear_cache(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Clears a single area of the local Xet cache.
+     */
+open func clearCacheArea(area: CacheArea)throws   {try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_clear_cache_area(self.uniffiClonePointer(),
+        FfiConverterTypeCacheArea_lower(area),$0
+    )
+}
+}
+    
+    /**
+     * Deletes a file from a branch of the repository, or the default revision if `null`, in a new commit, returning the commit ID.
+     */
+open func deleteFile(repo: String, path: String, revision: String?, commitMessage: String)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_delete_file(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterString.lower(commitMessage),$0
+    )
+})
+}
+    
+    /**
+     * Reconstructs a file from CAS by its Xet hash, without resolving a repository path.
+     */
+open func downloadByHash(xetHash: String, size: UInt64, destination: String, jwtInfo: CasJwtInfo)throws   {try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_by_hash(self.uniffiClonePointer(),
+        FfiConverterString.lower(xetHash),
+        FfiConverterUInt64.lower(size),
+        FfiConverterString.lower(destination),
+        FfiConverterTypeCasJwtInfo_lower(jwtInfo),$0
+    )
+}
+}
+    
+    /**
+     * Downloads a file from a Xet repository to a local path.
+     */
+open func downloadFile(repo: String, path: String, destination: String, revision: String?)throws  -> TransferReport  {
+    return try  FfiConverterTypeTransferReport_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_file(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(destination),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Downloads a file from a Xet repository without blocking the caller.
+     */
+open func downloadFileAsync(repo: String, path: String, destination: String, revision: String?)async throws  -> TransferReport  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_swift_xet_rust_fn_method_xetclient_download_file_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(repo),FfiConverterString.lower(path),FfiConverterString.lower(destination),FfiConverterOptionString.lower(revision)
+                )
+            },
+            pollFunc: ffi_swift_xet_rust_rust_future_poll_pointer,
+            completeFunc: ffi_swift_xet_rust_rust_future_complete_pointer,
+            freeFunc: ffi_swift_xet_rust_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeTransferReport_lift,
+            errorHandler: FfiConverterTypeXetError_lift
+        )
+}
+    
+    /**
+     * Downloads a file as part of a group, which pauses, resumes, cancels, and reports progress on it with the group's other downloads.
+     */
+open func downloadFileInGroup(repo: String, path: String, destination: String, revision: String?, group: TransferGroup)throws  -> TransferReport  {
+    return try  FfiConverterTypeTransferReport_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_file_in_group(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(destination),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterTypeTransferGroup_lower(group),$0
+    )
+})
+}
+    
+    /**
+     * Downloads a file as part of a group without blocking the caller.
+     */
+open func downloadFileInGroupAsync(repo: String, path: String, destination: String, revision: String?, group: TransferGroup)async throws  -> TransferReport  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_swift_xet_rust_fn_method_xetclient_download_file_in_group_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(repo),FfiConverterString.lower(path),FfiConverterString.lower(destination),FfiConverterOptionString.lower(revision),FfiConverterTypeTransferGroup_lower(group)
+                )
+            },
+            pollFunc: ffi_swift_xet_rust_rust_future_poll_pointer,
+            completeFunc: ffi_swift_xet_rust_rust_future_complete_pointer,
+            freeFunc: ffi_swift_xet_rust_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeTransferReport_lift,
+            errorHandler: FfiConverterTypeXetError_lift
+        )
+}
+    
+    /**
+     * Downloads a file that can be paused, resumed, and cancelled through a handle.
+     */
+open func downloadFileWithHandle(repo: String, path: String, destination: String, revision: String?, progress: ProgressCallback?, handle: DownloadHandle)throws  -> TransferReport  {
+    return try  FfiConverterTypeTransferReport_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_file_with_handle(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(destination),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterOptionCallbackInterfaceProgressCallback.lower(progress),
+        FfiConverterTypeDownloadHandle_lower(handle),$0
+    )
+})
+}
+    
+    /**
+     * Downloads a file without blocking the caller, pausing, resuming, and cancelling through a handle.
+     */
+open func downloadFileWithHandleAsync(repo: String, path: String, destination: String, revision: String?, progress: ProgressCallback?, handle: DownloadHandle)async throws  -> TransferReport  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_swift_xet_rust_fn_method_xetclient_download_file_with_handle_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(repo),FfiConverterString.lower(path),FfiConverterString.lower(destination),FfiConverterOptionString.lower(revision),FfiConverterOptionCallbackInterfaceProgressCallback.lower(progress),FfiConverterTypeDownloadHandle_lower(handle)
+                )
+            },
+            pollFunc: ffi_swift_xet_rust_rust_future_poll_pointer,
+            completeFunc: ffi_swift_xet_rust_rust_future_complete_pointer,
+            freeFunc: ffi_swift_xet_rust_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeTransferReport_lift,
+            errorHandler: FfiConverterTypeXetError_lift
+        )
+}
+    
+    /**
+     * Downloads a file, reporting progress and honoring cancellation.
+     */
+open func downloadFileWithProgress(repo: String, path: String, destination: String, revision: String?, progress: ProgressCallback?, cancellation: CancellationToken?)throws  -> TransferReport  {
+    return try  FfiConverterTypeTransferReport_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_file_with_progress(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(destination),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterOptionCallbackInterfaceProgressCallback.lower(progress),
+        FfiConverterOptionTypeCancellationToken.lower(cancellation),$0
+    )
+})
+}
+    
+    /**
+     * Downloads a file without blocking the caller, reporting progress and honoring cancellation.
+     */
+open func downloadFileWithProgressAsync(repo: String, path: String, destination: String, revision: String?, progress: ProgressCallback?, cancellation: CancellationToken?)async throws  -> TransferReport  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_swift_xet_rust_fn_method_xetclient_download_file_with_progress_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(repo),FfiConverterString.lower(path),FfiConverterString.lower(destination),FfiConverterOptionString.lower(revision),FfiConverterOptionCallbackInterfaceProgressCallback.lower(progress),FfiConverterOptionTypeCancellationToken.lower(cancellation)
+                )
+            },
+            pollFunc: ffi_swift_xet_rust_rust_future_poll_pointer,
+            completeFunc: ffi_swift_xet_rust_rust_future_complete_pointer,
+            freeFunc: ffi_swift_xet_rust_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeTransferReport_lift,
+            errorHandler: FfiConverterTypeXetError_lift
+        )
+}
+    
+    /**
+     * Downloads files using the Xet Content-Addressable Storage (CAS) system.
+     */
+open func downloadFiles(fileInfos: [XetFileInfo], destinationDir: String, jwtInfo: CasJwtInfo)throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_files(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeXetFileInfo.lower(fileInfos),
+        FfiConverterString.lower(destinationDir),
+        FfiConverterTypeCasJwtInfo_lower(jwtInfo),$0
+    )
+})
+}
+    
+    /**
+     * Downloads the requests matching the given patterns in a single batch operation.
+     */
+open func downloadFilesBatch(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String])throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFileDownloadRequest.lower(requests),
+        FfiConverterSequenceString.lower(allowPatterns),
+        FfiConverterSequenceString.lower(ignorePatterns),$0
+    )
+})
+}
+    
+    /**
+     * Downloads the requests matching the given patterns without blocking the caller.
+     */
+open func downloadFilesBatchAsync(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String])async throws  -> [String]  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceTypeFileDownloadRequest.lower(requests),FfiConverterSequenceString.lower(allowPatterns),FfiConverterSequenceString.lower(ignorePatterns)
+                )
+            },
+            pollFunc: ffi_swift_xet_rust_rust_future_poll_rust_buffer,
+            completeFunc: ffi_swift_xet_rust_rust_future_complete_rust_buffer,
+            freeFunc: ffi_swift_xet_rust_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceString.lift,
+            errorHandler: FfiConverterTypeXetError_lift
+        )
+}
+    
+    /**
+     * Downloads multiple files, reporting every request as downloaded, failed, or skipped.
+     */
+open func downloadFilesBatchDetailed(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String], continueOnError: Bool)throws  -> BatchDownloadResult  {
+    return try  FfiConverterTypeBatchDownloadResult_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_detailed(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFileDownloadRequest.lower(requests),
+        FfiConverterSequenceString.lower(allowPatterns),
+        FfiConverterSequenceString.lower(ignorePatterns),
+        FfiConverterBool.lower(continueOnError),$0
+    )
+})
+}
+    
+    /**
+     * Downloads multiple files without blocking the caller, reporting every request as downloaded, failed, or skipped.
+     */
+open func downloadFilesBatchDetailedAsync(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String], continueOnError: Bool)async throws  -> BatchDownloadResult  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_detailed_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceTypeFileDownloadRequest.lower(requests),FfiConverterSequenceString.lower(allowPatterns),FfiConverterSequenceString.lower(ignorePatterns),FfiConverterBool.lower(continueOnError)
+                )
+            },
+            pollFunc: ffi_swift_xet_rust_rust_future_poll_pointer,
+            completeFunc: ffi_swift_xet_rust_rust_future_complete_pointer,
+            freeFunc: ffi_swift_xet_rust_rust_future_free_pointer,
+            liftFunc: FfiConverterTypeBatchDownloadResult_lift,
+            errorHandler: FfiConverterTypeXetError_lift
+        )
+}
+    
+    /**
+     * Downloads the requests matching the given patterns, reporting the outcome of each one.
+     */
+open func downloadFilesBatchWithResults(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String])throws  -> [FileDownloadResult]  {
+    return try  FfiConverterSequenceTypeFileDownloadResult.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_with_results(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFileDownloadRequest.lower(requests),
+        FfiConverterSequenceString.lower(allowPatterns),
+        FfiConverterSequenceString.lower(ignorePatterns),$0
+    )
+})
+}
+    
+    /**
+     * Downloads the requests matching the given patterns without blocking the caller, reporting the outcome of each one.
+     */
+open func downloadFilesBatchWithResultsAsync(requests: [FileDownloadRequest], allowPatterns: [String], ignorePatterns: [String])async throws  -> [FileDownloadResult]  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_with_results_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceTypeFileDownloadRequest.lower(requests),FfiConverterSequenceString.lower(allowPatterns),FfiConverterSequenceString.lower(ignorePatterns)
+                )
+            },
+            pollFunc: ffi_swift_xet_rust_rust_future_poll_rust_buffer,
+            completeFunc: ffi_swift_xet_rust_rust_future_complete_rust_buffer,
+            freeFunc: ffi_swift_xet_rust_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeFileDownloadResult.lift,
+            errorHandler: FfiConverterTypeXetError_lift
+        )
+}
+    
+    /**
+     * Downloads multiple files, naming each destination from a template.
+     */
+open func downloadFilesBatchWithTemplate(requests: [FileDownloadRequest], destinationRoot: String, template: String, allowPatterns: [String], ignorePatterns: [String])throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_with_template(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFileDownloadRequest.lower(requests),
+        FfiConverterString.lower(destinationRoot),
+        FfiConverterString.lower(template),
+        FfiConverterSequenceString.lower(allowPatterns),
+        FfiConverterSequenceString.lower(ignorePatterns),$0
+    )
+})
+}
+    
+    /**
+     * Downloads the files under a folder of a repository into a local directory, returning the local paths.
+     */
+open func downloadFolder(repo: String, folderPath: String, destinationDir: String, revision: String?)throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_folder(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(folderPath),
+        FfiConverterString.lower(destinationDir),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Downloads the files under a folder of a repository without blocking the caller.
+     */
+open func downloadFolderAsync(repo: String, folderPath: String, destinationDir: String, revision: String?)async throws  -> [String]  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_swift_xet_rust_fn_method_xetclient_download_folder_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(repo),FfiConverterString.lower(folderPath),FfiConverterString.lower(destinationDir),FfiConverterOptionString.lower(revision)
+                )
+            },
+            pollFunc: ffi_swift_xet_rust_rust_future_poll_rust_buffer,
+            completeFunc: ffi_swift_xet_rust_rust_future_complete_rust_buffer,
+            freeFunc: ffi_swift_xet_rust_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceString.lift,
+            errorHandler: FfiConverterTypeXetError_lift
+        )
+}
+    
+    /**
+     * Downloads an archive of a repository's files at a revision, returning its size in bytes.
+     */
+open func downloadRepoArchive(repo: String, revision: String?, destination: String, format: ArchiveFormat)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_repo_archive(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterString.lower(destination),
+        FfiConverterTypeArchiveFormat_lower(format),$0
+    )
+})
+}
+    
+    /**
+     * Downloads an archive of a repository, reporting progress and honoring cancellation.
+     */
+open func downloadRepoArchiveWithProgress(repo: String, revision: String?, destination: String, format: ArchiveFormat, progress: ProgressCallback?, cancellation: CancellationToken?)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_repo_archive_with_progress(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterString.lower(destination),
+        FfiConverterTypeArchiveFormat_lower(format),
+        FfiConverterOptionCallbackInterfaceProgressCallback.lower(progress),
+        FfiConverterOptionTypeCancellationToken.lower(cancellation),$0
+    )
+})
+}
+    
+    /**
+     * Downloads a file into the client's cache and returns its local path.
+     */
+open func downloadToCache(repo: String, path: String, revision: String?)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_download_to_cache(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Downloads a file into the client's cache without blocking the caller.
+     */
+open func downloadToCacheAsync(repo: String, path: String, revision: String?)async throws  -> String  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_swift_xet_rust_fn_method_xetclient_download_to_cache_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(repo),FfiConverterString.lower(path),FfiConverterOptionString.lower(revision)
+                )
+            },
+            pollFunc: ffi_swift_xet_rust_rust_future_poll_rust_buffer,
+            completeFunc: ffi_swift_xet_rust_rust_future_complete_rust_buffer,
+            freeFunc: ffi_swift_xet_rust_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterString.lift,
+            errorHandler: FfiConverterTypeXetError_lift
+        )
+}
+    
+    /**
+     * Estimates the bytes uploading local files would transfer after deduplication, without uploading them.
+     */
+open func estimateUpload(repo: String, localPaths: [String], revision: String?)throws  -> UploadEstimate  {
+    return try  FfiConverterTypeUploadEstimate_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_estimate_upload(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterSequenceString.lower(localPaths),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Returns the requests whose destinations don't already hold their file, checking only local files and records.
+     */
+open func filterMissing(requests: [FileDownloadRequest]) -> [FileDownloadRequest]  {
+    return try!  FfiConverterSequenceTypeFileDownloadRequest.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_filter_missing(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFileDownloadRequest.lower(requests),$0
+    )
+})
+}
+    
+    /**
+     * Returns statistics about the local Xet cache.
+     */
+open func getCacheStats()throws  -> CacheStats  {
+    return try  FfiConverterTypeCacheStats_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_cache_stats(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the local path of a cached file without touching the network, or `null` if it isn't cached.
+     */
+open func getCachedPath(repo: String, path: String, revision: String?)throws  -> String?  {
+    return try  FfiConverterOptionString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_cached_path(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Retrieves a JWT token for accessing the Content-Addressable Storage (CAS) system.
+     */
+open func getCasJwt(repo: String, revision: String?, isUpload: Bool)throws  -> CasJwtInfo  {
+    return try  FfiConverterTypeCasJwtInfo_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_cas_jwt(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterBool.lower(isUpload),$0
+    )
+})
+}
+    
+    /**
+     * Returns how far the Hub's clock is ahead of the device's in seconds, as estimated from the most recent Hub response.
+     */
+open func getClockSkewSecs() -> Int64  {
+    return try!  FfiConverterInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_clock_skew_secs(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Retrieves file information from a pointer file in the repository.
+     */
+open func getFileInfo(repo: String, path: String, revision: String?)throws  -> XetFileInfo?  {
+    return try  FfiConverterOptionTypeXetFileInfo.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_file_info(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Returns metadata for each of the given paths that exists, resolved in as few requests as possible.
+     */
+open func getPathsInfo(repo: String, paths: [String], revision: String?)throws  -> [FileMetadata]  {
+    return try  FfiConverterSequenceTypeFileMetadata.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_paths_info(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterSequenceString.lower(paths),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Returns the Hub API rate limit reported by the most recent response that included one.
+     */
+open func getRateLimitStatus() -> RateLimitStatus?  {
+    return try!  FfiConverterOptionTypeRateLimitStatus.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_rate_limit_status(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Parses a repository identifier and returns structured repository information.
+     */
+open func getRepoInfo(repo: String)throws  -> RepoInfo  {
+    return try  FfiConverterTypeRepoInfo_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_repo_info(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),$0
+    )
+})
+}
+    
+    /**
+     * Returns the size of the files in a repository, or of those matching `patterns`.
+     */
+open func getRepoSize(repo: String, revision: String?, patterns: [String])throws  -> RepoSize  {
+    return try  FfiConverterTypeRepoSize_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_repo_size(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterSequenceString.lower(patterns),$0
+    )
+})
+}
+    
+    /**
+     * Returns the bytes and time of the transfers the client has completed, by label.
+     */
+open func getUsageReport() -> [LabelUsage]  {
+    return try!  FfiConverterSequenceTypeLabelUsage.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_usage_report(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the Xet hash, token refresh route, and CAS endpoint of a file, or `null` if it isn't stored with Xet.
+     */
+open func getXetPointerDetails(repo: String, path: String, revision: String?)throws  -> XetPointerDetails?  {
+    return try  FfiConverterOptionTypeXetPointerDetails.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_get_xet_pointer_details(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Lists the revisions of a file available in the client's cache, most recently cached first.
+     */
+open func listCachedRevisions(repo: String, path: String)throws  -> [CachedRevision]  {
+    return try  FfiConverterSequenceTypeCachedRevision.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_list_cached_revisions(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Lists every file under a directory, reporting whether any directory was too large to list.
+     */
+open func listFilesRecursive(repo: String, path: String, revision: String?)throws  -> TreeListing  {
+    return try  FfiConverterTypeTreeListing_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_list_files_recursive(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Lists the models in the managed library with the state of their local files.
+     */
+open func listManaged()throws  -> [ManagedModel]  {
+    return try  FfiConverterSequenceTypeManagedModel.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_list_managed(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Moves a file to a new path on a branch of the repository, or the default revision if `null`, in a new commit, returning the commit ID.
+     */
+open func moveFile(repo: String, fromPath: String, toPath: String, revision: String?, commitMessage: String)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_move_file(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(fromPath),
+        FfiConverterString.lower(toPath),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterString.lower(commitMessage),$0
+    )
+})
+}
+    
+    /**
+     * Opens a file for reading in chunks, without writing it to disk.
+     */
+open func openFileStream(repo: String, path: String, revision: String?)throws  -> FileStream  {
+    return try  FfiConverterTypeFileStream_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_open_file_stream(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Stops downloads at their next checkpoint, keeping their resume state, and returns whether they all stopped within the timeout.
+     */
+open func prepareForTermination(timeoutMs: UInt64) -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_prepare_for_termination(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(timeoutMs),$0
+    )
+})
+}
+    
+    /**
+     * Asks the Hub whether each file would be stored in Xet or inline in git, and whether it's ignored, before uploading any.
+     */
+open func preupload(repo: String, files: [PreuploadFile], revision: String?)throws  -> [PreuploadResult]  {
+    return try  FfiConverterSequenceTypePreuploadResult.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_preupload(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterSequenceTypePreuploadFile.lower(files),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+    /**
+     * Evicts the least recently modified files in a cache area until it fits a size limit.
+     */
+open func pruneCacheArea(area: CacheArea, maxSizeBytes: UInt64)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_prune_cache_area(self.uniffiClonePointer(),
+        FfiConverterTypeCacheArea_lower(area),
+        FfiConverterUInt64.lower(maxSizeBytes),$0
+    )
+})
+}
+    
+    /**
+     * Uploads the files staged in a session and commits them to a branch in a single commit, returning the commit ID.
+     */
+open func publish(session: UploadSession, repo: String, branch: String, message: String)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_publish(self.uniffiClonePointer(),
+        FfiConverterTypeUploadSession_lower(session),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(branch),
+        FfiConverterString.lower(message),$0
+    )
+})
+}
+    
+    /**
+     * Starts logging the client's requests, with signatures and credentials redacted, to a file of JSON lines for reporting network problems.
+     */
+open func recordSession(path: String)throws   {try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_record_session(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+}
+}
+    
+    /**
+     * Finishes or rolls back commits interrupted by a previous process.
+     */
+open func recoverPendingCommits()throws  -> [RecoveredCommit]  {
+    return try  FfiConverterSequenceTypeRecoveredCommit.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_recover_pending_commits(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Registers a model to be downloaded and kept up to date by `update_all_managed`.
+     */
+open func registerManagedModel(repo: String, revisionPolicy: ManagedRevisionPolicy, patterns: [String])throws  -> ManagedModel  {
+    return try  FfiConverterTypeManagedModel_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_register_managed_model(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterTypeManagedRevisionPolicy_lower(revisionPolicy),
+        FfiConverterSequenceString.lower(patterns),$0
+    )
+})
+}
+    
+    /**
+     * Clears the usage report, such as at the start of a new billing period.
+     */
+open func resetUsageReport()  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_reset_usage_report(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Sets the policy consulted before each file download begins.
+     */
+open func setDownloadPolicy(policy: DownloadPolicy?)  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_set_download_policy(self.uniffiClonePointer(),
+        FfiConverterOptionCallbackInterfaceDownloadPolicy.lower(policy),$0
+    )
+}
+}
+    
+    /**
+     * Sets the listener that receives client events.
+     */
+open func setEventListener(listener: XetEventListener?)  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_set_event_listener(self.uniffiClonePointer(),
+        FfiConverterOptionCallbackInterfaceXetEventListener.lower(listener),$0
+    )
+}
+}
+    
+    /**
+     * Sets the most bytes per second the client downloads across all transfers.
+     */
+open func setMaxDownloadBytesPerSecond(bytesPerSecond: UInt64?)throws   {try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_set_max_download_bytes_per_second(self.uniffiClonePointer(),
+        FfiConverterOptionUInt64.lower(bytesPerSecond),$0
+    )
+}
+}
+    
+    /**
+     * Sets whether the client writes diagnostics to standard error.
+     */
+open func setQuiet(quiet: Bool)  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_set_quiet(self.uniffiClonePointer(),
+        FfiConverterBool.lower(quiet),$0
+    )
+}
+}
+    
+    /**
+     * Sets the label transfers are attributed to in the usage report, or `null` to leave them unlabeled.
+     */
+open func setUsageLabel(label: String?)  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_set_usage_label(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(label),$0
+    )
+}
+}
+    
+    /**
+     * Downloads every file in a repository into a local directory, returning the local paths.
+     */
+open func snapshotDownload(repo: String, revision: String?, destinationDir: String, allowPatterns: [String], ignorePatterns: [String], destinationTemplate: String?)throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_snapshot_download(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterString.lower(destinationDir),
+        FfiConverterSequenceString.lower(allowPatterns),
+        FfiConverterSequenceString.lower(ignorePatterns),
+        FfiConverterOptionString.lower(destinationTemplate),$0
+    )
+})
+}
+    
+    /**
+     * Downloads every file in a repository into a local directory without blocking the caller.
+     */
+open func snapshotDownloadAsync(repo: String, revision: String?, destinationDir: String, allowPatterns: [String], ignorePatterns: [String], destinationTemplate: String?)async throws  -> [String]  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_swift_xet_rust_fn_method_xetclient_snapshot_download_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(repo),FfiConverterOptionString.lower(revision),FfiConverterString.lower(destinationDir),FfiConverterSequenceString.lower(allowPatterns),FfiConverterSequenceString.lower(ignorePatterns),FfiConverterOptionString.lower(destinationTemplate)
+                )
+            },
+            pollFunc: ffi_swift_xet_rust_rust_future_poll_rust_buffer,
+            completeFunc: ffi_swift_xet_rust_rust_future_complete_rust_buffer,
+            freeFunc: ffi_swift_xet_rust_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceString.lift,
+            errorHandler: FfiConverterTypeXetError_lift
+        )
+}
+    
+    /**
+     * Stages a local file to be published at a path in a repository, replacing any file staged at that path.
+     */
+open func stageFile(session: UploadSession, localPath: String, repoPath: String)throws   {try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_stage_file(self.uniffiClonePointer(),
+        FfiConverterTypeUploadSession_lower(session),
+        FfiConverterString.lower(localPath),
+        FfiConverterString.lower(repoPath),$0
+    )
+}
+}
+    
+    /**
+     * Starts periodic cache maintenance in the background.
+     */
+open func startCacheMaintenance(policy: CacheMaintenancePolicy)throws   {try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_start_cache_maintenance(self.uniffiClonePointer(),
+        FfiConverterTypeCacheMaintenancePolicy_lower(policy),$0
+    )
+}
+}
+    
+    /**
+     * Stops background cache maintenance.
+     */
+open func stopCacheMaintenance()  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_stop_cache_maintenance(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Stops the recording started with `record_session`, if any.
+     */
+open func stopRecording()  {try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_stop_recording(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Returns an iterator that lists a directory one page at a time.
+     */
+open func treeIterator(repo: String, path: String, revision: String?, recursive: Bool)throws  -> TreeIterator  {
+    return try  FfiConverterTypeTreeIterator_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_tree_iterator(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(path),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterBool.lower(recursive),$0
+    )
+})
+}
+    
+    /**
+     * Downloads every managed model that is missing, incomplete, or out of date.
+     */
+open func updateAllManaged()throws  -> [ManagedModel]  {
+    return try  FfiConverterSequenceTypeManagedModel.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_update_all_managed(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Uploads content from memory and commits it to the repository as one file, returning the commit ID.
+     */
+open func uploadBytes(repo: String, data: Data, pathInRepo: String, commitMessage: String)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_upload_bytes(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterData.lower(data),
+        FfiConverterString.lower(pathInRepo),
+        FfiConverterString.lower(commitMessage),$0
+    )
+})
+}
+    
+    /**
+     * Uploads a file from disk and commits it to a branch of the repository, or the default revision if `null`, returning the commit ID.
+     */
+open func uploadFile(repo: String, localPath: String, pathInRepo: String, revision: String?, commitMessage: String)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_upload_file(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(localPath),
+        FfiConverterString.lower(pathInRepo),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterString.lower(commitMessage),$0
+    )
+})
+}
+    
+    /**
+     * Uploads a file from disk and commits it, reporting chunking and hashing progress separately from the bytes sent after deduplication.
+     */
+open func uploadFileWithProgress(repo: String, localPath: String, pathInRepo: String, revision: String?, commitMessage: String, progress: ProgressCallback?)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_upload_file_with_progress(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(localPath),
+        FfiConverterString.lower(pathInRepo),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterString.lower(commitMessage),
+        FfiConverterOptionCallbackInterfaceProgressCallback.lower(progress),$0
+    )
+})
+}
+    
+    /**
+     * Uploads many local files in one session, so content shared between them is uploaded once, and commits them all in a single commit.
+     */
+open func uploadFiles(repo: String, requests: [FileUploadRequest], revision: String?, commitMessage: String)throws  -> BatchUploadResult  {
+    return try  FfiConverterTypeBatchUploadResult_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_upload_files(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterSequenceTypeFileUploadRequest.lower(requests),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterString.lower(commitMessage),$0
+    )
+})
+}
+    
+    /**
+     * Uploads many local files in one session and commits them all, reporting progress across the batch.
+     */
+open func uploadFilesWithProgress(repo: String, requests: [FileUploadRequest], revision: String?, commitMessage: String, progress: ProgressCallback?)throws  -> BatchUploadResult  {
+    return try  FfiConverterTypeBatchUploadResult_lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_upload_files_with_progress(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterSequenceTypeFileUploadRequest.lower(requests),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterString.lower(commitMessage),
+        FfiConverterOptionCallbackInterfaceProgressCallback.lower(progress),$0
+    )
+})
+}
+    
+    /**
+     * Uploads the files in a local directory, selected by glob patterns, under a path in the repository, committing at most 100 files per commit.
+     */
+open func uploadFolder(repo: String, localDir: String, pathInRepo: String, revision: String?, allowPatterns: [String], ignorePatterns: [String], commitMessage: String)throws  -> [BatchUploadResult]  {
+    return try  FfiConverterSequenceTypeBatchUploadResult.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_method_xetclient_upload_folder(self.uniffiClonePointer(),
+        FfiConverterString.lower(repo),
+        FfiConverterString.lower(localDir),
+        FfiConverterString.lower(pathInRepo),
+        FfiConverterOptionString.lower(revision),
+        FfiConverterSequenceString.lower(allowPatterns),
+        FfiConverterSequenceString.lower(ignorePatterns),
+        FfiConverterString.lower(commitMessage),$0
+    )
+})
+}
+    
+    /**
+     * Returns the version of the Xet client library.
+     */
+open func version() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetclient_version(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeXetClient: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = XetClient
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> XetClient {
+        return XetClient(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: XetClient) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> XetClient {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: XetClient, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetClient_lift(_ pointer: UnsafeMutableRawPointer) throws -> XetClient {
+    return try FfiConverterTypeXetClient.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetClient_lower(_ value: XetClient) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeXetClient.lower(value)
+}
+
+
+
+
+
+
+/**
+ * Information about a file stored in a Xet repository.
+ *
+ * This type contains the hash and size of a file, which are used to
+ * identify and download files from Xet's content-addressable storage system.
+ */
+public protocol XetFileInfoProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the size of the file in bytes.
+     */
+    func fileSize()  -> UInt64
+    
+    /**
+     * Returns the content hash of the file.
+     */
+    func hash()  -> String
+    
+}
+/**
+ * Information about a file stored in a Xet repository.
+ *
+ * This type contains the hash and size of a file, which are used to
+ * identify and download files from Xet's content-addressable storage system.
+ */
+open class XetFileInfo: XetFileInfoProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_xetfileinfo(self.pointer, $0) }
+    }
+    /**
+     * Creates a new file info instance.
+     */
+public convenience init(hash: String, fileSize: UInt64) {
+    let pointer =
+        try! rustCall() {
+    uniffi_swift_xet_rust_fn_constructor_xetfileinfo_new(
+        FfiConverterString.lower(hash),
+        FfiConverterUInt64.lower(fileSize),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_xetfileinfo(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the size of the file in bytes.
+     */
+open func fileSize() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetfileinfo_file_size(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the content hash of the file.
+     */
+open func hash() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetfileinfo_hash(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeXetFileInfo: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = XetFileInfo
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> XetFileInfo {
+        return XetFileInfo(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: XetFileInfo) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> XetFileInfo {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: XetFileInfo, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetFileInfo_lift(_ pointer: UnsafeMutableRawPointer) throws -> XetFileInfo {
+    return try FfiConverterTypeXetFileInfo.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetFileInfo_lower(_ value: XetFileInfo) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeXetFileInfo.lower(value)
+}
+
+
+
+
+
+
+/**
+ * Where the content of a Xet-backed file lives, and how to get access to it.
+ */
+public protocol XetPointerDetailsProtocol: AnyObject, Sendable {
+    
+    /**
+     * Returns the URL of the CAS server that stores the file's content.
+     */
+    func casEndpoint()  -> String
+    
+    /**
+     * Returns the Hub URL that issues CAS read tokens for the file.
+     */
+    func refreshRoute()  -> String
+    
+    /**
+     * Returns the size of the file in bytes.
+     */
+    func size()  -> UInt64
+    
+    /**
+     * Returns the Xet hash of the file's content.
+     */
+    func xetHash()  -> String
+    
+}
+/**
+ * Where the content of a Xet-backed file lives, and how to get access to it.
+ */
+open class XetPointerDetails: XetPointerDetailsProtocol, @unchecked Sendable {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_swift_xet_rust_fn_clone_xetpointerdetails(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_swift_xet_rust_fn_free_xetpointerdetails(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the URL of the CAS server that stores the file's content.
+     */
+open func casEndpoint() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetpointerdetails_cas_endpoint(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the Hub URL that issues CAS read tokens for the file.
+     */
+open func refreshRoute() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetpointerdetails_refresh_route(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the size of the file in bytes.
+     */
+open func size() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetpointerdetails_size(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the Xet hash of the file's content.
+     */
+open func xetHash() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_method_xetpointerdetails_xet_hash(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeXetPointerDetails: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = XetPointerDetails
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> XetPointerDetails {
+        return XetPointerDetails(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: XetPointerDetails) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> XetPointerDetails {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: XetPointerDetails, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetPointerDetails_lift(_ pointer: UnsafeMutableRawPointer) throws -> XetPointerDetails {
+    return try FfiConverterTypeXetPointerDetails.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetPointerDetails_lower(_ value: XetPointerDetails) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeXetPointerDetails.lower(value)
+}
+
+
+
+
+/**
+ * A policy for periodic, low-priority maintenance of the local Xet cache.
+ *
+ * Areas without a size limit are never pruned.
+ */
+public struct CacheMaintenancePolicy {
+    /**
+     * The number of seconds between maintenance passes.
+     */
+    public var intervalSecs: UInt64
+    /**
+     * The maximum size of the chunk cache in bytes, if limited.
+     */
+    public var maxChunkCacheBytes: UInt64?
+    /**
+     * The maximum size of the shard cache in bytes, if limited.
+     */
+    public var maxShardCacheBytes: UInt64?
+    /**
+     * The maximum size of cached metadata in bytes, if limited.
+     */
+    public var maxMetadataBytes: UInt64?
+    /**
+     * The maximum size of staged upload data in bytes, if limited.
+     */
+    public var maxStagedUploadBytes: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The number of seconds between maintenance passes.
+         */intervalSecs: UInt64 = UInt64(300), 
+        /**
+         * The maximum size of the chunk cache in bytes, if limited.
+         */maxChunkCacheBytes: UInt64? = nil, 
+        /**
+         * The maximum size of the shard cache in bytes, if limited.
+         */maxShardCacheBytes: UInt64? = nil, 
+        /**
+         * The maximum size of cached metadata in bytes, if limited.
+         */maxMetadataBytes: UInt64? = nil, 
+        /**
+         * The maximum size of staged upload data in bytes, if limited.
+         */maxStagedUploadBytes: UInt64? = nil) {
+        self.intervalSecs = intervalSecs
+        self.maxChunkCacheBytes = maxChunkCacheBytes
+        self.maxShardCacheBytes = maxShardCacheBytes
+        self.maxMetadataBytes = maxMetadataBytes
+        self.maxStagedUploadBytes = maxStagedUploadBytes
+    }
+}
+
+#if compiler(>=6)
+extension CacheMaintenancePolicy: Sendable {}
+#endif
+
+
+extension CacheMaintenancePolicy: Equatable, Hashable {
+    public static func ==(lhs: CacheMaintenancePolicy, rhs: CacheMaintenancePolicy) -> Bool {
+        if lhs.intervalSecs != rhs.intervalSecs {
+            return false
+        }
+        if lhs.maxChunkCacheBytes != rhs.maxChunkCacheBytes {
+            return false
+        }
+        if lhs.maxShardCacheBytes != rhs.maxShardCacheBytes {
+            return false
+        }
+        if lhs.maxMetadataBytes != rhs.maxMetadataBytes {
+            return false
+        }
+        if lhs.maxStagedUploadBytes != rhs.maxStagedUploadBytes {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(intervalSecs)
+        hasher.combine(maxChunkCacheBytes)
+        hasher.combine(maxShardCacheBytes)
+        hasher.combine(maxMetadataBytes)
+        hasher.combine(maxStagedUploadBytes)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCacheMaintenancePolicy: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CacheMaintenancePolicy {
+        return
+            try CacheMaintenancePolicy(
+                intervalSecs: FfiConverterUInt64.read(from: &buf), 
+                maxChunkCacheBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                maxShardCacheBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                maxMetadataBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                maxStagedUploadBytes: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CacheMaintenancePolicy, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.intervalSecs, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxChunkCacheBytes, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxShardCacheBytes, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxMetadataBytes, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxStagedUploadBytes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCacheMaintenancePolicy_lift(_ buf: RustBuffer) throws -> CacheMaintenancePolicy {
+    return try FfiConverterTypeCacheMaintenancePolicy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCacheMaintenancePolicy_lower(_ value: CacheMaintenancePolicy) -> RustBuffer {
+    return FfiConverterTypeCacheMaintenancePolicy.lower(value)
+}
+
+
+/**
+ * A directory that holds an area of the cache instead of the default cache directory.
+ */
+public struct CacheRoot {
+    /**
+     * The area to relocate: `Metadata`, `ManagedModels`, or `DownloadedFiles`.
+     */
+    public var area: CacheArea
+    /**
+     * The absolute path of the directory, such as an App Group container.
+     */
+    public var path: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The area to relocate: `Metadata`, `ManagedModels`, or `DownloadedFiles`.
+         */area: CacheArea, 
+        /**
+         * The absolute path of the directory, such as an App Group container.
+         */path: String) {
+        self.area = area
+        self.path = path
+    }
+}
+
+#if compiler(>=6)
+extension CacheRoot: Sendable {}
+#endif
+
+
+extension CacheRoot: Equatable, Hashable {
+    public static func ==(lhs: CacheRoot, rhs: CacheRoot) -> Bool {
+        if lhs.area != rhs.area {
+            return false
+        }
+        if lhs.path != rhs.path {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(area)
+        hasher.combine(path)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCacheRoot: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CacheRoot {
+        return
+            try CacheRoot(
+                area: FfiConverterTypeCacheArea.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CacheRoot, into buf: inout [UInt8]) {
+        FfiConverterTypeCacheArea.write(value.area, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCacheRoot_lift(_ buf: RustBuffer) throws -> CacheRoot {
+    return try FfiConverterTypeCacheRoot.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCacheRoot_lower(_ value: CacheRoot) -> RustBuffer {
+    return FfiConverterTypeCacheRoot.lower(value)
+}
+
+
+/**
+ * The measured round-trip time to a CAS endpoint.
+ */
+public struct CasEndpointLatency {
+    /**
+     * The URL of the endpoint.
+     */
+    public var endpoint: String
+    /**
+     * The round-trip time in milliseconds, or `null` if the endpoint couldn't be reached.
+     */
+    public var rttMs: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The URL of the endpoint.
+         */endpoint: String, 
+        /**
+         * The round-trip time in milliseconds, or `null` if the endpoint couldn't be reached.
+         */rttMs: UInt64?) {
+        self.endpoint = endpoint
+        self.rttMs = rttMs
+    }
+}
+
+#if compiler(>=6)
+extension CasEndpointLatency: Sendable {}
+#endif
+
+
+extension CasEndpointLatency: Equatable, Hashable {
+    public static func ==(lhs: CasEndpointLatency, rhs: CasEndpointLatency) -> Bool {
+        if lhs.endpoint != rhs.endpoint {
+            return false
+        }
+        if lhs.rttMs != rhs.rttMs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(endpoint)
+        hasher.combine(rttMs)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCasEndpointLatency: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CasEndpointLatency {
+        return
+            try CasEndpointLatency(
+                endpoint: FfiConverterString.read(from: &buf), 
+                rttMs: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CasEndpointLatency, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.endpoint, into: &buf)
+        FfiConverterOptionUInt64.write(value.rttMs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCasEndpointLatency_lift(_ buf: RustBuffer) throws -> CasEndpointLatency {
+    return try FfiConverterTypeCasEndpointLatency.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCasEndpointLatency_lower(_ value: CasEndpointLatency) -> RustBuffer {
+    return FfiConverterTypeCasEndpointLatency.lower(value)
+}
+
+
+/**
+ * The claims of a CAS access token, as decoded by `CasJwtInfo.claims`, which doesn't verify its signature.
+ */
+public struct CasTokenClaims {
+    /**
+     * What the token grants, such as `"read"` or `"write"`, if it says.
+     */
+    public var scope: String?
+    /**
+     * The repository the token was issued for, if it says.
+     */
+    public var repo: String?
+    /**
+     * The Unix time the token expires at, if it says.
+     */
+    public var exp: UInt64?
+    /**
+     * The Unix time the token was issued at, if it says.
+     */
+    public var issuedAt: UInt64?
+    /**
+     * The whole payload as JSON, including claims not listed above.
+     */
+    public var payload: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * What the token grants, such as `"read"` or `"write"`, if it says.
+         */scope: String?, 
+        /**
+         * The repository the token was issued for, if it says.
+         */repo: String?, 
+        /**
+         * The Unix time the token expires at, if it says.
+         */exp: UInt64?, 
+        /**
+         * The Unix time the token was issued at, if it says.
+         */issuedAt: UInt64?, 
+        /**
+         * The whole payload as JSON, including claims not listed above.
+         */payload: String) {
+        self.scope = scope
+        self.repo = repo
+        self.exp = exp
+        self.issuedAt = issuedAt
+        self.payload = payload
+    }
+}
+
+#if compiler(>=6)
+extension CasTokenClaims: Sendable {}
+#endif
+
+
+extension CasTokenClaims: Equatable, Hashable {
+    public static func ==(lhs: CasTokenClaims, rhs: CasTokenClaims) -> Bool {
+        if lhs.scope != rhs.scope {
+            return false
+        }
+        if lhs.repo != rhs.repo {
+            return false
+        }
+        if lhs.exp != rhs.exp {
+            return false
+        }
+        if lhs.issuedAt != rhs.issuedAt {
+            return false
+        }
+        if lhs.payload != rhs.payload {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(scope)
+        hasher.combine(repo)
+        hasher.combine(exp)
+        hasher.combine(issuedAt)
+        hasher.combine(payload)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCasTokenClaims: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CasTokenClaims {
+        return
+            try CasTokenClaims(
+                scope: FfiConverterOptionString.read(from: &buf), 
+                repo: FfiConverterOptionString.read(from: &buf), 
+                exp: FfiConverterOptionUInt64.read(from: &buf), 
+                issuedAt: FfiConverterOptionUInt64.read(from: &buf), 
+                payload: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CasTokenClaims, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.scope, into: &buf)
+        FfiConverterOptionString.write(value.repo, into: &buf)
+        FfiConverterOptionUInt64.write(value.exp, into: &buf)
+        FfiConverterOptionUInt64.write(value.issuedAt, into: &buf)
+        FfiConverterString.write(value.payload, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCasTokenClaims_lift(_ buf: RustBuffer) throws -> CasTokenClaims {
+    return try FfiConverterTypeCasTokenClaims.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCasTokenClaims_lower(_ value: CasTokenClaims) -> RustBuffer {
+    return FfiConverterTypeCasTokenClaims.lower(value)
+}
+
+
+/**
+ * Certificates or public keys that connections to matching hosts must present.
+ */
+public struct CertificatePin {
+    /**
+     * A glob pattern matched against the host name, such as `"huggingface.co"` or `"*.hf.co"`.
+     */
+    public var hostPattern: String
+    /**
+     * Hex-encoded SHA-256 digests of certificates or their public keys, any one of which must appear in the host's chain.
+     */
+    public var sha256Hashes: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * A glob pattern matched against the host name, such as `"huggingface.co"` or `"*.hf.co"`.
+         */hostPattern: String, 
+        /**
+         * Hex-encoded SHA-256 digests of certificates or their public keys, any one of which must appear in the host's chain.
+         */sha256Hashes: [String]) {
+        self.hostPattern = hostPattern
+        self.sha256Hashes = sha256Hashes
+    }
+}
+
+#if compiler(>=6)
+extension CertificatePin: Sendable {}
+#endif
+
+
+extension CertificatePin: Equatable, Hashable {
+    public static func ==(lhs: CertificatePin, rhs: CertificatePin) -> Bool {
+        if lhs.hostPattern != rhs.hostPattern {
+            return false
+        }
+        if lhs.sha256Hashes != rhs.sha256Hashes {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(hostPattern)
+        hasher.combine(sha256Hashes)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCertificatePin: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CertificatePin {
+        return
+            try CertificatePin(
+                hostPattern: FfiConverterString.read(from: &buf), 
+                sha256Hashes: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CertificatePin, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.hostPattern, into: &buf)
+        FfiConverterSequenceString.write(value.sha256Hashes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCertificatePin_lift(_ buf: RustBuffer) throws -> CertificatePin {
+    return try FfiConverterTypeCertificatePin.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCertificatePin_lower(_ value: CertificatePin) -> RustBuffer {
+    return FfiConverterTypeCertificatePin.lower(value)
+}
+
+
+/**
+ * The content-defined chunking an upload split its files with.
+ */
+public struct ChunkingParameters {
+    /**
+     * The average chunk size in bytes.
+     */
+    public var targetChunkSize: UInt64
+    /**
+     * The smallest chunk size in bytes, except for the last chunk of a file.
+     */
+    public var minChunkSize: UInt64
+    /**
+     * The largest chunk size in bytes.
+     */
+    public var maxChunkSize: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The average chunk size in bytes.
+         */targetChunkSize: UInt64, 
+        /**
+         * The smallest chunk size in bytes, except for the last chunk of a file.
+         */minChunkSize: UInt64, 
+        /**
+         * The largest chunk size in bytes.
+         */maxChunkSize: UInt64) {
+        self.targetChunkSize = targetChunkSize
+        self.minChunkSize = minChunkSize
+        self.maxChunkSize = maxChunkSize
+    }
+}
+
+#if compiler(>=6)
+extension ChunkingParameters: Sendable {}
+#endif
+
+
+extension ChunkingParameters: Equatable, Hashable {
+    public static func ==(lhs: ChunkingParameters, rhs: ChunkingParameters) -> Bool {
+        if lhs.targetChunkSize != rhs.targetChunkSize {
+            return false
+        }
+        if lhs.minChunkSize != rhs.minChunkSize {
+            return false
+        }
+        if lhs.maxChunkSize != rhs.maxChunkSize {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(targetChunkSize)
+        hasher.combine(minChunkSize)
+        hasher.combine(maxChunkSize)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeChunkingParameters: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ChunkingParameters {
+        return
+            try ChunkingParameters(
+                targetChunkSize: FfiConverterUInt64.read(from: &buf), 
+                minChunkSize: FfiConverterUInt64.read(from: &buf), 
+                maxChunkSize: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ChunkingParameters, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.targetChunkSize, into: &buf)
+        FfiConverterUInt64.write(value.minChunkSize, into: &buf)
+        FfiConverterUInt64.write(value.maxChunkSize, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChunkingParameters_lift(_ buf: RustBuffer) throws -> ChunkingParameters {
+    return try FfiConverterTypeChunkingParameters.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChunkingParameters_lower(_ value: ChunkingParameters) -> RustBuffer {
+    return FfiConverterTypeChunkingParameters.lower(value)
+}
+
+
+/**
+ * Sends requests for matching repositories to an endpoint other than the Hub.
+ */
+public struct EndpointOverride {
+    /**
+     * A glob pattern matched against the repository's full name, such as `"my-org/*"`.
+     */
+    public var repoPattern: String
+    /**
+     * The base URL of the endpoint, such as `"https://hub.internal.example.com"`.
+     */
+    public var endpoint: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * A glob pattern matched against the repository's full name, such as `"my-org/*"`.
+         */repoPattern: String, 
+        /**
+         * The base URL of the endpoint, such as `"https://hub.internal.example.com"`.
+         */endpoint: String) {
+        self.repoPattern = repoPattern
+        self.endpoint = endpoint
+    }
+}
+
+#if compiler(>=6)
+extension EndpointOverride: Sendable {}
+#endif
+
+
+extension EndpointOverride: Equatable, Hashable {
+    public static func ==(lhs: EndpointOverride, rhs: EndpointOverride) -> Bool {
+        if lhs.repoPattern != rhs.repoPattern {
+            return false
+        }
+        if lhs.endpoint != rhs.endpoint {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(repoPattern)
+        hasher.combine(endpoint)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeEndpointOverride: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EndpointOverride {
+        return
+            try EndpointOverride(
+                repoPattern: FfiConverterString.read(from: &buf), 
+                endpoint: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: EndpointOverride, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.repoPattern, into: &buf)
+        FfiConverterString.write(value.endpoint, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEndpointOverride_lift(_ buf: RustBuffer) throws -> EndpointOverride {
+    return try FfiConverterTypeEndpointOverride.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEndpointOverride_lower(_ value: EndpointOverride) -> RustBuffer {
+    return FfiConverterTypeEndpointOverride.lower(value)
+}
+
+
+/**
+ * A local file to upload with `upload_files`, and the path it's committed to.
+ */
+public struct FileUploadRequest {
+    /**
+     * The path of the file on disk.
+     */
+    public var localPath: String
+    /**
+     * The path the file is committed to, relative to the repository root.
+     */
+    public var pathInRepo: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The path of the file on disk.
+         */localPath: String, 
+        /**
+         * The path the file is committed to, relative to the repository root.
+         */pathInRepo: String) {
+        self.localPath = localPath
+        self.pathInRepo = pathInRepo
+    }
+}
+
+#if compiler(>=6)
+extension FileUploadRequest: Sendable {}
+#endif
+
+
+extension FileUploadRequest: Equatable, Hashable {
+    public static func ==(lhs: FileUploadRequest, rhs: FileUploadRequest) -> Bool {
+        if lhs.localPath != rhs.localPath {
+            return false
+        }
+        if lhs.pathInRepo != rhs.pathInRepo {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(localPath)
+        hasher.combine(pathInRepo)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileUploadRequest: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileUploadRequest {
+        return
+            try FileUploadRequest(
+                localPath: FfiConverterString.read(from: &buf), 
+                pathInRepo: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FileUploadRequest, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.localPath, into: &buf)
+        FfiConverterString.write(value.pathInRepo, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileUploadRequest_lift(_ buf: RustBuffer) throws -> FileUploadRequest {
+    return try FfiConverterTypeFileUploadRequest.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileUploadRequest_lower(_ value: FileUploadRequest) -> RustBuffer {
+    return FfiConverterTypeFileUploadRequest.lower(value)
+}
+
+
+/**
+ * The outcome of one file in a batch upload.
+ */
+public struct FileUploadResult {
+    /**
+     * The path the file was committed to.
+     */
+    public var pathInRepo: String
+    /**
+     * The SHA-256 digest of the file's content.
+     */
+    public var sha256: String
+    /**
+     * The size of the file in bytes.
+     */
+    public var size: UInt64
+    /**
+     * The bytes of the file not uploaded because an earlier file in the batch has the same content.
+     */
+    public var bytesDeduplicated: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The path the file was committed to.
+         */pathInRepo: String, 
+        /**
+         * The SHA-256 digest of the file's content.
+         */sha256: String, 
+        /**
+         * The size of the file in bytes.
+         */size: UInt64, 
+        /**
+         * The bytes of the file not uploaded because an earlier file in the batch has the same content.
+         */bytesDeduplicated: UInt64) {
+        self.pathInRepo = pathInRepo
+        self.sha256 = sha256
+        self.size = size
+        self.bytesDeduplicated = bytesDeduplicated
+    }
+}
+
+#if compiler(>=6)
+extension FileUploadResult: Sendable {}
+#endif
+
+
+extension FileUploadResult: Equatable, Hashable {
+    public static func ==(lhs: FileUploadResult, rhs: FileUploadResult) -> Bool {
+        if lhs.pathInRepo != rhs.pathInRepo {
+            return false
+        }
+        if lhs.sha256 != rhs.sha256 {
+            return false
+        }
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.bytesDeduplicated != rhs.bytesDeduplicated {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(pathInRepo)
+        hasher.combine(sha256)
+        hasher.combine(size)
+        hasher.combine(bytesDeduplicated)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileUploadResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileUploadResult {
+        return
+            try FileUploadResult(
+                pathInRepo: FfiConverterString.read(from: &buf), 
+                sha256: FfiConverterString.read(from: &buf), 
+                size: FfiConverterUInt64.read(from: &buf), 
+                bytesDeduplicated: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FileUploadResult, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.pathInRepo, into: &buf)
+        FfiConverterString.write(value.sha256, into: &buf)
+        FfiConverterUInt64.write(value.size, into: &buf)
+        FfiConverterUInt64.write(value.bytesDeduplicated, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileUploadResult_lift(_ buf: RustBuffer) throws -> FileUploadResult {
+    return try FfiConverterTypeFileUploadResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileUploadResult_lower(_ value: FileUploadResult) -> RustBuffer {
+    return FfiConverterTypeFileUploadResult.lower(value)
+}
+
+
+/**
+ * The bytes a client exchanged with one host; counts are approximate, taking responses at their declared length and uploads before deduplication.
+ */
+public struct HostUsage {
+    /**
+     * The host, with its port if it isn't the default.
+     */
+    public var host: String
+    /**
+     * The kind of service the host provides.
+     */
+    public var kind: EndpointKind
+    /**
+     * The bytes of request bodies and uploaded content sent to the host.
+     */
+    public var bytesSent: UInt64
+    /**
+     * The bytes of response bodies received from the host.
+     */
+    public var bytesReceived: UInt64
+    /**
+     * The number of requests the host answered.
+     */
+    public var requestCount: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The host, with its port if it isn't the default.
+         */host: String, 
+        /**
+         * The kind of service the host provides.
+         */kind: EndpointKind, 
+        /**
+         * The bytes of request bodies and uploaded content sent to the host.
+         */bytesSent: UInt64, 
+        /**
+         * The bytes of response bodies received from the host.
+         */bytesReceived: UInt64, 
+        /**
+         * The number of requests the host answered.
+         */requestCount: UInt64) {
+        self.host = host
+        self.kind = kind
+        self.bytesSent = bytesSent
+        self.bytesReceived = bytesReceived
+        self.requestCount = requestCount
+    }
+}
+
+#if compiler(>=6)
+extension HostUsage: Sendable {}
+#endif
+
+
+extension HostUsage: Equatable, Hashable {
+    public static func ==(lhs: HostUsage, rhs: HostUsage) -> Bool {
+        if lhs.host != rhs.host {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.bytesSent != rhs.bytesSent {
+            return false
+        }
+        if lhs.bytesReceived != rhs.bytesReceived {
+            return false
+        }
+        if lhs.requestCount != rhs.requestCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(host)
+        hasher.combine(kind)
+        hasher.combine(bytesSent)
+        hasher.combine(bytesReceived)
+        hasher.combine(requestCount)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHostUsage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HostUsage {
+        return
+            try HostUsage(
+                host: FfiConverterString.read(from: &buf), 
+                kind: FfiConverterTypeEndpointKind.read(from: &buf), 
+                bytesSent: FfiConverterUInt64.read(from: &buf), 
+                bytesReceived: FfiConverterUInt64.read(from: &buf), 
+                requestCount: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HostUsage, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.host, into: &buf)
+        FfiConverterTypeEndpointKind.write(value.kind, into: &buf)
+        FfiConverterUInt64.write(value.bytesSent, into: &buf)
+        FfiConverterUInt64.write(value.bytesReceived, into: &buf)
+        FfiConverterUInt64.write(value.requestCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHostUsage_lift(_ buf: RustBuffer) throws -> HostUsage {
+    return try FfiConverterTypeHostUsage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHostUsage_lower(_ value: HostUsage) -> RustBuffer {
+    return FfiConverterTypeHostUsage.lower(value)
+}
+
+
+/**
+ * The transfers a client completed for one label, as reported by `get_usage_report`.
+ */
+public struct LabelUsage {
+    /**
+     * The label the transfers were attributed to, or `null` for unlabeled transfers.
+     */
+    public var label: String?
+    /**
+     * The bytes of file content downloaded.
+     */
+    public var bytesDownloaded: UInt64
+    /**
+     * The bytes of file content uploaded, before deduplication.
+     */
+    public var bytesUploaded: UInt64
+    /**
+     * The number of completed downloads and uploads.
+     */
+    public var transferCount: UInt64
+    /**
+     * The combined wall-clock time of the transfers in milliseconds.
+     */
+    public var elapsedMs: UInt64
+    /**
+     * The bytes exchanged with each host the client sent requests to, sorted by host.
+     */
+    public var hosts: [HostUsage]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The label the transfers were attributed to, or `null` for unlabeled transfers.
+         */label: String?, 
+        /**
+         * The bytes of file content downloaded.
+         */bytesDownloaded: UInt64, 
+        /**
+         * The bytes of file content uploaded, before deduplication.
+         */bytesUploaded: UInt64, 
+        /**
+         * The number of completed downloads and uploads.
+         */transferCount: UInt64, 
+        /**
+         * The combined wall-clock time of the transfers in milliseconds.
+         */elapsedMs: UInt64, 
+        /**
+         * The bytes exchanged with each host the client sent requests to, sorted by host.
+         */hosts: [HostUsage]) {
+        self.label = label
+        self.bytesDownloaded = bytesDownloaded
+        self.bytesUploaded = bytesUploaded
+        self.transferCount = transferCount
+        self.elapsedMs = elapsedMs
+        self.hosts = hosts
+    }
+}
+
+#if compiler(>=6)
+extension LabelUsage: Sendable {}
+#endif
+
+
+extension LabelUsage: Equatable, Hashable {
+    public static func ==(lhs: LabelUsage, rhs: LabelUsage) -> Bool {
+        if lhs.label != rhs.label {
+            return false
+        }
+        if lhs.bytesDownloaded != rhs.bytesDownloaded {
+            return false
+        }
+        if lhs.bytesUploaded != rhs.bytesUploaded {
+            return false
+        }
+        if lhs.transferCount != rhs.transferCount {
+            return false
+        }
+        if lhs.elapsedMs != rhs.elapsedMs {
+            return false
+        }
+        if lhs.hosts != rhs.hosts {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(label)
+        hasher.combine(bytesDownloaded)
+        hasher.combine(bytesUploaded)
+        hasher.combine(transferCount)
+        hasher.combine(elapsedMs)
+        hasher.combine(hosts)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLabelUsage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LabelUsage {
+        return
+            try LabelUsage(
+                label: FfiConverterOptionString.read(from: &buf), 
+                bytesDownloaded: FfiConverterUInt64.read(from: &buf), 
+                bytesUploaded: FfiConverterUInt64.read(from: &buf), 
+                transferCount: FfiConverterUInt64.read(from: &buf), 
+                elapsedMs: FfiConverterUInt64.read(from: &buf), 
+                hosts: FfiConverterSequenceTypeHostUsage.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LabelUsage, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.label, into: &buf)
+        FfiConverterUInt64.write(value.bytesDownloaded, into: &buf)
+        FfiConverterUInt64.write(value.bytesUploaded, into: &buf)
+        FfiConverterUInt64.write(value.transferCount, into: &buf)
+        FfiConverterUInt64.write(value.elapsedMs, into: &buf)
+        FfiConverterSequenceTypeHostUsage.write(value.hosts, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLabelUsage_lift(_ buf: RustBuffer) throws -> LabelUsage {
+    return try FfiConverterTypeLabelUsage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLabelUsage_lower(_ value: LabelUsage) -> RustBuffer {
+    return FfiConverterTypeLabelUsage.lower(value)
+}
+
+
+/**
+ * A file to ask the Hub about with `preupload` before uploading it.
+ */
+public struct PreuploadFile {
+    /**
+     * The path the file would be committed to, relative to the repository root.
+     */
+    public var pathInRepo: String
+    /**
+     * The size of the file in bytes.
+     */
+    public var size: UInt64
+    /**
+     * The file's leading bytes, of which the Hub inspects the first 512.
+     */
+    public var sample: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The path the file would be committed to, relative to the repository root.
+         */pathInRepo: String, 
+        /**
+         * The size of the file in bytes.
+         */size: UInt64, 
+        /**
+         * The file's leading bytes, of which the Hub inspects the first 512.
+         */sample: Data) {
+        self.pathInRepo = pathInRepo
+        self.size = size
+        self.sample = sample
+    }
+}
+
+#if compiler(>=6)
+extension PreuploadFile: Sendable {}
+#endif
+
+
+extension PreuploadFile: Equatable, Hashable {
+    public static func ==(lhs: PreuploadFile, rhs: PreuploadFile) -> Bool {
+        if lhs.pathInRepo != rhs.pathInRepo {
+            return false
+        }
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.sample != rhs.sample {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(pathInRepo)
+        hasher.combine(size)
+        hasher.combine(sample)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePreuploadFile: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PreuploadFile {
+        return
+            try PreuploadFile(
+                pathInRepo: FfiConverterString.read(from: &buf), 
+                size: FfiConverterUInt64.read(from: &buf), 
+                sample: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PreuploadFile, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.pathInRepo, into: &buf)
+        FfiConverterUInt64.write(value.size, into: &buf)
+        FfiConverterData.write(value.sample, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePreuploadFile_lift(_ buf: RustBuffer) throws -> PreuploadFile {
+    return try FfiConverterTypePreuploadFile.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePreuploadFile_lower(_ value: PreuploadFile) -> RustBuffer {
+    return FfiConverterTypePreuploadFile.lower(value)
+}
+
+
+/**
+ * The Hub's verdict on a file passed to `preupload`.
+ */
+public struct PreuploadResult {
+    /**
+     * The path the file would be committed to.
+     */
+    public var pathInRepo: String
+    /**
+     * How the file's content would be stored.
+     */
+    public var uploadMode: UploadMode
+    /**
+     * Whether the repository's `.gitignore` excludes the file.
+     */
+    public var shouldIgnore: Bool
+    /**
+     * The object ID of the file currently at the path, if there is one.
+     */
+    public var remoteOid: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The path the file would be committed to.
+         */pathInRepo: String, 
+        /**
+         * How the file's content would be stored.
+         */uploadMode: UploadMode, 
+        /**
+         * Whether the repository's `.gitignore` excludes the file.
+         */shouldIgnore: Bool, 
+        /**
+         * The object ID of the file currently at the path, if there is one.
+         */remoteOid: String?) {
+        self.pathInRepo = pathInRepo
+        self.uploadMode = uploadMode
+        self.shouldIgnore = shouldIgnore
+        self.remoteOid = remoteOid
+    }
+}
+
+#if compiler(>=6)
+extension PreuploadResult: Sendable {}
+#endif
+
+
+extension PreuploadResult: Equatable, Hashable {
+    public static func ==(lhs: PreuploadResult, rhs: PreuploadResult) -> Bool {
+        if lhs.pathInRepo != rhs.pathInRepo {
+            return false
+        }
+        if lhs.uploadMode != rhs.uploadMode {
+            return false
+        }
+        if lhs.shouldIgnore != rhs.shouldIgnore {
+            return false
+        }
+        if lhs.remoteOid != rhs.remoteOid {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(pathInRepo)
+        hasher.combine(uploadMode)
+        hasher.combine(shouldIgnore)
+        hasher.combine(remoteOid)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePreuploadResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PreuploadResult {
+        return
+            try PreuploadResult(
+                pathInRepo: FfiConverterString.read(from: &buf), 
+                uploadMode: FfiConverterTypeUploadMode.read(from: &buf), 
+                shouldIgnore: FfiConverterBool.read(from: &buf), 
+                remoteOid: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PreuploadResult, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.pathInRepo, into: &buf)
+        FfiConverterTypeUploadMode.write(value.uploadMode, into: &buf)
+        FfiConverterBool.write(value.shouldIgnore, into: &buf)
+        FfiConverterOptionString.write(value.remoteOid, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePreuploadResult_lift(_ buf: RustBuffer) throws -> PreuploadResult {
+    return try FfiConverterTypePreuploadResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePreuploadResult_lower(_ value: PreuploadResult) -> RustBuffer {
+    return FfiConverterTypePreuploadResult.lower(value)
+}
+
+
+/**
+ * How the client retries metadata fetches, CAS token refreshes, and downloads.
+ */
+public struct RetryPolicy {
+    /**
+     * The most times an operation is tried, including the first attempt.
+     */
+    public var maxAttempts: UInt32
+    /**
+     * The delay before the first retry in milliseconds.
+     */
+    public var baseDelayMs: UInt64
+    /**
+     * The longest delay between attempts in milliseconds.
+     */
+    public var maxDelayMs: UInt64
+    /**
+     * Whether the delay doubles after each retry, up to `max_delay_ms`.
+     */
+    public var exponentialBackoff: Bool
+    /**
+     * Whether each delay is shortened by a random amount of up to half.
+     */
+    public var jitter: Bool
+    /**
+     * The classes of failure that are retried.
+     */
+    public var retryOn: [RetryClass]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The most times an operation is tried, including the first attempt.
+         */maxAttempts: UInt32 = UInt32(3), 
+        /**
+         * The delay before the first retry in milliseconds.
+         */baseDelayMs: UInt64 = UInt64(500), 
+        /**
+         * The longest delay between attempts in milliseconds.
+         */maxDelayMs: UInt64 = UInt64(10000), 
+        /**
+         * Whether the delay doubles after each retry, up to `max_delay_ms`.
+         */exponentialBackoff: Bool = true, 
+        /**
+         * Whether each delay is shortened by a random amount of up to half.
+         */jitter: Bool = true, 
+        /**
+         * The classes of failure that are retried.
+         */retryOn: [RetryClass]) {
+        self.maxAttempts = maxAttempts
+        self.baseDelayMs = baseDelayMs
+        self.maxDelayMs = maxDelayMs
+        self.exponentialBackoff = exponentialBackoff
+        self.jitter = jitter
+        self.retryOn = retryOn
+    }
+}
+
+#if compiler(>=6)
+extension RetryPolicy: Sendable {}
+#endif
+
+
+extension RetryPolicy: Equatable, Hashable {
+    public static func ==(lhs: RetryPolicy, rhs: RetryPolicy) -> Bool {
+        if lhs.maxAttempts != rhs.maxAttempts {
+            return false
+        }
+        if lhs.baseDelayMs != rhs.baseDelayMs {
+            return false
+        }
+        if lhs.maxDelayMs != rhs.maxDelayMs {
+            return false
+        }
+        if lhs.exponentialBackoff != rhs.exponentialBackoff {
+            return false
+        }
+        if lhs.jitter != rhs.jitter {
+            return false
+        }
+        if lhs.retryOn != rhs.retryOn {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(maxAttempts)
+        hasher.combine(baseDelayMs)
+        hasher.combine(maxDelayMs)
+        hasher.combine(exponentialBackoff)
+        hasher.combine(jitter)
+        hasher.combine(retryOn)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRetryPolicy: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RetryPolicy {
+        return
+            try RetryPolicy(
+                maxAttempts: FfiConverterUInt32.read(from: &buf), 
+                baseDelayMs: FfiConverterUInt64.read(from: &buf), 
+                maxDelayMs: FfiConverterUInt64.read(from: &buf), 
+                exponentialBackoff: FfiConverterBool.read(from: &buf), 
+                jitter: FfiConverterBool.read(from: &buf), 
+                retryOn: FfiConverterSequenceTypeRetryClass.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RetryPolicy, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.maxAttempts, into: &buf)
+        FfiConverterUInt64.write(value.baseDelayMs, into: &buf)
+        FfiConverterUInt64.write(value.maxDelayMs, into: &buf)
+        FfiConverterBool.write(value.exponentialBackoff, into: &buf)
+        FfiConverterBool.write(value.jitter, into: &buf)
+        FfiConverterSequenceTypeRetryClass.write(value.retryOn, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRetryPolicy_lift(_ buf: RustBuffer) throws -> RetryPolicy {
+    return try FfiConverterTypeRetryPolicy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRetryPolicy_lower(_ value: RetryPolicy) -> RustBuffer {
+    return FfiConverterTypeRetryPolicy.lower(value)
+}
+
+
+/**
+ * A network fault the client simulates on a schedule, for testing how an app copes with slow and failing connections.
+ */
+public struct SimulatedFault {
+    /**
+     * A substring of the URLs the fault applies to, such as `"/resolve/"`, or `null` for every request.
+     */
+    public var urlPattern: String?
+    /**
+     * What goes wrong.
+     */
+    public var kind: FaultKind
+    /**
+     * The number of matching requests to let through before the fault starts.
+     */
+    public var skip: UInt32
+    /**
+     * The number of matching requests the fault applies to, or `null` for every one after those skipped.
+     */
+    public var count: UInt32?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * A substring of the URLs the fault applies to, such as `"/resolve/"`, or `null` for every request.
+         */urlPattern: String? = nil, 
+        /**
+         * What goes wrong.
+         */kind: FaultKind, 
+        /**
+         * The number of matching requests to let through before the fault starts.
+         */skip: UInt32 = UInt32(0), 
+        /**
+         * The number of matching requests the fault applies to, or `null` for every one after those skipped.
+         */count: UInt32? = nil) {
+        self.urlPattern = urlPattern
+        self.kind = kind
+        self.skip = skip
+        self.count = count
+    }
+}
+
+#if compiler(>=6)
+extension SimulatedFault: Sendable {}
+#endif
+
+
+extension SimulatedFault: Equatable, Hashable {
+    public static func ==(lhs: SimulatedFault, rhs: SimulatedFault) -> Bool {
+        if lhs.urlPattern != rhs.urlPattern {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.skip != rhs.skip {
+            return false
+        }
+        if lhs.count != rhs.count {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(urlPattern)
+        hasher.combine(kind)
+        hasher.combine(skip)
+        hasher.combine(count)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSimulatedFault: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SimulatedFault {
+        return
+            try SimulatedFault(
+                urlPattern: FfiConverterOptionString.read(from: &buf), 
+                kind: FfiConverterTypeFaultKind.read(from: &buf), 
+                skip: FfiConverterUInt32.read(from: &buf), 
+                count: FfiConverterOptionUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SimulatedFault, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.urlPattern, into: &buf)
+        FfiConverterTypeFaultKind.write(value.kind, into: &buf)
+        FfiConverterUInt32.write(value.skip, into: &buf)
+        FfiConverterOptionUInt32.write(value.count, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSimulatedFault_lift(_ buf: RustBuffer) throws -> SimulatedFault {
+    return try FfiConverterTypeSimulatedFault.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSimulatedFault_lower(_ value: SimulatedFault) -> RustBuffer {
+    return FfiConverterTypeSimulatedFault.lower(value)
+}
+
+
+/**
+ * What uploading a set of files would transfer, as estimated by `estimate_upload`.
+ */
+public struct UploadEstimate {
+    /**
+     * The bytes in the files.
+     */
+    public var totalBytes: UInt64
+    /**
+     * The bytes of chunks CAS doesn't have, which an upload would send before compression.
+     */
+    public var newBytes: UInt64
+    /**
+     * The bytes of chunks an upload wouldn't send, because they repeat within the files or are already in CAS.
+     */
+    public var deduplicatedBytes: UInt64
+    /**
+     * Of the deduplicated bytes, those found by asking CAS about chunks uploaded from elsewhere.
+     */
+    public var globallyDeduplicatedBytes: UInt64
+    /**
+     * The number of chunks the files split into.
+     */
+    public var totalChunks: UInt64
+    /**
+     * The number of chunks an upload would send.
+     */
+    public var newChunks: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The bytes in the files.
+         */totalBytes: UInt64, 
+        /**
+         * The bytes of chunks CAS doesn't have, which an upload would send before compression.
+         */newBytes: UInt64, 
+        /**
+         * The bytes of chunks an upload wouldn't send, because they repeat within the files or are already in CAS.
+         */deduplicatedBytes: UInt64, 
+        /**
+         * Of the deduplicated bytes, those found by asking CAS about chunks uploaded from elsewhere.
+         */globallyDeduplicatedBytes: UInt64, 
+        /**
+         * The number of chunks the files split into.
+         */totalChunks: UInt64, 
+        /**
+         * The number of chunks an upload would send.
+         */newChunks: UInt64) {
+        self.totalBytes = totalBytes
+        self.newBytes = newBytes
+        self.deduplicatedBytes = deduplicatedBytes
+        self.globallyDeduplicatedBytes = globallyDeduplicatedBytes
+        self.totalChunks = totalChunks
+        self.newChunks = newChunks
+    }
+}
+
+#if compiler(>=6)
+extension UploadEstimate: Sendable {}
+#endif
+
+
+extension UploadEstimate: Equatable, Hashable {
+    public static func ==(lhs: UploadEstimate, rhs: UploadEstimate) -> Bool {
+        if lhs.totalBytes != rhs.totalBytes {
+            return false
+        }
+        if lhs.newBytes != rhs.newBytes {
+            return false
+        }
+        if lhs.deduplicatedBytes != rhs.deduplicatedBytes {
+            return false
+        }
+        if lhs.globallyDeduplicatedBytes != rhs.globallyDeduplicatedBytes {
+            return false
+        }
+        if lhs.totalChunks != rhs.totalChunks {
+            return false
+        }
+        if lhs.newChunks != rhs.newChunks {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(totalBytes)
+        hasher.combine(newBytes)
+        hasher.combine(deduplicatedBytes)
+        hasher.combine(globallyDeduplicatedBytes)
+        hasher.combine(totalChunks)
+        hasher.combine(newChunks)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUploadEstimate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UploadEstimate {
+        return
+            try UploadEstimate(
+                totalBytes: FfiConverterUInt64.read(from: &buf), 
+                newBytes: FfiConverterUInt64.read(from: &buf), 
+                deduplicatedBytes: FfiConverterUInt64.read(from: &buf), 
+                globallyDeduplicatedBytes: FfiConverterUInt64.read(from: &buf), 
+                totalChunks: FfiConverterUInt64.read(from: &buf), 
+                newChunks: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UploadEstimate, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.totalBytes, into: &buf)
+        FfiConverterUInt64.write(value.newBytes, into: &buf)
+        FfiConverterUInt64.write(value.deduplicatedBytes, into: &buf)
+        FfiConverterUInt64.write(value.globallyDeduplicatedBytes, into: &buf)
+        FfiConverterUInt64.write(value.totalChunks, into: &buf)
+        FfiConverterUInt64.write(value.newChunks, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUploadEstimate_lift(_ buf: RustBuffer) throws -> UploadEstimate {
+    return try FfiConverterTypeUploadEstimate.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUploadEstimate_lower(_ value: UploadEstimate) -> RustBuffer {
+    return FfiConverterTypeUploadEstimate.lower(value)
+}
+
+
+/**
+ * Configuration for a `XetClient`.
+ *
+ * All fields are optional. Guardrail limits protect users from accidentally
+ * downloading a repository that would fill their device.
+ */
+public struct XetClientConfig {
+    /**
+     * A Hugging Face authentication token, if any.
+     */
+    public var token: String?
+    /**
+     * The maximum size of a single downloaded file in bytes, if limited.
+     */
+    public var maxFileSizeBytes: UInt64?
+    /**
+     * The maximum combined size of a multi-file download in bytes, if limited.
+     */
+    public var maxSnapshotSizeBytes: UInt64?
+    /**
+     * The repository owners the client may access, or `null` to allow any owner.
+     */
+    public var allowedRepoOwners: [String]?
+    /**
+     * How to treat files flagged as unsafe by the Hub's security scanner.
+     */
+    public var unsafeFilePolicy: UnsafeFilePolicy
+    /**
+     * Whether to check downloaded files against the SHA-256 reported by the Hub.
+     */
+    public var verifyDownloads: Bool
+    /**
+     * The revision used when a method is called without one, or `null` for `"main"`.
+     */
+    public var defaultRevision: String?
+    /**
+     * The number of chunks fetched ahead of a sequential reader, or `null` for the default of 4.
+     */
+    public var readAheadChunks: UInt32?
+    /**
+     * The kind of runtime the client creates.
+     */
+    public var runtimeFlavor: RuntimeFlavor
+    /**
+     * The number of worker threads for a multi-threaded runtime, or `null` for one per core.
+     */
+    public var workerThreads: UInt32?
+    /**
+     * How multi-file downloads handle files that would share a destination.
+     */
+    public var collisionStrategy: DestinationCollisionStrategy
+    /**
+     * The Unicode normalization applied to destination paths.
+     */
+    public var pathNormalization: PathNormalization
+    /**
+     * Which commit multi-file downloads use if their revision moves partway through.
+     */
+    public var revisionPinning: RevisionPinning
+    /**
+     * What multi-file downloads leave on disk when one of their files fails.
+     */
+    public var failurePolicy: FailurePolicy
+    /**
+     * The CAS endpoint to use when the Hub offers several, or `null` to use the fastest.
+     */
+    public var preferredCasEndpoint: String?
+    /**
+     * The maximum number of redirects followed by a request, or `null` for the default of 10.
+     */
+    public var maxRedirects: UInt32?
+    /**
+     * Seconds without a client call after which the runtime and pooled connections are shed, or `null` to keep them.
+     */
+    public var idleTimeoutSecs: UInt64?
+    /**
+     * Whether to silence the diagnostics the client writes to standard error.
+     */
+    public var quiet: Bool
+    /**
+     * The number of files a multi-file download fetches at once, or `null` for the default of 4.
+     */
+    public var maxConcurrentDownloads: UInt32?
+    /**
+     * How snapshot and folder downloads treat zero-byte and placeholder files.
+     */
+    public var placeholderFilePolicy: PlaceholderFilePolicy
+    /**
+     * Whether downloads fall back on the last successful resolution of a file when the Hub is unreachable.
+     */
+    public var serveStaleMetadata: Bool
+    /**
+     * The most seconds the CDN URL a resolve request redirects to is reused for, or `null` to send every content request through the Hub.
+     */
+    public var resolveRedirectCacheSecs: UInt64?
+    /**
+     * The time CAS token expiry and signed URL expiry are judged against.
+     */
+    public var clockSource: ClockSource
+    /**
+     * The seconds the device's clock may differ from the Hub's before it's corrected, or `null` for the default of 30.
+     */
+    public var clockSkewToleranceSecs: UInt64?
+    /**
+     * The most bytes per second the client downloads across all transfers, if limited.
+     */
+    public var maxDownloadBytesPerSecond: UInt64?
+    /**
+     * How failed requests are retried, or `null` for 3 attempts with jittered exponential backoff on every retry class.
+     */
+    public var retryPolicy: RetryPolicy?
+    /**
+     * A preset supplying values for the download options left unset, or `null` to use each option's own default.
+     */
+    public var downloadPreset: DownloadPreset?
+    /**
+     * Endpoints that serve matching repositories instead of the Hub, with the first matching override applying.
+     */
+    public var endpointOverrides: [EndpointOverride]?
+    /**
+     * Hub endpoints tried in order when one can't be reached, such as a regional mirror followed by the Hub, or `null` for the Hub alone.
+     */
+    public var hubEndpoints: [String]?
+    /**
+     * PEM-encoded certificates to trust as roots in addition to the system's, such as an enterprise proxy's CA.
+     */
+    public var additionalRootCertificates: [String]?
+    /**
+     * Certificates or public keys that matching hosts must present, checked before any request is sent.
+     */
+    public var certificatePins: [CertificatePin]?
+    /**
+     * Directories that hold areas of the cache instead of the default cache directory, such as an App Group container shared with app extensions, or `null` to keep every area there.
+     */
+    public var cacheRoots: [CacheRoot]?
+    /**
+     * Seconds a connection may take to establish, or `null` for no limit.
+     */
+    public var connectTimeoutSecs: UInt64?
+    /**
+     * Seconds a response may go without receiving any data, or `null` for no limit.
+     */
+    public var readTimeoutSecs: UInt64?
+    /**
+     * Seconds any single request, or transfer of a file's content, may take before failing with a timeout error, or `null` for no limit.
+     */
+    public var operationTimeoutSecs: UInt64?
+    /**
+     * Whether multi-file downloads skip files in repositories the client can't read, rather than failing.
+     */
+    public var skipInaccessibleRepos: Bool
+    /**
+     * The number of range requests all of the client's downloads make at once, shared between them, or `null` for the default of 16.
+     */
+    public var maxConcurrentRangeGets: UInt32?
+    /**
+     * The largest response the client reads into memory in bytes, covering `get_file_content`, tree listings, and other Hub API responses, or `null` for the default of 512 MiB.
+     */
+    public var maxResponseBodyBytes: UInt64?
+    /**
+     * Network faults to simulate on the client's own requests, in the order they're checked. Transfers the Xet data crate makes over its own connections aren't affected.
+     */
+    public var simulatedFaults: [SimulatedFault]?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * A Hugging Face authentication token, if any.
+         */token: String? = nil, 
+        /**
+         * The maximum size of a single downloaded file in bytes, if limited.
+         */maxFileSizeBytes: UInt64? = nil, 
+        /**
+         * The maximum combined size of a multi-file download in bytes, if limited.
+         */maxSnapshotSizeBytes: UInt64? = nil, 
+        /**
+         * The repository owners the client may access, or `null` to allow any owner.
+         */allowedRepoOwners: [String]? = nil, 
+        /**
+         * How to treat files flagged as unsafe by the Hub's security scanner.
+         */unsafeFilePolicy: UnsafeFilePolicy = .allow, 
+        /**
+         * Whether to check downloaded files against the SHA-256 reported by the Hub.
+         */verifyDownloads: Bool = false, 
+        /**
+         * The revision used when a method is called without one, or `null` for `"main"`.
+         */defaultRevision: String? = nil, 
+        /**
+         * The number of chunks fetched ahead of a sequential reader, or `null` for the default of 4.
+         */readAheadChunks: UInt32? = nil, 
+        /**
+         * The kind of runtime the client creates.
+         */runtimeFlavor: RuntimeFlavor = .multiThread, 
+        /**
+         * The number of worker threads for a multi-threaded runtime, or `null` for one per core.
+         */workerThreads: UInt32? = nil, 
+        /**
+         * How multi-file downloads handle files that would share a destination.
+         */collisionStrategy: DestinationCollisionStrategy = .fail, 
+        /**
+         * The Unicode normalization applied to destination paths.
+         */pathNormalization: PathNormalization = .nfc, 
+        /**
+         * Which commit multi-file downloads use if their revision moves partway through.
+         */revisionPinning: RevisionPinning = .pinned, 
+        /**
+         * What multi-file downloads leave on disk when one of their files fails.
+         */failurePolicy: FailurePolicy = .keepCompleted, 
+        /**
+         * The CAS endpoint to use when the Hub offers several, or `null` to use the fastest.
+         */preferredCasEndpoint: String? = nil, 
+        /**
+         * The maximum number of redirects followed by a request, or `null` for the default of 10.
+         */maxRedirects: UInt32? = nil, 
+        /**
+         * Seconds without a client call after which the runtime and pooled connections are shed, or `null` to keep them.
+         */idleTimeoutSecs: UInt64? = nil, 
+        /**
+         * Whether to silence the diagnostics the client writes to standard error.
+         */quiet: Bool = false, 
+        /**
+         * The number of files a multi-file download fetches at once, or `null` for the default of 4.
+         */maxConcurrentDownloads: UInt32? = nil, 
+        /**
+         * How snapshot and folder downloads treat zero-byte and placeholder files.
+         */placeholderFilePolicy: PlaceholderFilePolicy = .fetch, 
+        /**
+         * Whether downloads fall back on the last successful resolution of a file when the Hub is unreachable.
+         */serveStaleMetadata: Bool = false, 
+        /**
+         * The most seconds the CDN URL a resolve request redirects to is reused for, or `null` to send every content request through the Hub.
+         */resolveRedirectCacheSecs: UInt64? = nil, 
+        /**
+         * The time CAS token expiry and signed URL expiry are judged against.
+         */clockSource: ClockSource = .hub, 
+        /**
+         * The seconds the device's clock may differ from the Hub's before it's corrected, or `null` for the default of 30.
+         */clockSkewToleranceSecs: UInt64? = nil, 
+        /**
+         * The most bytes per second the client downloads across all transfers, if limited.
+         */maxDownloadBytesPerSecond: UInt64? = nil, 
+        /**
+         * How failed requests are retried, or `null` for 3 attempts with jittered exponential backoff on every retry class.
+         */retryPolicy: RetryPolicy? = nil, 
+        /**
+         * A preset supplying values for the download options left unset, or `null` to use each option's own default.
+         */downloadPreset: DownloadPreset? = nil, 
+        /**
+         * Endpoints that serve matching repositories instead of the Hub, with the first matching override applying.
+         */endpointOverrides: [EndpointOverride]? = nil, 
+        /**
+         * Hub endpoints tried in order when one can't be reached, such as a regional mirror followed by the Hub, or `null` for the Hub alone.
+         */hubEndpoints: [String]? = nil, 
+        /**
+         * PEM-encoded certificates to trust as roots in addition to the system's, such as an enterprise proxy's CA.
+         */additionalRootCertificates: [String]? = nil, 
+        /**
+         * Certificates or public keys that matching hosts must present, checked before any request is sent.
+         */certificatePins: [CertificatePin]? = nil, 
+        /**
+         * Directories that hold areas of the cache instead of the default cache directory, such as an App Group container shared with app extensions, or `null` to keep every area there.
+         */cacheRoots: [CacheRoot]? = nil, 
+        /**
+         * Seconds a connection may take to establish, or `null` for no limit.
+         */connectTimeoutSecs: UInt64? = nil, 
+        /**
+         * Seconds a response may go without receiving any data, or `null` for no limit.
+         */readTimeoutSecs: UInt64? = nil, 
+        /**
+         * Seconds any single request, or transfer of a file's content, may take before failing with a timeout error, or `null` for no limit.
+         */operationTimeoutSecs: UInt64? = nil, 
+        /**
+         * Whether multi-file downloads skip files in repositories the client can't read, rather than failing.
+         */skipInaccessibleRepos: Bool = false, 
+        /**
+         * The number of range requests all of the client's downloads make at once, shared between them, or `null` for the default of 16.
+         */maxConcurrentRangeGets: UInt32? = nil, 
+        /**
+         * The largest response the client reads into memory in bytes, covering `get_file_content`, tree listings, and other Hub API responses, or `null` for the default of 512 MiB.
+         */maxResponseBodyBytes: UInt64? = nil, 
+        /**
+         * Network faults to simulate on the client's own requests, in the order they're checked. Transfers the Xet data crate makes over its own connections aren't affected.
+         */simulatedFaults: [SimulatedFault]? = nil) {
+        self.token = token
+        self.maxFileSizeBytes = maxFileSizeBytes
+        self.maxSnapshotSizeBytes = maxSnapshotSizeBytes
+        self.allowedRepoOwners = allowedRepoOwners
+        self.unsafeFilePolicy = unsafeFilePolicy
+        self.verifyDownloads = verifyDownloads
+        self.defaultRevision = defaultRevision
+        self.readAheadChunks = readAheadChunks
+        self.runtimeFlavor = runtimeFlavor
+        self.workerThreads = workerThreads
+        self.collisionStrategy = collisionStrategy
+        self.pathNormalization = pathNormalization
+        self.revisionPinning = revisionPinning
+        self.failurePolicy = failurePolicy
+        self.preferredCasEndpoint = preferredCasEndpoint
+        self.maxRedirects = maxRedirects
+        self.idleTimeoutSecs = idleTimeoutSecs
+        self.quiet = quiet
+        self.maxConcurrentDownloads = maxConcurrentDownloads
+        self.placeholderFilePolicy = placeholderFilePolicy
+        self.serveStaleMetadata = serveStaleMetadata
+        self.resolveRedirectCacheSecs = resolveRedirectCacheSecs
+        self.clockSource = clockSource
+        self.clockSkewToleranceSecs = clockSkewToleranceSecs
+        self.maxDownloadBytesPerSecond = maxDownloadBytesPerSecond
+        self.retryPolicy = retryPolicy
+        self.downloadPreset = downloadPreset
+        self.endpointOverrides = endpointOverrides
+        self.hubEndpoints = hubEndpoints
+        self.additionalRootCertificates = additionalRootCertificates
+        self.certificatePins = certificatePins
+        self.cacheRoots = cacheRoots
+        self.connectTimeoutSecs = connectTimeoutSecs
+        self.readTimeoutSecs = readTimeoutSecs
+        self.operationTimeoutSecs = operationTimeoutSecs
+        self.skipInaccessibleRepos = skipInaccessibleRepos
+        self.maxConcurrentRangeGets = maxConcurrentRangeGets
+        self.maxResponseBodyBytes = maxResponseBodyBytes
+        self.simulatedFaults = simulatedFaults
+    }
+}
+
+#if compiler(>=6)
+extension XetClientConfig: Sendable {}
+#endif
+
+
+extension XetClientConfig: Equatable, Hashable {
+    public static func ==(lhs: XetClientConfig, rhs: XetClientConfig) -> Bool {
+        if lhs.token != rhs.token {
+            return false
+        }
+        if lhs.maxFileSizeBytes != rhs.maxFileSizeBytes {
+            return false
+        }
+        if lhs.maxSnapshotSizeBytes != rhs.maxSnapshotSizeBytes {
+            return false
+        }
+        if lhs.allowedRepoOwners != rhs.allowedRepoOwners {
+            return false
+        }
+        if lhs.unsafeFilePolicy != rhs.unsafeFilePolicy {
+            return false
+        }
+        if lhs.verifyDownloads != rhs.verifyDownloads {
+            return false
+        }
+        if lhs.defaultRevision != rhs.defaultRevision {
+            return false
+        }
+        if lhs.readAheadChunks != rhs.readAheadChunks {
+            return false
+        }
+        if lhs.runtimeFlavor != rhs.runtimeFlavor {
+            return false
+        }
+        if lhs.workerThreads != rhs.workerThreads {
+            return false
+        }
+        if lhs.collisionStrategy != rhs.collisionStrategy {
+            return false
+        }
+        if lhs.pathNormalization != rhs.pathNormalization {
+            return false
+        }
+        if lhs.revisionPinning != rhs.revisionPinning {
+            return false
+        }
+        if lhs.failurePolicy != rhs.failurePolicy {
+            return false
+        }
+        if lhs.preferredCasEndpoint != rhs.preferredCasEndpoint {
+            return false
+        }
+        if lhs.maxRedirects != rhs.maxRedirects {
+            return false
+        }
+        if lhs.idleTimeoutSecs != rhs.idleTimeoutSecs {
+            return false
+        }
+        if lhs.quiet != rhs.quiet {
+            return false
+        }
+        if lhs.maxConcurrentDownloads != rhs.maxConcurrentDownloads {
+            return false
+        }
+        if lhs.placeholderFilePolicy != rhs.placeholderFilePolicy {
+            return false
+        }
+        if lhs.serveStaleMetadata != rhs.serveStaleMetadata {
+            return false
+        }
+        if lhs.resolveRedirectCacheSecs != rhs.resolveRedirectCacheSecs {
+            return false
+        }
+        if lhs.clockSource != rhs.clockSource {
+            return false
+        }
+        if lhs.clockSkewToleranceSecs != rhs.clockSkewToleranceSecs {
+            return false
+        }
+        if lhs.maxDownloadBytesPerSecond != rhs.maxDownloadBytesPerSecond {
+            return false
+        }
+        if lhs.retryPolicy != rhs.retryPolicy {
+            return false
+        }
+        if lhs.downloadPreset != rhs.downloadPreset {
+            return false
+        }
+        if lhs.endpointOverrides != rhs.endpointOverrides {
+            return false
+        }
+        if lhs.hubEndpoints != rhs.hubEndpoints {
+            return false
+        }
+        if lhs.additionalRootCertificates != rhs.additionalRootCertificates {
+            return false
+        }
+        if lhs.certificatePins != rhs.certificatePins {
+            return false
+        }
+        if lhs.cacheRoots != rhs.cacheRoots {
+            return false
+        }
+        if lhs.connectTimeoutSecs != rhs.connectTimeoutSecs {
+            return false
+        }
+        if lhs.readTimeoutSecs != rhs.readTimeoutSecs {
+            return false
+        }
+        if lhs.operationTimeoutSecs != rhs.operationTimeoutSecs {
+            return false
+        }
+        if lhs.skipInaccessibleRepos != rhs.skipInaccessibleRepos {
+            return false
+        }
+        if lhs.maxConcurrentRangeGets != rhs.maxConcurrentRangeGets {
+            return false
+        }
+        if lhs.maxResponseBodyBytes != rhs.maxResponseBodyBytes {
+            return false
+        }
+        if lhs.simulatedFaults != rhs.simulatedFaults {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(token)
+        hasher.combine(maxFileSizeBytes)
+        hasher.combine(maxSnapshotSizeBytes)
+        hasher.combine(allowedRepoOwners)
+        hasher.combine(unsafeFilePolicy)
+        hasher.combine(verifyDownloads)
+        hasher.combine(defaultRevision)
+        hasher.combine(readAheadChunks)
+        hasher.combine(runtimeFlavor)
+        hasher.combine(workerThreads)
+        hasher.combine(collisionStrategy)
+        hasher.combine(pathNormalization)
+        hasher.combine(revisionPinning)
+        hasher.combine(failurePolicy)
+        hasher.combine(preferredCasEndpoint)
+        hasher.combine(maxRedirects)
+        hasher.combine(idleTimeoutSecs)
+        hasher.combine(quiet)
+        hasher.combine(maxConcurrentDownloads)
+        hasher.combine(placeholderFilePolicy)
+        hasher.combine(serveStaleMetadata)
+        hasher.combine(resolveRedirectCacheSecs)
+        hasher.combine(clockSource)
+        hasher.combine(clockSkewToleranceSecs)
+        hasher.combine(maxDownloadBytesPerSecond)
+        hasher.combine(retryPolicy)
+        hasher.combine(downloadPreset)
+        hasher.combine(endpointOverrides)
+        hasher.combine(hubEndpoints)
+        hasher.combine(additionalRootCertificates)
+        hasher.combine(certificatePins)
+        hasher.combine(cacheRoots)
+        hasher.combine(connectTimeoutSecs)
+        hasher.combine(readTimeoutSecs)
+        hasher.combine(operationTimeoutSecs)
+        hasher.combine(skipInaccessibleRepos)
+        hasher.combine(maxConcurrentRangeGets)
+        hasher.combine(maxResponseBodyBytes)
+        hasher.combine(simulatedFaults)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeXetClientConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> XetClientConfig {
+        return
+            try XetClientConfig(
+                token: FfiConverterOptionString.read(from: &buf), 
+                maxFileSizeBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                maxSnapshotSizeBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                allowedRepoOwners: FfiConverterOptionSequenceString.read(from: &buf), 
+                unsafeFilePolicy: FfiConverterTypeUnsafeFilePolicy.read(from: &buf), 
+                verifyDownloads: FfiConverterBool.read(from: &buf), 
+                defaultRevision: FfiConverterOptionString.read(from: &buf), 
+                readAheadChunks: FfiConverterOptionUInt32.read(from: &buf), 
+                runtimeFlavor: FfiConverterTypeRuntimeFlavor.read(from: &buf), 
+                workerThreads: FfiConverterOptionUInt32.read(from: &buf), 
+                collisionStrategy: FfiConverterTypeDestinationCollisionStrategy.read(from: &buf), 
+                pathNormalization: FfiConverterTypePathNormalization.read(from: &buf), 
+                revisionPinning: FfiConverterTypeRevisionPinning.read(from: &buf), 
+                failurePolicy: FfiConverterTypeFailurePolicy.read(from: &buf), 
+                preferredCasEndpoint: FfiConverterOptionString.read(from: &buf), 
+                maxRedirects: FfiConverterOptionUInt32.read(from: &buf), 
+                idleTimeoutSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                quiet: FfiConverterBool.read(from: &buf), 
+                maxConcurrentDownloads: FfiConverterOptionUInt32.read(from: &buf), 
+                placeholderFilePolicy: FfiConverterTypePlaceholderFilePolicy.read(from: &buf), 
+                serveStaleMetadata: FfiConverterBool.read(from: &buf), 
+                resolveRedirectCacheSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                clockSource: FfiConverterTypeClockSource.read(from: &buf), 
+                clockSkewToleranceSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                maxDownloadBytesPerSecond: FfiConverterOptionUInt64.read(from: &buf), 
+                retryPolicy: FfiConverterOptionTypeRetryPolicy.read(from: &buf), 
+                downloadPreset: FfiConverterOptionTypeDownloadPreset.read(from: &buf), 
+                endpointOverrides: FfiConverterOptionSequenceTypeEndpointOverride.read(from: &buf), 
+                hubEndpoints: FfiConverterOptionSequenceString.read(from: &buf), 
+                additionalRootCertificates: FfiConverterOptionSequenceString.read(from: &buf), 
+                certificatePins: FfiConverterOptionSequenceTypeCertificatePin.read(from: &buf), 
+                cacheRoots: FfiConverterOptionSequenceTypeCacheRoot.read(from: &buf), 
+                connectTimeoutSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                readTimeoutSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                operationTimeoutSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                skipInaccessibleRepos: FfiConverterBool.read(from: &buf), 
+                maxConcurrentRangeGets: FfiConverterOptionUInt32.read(from: &buf), 
+                maxResponseBodyBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                simulatedFaults: FfiConverterOptionSequenceTypeSimulatedFault.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: XetClientConfig, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.token, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxFileSizeBytes, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxSnapshotSizeBytes, into: &buf)
+        FfiConverterOptionSequenceString.write(value.allowedRepoOwners, into: &buf)
+        FfiConverterTypeUnsafeFilePolicy.write(value.unsafeFilePolicy, into: &buf)
+        FfiConverterBool.write(value.verifyDownloads, into: &buf)
+        FfiConverterOptionString.write(value.defaultRevision, into: &buf)
+        FfiConverterOptionUInt32.write(value.readAheadChunks, into: &buf)
+        FfiConverterTypeRuntimeFlavor.write(value.runtimeFlavor, into: &buf)
+        FfiConverterOptionUInt32.write(value.workerThreads, into: &buf)
+        FfiConverterTypeDestinationCollisionStrategy.write(value.collisionStrategy, into: &buf)
+        FfiConverterTypePathNormalization.write(value.pathNormalization, into: &buf)
+        FfiConverterTypeRevisionPinning.write(value.revisionPinning, into: &buf)
+        FfiConverterTypeFailurePolicy.write(value.failurePolicy, into: &buf)
+        FfiConverterOptionString.write(value.preferredCasEndpoint, into: &buf)
+        FfiConverterOptionUInt32.write(value.maxRedirects, into: &buf)
+        FfiConverterOptionUInt64.write(value.idleTimeoutSecs, into: &buf)
+        FfiConverterBool.write(value.quiet, into: &buf)
+        FfiConverterOptionUInt32.write(value.maxConcurrentDownloads, into: &buf)
+        FfiConverterTypePlaceholderFilePolicy.write(value.placeholderFilePolicy, into: &buf)
+        FfiConverterBool.write(value.serveStaleMetadata, into: &buf)
+        FfiConverterOptionUInt64.write(value.resolveRedirectCacheSecs, into: &buf)
+        FfiConverterTypeClockSource.write(value.clockSource, into: &buf)
+        FfiConverterOptionUInt64.write(value.clockSkewToleranceSecs, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxDownloadBytesPerSecond, into: &buf)
+        FfiConverterOptionTypeRetryPolicy.write(value.retryPolicy, into: &buf)
+        FfiConverterOptionTypeDownloadPreset.write(value.downloadPreset, into: &buf)
+        FfiConverterOptionSequenceTypeEndpointOverride.write(value.endpointOverrides, into: &buf)
+        FfiConverterOptionSequenceString.write(value.hubEndpoints, into: &buf)
+        FfiConverterOptionSequenceString.write(value.additionalRootCertificates, into: &buf)
+        FfiConverterOptionSequenceTypeCertificatePin.write(value.certificatePins, into: &buf)
+        FfiConverterOptionSequenceTypeCacheRoot.write(value.cacheRoots, into: &buf)
+        FfiConverterOptionUInt64.write(value.connectTimeoutSecs, into: &buf)
+        FfiConverterOptionUInt64.write(value.readTimeoutSecs, into: &buf)
+        FfiConverterOptionUInt64.write(value.operationTimeoutSecs, into: &buf)
+        FfiConverterBool.write(value.skipInaccessibleRepos, into: &buf)
+        FfiConverterOptionUInt32.write(value.maxConcurrentRangeGets, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxResponseBodyBytes, into: &buf)
+        FfiConverterOptionSequenceTypeSimulatedFault.write(value.simulatedFaults, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetClientConfig_lift(_ buf: RustBuffer) throws -> XetClientConfig {
+    return try FfiConverterTypeXetClientConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetClientConfig_lower(_ value: XetClientConfig) -> RustBuffer {
+    return FfiConverterTypeXetClientConfig.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The format of a repository archive downloaded with `download_repo_archive`.
+ */
+
+public enum ArchiveFormat {
+    
+    /**
+     * A gzip-compressed tarball.
+     */
+    case tarGz
+    /**
+     * A zip archive.
+     */
+    case zip
+}
+
+
+#if compiler(>=6)
+extension ArchiveFormat: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeArchiveFormat: FfiConverterRustBuffer {
+    typealias SwiftType = ArchiveFormat
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ArchiveFormat {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .tarGz
+        
+        case 2: return .zip
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ArchiveFormat, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .tarGz:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .zip:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeArchiveFormat_lift(_ buf: RustBuffer) throws -> ArchiveFormat {
+    return try FfiConverterTypeArchiveFormat.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeArchiveFormat_lower(_ value: ArchiveFormat) -> RustBuffer {
+    return FfiConverterTypeArchiveFormat.lower(value)
+}
+
+
+extension ArchiveFormat: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A region of the local Xet cache.
+ *
+ * Splitting the cache into areas lets eviction treat cheap-to-refetch
+ * metadata differently from expensive content chunks.
+ */
+
+public enum CacheArea {
+    
+    /**
+     * Content chunks reconstructed from CAS downloads.
+     */
+    case chunks
+    /**
+     * Shard files describing the contents of xorbs.
+     */
+    case shards
+    /**
+     * Resolution metadata and other small bookkeeping files.
+     */
+    case metadata
+    /**
+     * Data staged for upload that has not been committed yet.
+     */
+    case stagedUploads
+    /**
+     * Files of models registered with `register_managed_model`, and their manifest.
+     */
+    case managedModels
+    /**
+     * Files downloaded with `download_to_cache`, stored once per etag.
+     */
+    case downloadedFiles
+}
+
+
+#if compiler(>=6)
+extension CacheArea: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCacheArea: FfiConverterRustBuffer {
+    typealias SwiftType = CacheArea
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CacheArea {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .chunks
+        
+        case 2: return .shards
+        
+        case 3: return .metadata
+        
+        case 4: return .stagedUploads
+        
+        case 5: return .managedModels
+        
+        case 6: return .downloadedFiles
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CacheArea, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .chunks:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .shards:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .metadata:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .stagedUploads:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .managedModels:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .downloadedFiles:
+            writeInt(&buf, Int32(6))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCacheArea_lift(_ buf: RustBuffer) throws -> CacheArea {
+    return try FfiConverterTypeCacheArea.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCacheArea_lower(_ value: CacheArea) -> RustBuffer {
+    return FfiConverterTypeCacheArea.lower(value)
+}
+
+
+extension CacheArea: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Why a transfer was cancelled.
+ */
+
+public enum CancellationReason {
+    
+    /**
+     * The user stopped the transfer. It shouldn't be retried automatically.
+     */
+    case userRequested
+    /**
+     * An app or download policy vetoed the transfer. It shouldn't be retried automatically.
+     */
+    case policyVeto
+    /**
+     * Network conditions stopped the transfer, such as a metered-network pause that timed out.
+     */
+    case networkConditions
+    /**
+     * The host app is about to exit. The transfer resumes when it's retried after the next launch.
+     */
+    case appTerminating
+}
+
+
+#if compiler(>=6)
+extension CancellationReason: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCancellationReason: FfiConverterRustBuffer {
+    typealias SwiftType = CancellationReason
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CancellationReason {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .userRequested
+        
+        case 2: return .policyVeto
+        
+        case 3: return .networkConditions
+        
+        case 4: return .appTerminating
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CancellationReason, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .userRequested:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .policyVeto:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .networkConditions:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .appTerminating:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCancellationReason_lift(_ buf: RustBuffer) throws -> CancellationReason {
+    return try FfiConverterTypeCancellationReason.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCancellationReason_lower(_ value: CancellationReason) -> RustBuffer {
+    return FfiConverterTypeCancellationReason.lower(value)
+}
+
+
+extension CancellationReason: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The time the client judges token expiry and other server-set deadlines against.
+ */
+
+public enum ClockSource {
+    
+    /**
+     * The device's clock, corrected by the skew estimated from Hub responses once it exceeds the tolerance.
+     */
+    case hub
+    /**
+     * The device's clock as is.
+     */
+    case device
+}
+
+
+#if compiler(>=6)
+extension ClockSource: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeClockSource: FfiConverterRustBuffer {
+    typealias SwiftType = ClockSource
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ClockSource {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .hub
+        
+        case 2: return .device
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ClockSource, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .hub:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .device:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeClockSource_lift(_ buf: RustBuffer) throws -> ClockSource {
+    return try FfiConverterTypeClockSource.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeClockSource_lower(_ value: ClockSource) -> RustBuffer {
+    return FfiConverterTypeClockSource.lower(value)
+}
+
+
+extension ClockSource: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * What happened to an interrupted commit during recovery.
+ */
+
+public enum CommitRecoveryAction {
+    
+    /**
+     * All content had been uploaded and the commit was created.
+     */
+    case committed
+    /**
+     * The upload never finished, or the Hub refused the commit, so the intent was discarded.
+     */
+    case rolledBack
+    /**
+     * The commit could not be completed yet and will be retried on the next recovery.
+     */
+    case deferred
+    /**
+     * Some of the files were uploaded; uploading the same files again skips them.
+     */
+    case resumable
+}
+
+
+#if compiler(>=6)
+extension CommitRecoveryAction: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCommitRecoveryAction: FfiConverterRustBuffer {
+    typealias SwiftType = CommitRecoveryAction
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CommitRecoveryAction {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .committed
+        
+        case 2: return .rolledBack
+        
+        case 3: return .deferred
+        
+        case 4: return .resumable
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CommitRecoveryAction, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .committed:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .rolledBack:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .deferred:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .resumable:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCommitRecoveryAction_lift(_ buf: RustBuffer) throws -> CommitRecoveryAction {
+    return try FfiConverterTypeCommitRecoveryAction.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCommitRecoveryAction_lower(_ value: CommitRecoveryAction) -> RustBuffer {
+    return FfiConverterTypeCommitRecoveryAction.lower(value)
+}
+
+
+extension CommitRecoveryAction: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How multi-file downloads handle two files that would be written to the same destination.
+ */
+
+public enum DestinationCollisionStrategy {
+    
+    /**
+     * Fail with `DestinationCollision` before downloading anything.
+     */
+    case fail
+    /**
+     * Write later files to a renamed destination, such as `model-2.bin`.
+     */
+    case rename
+}
+
+
+#if compiler(>=6)
+extension DestinationCollisionStrategy: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDestinationCollisionStrategy: FfiConverterRustBuffer {
+    typealias SwiftType = DestinationCollisionStrategy
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DestinationCollisionStrategy {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .fail
+        
+        case 2: return .rename
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DestinationCollisionStrategy, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .fail:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .rename:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDestinationCollisionStrategy_lift(_ buf: RustBuffer) throws -> DestinationCollisionStrategy {
+    return try FfiConverterTypeDestinationCollisionStrategy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDestinationCollisionStrategy_lower(_ value: DestinationCollisionStrategy) -> RustBuffer {
+    return FfiConverterTypeDestinationCollisionStrategy.lower(value)
+}
+
+
+extension DestinationCollisionStrategy: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A named set of download options tuned for common network conditions.
+ */
+
+public enum DownloadPreset {
+    
+    /**
+     * Fetches several files at once with a deep read-ahead window.
+     */
+    case wiFi
+    /**
+     * Fetches few files at once with a shallow read-ahead window, and limits throughput to 2 MiB per second.
+     */
+    case cellular
+    /**
+     * Fetches one file at a time with minimal memory, retries patiently, and releases the client's threads when idle.
+     */
+    case background
+}
+
+
+#if compiler(>=6)
+extension DownloadPreset: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDownloadPreset: FfiConverterRustBuffer {
+    typealias SwiftType = DownloadPreset
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DownloadPreset {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .wiFi
+        
+        case 2: return .cellular
+        
+        case 3: return .background
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DownloadPreset, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .wiFi:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .cellular:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .background:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDownloadPreset_lift(_ buf: RustBuffer) throws -> DownloadPreset {
+    return try FfiConverterTypeDownloadPreset.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDownloadPreset_lower(_ value: DownloadPreset) -> RustBuffer {
+    return FfiConverterTypeDownloadPreset.lower(value)
+}
+
+
+extension DownloadPreset: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The transfer path that produced a downloaded file.
+ */
+
+public enum DownloadSource {
+    
+    /**
+     * Reconstructed from Xet content-addressable storage.
+     */
+    case xet
+    /**
+     * Fetched from the resolved download URL.
+     */
+    case http
+    /**
+     * Fetched from the Hub's resolve endpoints without metadata.
+     */
+    case legacy
+    /**
+     * Not fetched, because the destination already held the resolved content.
+     */
+    case skipped
+}
+
+
+#if compiler(>=6)
+extension DownloadSource: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDownloadSource: FfiConverterRustBuffer {
+    typealias SwiftType = DownloadSource
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DownloadSource {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .xet
+        
+        case 2: return .http
+        
+        case 3: return .legacy
+        
+        case 4: return .skipped
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DownloadSource, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .xet:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .http:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .legacy:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .skipped:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDownloadSource_lift(_ buf: RustBuffer) throws -> DownloadSource {
+    return try FfiConverterTypeDownloadSource.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDownloadSource_lower(_ value: DownloadSource) -> RustBuffer {
+    return FfiConverterTypeDownloadSource.lower(value)
+}
+
+
+extension DownloadSource: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The kind of service a host answering a client's requests provides.
+ */
+
+public enum EndpointKind {
+    
+    /**
+     * The Hub's website and API, including resolve requests it answers itself.
+     */
+    case hub
+    /**
+     * The Xet content-addressable storage API.
+     */
+    case cas
+    /**
+     * A CDN or storage bucket serving signed URLs, such as redirect targets and xorb ranges.
+     */
+    case cdn
+}
+
+
+#if compiler(>=6)
+extension EndpointKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeEndpointKind: FfiConverterRustBuffer {
+    typealias SwiftType = EndpointKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EndpointKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .hub
+        
+        case 2: return .cas
+        
+        case 3: return .cdn
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: EndpointKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .hub:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .cas:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .cdn:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEndpointKind_lift(_ buf: RustBuffer) throws -> EndpointKind {
+    return try FfiConverterTypeEndpointKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEndpointKind_lower(_ value: EndpointKind) -> RustBuffer {
+    return FfiConverterTypeEndpointKind.lower(value)
+}
+
+
+extension EndpointKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The underlying failure behind an error, for handling errors programmatically.
+ */
+
+public enum ErrorCause {
+    
+    /**
+     * A file system operation failed.
+     */
+    case io(kind: IoErrorKind
+    )
+    /**
+     * An HTTP request failed, with the response status if one was received.
+     */
+    case http(kind: HttpErrorKind, status: UInt16?
+    )
+    /**
+     * Xet data processing failed; `kind` names the data processing error variant.
+     */
+    case dataProcessing(kind: String
+    )
+}
+
+
+#if compiler(>=6)
+extension ErrorCause: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeErrorCause: FfiConverterRustBuffer {
+    typealias SwiftType = ErrorCause
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ErrorCause {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .io(kind: try FfiConverterTypeIoErrorKind.read(from: &buf)
+        )
+        
+        case 2: return .http(kind: try FfiConverterTypeHttpErrorKind.read(from: &buf), status: try FfiConverterOptionUInt16.read(from: &buf)
+        )
+        
+        case 3: return .dataProcessing(kind: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ErrorCause, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .io(kind):
+            writeInt(&buf, Int32(1))
+            FfiConverterTypeIoErrorKind.write(kind, into: &buf)
+            
+        
+        case let .http(kind,status):
+            writeInt(&buf, Int32(2))
+            FfiConverterTypeHttpErrorKind.write(kind, into: &buf)
+            FfiConverterOptionUInt16.write(status, into: &buf)
+            
+        
+        case let .dataProcessing(kind):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(kind, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeErrorCause_lift(_ buf: RustBuffer) throws -> ErrorCause {
+    return try FfiConverterTypeErrorCause.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeErrorCause_lower(_ value: ErrorCause) -> RustBuffer {
+    return FfiConverterTypeErrorCause.lower(value)
+}
+
+
+extension ErrorCause: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * What a multi-file download leaves on disk when one of its files fails.
+ */
+
+public enum FailurePolicy {
+    
+    /**
+     * Keep the files that finished before the failure.
+     */
+    case keepCompleted
+    /**
+     * Undo everything the download wrote, restoring any files it replaced.
+     */
+    case rollBack
+}
+
+
+#if compiler(>=6)
+extension FailurePolicy: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFailurePolicy: FfiConverterRustBuffer {
+    typealias SwiftType = FailurePolicy
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FailurePolicy {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .keepCompleted
+        
+        case 2: return .rollBack
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FailurePolicy, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .keepCompleted:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .rollBack:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFailurePolicy_lift(_ buf: RustBuffer) throws -> FailurePolicy {
+    return try FfiConverterTypeFailurePolicy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFailurePolicy_lower(_ value: FailurePolicy) -> RustBuffer {
+    return FfiConverterTypeFailurePolicy.lower(value)
+}
+
+
+extension FailurePolicy: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * What goes wrong with a request subject to a `SimulatedFault`.
+ */
+
+public enum FaultKind {
+    
+    /**
+     * The request is delayed before it's sent.
+     */
+    case latency(millis: UInt64
+    )
+    /**
+     * The server appears to answer with `status`, such as 429 or 503, without the request being sent.
+     */
+    case status(status: UInt16, retryAfterSecs: UInt64?
+    )
+    /**
+     * The connection drops after `after_bytes` of the response body.
+     */
+    case dropConnection(afterBytes: UInt64
+    )
+    /**
+     * The response body ends cleanly after `after_bytes`, short of its declared length.
+     */
+    case truncateBody(afterBytes: UInt64
+    )
+}
+
+
+#if compiler(>=6)
+extension FaultKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFaultKind: FfiConverterRustBuffer {
+    typealias SwiftType = FaultKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FaultKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .latency(millis: try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        case 2: return .status(status: try FfiConverterUInt16.read(from: &buf), retryAfterSecs: try FfiConverterOptionUInt64.read(from: &buf)
+        )
+        
+        case 3: return .dropConnection(afterBytes: try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        case 4: return .truncateBody(afterBytes: try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FaultKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .latency(millis):
+            writeInt(&buf, Int32(1))
+            FfiConverterUInt64.write(millis, into: &buf)
+            
+        
+        case let .status(status,retryAfterSecs):
+            writeInt(&buf, Int32(2))
+            FfiConverterUInt16.write(status, into: &buf)
+            FfiConverterOptionUInt64.write(retryAfterSecs, into: &buf)
+            
+        
+        case let .dropConnection(afterBytes):
+            writeInt(&buf, Int32(3))
+            FfiConverterUInt64.write(afterBytes, into: &buf)
+            
+        
+        case let .truncateBody(afterBytes):
+            writeInt(&buf, Int32(4))
+            FfiConverterUInt64.write(afterBytes, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFaultKind_lift(_ buf: RustBuffer) throws -> FaultKind {
+    return try FfiConverterTypeFaultKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFaultKind_lower(_ value: FaultKind) -> RustBuffer {
+    return FfiConverterTypeFaultKind.lower(value)
+}
+
+
+extension FaultKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * What became of one file in a batch download.
+ */
+
+public enum FileDownloadStatus {
+    
+    /**
+     * The file is at its destination, whether downloaded or already present.
+     */
+    case downloaded
+    /**
+     * The file failed to download.
+     */
+    case failed
+    /**
+     * The file wasn't attempted, because the batch's patterns excluded it or the batch stopped at an earlier failure.
+     */
+    case skipped
+    /**
+     * The file wasn't attempted, because the client can't read its repository.
+     */
+    case unauthorized
+}
+
+
+#if compiler(>=6)
+extension FileDownloadStatus: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileDownloadStatus: FfiConverterRustBuffer {
+    typealias SwiftType = FileDownloadStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileDownloadStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .downloaded
+        
+        case 2: return .failed
+        
+        case 3: return .skipped
+        
+        case 4: return .unauthorized
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FileDownloadStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .downloaded:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .failed:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .skipped:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .unauthorized:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileDownloadStatus_lift(_ buf: RustBuffer) throws -> FileDownloadStatus {
+    return try FfiConverterTypeFileDownloadStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileDownloadStatus_lower(_ value: FileDownloadStatus) -> RustBuffer {
+    return FfiConverterTypeFileDownloadStatus.lower(value)
+}
+
+
+extension FileDownloadStatus: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The stage at which an HTTP request failed.
+ */
+
+public enum HttpErrorKind {
+    
+    /**
+     * The host name couldn't be resolved.
+     */
+    case dns
+    /**
+     * A connection to the server couldn't be established.
+     */
+    case connect
+    /**
+     * The request timed out.
+     */
+    case timeout
+    /**
+     * The request was redirected too many times.
+     */
+    case redirect
+    /**
+     * The server answered with an error status.
+     */
+    case status
+    /**
+     * The response body couldn't be read or decoded.
+     */
+    case body
+    /**
+     * The request failed for another reason.
+     */
+    case other
+}
+
+
+#if compiler(>=6)
+extension HttpErrorKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHttpErrorKind: FfiConverterRustBuffer {
+    typealias SwiftType = HttpErrorKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HttpErrorKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .dns
+        
+        case 2: return .connect
+        
+        case 3: return .timeout
+        
+        case 4: return .redirect
+        
+        case 5: return .status
+        
+        case 6: return .body
+        
+        case 7: return .other
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: HttpErrorKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .dns:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .connect:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .timeout:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .redirect:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .status:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .body:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .other:
+            writeInt(&buf, Int32(7))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHttpErrorKind_lift(_ buf: RustBuffer) throws -> HttpErrorKind {
+    return try FfiConverterTypeHttpErrorKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHttpErrorKind_lower(_ value: HttpErrorKind) -> RustBuffer {
+    return FfiConverterTypeHttpErrorKind.lower(value)
+}
+
+
+extension HttpErrorKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The kind of a failed file system operation.
+ */
+
+public enum IoErrorKind {
+    
+    case notFound
+    case permissionDenied
+    case alreadyExists
+    case storageFull
+    case readOnlyFilesystem
+    case interrupted
+    case unexpectedEof
+    case invalidData
+    case other
+}
+
+
+#if compiler(>=6)
+extension IoErrorKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeIoErrorKind: FfiConverterRustBuffer {
+    typealias SwiftType = IoErrorKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IoErrorKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .notFound
+        
+        case 2: return .permissionDenied
+        
+        case 3: return .alreadyExists
+        
+        case 4: return .storageFull
+        
+        case 5: return .readOnlyFilesystem
+        
+        case 6: return .interrupted
+        
+        case 7: return .unexpectedEof
+        
+        case 8: return .invalidData
+        
+        case 9: return .other
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: IoErrorKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .notFound:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .permissionDenied:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .alreadyExists:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .storageFull:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .readOnlyFilesystem:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .interrupted:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .unexpectedEof:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .invalidData:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .other:
+            writeInt(&buf, Int32(9))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIoErrorKind_lift(_ buf: RustBuffer) throws -> IoErrorKind {
+    return try FfiConverterTypeIoErrorKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIoErrorKind_lower(_ value: IoErrorKind) -> RustBuffer {
+    return FfiConverterTypeIoErrorKind.lower(value)
+}
+
+
+extension IoErrorKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The state of a managed model's local files.
+ */
+
+public enum ManagedModelStatus {
+    
+    /**
+     * No update has started yet.
+     */
+    case notDownloaded
+    /**
+     * An update started but didn't finish.
+     */
+    case partiallyDownloaded
+    /**
+     * The files are complete, but the revision has moved or the patterns have changed since.
+     */
+    case updateAvailable
+    /**
+     * The files are complete and current, as far as the client could determine.
+     */
+    case upToDate
+}
+
+
+#if compiler(>=6)
+extension ManagedModelStatus: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeManagedModelStatus: FfiConverterRustBuffer {
+    typealias SwiftType = ManagedModelStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManagedModelStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .notDownloaded
+        
+        case 2: return .partiallyDownloaded
+        
+        case 3: return .updateAvailable
+        
+        case 4: return .upToDate
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ManagedModelStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .notDownloaded:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .partiallyDownloaded:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .updateAvailable:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .upToDate:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManagedModelStatus_lift(_ buf: RustBuffer) throws -> ManagedModelStatus {
+    return try FfiConverterTypeManagedModelStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManagedModelStatus_lower(_ value: ManagedModelStatus) -> RustBuffer {
+    return FfiConverterTypeManagedModelStatus.lower(value)
+}
+
+
+extension ManagedModelStatus: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Which commit of a managed model's repository to keep downloaded.
+ */
+
+public enum ManagedRevisionPolicy {
+    
+    /**
+     * Follow a branch, offering an update whenever it advances.
+     */
+    case track(revision: String
+    )
+    /**
+     * Stay at a fixed revision, such as a tag or commit hash.
+     */
+    case pin(revision: String
+    )
+}
+
+
+#if compiler(>=6)
+extension ManagedRevisionPolicy: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeManagedRevisionPolicy: FfiConverterRustBuffer {
+    typealias SwiftType = ManagedRevisionPolicy
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManagedRevisionPolicy {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .track(revision: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .pin(revision: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ManagedRevisionPolicy, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .track(revision):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(revision, into: &buf)
+            
+        
+        case let .pin(revision):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(revision, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManagedRevisionPolicy_lift(_ buf: RustBuffer) throws -> ManagedRevisionPolicy {
+    return try FfiConverterTypeManagedRevisionPolicy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManagedRevisionPolicy_lower(_ value: ManagedRevisionPolicy) -> RustBuffer {
+    return FfiConverterTypeManagedRevisionPolicy.lower(value)
+}
+
+
+extension ManagedRevisionPolicy: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The Unicode normalization applied to local paths the client writes.
+ */
+
+public enum PathNormalization {
+    
+    /**
+     * Compose characters, as in most Hub paths and Swift string literals.
+     */
+    case nfc
+    /**
+     * Decompose characters, as HFS+ stores names.
+     */
+    case nfd
+    /**
+     * Write paths exactly as given.
+     */
+    case preserve
+}
+
+
+#if compiler(>=6)
+extension PathNormalization: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePathNormalization: FfiConverterRustBuffer {
+    typealias SwiftType = PathNormalization
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PathNormalization {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .nfc
+        
+        case 2: return .nfd
+        
+        case 3: return .preserve
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: PathNormalization, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .nfc:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .nfd:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .preserve:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePathNormalization_lift(_ buf: RustBuffer) throws -> PathNormalization {
+    return try FfiConverterTypePathNormalization.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePathNormalization_lower(_ value: PathNormalization) -> RustBuffer {
+    return FfiConverterTypePathNormalization.lower(value)
+}
+
+
+extension PathNormalization: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How snapshot and folder downloads treat zero-byte files and Git placeholders such as `.gitattributes` and `.gitkeep`.
+ */
+
+public enum PlaceholderFilePolicy {
+    
+    /**
+     * Download them like any other file.
+     */
+    case fetch
+    /**
+     * Create zero-byte files locally without any requests, and download placeholders that have content like any other file.
+     */
+    case createLocally
+    /**
+     * Leave out zero-byte files and placeholders entirely.
+     */
+    case skip
+}
+
+
+#if compiler(>=6)
+extension PlaceholderFilePolicy: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePlaceholderFilePolicy: FfiConverterRustBuffer {
+    typealias SwiftType = PlaceholderFilePolicy
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PlaceholderFilePolicy {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .fetch
+        
+        case 2: return .createLocally
+        
+        case 3: return .skip
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: PlaceholderFilePolicy, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .fetch:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .createLocally:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .skip:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePlaceholderFilePolicy_lift(_ buf: RustBuffer) throws -> PlaceholderFilePolicy {
+    return try FfiConverterTypePlaceholderFilePolicy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePlaceholderFilePolicy_lower(_ value: PlaceholderFilePolicy) -> RustBuffer {
+    return FfiConverterTypePlaceholderFilePolicy.lower(value)
+}
+
+
+extension PlaceholderFilePolicy: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * An operation on a repository that a token may or may not be granted.
+ */
+
+public enum RepoOperation {
+    
+    /**
+     * Resolving and downloading the repository's files.
+     */
+    case read
+    /**
+     * Uploading files and committing to the repository.
+     */
+    case write
+}
+
+
+#if compiler(>=6)
+extension RepoOperation: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRepoOperation: FfiConverterRustBuffer {
+    typealias SwiftType = RepoOperation
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepoOperation {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .read
+        
+        case 2: return .write
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RepoOperation, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .read:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .write:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepoOperation_lift(_ buf: RustBuffer) throws -> RepoOperation {
+    return try FfiConverterTypeRepoOperation.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepoOperation_lower(_ value: RepoOperation) -> RustBuffer {
+    return FfiConverterTypeRepoOperation.lower(value)
+}
+
+
+extension RepoOperation: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A class of failure that might succeed if tried again.
+ */
+
+public enum RetryClass {
+    
+    /**
+     * The request timed out.
+     */
+    case timeout
+    /**
+     * The host couldn't be resolved or connected to.
+     */
+    case connect
+    /**
+     * The server answered with a 5xx status.
+     */
+    case serverError
+    /**
+     * The server answered with 429 Too Many Requests.
+     */
+    case rateLimited
+}
+
+
+#if compiler(>=6)
+extension RetryClass: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRetryClass: FfiConverterRustBuffer {
+    typealias SwiftType = RetryClass
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RetryClass {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .timeout
+        
+        case 2: return .connect
+        
+        case 3: return .serverError
+        
+        case 4: return .rateLimited
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RetryClass, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .timeout:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .connect:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .serverError:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .rateLimited:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRetryClass_lift(_ buf: RustBuffer) throws -> RetryClass {
+    return try FfiConverterTypeRetryClass.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRetryClass_lower(_ value: RetryClass) -> RustBuffer {
+    return FfiConverterTypeRetryClass.lower(value)
+}
+
+
+extension RetryClass: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Which commit a multi-file download uses when its revision moves partway through.
+ */
+
+public enum RevisionPinning {
+    
+    /**
+     * Resolve each revision to a commit once and download every file at that commit.
+     */
+    case pinned
+    /**
+     * Resolve the revision again for each file.
+     */
+    case followHead
+}
+
+
+#if compiler(>=6)
+extension RevisionPinning: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRevisionPinning: FfiConverterRustBuffer {
+    typealias SwiftType = RevisionPinning
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RevisionPinning {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .pinned
+        
+        case 2: return .followHead
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RevisionPinning, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .pinned:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .followHead:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevisionPinning_lift(_ buf: RustBuffer) throws -> RevisionPinning {
+    return try FfiConverterTypeRevisionPinning.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevisionPinning_lower(_ value: RevisionPinning) -> RustBuffer {
+    return FfiConverterTypeRevisionPinning.lower(value)
+}
+
+
+extension RevisionPinning: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The kind of async runtime a client drives its work on.
+ */
+
+public enum RuntimeFlavor {
+    
+    /**
+     * A runtime with a pool of worker threads.
+     */
+    case multiThread
+    /**
+     * A runtime that runs only on the threads calling into the client.
+     */
+    case currentThread
+}
+
+
+#if compiler(>=6)
+extension RuntimeFlavor: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRuntimeFlavor: FfiConverterRustBuffer {
+    typealias SwiftType = RuntimeFlavor
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RuntimeFlavor {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .multiThread
+        
+        case 2: return .currentThread
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RuntimeFlavor, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .multiThread:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .currentThread:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRuntimeFlavor_lift(_ buf: RustBuffer) throws -> RuntimeFlavor {
+    return try FfiConverterTypeRuntimeFlavor.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRuntimeFlavor_lower(_ value: RuntimeFlavor) -> RustBuffer {
+    return FfiConverterTypeRuntimeFlavor.lower(value)
+}
+
+
+extension RuntimeFlavor: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How the client treats files flagged by the Hub's security scanner.
+ */
+
+public enum UnsafeFilePolicy {
+    
+    /**
+     * Download flagged files without checking their scan status.
+     */
+    case allow
+    /**
+     * Download flagged files, but report them with an `UnsafeFileDetected` event.
+     * Files whose scan status can't be looked up are downloaded as well.
+     */
+    case warn
+    /**
+     * Refuse to download flagged files.
+     */
+    case refuse
+}
+
+
+#if compiler(>=6)
+extension UnsafeFilePolicy: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUnsafeFilePolicy: FfiConverterRustBuffer {
+    typealias SwiftType = UnsafeFilePolicy
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UnsafeFilePolicy {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .allow
+        
+        case 2: return .warn
+        
+        case 3: return .refuse
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: UnsafeFilePolicy, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .allow:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .warn:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .refuse:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUnsafeFilePolicy_lift(_ buf: RustBuffer) throws -> UnsafeFilePolicy {
+    return try FfiConverterTypeUnsafeFilePolicy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUnsafeFilePolicy_lower(_ value: UnsafeFilePolicy) -> RustBuffer {
+    return FfiConverterTypeUnsafeFilePolicy.lower(value)
+}
+
+
+extension UnsafeFilePolicy: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How the Hub stores a file's content when it's committed.
+ */
+
+public enum UploadMode {
+    
+    /**
+     * The content is uploaded to Xet storage and committed as a pointer, which the Hub reports as LFS.
+     */
+    case xet
+    /**
+     * The content is committed inline in git.
+     */
+    case regular
+}
+
+
+#if compiler(>=6)
+extension UploadMode: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUploadMode: FfiConverterRustBuffer {
+    typealias SwiftType = UploadMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UploadMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .xet
+        
+        case 2: return .regular
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: UploadMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .xet:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .regular:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUploadMode_lift(_ buf: RustBuffer) throws -> UploadMode {
+    return try FfiConverterTypeUploadMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUploadMode_lower(_ value: UploadMode) -> RustBuffer {
+    return FfiConverterTypeUploadMode.lower(value)
+}
+
+
+extension UploadMode: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Whether a downloaded file was checked against its expected hash.
+ */
+
+public enum VerificationStatus {
+    
+    /**
+     * Verification was not enabled for the client.
+     */
+    case notRequested
+    /**
+     * The Hub didn't report a SHA-256 for the file, so it couldn't be checked.
+     */
+    case unavailable
+    /**
+     * The file's SHA-256 matched the one reported by the Hub.
+     */
+    case verified
+}
+
+
+#if compiler(>=6)
+extension VerificationStatus: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVerificationStatus: FfiConverterRustBuffer {
+    typealias SwiftType = VerificationStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VerificationStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .notRequested
+        
+        case 2: return .unavailable
+        
+        case 3: return .verified
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: VerificationStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .notRequested:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .unavailable:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .verified:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVerificationStatus_lift(_ buf: RustBuffer) throws -> VerificationStatus {
+    return try FfiConverterTypeVerificationStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVerificationStatus_lower(_ value: VerificationStatus) -> RustBuffer {
+    return FfiConverterTypeVerificationStatus.lower(value)
+}
+
+
+extension VerificationStatus: Equatable, Hashable {}
+
+
+
+
+
+
+
+/**
+ * An error that occurs during Xet operations.
+ *
+ * This error type represents various failure conditions that can occur when
+ * interacting with Xet repositories, including network issues, authentication
+ * problems, and data processing errors.
+ */
+public enum XetError: Swift.Error {
+
+    
+    
+    /**
+     * A general operation failure occurred.
+     */
+    case OperationFailed(message: String, cause: ErrorCause?
+    )
+    /**
+     * Invalid input was provided to a method.
+     */
+    case InvalidInput(message: String
+    )
+    /**
+     * An I/O error occurred during file operations.
+     */
+    case IoError(message: String, cause: ErrorCause?
+    )
+    /**
+     * A network error occurred during a request.
+     */
+    case NetworkError(message: String, cause: ErrorCause?
+    )
+    /**
+     * An authentication error occurred.
+     */
+    case AuthError(message: String
+    )
+    /**
+     * A cache operation failed.
+     */
+    case CacheError(message: String
+    )
+    /**
+     * A token-related error occurred.
+     */
+    case TokenError(message: String
+    )
+    /**
+     * The operation was refused by a configured policy or guardrail.
+     */
+    case PolicyViolation(message: String
+    )
+    /**
+     * Downloaded content did not match its expected hash.
+     */
+    case IntegrityError(message: String
+    )
+    /**
+     * The operation was cancelled, for the given reason.
+     */
+    case Cancelled(message: String, reason: CancellationReason
+    )
+    /**
+     * A blocking method was called from a context where it would deadlock.
+     */
+    case ReentrantCall(message: String
+    )
+    /**
+     * Two files in a multi-file download would be written to the same destination.
+     */
+    case DestinationCollision(message: String
+    )
+    /**
+     * The repository can't be accessed without suitable credentials.
+     */
+    case AuthRequired(repo: String
+    )
+    /**
+     * The Hub answered with a server error, and may be down as a whole.
+     */
+    case ServiceUnavailable(message: String, retryAfterSecs: UInt64?, serviceWide: Bool
+    )
+    /**
+     * A CAS token expired before a transfer could start, and couldn't be refreshed.
+     */
+    case TokenExpired(message: String, expiredAt: UInt64
+    )
+    /**
+     * The operation didn't finish within its time limit.
+     */
+    case Timeout(message: String
+    )
+    /**
+     * The client's token doesn't grant the operation on the repository.
+     */
+    case PermissionDenied(repo: String, operation: RepoOperation
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeXetError: FfiConverterRustBuffer {
+    typealias SwiftType = XetError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> XetError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .OperationFailed(
+            message: try FfiConverterString.read(from: &buf), 
+            cause: try FfiConverterOptionTypeErrorCause.read(from: &buf)
+            )
+        case 2: return .InvalidInput(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .IoError(
+            message: try FfiConverterString.read(from: &buf), 
+            cause: try FfiConverterOptionTypeErrorCause.read(from: &buf)
+            )
+        case 4: return .NetworkError(
+            message: try FfiConverterString.read(from: &buf), 
+            cause: try FfiConverterOptionTypeErrorCause.read(from: &buf)
+            )
+        case 5: return .AuthError(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 6: return .CacheError(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 7: return .TokenError(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 8: return .PolicyViolation(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 9: return .IntegrityError(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 10: return .Cancelled(
+            message: try FfiConverterString.read(from: &buf), 
+            reason: try FfiConverterTypeCancellationReason.read(from: &buf)
+            )
+        case 11: return .ReentrantCall(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 12: return .DestinationCollision(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 13: return .AuthRequired(
+            repo: try FfiConverterString.read(from: &buf)
+            )
+        case 14: return .ServiceUnavailable(
+            message: try FfiConverterString.read(from: &buf), 
+            retryAfterSecs: try FfiConverterOptionUInt64.read(from: &buf), 
+            serviceWide: try FfiConverterBool.read(from: &buf)
+            )
+        case 15: return .TokenExpired(
+            message: try FfiConverterString.read(from: &buf), 
+            expiredAt: try FfiConverterUInt64.read(from: &buf)
+            )
+        case 16: return .Timeout(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 17: return .PermissionDenied(
+            repo: try FfiConverterString.read(from: &buf), 
+            operation: try FfiConverterTypeRepoOperation.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: XetError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .OperationFailed(message,cause):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(message, into: &buf)
+            FfiConverterOptionTypeErrorCause.write(cause, into: &buf)
+            
+        
+        case let .InvalidInput(message):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .IoError(message,cause):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(message, into: &buf)
+            FfiConverterOptionTypeErrorCause.write(cause, into: &buf)
+            
+        
+        case let .NetworkError(message,cause):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(message, into: &buf)
+            FfiConverterOptionTypeErrorCause.write(cause, into: &buf)
+            
+        
+        case let .AuthError(message):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .CacheError(message):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .TokenError(message):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .PolicyViolation(message):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .IntegrityError(message):
+            writeInt(&buf, Int32(9))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .Cancelled(message,reason):
+            writeInt(&buf, Int32(10))
+            FfiConverterString.write(message, into: &buf)
+            FfiConverterTypeCancellationReason.write(reason, into: &buf)
+            
+        
+        case let .ReentrantCall(message):
+            writeInt(&buf, Int32(11))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .DestinationCollision(message):
+            writeInt(&buf, Int32(12))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .AuthRequired(repo):
+            writeInt(&buf, Int32(13))
+            FfiConverterString.write(repo, into: &buf)
+            
+        
+        case let .ServiceUnavailable(message,retryAfterSecs,serviceWide):
+            writeInt(&buf, Int32(14))
+            FfiConverterString.write(message, into: &buf)
+            FfiConverterOptionUInt64.write(retryAfterSecs, into: &buf)
+            FfiConverterBool.write(serviceWide, into: &buf)
+            
+        
+        case let .TokenExpired(message,expiredAt):
+            writeInt(&buf, Int32(15))
+            FfiConverterString.write(message, into: &buf)
+            FfiConverterUInt64.write(expiredAt, into: &buf)
+            
+        
+        case let .Timeout(message):
+            writeInt(&buf, Int32(16))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .PermissionDenied(repo,operation):
+            writeInt(&buf, Int32(17))
+            FfiConverterString.write(repo, into: &buf)
+            FfiConverterTypeRepoOperation.write(operation, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetError_lift(_ buf: RustBuffer) throws -> XetError {
+    return try FfiConverterTypeXetError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetError_lower(_ value: XetError) -> RustBuffer {
+    return FfiConverterTypeXetError.lower(value)
+}
+
+
+extension XetError: Equatable, Hashable {}
+
+
+
+
+extension XetError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A notable occurrence reported to the client's event listener.
+ */
+
+public enum XetEvent {
+    
+    /**
+     * A file flagged by the Hub's security scanner was encountered.
+     */
+    case unsafeFileDetected(repo: String, path: String, status: String
+    )
+    /**
+     * A file in a multi-file download was renamed to avoid a destination collision.
+     */
+    case destinationRenamed(repo: String, path: String, destination: String, renamedTo: String
+    )
+    /**
+     * A Hub API response reported the current rate limit.
+     */
+    case rateLimitUpdated(limit: UInt64?, remaining: UInt64?, resetAt: UInt64?
+    )
+    /**
+     * A file download was cancelled through its `CancellationToken`.
+     */
+    case transferCancelled(repo: String, path: String, reason: CancellationReason
+    )
+    /**
+     * A file was resolved from a cached resolution because the Hub was unreachable.
+     */
+    case staleMetadataServed(repo: String, path: String, revision: String
+    )
+    /**
+     * A revision moved while a file was being fetched, so the file was fetched again at the commit the revision was resolved to.
+     */
+    case revisionMoved(repo: String, path: String, revision: String, commit: String
+    )
+    /**
+     * A multi-file download skipped the files in a repository the client can't read.
+     */
+    case repoSkipped(repo: String
+    )
+}
+
+
+#if compiler(>=6)
+extension XetEvent: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeXetEvent: FfiConverterRustBuffer {
+    typealias SwiftType = XetEvent
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> XetEvent {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .unsafeFileDetected(repo: try FfiConverterString.read(from: &buf), path: try FfiConverterString.read(from: &buf), status: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .destinationRenamed(repo: try FfiConverterString.read(from: &buf), path: try FfiConverterString.read(from: &buf), destination: try FfiConverterString.read(from: &buf), renamedTo: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .rateLimitUpdated(limit: try FfiConverterOptionUInt64.read(from: &buf), remaining: try FfiConverterOptionUInt64.read(from: &buf), resetAt: try FfiConverterOptionUInt64.read(from: &buf)
+        )
+        
+        case 4: return .transferCancelled(repo: try FfiConverterString.read(from: &buf), path: try FfiConverterString.read(from: &buf), reason: try FfiConverterTypeCancellationReason.read(from: &buf)
+        )
+        
+        case 5: return .staleMetadataServed(repo: try FfiConverterString.read(from: &buf), path: try FfiConverterString.read(from: &buf), revision: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 6: return .revisionMoved(repo: try FfiConverterString.read(from: &buf), path: try FfiConverterString.read(from: &buf), revision: try FfiConverterString.read(from: &buf), commit: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 7: return .repoSkipped(repo: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: XetEvent, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .unsafeFileDetected(repo,path,status):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(repo, into: &buf)
+            FfiConverterString.write(path, into: &buf)
+            FfiConverterString.write(status, into: &buf)
+            
+        
+        case let .destinationRenamed(repo,path,destination,renamedTo):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(repo, into: &buf)
+            FfiConverterString.write(path, into: &buf)
+            FfiConverterString.write(destination, into: &buf)
+            FfiConverterString.write(renamedTo, into: &buf)
+            
+        
+        case let .rateLimitUpdated(limit,remaining,resetAt):
+            writeInt(&buf, Int32(3))
+            FfiConverterOptionUInt64.write(limit, into: &buf)
+            FfiConverterOptionUInt64.write(remaining, into: &buf)
+            FfiConverterOptionUInt64.write(resetAt, into: &buf)
+            
+        
+        case let .transferCancelled(repo,path,reason):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(repo, into: &buf)
+            FfiConverterString.write(path, into: &buf)
+            FfiConverterTypeCancellationReason.write(reason, into: &buf)
+            
+        
+        case let .staleMetadataServed(repo,path,revision):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(repo, into: &buf)
+            FfiConverterString.write(path, into: &buf)
+            FfiConverterString.write(revision, into: &buf)
+            
+        
+        case let .revisionMoved(repo,path,revision,commit):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(repo, into: &buf)
+            FfiConverterString.write(path, into: &buf)
+            FfiConverterString.write(revision, into: &buf)
+            FfiConverterString.write(commit, into: &buf)
+            
+        
+        case let .repoSkipped(repo):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(repo, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetEvent_lift(_ buf: RustBuffer) throws -> XetEvent {
+    return try FfiConverterTypeXetEvent.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeXetEvent_lower(_ value: XetEvent) -> RustBuffer {
+    return FfiConverterTypeXetEvent.lower(value)
+}
+
+
+extension XetEvent: Equatable, Hashable {}
+
+
+
+
+
+
+
+
+
+/**
+ * A host-provided policy consulted before each file download begins.
+ */
+public protocol DownloadPolicy: AnyObject, Sendable {
+    
+    /**
+     * Returns whether the file may be downloaded.
+     */
+    func allowDownload(path: String, size: UInt64?, contentType: String?)  -> Bool
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceDownloadPolicy {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceDownloadPolicy] = [UniffiVTableCallbackInterfaceDownloadPolicy(
+        allowDownload: { (
+            uniffiHandle: UInt64,
+            path: RustBuffer,
+            size: RustBuffer,
+            contentType: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<Int8>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> Bool in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceDownloadPolicy.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.allowDownload(
+                     path: try FfiConverterString.lift(path),
+                     size: try FfiConverterOptionUInt64.lift(size),
+                     contentType: try FfiConverterOptionString.lift(contentType)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterBool.lower($0) }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceDownloadPolicy.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface DownloadPolicy: handle missing in uniffiFree")
+            }
+        }
+    )]
+}
+
+private func uniffiCallbackInitDownloadPolicy() {
+    uniffi_swift_xet_rust_fn_init_callback_vtable_downloadpolicy(UniffiCallbackInterfaceDownloadPolicy.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceDownloadPolicy {
+    fileprivate static let handleMap = UniffiHandleMap<DownloadPolicy>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceDownloadPolicy : FfiConverter {
+    typealias SwiftType = DownloadPolicy
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceDownloadPolicy_lift(_ handle: UInt64) throws -> DownloadPolicy {
+    return try FfiConverterCallbackInterfaceDownloadPolicy.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceDownloadPolicy_lower(_ v: DownloadPolicy) -> UInt64 {
+    return FfiConverterCallbackInterfaceDownloadPolicy.lower(v)
+}
+
+
+
+
+/**
+ * A host-provided receiver for transfer progress.
+ */
+public protocol ProgressCallback: AnyObject, Sendable {
+    
+    /**
+     * Called as a transfer makes progress, and once more when it completes.
+     */
+    func onProgress(update: ProgressUpdate) 
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceProgressCallback {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceProgressCallback] = [UniffiVTableCallbackInterfaceProgressCallback(
+        onProgress: { (
+            uniffiHandle: UInt64,
+            update: UnsafeMutableRawPointer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceProgressCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onProgress(
+                     update: try FfiConverterTypeProgressUpdate_lift(update)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceProgressCallback.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface ProgressCallback: handle missing in uniffiFree")
+            }
+        }
+    )]
+}
+
+private func uniffiCallbackInitProgressCallback() {
+    uniffi_swift_xet_rust_fn_init_callback_vtable_progresscallback(UniffiCallbackInterfaceProgressCallback.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceProgressCallback {
+    fileprivate static let handleMap = UniffiHandleMap<ProgressCallback>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceProgressCallback : FfiConverter {
+    typealias SwiftType = ProgressCallback
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceProgressCallback_lift(_ handle: UInt64) throws -> ProgressCallback {
+    return try FfiConverterCallbackInterfaceProgressCallback.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceProgressCallback_lower(_ v: ProgressCallback) -> UInt64 {
+    return FfiConverterCallbackInterfaceProgressCallback.lower(v)
+}
+
+
+
+
+/**
+ * A host-provided listener for client events.
+ */
+public protocol XetEventListener: AnyObject, Sendable {
+    
+    /**
+     * Called when an event occurs.
+     */
+    func onEvent(event: XetEvent) 
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceXetEventListener {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceXetEventListener] = [UniffiVTableCallbackInterfaceXetEventListener(
+        onEvent: { (
+            uniffiHandle: UInt64,
+            event: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceXetEventListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onEvent(
+                     event: try FfiConverterTypeXetEvent_lift(event)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceXetEventListener.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface XetEventListener: handle missing in uniffiFree")
+            }
+        }
+    )]
+}
+
+private func uniffiCallbackInitXetEventListener() {
+    uniffi_swift_xet_rust_fn_init_callback_vtable_xeteventlistener(UniffiCallbackInterfaceXetEventListener.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceXetEventListener {
+    fileprivate static let handleMap = UniffiHandleMap<XetEventListener>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceXetEventListener : FfiConverter {
+    typealias SwiftType = XetEventListener
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceXetEventListener_lift(_ handle: UInt64) throws -> XetEventListener {
+    return try FfiConverterCallbackInterfaceXetEventListener.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceXetEventListener_lower(_ v: XetEventListener) -> UInt64 {
+    return FfiConverterCallbackInterfaceXetEventListener.lower(v)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt16: FfiConverterRustBuffer {
+    typealias SwiftType = UInt16?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt16.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt16.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeCancellationToken: FfiConverterRustBuffer {
+    typealias SwiftType = CancellationToken?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCancellationToken.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCancellationToken.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeRateLimitStatus: FfiConverterRustBuffer {
+    typealias SwiftType = RateLimitStatus?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRateLimitStatus.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRateLimitStatus.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeTransferReport: FfiConverterRustBuffer {
+    typealias SwiftType = TransferReport?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTransferReport.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTransferReport.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeXetFileInfo: FfiConverterRustBuffer {
+    typealias SwiftType = XetFileInfo?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeXetFileInfo.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeXetFileInfo.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeXetPointerDetails: FfiConverterRustBuffer {
+    typealias SwiftType = XetPointerDetails?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeXetPointerDetails.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeXetPointerDetails.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeRetryPolicy: FfiConverterRustBuffer {
+    typealias SwiftType = RetryPolicy?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRetryPolicy.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRetryPolicy.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeCancellationReason: FfiConverterRustBuffer {
+    typealias SwiftType = CancellationReason?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCancellationReason.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCancellationReason.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeDownloadPreset: FfiConverterRustBuffer {
+    typealias SwiftType = DownloadPreset?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeDownloadPreset.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeDownloadPreset.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeErrorCause: FfiConverterRustBuffer {
+    typealias SwiftType = ErrorCause?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeErrorCause.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeErrorCause.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionCallbackInterfaceDownloadPolicy: FfiConverterRustBuffer {
+    typealias SwiftType = DownloadPolicy?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterCallbackInterfaceDownloadPolicy.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterCallbackInterfaceDownloadPolicy.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionCallbackInterfaceProgressCallback: FfiConverterRustBuffer {
+    typealias SwiftType = ProgressCallback?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterCallbackInterfaceProgressCallback.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterCallbackInterfaceProgressCallback.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionCallbackInterfaceXetEventListener: FfiConverterRustBuffer {
+    typealias SwiftType = XetEventListener?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterCallbackInterfaceXetEventListener.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterCallbackInterfaceXetEventListener.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeFileMetadata: FfiConverterRustBuffer {
+    typealias SwiftType = [FileMetadata]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeFileMetadata.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeFileMetadata.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeCacheRoot: FfiConverterRustBuffer {
+    typealias SwiftType = [CacheRoot]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeCacheRoot.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeCacheRoot.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeCertificatePin: FfiConverterRustBuffer {
+    typealias SwiftType = [CertificatePin]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeCertificatePin.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeCertificatePin.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeEndpointOverride: FfiConverterRustBuffer {
+    typealias SwiftType = [EndpointOverride]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeEndpointOverride.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeEndpointOverride.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeSimulatedFault: FfiConverterRustBuffer {
+    typealias SwiftType = [SimulatedFault]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeSimulatedFault.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeSimulatedFault.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
+
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeBatchUploadResult: FfiConverterRustBuffer {
+    typealias SwiftType = [BatchUploadResult]
+
+    public static func write(_ value: [BatchUploadResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeBatchUploadResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [BatchUploadResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [BatchUploadResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeBatchUploadResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCachedRevision: FfiConverterRustBuffer {
+    typealias SwiftType = [CachedRevision]
+
+    public static func write(_ value: [CachedRevision], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCachedRevision.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CachedRevision] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CachedRevision]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCachedRevision.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFileDownloadRequest: FfiConverterRustBuffer {
+    typealias SwiftType = [FileDownloadRequest]
+
+    public static func write(_ value: [FileDownloadRequest], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFileDownloadRequest.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FileDownloadRequest] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FileDownloadRequest]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFileDownloadRequest.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFileDownloadResult: FfiConverterRustBuffer {
+    typealias SwiftType = [FileDownloadResult]
+
+    public static func write(_ value: [FileDownloadResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFileDownloadResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FileDownloadResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FileDownloadResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFileDownloadResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFileMetadata: FfiConverterRustBuffer {
+    typealias SwiftType = [FileMetadata]
+
+    public static func write(_ value: [FileMetadata], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFileMetadata.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FileMetadata] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FileMetadata]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFileMetadata.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeManagedModel: FfiConverterRustBuffer {
+    typealias SwiftType = [ManagedModel]
+
+    public static func write(_ value: [ManagedModel], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeManagedModel.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ManagedModel] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ManagedModel]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeManagedModel.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRecoveredCommit: FfiConverterRustBuffer {
+    typealias SwiftType = [RecoveredCommit]
+
+    public static func write(_ value: [RecoveredCommit], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRecoveredCommit.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [RecoveredCommit] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [RecoveredCommit]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRecoveredCommit.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeXetFileInfo: FfiConverterRustBuffer {
+    typealias SwiftType = [XetFileInfo]
+
+    public static func write(_ value: [XetFileInfo], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeXetFileInfo.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [XetFileInfo] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [XetFileInfo]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeXetFileInfo.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCacheRoot: FfiConverterRustBuffer {
+    typealias SwiftType = [CacheRoot]
+
+    public static func write(_ value: [CacheRoot], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCacheRoot.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CacheRoot] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CacheRoot]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCacheRoot.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCasEndpointLatency: FfiConverterRustBuffer {
+    typealias SwiftType = [CasEndpointLatency]
+
+    public static func write(_ value: [CasEndpointLatency], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCasEndpointLatency.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CasEndpointLatency] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CasEndpointLatency]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCasEndpointLatency.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCertificatePin: FfiConverterRustBuffer {
+    typealias SwiftType = [CertificatePin]
+
+    public static func write(_ value: [CertificatePin], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCertificatePin.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CertificatePin] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CertificatePin]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCertificatePin.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeEndpointOverride: FfiConverterRustBuffer {
+    typealias SwiftType = [EndpointOverride]
+
+    public static func write(_ value: [EndpointOverride], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeEndpointOverride.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [EndpointOverride] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [EndpointOverride]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeEndpointOverride.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFileUploadRequest: FfiConverterRustBuffer {
+    typealias SwiftType = [FileUploadRequest]
+
+    public static func write(_ value: [FileUploadRequest], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFileUploadRequest.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FileUploadRequest] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FileUploadRequest]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFileUploadRequest.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFileUploadResult: FfiConverterRustBuffer {
+    typealias SwiftType = [FileUploadResult]
+
+    public static func write(_ value: [FileUploadResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFileUploadResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FileUploadResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FileUploadResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFileUploadResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeHostUsage: FfiConverterRustBuffer {
+    typealias SwiftType = [HostUsage]
+
+    public static func write(_ value: [HostUsage], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeHostUsage.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [HostUsage] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [HostUsage]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeHostUsage.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeLabelUsage: FfiConverterRustBuffer {
+    typealias SwiftType = [LabelUsage]
+
+    public static func write(_ value: [LabelUsage], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeLabelUsage.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [LabelUsage] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [LabelUsage]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeLabelUsage.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypePreuploadFile: FfiConverterRustBuffer {
+    typealias SwiftType = [PreuploadFile]
+
+    public static func write(_ value: [PreuploadFile], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePreuploadFile.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PreuploadFile] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [PreuploadFile]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePreuploadFile.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypePreuploadResult: FfiConverterRustBuffer {
+    typealias SwiftType = [PreuploadResult]
+
+    public static func write(_ value: [PreuploadResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePreuploadResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PreuploadResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [PreuploadResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePreuploadResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSimulatedFault: FfiConverterRustBuffer {
+    typealias SwiftType = [SimulatedFault]
+
+    public static func write(_ value: [SimulatedFault], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSimulatedFault.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SimulatedFault] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SimulatedFault]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSimulatedFault.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRetryClass: FfiConverterRustBuffer {
+    typealias SwiftType = [RetryClass]
+
+    public static func write(_ value: [RetryClass], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRetryClass.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [RetryClass] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [RetryClass]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRetryClass.read(from: &buf))
+        }
+        return seq
+    }
+}
+private let UNIFFI_RUST_FUTURE_POLL_READY: Int8 = 0
+private let UNIFFI_RUST_FUTURE_POLL_MAYBE_READY: Int8 = 1
+
+fileprivate let uniffiContinuationHandleMap = UniffiHandleMap<UnsafeContinuation<Int8, Never>>()
+
+fileprivate func uniffiRustCallAsync<F, T>(
+    rustFutureFunc: () -> UInt64,
+    pollFunc: (UInt64, @escaping UniffiRustFutureContinuationCallback, UInt64) -> (),
+    completeFunc: (UInt64, UnsafeMutablePointer<RustCallStatus>) -> F,
+    freeFunc: (UInt64) -> (),
+    liftFunc: (F) throws -> T,
+    errorHandler: ((RustBuffer) throws -> Swift.Error)?
+) async throws -> T {
+    // Make sure to call the ensure init function since future creation doesn't have a
+    // RustCallStatus param, so doesn't use makeRustCall()
+    uniffiEnsureSwiftXetRustInitialized()
+    let rustFuture = rustFutureFunc()
+    defer {
+        freeFunc(rustFuture)
+    }
+    var pollResult: Int8;
+    repeat {
+        pollResult = await withUnsafeContinuation {
+            pollFunc(
+                rustFuture,
+                uniffiFutureContinuationCallback,
+                uniffiContinuationHandleMap.insert(obj: $0)
+            )
+        }
+    } while pollResult != UNIFFI_RUST_FUTURE_POLL_READY
+
+    return try liftFunc(makeRustCall(
+        { completeFunc(rustFuture, $0) },
+        errorHandler: errorHandler
+    ))
+}
+
+// Callback handlers for an async calls.  These are invoked by Rust when the future is ready.  They
+// lift the return value or error and resume the suspended function.
+fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: Int8) {
+    if let continuation = try? uniffiContinuationHandleMap.remove(handle: handle) {
+        continuation.resume(returning: pollResult)
+    } else {
+        print("uniffiFutureContinuationCallback invalid handle")
+    }
+}
+/**
+ * Returns the stable, machine-readable code of an error, such as `"XET_E_AUTH_REQUIRED"`.
+ */
+public func errorCode(error: XetError) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_func_error_code(
+        FfiConverterTypeXetError_lower(error),$0
+    )
+})
+}
+/**
+ * Formats a byte count with a decimal unit, such as `"1.5 GB"`, the same on every platform and in every locale.
+ */
+public func formatBytes(bytes: UInt64) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_func_format_bytes(
+        FfiConverterUInt64.lower(bytes),$0
+    )
+})
+}
+/**
+ * Formats the time left to transfer the remaining bytes at a rate, such as `"3m 07s"`, or returns `null` while the transfer isn't making progress.
+ */
+public func formatEta(remainingBytes: UInt64, bytesPerSecond: Double) -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_func_format_eta(
+        FfiConverterUInt64.lower(remainingBytes),
+        FfiConverterDouble.lower(bytesPerSecond),$0
+    )
+})
+}
+/**
+ * Formats a throughput in bytes per second, such as `"12.3 MB/s"`.
+ */
+public func formatRate(bytesPerSecond: Double) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_swift_xet_rust_fn_func_format_rate(
+        FfiConverterDouble.lower(bytesPerSecond),$0
+    )
+})
+}
+/**
+ * Reads a recording made with `record_session` into simulated faults that reproduce its failures and slow responses.
+ */
+public func replayFaults(path: String)throws  -> [SimulatedFault]  {
+    return try  FfiConverterSequenceTypeSimulatedFault.lift(try rustCallWithError(FfiConverterTypeXetError_lift) {
+    uniffi_swift_xet_rust_fn_func_replay_faults(
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+
+private enum InitializationResult {
+    case ok
+    case contractVersionMismatch
+    case apiChecksumMismatch
+}
+// Use a global variable to perform the versioning checks. Swift ensures that
+// the code inside is only computed once.
+private let initializationResult: InitializationResult = {
+    // Get the bindings contract version from our ComponentInterface
+    let bindings_contract_version = 29
+    // Get the scaffolding contract version by calling the into the dylib
+    let scaffolding_contract_version = ffi_swift_xet_rust_uniffi_contract_version()
+    if bindings_contract_version != scaffolding_contract_version {
+        return InitializationResult.contractVersionMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_func_error_code() != 37891) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_func_format_bytes() != 64046) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_func_format_eta() != 58928) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_func_format_rate() != 24229) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_func_replay_faults() != 14561) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_batchdownloadresult_downloaded_paths() != 41401) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_batchdownloadresult_failed_count() != 23444) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_batchdownloadresult_results() != 44387) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_batchdownloadresult_skipped_count() != 46298) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_batchdownloadresult_unauthorized_count() != 32569) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_batchuploadresult_chunking() != 22736) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_batchuploadresult_commit_oid() != 41350) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_batchuploadresult_files() != 1659) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cacheareastats_file_count() != 14519) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cacheareastats_total_size_bytes() != 2110) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cachestats_area_stats() != 13640) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cachestats_deduplicated_bytes() != 21855) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cachestats_file_count() != 12498) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cachestats_total_size_bytes() != 16924) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cachedrevision_commit() != 19667) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cachedrevision_local_path() != 22845) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cachedrevision_refs() != 30306) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cachedrevision_size() != 30207) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cancellationtoken_cancel() != 45185) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cancellationtoken_cancel_with_reason() != 3651) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cancellationtoken_is_cancelled() != 38867) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_cancellationtoken_reason() != 1467) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_casjwtinfo_access_token() != 32548) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_casjwtinfo_cas_url() != 45659) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_casjwtinfo_claims() != 43440) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_casjwtinfo_endpoint_latencies() != 37453) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_casjwtinfo_exp() != 62544) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_downloadhandle_cancel() != 3479) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_downloadhandle_cancel_with_reason() != 43894) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_downloadhandle_is_cancelled() != 26739) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_downloadhandle_is_paused() != 45722) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_downloadhandle_pause() != 41770) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_downloadhandle_resume() != 30580) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadrequest_destination() != 8610) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadrequest_path() != 30807) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadrequest_priority() != 41219) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadrequest_repo() != 16310) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadrequest_revision() != 55791) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadrequest_set_priority() != 27556) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadresult_destination() != 7972) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadresult_error_code() != 40892) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadresult_error_message() != 53958) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadresult_report() != 39687) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadresult_request() != 46637) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filedownloadresult_status() != 38129) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filemetadata_entry_type() != 65196) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filemetadata_hash() != 6123) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filemetadata_oid() != 41330) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filemetadata_path() != 41865) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filemetadata_security_status() != 43428) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filemetadata_size() != 56132) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filemetadata_xet_hash() != 48064) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filestream_position() != 63557) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filestream_read_next() != 1664) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_filestream_size() != 23024) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_managedmodel_downloaded_file_count() != 55195) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_managedmodel_file_count() != 24145) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_managedmodel_installed_commit() != 50379) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_managedmodel_last_error() != 61224) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_managedmodel_latest_commit() != 38605) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_managedmodel_local_path() != 46505) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_managedmodel_patterns() != 56155) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_managedmodel_repo() != 30277) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_managedmodel_revision_policy() != 4363) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_managedmodel_status() != 46487) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_progressupdate_total_bytes() != 26346) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_progressupdate_total_bytes_completed() != 24618) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_progressupdate_total_transfer_bytes() != 33800) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_progressupdate_total_transfer_bytes_completed() != 43253) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_ratelimitstatus_limit() != 40074) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_ratelimitstatus_remaining() != 6370) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_ratelimitstatus_reset_at() != 25190) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_ratelimitstatus_seconds_until_reset() != 64650) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_recoveredcommit_action() != 46352) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_recoveredcommit_commit_oid() != 13017) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_recoveredcommit_error() != 25422) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_recoveredcommit_paths() != 11669) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_recoveredcommit_repo() != 19917) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_recoveredcommit_revision() != 57564) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_repoinfo_full_name() != 5027) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_repoinfo_repo_type() != 64290) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_reposize_estimated_disk_bytes() != 40172) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_reposize_file_count() != 50925) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_reposize_logical_bytes() != 6034) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transfergroup_cancel() != 45213) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transfergroup_cancel_with_reason() != 55476) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transfergroup_download_count() != 20228) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transfergroup_is_cancelled() != 22547) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transfergroup_is_paused() != 22594) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transfergroup_pause() != 24477) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transfergroup_progress() != 17957) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transfergroup_resume() != 11650) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transferreport_bytes() != 10591) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transferreport_cas_endpoint() != 17073) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transferreport_cas_endpoint_latencies() != 5928) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transferreport_elapsed_ms() != 47827) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transferreport_served_stale() != 56635) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transferreport_source() != 27659) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transferreport_verification() != 38051) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_transferreport_verification_ms() != 33857) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_treeiterator_is_finished() != 40010) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_treeiterator_next_page() != 21741) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_treelisting_files() != 14937) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_treelisting_is_complete() != 37492) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_treelisting_unlisted_paths() != 29981) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_uploadsession_staged_paths() != 29931) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_can_access() != 54521) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_clear_cache() != 6243) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_clear_cache_area() != 22453) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterUInt64.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_delete_file() != 41306) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_by_hash() != 6801) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_file() != 37247) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterOptionTypeXetFileInfo: FfiConverterRustBuffer {
-    typealias SwiftType = XetFileInfo?
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeXetFileInfo.write(value, into: &buf)
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_file_async() != 54175) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeXetFileInfo.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_file_in_group() != 2338) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
-
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterString.write(item, into: &buf)
-        }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_file_in_group_async() != 63337) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [String]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterString.read(from: &buf))
-        }
-        return seq
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_file_with_handle() != 25503) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterSequenceTypeXetFileInfo: FfiConverterRustBuffer {
-    typealias SwiftType = [XetFileInfo]
-
-    public static func write(_ value: [XetFileInfo], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeXetFileInfo.write(item, into: &buf)
-        }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_file_with_handle_async() != 6895) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [XetFileInfo] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [XetFileInfo]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeXetFileInfo.read(from: &buf))
-        }
-        return seq
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_file_with_progress() != 48606) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-private enum InitializationResult {
-    case ok
-    case contractVersionMismatch
-    case apiChecksumMismatch
-}
-// Use a global variable to perform the versioning checks. Swift ensures that
-// the code inside is only computed once.
-private let initializationResult: InitializationResult = {
-    // Get the bindings contract version from our ComponentInterface
-    let bindings_contract_version = 29
-    // Get the scaffolding contract version by calling the into the dylib
-    let scaffolding_contract_version = ffi_swift_xet_rust_uniffi_contract_version()
-    if bindings_contract_version != scaffolding_contract_version {
-        return InitializationResult.contractVersionMismatch
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_file_with_progress_async() != 52294) {
+        return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_cachestats_file_count() != 12498) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_files() != 39385) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_cachestats_total_size_bytes() != 16924) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch() != 8417) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_casjwtinfo_access_token() != 32548) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_async() != 33291) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_casjwtinfo_cas_url() != 45659) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_detailed() != 62143) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_casjwtinfo_exp() != 62544) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_detailed_async() != 35197) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_filemetadata_entry_type() != 65196) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_with_results() != 25251) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_filemetadata_hash() != 6123) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_with_results_async() != 31069) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_filemetadata_oid() != 41330) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_with_template() != 50970) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_filemetadata_path() != 41865) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_folder() != 52839) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_filemetadata_size() != 56132) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_folder_async() != 44934) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_repoinfo_full_name() != 5027) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_repo_archive() != 11890) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_repoinfo_repo_type() != 64290) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_repo_archive_with_progress() != 58383) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_xetclient_clear_cache() != 6243) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_to_cache() != 41121) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_files() != 39385) {
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_download_to_cache_async() != 35683) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_estimate_upload() != 36675) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_filter_missing() != 21684) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_swift_xet_rust_checksum_method_xetclient_get_cache_stats() != 60885) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_get_cached_path() != 58446) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_swift_xet_rust_checksum_method_xetclient_get_cas_jwt() != 12531) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_get_clock_skew_secs() != 22132) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_swift_xet_rust_checksum_method_xetclient_get_file_info() != 20923) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_get_paths_info() != 9565) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_get_rate_limit_status() != 63559) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_swift_xet_rust_checksum_method_xetclient_get_repo_info() != 7739) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_get_repo_size() != 18667) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_get_usage_report() != 24935) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_get_xet_pointer_details() != 11007) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_list_cached_revisions() != 47612) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_list_files_recursive() != 3404) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_list_managed() != 24858) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_move_file() != 32887) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_open_file_stream() != 47919) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_prepare_for_termination() != 48548) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_preupload() != 9465) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_prune_cache_area() != 58086) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_publish() != 956) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_record_session() != 8188) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_recover_pending_commits() != 42308) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_register_managed_model() != 65430) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_reset_usage_report() != 4273) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_set_download_policy() != 6596) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_set_event_listener() != 40373) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_set_max_download_bytes_per_second() != 63553) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_set_quiet() != 4769) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_set_usage_label() != 14582) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_snapshot_download() != 42361) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_snapshot_download_async() != 26802) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_stage_file() != 14172) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_start_cache_maintenance() != 57764) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_stop_cache_maintenance() != 22621) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_stop_recording() != 9708) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_tree_iterator() != 6392) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_update_all_managed() != 3382) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_upload_bytes() != 32080) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_upload_file() != 16792) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_upload_file_with_progress() != 56741) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_upload_files() != 25590) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_upload_files_with_progress() != 21728) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetclient_upload_folder() != 5129) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_swift_xet_rust_checksum_method_xetclient_version() != 7693) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -1957,16 +14286,73 @@ private let initializationResult: InitializationResult = {
     if (uniffi_swift_xet_rust_checksum_method_xetfileinfo_hash() != 14067) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_swift_xet_rust_checksum_method_xetpointerdetails_cas_endpoint() != 21913) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetpointerdetails_refresh_route() != 45036) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetpointerdetails_size() != 36499) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xetpointerdetails_xet_hash() != 15605) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_constructor_cancellationtoken_new() != 12510) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_constructor_cancellationtoken_with_label() != 60033) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_constructor_cancellationtoken_with_timeout() != 10130) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_constructor_downloadhandle_new() != 10163) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_constructor_downloadhandle_with_label() != 60814) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_constructor_downloadhandle_with_timeout() != 59846) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_constructor_filedownloadrequest_new() != 58072) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_constructor_filedownloadrequest_with_priority() != 16668) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_constructor_transfergroup_new() != 25920) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_constructor_uploadsession_new() != 52170) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_swift_xet_rust_checksum_constructor_xetclient_new() != 64112) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_swift_xet_rust_checksum_constructor_xetclient_with_config() != 29297) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_swift_xet_rust_checksum_constructor_xetclient_with_token() != 33097) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_swift_xet_rust_checksum_constructor_xetfileinfo_new() != 29667) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_swift_xet_rust_checksum_method_downloadpolicy_allow_download() != 35522) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_progresscallback_on_progress() != 34598) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_swift_xet_rust_checksum_method_xeteventlistener_on_event() != 53827) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
+    uniffiCallbackInitDownloadPolicy()
+    uniffiCallbackInitProgressCallback()
+    uniffiCallbackInitXetEventListener()
     return InitializationResult.ok
 }()
 
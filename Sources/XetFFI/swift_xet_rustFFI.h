@@ -250,6 +250,131 @@ typedef struct UniffiForeignFutureStructVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureStructVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_DOWNLOAD_POLICY_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_DOWNLOAD_POLICY_METHOD0
+typedef void (*UniffiCallbackInterfaceDownloadPolicyMethod0)(uint64_t, RustBuffer, RustBuffer, RustBuffer, int8_t* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_PROGRESS_CALLBACK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_PROGRESS_CALLBACK_METHOD0
+typedef void (*UniffiCallbackInterfaceProgressCallbackMethod0)(uint64_t, void*_Nonnull, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_XET_EVENT_LISTENER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_XET_EVENT_LISTENER_METHOD0
+typedef void (*UniffiCallbackInterfaceXetEventListenerMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_DOWNLOAD_POLICY
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_DOWNLOAD_POLICY
+typedef struct UniffiVTableCallbackInterfaceDownloadPolicy {
+    UniffiCallbackInterfaceDownloadPolicyMethod0 _Nonnull allowDownload;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceDownloadPolicy;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_PROGRESS_CALLBACK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_PROGRESS_CALLBACK
+typedef struct UniffiVTableCallbackInterfaceProgressCallback {
+    UniffiCallbackInterfaceProgressCallbackMethod0 _Nonnull onProgress;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceProgressCallback;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_XET_EVENT_LISTENER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_XET_EVENT_LISTENER
+typedef struct UniffiVTableCallbackInterfaceXetEventListener {
+    UniffiCallbackInterfaceXetEventListenerMethod0 _Nonnull onEvent;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceXetEventListener;
+
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_BATCHDOWNLOADRESULT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_BATCHDOWNLOADRESULT
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_batchdownloadresult(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_BATCHDOWNLOADRESULT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_BATCHDOWNLOADRESULT
+void uniffi_swift_xet_rust_fn_free_batchdownloadresult(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHDOWNLOADRESULT_DOWNLOADED_PATHS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHDOWNLOADRESULT_DOWNLOADED_PATHS
+RustBuffer uniffi_swift_xet_rust_fn_method_batchdownloadresult_downloaded_paths(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHDOWNLOADRESULT_FAILED_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHDOWNLOADRESULT_FAILED_COUNT
+uint32_t uniffi_swift_xet_rust_fn_method_batchdownloadresult_failed_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHDOWNLOADRESULT_RESULTS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHDOWNLOADRESULT_RESULTS
+RustBuffer uniffi_swift_xet_rust_fn_method_batchdownloadresult_results(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHDOWNLOADRESULT_SKIPPED_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHDOWNLOADRESULT_SKIPPED_COUNT
+uint32_t uniffi_swift_xet_rust_fn_method_batchdownloadresult_skipped_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHDOWNLOADRESULT_UNAUTHORIZED_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHDOWNLOADRESULT_UNAUTHORIZED_COUNT
+uint32_t uniffi_swift_xet_rust_fn_method_batchdownloadresult_unauthorized_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_BATCHUPLOADRESULT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_BATCHUPLOADRESULT
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_batchuploadresult(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_BATCHUPLOADRESULT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_BATCHUPLOADRESULT
+void uniffi_swift_xet_rust_fn_free_batchuploadresult(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHUPLOADRESULT_CHUNKING
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHUPLOADRESULT_CHUNKING
+RustBuffer uniffi_swift_xet_rust_fn_method_batchuploadresult_chunking(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHUPLOADRESULT_COMMIT_OID
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHUPLOADRESULT_COMMIT_OID
+RustBuffer uniffi_swift_xet_rust_fn_method_batchuploadresult_commit_oid(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHUPLOADRESULT_FILES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_BATCHUPLOADRESULT_FILES
+RustBuffer uniffi_swift_xet_rust_fn_method_batchuploadresult_files(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_CACHEAREASTATS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_CACHEAREASTATS
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_cacheareastats(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_CACHEAREASTATS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_CACHEAREASTATS
+void uniffi_swift_xet_rust_fn_free_cacheareastats(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEAREASTATS_FILE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEAREASTATS_FILE_COUNT
+uint64_t uniffi_swift_xet_rust_fn_method_cacheareastats_file_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEAREASTATS_TOTAL_SIZE_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEAREASTATS_TOTAL_SIZE_BYTES
+uint64_t uniffi_swift_xet_rust_fn_method_cacheareastats_total_size_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_CACHESTATS
 #define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_CACHESTATS
@@ -261,6 +386,16 @@ void*_Nonnull uniffi_swift_xet_rust_fn_clone_cachestats(void*_Nonnull ptr, RustC
 void uniffi_swift_xet_rust_fn_free_cachestats(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHESTATS_AREA_STATS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHESTATS_AREA_STATS
+void*_Nonnull uniffi_swift_xet_rust_fn_method_cachestats_area_stats(void*_Nonnull ptr, RustBuffer area, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHESTATS_DEDUPLICATED_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHESTATS_DEDUPLICATED_BYTES
+uint64_t uniffi_swift_xet_rust_fn_method_cachestats_deduplicated_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHESTATS_FILE_COUNT
 #define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHESTATS_FILE_COUNT
 uint64_t uniffi_swift_xet_rust_fn_method_cachestats_file_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -271,6 +406,82 @@ uint64_t uniffi_swift_xet_rust_fn_method_cachestats_file_count(void*_Nonnull ptr
 uint64_t uniffi_swift_xet_rust_fn_method_cachestats_total_size_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_CACHEDREVISION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_CACHEDREVISION
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_cachedrevision(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_CACHEDREVISION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_CACHEDREVISION
+void uniffi_swift_xet_rust_fn_free_cachedrevision(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEDREVISION_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEDREVISION_COMMIT
+RustBuffer uniffi_swift_xet_rust_fn_method_cachedrevision_commit(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEDREVISION_LOCAL_PATH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEDREVISION_LOCAL_PATH
+RustBuffer uniffi_swift_xet_rust_fn_method_cachedrevision_local_path(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEDREVISION_REFS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEDREVISION_REFS
+RustBuffer uniffi_swift_xet_rust_fn_method_cachedrevision_refs(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEDREVISION_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CACHEDREVISION_SIZE
+uint64_t uniffi_swift_xet_rust_fn_method_cachedrevision_size(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_CANCELLATIONTOKEN
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_CANCELLATIONTOKEN
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_cancellationtoken(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_CANCELLATIONTOKEN
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_CANCELLATIONTOKEN
+void uniffi_swift_xet_rust_fn_free_cancellationtoken(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_CANCELLATIONTOKEN_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_CANCELLATIONTOKEN_NEW
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_cancellationtoken_new(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_CANCELLATIONTOKEN_WITH_LABEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_CANCELLATIONTOKEN_WITH_LABEL
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_cancellationtoken_with_label(RustBuffer label, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_CANCELLATIONTOKEN_WITH_TIMEOUT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_CANCELLATIONTOKEN_WITH_TIMEOUT
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_cancellationtoken_with_timeout(uint64_t timeout_secs, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CANCELLATIONTOKEN_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CANCELLATIONTOKEN_CANCEL
+void uniffi_swift_xet_rust_fn_method_cancellationtoken_cancel(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CANCELLATIONTOKEN_CANCEL_WITH_REASON
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CANCELLATIONTOKEN_CANCEL_WITH_REASON
+void uniffi_swift_xet_rust_fn_method_cancellationtoken_cancel_with_reason(void*_Nonnull ptr, RustBuffer reason, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
+int8_t uniffi_swift_xet_rust_fn_method_cancellationtoken_is_cancelled(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CANCELLATIONTOKEN_REASON
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CANCELLATIONTOKEN_REASON
+RustBuffer uniffi_swift_xet_rust_fn_method_cancellationtoken_reason(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_CASJWTINFO
 #define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_CASJWTINFO
 void*_Nonnull uniffi_swift_xet_rust_fn_clone_casjwtinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -291,11 +502,167 @@ RustBuffer uniffi_swift_xet_rust_fn_method_casjwtinfo_access_token(void*_Nonnull
 RustBuffer uniffi_swift_xet_rust_fn_method_casjwtinfo_cas_url(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CASJWTINFO_CLAIMS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CASJWTINFO_CLAIMS
+RustBuffer uniffi_swift_xet_rust_fn_method_casjwtinfo_claims(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CASJWTINFO_ENDPOINT_LATENCIES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CASJWTINFO_ENDPOINT_LATENCIES
+RustBuffer uniffi_swift_xet_rust_fn_method_casjwtinfo_endpoint_latencies(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CASJWTINFO_EXP
 #define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_CASJWTINFO_EXP
 uint64_t uniffi_swift_xet_rust_fn_method_casjwtinfo_exp(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_DOWNLOADHANDLE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_DOWNLOADHANDLE
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_downloadhandle(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_DOWNLOADHANDLE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_DOWNLOADHANDLE
+void uniffi_swift_xet_rust_fn_free_downloadhandle(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_DOWNLOADHANDLE_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_DOWNLOADHANDLE_NEW
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_downloadhandle_new(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_DOWNLOADHANDLE_WITH_LABEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_DOWNLOADHANDLE_WITH_LABEL
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_downloadhandle_with_label(RustBuffer label, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_DOWNLOADHANDLE_WITH_TIMEOUT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_DOWNLOADHANDLE_WITH_TIMEOUT
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_downloadhandle_with_timeout(uint64_t timeout_secs, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_CANCEL
+void uniffi_swift_xet_rust_fn_method_downloadhandle_cancel(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_CANCEL_WITH_REASON
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_CANCEL_WITH_REASON
+void uniffi_swift_xet_rust_fn_method_downloadhandle_cancel_with_reason(void*_Nonnull ptr, RustBuffer reason, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_IS_CANCELLED
+int8_t uniffi_swift_xet_rust_fn_method_downloadhandle_is_cancelled(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_IS_PAUSED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_IS_PAUSED
+int8_t uniffi_swift_xet_rust_fn_method_downloadhandle_is_paused(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_PAUSE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_PAUSE
+void uniffi_swift_xet_rust_fn_method_downloadhandle_pause(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_RESUME
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_DOWNLOADHANDLE_RESUME
+void uniffi_swift_xet_rust_fn_method_downloadhandle_resume(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_FILEDOWNLOADREQUEST
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_FILEDOWNLOADREQUEST
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_filedownloadrequest(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_FILEDOWNLOADREQUEST
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_FILEDOWNLOADREQUEST
+void uniffi_swift_xet_rust_fn_free_filedownloadrequest(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_FILEDOWNLOADREQUEST_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_FILEDOWNLOADREQUEST_NEW
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_filedownloadrequest_new(RustBuffer repo, RustBuffer path, RustBuffer destination, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_FILEDOWNLOADREQUEST_WITH_PRIORITY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_FILEDOWNLOADREQUEST_WITH_PRIORITY
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_filedownloadrequest_with_priority(RustBuffer repo, RustBuffer path, RustBuffer destination, RustBuffer revision, int32_t priority, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_DESTINATION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_DESTINATION
+RustBuffer uniffi_swift_xet_rust_fn_method_filedownloadrequest_destination(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_PATH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_PATH
+RustBuffer uniffi_swift_xet_rust_fn_method_filedownloadrequest_path(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_PRIORITY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_PRIORITY
+int32_t uniffi_swift_xet_rust_fn_method_filedownloadrequest_priority(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_REPO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_REPO
+RustBuffer uniffi_swift_xet_rust_fn_method_filedownloadrequest_repo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_REVISION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_REVISION
+RustBuffer uniffi_swift_xet_rust_fn_method_filedownloadrequest_revision(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_SET_PRIORITY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADREQUEST_SET_PRIORITY
+void uniffi_swift_xet_rust_fn_method_filedownloadrequest_set_priority(void*_Nonnull ptr, int32_t priority, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_FILEDOWNLOADRESULT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_FILEDOWNLOADRESULT
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_filedownloadresult(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_FILEDOWNLOADRESULT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_FILEDOWNLOADRESULT
+void uniffi_swift_xet_rust_fn_free_filedownloadresult(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_DESTINATION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_DESTINATION
+RustBuffer uniffi_swift_xet_rust_fn_method_filedownloadresult_destination(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_ERROR_CODE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_ERROR_CODE
+RustBuffer uniffi_swift_xet_rust_fn_method_filedownloadresult_error_code(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_ERROR_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_ERROR_MESSAGE
+RustBuffer uniffi_swift_xet_rust_fn_method_filedownloadresult_error_message(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_REPORT
+RustBuffer uniffi_swift_xet_rust_fn_method_filedownloadresult_report(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_REQUEST
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_REQUEST
+void*_Nonnull uniffi_swift_xet_rust_fn_method_filedownloadresult_request(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEDOWNLOADRESULT_STATUS
+RustBuffer uniffi_swift_xet_rust_fn_method_filedownloadresult_status(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_FILEMETADATA
 #define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_FILEMETADATA
 void*_Nonnull uniffi_swift_xet_rust_fn_clone_filemetadata(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -326,515 +693,2385 @@ RustBuffer uniffi_swift_xet_rust_fn_method_filemetadata_oid(void*_Nonnull ptr, R
 RustBuffer uniffi_swift_xet_rust_fn_method_filemetadata_path(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEMETADATA_SECURITY_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEMETADATA_SECURITY_STATUS
+RustBuffer uniffi_swift_xet_rust_fn_method_filemetadata_security_status(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEMETADATA_SIZE
 #define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEMETADATA_SIZE
 RustBuffer uniffi_swift_xet_rust_fn_method_filemetadata_size(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_REPOINFO
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_REPOINFO
-void*_Nonnull uniffi_swift_xet_rust_fn_clone_repoinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEMETADATA_XET_HASH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILEMETADATA_XET_HASH
+RustBuffer uniffi_swift_xet_rust_fn_method_filemetadata_xet_hash(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_REPOINFO
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_REPOINFO
-void uniffi_swift_xet_rust_fn_free_repoinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_FILESTREAM
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_FILESTREAM
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_filestream(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOINFO_FULL_NAME
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOINFO_FULL_NAME
-RustBuffer uniffi_swift_xet_rust_fn_method_repoinfo_full_name(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_FILESTREAM
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_FILESTREAM
+void uniffi_swift_xet_rust_fn_free_filestream(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOINFO_REPO_TYPE
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOINFO_REPO_TYPE
-RustBuffer uniffi_swift_xet_rust_fn_method_repoinfo_repo_type(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILESTREAM_POSITION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILESTREAM_POSITION
+uint64_t uniffi_swift_xet_rust_fn_method_filestream_position(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_XETCLIENT
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_XETCLIENT
-void*_Nonnull uniffi_swift_xet_rust_fn_clone_xetclient(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILESTREAM_READ_NEXT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILESTREAM_READ_NEXT
+RustBuffer uniffi_swift_xet_rust_fn_method_filestream_read_next(void*_Nonnull ptr, uint32_t max_bytes, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_XETCLIENT
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_XETCLIENT
-void uniffi_swift_xet_rust_fn_free_xetclient(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILESTREAM_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_FILESTREAM_SIZE
+uint64_t uniffi_swift_xet_rust_fn_method_filestream_size(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETCLIENT_NEW
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETCLIENT_NEW
-void*_Nonnull uniffi_swift_xet_rust_fn_constructor_xetclient_new(RustCallStatus *_Nonnull out_status
-    
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_MANAGEDMODEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_MANAGEDMODEL
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_managedmodel(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETCLIENT_WITH_TOKEN
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETCLIENT_WITH_TOKEN
-void*_Nonnull uniffi_swift_xet_rust_fn_constructor_xetclient_with_token(RustBuffer token, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_MANAGEDMODEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_MANAGEDMODEL
+void uniffi_swift_xet_rust_fn_free_managedmodel(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_CLEAR_CACHE
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_CLEAR_CACHE
-void uniffi_swift_xet_rust_fn_method_xetclient_clear_cache(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_DOWNLOADED_FILE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_DOWNLOADED_FILE_COUNT
+uint64_t uniffi_swift_xet_rust_fn_method_managedmodel_downloaded_file_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES
-RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_download_files(void*_Nonnull ptr, RustBuffer file_infos, RustBuffer destination_dir, void*_Nonnull jwt_info, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_FILE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_FILE_COUNT
+uint64_t uniffi_swift_xet_rust_fn_method_managedmodel_file_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CACHE_STATS
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CACHE_STATS
-void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_get_cache_stats(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_INSTALLED_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_INSTALLED_COMMIT
+RustBuffer uniffi_swift_xet_rust_fn_method_managedmodel_installed_commit(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CAS_JWT
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CAS_JWT
-void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_get_cas_jwt(void*_Nonnull ptr, RustBuffer repo, RustBuffer revision, int8_t is_upload, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_LAST_ERROR
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_LAST_ERROR
+RustBuffer uniffi_swift_xet_rust_fn_method_managedmodel_last_error(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_FILE_INFO
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_FILE_INFO
-RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_get_file_info(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer revision, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_LATEST_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_LATEST_COMMIT
+RustBuffer uniffi_swift_xet_rust_fn_method_managedmodel_latest_commit(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_REPO_INFO
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_REPO_INFO
-void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_get_repo_info(void*_Nonnull ptr, RustBuffer repo, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_LOCAL_PATH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_LOCAL_PATH
+RustBuffer uniffi_swift_xet_rust_fn_method_managedmodel_local_path(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_VERSION
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_VERSION
-RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_version(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_PATTERNS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_PATTERNS
+RustBuffer uniffi_swift_xet_rust_fn_method_managedmodel_patterns(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_XETFILEINFO
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_XETFILEINFO
-void*_Nonnull uniffi_swift_xet_rust_fn_clone_xetfileinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_REPO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_REPO
+RustBuffer uniffi_swift_xet_rust_fn_method_managedmodel_repo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_XETFILEINFO
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_XETFILEINFO
-void uniffi_swift_xet_rust_fn_free_xetfileinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_REVISION_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_REVISION_POLICY
+RustBuffer uniffi_swift_xet_rust_fn_method_managedmodel_revision_policy(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETFILEINFO_NEW
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETFILEINFO_NEW
-void*_Nonnull uniffi_swift_xet_rust_fn_constructor_xetfileinfo_new(RustBuffer hash, uint64_t file_size, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_MANAGEDMODEL_STATUS
+RustBuffer uniffi_swift_xet_rust_fn_method_managedmodel_status(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETFILEINFO_FILE_SIZE
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETFILEINFO_FILE_SIZE
-uint64_t uniffi_swift_xet_rust_fn_method_xetfileinfo_file_size(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_PROGRESSUPDATE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_PROGRESSUPDATE
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_progressupdate(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETFILEINFO_HASH
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETFILEINFO_HASH
-RustBuffer uniffi_swift_xet_rust_fn_method_xetfileinfo_hash(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_PROGRESSUPDATE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_PROGRESSUPDATE
+void uniffi_swift_xet_rust_fn_free_progressupdate(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_ALLOC
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_ALLOC
-RustBuffer ffi_swift_xet_rust_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_PROGRESSUPDATE_TOTAL_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_PROGRESSUPDATE_TOTAL_BYTES
+uint64_t uniffi_swift_xet_rust_fn_method_progressupdate_total_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_FROM_BYTES
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_FROM_BYTES
-RustBuffer ffi_swift_xet_rust_rustbuffer_from_bytes(ForeignBytes bytes, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_PROGRESSUPDATE_TOTAL_BYTES_COMPLETED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_PROGRESSUPDATE_TOTAL_BYTES_COMPLETED
+uint64_t uniffi_swift_xet_rust_fn_method_progressupdate_total_bytes_completed(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_FREE
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_FREE
-void ffi_swift_xet_rust_rustbuffer_free(RustBuffer buf, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_PROGRESSUPDATE_TOTAL_TRANSFER_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_PROGRESSUPDATE_TOTAL_TRANSFER_BYTES
+uint64_t uniffi_swift_xet_rust_fn_method_progressupdate_total_transfer_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_RESERVE
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_RESERVE
-RustBuffer ffi_swift_xet_rust_rustbuffer_reserve(RustBuffer buf, uint64_t additional, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_PROGRESSUPDATE_TOTAL_TRANSFER_BYTES_COMPLETED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_PROGRESSUPDATE_TOTAL_TRANSFER_BYTES_COMPLETED
+uint64_t uniffi_swift_xet_rust_fn_method_progressupdate_total_transfer_bytes_completed(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U8
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U8
-void ffi_swift_xet_rust_rust_future_poll_u8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_RATELIMITSTATUS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_RATELIMITSTATUS
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_ratelimitstatus(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U8
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U8
-void ffi_swift_xet_rust_rust_future_cancel_u8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_RATELIMITSTATUS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_RATELIMITSTATUS
+void uniffi_swift_xet_rust_fn_free_ratelimitstatus(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U8
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U8
-void ffi_swift_xet_rust_rust_future_free_u8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RATELIMITSTATUS_LIMIT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RATELIMITSTATUS_LIMIT
+RustBuffer uniffi_swift_xet_rust_fn_method_ratelimitstatus_limit(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U8
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U8
-uint8_t ffi_swift_xet_rust_rust_future_complete_u8(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RATELIMITSTATUS_REMAINING
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RATELIMITSTATUS_REMAINING
+RustBuffer uniffi_swift_xet_rust_fn_method_ratelimitstatus_remaining(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I8
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I8
-void ffi_swift_xet_rust_rust_future_poll_i8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RATELIMITSTATUS_RESET_AT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RATELIMITSTATUS_RESET_AT
+RustBuffer uniffi_swift_xet_rust_fn_method_ratelimitstatus_reset_at(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I8
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I8
-void ffi_swift_xet_rust_rust_future_cancel_i8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RATELIMITSTATUS_SECONDS_UNTIL_RESET
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RATELIMITSTATUS_SECONDS_UNTIL_RESET
+RustBuffer uniffi_swift_xet_rust_fn_method_ratelimitstatus_seconds_until_reset(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I8
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I8
-void ffi_swift_xet_rust_rust_future_free_i8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_RECOVEREDCOMMIT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_RECOVEREDCOMMIT
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_recoveredcommit(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I8
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I8
-int8_t ffi_swift_xet_rust_rust_future_complete_i8(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_RECOVEREDCOMMIT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_RECOVEREDCOMMIT
+void uniffi_swift_xet_rust_fn_free_recoveredcommit(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U16
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U16
-void ffi_swift_xet_rust_rust_future_poll_u16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_ACTION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_ACTION
+RustBuffer uniffi_swift_xet_rust_fn_method_recoveredcommit_action(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U16
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U16
-void ffi_swift_xet_rust_rust_future_cancel_u16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_COMMIT_OID
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_COMMIT_OID
+RustBuffer uniffi_swift_xet_rust_fn_method_recoveredcommit_commit_oid(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U16
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U16
-void ffi_swift_xet_rust_rust_future_free_u16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_ERROR
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_ERROR
+RustBuffer uniffi_swift_xet_rust_fn_method_recoveredcommit_error(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U16
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U16
-uint16_t ffi_swift_xet_rust_rust_future_complete_u16(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_PATHS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_PATHS
+RustBuffer uniffi_swift_xet_rust_fn_method_recoveredcommit_paths(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_REPO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_REPO
+RustBuffer uniffi_swift_xet_rust_fn_method_recoveredcommit_repo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_REVISION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_RECOVEREDCOMMIT_REVISION
+RustBuffer uniffi_swift_xet_rust_fn_method_recoveredcommit_revision(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_REPOINFO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_REPOINFO
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_repoinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_REPOINFO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_REPOINFO
+void uniffi_swift_xet_rust_fn_free_repoinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOINFO_FULL_NAME
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOINFO_FULL_NAME
+RustBuffer uniffi_swift_xet_rust_fn_method_repoinfo_full_name(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOINFO_REPO_TYPE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOINFO_REPO_TYPE
+RustBuffer uniffi_swift_xet_rust_fn_method_repoinfo_repo_type(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_REPOSIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_REPOSIZE
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_reposize(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_REPOSIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_REPOSIZE
+void uniffi_swift_xet_rust_fn_free_reposize(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOSIZE_ESTIMATED_DISK_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOSIZE_ESTIMATED_DISK_BYTES
+uint64_t uniffi_swift_xet_rust_fn_method_reposize_estimated_disk_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOSIZE_FILE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOSIZE_FILE_COUNT
+uint64_t uniffi_swift_xet_rust_fn_method_reposize_file_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOSIZE_LOGICAL_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_REPOSIZE_LOGICAL_BYTES
+uint64_t uniffi_swift_xet_rust_fn_method_reposize_logical_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_TRANSFERGROUP
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_TRANSFERGROUP
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_transfergroup(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_TRANSFERGROUP
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_TRANSFERGROUP
+void uniffi_swift_xet_rust_fn_free_transfergroup(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_TRANSFERGROUP_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_TRANSFERGROUP_NEW
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_transfergroup_new(RustBuffer progress, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_CANCEL
+void uniffi_swift_xet_rust_fn_method_transfergroup_cancel(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_CANCEL_WITH_REASON
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_CANCEL_WITH_REASON
+void uniffi_swift_xet_rust_fn_method_transfergroup_cancel_with_reason(void*_Nonnull ptr, RustBuffer reason, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_DOWNLOAD_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_DOWNLOAD_COUNT
+uint64_t uniffi_swift_xet_rust_fn_method_transfergroup_download_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_IS_CANCELLED
+int8_t uniffi_swift_xet_rust_fn_method_transfergroup_is_cancelled(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_IS_PAUSED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_IS_PAUSED
+int8_t uniffi_swift_xet_rust_fn_method_transfergroup_is_paused(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_PAUSE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_PAUSE
+void uniffi_swift_xet_rust_fn_method_transfergroup_pause(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_PROGRESS
+void*_Nonnull uniffi_swift_xet_rust_fn_method_transfergroup_progress(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_RESUME
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERGROUP_RESUME
+void uniffi_swift_xet_rust_fn_method_transfergroup_resume(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_TRANSFERREPORT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_TRANSFERREPORT
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_transferreport(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_TRANSFERREPORT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_TRANSFERREPORT
+void uniffi_swift_xet_rust_fn_free_transferreport(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_BYTES
+uint64_t uniffi_swift_xet_rust_fn_method_transferreport_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_CAS_ENDPOINT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_CAS_ENDPOINT
+RustBuffer uniffi_swift_xet_rust_fn_method_transferreport_cas_endpoint(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_CAS_ENDPOINT_LATENCIES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_CAS_ENDPOINT_LATENCIES
+RustBuffer uniffi_swift_xet_rust_fn_method_transferreport_cas_endpoint_latencies(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_ELAPSED_MS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_ELAPSED_MS
+uint64_t uniffi_swift_xet_rust_fn_method_transferreport_elapsed_ms(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_SERVED_STALE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_SERVED_STALE
+int8_t uniffi_swift_xet_rust_fn_method_transferreport_served_stale(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_SOURCE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_SOURCE
+RustBuffer uniffi_swift_xet_rust_fn_method_transferreport_source(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_VERIFICATION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_VERIFICATION
+RustBuffer uniffi_swift_xet_rust_fn_method_transferreport_verification(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_VERIFICATION_MS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TRANSFERREPORT_VERIFICATION_MS
+uint64_t uniffi_swift_xet_rust_fn_method_transferreport_verification_ms(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_TREEITERATOR
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_TREEITERATOR
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_treeiterator(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_TREEITERATOR
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_TREEITERATOR
+void uniffi_swift_xet_rust_fn_free_treeiterator(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TREEITERATOR_IS_FINISHED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TREEITERATOR_IS_FINISHED
+int8_t uniffi_swift_xet_rust_fn_method_treeiterator_is_finished(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TREEITERATOR_NEXT_PAGE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TREEITERATOR_NEXT_PAGE
+RustBuffer uniffi_swift_xet_rust_fn_method_treeiterator_next_page(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_TREELISTING
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_TREELISTING
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_treelisting(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_TREELISTING
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_TREELISTING
+void uniffi_swift_xet_rust_fn_free_treelisting(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TREELISTING_FILES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TREELISTING_FILES
+RustBuffer uniffi_swift_xet_rust_fn_method_treelisting_files(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TREELISTING_IS_COMPLETE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TREELISTING_IS_COMPLETE
+int8_t uniffi_swift_xet_rust_fn_method_treelisting_is_complete(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TREELISTING_UNLISTED_PATHS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_TREELISTING_UNLISTED_PATHS
+RustBuffer uniffi_swift_xet_rust_fn_method_treelisting_unlisted_paths(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_UPLOADSESSION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_UPLOADSESSION
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_uploadsession(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_UPLOADSESSION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_UPLOADSESSION
+void uniffi_swift_xet_rust_fn_free_uploadsession(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_UPLOADSESSION_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_UPLOADSESSION_NEW
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_uploadsession_new(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_UPLOADSESSION_STAGED_PATHS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_UPLOADSESSION_STAGED_PATHS
+RustBuffer uniffi_swift_xet_rust_fn_method_uploadsession_staged_paths(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_XETCLIENT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_XETCLIENT
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_xetclient(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_XETCLIENT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_XETCLIENT
+void uniffi_swift_xet_rust_fn_free_xetclient(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETCLIENT_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETCLIENT_NEW
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_xetclient_new(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETCLIENT_WITH_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETCLIENT_WITH_CONFIG
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_xetclient_with_config(RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETCLIENT_WITH_TOKEN
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETCLIENT_WITH_TOKEN
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_xetclient_with_token(RustBuffer token, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_CAN_ACCESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_CAN_ACCESS
+int8_t uniffi_swift_xet_rust_fn_method_xetclient_can_access(void*_Nonnull ptr, RustBuffer repo, RustBuffer operation, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_CLEAR_CACHE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_CLEAR_CACHE
+void uniffi_swift_xet_rust_fn_method_xetclient_clear_cache(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_CLEAR_CACHE_AREA
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_CLEAR_CACHE_AREA
+void uniffi_swift_xet_rust_fn_method_xetclient_clear_cache_area(void*_Nonnull ptr, RustBuffer area, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DELETE_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DELETE_FILE
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_delete_file(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer revision, RustBuffer commit_message, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_BY_HASH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_BY_HASH
+void uniffi_swift_xet_rust_fn_method_xetclient_download_by_hash(void*_Nonnull ptr, RustBuffer xet_hash, uint64_t size, RustBuffer destination, void*_Nonnull jwt_info, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_download_file(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer destination, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_ASYNC
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_file_async(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer destination, RustBuffer revision
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_IN_GROUP
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_IN_GROUP
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_download_file_in_group(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer destination, RustBuffer revision, void*_Nonnull group, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_IN_GROUP_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_IN_GROUP_ASYNC
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_file_in_group_async(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer destination, RustBuffer revision, void*_Nonnull group
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_HANDLE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_HANDLE
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_download_file_with_handle(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer destination, RustBuffer revision, RustBuffer progress, void*_Nonnull handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_HANDLE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_HANDLE_ASYNC
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_file_with_handle_async(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer destination, RustBuffer revision, RustBuffer progress, void*_Nonnull handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_PROGRESS
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_download_file_with_progress(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer destination, RustBuffer revision, RustBuffer progress, RustBuffer cancellation, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_PROGRESS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_PROGRESS_ASYNC
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_file_with_progress_async(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer destination, RustBuffer revision, RustBuffer progress, RustBuffer cancellation
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_download_files(void*_Nonnull ptr, RustBuffer file_infos, RustBuffer destination_dir, void*_Nonnull jwt_info, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch(void*_Nonnull ptr, RustBuffer requests, RustBuffer allow_patterns, RustBuffer ignore_patterns, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_ASYNC
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_async(void*_Nonnull ptr, RustBuffer requests, RustBuffer allow_patterns, RustBuffer ignore_patterns
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_DETAILED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_DETAILED
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_detailed(void*_Nonnull ptr, RustBuffer requests, RustBuffer allow_patterns, RustBuffer ignore_patterns, int8_t continue_on_error, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_DETAILED_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_DETAILED_ASYNC
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_detailed_async(void*_Nonnull ptr, RustBuffer requests, RustBuffer allow_patterns, RustBuffer ignore_patterns, int8_t continue_on_error
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_RESULTS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_RESULTS
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_with_results(void*_Nonnull ptr, RustBuffer requests, RustBuffer allow_patterns, RustBuffer ignore_patterns, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_RESULTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_RESULTS_ASYNC
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_with_results_async(void*_Nonnull ptr, RustBuffer requests, RustBuffer allow_patterns, RustBuffer ignore_patterns
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_TEMPLATE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_TEMPLATE
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_download_files_batch_with_template(void*_Nonnull ptr, RustBuffer requests, RustBuffer destination_root, RustBuffer template, RustBuffer allow_patterns, RustBuffer ignore_patterns, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FOLDER
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_download_folder(void*_Nonnull ptr, RustBuffer repo, RustBuffer folder_path, RustBuffer destination_dir, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FOLDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_FOLDER_ASYNC
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_folder_async(void*_Nonnull ptr, RustBuffer repo, RustBuffer folder_path, RustBuffer destination_dir, RustBuffer revision
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_REPO_ARCHIVE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_REPO_ARCHIVE
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_repo_archive(void*_Nonnull ptr, RustBuffer repo, RustBuffer revision, RustBuffer destination, RustBuffer format, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_REPO_ARCHIVE_WITH_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_REPO_ARCHIVE_WITH_PROGRESS
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_repo_archive_with_progress(void*_Nonnull ptr, RustBuffer repo, RustBuffer revision, RustBuffer destination, RustBuffer format, RustBuffer progress, RustBuffer cancellation, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_TO_CACHE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_TO_CACHE
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_download_to_cache(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_TO_CACHE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_DOWNLOAD_TO_CACHE_ASYNC
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_download_to_cache_async(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer revision
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_ESTIMATE_UPLOAD
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_ESTIMATE_UPLOAD
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_estimate_upload(void*_Nonnull ptr, RustBuffer repo, RustBuffer local_paths, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_FILTER_MISSING
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_FILTER_MISSING
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_filter_missing(void*_Nonnull ptr, RustBuffer requests, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CACHE_STATS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CACHE_STATS
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_get_cache_stats(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CACHED_PATH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CACHED_PATH
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_get_cached_path(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CAS_JWT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CAS_JWT
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_get_cas_jwt(void*_Nonnull ptr, RustBuffer repo, RustBuffer revision, int8_t is_upload, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CLOCK_SKEW_SECS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_CLOCK_SKEW_SECS
+int64_t uniffi_swift_xet_rust_fn_method_xetclient_get_clock_skew_secs(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_FILE_INFO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_FILE_INFO
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_get_file_info(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_PATHS_INFO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_PATHS_INFO
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_get_paths_info(void*_Nonnull ptr, RustBuffer repo, RustBuffer paths, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_RATE_LIMIT_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_RATE_LIMIT_STATUS
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_get_rate_limit_status(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_REPO_INFO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_REPO_INFO
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_get_repo_info(void*_Nonnull ptr, RustBuffer repo, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_REPO_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_REPO_SIZE
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_get_repo_size(void*_Nonnull ptr, RustBuffer repo, RustBuffer revision, RustBuffer patterns, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_USAGE_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_USAGE_REPORT
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_get_usage_report(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_XET_POINTER_DETAILS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_GET_XET_POINTER_DETAILS
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_get_xet_pointer_details(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_LIST_CACHED_REVISIONS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_LIST_CACHED_REVISIONS
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_list_cached_revisions(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_LIST_FILES_RECURSIVE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_LIST_FILES_RECURSIVE
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_list_files_recursive(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_LIST_MANAGED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_LIST_MANAGED
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_list_managed(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_MOVE_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_MOVE_FILE
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_move_file(void*_Nonnull ptr, RustBuffer repo, RustBuffer from_path, RustBuffer to_path, RustBuffer revision, RustBuffer commit_message, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_OPEN_FILE_STREAM
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_OPEN_FILE_STREAM
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_open_file_stream(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_PREPARE_FOR_TERMINATION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_PREPARE_FOR_TERMINATION
+int8_t uniffi_swift_xet_rust_fn_method_xetclient_prepare_for_termination(void*_Nonnull ptr, uint64_t timeout_ms, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_PREUPLOAD
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_PREUPLOAD
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_preupload(void*_Nonnull ptr, RustBuffer repo, RustBuffer files, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_PRUNE_CACHE_AREA
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_PRUNE_CACHE_AREA
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_prune_cache_area(void*_Nonnull ptr, RustBuffer area, uint64_t max_size_bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_PUBLISH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_PUBLISH
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_publish(void*_Nonnull ptr, void*_Nonnull session, RustBuffer repo, RustBuffer branch, RustBuffer message, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_RECORD_SESSION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_RECORD_SESSION
+void uniffi_swift_xet_rust_fn_method_xetclient_record_session(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_RECOVER_PENDING_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_RECOVER_PENDING_COMMITS
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_recover_pending_commits(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_REGISTER_MANAGED_MODEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_REGISTER_MANAGED_MODEL
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_register_managed_model(void*_Nonnull ptr, RustBuffer repo, RustBuffer revision_policy, RustBuffer patterns, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_RESET_USAGE_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_RESET_USAGE_REPORT
+void uniffi_swift_xet_rust_fn_method_xetclient_reset_usage_report(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SET_DOWNLOAD_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SET_DOWNLOAD_POLICY
+void uniffi_swift_xet_rust_fn_method_xetclient_set_download_policy(void*_Nonnull ptr, RustBuffer policy, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SET_EVENT_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SET_EVENT_LISTENER
+void uniffi_swift_xet_rust_fn_method_xetclient_set_event_listener(void*_Nonnull ptr, RustBuffer listener, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SET_MAX_DOWNLOAD_BYTES_PER_SECOND
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SET_MAX_DOWNLOAD_BYTES_PER_SECOND
+void uniffi_swift_xet_rust_fn_method_xetclient_set_max_download_bytes_per_second(void*_Nonnull ptr, RustBuffer bytes_per_second, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SET_QUIET
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SET_QUIET
+void uniffi_swift_xet_rust_fn_method_xetclient_set_quiet(void*_Nonnull ptr, int8_t quiet, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SET_USAGE_LABEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SET_USAGE_LABEL
+void uniffi_swift_xet_rust_fn_method_xetclient_set_usage_label(void*_Nonnull ptr, RustBuffer label, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SNAPSHOT_DOWNLOAD
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SNAPSHOT_DOWNLOAD
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_snapshot_download(void*_Nonnull ptr, RustBuffer repo, RustBuffer revision, RustBuffer destination_dir, RustBuffer allow_patterns, RustBuffer ignore_patterns, RustBuffer destination_template, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SNAPSHOT_DOWNLOAD_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_SNAPSHOT_DOWNLOAD_ASYNC
+uint64_t uniffi_swift_xet_rust_fn_method_xetclient_snapshot_download_async(void*_Nonnull ptr, RustBuffer repo, RustBuffer revision, RustBuffer destination_dir, RustBuffer allow_patterns, RustBuffer ignore_patterns, RustBuffer destination_template
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_STAGE_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_STAGE_FILE
+void uniffi_swift_xet_rust_fn_method_xetclient_stage_file(void*_Nonnull ptr, void*_Nonnull session, RustBuffer local_path, RustBuffer repo_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_START_CACHE_MAINTENANCE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_START_CACHE_MAINTENANCE
+void uniffi_swift_xet_rust_fn_method_xetclient_start_cache_maintenance(void*_Nonnull ptr, RustBuffer policy, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_STOP_CACHE_MAINTENANCE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_STOP_CACHE_MAINTENANCE
+void uniffi_swift_xet_rust_fn_method_xetclient_stop_cache_maintenance(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_STOP_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_STOP_RECORDING
+void uniffi_swift_xet_rust_fn_method_xetclient_stop_recording(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_TREE_ITERATOR
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_TREE_ITERATOR
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_tree_iterator(void*_Nonnull ptr, RustBuffer repo, RustBuffer path, RustBuffer revision, int8_t recursive, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPDATE_ALL_MANAGED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPDATE_ALL_MANAGED
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_update_all_managed(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_BYTES
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_upload_bytes(void*_Nonnull ptr, RustBuffer repo, RustBuffer data, RustBuffer path_in_repo, RustBuffer commit_message, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_FILE
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_upload_file(void*_Nonnull ptr, RustBuffer repo, RustBuffer local_path, RustBuffer path_in_repo, RustBuffer revision, RustBuffer commit_message, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_FILE_WITH_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_FILE_WITH_PROGRESS
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_upload_file_with_progress(void*_Nonnull ptr, RustBuffer repo, RustBuffer local_path, RustBuffer path_in_repo, RustBuffer revision, RustBuffer commit_message, RustBuffer progress, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_FILES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_FILES
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_upload_files(void*_Nonnull ptr, RustBuffer repo, RustBuffer requests, RustBuffer revision, RustBuffer commit_message, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_FILES_WITH_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_FILES_WITH_PROGRESS
+void*_Nonnull uniffi_swift_xet_rust_fn_method_xetclient_upload_files_with_progress(void*_Nonnull ptr, RustBuffer repo, RustBuffer requests, RustBuffer revision, RustBuffer commit_message, RustBuffer progress, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_UPLOAD_FOLDER
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_upload_folder(void*_Nonnull ptr, RustBuffer repo, RustBuffer local_dir, RustBuffer path_in_repo, RustBuffer revision, RustBuffer allow_patterns, RustBuffer ignore_patterns, RustBuffer commit_message, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_VERSION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETCLIENT_VERSION
+RustBuffer uniffi_swift_xet_rust_fn_method_xetclient_version(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_XETFILEINFO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_XETFILEINFO
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_xetfileinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_XETFILEINFO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_XETFILEINFO
+void uniffi_swift_xet_rust_fn_free_xetfileinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETFILEINFO_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CONSTRUCTOR_XETFILEINFO_NEW
+void*_Nonnull uniffi_swift_xet_rust_fn_constructor_xetfileinfo_new(RustBuffer hash, uint64_t file_size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETFILEINFO_FILE_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETFILEINFO_FILE_SIZE
+uint64_t uniffi_swift_xet_rust_fn_method_xetfileinfo_file_size(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETFILEINFO_HASH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETFILEINFO_HASH
+RustBuffer uniffi_swift_xet_rust_fn_method_xetfileinfo_hash(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_XETPOINTERDETAILS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_CLONE_XETPOINTERDETAILS
+void*_Nonnull uniffi_swift_xet_rust_fn_clone_xetpointerdetails(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_XETPOINTERDETAILS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FREE_XETPOINTERDETAILS
+void uniffi_swift_xet_rust_fn_free_xetpointerdetails(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETPOINTERDETAILS_CAS_ENDPOINT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETPOINTERDETAILS_CAS_ENDPOINT
+RustBuffer uniffi_swift_xet_rust_fn_method_xetpointerdetails_cas_endpoint(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETPOINTERDETAILS_REFRESH_ROUTE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETPOINTERDETAILS_REFRESH_ROUTE
+RustBuffer uniffi_swift_xet_rust_fn_method_xetpointerdetails_refresh_route(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETPOINTERDETAILS_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETPOINTERDETAILS_SIZE
+uint64_t uniffi_swift_xet_rust_fn_method_xetpointerdetails_size(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETPOINTERDETAILS_XET_HASH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_METHOD_XETPOINTERDETAILS_XET_HASH
+RustBuffer uniffi_swift_xet_rust_fn_method_xetpointerdetails_xet_hash(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_INIT_CALLBACK_VTABLE_DOWNLOADPOLICY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_INIT_CALLBACK_VTABLE_DOWNLOADPOLICY
+void uniffi_swift_xet_rust_fn_init_callback_vtable_downloadpolicy(const UniffiVTableCallbackInterfaceDownloadPolicy* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_INIT_CALLBACK_VTABLE_PROGRESSCALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_INIT_CALLBACK_VTABLE_PROGRESSCALLBACK
+void uniffi_swift_xet_rust_fn_init_callback_vtable_progresscallback(const UniffiVTableCallbackInterfaceProgressCallback* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_INIT_CALLBACK_VTABLE_XETEVENTLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_INIT_CALLBACK_VTABLE_XETEVENTLISTENER
+void uniffi_swift_xet_rust_fn_init_callback_vtable_xeteventlistener(const UniffiVTableCallbackInterfaceXetEventListener* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FUNC_ERROR_CODE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FUNC_ERROR_CODE
+RustBuffer uniffi_swift_xet_rust_fn_func_error_code(RustBuffer error, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FUNC_FORMAT_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FUNC_FORMAT_BYTES
+RustBuffer uniffi_swift_xet_rust_fn_func_format_bytes(uint64_t bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FUNC_FORMAT_ETA
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FUNC_FORMAT_ETA
+RustBuffer uniffi_swift_xet_rust_fn_func_format_eta(uint64_t remaining_bytes, double bytes_per_second, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FUNC_FORMAT_RATE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FUNC_FORMAT_RATE
+RustBuffer uniffi_swift_xet_rust_fn_func_format_rate(double bytes_per_second, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FUNC_REPLAY_FAULTS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_FN_FUNC_REPLAY_FAULTS
+RustBuffer uniffi_swift_xet_rust_fn_func_replay_faults(RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_ALLOC
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_ALLOC
+RustBuffer ffi_swift_xet_rust_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_FROM_BYTES
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_FROM_BYTES
+RustBuffer ffi_swift_xet_rust_rustbuffer_from_bytes(ForeignBytes bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_FREE
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_FREE
+void ffi_swift_xet_rust_rustbuffer_free(RustBuffer buf, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_RESERVE
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUSTBUFFER_RESERVE
+RustBuffer ffi_swift_xet_rust_rustbuffer_reserve(RustBuffer buf, uint64_t additional, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U8
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U8
+void ffi_swift_xet_rust_rust_future_poll_u8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U8
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U8
+void ffi_swift_xet_rust_rust_future_cancel_u8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U8
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U8
+void ffi_swift_xet_rust_rust_future_free_u8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U8
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U8
+uint8_t ffi_swift_xet_rust_rust_future_complete_u8(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I8
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I8
+void ffi_swift_xet_rust_rust_future_poll_i8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I8
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I8
+void ffi_swift_xet_rust_rust_future_cancel_i8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I8
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I8
+void ffi_swift_xet_rust_rust_future_free_i8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I8
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I8
+int8_t ffi_swift_xet_rust_rust_future_complete_i8(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U16
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U16
+void ffi_swift_xet_rust_rust_future_poll_u16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U16
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U16
+void ffi_swift_xet_rust_rust_future_cancel_u16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U16
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U16
+void ffi_swift_xet_rust_rust_future_free_u16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U16
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U16
+uint16_t ffi_swift_xet_rust_rust_future_complete_u16(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I16
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I16
+void ffi_swift_xet_rust_rust_future_poll_i16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I16
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I16
+void ffi_swift_xet_rust_rust_future_cancel_i16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I16
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I16
+void ffi_swift_xet_rust_rust_future_free_i16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I16
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I16
+int16_t ffi_swift_xet_rust_rust_future_complete_i16(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U32
+void ffi_swift_xet_rust_rust_future_poll_u32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U32
+void ffi_swift_xet_rust_rust_future_cancel_u32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U32
+void ffi_swift_xet_rust_rust_future_free_u32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U32
+uint32_t ffi_swift_xet_rust_rust_future_complete_u32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I32
+void ffi_swift_xet_rust_rust_future_poll_i32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I32
+void ffi_swift_xet_rust_rust_future_cancel_i32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I32
+void ffi_swift_xet_rust_rust_future_free_i32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I32
+int32_t ffi_swift_xet_rust_rust_future_complete_i32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U64
+void ffi_swift_xet_rust_rust_future_poll_u64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U64
+void ffi_swift_xet_rust_rust_future_cancel_u64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U64
+void ffi_swift_xet_rust_rust_future_free_u64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U64
+uint64_t ffi_swift_xet_rust_rust_future_complete_u64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I64
+void ffi_swift_xet_rust_rust_future_poll_i64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I64
+void ffi_swift_xet_rust_rust_future_cancel_i64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I64
+void ffi_swift_xet_rust_rust_future_free_i64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I64
+int64_t ffi_swift_xet_rust_rust_future_complete_i64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_F32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_F32
+void ffi_swift_xet_rust_rust_future_poll_f32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_F32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_F32
+void ffi_swift_xet_rust_rust_future_cancel_f32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_F32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_F32
+void ffi_swift_xet_rust_rust_future_free_f32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_F32
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_F32
+float ffi_swift_xet_rust_rust_future_complete_f32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_F64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_F64
+void ffi_swift_xet_rust_rust_future_poll_f64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_F64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_F64
+void ffi_swift_xet_rust_rust_future_cancel_f64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_F64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_F64
+void ffi_swift_xet_rust_rust_future_free_f64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_F64
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_F64
+double ffi_swift_xet_rust_rust_future_complete_f64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_POINTER
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_POINTER
+void ffi_swift_xet_rust_rust_future_poll_pointer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_POINTER
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_POINTER
+void ffi_swift_xet_rust_rust_future_cancel_pointer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_POINTER
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_POINTER
+void ffi_swift_xet_rust_rust_future_free_pointer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_POINTER
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_POINTER
+void*_Nonnull ffi_swift_xet_rust_rust_future_complete_pointer(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_RUST_BUFFER
+void ffi_swift_xet_rust_rust_future_poll_rust_buffer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_RUST_BUFFER
+void ffi_swift_xet_rust_rust_future_cancel_rust_buffer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_RUST_BUFFER
+void ffi_swift_xet_rust_rust_future_free_rust_buffer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_RUST_BUFFER
+RustBuffer ffi_swift_xet_rust_rust_future_complete_rust_buffer(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_VOID
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_VOID
+void ffi_swift_xet_rust_rust_future_poll_void(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_VOID
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_VOID
+void ffi_swift_xet_rust_rust_future_cancel_void(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_VOID
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_VOID
+void ffi_swift_xet_rust_rust_future_free_void(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_VOID
+#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_VOID
+void ffi_swift_xet_rust_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_FUNC_ERROR_CODE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_FUNC_ERROR_CODE
+uint16_t uniffi_swift_xet_rust_checksum_func_error_code(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_FUNC_FORMAT_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_FUNC_FORMAT_BYTES
+uint16_t uniffi_swift_xet_rust_checksum_func_format_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_FUNC_FORMAT_ETA
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_FUNC_FORMAT_ETA
+uint16_t uniffi_swift_xet_rust_checksum_func_format_eta(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_FUNC_FORMAT_RATE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_FUNC_FORMAT_RATE
+uint16_t uniffi_swift_xet_rust_checksum_func_format_rate(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_FUNC_REPLAY_FAULTS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_FUNC_REPLAY_FAULTS
+uint16_t uniffi_swift_xet_rust_checksum_func_replay_faults(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHDOWNLOADRESULT_DOWNLOADED_PATHS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHDOWNLOADRESULT_DOWNLOADED_PATHS
+uint16_t uniffi_swift_xet_rust_checksum_method_batchdownloadresult_downloaded_paths(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHDOWNLOADRESULT_FAILED_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHDOWNLOADRESULT_FAILED_COUNT
+uint16_t uniffi_swift_xet_rust_checksum_method_batchdownloadresult_failed_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHDOWNLOADRESULT_RESULTS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHDOWNLOADRESULT_RESULTS
+uint16_t uniffi_swift_xet_rust_checksum_method_batchdownloadresult_results(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHDOWNLOADRESULT_SKIPPED_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHDOWNLOADRESULT_SKIPPED_COUNT
+uint16_t uniffi_swift_xet_rust_checksum_method_batchdownloadresult_skipped_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHDOWNLOADRESULT_UNAUTHORIZED_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHDOWNLOADRESULT_UNAUTHORIZED_COUNT
+uint16_t uniffi_swift_xet_rust_checksum_method_batchdownloadresult_unauthorized_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHUPLOADRESULT_CHUNKING
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHUPLOADRESULT_CHUNKING
+uint16_t uniffi_swift_xet_rust_checksum_method_batchuploadresult_chunking(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHUPLOADRESULT_COMMIT_OID
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHUPLOADRESULT_COMMIT_OID
+uint16_t uniffi_swift_xet_rust_checksum_method_batchuploadresult_commit_oid(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHUPLOADRESULT_FILES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_BATCHUPLOADRESULT_FILES
+uint16_t uniffi_swift_xet_rust_checksum_method_batchuploadresult_files(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEAREASTATS_FILE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEAREASTATS_FILE_COUNT
+uint16_t uniffi_swift_xet_rust_checksum_method_cacheareastats_file_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEAREASTATS_TOTAL_SIZE_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEAREASTATS_TOTAL_SIZE_BYTES
+uint16_t uniffi_swift_xet_rust_checksum_method_cacheareastats_total_size_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_AREA_STATS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_AREA_STATS
+uint16_t uniffi_swift_xet_rust_checksum_method_cachestats_area_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_DEDUPLICATED_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_DEDUPLICATED_BYTES
+uint16_t uniffi_swift_xet_rust_checksum_method_cachestats_deduplicated_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_FILE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_FILE_COUNT
+uint16_t uniffi_swift_xet_rust_checksum_method_cachestats_file_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_TOTAL_SIZE_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_TOTAL_SIZE_BYTES
+uint16_t uniffi_swift_xet_rust_checksum_method_cachestats_total_size_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEDREVISION_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEDREVISION_COMMIT
+uint16_t uniffi_swift_xet_rust_checksum_method_cachedrevision_commit(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEDREVISION_LOCAL_PATH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEDREVISION_LOCAL_PATH
+uint16_t uniffi_swift_xet_rust_checksum_method_cachedrevision_local_path(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEDREVISION_REFS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEDREVISION_REFS
+uint16_t uniffi_swift_xet_rust_checksum_method_cachedrevision_refs(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEDREVISION_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHEDREVISION_SIZE
+uint16_t uniffi_swift_xet_rust_checksum_method_cachedrevision_size(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CANCELLATIONTOKEN_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CANCELLATIONTOKEN_CANCEL
+uint16_t uniffi_swift_xet_rust_checksum_method_cancellationtoken_cancel(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CANCELLATIONTOKEN_CANCEL_WITH_REASON
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CANCELLATIONTOKEN_CANCEL_WITH_REASON
+uint16_t uniffi_swift_xet_rust_checksum_method_cancellationtoken_cancel_with_reason(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
+uint16_t uniffi_swift_xet_rust_checksum_method_cancellationtoken_is_cancelled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CANCELLATIONTOKEN_REASON
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CANCELLATIONTOKEN_REASON
+uint16_t uniffi_swift_xet_rust_checksum_method_cancellationtoken_reason(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_ACCESS_TOKEN
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_ACCESS_TOKEN
+uint16_t uniffi_swift_xet_rust_checksum_method_casjwtinfo_access_token(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_CAS_URL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_CAS_URL
+uint16_t uniffi_swift_xet_rust_checksum_method_casjwtinfo_cas_url(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_CLAIMS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_CLAIMS
+uint16_t uniffi_swift_xet_rust_checksum_method_casjwtinfo_claims(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_ENDPOINT_LATENCIES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_ENDPOINT_LATENCIES
+uint16_t uniffi_swift_xet_rust_checksum_method_casjwtinfo_endpoint_latencies(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_EXP
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_EXP
+uint16_t uniffi_swift_xet_rust_checksum_method_casjwtinfo_exp(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_CANCEL
+uint16_t uniffi_swift_xet_rust_checksum_method_downloadhandle_cancel(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_CANCEL_WITH_REASON
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_CANCEL_WITH_REASON
+uint16_t uniffi_swift_xet_rust_checksum_method_downloadhandle_cancel_with_reason(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_IS_CANCELLED
+uint16_t uniffi_swift_xet_rust_checksum_method_downloadhandle_is_cancelled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_IS_PAUSED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_IS_PAUSED
+uint16_t uniffi_swift_xet_rust_checksum_method_downloadhandle_is_paused(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_PAUSE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_PAUSE
+uint16_t uniffi_swift_xet_rust_checksum_method_downloadhandle_pause(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_RESUME
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADHANDLE_RESUME
+uint16_t uniffi_swift_xet_rust_checksum_method_downloadhandle_resume(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_DESTINATION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_DESTINATION
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadrequest_destination(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_PATH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_PATH
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadrequest_path(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_PRIORITY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_PRIORITY
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadrequest_priority(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_REPO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_REPO
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadrequest_repo(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_REVISION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_REVISION
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadrequest_revision(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_SET_PRIORITY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADREQUEST_SET_PRIORITY
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadrequest_set_priority(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_DESTINATION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_DESTINATION
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadresult_destination(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_ERROR_CODE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_ERROR_CODE
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadresult_error_code(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_ERROR_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_ERROR_MESSAGE
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadresult_error_message(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_REPORT
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadresult_report(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_REQUEST
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_REQUEST
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadresult_request(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEDOWNLOADRESULT_STATUS
+uint16_t uniffi_swift_xet_rust_checksum_method_filedownloadresult_status(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_ENTRY_TYPE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_ENTRY_TYPE
+uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_entry_type(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_HASH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_HASH
+uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_hash(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_OID
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_OID
+uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_oid(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_PATH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_PATH
+uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_path(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_SECURITY_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_SECURITY_STATUS
+uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_security_status(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_SIZE
+uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_size(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_XET_HASH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_XET_HASH
+uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_xet_hash(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILESTREAM_POSITION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILESTREAM_POSITION
+uint16_t uniffi_swift_xet_rust_checksum_method_filestream_position(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILESTREAM_READ_NEXT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILESTREAM_READ_NEXT
+uint16_t uniffi_swift_xet_rust_checksum_method_filestream_read_next(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILESTREAM_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILESTREAM_SIZE
+uint16_t uniffi_swift_xet_rust_checksum_method_filestream_size(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_DOWNLOADED_FILE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_DOWNLOADED_FILE_COUNT
+uint16_t uniffi_swift_xet_rust_checksum_method_managedmodel_downloaded_file_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_FILE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_FILE_COUNT
+uint16_t uniffi_swift_xet_rust_checksum_method_managedmodel_file_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_INSTALLED_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_INSTALLED_COMMIT
+uint16_t uniffi_swift_xet_rust_checksum_method_managedmodel_installed_commit(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_LAST_ERROR
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_LAST_ERROR
+uint16_t uniffi_swift_xet_rust_checksum_method_managedmodel_last_error(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_LATEST_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_LATEST_COMMIT
+uint16_t uniffi_swift_xet_rust_checksum_method_managedmodel_latest_commit(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_LOCAL_PATH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_LOCAL_PATH
+uint16_t uniffi_swift_xet_rust_checksum_method_managedmodel_local_path(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_PATTERNS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_PATTERNS
+uint16_t uniffi_swift_xet_rust_checksum_method_managedmodel_patterns(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_REPO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_REPO
+uint16_t uniffi_swift_xet_rust_checksum_method_managedmodel_repo(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_REVISION_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_REVISION_POLICY
+uint16_t uniffi_swift_xet_rust_checksum_method_managedmodel_revision_policy(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_MANAGEDMODEL_STATUS
+uint16_t uniffi_swift_xet_rust_checksum_method_managedmodel_status(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_PROGRESSUPDATE_TOTAL_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_PROGRESSUPDATE_TOTAL_BYTES
+uint16_t uniffi_swift_xet_rust_checksum_method_progressupdate_total_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_PROGRESSUPDATE_TOTAL_BYTES_COMPLETED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_PROGRESSUPDATE_TOTAL_BYTES_COMPLETED
+uint16_t uniffi_swift_xet_rust_checksum_method_progressupdate_total_bytes_completed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_PROGRESSUPDATE_TOTAL_TRANSFER_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_PROGRESSUPDATE_TOTAL_TRANSFER_BYTES
+uint16_t uniffi_swift_xet_rust_checksum_method_progressupdate_total_transfer_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_PROGRESSUPDATE_TOTAL_TRANSFER_BYTES_COMPLETED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_PROGRESSUPDATE_TOTAL_TRANSFER_BYTES_COMPLETED
+uint16_t uniffi_swift_xet_rust_checksum_method_progressupdate_total_transfer_bytes_completed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RATELIMITSTATUS_LIMIT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RATELIMITSTATUS_LIMIT
+uint16_t uniffi_swift_xet_rust_checksum_method_ratelimitstatus_limit(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RATELIMITSTATUS_REMAINING
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RATELIMITSTATUS_REMAINING
+uint16_t uniffi_swift_xet_rust_checksum_method_ratelimitstatus_remaining(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RATELIMITSTATUS_RESET_AT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RATELIMITSTATUS_RESET_AT
+uint16_t uniffi_swift_xet_rust_checksum_method_ratelimitstatus_reset_at(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RATELIMITSTATUS_SECONDS_UNTIL_RESET
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RATELIMITSTATUS_SECONDS_UNTIL_RESET
+uint16_t uniffi_swift_xet_rust_checksum_method_ratelimitstatus_seconds_until_reset(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_ACTION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_ACTION
+uint16_t uniffi_swift_xet_rust_checksum_method_recoveredcommit_action(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_COMMIT_OID
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_COMMIT_OID
+uint16_t uniffi_swift_xet_rust_checksum_method_recoveredcommit_commit_oid(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_ERROR
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_ERROR
+uint16_t uniffi_swift_xet_rust_checksum_method_recoveredcommit_error(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_PATHS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_PATHS
+uint16_t uniffi_swift_xet_rust_checksum_method_recoveredcommit_paths(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_REPO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_REPO
+uint16_t uniffi_swift_xet_rust_checksum_method_recoveredcommit_repo(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_REVISION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_RECOVEREDCOMMIT_REVISION
+uint16_t uniffi_swift_xet_rust_checksum_method_recoveredcommit_revision(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOINFO_FULL_NAME
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOINFO_FULL_NAME
+uint16_t uniffi_swift_xet_rust_checksum_method_repoinfo_full_name(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOINFO_REPO_TYPE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOINFO_REPO_TYPE
+uint16_t uniffi_swift_xet_rust_checksum_method_repoinfo_repo_type(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOSIZE_ESTIMATED_DISK_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOSIZE_ESTIMATED_DISK_BYTES
+uint16_t uniffi_swift_xet_rust_checksum_method_reposize_estimated_disk_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOSIZE_FILE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOSIZE_FILE_COUNT
+uint16_t uniffi_swift_xet_rust_checksum_method_reposize_file_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOSIZE_LOGICAL_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOSIZE_LOGICAL_BYTES
+uint16_t uniffi_swift_xet_rust_checksum_method_reposize_logical_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_CANCEL
+uint16_t uniffi_swift_xet_rust_checksum_method_transfergroup_cancel(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_CANCEL_WITH_REASON
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_CANCEL_WITH_REASON
+uint16_t uniffi_swift_xet_rust_checksum_method_transfergroup_cancel_with_reason(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_DOWNLOAD_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_DOWNLOAD_COUNT
+uint16_t uniffi_swift_xet_rust_checksum_method_transfergroup_download_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_IS_CANCELLED
+uint16_t uniffi_swift_xet_rust_checksum_method_transfergroup_is_cancelled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_IS_PAUSED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_IS_PAUSED
+uint16_t uniffi_swift_xet_rust_checksum_method_transfergroup_is_paused(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_PAUSE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_PAUSE
+uint16_t uniffi_swift_xet_rust_checksum_method_transfergroup_pause(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_PROGRESS
+uint16_t uniffi_swift_xet_rust_checksum_method_transfergroup_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_RESUME
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERGROUP_RESUME
+uint16_t uniffi_swift_xet_rust_checksum_method_transfergroup_resume(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_BYTES
+uint16_t uniffi_swift_xet_rust_checksum_method_transferreport_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_CAS_ENDPOINT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_CAS_ENDPOINT
+uint16_t uniffi_swift_xet_rust_checksum_method_transferreport_cas_endpoint(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_CAS_ENDPOINT_LATENCIES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_CAS_ENDPOINT_LATENCIES
+uint16_t uniffi_swift_xet_rust_checksum_method_transferreport_cas_endpoint_latencies(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_ELAPSED_MS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_ELAPSED_MS
+uint16_t uniffi_swift_xet_rust_checksum_method_transferreport_elapsed_ms(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_SERVED_STALE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_SERVED_STALE
+uint16_t uniffi_swift_xet_rust_checksum_method_transferreport_served_stale(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_SOURCE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_SOURCE
+uint16_t uniffi_swift_xet_rust_checksum_method_transferreport_source(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_VERIFICATION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_VERIFICATION
+uint16_t uniffi_swift_xet_rust_checksum_method_transferreport_verification(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_VERIFICATION_MS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TRANSFERREPORT_VERIFICATION_MS
+uint16_t uniffi_swift_xet_rust_checksum_method_transferreport_verification_ms(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TREEITERATOR_IS_FINISHED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TREEITERATOR_IS_FINISHED
+uint16_t uniffi_swift_xet_rust_checksum_method_treeiterator_is_finished(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TREEITERATOR_NEXT_PAGE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TREEITERATOR_NEXT_PAGE
+uint16_t uniffi_swift_xet_rust_checksum_method_treeiterator_next_page(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TREELISTING_FILES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TREELISTING_FILES
+uint16_t uniffi_swift_xet_rust_checksum_method_treelisting_files(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TREELISTING_IS_COMPLETE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TREELISTING_IS_COMPLETE
+uint16_t uniffi_swift_xet_rust_checksum_method_treelisting_is_complete(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TREELISTING_UNLISTED_PATHS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_TREELISTING_UNLISTED_PATHS
+uint16_t uniffi_swift_xet_rust_checksum_method_treelisting_unlisted_paths(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_UPLOADSESSION_STAGED_PATHS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_UPLOADSESSION_STAGED_PATHS
+uint16_t uniffi_swift_xet_rust_checksum_method_uploadsession_staged_paths(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_CAN_ACCESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_CAN_ACCESS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_can_access(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_CLEAR_CACHE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_CLEAR_CACHE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_clear_cache(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_CLEAR_CACHE_AREA
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_CLEAR_CACHE_AREA
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_clear_cache_area(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DELETE_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DELETE_FILE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_delete_file(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_BY_HASH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_BY_HASH
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_by_hash(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_file(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_ASYNC
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_file_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_IN_GROUP
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_IN_GROUP
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_file_in_group(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_IN_GROUP_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_IN_GROUP_ASYNC
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_file_in_group_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_HANDLE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_HANDLE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_file_with_handle(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_HANDLE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_HANDLE_ASYNC
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_file_with_handle_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_PROGRESS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_file_with_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_PROGRESS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILE_WITH_PROGRESS_ASYNC
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_file_with_progress_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_files(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_ASYNC
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_DETAILED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_DETAILED
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_detailed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_DETAILED_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_DETAILED_ASYNC
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_detailed_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_RESULTS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_RESULTS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_with_results(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_RESULTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_RESULTS_ASYNC
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_with_results_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_TEMPLATE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES_BATCH_WITH_TEMPLATE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_files_batch_with_template(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FOLDER
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_folder(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FOLDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FOLDER_ASYNC
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_folder_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_REPO_ARCHIVE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_REPO_ARCHIVE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_repo_archive(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_REPO_ARCHIVE_WITH_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_REPO_ARCHIVE_WITH_PROGRESS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_repo_archive_with_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_TO_CACHE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_TO_CACHE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_to_cache(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_TO_CACHE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_TO_CACHE_ASYNC
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_to_cache_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_ESTIMATE_UPLOAD
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_ESTIMATE_UPLOAD
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_estimate_upload(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I16
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I16
-void ffi_swift_xet_rust_rust_future_poll_i16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_FILTER_MISSING
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_FILTER_MISSING
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_filter_missing(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I16
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I16
-void ffi_swift_xet_rust_rust_future_cancel_i16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CACHE_STATS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CACHE_STATS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_cache_stats(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I16
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I16
-void ffi_swift_xet_rust_rust_future_free_i16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CACHED_PATH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CACHED_PATH
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_cached_path(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I16
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I16
-int16_t ffi_swift_xet_rust_rust_future_complete_i16(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CAS_JWT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CAS_JWT
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_cas_jwt(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U32
-void ffi_swift_xet_rust_rust_future_poll_u32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CLOCK_SKEW_SECS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CLOCK_SKEW_SECS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_clock_skew_secs(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U32
-void ffi_swift_xet_rust_rust_future_cancel_u32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_FILE_INFO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_FILE_INFO
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_file_info(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U32
-void ffi_swift_xet_rust_rust_future_free_u32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_PATHS_INFO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_PATHS_INFO
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_paths_info(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U32
-uint32_t ffi_swift_xet_rust_rust_future_complete_u32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_RATE_LIMIT_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_RATE_LIMIT_STATUS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_rate_limit_status(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I32
-void ffi_swift_xet_rust_rust_future_poll_i32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_REPO_INFO
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_REPO_INFO
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_repo_info(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I32
-void ffi_swift_xet_rust_rust_future_cancel_i32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_REPO_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_REPO_SIZE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_repo_size(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I32
-void ffi_swift_xet_rust_rust_future_free_i32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_USAGE_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_USAGE_REPORT
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_usage_report(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I32
-int32_t ffi_swift_xet_rust_rust_future_complete_i32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_XET_POINTER_DETAILS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_XET_POINTER_DETAILS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_xet_pointer_details(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_U64
-void ffi_swift_xet_rust_rust_future_poll_u64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_LIST_CACHED_REVISIONS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_LIST_CACHED_REVISIONS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_list_cached_revisions(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_U64
-void ffi_swift_xet_rust_rust_future_cancel_u64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_LIST_FILES_RECURSIVE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_LIST_FILES_RECURSIVE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_list_files_recursive(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_U64
-void ffi_swift_xet_rust_rust_future_free_u64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_LIST_MANAGED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_LIST_MANAGED
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_list_managed(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_U64
-uint64_t ffi_swift_xet_rust_rust_future_complete_u64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_MOVE_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_MOVE_FILE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_move_file(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_I64
-void ffi_swift_xet_rust_rust_future_poll_i64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_OPEN_FILE_STREAM
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_OPEN_FILE_STREAM
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_open_file_stream(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_I64
-void ffi_swift_xet_rust_rust_future_cancel_i64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_PREPARE_FOR_TERMINATION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_PREPARE_FOR_TERMINATION
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_prepare_for_termination(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_I64
-void ffi_swift_xet_rust_rust_future_free_i64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_PREUPLOAD
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_PREUPLOAD
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_preupload(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_I64
-int64_t ffi_swift_xet_rust_rust_future_complete_i64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_PRUNE_CACHE_AREA
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_PRUNE_CACHE_AREA
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_prune_cache_area(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_F32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_F32
-void ffi_swift_xet_rust_rust_future_poll_f32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_PUBLISH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_PUBLISH
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_publish(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_F32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_F32
-void ffi_swift_xet_rust_rust_future_cancel_f32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_RECORD_SESSION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_RECORD_SESSION
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_record_session(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_F32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_F32
-void ffi_swift_xet_rust_rust_future_free_f32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_RECOVER_PENDING_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_RECOVER_PENDING_COMMITS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_recover_pending_commits(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_F32
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_F32
-float ffi_swift_xet_rust_rust_future_complete_f32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_REGISTER_MANAGED_MODEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_REGISTER_MANAGED_MODEL
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_register_managed_model(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_F64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_F64
-void ffi_swift_xet_rust_rust_future_poll_f64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_RESET_USAGE_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_RESET_USAGE_REPORT
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_reset_usage_report(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_F64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_F64
-void ffi_swift_xet_rust_rust_future_cancel_f64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SET_DOWNLOAD_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SET_DOWNLOAD_POLICY
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_set_download_policy(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_F64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_F64
-void ffi_swift_xet_rust_rust_future_free_f64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SET_EVENT_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SET_EVENT_LISTENER
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_set_event_listener(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_F64
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_F64
-double ffi_swift_xet_rust_rust_future_complete_f64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SET_MAX_DOWNLOAD_BYTES_PER_SECOND
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SET_MAX_DOWNLOAD_BYTES_PER_SECOND
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_set_max_download_bytes_per_second(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_POINTER
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_POINTER
-void ffi_swift_xet_rust_rust_future_poll_pointer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SET_QUIET
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SET_QUIET
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_set_quiet(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_POINTER
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_POINTER
-void ffi_swift_xet_rust_rust_future_cancel_pointer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SET_USAGE_LABEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SET_USAGE_LABEL
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_set_usage_label(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_POINTER
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_POINTER
-void ffi_swift_xet_rust_rust_future_free_pointer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SNAPSHOT_DOWNLOAD
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SNAPSHOT_DOWNLOAD
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_snapshot_download(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_POINTER
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_POINTER
-void*_Nonnull ffi_swift_xet_rust_rust_future_complete_pointer(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SNAPSHOT_DOWNLOAD_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_SNAPSHOT_DOWNLOAD_ASYNC
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_snapshot_download_async(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_RUST_BUFFER
-void ffi_swift_xet_rust_rust_future_poll_rust_buffer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_STAGE_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_STAGE_FILE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_stage_file(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_RUST_BUFFER
-void ffi_swift_xet_rust_rust_future_cancel_rust_buffer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_START_CACHE_MAINTENANCE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_START_CACHE_MAINTENANCE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_start_cache_maintenance(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_RUST_BUFFER
-void ffi_swift_xet_rust_rust_future_free_rust_buffer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_STOP_CACHE_MAINTENANCE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_STOP_CACHE_MAINTENANCE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_stop_cache_maintenance(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_RUST_BUFFER
-RustBuffer ffi_swift_xet_rust_rust_future_complete_rust_buffer(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_STOP_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_STOP_RECORDING
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_stop_recording(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_VOID
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_POLL_VOID
-void ffi_swift_xet_rust_rust_future_poll_void(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_TREE_ITERATOR
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_TREE_ITERATOR
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_tree_iterator(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_VOID
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_CANCEL_VOID
-void ffi_swift_xet_rust_rust_future_cancel_void(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPDATE_ALL_MANAGED
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPDATE_ALL_MANAGED
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_update_all_managed(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_VOID
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_FREE_VOID
-void ffi_swift_xet_rust_rust_future_free_void(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_BYTES
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_upload_bytes(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_VOID
-#define UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_RUST_FUTURE_COMPLETE_VOID
-void ffi_swift_xet_rust_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_FILE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_upload_file(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_FILE_COUNT
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_FILE_COUNT
-uint16_t uniffi_swift_xet_rust_checksum_method_cachestats_file_count(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_FILE_WITH_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_FILE_WITH_PROGRESS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_upload_file_with_progress(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_TOTAL_SIZE_BYTES
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CACHESTATS_TOTAL_SIZE_BYTES
-uint16_t uniffi_swift_xet_rust_checksum_method_cachestats_total_size_bytes(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_FILES
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_FILES
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_upload_files(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_ACCESS_TOKEN
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_ACCESS_TOKEN
-uint16_t uniffi_swift_xet_rust_checksum_method_casjwtinfo_access_token(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_FILES_WITH_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_FILES_WITH_PROGRESS
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_upload_files_with_progress(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_CAS_URL
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_CAS_URL
-uint16_t uniffi_swift_xet_rust_checksum_method_casjwtinfo_cas_url(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_UPLOAD_FOLDER
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_upload_folder(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_EXP
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_CASJWTINFO_EXP
-uint16_t uniffi_swift_xet_rust_checksum_method_casjwtinfo_exp(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_VERSION
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_VERSION
+uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_version(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_ENTRY_TYPE
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_ENTRY_TYPE
-uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_entry_type(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETFILEINFO_FILE_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETFILEINFO_FILE_SIZE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetfileinfo_file_size(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_HASH
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_HASH
-uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_hash(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETFILEINFO_HASH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETFILEINFO_HASH
+uint16_t uniffi_swift_xet_rust_checksum_method_xetfileinfo_hash(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_OID
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_OID
-uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_oid(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETPOINTERDETAILS_CAS_ENDPOINT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETPOINTERDETAILS_CAS_ENDPOINT
+uint16_t uniffi_swift_xet_rust_checksum_method_xetpointerdetails_cas_endpoint(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_PATH
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_PATH
-uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_path(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETPOINTERDETAILS_REFRESH_ROUTE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETPOINTERDETAILS_REFRESH_ROUTE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetpointerdetails_refresh_route(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_SIZE
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_FILEMETADATA_SIZE
-uint16_t uniffi_swift_xet_rust_checksum_method_filemetadata_size(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETPOINTERDETAILS_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETPOINTERDETAILS_SIZE
+uint16_t uniffi_swift_xet_rust_checksum_method_xetpointerdetails_size(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOINFO_FULL_NAME
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOINFO_FULL_NAME
-uint16_t uniffi_swift_xet_rust_checksum_method_repoinfo_full_name(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETPOINTERDETAILS_XET_HASH
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETPOINTERDETAILS_XET_HASH
+uint16_t uniffi_swift_xet_rust_checksum_method_xetpointerdetails_xet_hash(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOINFO_REPO_TYPE
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_REPOINFO_REPO_TYPE
-uint16_t uniffi_swift_xet_rust_checksum_method_repoinfo_repo_type(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_CANCELLATIONTOKEN_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_CANCELLATIONTOKEN_NEW
+uint16_t uniffi_swift_xet_rust_checksum_constructor_cancellationtoken_new(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_CLEAR_CACHE
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_CLEAR_CACHE
-uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_clear_cache(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_CANCELLATIONTOKEN_WITH_LABEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_CANCELLATIONTOKEN_WITH_LABEL
+uint16_t uniffi_swift_xet_rust_checksum_constructor_cancellationtoken_with_label(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_DOWNLOAD_FILES
-uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_download_files(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_CANCELLATIONTOKEN_WITH_TIMEOUT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_CANCELLATIONTOKEN_WITH_TIMEOUT
+uint16_t uniffi_swift_xet_rust_checksum_constructor_cancellationtoken_with_timeout(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CACHE_STATS
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CACHE_STATS
-uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_cache_stats(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_DOWNLOADHANDLE_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_DOWNLOADHANDLE_NEW
+uint16_t uniffi_swift_xet_rust_checksum_constructor_downloadhandle_new(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CAS_JWT
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_CAS_JWT
-uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_cas_jwt(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_DOWNLOADHANDLE_WITH_LABEL
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_DOWNLOADHANDLE_WITH_LABEL
+uint16_t uniffi_swift_xet_rust_checksum_constructor_downloadhandle_with_label(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_FILE_INFO
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_FILE_INFO
-uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_file_info(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_DOWNLOADHANDLE_WITH_TIMEOUT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_DOWNLOADHANDLE_WITH_TIMEOUT
+uint16_t uniffi_swift_xet_rust_checksum_constructor_downloadhandle_with_timeout(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_REPO_INFO
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_GET_REPO_INFO
-uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_get_repo_info(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_FILEDOWNLOADREQUEST_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_FILEDOWNLOADREQUEST_NEW
+uint16_t uniffi_swift_xet_rust_checksum_constructor_filedownloadrequest_new(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_VERSION
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETCLIENT_VERSION
-uint16_t uniffi_swift_xet_rust_checksum_method_xetclient_version(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_FILEDOWNLOADREQUEST_WITH_PRIORITY
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_FILEDOWNLOADREQUEST_WITH_PRIORITY
+uint16_t uniffi_swift_xet_rust_checksum_constructor_filedownloadrequest_with_priority(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETFILEINFO_FILE_SIZE
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETFILEINFO_FILE_SIZE
-uint16_t uniffi_swift_xet_rust_checksum_method_xetfileinfo_file_size(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_TRANSFERGROUP_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_TRANSFERGROUP_NEW
+uint16_t uniffi_swift_xet_rust_checksum_constructor_transfergroup_new(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETFILEINFO_HASH
-#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETFILEINFO_HASH
-uint16_t uniffi_swift_xet_rust_checksum_method_xetfileinfo_hash(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_UPLOADSESSION_NEW
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_UPLOADSESSION_NEW
+uint16_t uniffi_swift_xet_rust_checksum_constructor_uploadsession_new(void
     
 );
 #endif
@@ -842,6 +3079,12 @@ uint16_t uniffi_swift_xet_rust_checksum_method_xetfileinfo_hash(void
 #define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_XETCLIENT_NEW
 uint16_t uniffi_swift_xet_rust_checksum_constructor_xetclient_new(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_XETCLIENT_WITH_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_XETCLIENT_WITH_CONFIG
+uint16_t uniffi_swift_xet_rust_checksum_constructor_xetclient_with_config(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_XETCLIENT_WITH_TOKEN
@@ -854,6 +3097,24 @@ uint16_t uniffi_swift_xet_rust_checksum_constructor_xetclient_with_token(void
 #define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_CONSTRUCTOR_XETFILEINFO_NEW
 uint16_t uniffi_swift_xet_rust_checksum_constructor_xetfileinfo_new(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADPOLICY_ALLOW_DOWNLOAD
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_DOWNLOADPOLICY_ALLOW_DOWNLOAD
+uint16_t uniffi_swift_xet_rust_checksum_method_downloadpolicy_allow_download(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_PROGRESSCALLBACK_ON_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_PROGRESSCALLBACK_ON_PROGRESS
+uint16_t uniffi_swift_xet_rust_checksum_method_progresscallback_on_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETEVENTLISTENER_ON_EVENT
+#define UNIFFI_FFIDEF_UNIFFI_SWIFT_XET_RUST_CHECKSUM_METHOD_XETEVENTLISTENER_ON_EVENT
+uint16_t uniffi_swift_xet_rust_checksum_method_xeteventlistener_on_event(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_SWIFT_XET_RUST_UNIFFI_CONTRACT_VERSION
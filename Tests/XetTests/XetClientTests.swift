@@ -63,6 +63,36 @@ import Testing
         #expect(sizeOnDisk == fileInfo.fileSize())
     }
 
+    @Test("Parallel downloads share one client")
+    func parallelDownloads() async throws {
+        let xet = try XetClient()
+        let paths = ["config.json", "generation_config.json", "tokenizer_config.json", "README.md"]
+
+        let tempDir = FileManager.default.temporaryDirectory
+            .appendingPathComponent(UUID().uuidString, isDirectory: true)
+        try FileManager.default.createDirectory(at: tempDir, withIntermediateDirectories: true)
+        defer { try? FileManager.default.removeItem(at: tempDir) }
+
+        let reports = try await withThrowingTaskGroup(of: TransferReport.self) { group in
+            for round in 0..<4 {
+                for path in paths {
+                    group.addTask {
+                        try xet.downloadFile(
+                            repo: "Qwen/Qwen3-0.6B",
+                            path: path,
+                            destination: tempDir.appendingPathComponent("\(round)/\(path)").path,
+                            revision: "main"
+                        )
+                    }
+                }
+            }
+            return try await group.reduce(into: []) { $0.append($1) }
+        }
+
+        #expect(reports.count == paths.count * 4)
+        #expect(reports.allSatisfy { $0.bytes() > 0 })
+    }
+
     @Test("XetFileInfo creation and serialization")
     func xetFileInfo() async throws {
         let fileInfo = XetFileInfo(hash: "abc123", fileSize: 1024)